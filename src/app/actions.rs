@@ -1,3 +1,4 @@
+use crate::i18n::{tr, MessageId};
 use std::fmt::{self, Display};
 use strum::IntoEnumIterator;
 use strum_macros::EnumIter;
@@ -7,6 +8,8 @@ pub enum Action {
     ChangeCardStatusToActive,
     ChangeCardStatusToCompleted,
     ChangeCardStatusToStale,
+    ArchiveCard,
+    ArchiveBoard,
     ChangeCardPriorityToHigh,
     ChangeCardPriorityToMedium,
     ChangeCardPriorityToLow,
@@ -14,14 +17,27 @@ pub enum Action {
     Delete,
     DeleteBoard,
     Down,
+    DuplicateBoard,
+    DuplicateCard,
+    EditBoard,
+    ExportToMarkdown,
+    FilterByDateRange,
+    FilterByPriority,
+    FilterByStatus,
     Accept,
     GoToMainMenu,
     GoToPreviousViewOrCancel,
+    GrowSecondaryPane,
     HideUiElement,
     Left,
+    MoveBoardLeft,
+    MoveBoardRight,
     MoveCardDown,
     MoveCardLeft,
     MoveCardRight,
+    MoveCardToBoard,
+    MoveCardToBottom,
+    MoveCardToTop,
     MoveCardUp,
     NewBoard,
     NewCard,
@@ -33,10 +49,24 @@ pub enum Action {
     ResetUI,
     Right,
     SaveState,
+    SelectCardRangeDown,
+    SelectCardRangeUp,
+    SetCardHighlightColor,
+    ShrinkSecondaryPane,
+    ShowBoardStats,
+    SkipTutorialStep,
+    SortCards,
+    StartHistoryStepCount,
     StopUserInput,
     TakeUserInput,
+    ToggleCardSelection,
     ToggleCommandPalette,
+    ToggleCompletedCardsVisibility,
+    ToggleArchivedView,
+    ToggleFocusTimer,
+    ToggleNotifications,
     Undo,
+    UndoFromToast,
     Up,
 }
 
@@ -44,46 +74,97 @@ impl Action {
     pub fn all() -> Vec<Action> {
         Action::iter().collect()
     }
+
+    /// Actions that keep an active card range selection, every other action collapses it
+    pub fn card_range_actions() -> Vec<Action> {
+        vec![
+            Action::ChangeCardStatusToActive,
+            Action::ChangeCardStatusToCompleted,
+            Action::ChangeCardStatusToStale,
+            Action::ChangeCardPriorityToHigh,
+            Action::ChangeCardPriorityToMedium,
+            Action::ChangeCardPriorityToLow,
+            Action::Delete,
+            Action::MoveCardLeft,
+            Action::MoveCardRight,
+            Action::SelectCardRangeDown,
+            Action::SelectCardRangeUp,
+        ]
+    }
+
+    pub fn message_id(&self) -> MessageId {
+        match self {
+            Action::ChangeCardStatusToActive => MessageId::ActionChangeCardStatusToActive,
+            Action::ChangeCardStatusToCompleted => MessageId::ActionChangeCardStatusToCompleted,
+            Action::ChangeCardStatusToStale => MessageId::ActionChangeCardStatusToStale,
+            Action::ArchiveCard => MessageId::ActionArchiveCard,
+            Action::ArchiveBoard => MessageId::ActionArchiveBoard,
+            Action::ChangeCardPriorityToHigh => MessageId::ActionChangeCardPriorityToHigh,
+            Action::ChangeCardPriorityToMedium => MessageId::ActionChangeCardPriorityToMedium,
+            Action::ChangeCardPriorityToLow => MessageId::ActionChangeCardPriorityToLow,
+            Action::ClearAllToasts => MessageId::ActionClearAllToasts,
+            Action::Delete => MessageId::ActionDelete,
+            Action::DeleteBoard => MessageId::ActionDeleteBoard,
+            Action::Down => MessageId::ActionDown,
+            Action::DuplicateBoard => MessageId::ActionDuplicateBoard,
+            Action::DuplicateCard => MessageId::ActionDuplicateCard,
+            Action::EditBoard => MessageId::ActionEditBoard,
+            Action::ExportToMarkdown => MessageId::ActionExportToMarkdown,
+            Action::FilterByDateRange => MessageId::ActionFilterByDateRange,
+            Action::FilterByPriority => MessageId::ActionFilterByPriority,
+            Action::FilterByStatus => MessageId::ActionFilterByStatus,
+            Action::Accept => MessageId::ActionAccept,
+            Action::GoToMainMenu => MessageId::ActionGoToMainMenu,
+            Action::GoToPreviousViewOrCancel => MessageId::ActionGoToPreviousViewOrCancel,
+            Action::GrowSecondaryPane => MessageId::ActionGrowSecondaryPane,
+            Action::HideUiElement => MessageId::ActionHideUiElement,
+            Action::Left => MessageId::ActionLeft,
+            Action::MoveBoardLeft => MessageId::ActionMoveBoardLeft,
+            Action::MoveBoardRight => MessageId::ActionMoveBoardRight,
+            Action::MoveCardDown => MessageId::ActionMoveCardDown,
+            Action::MoveCardLeft => MessageId::ActionMoveCardLeft,
+            Action::MoveCardRight => MessageId::ActionMoveCardRight,
+            Action::MoveCardToBoard => MessageId::ActionMoveCardToBoard,
+            Action::MoveCardToBottom => MessageId::ActionMoveCardToBottom,
+            Action::MoveCardToTop => MessageId::ActionMoveCardToTop,
+            Action::MoveCardUp => MessageId::ActionMoveCardUp,
+            Action::NewBoard => MessageId::ActionNewBoard,
+            Action::NewCard => MessageId::ActionNewCard,
+            Action::NextFocus => MessageId::ActionNextFocus,
+            Action::OpenConfigMenu => MessageId::ActionOpenConfigMenu,
+            Action::PrvFocus => MessageId::ActionPrvFocus,
+            Action::Quit => MessageId::ActionQuit,
+            Action::Redo => MessageId::ActionRedo,
+            Action::ResetUI => MessageId::ActionResetUI,
+            Action::Right => MessageId::ActionRight,
+            Action::SaveState => MessageId::ActionSaveState,
+            Action::SelectCardRangeDown => MessageId::ActionSelectCardRangeDown,
+            Action::SelectCardRangeUp => MessageId::ActionSelectCardRangeUp,
+            Action::SetCardHighlightColor => MessageId::ActionSetCardHighlightColor,
+            Action::ShrinkSecondaryPane => MessageId::ActionShrinkSecondaryPane,
+            Action::ShowBoardStats => MessageId::ActionShowBoardStats,
+            Action::SkipTutorialStep => MessageId::ActionSkipTutorialStep,
+            Action::SortCards => MessageId::ActionSortCards,
+            Action::StartHistoryStepCount => MessageId::ActionStartHistoryStepCount,
+            Action::StopUserInput => MessageId::ActionStopUserInput,
+            Action::TakeUserInput => MessageId::ActionTakeUserInput,
+            Action::ToggleCardSelection => MessageId::ActionToggleCardSelection,
+            Action::ToggleCommandPalette => MessageId::ActionToggleCommandPalette,
+            Action::ToggleCompletedCardsVisibility => {
+                MessageId::ActionToggleCompletedCardsVisibility
+            }
+            Action::ToggleArchivedView => MessageId::ActionToggleArchivedView,
+            Action::ToggleFocusTimer => MessageId::ActionToggleFocusTimer,
+            Action::ToggleNotifications => MessageId::ActionToggleNotifications,
+            Action::Undo => MessageId::ActionUndo,
+            Action::UndoFromToast => MessageId::ActionUndoFromToast,
+            Action::Up => MessageId::ActionUp,
+        }
+    }
 }
 
 impl Display for Action {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let str = match self {
-            Action::ChangeCardStatusToActive => "Change card status to active",
-            Action::ChangeCardStatusToCompleted => "Change card status to completed",
-            Action::ChangeCardStatusToStale => "Change card status to stale",
-            Action::ChangeCardPriorityToHigh => "Change card priority to high",
-            Action::ChangeCardPriorityToMedium => "Change card priority to medium",
-            Action::ChangeCardPriorityToLow => "Change card priority to low",
-            Action::ClearAllToasts => "Clear all toasts",
-            Action::Delete => "Delete focused element",
-            Action::DeleteBoard => "Delete Board",
-            Action::Down => "Go down",
-            Action::Accept => "Accept",
-            Action::GoToMainMenu => "Go to main menu",
-            Action::GoToPreviousViewOrCancel => "Go to previous View or cancel",
-            Action::HideUiElement => "Hide Focused element",
-            Action::Left => "Go left",
-            Action::MoveCardDown => "Move card down",
-            Action::MoveCardLeft => "Move card left",
-            Action::MoveCardRight => "Move card right",
-            Action::MoveCardUp => "Move card up",
-            Action::NewBoard => "Create new board",
-            Action::NewCard => "Create new card in current board",
-            Action::NextFocus => "Focus next",
-            Action::OpenConfigMenu => "Configure",
-            Action::PrvFocus => "Focus previous",
-            Action::Quit => "Quit",
-            Action::Redo => "Redo",
-            Action::ResetUI => "Reset UI",
-            Action::Right => "Go right",
-            Action::SaveState => "Save Kanban state",
-            Action::StopUserInput => "Stop input mode",
-            Action::TakeUserInput => "Enter input mode",
-            Action::ToggleCommandPalette => "Open command palette",
-            Action::Undo => "Undo",
-            Action::Up => "Go up",
-        };
-        write!(f, "{}", str)
+        write!(f, "{}", tr(self.message_id()))
     }
 }
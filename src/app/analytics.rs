@@ -0,0 +1,215 @@
+//! Card counts for the Stats view, worked out from the boards as they are now. Everything takes
+//! the boards and the current time so the numbers can be checked without any app state
+//!
+//! ```
+//! use chrono::NaiveDate;
+//! use rust_kanban::app::{
+//!     analytics::{
+//!         completed_this_week, most_overdue_cards, priority_counts, status_counts_per_board,
+//!     },
+//!     kanban::{Board, Boards, CardPriority},
+//! };
+//! use serde_json::json;
+//!
+//! let card = |id: u64, status: &str, priority: &str, due_date: &str, date_completed: &str| {
+//!     json!({
+//!         "id": [id, 1], "name": format!("Card {}", id), "description": "",
+//!         "date_created": "", "date_modified": "", "due_date": due_date,
+//!         "date_completed": date_completed, "priority": priority, "card_status": status,
+//!         "tags": [], "comments": [],
+//!     })
+//! };
+//! let board = Board::from_json(&json!({
+//!     "name": "Release", "description": "",
+//!     "cards": [
+//!         card(1, "Active", "High", "01/05/2024", ""),
+//!         card(2, "Stale", "Low", "10/05/2024", ""),
+//!         card(3, "Complete", "Low", "01/05/2024", "14/05/2024-09:00:00"),
+//!         card(4, "Archived", "Medium", "01/01/2024", ""),
+//!         card(5, "Active", "Medium", "Not Set", ""),
+//!     ],
+//! }))
+//! .unwrap();
+//! let boards = Boards::from(vec![board]);
+//! // A Wednesday
+//! let now = NaiveDate::from_ymd_opt(2024, 5, 15)
+//!     .unwrap()
+//!     .and_hms_opt(12, 0, 0)
+//!     .unwrap();
+//!
+//! let counts = &status_counts_per_board(&boards)[0];
+//! assert_eq!(counts.board_name, "Release");
+//! assert_eq!((counts.active, counts.complete, counts.stale), (2, 1, 1));
+//! assert_eq!(counts.archived, 1);
+//!
+//! // Archived cards are off the boards and are not counted
+//! assert_eq!(
+//!     priority_counts(&boards),
+//!     [
+//!         (CardPriority::Low, 2),
+//!         (CardPriority::Medium, 1),
+//!         (CardPriority::High, 1),
+//!     ]
+//! );
+//!
+//! // Only open cards are overdue, the longest overdue comes first
+//! let overdue = most_overdue_cards(&boards, now, 5);
+//! let overdue = overdue
+//!     .iter()
+//!     .map(|card| (card.card_name.as_str(), card.days_overdue))
+//!     .collect::<Vec<(&str, i64)>>();
+//! assert_eq!(overdue, [("Card 1", 14), ("Card 2", 5)]);
+//! assert_eq!(most_overdue_cards(&boards, now, 1).len(), 1);
+//!
+//! assert_eq!(completed_this_week(&boards, now), 1);
+//! ```
+
+use crate::{
+    app::kanban::{Boards, Card, CardPriority, CardStatus},
+    util::parse_date_time,
+};
+use chrono::{Datelike, NaiveDateTime};
+
+/// How many cards of one board are in each built in status
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct BoardStatusCounts {
+    pub board_name: String,
+    pub active: usize,
+    pub complete: usize,
+    pub stale: usize,
+    pub archived: usize,
+}
+
+/// An open card whose due date has passed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OverdueCard {
+    pub board_name: String,
+    pub card_name: String,
+    /// Whole days between the due day and today, at least 1
+    pub days_overdue: i64,
+}
+
+/// One entry per board in board order. Boards with their own statuses are counted by the built
+/// in status their cards keep next to the custom one
+pub fn status_counts_per_board(boards: &Boards) -> Vec<BoardStatusCounts> {
+    boards
+        .get_boards()
+        .iter()
+        .map(|board| {
+            let mut counts = BoardStatusCounts {
+                board_name: board.name.clone(),
+                ..Default::default()
+            };
+            for card in board.cards.get_all_cards() {
+                match card.card_status {
+                    CardStatus::Active => counts.active += 1,
+                    CardStatus::Complete => counts.complete += 1,
+                    CardStatus::Stale => counts.stale += 1,
+                    CardStatus::Archived => counts.archived += 1,
+                }
+            }
+            counts
+        })
+        .collect()
+}
+
+/// Estimate sums per status over every board, in the order the statuses first appear. Boards
+/// with their own statuses add to the status of the same name
+pub fn estimate_per_status(boards: &Boards) -> Vec<(String, f32)> {
+    let mut estimates: Vec<(String, f32)> = Vec::new();
+    for board in boards.get_boards() {
+        for (status_name, board_estimate) in board.get_estimate_by_status() {
+            match estimates.iter_mut().find(|(name, _)| *name == status_name) {
+                Some((_, estimate)) => *estimate += board_estimate,
+                None => estimates.push((status_name, board_estimate)),
+            }
+        }
+    }
+    estimates
+}
+
+/// Cards on every board by priority, in the order of [`CardPriority::all`]
+pub fn priority_counts(boards: &Boards) -> Vec<(CardPriority, usize)> {
+    CardPriority::all()
+        .into_iter()
+        .map(|priority| {
+            let count = cards_on_boards(boards)
+                .filter(|(_, card)| card.priority == priority)
+                .count();
+            (priority, count)
+        })
+        .collect()
+}
+
+/// Up to `limit` open cards that are past their due day, the longest overdue first. Counted the
+/// same way as the overdue cards in the stats history
+pub fn most_overdue_cards(boards: &Boards, now: NaiveDateTime, limit: usize) -> Vec<OverdueCard> {
+    let today = now.date();
+    let mut overdue_cards = cards_on_boards(boards)
+        .filter(|(_, card)| matches!(card.card_status, CardStatus::Active | CardStatus::Stale))
+        .filter_map(|(board_name, card)| {
+            let due_date = parse_date_time(&card.due_date)?.date();
+            (due_date < today).then(|| OverdueCard {
+                board_name: board_name.to_string(),
+                card_name: card.name.clone(),
+                days_overdue: (today - due_date).num_days(),
+            })
+        })
+        .collect::<Vec<OverdueCard>>();
+    // Stable, so cards overdue by the same amount keep their board order
+    overdue_cards.sort_by_key(|overdue_card| std::cmp::Reverse(overdue_card.days_overdue));
+    overdue_cards.truncate(limit);
+    overdue_cards
+}
+
+/// Completed cards whose completion date falls in the current Monday to Sunday week
+pub fn completed_this_week(boards: &Boards, now: NaiveDateTime) -> usize {
+    cards_on_boards(boards)
+        .filter(|(_, card)| card.card_status == CardStatus::Complete)
+        .filter_map(|(_, card)| parse_date_time(&card.date_completed))
+        .filter(|date_completed| date_completed.date().iso_week() == now.date().iso_week())
+        .count()
+}
+
+/// Every card that is not archived, with the name of its board
+fn cards_on_boards(boards: &Boards) -> impl Iterator<Item = (&str, &Card)> {
+    boards.get_boards().iter().flat_map(|board| {
+        board
+            .cards
+            .get_all_cards()
+            .iter()
+            .filter(|card| card.card_status != CardStatus::Archived)
+            .map(move |card| (board.name.as_str(), card))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::kanban::Board;
+
+    #[test]
+    fn estimates_are_summed_per_status_over_every_board() {
+        let boards = ["Todo", "Doing"]
+            .iter()
+            .map(|board_name| {
+                let mut board = Board::new(board_name, "");
+                for (card_status, estimate) in
+                    [(CardStatus::Active, 2.0), (CardStatus::Complete, 0.5)]
+                {
+                    board.cards.add_card(Card {
+                        card_status,
+                        estimate: Some(estimate),
+                        ..Card::default()
+                    });
+                }
+                board
+            })
+            .collect::<Vec<Board>>();
+        assert_eq!(
+            estimate_per_status(&Boards::from(boards)),
+            vec![("Active".to_string(), 4.0), ("Complete".to_string(), 1.0)]
+        );
+        assert!(estimate_per_status(&Boards::default()).is_empty());
+    }
+}
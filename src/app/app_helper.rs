@@ -1,38 +1,170 @@
 use crate::{
     app::{
         actions::Action,
+        calendar::cards_due_on,
+        card_filter::CardFilter,
+        focus_timer::FocusTimer,
         handle_exit,
-        kanban::{Board, Boards, Card, CardPriority, CardStatus, Cards},
-        state::{AppStatus, Focus, KeyBindings, PathCheckState},
-        ActionHistory, App, AppConfig, AppReturn, ConfigEnum, DateTimeFormat, MainMenuItem,
+        kanban::{
+            format_estimate, parse_estimate, parse_minutes, parse_waiting_on, take_estimate_token,
+            Board, Boards, Card, CardPriority, CardSortOrder, CardStatus, ChecklistItem,
+            StatusSelection,
+        },
+        state::{
+            AppStatus, CardRangeSelection, CreatedItemFlash, DuplicateCardNameMatch, Focus,
+            KeyBindings, PathCheckState,
+        },
+        visibility::{
+            board_window, card_window, keep_window_around, scroll_window_to, shift_window_back,
+            shift_window_forward,
+        },
+        waiting_on::WaitingOnGroups,
+        ActionHistory, ActionHistoryManager, App, AppConfig, AppReturn, ConfigEditMode, ConfigEnum,
+        DateTimeFormat, MainMenuItem,
     },
+    changelog::{get_entries_since, parse_version, ChangelogEntry},
     constants::{
-        DEFAULT_TOAST_DURATION, FIELD_NOT_SET, IO_EVENT_WAIT_TIME, MOUSE_OUT_OF_BOUNDS_COORDINATES,
+        CARD_TEMPLATES_FILE_NAME, DEFAULT_SECONDARY_PANE_HEIGHT, DEFAULT_TOAST_DURATION,
+        FIELD_NOT_SET, FOCUS_TIMER_MINUTES, GITHUB_DEFAULT_BOARD_NAME, GITHUB_DEFAULT_TOKEN_ENV,
+        MARKDOWN_EXPORT_FILE_NAME, MAX_HISTORY_STEP_COUNT, MIN_SECONDARY_PANE_HEIGHT,
+        MOUSE_OUT_OF_BOUNDS_COORDINATES, STATS_HISTORY_FILE_NAME,
     },
+    i18n::{tr, tr_args, MessageId},
     inputs::{key::Key, mouse::Mouse},
     io::{
-        data_handler::{get_config, save_theme, write_config},
-        io_handler::refresh_visible_boards_and_cards,
-        IoEvent,
+        clipboard::copy_to_clipboard,
+        data_handler::{
+            get_config, load_save_recovery_candidate, restore_themes_from_backup, save_theme,
+        },
+        io_handler::{
+            add_imported_boards, auto_save, get_shown_board_ids, refresh_visible_boards_and_cards,
+        },
+        logger::{get_default_level, set_default_level, SELECTABLE_LOG_LEVELS},
+        markdown_mirror::{apply_mirror_diff, get_mirror_diff, write_mirror},
+        IoCompletionKind, IoEvent, IoOutcome,
+    },
+    model::{
+        card_templates::{card_template_from, load_card_templates, save_card_template},
+        date_range_filter::DateRangeFilter,
+        duplicate_boards::{self, DIVERGED_CARD_TAG},
+        github_issues::parse_github_repo,
+        plain_text_export::{board_to_plain_text, card_to_plain_text},
+        stats_history::load_stats_history,
     },
     ui::{
-        text_box::TextBox,
+        text_box::{helper_enums::CursorMove, TextBox},
         theme::{Theme, ThemeEnum},
         widgets::{
-            command_palette::CommandPaletteWidget,
+            command_palette::{CardSearchField, CommandPaletteWidget},
             toast::{Toast, ToastType},
         },
         PopUp, TextColorOptions, TextModifierOptions, View,
     },
-    util::{date_format_converter, date_format_finder, parse_hex_to_rgb},
+    util::{date_format_converter, date_format_finder, parse_date_time, parse_hex_to_rgb},
 };
 use chrono::NaiveDateTime;
 use linked_hash_map::LinkedHashMap;
 use log::{debug, error, info, warn};
 use ratatui::{style::Color, widgets::ListState};
-use std::{fs, path::Path, str::FromStr, time::Duration};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    str::FromStr,
+    time::{Duration, Instant},
+};
 use strum::IntoEnumIterator;
 
+/// Selects `board_id` with the board window scrolled to show it, for navigation that wrapped
+/// past the first or last board
+fn wrap_to_board(app: &mut App, board_ids: &[(u64, u64)], board_id: (u64, u64)) {
+    let visible_board_ids = board_window(
+        board_ids,
+        Some(board_id),
+        app.config.no_of_boards_to_show.into(),
+    );
+    set_visible_boards(app, &visible_board_ids);
+    app.state.current_board_id = Some(board_id);
+    app.state.current_card_id = app
+        .visible_boards_and_cards
+        .get(&board_id)
+        .and_then(|card_ids| card_ids.first().copied());
+}
+
+/// Selects `board_id` with the board window moved only as far as needed to show it. The current
+/// card is kept when it is on that board
+pub fn show_board(app: &mut App, board_id: (u64, u64), previous_visible_board_ids: &[(u64, u64)]) {
+    let visible_board_ids = scroll_window_to(
+        &get_shown_board_ids(app),
+        previous_visible_board_ids,
+        board_id,
+        app.config.no_of_boards_to_show.into(),
+    );
+    set_visible_boards(app, &visible_board_ids);
+    let boards: &Boards = if app.filtered_boards.is_empty() {
+        &app.boards
+    } else {
+        &app.filtered_boards
+    };
+    let current_card_id = app.state.current_card_id.filter(|card_id| {
+        boards
+            .get_board_with_id(board_id)
+            .is_some_and(|board| board.cards.get_card_index(*card_id).is_some())
+    });
+    app.state.current_board_id = Some(board_id);
+    app.state.current_card_id = current_card_id;
+    refresh_visible_cards_for_board(app, board_id);
+}
+
+/// Selects `card_id` with the board's card window scrolled to show it, for navigation that
+/// wrapped past the first or last card
+fn wrap_to_card(app: &mut App, board_id: (u64, u64), card_ids: &[(u64, u64)], card_id: (u64, u64)) {
+    let visible_card_ids = card_window(
+        card_ids,
+        Some(card_id),
+        app.get_no_of_cards_to_show().into(),
+    );
+    app.visible_boards_and_cards
+        .entry(board_id)
+        .and_modify(|cards| *cards = visible_card_ids);
+    app.state.current_card_id = Some(card_id);
+}
+
+/// Selects a board, or a card on it, that was just created or restored with the board and card
+/// windows scrolled to show it. It is flashed unless animations are disabled
+pub fn reveal_created_item(app: &mut App, board_id: (u64, u64), card_id: Option<(u64, u64)>) {
+    let previous_visible_board_ids = app
+        .visible_boards_and_cards
+        .keys()
+        .copied()
+        .collect::<Vec<(u64, u64)>>();
+    refresh_visible_boards_and_cards(app);
+    let shown_board_ids = get_shown_board_ids(app);
+    if !shown_board_ids.contains(&board_id) {
+        debug!("Cannot reveal board {:?}: board is not shown", board_id);
+        return;
+    }
+    let visible_board_ids = scroll_window_to(
+        &shown_board_ids,
+        &previous_visible_board_ids,
+        board_id,
+        app.config.no_of_boards_to_show.into(),
+    );
+    set_visible_boards(app, &visible_board_ids);
+    app.state.current_board_id = Some(board_id);
+    app.state.current_card_id = card_id;
+    refresh_visible_cards_for_board(app, board_id);
+    // A card hidden by a filter is not selected, the next one shown should not flash in its place.
+    // A board gets its first card selected, that does not stop the board from flashing
+    if !app.config.disable_animations && (card_id.is_none() || app.state.current_card_id == card_id)
+    {
+        app.state.created_item_flash = Some(CreatedItemFlash {
+            board_id,
+            card_id,
+            started_at: Instant::now(),
+        });
+    }
+}
+
 pub fn go_right(app: &mut App) {
     let current_visible_boards: LinkedHashMap<(u64, u64), Vec<(u64, u64)>> =
         app.visible_boards_and_cards.clone();
@@ -60,30 +192,38 @@ pub fn go_right(app: &mut App) {
     {
         Some(current_board_index) => {
             if current_board_index == current_visible_boards.len() - 1 {
-                match boards.get_board_index(current_board_id) {
+                let board_ids = get_shown_board_ids(app);
+                match board_ids
+                    .iter()
+                    .position(|board_id| *board_id == current_board_id)
+                {
                     Some(current_board_index_in_all_boards) => {
-                        if current_board_index_in_all_boards == (boards.len() - 1) {
-                            app.send_error_toast(
-                                "Cannot go right: Already at the last board",
-                                None,
-                            );
+                        if current_board_index_in_all_boards == (board_ids.len() - 1) {
+                            if app.config.wrap_around_navigation {
+                                wrap_to_board(app, &board_ids, board_ids[0]);
+                            } else {
+                                app.send_info_toast(
+                                    "Cannot go right: Already at the last board",
+                                    None,
+                                );
+                            }
                             return;
                         }
-                        if let Some(next_board) =
-                            boards.get_board_with_index(current_board_index_in_all_boards + 1)
+                        if let Some(next_board_id) = board_ids
+                            .get(current_board_index_in_all_boards + 1)
+                            .copied()
                         {
-                            let next_board_card_ids = next_board.cards.get_all_card_ids();
-                            app.visible_boards_and_cards
-                                .insert(next_board.id, next_board_card_ids.clone());
-
-                            if let Some((&first_board_id, _)) =
-                                app.visible_boards_and_cards.iter().next()
-                            {
-                                app.visible_boards_and_cards.remove(&first_board_id);
-                            }
-
-                            app.state.current_board_id = Some(next_board.id);
-                            app.state.current_card_id = next_board_card_ids.first().copied();
+                            let visible_board_ids = shift_window_forward(
+                                &board_ids,
+                                &current_visible_boards.keys().copied().collect::<Vec<_>>(),
+                                app.config.no_of_boards_to_show.into(),
+                            );
+                            set_visible_boards(app, &visible_board_ids);
+                            app.state.current_board_id = Some(next_board_id);
+                            app.state.current_card_id = app
+                                .visible_boards_and_cards
+                                .get(&next_board_id)
+                                .and_then(|card_ids| card_ids.first().copied());
                         }
                     }
                     None => {
@@ -145,38 +285,38 @@ pub fn go_left(app: &mut App) {
     {
         Some(current_board_index) => {
             if current_board_index == 0 {
-                match boards.get_board_index(current_board_id) {
+                let board_ids = get_shown_board_ids(app);
+                match board_ids
+                    .iter()
+                    .position(|board_id| *board_id == current_board_id)
+                {
                     Some(current_board_index_in_all_boards) => {
                         if current_board_index_in_all_boards == 0 {
-                            app.send_error_toast(
-                                "Cannot go left: Already at the first board",
-                                None,
-                            );
+                            if app.config.wrap_around_navigation {
+                                wrap_to_board(app, &board_ids, board_ids[board_ids.len() - 1]);
+                            } else {
+                                app.send_info_toast(
+                                    "Cannot go left: Already at the first board",
+                                    None,
+                                );
+                            }
                             return;
                         }
-                        if let Some(previous_board) =
-                            boards.get_board_with_index(current_board_index_in_all_boards - 1)
+                        if let Some(previous_board_id) = board_ids
+                            .get(current_board_index_in_all_boards - 1)
+                            .copied()
                         {
-                            let previous_board_card_ids = previous_board.cards.get_all_card_ids();
-                            let mut new_visible_boards_and_cards: LinkedHashMap<
-                                (u64, u64),
-                                Vec<(u64, u64)>,
-                            > = LinkedHashMap::new();
-                            new_visible_boards_and_cards
-                                .insert(previous_board.id, previous_board_card_ids.clone());
-                            for (board_id, card_ids) in current_visible_boards
-                                .iter()
-                                .take(current_visible_boards.len() - 1)
-                            {
-                                new_visible_boards_and_cards.insert(*board_id, card_ids.clone());
-                            }
-                            app.visible_boards_and_cards = new_visible_boards_and_cards;
-                            app.state.current_board_id = Some(previous_board.id);
-                            app.state.current_card_id = if previous_board_card_ids.is_empty() {
-                                None
-                            } else {
-                                Some(previous_board_card_ids[0])
-                            };
+                            let visible_board_ids = shift_window_back(
+                                &board_ids,
+                                &current_visible_boards.keys().copied().collect::<Vec<_>>(),
+                                app.config.no_of_boards_to_show.into(),
+                            );
+                            set_visible_boards(app, &visible_board_ids);
+                            app.state.current_board_id = Some(previous_board_id);
+                            app.state.current_card_id = app
+                                .visible_boards_and_cards
+                                .get(&previous_board_id)
+                                .and_then(|card_ids| card_ids.first().copied());
                         }
                     }
                     None => {
@@ -231,24 +371,24 @@ pub fn go_up(app: &mut App) {
         app.state.current_board_id.unwrap()
     };
 
+    let current_board_cards = match boards.get_board_with_id(current_board_id) {
+        Some(current_board) => app.get_displayable_cards(current_board),
+        None => {
+            debug!("Cannot go up: current board not found");
+            app.send_error_toast("Cannot go up: Something went wrong", None);
+            return;
+        }
+    };
+
     let current_card_id = if let Some(current_card_id) = current_card_id {
         current_card_id
     } else {
-        match boards.get_board_with_id(current_board_id) {
-            Some(current_board) => {
-                if current_board.cards.is_empty() {
-                    debug!("Cannot go up: current board has no cards");
-                    app.send_error_toast("Cannot go up: current board has no cards", None);
-                    return;
-                }
-                current_board.cards.get_first_card_id().unwrap()
-            }
-            None => {
-                debug!("Cannot go up: current board not found");
-                app.send_error_toast("Cannot go up: Something went wrong", None);
-                return;
-            }
+        if current_board_cards.is_empty() {
+            debug!("Cannot go up: current board has no cards");
+            app.send_error_toast("Cannot go up: current board has no cards", None);
+            return;
         }
+        current_board_cards.get_first_card_id().unwrap()
     };
 
     if let Some(current_card_index) = current_visible_boards
@@ -260,35 +400,39 @@ pub fn go_up(app: &mut App) {
         .position(|card_id| *card_id == current_card_id)
     {
         if current_card_index == 0 {
-            if let Some(current_card_index_in_all_cards) = boards
-                .get_board_with_id(current_board_id)
-                .unwrap()
-                .cards
-                .get_card_index(current_card_id)
+            if let Some(current_card_index_in_all_cards) =
+                current_board_cards.get_card_index(current_card_id)
             {
                 if current_card_index_in_all_cards == 0 {
-                    app.send_error_toast("Cannot go up: Already at the first card", None);
-                    return;
-                }
-                if let Some(board) = boards.get_board_with_id(current_board_id) {
-                    if let Some(previous_card) = board
-                        .cards
-                        .get_card_with_index(current_card_index_in_all_cards - 1)
-                    {
-                        let previous_card_id = previous_card.id;
-                        let previous_cards = board.cards.get_cards_with_range(
-                            current_card_index_in_all_cards - 1,
-                            current_card_index_in_all_cards - 1
-                                + app.config.no_of_cards_to_show as usize,
+                    let card_ids = current_board_cards.get_all_card_ids();
+                    if app.config.wrap_around_navigation {
+                        wrap_to_card(
+                            app,
+                            current_board_id,
+                            &card_ids,
+                            card_ids[card_ids.len() - 1],
                         );
-                        app.visible_boards_and_cards
-                            .entry(current_board_id)
-                            .and_modify(|cards| *cards = previous_cards.get_all_card_ids());
-                        app.state.current_card_id = Some(previous_card_id);
                     } else {
-                        debug!("Cannot go up: Already at the first card");
-                        app.send_error_toast("Cannot go up: Already at the first card", None);
+                        app.send_info_toast("Cannot go up: Already at the first card", None);
                     }
+                    return;
+                }
+                if let Some(previous_card) =
+                    current_board_cards.get_card_with_index(current_card_index_in_all_cards - 1)
+                {
+                    let previous_card_id = previous_card.id;
+                    let previous_card_ids = shift_window_back(
+                        &current_board_cards.get_all_card_ids(),
+                        &current_visible_boards[&current_board_id],
+                        app.get_no_of_cards_to_show().into(),
+                    );
+                    app.visible_boards_and_cards
+                        .entry(current_board_id)
+                        .and_modify(|cards| *cards = previous_card_ids);
+                    app.state.current_card_id = Some(previous_card_id);
+                } else {
+                    debug!("Cannot go up: Already at the first card");
+                    app.send_error_toast("Cannot go up: Already at the first card", None);
                 }
             } else {
                 debug!("Cannot go up: current card not found");
@@ -330,17 +474,20 @@ pub fn go_down(app: &mut App) {
         app.state.current_board_id = boards.get_first_board_id();
         app.state.current_board_id.unwrap()
     };
+    let current_board_cards = boards
+        .get_board_with_id(current_board_id)
+        .map(|board| app.get_displayable_cards(board));
     let current_card_id = if let Some(current_card_id) = current_card_id {
         current_card_id
     } else {
-        match boards.get_board_with_id(current_board_id) {
-            Some(current_board) => {
-                if current_board.cards.is_empty() {
+        match &current_board_cards {
+            Some(current_board_cards) => {
+                if current_board_cards.is_empty() {
                     debug!("Cannot go down: current board has no cards");
                     app.send_error_toast("Cannot go down: Current board has no cards", None);
                     return;
                 }
-                current_board.cards.get_first_card_id().unwrap()
+                current_board_cards.get_first_card_id().unwrap()
             }
             None => {
                 debug!("Cannot go down: current board not found, trying to get the first board");
@@ -366,56 +513,32 @@ pub fn go_down(app: &mut App) {
         .iter()
         .position(|card_id| *card_id == current_card_id)
     {
-        if current_card_index == app.config.no_of_cards_to_show as usize - 1 {
-            if let Some(current_card_index_in_all_cards) = boards
-                .get_board_with_id(current_board_id)
+        if current_card_index == current_visible_boards[&current_board_id].len() - 1 {
+            if let Some(current_card_index_in_all_cards) = current_board_cards
+                .as_ref()
                 .unwrap()
-                .cards
                 .get_card_index(current_card_id)
             {
-                if let Some(board) = boards.get_board_with_id(current_board_id) {
-                    if let Some(next_card) = board
-                        .cards
-                        .get_card_with_index(current_card_index_in_all_cards + 1)
+                if let Some(board_cards) = &current_board_cards {
+                    if let Some(next_card) =
+                        board_cards.get_card_with_index(current_card_index_in_all_cards + 1)
                     {
                         let next_card_id = next_card.id;
-                        let start_index = current_card_index_in_all_cards + 1;
-                        let end_index = current_card_index_in_all_cards
-                            + 1
-                            + app.config.no_of_cards_to_show as usize;
-                        let end_index = if end_index > board.cards.len() {
-                            board.cards.len()
-                        } else {
-                            end_index
-                        };
-                        let next_card_ids = board
-                            .cards
-                            .get_cards_with_range(start_index, end_index)
-                            .get_all_card_ids();
-                        let next_card_ids =
-                            if next_card_ids.len() < app.config.no_of_cards_to_show as usize {
-                                let mut next_card_ids = next_card_ids;
-                                let mut start_index = start_index;
-                                while next_card_ids.len() < app.config.no_of_cards_to_show as usize
-                                    && start_index > 0
-                                {
-                                    start_index -= 1;
-                                    next_card_ids.insert(
-                                        0,
-                                        board.cards.get_card_with_index(start_index).unwrap().id,
-                                    );
-                                }
-                                next_card_ids
-                            } else {
-                                next_card_ids
-                            };
+                        let next_card_ids = shift_window_forward(
+                            &board_cards.get_all_card_ids(),
+                            &current_visible_boards[&current_board_id],
+                            app.get_no_of_cards_to_show().into(),
+                        );
                         app.visible_boards_and_cards
                             .entry(current_board_id)
                             .and_modify(|cards| *cards = next_card_ids);
                         app.state.current_card_id = Some(next_card_id);
+                    } else if app.config.wrap_around_navigation {
+                        let card_ids = board_cards.get_all_card_ids();
+                        wrap_to_card(app, current_board_id, &card_ids, card_ids[0]);
                     } else {
                         debug!("Cannot go down: Already at the last card");
-                        app.send_error_toast("Cannot go down: Already at the last card", None);
+                        app.send_info_toast("Cannot go down: Already at the last card", None);
                     }
                 }
             } else {
@@ -559,6 +682,9 @@ pub async fn handle_user_input_mode(app: &mut App<'_>, key: Key) -> AppReturn {
                         app.close_popup();
                     }
                 }
+                PopUp::CardHighlightColorSelector | PopUp::BoardAccentColorSelector => {
+                    app.close_popup();
+                }
                 PopUp::CardStatusSelector => {
                     if app.state.card_being_edited.is_some() {
                         app.set_popup(PopUp::ConfirmDiscardCardChanges);
@@ -566,15 +692,31 @@ pub async fn handle_user_input_mode(app: &mut App<'_>, key: Key) -> AppReturn {
                         app.close_popup();
                     }
                 }
-                PopUp::CustomHexColorPromptBG | PopUp::CustomHexColorPromptFG => {
+                PopUp::CustomHexColorPromptBG
+                | PopUp::CustomHexColorPromptFG
+                | PopUp::CustomDateRangePrompt
+                | PopUp::ImportTrelloPrompt
+                | PopUp::ImportJsonPrompt
+                | PopUp::ImportBoardJsonPrompt
+                | PopUp::ImportGithubPrompt => {
+                    app.close_popup();
+                }
+                PopUp::SearchCards => {
+                    clear_card_search(app);
                     app.close_popup();
                 }
+                PopUp::FindReplace => {
+                    app.close_popup();
+                    return AppReturn::Continue;
+                }
                 _ => {}
             }
         }
         app.state.app_status = AppStatus::Initialized;
         app.state.path_check_state = PathCheckState::default();
         info!("Exiting user input mode");
+    } else if key == Key::CtrlEnter && is_rapid_entry_submit_allowed(app) {
+        handle_rapid_entry_submit(app);
     } else if app.config.keybindings.toggle_command_palette.contains(&key) {
         app.widgets.command_palette.already_in_user_input_mode = true;
         app.widgets.command_palette.last_focus = Some(app.state.focus);
@@ -627,6 +769,9 @@ pub async fn handle_user_input_mode(app: &mut App<'_>, key: Key) -> AppReturn {
                     }
                     return AppReturn::Continue;
                 }
+                Key::Ctrl('b') if app.state.focus == Focus::CommandPaletteCommand => {
+                    return CommandPaletteWidget::edit_keybinding_for_selected_command(app);
+                }
                 Key::Enter => {
                     match app.state.focus {
                         Focus::CommandPaletteCommand => {
@@ -672,6 +817,27 @@ pub async fn handle_user_input_mode(app: &mut App<'_>, key: Key) -> AppReturn {
             };
         }
 
+        if app.state.z_stack.last() == Some(&PopUp::FindReplace) {
+            if app.config.keybindings.next_focus.contains(&key) {
+                handle_next_focus(app);
+            } else if app.config.keybindings.prv_focus.contains(&key) {
+                handle_prv_focus(app);
+            } else if app.config.keybindings.accept.contains(&key) {
+                return handle_find_replace(app);
+            } else {
+                match app.state.focus {
+                    Focus::FindReplaceQuery => {
+                        app.state.text_buffers.find_replace_query.input(key);
+                    }
+                    Focus::FindReplaceReplacement => {
+                        app.state.text_buffers.find_replace_replacement.input(key);
+                    }
+                    _ => {}
+                }
+            }
+            return AppReturn::Continue;
+        }
+
         // Handle user input for anything other than the command palette
         match app.state.focus {
             Focus::NewBoardName => {
@@ -686,6 +852,9 @@ pub async fn handle_user_input_mode(app: &mut App<'_>, key: Key) -> AppReturn {
             Focus::NewBoardDescription => {
                 app.state.text_buffers.board_description.input(key);
             }
+            Focus::BoardNotes => {
+                app.state.text_buffers.board_notes.input(key);
+            }
             Focus::CardName => {
                 if app.config.keybindings.next_focus.contains(&key) {
                     handle_next_focus(app);
@@ -696,15 +865,54 @@ pub async fn handle_user_input_mode(app: &mut App<'_>, key: Key) -> AppReturn {
                 }
             }
             Focus::CardDescription => {
-                app.state.text_buffers.card_description.input(key);
+                // Ctrl+H would otherwise delete a char like backspace does
+                if key == Key::Ctrl('h') {
+                    app.set_popup(PopUp::FindReplace);
+                } else {
+                    app.state.text_buffers.card_description.input(key);
+                }
+            }
+            Focus::CardEstimate => {
+                if app.config.keybindings.next_focus.contains(&key) {
+                    handle_next_focus(app);
+                } else if app.config.keybindings.prv_focus.contains(&key) {
+                    handle_prv_focus(app);
+                } else {
+                    app.state.text_buffers.card_estimate.input(key);
+                    sync_card_estimate_from_text_buffer(app);
+                }
+            }
+            Focus::CardEstimatedTime | Focus::CardActualTime => {
+                if app.config.keybindings.next_focus.contains(&key) {
+                    handle_next_focus(app);
+                } else if app.config.keybindings.prv_focus.contains(&key) {
+                    handle_prv_focus(app);
+                } else {
+                    if app.state.focus == Focus::CardEstimatedTime {
+                        app.state.text_buffers.card_estimated_time.input(key);
+                    } else {
+                        app.state.text_buffers.card_actual_time.input(key);
+                    }
+                    sync_card_minutes_from_text_buffer(app);
+                }
+            }
+            Focus::CardWaitingOn => {
+                if app.config.keybindings.next_focus.contains(&key) {
+                    handle_next_focus(app);
+                } else if app.config.keybindings.prv_focus.contains(&key) {
+                    handle_prv_focus(app);
+                } else {
+                    app.state.text_buffers.card_waiting_on.input(key);
+                    sync_card_waiting_on_from_text_buffer(app);
+                }
             }
-            Focus::CardDueDate => {
+            Focus::CardStartDate | Focus::CardDueDate => {
                 if app.state.card_being_edited.is_none()
                     && app.state.z_stack.last() == Some(&PopUp::ViewCard)
                 {
                     handle_edit_new_card(app);
                 }
-                app.set_popup(PopUp::DateTimePicker);
+                open_card_date_picker(app);
             }
             Focus::CardPriority => {
                 if app.config.keybindings.next_focus.contains(&key) {
@@ -724,6 +932,17 @@ pub async fn handle_user_input_mode(app: &mut App<'_>, key: Key) -> AppReturn {
                     app.set_popup(PopUp::CardStatusSelector);
                 }
             }
+            Focus::CardColor => {
+                if app.config.keybindings.next_focus.contains(&key) {
+                    handle_next_focus(app);
+                } else if app.config.keybindings.prv_focus.contains(&key) {
+                    handle_prv_focus(app);
+                } else if key == Key::Enter {
+                    open_card_highlight_color_selector(app);
+                } else if key == Key::Right || key == Key::Left {
+                    cycle_card_color(app, key == Key::Right);
+                }
+            }
             Focus::CardTags => {
                 if let Some((_, current_card)) = &mut app.state.card_being_edited {
                     match key {
@@ -1036,6 +1255,111 @@ pub async fn handle_user_input_mode(app: &mut App<'_>, key: Key) -> AppReturn {
                     return AppReturn::Continue;
                 }
             }
+            Focus::CardChecklist => {
+                if let Some((_, current_card)) = &mut app.state.card_being_edited {
+                    let current_selected = app
+                        .state
+                        .app_list_states
+                        .card_view_checklist
+                        .selected()
+                        .unwrap_or(0);
+                    match key {
+                        Key::ShiftRight => {
+                            if !current_card.checklist.is_empty() {
+                                let max = current_card.checklist.len();
+                                if current_selected < max - 1 {
+                                    app.state
+                                        .app_list_states
+                                        .card_view_checklist
+                                        .select(Some(current_selected + 1));
+                                }
+                            }
+                        }
+                        Key::ShiftLeft => {
+                            if !current_card.checklist.is_empty() {
+                                app.state
+                                    .app_list_states
+                                    .card_view_checklist
+                                    .select(Some(current_selected.saturating_sub(1)));
+                            }
+                        }
+                        Key::Enter => {
+                            let insert_index = app
+                                .state
+                                .app_list_states
+                                .card_view_checklist
+                                .selected()
+                                .map_or(current_card.checklist.len(), |selected| selected + 1);
+                            current_card
+                                .checklist
+                                .insert(insert_index, ChecklistItem::new(""));
+                            app.state
+                                .text_buffers
+                                .prepare_checklist_for_card(current_card);
+                            app.state
+                                .app_list_states
+                                .card_view_checklist
+                                .select(Some(insert_index));
+                        }
+                        Key::Delete => {
+                            if current_card.checklist.is_empty() {
+                                app.send_error_toast(
+                                    &tr(MessageId::ToastNoChecklistItemsToDelete),
+                                    None,
+                                );
+                            } else if let Some(delete_index) =
+                                app.state.app_list_states.card_view_checklist.selected()
+                            {
+                                current_card.checklist.remove(delete_index);
+                                app.state
+                                    .text_buffers
+                                    .prepare_checklist_for_card(current_card);
+                                if delete_index != 0 {
+                                    app.state
+                                        .app_list_states
+                                        .card_view_checklist
+                                        .select(Some(delete_index - 1));
+                                } else if current_card.checklist.is_empty() {
+                                    app.state.app_list_states.card_view_checklist.select(None);
+                                }
+                            }
+                        }
+                        _ if app.config.keybindings.next_focus.contains(&key) => {
+                            handle_next_focus(app)
+                        }
+                        _ if app.config.keybindings.prv_focus.contains(&key) => {
+                            handle_prv_focus(app)
+                        }
+                        _ => {
+                            if let Some(selected_item_index) =
+                                app.state.app_list_states.card_view_checklist.selected()
+                            {
+                                if let Some(current_item_text_box) = app
+                                    .state
+                                    .text_buffers
+                                    .card_checklist
+                                    .get_mut(selected_item_index)
+                                {
+                                    current_item_text_box.input(key);
+                                    current_card.checklist[selected_item_index].text =
+                                        current_item_text_box.get_joined_lines();
+                                }
+                            } else {
+                                app.send_warning_toast(
+                                    &format!(
+                                        "No checklist item selected to edit, use {} or {}",
+                                        Key::ShiftRight,
+                                        Key::ShiftLeft
+                                    ),
+                                    None,
+                                );
+                            }
+                        }
+                    }
+                } else {
+                    return AppReturn::Continue;
+                }
+            }
             Focus::EmailIDField => {
                 if app.config.keybindings.next_focus.contains(&key) {
                     handle_next_focus(app);
@@ -1151,18 +1475,26 @@ pub async fn handle_user_input_mode(app: &mut App<'_>, key: Key) -> AppReturn {
                         } else {
                             debug!("Dont know what to do with Submit button in user input mode for popup: {:?}", app.state.z_stack.last());
                         }
-                        match app.state.current_view {
-                            View::NewCard => {
-                                handle_new_card_action(app);
+                        let rapid_entry = app.config.rapid_card_entry;
+                        let stayed_in_form = match app.state.current_view {
+                            View::NewCard => handle_new_card_action(app, rapid_entry, false),
+                            View::NewBoard => handle_new_board_action(app, rapid_entry),
+                            View::EditBoard => {
+                                handle_edit_board_action(app);
+                                false
                             }
-                            View::NewBoard => {
-                                handle_new_board_action(app);
+                            View::EditBoardNotes => {
+                                handle_edit_board_notes_action(app);
+                                false
                             }
                             _ => {
                                 debug!("Dont know what to do with Submit button in user input mode for view: {:?}", app.state.current_view);
+                                false
                             }
+                        };
+                        if !stayed_in_form {
+                            app.state.app_status = AppStatus::Initialized;
                         }
-                        app.state.app_status = AppStatus::Initialized;
                     }
                     _ if app.config.keybindings.next_focus.contains(&key) => handle_next_focus(app),
                     _ if app.config.keybindings.prv_focus.contains(&key) => handle_prv_focus(app),
@@ -1171,6 +1503,19 @@ pub async fn handle_user_input_mode(app: &mut App<'_>, key: Key) -> AppReturn {
                     }
                 }
             }
+            Focus::UseTemplate | Focus::SaveCardAsTemplate => match key {
+                Key::Enter => {
+                    app.state.app_status = AppStatus::Initialized;
+                    if app.state.focus == Focus::UseTemplate {
+                        open_card_template_selector(app);
+                    } else {
+                        handle_save_card_as_template(app);
+                    }
+                }
+                _ if app.config.keybindings.next_focus.contains(&key) => handle_next_focus(app),
+                _ if app.config.keybindings.prv_focus.contains(&key) => handle_prv_focus(app),
+                _ => {}
+            },
             Focus::ChangeCardStatusPopup => match key {
                 Key::Up => app.select_card_status_prv(),
                 Key::Down => app.select_card_status_next(),
@@ -1187,6 +1532,22 @@ pub async fn handle_user_input_mode(app: &mut App<'_>, key: Key) -> AppReturn {
                 }
                 _ => {}
             },
+            Focus::ChangeCardHighlightColorPopup => match key {
+                Key::Up => app.select_card_highlight_color_prv(),
+                Key::Down => app.select_card_highlight_color_next(),
+                Key::Enter => {
+                    handle_change_card_highlight_color(app);
+                }
+                _ => {}
+            },
+            Focus::ChangeBoardAccentColorPopup => match key {
+                Key::Up => app.select_board_accent_color_prv(),
+                Key::Down => app.select_board_accent_color_next(),
+                Key::Enter => {
+                    handle_change_board_accent_color(app);
+                }
+                _ => {}
+            },
             Focus::TextInput => {
                 let accept_keys = &app.config.keybindings.accept;
                 if accept_keys.contains(&key) {
@@ -1197,6 +1558,16 @@ pub async fn handle_user_input_mode(app: &mut App<'_>, key: Key) -> AppReturn {
                         Some(PopUp::CustomHexColorPromptBG) => {
                             return handle_custom_hex_color_prompt(app, false)
                         }
+                        Some(PopUp::CustomDateRangePrompt) => {
+                            return handle_custom_date_range_prompt(app)
+                        }
+                        Some(
+                            PopUp::ImportTrelloPrompt
+                            | PopUp::ImportJsonPrompt
+                            | PopUp::ImportBoardJsonPrompt
+                            | PopUp::ImportGithubPrompt,
+                        ) => return handle_import_prompt(app).await,
+                        Some(PopUp::SearchCards) => return handle_search_cards_accept(app),
                         _ => {
                             debug!(
                                 "TextInput is not used in the current popup: {:?}",
@@ -1212,16 +1583,40 @@ pub async fn handle_user_input_mode(app: &mut App<'_>, key: Key) -> AppReturn {
                         Some(PopUp::CustomHexColorPromptBG) => {
                             app.state.text_buffers.theme_editor_bg_hex.input(key);
                         }
-                        _ => {
-                            debug!(
-                                "No user input handler found for focus: {:?}",
-                                app.state.focus
-                            )
+                        Some(PopUp::CustomDateRangePrompt) => {
+                            app.state.text_buffers.custom_date_range.input(key);
                         }
-                    }
-                }
-            }
-            Focus::DTPCalender
+                        Some(
+                            PopUp::ImportTrelloPrompt
+                            | PopUp::ImportJsonPrompt
+                            | PopUp::ImportBoardJsonPrompt
+                            | PopUp::ImportGithubPrompt,
+                        ) => match (key, app.state.path_check_state.potential_completion.clone()) {
+                            (Key::Right | Key::Tab, Some(potential_completion)) => {
+                                app.state
+                                    .text_buffers
+                                    .import_path
+                                    .insert_str(potential_completion);
+                            }
+                            _ => {
+                                app.state.text_buffers.import_path.input(key);
+                            }
+                        },
+                        Some(PopUp::SearchCards) => {
+                            if app.state.text_buffers.card_search.input(key) {
+                                apply_card_search(app);
+                            }
+                        }
+                        _ => {
+                            debug!(
+                                "No user input handler found for focus: {:?}",
+                                app.state.focus
+                            )
+                        }
+                    }
+                }
+            }
+            Focus::DTPCalender
             | Focus::DTPMonth
             | Focus::DTPYear
             | Focus::DTPToggleTimePicker
@@ -1250,6 +1645,180 @@ pub async fn handle_user_input_mode(app: &mut App<'_>, key: Key) -> AppReturn {
     AppReturn::Continue
 }
 
+// Kept on the edited card while valid so the discard prompt can list it
+fn sync_card_estimate_from_text_buffer(app: &mut App) {
+    let estimate = parse_estimate(&app.state.text_buffers.card_estimate.get_joined_lines());
+    if let (Some((_, card_being_edited)), Ok(estimate)) =
+        (&mut app.state.card_being_edited, estimate)
+    {
+        card_being_edited.estimate = estimate;
+    }
+}
+
+fn sync_card_minutes_from_text_buffer(app: &mut App) {
+    let is_estimated_time = app.state.focus == Focus::CardEstimatedTime;
+    let text_buffer = if is_estimated_time {
+        &app.state.text_buffers.card_estimated_time
+    } else {
+        &app.state.text_buffers.card_actual_time
+    };
+    let minutes = parse_minutes(&text_buffer.get_joined_lines());
+    if let (Some((_, card_being_edited)), Ok(minutes)) = (&mut app.state.card_being_edited, minutes)
+    {
+        if is_estimated_time {
+            card_being_edited.estimated_minutes = minutes;
+        } else {
+            card_being_edited.actual_minutes = minutes;
+        }
+    }
+}
+
+fn sync_card_waiting_on_from_text_buffer(app: &mut App) {
+    let waiting_on = parse_waiting_on(&app.state.text_buffers.card_waiting_on.get_joined_lines());
+    if let Some((_, card_being_edited)) = &mut app.state.card_being_edited {
+        card_being_edited.waiting_on = waiting_on;
+    }
+}
+
+/// Puts text pasted in the terminal into the focused text field in one insert, so its line
+/// breaks are never read as Enter presses. Outside of user input there is no text field to
+/// paste into and the paste is dropped
+pub fn handle_paste(app: &mut App, text: &str) {
+    if app.state.app_status != AppStatus::UserInput {
+        debug!(
+            "Ignoring paste of {} bytes, no text field is focused",
+            text.len()
+        );
+        return;
+    }
+    let text_buffers = &mut app.state.text_buffers;
+    match app.state.focus {
+        Focus::NewBoardName => {
+            text_buffers.board_name.paste_external(text);
+        }
+        Focus::NewBoardDescription => {
+            text_buffers.board_description.paste_external(text);
+        }
+        Focus::BoardNotes => {
+            text_buffers.board_notes.paste_external(text);
+        }
+        Focus::CardName => {
+            text_buffers.card_name.paste_external(text);
+        }
+        Focus::CardDescription => {
+            text_buffers.card_description.paste_external(text);
+        }
+        Focus::CardEstimate => {
+            text_buffers.card_estimate.paste_external(text);
+            sync_card_estimate_from_text_buffer(app);
+        }
+        Focus::CardEstimatedTime => {
+            text_buffers.card_estimated_time.paste_external(text);
+            sync_card_minutes_from_text_buffer(app);
+        }
+        Focus::CardActualTime => {
+            text_buffers.card_actual_time.paste_external(text);
+            sync_card_minutes_from_text_buffer(app);
+        }
+        Focus::CardWaitingOn => {
+            text_buffers.card_waiting_on.paste_external(text);
+            sync_card_waiting_on_from_text_buffer(app);
+        }
+        Focus::CardTags | Focus::CardComments | Focus::CardChecklist => {
+            paste_into_card_list_item(app, text);
+        }
+        Focus::EmailIDField => {
+            text_buffers.email_id.paste_external(text);
+        }
+        Focus::PasswordField => {
+            text_buffers.password.paste_external(text);
+        }
+        Focus::ConfirmPasswordField => {
+            text_buffers.confirm_password.paste_external(text);
+        }
+        Focus::ResetPasswordLinkField => {
+            text_buffers.reset_password_link.paste_external(text);
+        }
+        Focus::CommandPaletteCommand | Focus::CommandPaletteBoard | Focus::CommandPaletteCard => {
+            text_buffers.command_palette.paste_external(text);
+        }
+        Focus::EditGeneralConfigPopup => {
+            text_buffers.general_config.paste_external(text);
+        }
+        Focus::FindReplaceQuery => {
+            text_buffers.find_replace_query.paste_external(text);
+        }
+        Focus::FindReplaceReplacement => {
+            text_buffers.find_replace_replacement.paste_external(text);
+        }
+        Focus::TextInput => match app.state.z_stack.last() {
+            Some(PopUp::CustomHexColorPromptFG) => {
+                text_buffers.theme_editor_fg_hex.paste_external(text);
+            }
+            Some(PopUp::CustomHexColorPromptBG) => {
+                text_buffers.theme_editor_bg_hex.paste_external(text);
+            }
+            Some(PopUp::CustomDateRangePrompt) => {
+                text_buffers.custom_date_range.paste_external(text);
+            }
+            Some(
+                PopUp::ImportTrelloPrompt
+                | PopUp::ImportJsonPrompt
+                | PopUp::ImportBoardJsonPrompt
+                | PopUp::ImportGithubPrompt,
+            ) => {
+                text_buffers.import_path.paste_external(text);
+            }
+            Some(PopUp::SearchCards) => {
+                if text_buffers.card_search.paste_external(text) {
+                    apply_card_search(app);
+                }
+            }
+            popup => debug!("Ignoring paste, TextInput is not used in {:?}", popup),
+        },
+        focus => debug!("Ignoring paste, {:?} is not a text field", focus),
+    }
+}
+
+/// Tags, comments and checklist items are edited one at a time, the selected one gets the paste
+fn paste_into_card_list_item(app: &mut App, text: &str) {
+    let Some((_, current_card)) = &mut app.state.card_being_edited else {
+        return;
+    };
+    let text_buffers = &mut app.state.text_buffers;
+    let list_states = &app.state.app_list_states;
+    match app.state.focus {
+        Focus::CardTags => {
+            let Some(index) = list_states.card_view_tag_list.selected() else {
+                return;
+            };
+            if let Some(text_box) = text_buffers.card_tags.get_mut(index) {
+                text_box.paste_external(text);
+                current_card.tags[index] = text_box.get_joined_lines();
+            }
+        }
+        Focus::CardComments => {
+            let Some(index) = list_states.card_view_comment_list.selected() else {
+                return;
+            };
+            if let Some(text_box) = text_buffers.card_comments.get_mut(index) {
+                text_box.paste_external(text);
+                current_card.comments[index] = text_box.get_joined_lines();
+            }
+        }
+        Focus::CardChecklist => {
+            let Some(index) = list_states.card_view_checklist.selected() else {
+                return;
+            };
+            if let Some(text_box) = text_buffers.card_checklist.get_mut(index) {
+                text_box.paste_external(text);
+                current_card.checklist[index].text = text_box.get_joined_lines();
+            }
+        }
+        _ => {}
+    }
+}
+
 pub async fn handle_edit_keybinding_mode(app: &mut App<'_>, key: Key) -> AppReturn {
     // Handle escape key or other keys that should stop user input
     if matches!(key, Key::Esc) {
@@ -1272,8 +1841,70 @@ pub async fn handle_edit_keybinding_mode(app: &mut App<'_>, key: Key) -> AppRetu
     AppReturn::Continue
 }
 
+/// Undo and redo counts are typed on the board, not in popups or other views
+fn is_history_step_count_allowed(app: &App) -> bool {
+    app.state.z_stack.is_empty()
+        && app.state.focus == Focus::Body
+        && View::views_with_kanban_board().contains(&app.state.current_view)
+}
+
+/// The digit when the key adds to the undo and redo count. Once a count was started with the
+/// start_history_step_count key every digit adds to it, otherwise a digit bound to an action keeps
+/// doing that action, so with the default keys only 0, 7, 8 and 9 start a count on their own
+fn history_step_count_digit(
+    app: &App,
+    key: Key,
+    history_step_count: Option<usize>,
+) -> Option<usize> {
+    let Key::Char(character) = key else {
+        return None;
+    };
+    let digit = character.to_digit(10)? as usize;
+    let starts_or_extends_count =
+        history_step_count.is_some() || app.config.keybindings.key_to_action(&key).is_none();
+    (is_history_step_count_allowed(app) && starts_or_extends_count).then_some(digit)
+}
+
 pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
+    if key == Key::CtrlEnter && is_rapid_entry_submit_allowed(app) {
+        handle_rapid_entry_submit(app);
+        return AppReturn::Continue;
+    }
+    if is_config_value_step_allowed(app, key) {
+        handle_config_value_step(app, key == Key::Char('+'));
+        return AppReturn::Continue;
+    }
+    if key == Key::Char(' ')
+        && app.state.z_stack.last() == Some(&PopUp::ViewCard)
+        && app.state.focus == Focus::CardChecklist
+    {
+        handle_toggle_checklist_item(app);
+        return AppReturn::Continue;
+    }
+    if key == Key::Char(' ') && app.state.z_stack.last() == Some(&PopUp::FilterByPriority) {
+        handle_toggle_priority_filter(app);
+        return AppReturn::Continue;
+    }
+    if key == Key::Char(' ') && app.state.z_stack.last() == Some(&PopUp::FilterByStatus) {
+        handle_toggle_status_filter(app);
+        return AppReturn::Continue;
+    }
+    // Any key other than a digit, undo or redo drops the count
+    let history_step_count = app.state.history_step_count.take();
+    if let Some(digit) = history_step_count_digit(app, key, history_step_count) {
+        app.state.history_step_count = Some(
+            history_step_count
+                .unwrap_or(0)
+                .saturating_mul(10)
+                .saturating_add(digit)
+                .min(MAX_HISTORY_STEP_COUNT),
+        );
+        return AppReturn::Continue;
+    }
     if let Some(action) = app.config.keybindings.key_to_action(&key) {
+        if !Action::card_range_actions().contains(&action) {
+            app.state.card_range_selection = None;
+        }
         match action {
             Action::Quit => handle_exit(app).await,
             Action::NextFocus => {
@@ -1292,12 +1923,26 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
                     }
                 }
                 app.widgets.toast_widget.toasts = vec![];
+                app.state.secondary_pane_height = DEFAULT_SECONDARY_PANE_HEIGHT;
+                if app.config.secondary_pane_height.is_some() {
+                    store_in_config_file(app, "help and log pane height", |config| {
+                        config.secondary_pane_height = None;
+                    });
+                }
                 app.set_view(app.config.default_view);
-                app.send_info_toast("UI reset, all toasts cleared", None);
+                app.send_info_toast(&tr(MessageId::ToastUiReset), None);
                 app.close_popup();
                 refresh_visible_boards_and_cards(app);
                 AppReturn::Continue
             }
+            Action::GrowSecondaryPane => {
+                resize_secondary_panes(app, true);
+                AppReturn::Continue
+            }
+            Action::ShrinkSecondaryPane => {
+                resize_secondary_panes(app, false);
+                AppReturn::Continue
+            }
             Action::OpenConfigMenu => {
                 if matches!(app.state.current_view, View::ConfigMenu) {
                     handle_go_to_previous_view(app).await;
@@ -1331,20 +1976,71 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
                         }
                         PopUp::ChangeDateFormatPopup => app.change_date_format_popup_prv(),
                         PopUp::FilterByTag => app.filter_by_tag_popup_prv(),
+                        PopUp::FilterByDateRange => app.filter_by_date_range_popup_prv(),
+                        PopUp::FilterByPriority => app.filter_by_priority_popup_prv(),
+                        PopUp::FilterByStatus => app.filter_by_status_popup_prv(),
                         PopUp::ViewCard => {
                             if app.state.focus == Focus::CardDescription {
                                 app.state.text_buffers.card_description.scroll((-1, 0));
+                            } else if app.state.focus == Focus::CardChecklist {
+                                app.card_view_checklist_prv();
                             }
                         }
                         PopUp::CardPrioritySelector => {
                             app.select_card_priority_prv();
                         }
+                        PopUp::CardHighlightColorSelector => {
+                            app.select_card_highlight_color_prv();
+                        }
+                        PopUp::BoardAccentColorSelector => {
+                            app.select_board_accent_color_prv();
+                        }
+                        PopUp::SortCardsBy => {
+                            app.sort_cards_by_popup_prv();
+                        }
+                        PopUp::MoveCardToBoard => {
+                            app.move_card_to_board_popup_prv();
+                        }
+                        PopUp::CalendarDayCards => {
+                            app.calendar_day_cards_popup_prv();
+                        }
+                        PopUp::WaitingOnList => {
+                            app.waiting_on_list_popup_prv();
+                        }
+                        PopUp::SelectCardTemplate => {
+                            app.card_templates_popup_prv();
+                        }
+                        PopUp::SetLogLevel => {
+                            app.log_level_popup_prv();
+                        }
                         PopUp::DateTimePicker => {
                             handle_date_time_picker_action(app, None, Some(action));
                         }
                         PopUp::TagPicker => {
                             app.tag_picker_prv();
                         }
+                        PopUp::MirrorSyncSummary
+                            if app.state.focus == Focus::MirrorSyncConflicts =>
+                        {
+                            app.mirror_sync_conflict_prv();
+                        }
+                        PopUp::Notifications if app.state.focus == Focus::NotificationsList => {
+                            app.notification_prv();
+                        }
+                        PopUp::WhatsNew => {
+                            app.state.whats_new_scroll =
+                                app.state.whats_new_scroll.saturating_sub(1);
+                        }
+                        PopUp::GithubImportSummary => {
+                            app.state.github_import_report_scroll =
+                                app.state.github_import_report_scroll.saturating_sub(1);
+                        }
+                        PopUp::ConflictMarkerWarning => {
+                            app.conflict_marker_card_prv();
+                        }
+                        PopUp::DuplicateBoards => {
+                            app.duplicate_board_pair_prv();
+                        }
                         _ => {}
                     }
                     return AppReturn::Continue;
@@ -1380,6 +2076,9 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
                         app.load_save_prv(false);
                         app.dispatch(IoEvent::LoadLocalPreview).await;
                     }
+                    View::ArchivedCards => app.archived_cards_prv(),
+                    View::Calendar => shift_calendar_date_by_days(app, -1),
+                    View::Swimlane => swimlane_go_vertical(app, false),
                     View::LoadCloudSave => {
                         app.load_save_prv(true);
                         app.dispatch(IoEvent::LoadCloudPreview).await;
@@ -1398,11 +2097,16 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
                                 next_focus_key, prev_focus_key), None);
                         }
                     }
-                    View::NewBoard => {
+                    View::NewBoard | View::EditBoard => {
                         if app.state.focus == Focus::NewBoardDescription {
                             app.state.text_buffers.board_description.scroll((-1, 0))
                         }
                     }
+                    View::EditBoardNotes => {
+                        if app.state.focus == Focus::BoardNotes {
+                            app.state.text_buffers.board_notes.scroll((-1, 0))
+                        }
+                    }
                     View::NewCard => {
                         if app.state.focus == Focus::CardDescription {
                             app.state.text_buffers.card_description.scroll((-1, 0))
@@ -1441,9 +2145,14 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
                         }
                         PopUp::ChangeDateFormatPopup => app.change_date_format_popup_next(),
                         PopUp::FilterByTag => app.filter_by_tag_popup_next(),
+                        PopUp::FilterByDateRange => app.filter_by_date_range_popup_next(),
+                        PopUp::FilterByPriority => app.filter_by_priority_popup_next(),
+                        PopUp::FilterByStatus => app.filter_by_status_popup_next(),
                         PopUp::ViewCard => {
                             if app.state.focus == Focus::CardDescription {
                                 app.state.text_buffers.card_description.scroll((1, 0))
+                            } else if app.state.focus == Focus::CardChecklist {
+                                app.card_view_checklist_next();
                             }
                         }
                         PopUp::SaveThemePrompt => {
@@ -1452,12 +2161,60 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
                         PopUp::CardPrioritySelector => {
                             app.select_card_priority_next();
                         }
+                        PopUp::CardHighlightColorSelector => {
+                            app.select_card_highlight_color_next();
+                        }
+                        PopUp::BoardAccentColorSelector => {
+                            app.select_board_accent_color_next();
+                        }
+                        PopUp::SortCardsBy => {
+                            app.sort_cards_by_popup_next();
+                        }
+                        PopUp::MoveCardToBoard => {
+                            app.move_card_to_board_popup_next();
+                        }
+                        PopUp::CalendarDayCards => {
+                            app.calendar_day_cards_popup_next();
+                        }
+                        PopUp::WaitingOnList => {
+                            app.waiting_on_list_popup_next();
+                        }
+                        PopUp::SelectCardTemplate => {
+                            app.card_templates_popup_next();
+                        }
+                        PopUp::SetLogLevel => {
+                            app.log_level_popup_next();
+                        }
                         PopUp::DateTimePicker => {
                             handle_date_time_picker_action(app, None, Some(action));
                         }
                         PopUp::TagPicker => {
                             app.tag_picker_next();
                         }
+                        PopUp::MirrorSyncSummary
+                            if app.state.focus == Focus::MirrorSyncConflicts =>
+                        {
+                            app.mirror_sync_conflict_next();
+                        }
+                        PopUp::Notifications if app.state.focus == Focus::NotificationsList => {
+                            app.notification_next();
+                        }
+                        PopUp::WhatsNew => {
+                            // Clamped to the content when rendering
+                            app.state.whats_new_scroll =
+                                app.state.whats_new_scroll.saturating_add(1);
+                        }
+                        PopUp::GithubImportSummary => {
+                            // Clamped to the content when rendering
+                            app.state.github_import_report_scroll =
+                                app.state.github_import_report_scroll.saturating_add(1);
+                        }
+                        PopUp::ConflictMarkerWarning => {
+                            app.conflict_marker_card_next();
+                        }
+                        PopUp::DuplicateBoards => {
+                            app.duplicate_board_pair_next();
+                        }
                         _ => {}
                     }
                     return AppReturn::Continue;
@@ -1493,6 +2250,9 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
                         app.load_save_next(false);
                         app.dispatch(IoEvent::LoadLocalPreview).await;
                     }
+                    View::ArchivedCards => app.archived_cards_next(),
+                    View::Calendar => shift_calendar_date_by_days(app, 1),
+                    View::Swimlane => swimlane_go_vertical(app, true),
                     View::LoadCloudSave => {
                         app.load_save_next(true);
                         app.dispatch(IoEvent::LoadCloudPreview).await;
@@ -1511,11 +2271,16 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
                                 next_focus_key, prev_focus_key), None);
                         }
                     }
-                    View::NewBoard => {
+                    View::NewBoard | View::EditBoard => {
                         if app.state.focus == Focus::NewBoardDescription {
                             app.state.text_buffers.board_description.scroll((1, 0))
                         }
                     }
+                    View::EditBoardNotes => {
+                        if app.state.focus == Focus::BoardNotes {
+                            app.state.text_buffers.board_notes.scroll((1, 0))
+                        }
+                    }
                     View::NewCard => {
                         if app.state.focus == Focus::CardDescription {
                             app.state.text_buffers.card_description.scroll((1, 0))
@@ -1537,18 +2302,47 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
                 reset_mouse(app);
                 if let Some(popup) = app.state.z_stack.last() {
                     match popup {
-                        PopUp::ConfirmDiscardCardChanges => {
+                        PopUp::ConfirmDiscardCardChanges
+                        | PopUp::ConfirmKeepDraft
+                        | PopUp::ConfirmClearWaitingOn
+                        | PopUp::ConfirmRestoreThemesFromBackup
+                        | PopUp::RecoverSaveFile
+                        | PopUp::ImportOptions => {
                             toggle_focus_between_submit_and_extra(app);
                         }
+                        PopUp::MirrorSyncSummary => {
+                            if app.state.focus == Focus::MirrorSyncConflicts {
+                                toggle_selected_mirror_conflict_resolution(app);
+                            } else {
+                                toggle_focus_between_submit_and_extra(app);
+                            }
+                        }
+                        PopUp::ConfirmDuplicateCardName
+                        | PopUp::DuplicateBoards
+                        | PopUp::ConfirmQuit => {
+                            let available_targets = popup.get_available_targets();
+                            app.state
+                                .set_focus(app.state.focus.next(&available_targets));
+                        }
                         PopUp::DateTimePicker => {
                             handle_date_time_picker_action(app, None, Some(action));
                         }
+                        PopUp::ViewCard if app.state.focus == Focus::CardColor => {
+                            cycle_card_color(app, true);
+                        }
                         _ => {}
                     }
+                } else if app.state.focus == Focus::Body && app.state.current_view == View::Swimlane
+                {
+                    swimlane_go_horizontal(app, true);
                 } else if app.state.focus == Focus::Body
                     && View::views_with_kanban_board().contains(&app.state.current_view)
                 {
                     go_right(app);
+                } else if app.state.current_view == View::Stats {
+                    app.state.stats_metric = app.state.stats_metric.next();
+                } else if app.state.current_view == View::Calendar {
+                    shift_calendar_date_by_months(app, true);
                 }
                 AppReturn::Continue
             }
@@ -1556,24 +2350,53 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
                 reset_mouse(app);
                 if let Some(popup) = app.state.z_stack.last() {
                     match popup {
-                        PopUp::ConfirmDiscardCardChanges => {
+                        PopUp::ConfirmDiscardCardChanges
+                        | PopUp::ConfirmKeepDraft
+                        | PopUp::ConfirmClearWaitingOn
+                        | PopUp::ConfirmRestoreThemesFromBackup
+                        | PopUp::RecoverSaveFile
+                        | PopUp::ImportOptions => {
                             toggle_focus_between_submit_and_extra(app);
                         }
+                        PopUp::MirrorSyncSummary => {
+                            if app.state.focus == Focus::MirrorSyncConflicts {
+                                toggle_selected_mirror_conflict_resolution(app);
+                            } else {
+                                toggle_focus_between_submit_and_extra(app);
+                            }
+                        }
+                        PopUp::ConfirmDuplicateCardName
+                        | PopUp::DuplicateBoards
+                        | PopUp::ConfirmQuit => {
+                            let available_targets = popup.get_available_targets();
+                            app.state
+                                .set_focus(app.state.focus.prev(&available_targets));
+                        }
                         PopUp::DateTimePicker => {
                             handle_date_time_picker_action(app, None, Some(action));
                         }
+                        PopUp::ViewCard if app.state.focus == Focus::CardColor => {
+                            cycle_card_color(app, false);
+                        }
                         _ => {}
                     }
+                } else if app.state.focus == Focus::Body && app.state.current_view == View::Swimlane
+                {
+                    swimlane_go_horizontal(app, false);
                 } else if app.state.focus == Focus::Body
                     && View::views_with_kanban_board().contains(&app.state.current_view)
                 {
                     go_left(app);
+                } else if app.state.current_view == View::Stats {
+                    app.state.stats_metric = app.state.stats_metric.prev();
+                } else if app.state.current_view == View::Calendar {
+                    shift_calendar_date_by_months(app, false);
                 }
                 AppReturn::Continue
             }
             Action::TakeUserInput => {
                 match app.state.current_view {
-                    View::NewBoard | View::NewCard => {
+                    View::NewBoard | View::EditBoard | View::EditBoardNotes | View::NewCard => {
                         app.state.app_status = AppStatus::UserInput;
                         info!("Taking user input");
                     }
@@ -1582,7 +2405,14 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
                             match popup {
                                 PopUp::EditGeneralConfig
                                 | PopUp::CustomHexColorPromptFG
-                                | PopUp::CustomHexColorPromptBG => {
+                                | PopUp::CustomHexColorPromptBG
+                                | PopUp::CustomDateRangePrompt
+                                | PopUp::ImportTrelloPrompt
+                                | PopUp::ImportJsonPrompt
+                                | PopUp::ImportBoardJsonPrompt
+                                | PopUp::ImportGithubPrompt
+                                | PopUp::SearchCards
+                                | PopUp::FindReplace => {
                                     app.state.app_status = AppStatus::UserInput;
                                     info!("Taking user input");
                                 }
@@ -1622,7 +2452,12 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
                                 handle_edit_general_config(app);
                             }
                         }
-                        PopUp::EditSpecificKeyBinding => handle_edit_specific_keybinding(app),
+                        PopUp::EditSpecificKeyBinding => {
+                            handle_edit_specific_keybinding(app);
+                            if return_to_command_palette_after_keybinding_edit(app) {
+                                return AppReturn::Continue;
+                            }
+                        }
                         PopUp::SelectDefaultView => handle_default_view_selection(app),
                         PopUp::ChangeDateFormatPopup => handle_change_date_format(app),
                         PopUp::ChangeTheme => {
@@ -1636,6 +2471,15 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
                         PopUp::CustomHexColorPromptBG => {
                             return handle_custom_hex_color_prompt(app, false)
                         }
+                        PopUp::CustomDateRangePrompt => {
+                            return handle_custom_date_range_prompt(app)
+                        }
+                        PopUp::ImportTrelloPrompt
+                        | PopUp::ImportJsonPrompt
+                        | PopUp::ImportBoardJsonPrompt
+                        | PopUp::ImportGithubPrompt => return handle_import_prompt(app).await,
+                        PopUp::SearchCards => return handle_search_cards_accept(app),
+                        PopUp::FindReplace => return handle_find_replace(app),
                         PopUp::ViewCard => return handle_general_actions_view_card(app),
                         PopUp::CommandPalette => {
                             unreachable!("Command palette should not be handled here");
@@ -1650,13 +2494,99 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
                             }
                             _ => {}
                         },
+                        PopUp::ConfirmDuplicateCardName => {
+                            handle_duplicate_card_name_prompt(app);
+                            return AppReturn::Continue;
+                        }
+                        PopUp::ConfirmKeepDraft => {
+                            handle_keep_draft_prompt(app);
+                            return AppReturn::Continue;
+                        }
+                        PopUp::ConfirmClearWaitingOn => {
+                            handle_clear_waiting_on_prompt(app);
+                            return AppReturn::Continue;
+                        }
+                        PopUp::DuplicateBoards => {
+                            handle_duplicate_boards_action(app);
+                            return AppReturn::Continue;
+                        }
+                        PopUp::ConfirmRestoreThemesFromBackup => {
+                            handle_restore_themes_from_backup_prompt(app);
+                            return AppReturn::Continue;
+                        }
+                        PopUp::RecoverSaveFile => {
+                            handle_save_recovery_prompt(app);
+                            return AppReturn::Continue;
+                        }
+                        PopUp::ConfirmQuit => return handle_confirm_quit(app).await,
+                        PopUp::ImportOptions => {
+                            handle_import_options(app);
+                            return AppReturn::Continue;
+                        }
+                        PopUp::MirrorSyncSummary => {
+                            if app.state.focus == Focus::MirrorSyncConflicts {
+                                toggle_selected_mirror_conflict_resolution(app);
+                            } else {
+                                handle_mirror_sync_prompt(app);
+                            }
+                            return AppReturn::Continue;
+                        }
+                        PopUp::Notifications => {
+                            if app.state.focus == Focus::SubmitButton {
+                                app.state.notifications.mark_all_read();
+                            }
+                            return AppReturn::Continue;
+                        }
+                        PopUp::WhatsNew | PopUp::GithubImportSummary | PopUp::BoardStats => {
+                            app.close_popup();
+                            return AppReturn::Continue;
+                        }
+                        PopUp::ConflictMarkerWarning => {
+                            jump_to_conflict_marker_card(app);
+                            return AppReturn::Continue;
+                        }
                         PopUp::CardPrioritySelector => {
                             return handle_change_card_priority(app, None);
                         }
+                        PopUp::CardHighlightColorSelector => {
+                            return handle_change_card_highlight_color(app);
+                        }
+                        PopUp::BoardAccentColorSelector => {
+                            return handle_change_board_accent_color(app);
+                        }
+                        PopUp::SortCardsBy => {
+                            return handle_sort_cards_by(app);
+                        }
+                        PopUp::MoveCardToBoard => {
+                            return handle_move_card_to_board(app);
+                        }
+                        PopUp::CalendarDayCards => {
+                            handle_calendar_day_cards(app);
+                            return AppReturn::Continue;
+                        }
+                        PopUp::WaitingOnList => {
+                            handle_waiting_on_list(app);
+                            return AppReturn::Continue;
+                        }
+                        PopUp::SelectCardTemplate => {
+                            handle_select_card_template(app);
+                            return AppReturn::Continue;
+                        }
+                        PopUp::SetLogLevel => {
+                            return handle_set_log_level(app);
+                        }
                         PopUp::FilterByTag => {
                             handle_filter_by_tag(app);
                             return AppReturn::Continue;
                         }
+                        PopUp::FilterByStatus | PopUp::FilterByPriority => {
+                            app.close_popup();
+                            return AppReturn::Continue;
+                        }
+                        PopUp::FilterByDateRange => {
+                            handle_filter_by_date_range(app);
+                            return AppReturn::Continue;
+                        }
                         PopUp::DateTimePicker => {
                             handle_date_time_picker_action(app, None, Some(action));
                             return AppReturn::Continue;
@@ -1684,17 +2614,33 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
                         _ => AppReturn::Continue,
                     },
                     View::NewBoard => {
-                        handle_new_board_action(app);
+                        handle_new_board_action(app, app.config.rapid_card_entry);
+                        AppReturn::Continue
+                    }
+                    View::EditBoard => {
+                        handle_edit_board_action(app);
+                        AppReturn::Continue
+                    }
+                    View::EditBoardNotes => {
+                        handle_edit_board_notes_action(app);
                         AppReturn::Continue
                     }
                     View::NewCard => {
-                        handle_new_card_action(app);
+                        handle_new_card_action(app, app.config.rapid_card_entry, false);
                         AppReturn::Continue
                     }
                     View::LoadLocalSave => {
                         app.dispatch(IoEvent::LoadSaveLocal).await;
                         AppReturn::Continue
                     }
+                    View::ArchivedCards => {
+                        handle_restore_archived_card(app);
+                        AppReturn::Continue
+                    }
+                    View::Calendar => {
+                        open_calendar_day_cards(app);
+                        AppReturn::Continue
+                    }
                     View::EditKeybindings => {
                         handle_edit_keybindings_action(app);
                         AppReturn::Continue
@@ -1736,6 +2682,12 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
                             && app.state.focus == Focus::Body
                             && app.state.current_board_id.is_some()
                             && app.state.current_card_id.is_some()
+                            && app
+                                .visible_boards_and_cards
+                                .get(&app.state.current_board_id.unwrap())
+                                .is_some_and(|cards| {
+                                    cards.contains(&app.state.current_card_id.unwrap())
+                                })
                         {
                             app.set_popup(PopUp::ViewCard);
                         }
@@ -1850,6 +2802,21 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
                 }
                 AppReturn::Continue
             }
+            Action::EditBoard => {
+                if View::views_with_kanban_board().contains(&app.state.current_view)
+                    && !open_edit_board_form(app)
+                {
+                    app.send_warning_toast(&tr(MessageId::ToastNoBoardAvailableToEdit), None);
+                }
+                AppReturn::Continue
+            }
+            Action::ExportToMarkdown => {
+                if View::views_with_kanban_board().contains(&app.state.current_view) {
+                    let export_path = app.config.save_directory.join(MARKDOWN_EXPORT_FILE_NAME);
+                    app.dispatch(IoEvent::ExportToMarkdown(export_path)).await;
+                }
+                AppReturn::Continue
+            }
             Action::NewCard => {
                 if View::views_with_kanban_board().contains(&app.state.current_view) {
                     if app.state.current_board_id.is_none() {
@@ -1866,15 +2833,12 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
             Action::Delete => match app.state.current_view {
                 View::LoadLocalSave => {
                     app.dispatch(IoEvent::DeleteLocalSave).await;
-                    tokio::time::sleep(Duration::from_millis(IO_EVENT_WAIT_TIME)).await;
                     app.dispatch(IoEvent::LoadLocalPreview).await;
                     AppReturn::Continue
                 }
                 View::LoadCloudSave => {
                     app.dispatch(IoEvent::DeleteCloudSave).await;
-                    tokio::time::sleep(Duration::from_millis(IO_EVENT_WAIT_TIME)).await;
                     app.dispatch(IoEvent::GetCloudData).await;
-                    tokio::time::sleep(Duration::from_millis(IO_EVENT_WAIT_TIME)).await;
                     app.dispatch(IoEvent::LoadCloudPreview).await;
                     AppReturn::Continue
                 }
@@ -1884,6 +2848,9 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
                     }
                     match app.state.focus {
                         Focus::Body => {
+                            if let Some(card_ids) = app.get_selected_card_ids() {
+                                return handle_delete_card_range(app, card_ids);
+                            }
                             if let Some(current_board_id) = app.state.current_board_id {
                                 if let Some(current_card_id) = app.state.current_card_id {
                                     match app.boards.get_mut_board_with_id(current_board_id) {
@@ -1922,10 +2889,10 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
                                                         current_board.id,
                                                     ),
                                                 );
-                                                app.send_warning_toast(
-                                                    &format!("Deleted card {}", card_name),
-                                                    None,
-                                                );
+                                                app.send_undoable_toast(&format!(
+                                                    "Deleted card {}",
+                                                    card_name
+                                                ));
                                                 if let Some(visible_cards) = app
                                                     .visible_boards_and_cards
                                                     .get_mut(&current_board_id)
@@ -1966,10 +2933,10 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
                                     warn!("Deleted board {}", board_name);
                                     app.action_history_manager
                                         .new_action(ActionHistory::DeleteBoard(board));
-                                    app.send_warning_toast(
-                                        &format!("Deleted board {}", board_name),
-                                        None,
-                                    );
+                                    app.send_undoable_toast(&format!(
+                                        "Deleted board {}",
+                                        board_name
+                                    ));
                                     app.visible_boards_and_cards.remove(&current_board_id);
                                     refresh_visible_boards_and_cards(app);
                                 }
@@ -2007,10 +2974,7 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
                                 warn!("Deleted board {}", board_name);
                                 app.action_history_manager
                                     .new_action(ActionHistory::DeleteBoard(board));
-                                app.send_warning_toast(
-                                    &format!("Deleted board {}", board_name),
-                                    None,
-                                );
+                                app.send_undoable_toast(&format!("Deleted board {}", board_name));
                                 app.visible_boards_and_cards.remove(&current_board_id);
                                 refresh_visible_boards_and_cards(app);
                             }
@@ -2044,37 +3008,75 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
                 };
                 handle_change_card_status(app, Some(CardStatus::Stale))
             }
-            Action::ChangeCardPriorityToHigh => {
+            Action::ArchiveCard => {
                 if !View::views_with_kanban_board().contains(&app.state.current_view)
                     || app.state.focus != Focus::Body
                 {
                     return AppReturn::Continue;
                 };
-                handle_change_card_priority(app, Some(CardPriority::High))
+                handle_archive_card(app);
+                AppReturn::Continue
             }
-            Action::ChangeCardPriorityToMedium => {
+            Action::DuplicateBoard => {
                 if !View::views_with_kanban_board().contains(&app.state.current_view)
                     || app.state.focus != Focus::Body
                 {
                     return AppReturn::Continue;
                 };
-                handle_change_card_priority(app, Some(CardPriority::Medium))
+                handle_duplicate_board(app);
+                AppReturn::Continue
             }
-            Action::ChangeCardPriorityToLow => {
+            Action::DuplicateCard => {
+                if !View::views_with_kanban_board().contains(&app.state.current_view) {
+                    return AppReturn::Continue;
+                }
+                if app.state.z_stack.last() == Some(&PopUp::ViewCard) {
+                    if app.state.card_being_edited.is_some() {
+                        app.send_warning_toast(
+                            "Save or discard the changes before duplicating the card",
+                            None,
+                        );
+                        return AppReturn::Continue;
+                    }
+                } else if app.state.focus != Focus::Body {
+                    return AppReturn::Continue;
+                }
+                handle_duplicate_card(app);
+                AppReturn::Continue
+            }
+            Action::ChangeCardPriorityToHigh => {
                 if !View::views_with_kanban_board().contains(&app.state.current_view)
                     || app.state.focus != Focus::Body
                 {
                     return AppReturn::Continue;
                 };
-                handle_change_card_priority(app, Some(CardPriority::Low))
+                handle_change_card_priority(app, Some(CardPriority::High))
             }
-            Action::GoToMainMenu => {
+            Action::ChangeCardPriorityToMedium => {
+                if !View::views_with_kanban_board().contains(&app.state.current_view)
+                    || app.state.focus != Focus::Body
+                {
+                    return AppReturn::Continue;
+                };
+                handle_change_card_priority(app, Some(CardPriority::Medium))
+            }
+            Action::ChangeCardPriorityToLow => {
+                if !View::views_with_kanban_board().contains(&app.state.current_view)
+                    || app.state.focus != Focus::Body
+                {
+                    return AppReturn::Continue;
+                };
+                handle_change_card_priority(app, Some(CardPriority::Low))
+            }
+            Action::GoToMainMenu => {
+                // The new board and new card forms are left through set_view, which keeps what
+                // was typed until it is kept as a draft or discarded
                 match app.state.current_view {
-                    View::NewBoard => {
-                        reset_new_board_form(app);
+                    View::EditBoard => {
+                        reset_edit_board_form(app);
                     }
-                    View::NewCard => {
-                        reset_new_card_form(app);
+                    View::EditBoardNotes => {
+                        reset_edit_board_notes_form(app);
                     }
                     View::Login => {
                         reset_login_form(app);
@@ -2095,6 +3097,24 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
                 }
                 AppReturn::Continue
             }
+            Action::MoveBoardLeft => {
+                if !View::views_with_kanban_board().contains(&app.state.current_view)
+                    || app.state.focus != Focus::Body
+                {
+                    return AppReturn::Continue;
+                };
+                handle_move_board(app, false);
+                AppReturn::Continue
+            }
+            Action::MoveBoardRight => {
+                if !View::views_with_kanban_board().contains(&app.state.current_view)
+                    || app.state.focus != Focus::Body
+                {
+                    return AppReturn::Continue;
+                };
+                handle_move_board(app, true);
+                AppReturn::Continue
+            }
             Action::MoveCardUp => {
                 if !View::views_with_kanban_board().contains(&app.state.current_view) {
                     return AppReturn::Continue;
@@ -2103,6 +3123,10 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
                     if app.state.current_card_id.is_none() {
                         return AppReturn::Continue;
                     } else {
+                        if refuse_move_on_auto_sorted_board(app) {
+                            return AppReturn::Continue;
+                        }
+                        let no_of_cards_to_show = app.get_no_of_cards_to_show() as usize;
                         let boards: &mut Boards = if app.filtered_boards.is_empty() {
                             &mut app.boards
                         } else {
@@ -2161,8 +3185,7 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
                                         app.visible_boards_and_cards[&current_board_id].iter()
                                     {
                                         if *card != current_card_id
-                                            && visible_cards.len()
-                                                < app.config.no_of_cards_to_show as usize
+                                            && visible_cards.len() < no_of_cards_to_show
                                         {
                                             visible_cards.push(*card);
                                         }
@@ -2207,6 +3230,10 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
                     if app.state.current_card_id.is_none() {
                         return AppReturn::Continue;
                     } else {
+                        if refuse_move_on_auto_sorted_board(app) {
+                            return AppReturn::Continue;
+                        }
+                        let no_of_cards_to_show = app.get_no_of_cards_to_show() as usize;
                         let boards: &mut Boards = if app.filtered_boards.is_empty() {
                             &mut app.boards
                         } else {
@@ -2268,7 +3295,7 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
                             for card in app.visible_boards_and_cards[&current_board_id].iter().rev()
                             {
                                 if *card != current_card_id
-                                    && visible_cards.len() < app.config.no_of_cards_to_show as usize
+                                    && visible_cards.len() < no_of_cards_to_show
                                 {
                                     visible_cards.insert(0, *card);
                                 }
@@ -2299,14 +3326,20 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
                 }
                 AppReturn::Continue
             }
+            Action::MoveCardToBottom => handle_move_card_to_board_edge(app, false),
+            Action::MoveCardToTop => handle_move_card_to_board_edge(app, true),
             Action::MoveCardRight => {
                 if !View::views_with_kanban_board().contains(&app.state.current_view) {
                     return AppReturn::Continue;
                 }
                 if app.state.focus == Focus::Body {
+                    if let Some(card_ids) = app.get_selected_card_ids() {
+                        return handle_move_card_range(app, card_ids, true);
+                    }
                     if app.state.current_card_id.is_none() {
                         return AppReturn::Continue;
                     } else if let Some(current_board_id) = app.state.current_board_id {
+                        let no_of_cards_to_show = app.get_no_of_cards_to_show() as usize;
                         let boards: &mut Boards = if app.filtered_boards.is_empty() {
                             &mut app.boards
                         } else {
@@ -2343,9 +3376,8 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
                                     .get_mut_board_with_index(moved_to_board_index)
                                     .unwrap();
                                 moved_to_board.cards.add_card(card.clone());
-                                if moved_to_board.cards.len()
-                                    <= app.config.no_of_cards_to_show as usize
-                                {
+                                moved_to_board.fix_card_statuses();
+                                if moved_to_board.cards.len() <= no_of_cards_to_show {
                                     app.visible_boards_and_cards
                                         .entry(moved_to_board.id)
                                         .and_modify(|cards| cards.push(card_id));
@@ -2358,15 +3390,12 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
                                 let mut moved_to_board_visible_cards: Vec<(u64, u64)> = vec![];
                                 let mut moved_from_board_visible_cards: Vec<(u64, u64)> = vec![];
                                 for card in moved_to_board.cards.get_all_cards().iter().rev() {
-                                    if moved_to_board_visible_cards.len()
-                                        < app.config.no_of_cards_to_show as usize
-                                    {
+                                    if moved_to_board_visible_cards.len() < no_of_cards_to_show {
                                         moved_to_board_visible_cards.insert(0, card.id);
                                     }
                                 }
                                 for card in moved_from_board_cards.get_all_cards().iter().rev() {
-                                    if moved_from_board_visible_cards.len()
-                                        < app.config.no_of_cards_to_show as usize
+                                    if moved_from_board_visible_cards.len() < no_of_cards_to_show
                                         && !moved_to_board_visible_cards.contains(&card.id)
                                     {
                                         moved_from_board_visible_cards.insert(0, card.id);
@@ -2384,13 +3413,13 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
                                     "Moved card \"{}\" to board \"{}\"",
                                     card_name, moved_to_board.name
                                 );
-                                app.action_history_manager.new_action(
+                                app.action_history_manager.new_card_move_step(
                                     ActionHistory::MoveCardBetweenBoards(
                                         card.clone(),
                                         moved_from_board_id,
                                         moved_to_board.id,
                                         card_index,
-                                        0,
+                                        moved_to_board.cards.len() - 1,
                                     ),
                                 );
 
@@ -2413,9 +3442,13 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
                     return AppReturn::Continue;
                 }
                 if app.state.focus == Focus::Body {
+                    if let Some(card_ids) = app.get_selected_card_ids() {
+                        return handle_move_card_range(app, card_ids, false);
+                    }
                     if app.state.current_card_id.is_none() {
                         return AppReturn::Continue;
                     } else if let Some(current_board) = app.state.current_board_id {
+                        let no_of_cards_to_show = app.get_no_of_cards_to_show() as usize;
                         let boards: &mut Boards = if app.filtered_boards.is_empty() {
                             &mut app.boards
                         } else {
@@ -2452,9 +3485,8 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
                                 let card_id = card.id;
                                 let card_name = card.name.clone();
                                 moved_to_board.cards.add_card(card.clone());
-                                if moved_to_board.cards.len()
-                                    <= app.config.no_of_cards_to_show as usize
-                                {
+                                moved_to_board.fix_card_statuses();
+                                if moved_to_board.cards.len() <= no_of_cards_to_show {
                                     app.visible_boards_and_cards
                                         .entry(moved_to_board_id)
                                         .and_modify(|cards| cards.push(card_id));
@@ -2467,15 +3499,12 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
                                 let mut moved_to_board_visible_cards: Vec<(u64, u64)> = vec![];
                                 let mut moved_from_board_visible_cards: Vec<(u64, u64)> = vec![];
                                 for card in moved_to_board.cards.get_all_cards().iter().rev() {
-                                    if moved_to_board_visible_cards.len()
-                                        < app.config.no_of_cards_to_show as usize
-                                    {
+                                    if moved_to_board_visible_cards.len() < no_of_cards_to_show {
                                         moved_to_board_visible_cards.insert(0, card.id);
                                     }
                                 }
                                 for card in moved_from_board_cards.get_all_cards().iter().rev() {
-                                    if moved_from_board_visible_cards.len()
-                                        < app.config.no_of_cards_to_show as usize
+                                    if moved_from_board_visible_cards.len() < no_of_cards_to_show
                                         && !moved_to_board_visible_cards.contains(&card.id)
                                     {
                                         moved_from_board_visible_cards.insert(0, card.id);
@@ -2493,13 +3522,13 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
                                     "Moved card \"{}\" to board \"{}\"",
                                     card_name, moved_to_board.name
                                 );
-                                app.action_history_manager.new_action(
+                                app.action_history_manager.new_card_move_step(
                                     ActionHistory::MoveCardBetweenBoards(
                                         card.clone(),
                                         moved_from_board_id,
                                         moved_to_board_id,
                                         card_index,
-                                        0,
+                                        moved_to_board.cards.len() - 1,
                                     ),
                                 );
 
@@ -2558,15 +3587,43 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
                 }
                 AppReturn::Continue
             }
+            Action::StartHistoryStepCount => {
+                if is_history_step_count_allowed(app) {
+                    app.state.history_step_count = Some(0);
+                }
+                AppReturn::Continue
+            }
             Action::Undo => {
                 if View::views_with_kanban_board().contains(&app.state.current_view) {
+                    match history_step_count {
+                        Some(steps) if steps > 1 => app.undo_steps(steps),
+                        _ => app.undo(),
+                    }
+                }
+                AppReturn::Continue
+            }
+            Action::UndoFromToast => {
+                if !app.state.z_stack.is_empty()
+                    || !View::views_with_kanban_board().contains(&app.state.current_view)
+                {
+                    return AppReturn::Continue;
+                }
+                let latest_action_id = app.action_history_manager.latest_action_id;
+                let toast_index = app.widgets.toast_widget.toasts.iter().position(|toast| {
+                    toast.undo_action_id.is_some() && toast.undo_action_id == latest_action_id
+                });
+                if let Some(toast_index) = toast_index {
+                    app.widgets.toast_widget.toasts.remove(toast_index);
                     app.undo();
                 }
                 AppReturn::Continue
             }
             Action::Redo => {
                 if View::views_with_kanban_board().contains(&app.state.current_view) {
-                    app.redo();
+                    match history_step_count {
+                        Some(steps) if steps > 1 => app.redo_steps(steps),
+                        _ => app.redo(),
+                    }
                 }
                 AppReturn::Continue
             }
@@ -2575,12 +3632,94 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
                 info!("Cleared toast messages");
                 AppReturn::Continue
             }
+            Action::ToggleCardSelection => handle_toggle_card_selection(app),
+            Action::ToggleCompletedCardsVisibility => handle_toggle_completed_cards_visibility(app),
+            Action::ArchiveBoard => handle_archive_board(app),
+            Action::ToggleArchivedView => handle_toggle_archived_view(app),
+            Action::ShowBoardStats => handle_show_board_stats(app),
+            Action::FilterByStatus => {
+                if !View::views_with_kanban_board().contains(&app.state.current_view)
+                    || app.state.focus != Focus::Body
+                {
+                    return AppReturn::Continue;
+                };
+                open_filter_by_status(app);
+                AppReturn::Continue
+            }
+            Action::FilterByPriority => {
+                if !View::views_with_kanban_board().contains(&app.state.current_view)
+                    || app.state.focus != Focus::Body
+                {
+                    return AppReturn::Continue;
+                };
+                open_filter_by_priority(app);
+                AppReturn::Continue
+            }
+            Action::FilterByDateRange => {
+                if !View::views_with_kanban_board().contains(&app.state.current_view)
+                    || app.state.focus != Focus::Body
+                {
+                    return AppReturn::Continue;
+                };
+                open_filter_by_date_range(app);
+                AppReturn::Continue
+            }
+            Action::SortCards => {
+                if !View::views_with_kanban_board().contains(&app.state.current_view)
+                    || app.state.focus != Focus::Body
+                {
+                    return AppReturn::Continue;
+                };
+                if !open_sort_cards_by(app) {
+                    app.send_warning_toast("No board selected", None);
+                }
+                AppReturn::Continue
+            }
+            Action::MoveCardToBoard => {
+                if !View::views_with_kanban_board().contains(&app.state.current_view)
+                    || app.state.focus != Focus::Body
+                {
+                    return AppReturn::Continue;
+                };
+                open_move_card_to_board(app);
+                AppReturn::Continue
+            }
+            Action::ToggleFocusTimer => {
+                toggle_focus_timer(app);
+                AppReturn::Continue
+            }
+            Action::ToggleNotifications => {
+                handle_toggle_notifications(app);
+                AppReturn::Continue
+            }
+            Action::SkipTutorialStep => {
+                if app.widgets.tutorial.step.is_some() && !app.widgets.tutorial.advance(&app.boards)
+                {
+                    app.send_info_toast(&tr(MessageId::ToastTutorialCompleteYouAreAllSet), None);
+                }
+                AppReturn::Continue
+            }
+            Action::SelectCardRangeUp => handle_select_card_range(app, true),
+            Action::SelectCardRangeDown => handle_select_card_range(app, false),
+            Action::SetCardHighlightColor => {
+                if !View::views_with_kanban_board().contains(&app.state.current_view)
+                    || app.state.focus != Focus::Body
+                {
+                    return AppReturn::Continue;
+                };
+                if !open_card_highlight_color_selector(app) {
+                    app.send_error_toast("Could not find current card", None);
+                }
+                AppReturn::Continue
+            }
         }
     } else {
         // Warn user that they are not in user input mode
         if app.state.card_being_edited.is_some()
             || app.state.current_view == View::NewCard
             || app.state.current_view == View::NewBoard
+            || app.state.current_view == View::EditBoard
+            || app.state.current_view == View::EditBoardNotes
         {
             let mut keys = String::new();
             for key in app.config.keybindings.take_user_input.iter() {
@@ -2607,1388 +3746,3608 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
     }
 }
 
-fn toggle_focus_between_submit_and_extra(app: &mut App) {
-    app.state.set_focus(match app.state.focus {
-        Focus::SubmitButton => Focus::ExtraFocus,
-        _ => Focus::SubmitButton,
-    });
+/// Starts a focus timer on the current card, or pauses and resumes the one that is already
+/// running
+pub fn toggle_focus_timer(app: &mut App) {
+    let now = chrono::Local::now();
+    if let Some(focus_timer) = &mut app.state.focus_timer {
+        let info_msg = if focus_timer.is_paused() {
+            focus_timer.resume(now);
+            format!("Resumed focus on \"{}\"", focus_timer.card_name)
+        } else {
+            focus_timer.pause(now);
+            format!("Paused focus on \"{}\"", focus_timer.card_name)
+        };
+        info!("{}", info_msg);
+        app.send_info_toast(&info_msg, None);
+        return;
+    }
+    let current_card = app
+        .state
+        .current_board_id
+        .zip(app.state.current_card_id)
+        .and_then(|(board_id, card_id)| {
+            let card = app
+                .boards
+                .get_board_with_id(board_id)?
+                .cards
+                .get_card_with_id(card_id)?;
+            Some((board_id, card_id, card.name.clone()))
+        });
+    let Some((board_id, card_id, card_name)) =
+        current_card.filter(|_| View::views_with_kanban_board().contains(&app.state.current_view))
+    else {
+        app.send_error_toast(&tr(MessageId::ToastNoCardSelectedToFocusOn), None);
+        return;
+    };
+    let info_msg = format!(
+        "Focusing on \"{}\" for {} minutes",
+        card_name, FOCUS_TIMER_MINUTES
+    );
+    app.state.focus_timer = Some(FocusTimer::start(
+        board_id,
+        card_id,
+        card_name,
+        chrono::Duration::minutes(FOCUS_TIMER_MINUTES),
+        now,
+    ));
+    info!("{}", info_msg);
+    app.send_info_toast(&info_msg, None);
 }
 
-pub async fn handle_mouse_action(app: &mut App<'_>, mouse_action: Mouse) -> AppReturn {
-    let mut left_button_pressed = false;
-    let mut right_button_pressed = false;
-    let mut middle_button_pressed = false;
-    let mut mouse_scroll_up = false;
-    let mut mouse_scroll_down = false;
-    let mut mouse_scroll_left = false;
-    let mut mouse_scroll_right = false;
-    match mouse_action {
-        Mouse::Move(x, y) => {
-            app.state.previous_mouse_coordinates = app.state.current_mouse_coordinates;
-            app.state.current_mouse_coordinates = (x, y);
-        }
-        Mouse::Drag(x, y) => {
-            app.state.current_mouse_coordinates = (x, y);
-            let is_invalid_state = !View::views_with_kanban_board()
-                .contains(&app.state.current_view)
-                || app.state.hovered_card.is_none()
-                || app.state.hovered_board.is_none();
-            if is_invalid_state {
-                return AppReturn::Continue;
-            }
-            if !app.state.card_drag_mode {
-                app.state.card_drag_mode = true;
-            }
-        }
-        Mouse::LeftPress => left_button_pressed = true,
-        Mouse::RightPress => right_button_pressed = true,
-        Mouse::MiddlePress => middle_button_pressed = true,
-        Mouse::ScrollUp => mouse_scroll_up = true,
-        Mouse::ScrollDown => mouse_scroll_down = true,
-        Mouse::ScrollLeft => mouse_scroll_left = true,
-        Mouse::ScrollRight => mouse_scroll_right = true,
-        Mouse::Unknown => {}
+pub fn cancel_focus_timer(app: &mut App) {
+    if let Some(focus_timer) = app.state.focus_timer.take() {
+        let info_msg = format!("Cancelled focus on \"{}\"", focus_timer.card_name);
+        info!("{}", info_msg);
+        app.send_info_toast(&info_msg, None);
+    } else {
+        app.send_warning_toast(&tr(MessageId::ToastNoFocusTimerIsRunning), None);
     }
+}
 
-    if let Some(mouse_action) = &app.state.last_mouse_action {
-        match mouse_action {
-            Mouse::Drag(_, _) => {
-                if left_button_pressed || right_button_pressed || middle_button_pressed {
-                    left_button_pressed = false;
-                    right_button_pressed = false;
-                    middle_button_pressed = false;
-                    if app.state.hovered_card.is_some() && app.state.hovered_board.is_some() {
-                        move_dragged_card(app);
-                        reset_card_drag_mode(app);
-                        refresh_visible_boards_and_cards(app);
-                    }
-                    reset_card_drag_mode(app);
-                }
-            }
-            Mouse::Unknown => {
-                reset_card_drag_mode(app);
-            }
-            _ => {}
-        }
+/// Archives the current board, or brings it back when it is already archived and shown with
+/// the archived boards
+fn handle_archive_board(app: &mut App) -> AppReturn {
+    if !View::views_with_kanban_board().contains(&app.state.current_view)
+        || app.state.focus != Focus::Body
+    {
+        return AppReturn::Continue;
     }
-
-    if right_button_pressed {
-        return handle_go_to_previous_view(app).await;
+    let Some(current_board_id) = app.state.current_board_id else {
+        warn!("No board selected to archive");
+        app.send_warning_toast(&tr(MessageId::ToastNoBoardSelectedToArchive), None);
+        return AppReturn::Continue;
+    };
+    let Some(board) = app.boards.get_mut_board_with_id(current_board_id) else {
+        debug!("Cannot archive board: current board not found");
+        app.send_error_toast("Something went wrong, could not find the board", None);
+        return AppReturn::Continue;
+    };
+    let old_board = board.clone();
+    board.archived = !board.archived;
+    let new_board = board.clone();
+    app.action_history_manager
+        .new_action(ActionHistory::EditBoard(old_board, new_board.clone()));
+    // A filtered view holds its own copies, they have to agree on what is hidden
+    if let Some(filtered_board) = app.filtered_boards.get_mut_board_with_id(current_board_id) {
+        filtered_board.archived = new_board.archived;
+    }
+    refresh_visible_boards_and_cards(app);
+    if app.visible_boards_and_cards.is_empty() {
+        app.state.current_board_id = None;
+        app.state.current_card_id = None;
     }
+    let info_msg = if !new_board.archived {
+        format!("Unarchived board \"{}\"", new_board.name)
+    } else if app.state.show_archived_boards {
+        format!("Archived board \"{}\"", new_board.name)
+    } else {
+        format!(
+            "Archived board \"{}\", show archived boards to see it again",
+            new_board.name
+        )
+    };
+    info!("{}", info_msg);
+    app.send_info_toast(&info_msg, None);
+    AppReturn::Continue
+}
 
-    if app.state.focus == Focus::Log {
-        if mouse_scroll_down {
-            app.log_next();
-        }
-        if mouse_scroll_up {
-            app.log_prv();
-        }
+fn handle_toggle_archived_view(app: &mut App) -> AppReturn {
+    if !View::views_with_kanban_board().contains(&app.state.current_view) {
+        return AppReturn::Continue;
     }
+    app.state.show_archived_boards = !app.state.show_archived_boards;
+    refresh_visible_boards_and_cards(app);
+    if app.visible_boards_and_cards.is_empty() {
+        app.state.current_board_id = None;
+        app.state.current_card_id = None;
+    }
+    let info_msg = if app.state.show_archived_boards {
+        let archived_board_count = app
+            .boards
+            .get_boards()
+            .iter()
+            .filter(|board| board.archived)
+            .count();
+        format!(
+            "Showing {} archived board{}",
+            archived_board_count,
+            if archived_board_count == 1 { "" } else { "s" }
+        )
+    } else {
+        "Hiding archived boards".to_string()
+    };
+    info!("{}", info_msg);
+    app.send_info_toast(&info_msg, None);
+    AppReturn::Continue
+}
 
-    if middle_button_pressed {
-        if app.state.z_stack.is_empty() {
-            app.set_popup(PopUp::CommandPalette);
-        } else {
-            match app.state.z_stack.last().unwrap() {
-                PopUp::CommandPalette => {
-                    app.close_popup();
-                    app.widgets.command_palette.reset(&mut app.state);
-                    app.state.app_status = AppStatus::Initialized;
-                }
-                PopUp::ViewCard => {
-                    if app.state.card_being_edited.is_some() {
-                        app.set_popup(PopUp::ConfirmDiscardCardChanges);
-                    } else {
-                        app.set_popup(PopUp::CommandPalette);
-                    }
-                }
-                PopUp::ConfirmDiscardCardChanges => {
-                    app.close_popup();
-                    app.set_popup(PopUp::CommandPalette);
-                }
-                _ => {
-                    app.set_popup(PopUp::CommandPalette);
-                }
-            }
-        }
+fn handle_show_board_stats(app: &mut App) -> AppReturn {
+    if !View::views_with_kanban_board().contains(&app.state.current_view) {
+        return AppReturn::Continue;
+    }
+    if app.state.current_board_id.is_none() {
+        app.send_warning_toast("No board selected", None);
         return AppReturn::Continue;
     }
+    app.set_popup(PopUp::BoardStats);
+    AppReturn::Continue
+}
 
-    if let (Some(popup), Some(mouse_focus)) = (app.state.z_stack.last(), app.state.mouse_focus) {
-        match popup {
-            PopUp::CommandPalette => {
-                if left_button_pressed {
-                    match mouse_focus {
-                        Focus::CommandPaletteCommand => {
-                            return CommandPaletteWidget::handle_command(app).await;
-                        }
-                        Focus::CommandPaletteCard => {
-                            handle_command_palette_card_selection(app);
-                            app.close_popup();
-                            app.widgets.command_palette.reset(&mut app.state);
-                            app.state.app_status = AppStatus::Initialized;
+/// Opens the sort order picker for the current board, returns false if there is no current board
+pub fn open_sort_cards_by(app: &mut App) -> bool {
+    if app.state.current_board_id.is_none() {
+        return false;
+    }
+    app.set_popup(PopUp::SortCardsBy);
+    app.state.app_status = AppStatus::Initialized;
+    app.state
+        .app_list_states
+        .sort_cards_by_selector
+        .select(Some(0));
+    true
+}
+
+/// Opens the board picker for the current card, warns instead when there is no card or nowhere
+/// to move it to
+pub fn open_move_card_to_board(app: &mut App) {
+    if app.state.current_card_id.is_none() {
+        app.send_warning_toast("No card selected", None);
+        return;
+    }
+    if app.get_move_card_to_board_targets().is_empty() {
+        app.send_warning_toast(
+            &tr(MessageId::ToastThereAreNoOtherBoardsToMoveTheCardTo),
+            None,
+        );
+        return;
+    }
+    app.set_popup(PopUp::MoveCardToBoard);
+    app.state.app_status = AppStatus::Initialized;
+    app.state
+        .app_list_states
+        .move_card_to_board_selector
+        .select(Some(0));
+}
+
+/// Opens the log level picker with the current level selected
+pub fn open_set_log_level(app: &mut App) {
+    app.set_popup(PopUp::SetLogLevel);
+    app.state.app_status = AppStatus::Initialized;
+    let current_level = get_default_level();
+    let current_index = SELECTABLE_LOG_LEVELS
+        .iter()
+        .position(|level| *level == current_level)
+        .unwrap_or(0);
+    app.state
+        .app_list_states
+        .log_level_selector
+        .select(Some(current_index));
+}
+
+/// Opens the status filter picker, the filter is applied live as statuses are toggled
+pub fn open_filter_by_status(app: &mut App) {
+    app.set_popup(PopUp::FilterByStatus);
+    app.state.app_status = AppStatus::Initialized;
+    app.state
+        .app_list_states
+        .filter_by_status_list
+        .select(Some(0));
+}
+
+/// Opens the due date filter picker on the range in use, the first preset when there is none
+pub fn open_filter_by_date_range(app: &mut App) {
+    let selected_index = match app.state.active_date_filter {
+        Some(DateRangeFilter::Custom(..)) => DateRangeFilter::presets().len(),
+        Some(date_filter) => DateRangeFilter::presets()
+            .iter()
+            .position(|preset| *preset == date_filter)
+            .unwrap_or(0),
+        None => 0,
+    };
+    app.set_popup(PopUp::FilterByDateRange);
+    app.state.app_status = AppStatus::Initialized;
+    app.state
+        .app_list_states
+        .filter_by_date_range_list
+        .select(Some(selected_index));
+}
+
+/// Applies the highlighted preset and closes the picker, picking the preset in use again turns
+/// the filter off. The last entry asks for a custom range instead
+fn handle_filter_by_date_range(app: &mut App) {
+    let Some(selected_index) = app
+        .state
+        .app_list_states
+        .filter_by_date_range_list
+        .selected()
+    else {
+        debug!("No range selected in the date filter");
+        return;
+    };
+    let Some(preset) = DateRangeFilter::presets().get(selected_index).copied() else {
+        app.state.text_buffers.custom_date_range.reset();
+        app.set_popup(PopUp::CustomDateRangePrompt);
+        return;
+    };
+    app.close_popup();
+    if app.state.active_date_filter == Some(preset) {
+        app.state.active_date_filter = None;
+        app.send_warning_toast(&format!("Removed \"{}\" filter", preset.name()), None);
+    } else {
+        app.state.active_date_filter = Some(preset);
+        app.send_info_toast(
+            &format!(
+                "Showing cards {}",
+                preset.describe(app.config.date_time_format)
+            ),
+            None,
+        );
+    }
+    app.state.current_card_id = None;
+    refresh_visible_boards_and_cards(app);
+}
+
+/// Applies the typed range, the prompt stays open while it does not parse
+fn handle_custom_date_range_prompt(app: &mut App) -> AppReturn {
+    let custom_date_range = app.state.text_buffers.custom_date_range.get_joined_lines();
+    match DateRangeFilter::parse_custom_range(&custom_date_range, app.config.date_time_format) {
+        Ok(date_filter) => {
+            // The prompt and the picker under it are both done with
+            app.close_popup();
+            if app.state.z_stack.last() == Some(&PopUp::FilterByDateRange) {
+                app.close_popup();
+            }
+            app.state.active_date_filter = Some(date_filter);
+            app.send_info_toast(
+                &format!(
+                    "Showing cards {}",
+                    date_filter.describe(app.config.date_time_format)
+                ),
+                None,
+            );
+            app.state.current_card_id = None;
+            refresh_visible_boards_and_cards(app);
+        }
+        Err(err) => app.send_error_toast(&err, None),
+    }
+    AppReturn::Continue
+}
+
+/// Opens the priority filter picker, the filter is applied live as priorities are toggled
+pub fn open_filter_by_priority(app: &mut App) {
+    app.set_popup(PopUp::FilterByPriority);
+    app.state.app_status = AppStatus::Initialized;
+    app.state
+        .app_list_states
+        .filter_by_priority_list
+        .select(Some(0));
+}
+
+fn handle_toggle_priority_filter(app: &mut App) {
+    let all_priorities = CardPriority::all();
+    let Some(selected_priority) = app
+        .state
+        .app_list_states
+        .filter_by_priority_list
+        .selected()
+        .and_then(|index| all_priorities.get(index))
+    else {
+        debug!("No priority selected to toggle in the priority filter");
+        return;
+    };
+    let mut priorities = app.state.active_priority_filter.take().unwrap_or_default();
+    if priorities.contains(selected_priority) {
+        priorities.retain(|priority| priority != selected_priority);
+        app.send_warning_toast(
+            &format!("Removed priority \"{}\" from filter", selected_priority),
+            None,
+        );
+    } else {
+        priorities.push(selected_priority.clone());
+        app.send_info_toast(
+            &format!("Added priority \"{}\" to filter", selected_priority),
+            None,
+        );
+    }
+    app.state.active_priority_filter = if priorities.is_empty() {
+        None
+    } else {
+        Some(priorities)
+    };
+    app.state.current_card_id = None;
+    refresh_visible_boards_and_cards(app);
+}
+
+fn handle_toggle_status_filter(app: &mut App) {
+    let all_statuses = CardStatus::all();
+    let Some(selected_status) = app
+        .state
+        .app_list_states
+        .filter_by_status_list
+        .selected()
+        .and_then(|index| all_statuses.get(index))
+    else {
+        debug!("No status selected to toggle in the status filter");
+        return;
+    };
+    let mut statuses = app.state.active_status_filter.take().unwrap_or_default();
+    if statuses.contains(selected_status) {
+        statuses.retain(|status| status != selected_status);
+        app.send_warning_toast(
+            &format!("Removed status \"{}\" from filter", selected_status),
+            None,
+        );
+    } else {
+        statuses.push(selected_status.clone());
+        app.send_info_toast(
+            &format!("Added status \"{}\" to filter", selected_status),
+            None,
+        );
+    }
+    app.state.active_status_filter = if statuses.is_empty() {
+        None
+    } else {
+        Some(statuses)
+    };
+    app.state.current_card_id = None;
+    refresh_visible_boards_and_cards(app);
+}
+
+fn handle_sort_cards_by(app: &mut App) -> AppReturn {
+    let all_orders = CardSortOrder::all();
+    let current_index = app
+        .state
+        .app_list_states
+        .sort_cards_by_selector
+        .selected()
+        .unwrap_or(0)
+        .min(all_orders.len() - 1);
+    let Some(current_board_id) = app.state.current_board_id else {
+        app.send_error_toast(&tr(MessageId::ToastErrorCouldNotFindCurrentBoard), None);
+        return AppReturn::Continue;
+    };
+    app.close_popup();
+    app.sort_cards_in_board(current_board_id, all_orders[current_index]);
+    AppReturn::Continue
+}
+
+fn handle_move_card_to_board(app: &mut App) -> AppReturn {
+    let targets = app.get_move_card_to_board_targets();
+    let Some(selected_board_id) = app
+        .state
+        .app_list_states
+        .move_card_to_board_selector
+        .selected()
+        .and_then(|index| targets.get(index).copied())
+    else {
+        app.send_error_toast(&tr(MessageId::ToastCouldNotFindTheSelectedBoard), None);
+        return AppReturn::Continue;
+    };
+    app.close_popup();
+    app.move_current_card_to_board(selected_board_id);
+    AppReturn::Continue
+}
+
+/// Takes effect straight away, the per module log filters still override the new level
+fn handle_set_log_level(app: &mut App) -> AppReturn {
+    let selected_index = app
+        .state
+        .app_list_states
+        .log_level_selector
+        .selected()
+        .unwrap_or(0)
+        .min(SELECTABLE_LOG_LEVELS.len() - 1);
+    let log_level = SELECTABLE_LOG_LEVELS[selected_index];
+    app.close_popup();
+    set_default_level(log_level);
+    app.send_info_toast(
+        &tr_args(
+            MessageId::ToastLogLevelSetTo,
+            &[("level", &log_level.to_string())],
+        ),
+        None,
+    );
+    AppReturn::Continue
+}
+
+fn handle_toggle_completed_cards_visibility(app: &mut App) -> AppReturn {
+    if !View::views_with_kanban_board().contains(&app.state.current_view)
+        || app.state.focus != Focus::Body
+    {
+        return AppReturn::Continue;
+    }
+    let current_board_id = if let Some(current_board_id) = app.state.current_board_id {
+        current_board_id
+    } else {
+        warn!("No board selected to toggle completed cards visibility");
+        app.send_warning_toast(
+            "No board selected to toggle completed cards visibility",
+            None,
+        );
+        return AppReturn::Continue;
+    };
+    let board_name = if let Some(board) = app.boards.get_board_with_id(current_board_id) {
+        board.name.clone()
+    } else {
+        debug!("Cannot toggle completed cards visibility: current board not found");
+        app.send_error_toast("Something went wrong, could not find the board", None);
+        return AppReturn::Continue;
+    };
+    let info_msg = if app
+        .state
+        .boards_with_hidden_completed_cards
+        .remove(&current_board_id)
+    {
+        format!("Showing completed cards for board \"{}\"", board_name)
+    } else {
+        app.state
+            .boards_with_hidden_completed_cards
+            .insert(current_board_id);
+        format!("Hiding completed cards for board \"{}\"", board_name)
+    };
+    refresh_visible_cards_for_board(app, current_board_id);
+    info!("{}", info_msg);
+    app.send_info_toast(&info_msg, None);
+    AppReturn::Continue
+}
+
+/// Recomputes the visible cards of a single board, keeping the selection on a card that is
+/// still displayed (the next visible one if the current card got hidden)
+pub fn refresh_visible_cards_for_board(app: &mut App, board_id: (u64, u64)) {
+    // Cards created on or moved to a board with its own statuses are put in one of them here,
+    // auto sorted boards are sorted here too so every change keeps them in priority order
+    for boards in [&mut app.boards, &mut app.filtered_boards] {
+        if let Some(board) = boards.get_mut_board_with_id(board_id) {
+            board.fix_card_statuses();
+            board.apply_auto_sort();
+        }
+    }
+    let boards: &Boards = if app.filtered_boards.is_empty() {
+        &app.boards
+    } else {
+        &app.filtered_boards
+    };
+    let board = if let Some(board) = boards.get_board_with_id(board_id) {
+        board
+    } else {
+        debug!(
+            "Cannot refresh visible cards: board {:?} not found",
+            board_id
+        );
+        return;
+    };
+    let displayable_cards = app.get_displayable_cards(board);
+    let no_of_cards_to_show = app.get_no_of_cards_to_show() as usize;
+    let is_current_board = app.state.current_board_id == Some(board_id);
+    let selected_card_id = if !is_current_board {
+        None
+    } else if let Some(current_card_id) = app.state.current_card_id {
+        if displayable_cards.get_card_index(current_card_id).is_some() {
+            Some(current_card_id)
+        } else {
+            let current_card_index = board.cards.get_card_index(current_card_id).unwrap_or(0);
+            board
+                .cards
+                .get_all_cards()
+                .iter()
+                .skip(current_card_index)
+                .find(|card| displayable_cards.get_card_index(card.id).is_some())
+                .or_else(|| displayable_cards.get_all_cards().last())
+                .map(|card| card.id)
+        }
+    } else {
+        displayable_cards.get_first_card_id()
+    };
+
+    let visible_card_ids = keep_window_around(
+        &displayable_cards.get_all_card_ids(),
+        app.visible_boards_and_cards
+            .get(&board_id)
+            .map_or(&[][..], |card_ids| card_ids.as_slice()),
+        selected_card_id,
+        no_of_cards_to_show,
+    );
+    app.visible_boards_and_cards
+        .entry(board_id)
+        .and_modify(|cards| *cards = visible_card_ids);
+    if is_current_board {
+        app.state.current_card_id = selected_card_id;
+    }
+}
+
+/// Replaces the visible boards, boards that stay on screen keep their card window and new ones
+/// start at their first card
+fn set_visible_boards(app: &mut App, board_ids: &[(u64, u64)]) {
+    let no_of_cards_to_show = app.get_no_of_cards_to_show() as usize;
+    let boards = if app.filtered_boards.is_empty() {
+        &app.boards
+    } else {
+        &app.filtered_boards
+    };
+    let mut visible_boards_and_cards: LinkedHashMap<(u64, u64), Vec<(u64, u64)>> =
+        LinkedHashMap::new();
+    for board_id in board_ids {
+        let card_ids = if let Some(card_ids) = app.visible_boards_and_cards.get(board_id) {
+            card_ids.clone()
+        } else if let Some(board) = boards.get_board_with_id(*board_id) {
+            let displayable_cards = app.get_displayable_cards(board);
+            card_window(
+                &displayable_cards.get_all_card_ids(),
+                None,
+                no_of_cards_to_show,
+            )
+        } else {
+            debug!("Cannot show board {:?}: board not found", board_id);
+            continue;
+        };
+        visible_boards_and_cards.insert(*board_id, card_ids);
+    }
+    app.visible_boards_and_cards = visible_boards_and_cards;
+}
+
+/// Re-windows the cards of every visible board, used when the number of cards that fit changes
+pub fn refresh_visible_cards_for_all_boards(app: &mut App) {
+    let visible_board_ids = app
+        .visible_boards_and_cards
+        .keys()
+        .copied()
+        .collect::<Vec<(u64, u64)>>();
+    for board_id in visible_board_ids {
+        refresh_visible_cards_for_board(app, board_id);
+    }
+}
+
+/// Extends the range selection from the current card, anchoring it first if no range is active
+fn handle_select_card_range(app: &mut App, up: bool) -> AppReturn {
+    if !View::views_with_kanban_board().contains(&app.state.current_view)
+        || app.state.focus != Focus::Body
+    {
+        return AppReturn::Continue;
+    }
+    let (current_board_id, current_card_id) =
+        match (app.state.current_board_id, app.state.current_card_id) {
+            (Some(current_board_id), Some(current_card_id)) => (current_board_id, current_card_id),
+            _ => return AppReturn::Continue,
+        };
+    let is_anchored_on_current_board = app
+        .state
+        .card_range_selection
+        .is_some_and(|range_selection| range_selection.board_id == current_board_id);
+    if !is_anchored_on_current_board {
+        // A range replaces the marked cards rather than adding to them
+        app.state.selected_card_ids.clear();
+        app.state.card_range_selection = Some(CardRangeSelection {
+            board_id: current_board_id,
+            anchor_card_id: current_card_id,
+        });
+    }
+    if up {
+        go_up(app);
+    } else {
+        go_down(app);
+    }
+    AppReturn::Continue
+}
+
+/// Marks or unmarks the current card for bulk actions. Marking a card on another board starts
+/// a new selection there, the bulk actions only ever work on one board
+fn handle_toggle_card_selection(app: &mut App) -> AppReturn {
+    if !View::views_with_kanban_board().contains(&app.state.current_view)
+        || app.state.focus != Focus::Body
+    {
+        return AppReturn::Continue;
+    }
+    let Some(current_card_id) = app.state.current_card_id else {
+        return AppReturn::Continue;
+    };
+    let marked_card_ids = app.get_marked_card_ids();
+    if marked_card_ids.len() != app.state.selected_card_ids.len() {
+        app.state.selected_card_ids = marked_card_ids;
+    }
+    if let Some(index) = app
+        .state
+        .selected_card_ids
+        .iter()
+        .position(|card_id| *card_id == current_card_id)
+    {
+        app.state.selected_card_ids.remove(index);
+    } else {
+        app.state.selected_card_ids.push(current_card_id);
+    }
+    let message = match app.state.selected_card_ids.len() {
+        0 => "No cards marked".to_string(),
+        1 => "1 card marked".to_string(),
+        marked_count => format!("{} cards marked", marked_count),
+    };
+    app.send_info_toast(&message, None);
+    AppReturn::Continue
+}
+
+/// Stamps date_modified and records the edit only when the card actually changed, so picking
+/// the value a card already has does not rewrite it in the save file
+fn record_card_edit(
+    action_history_manager: &mut ActionHistoryManager,
+    old_card: Card,
+    card: &mut Card,
+    board_id: (u64, u64),
+    date_time_format: DateTimeFormat,
+) {
+    if *card == old_card {
+        return;
+    }
+    card.date_modified = chrono::Local::now()
+        .format(date_time_format.to_parser_string())
+        .to_string();
+    action_history_manager.new_action(ActionHistory::EditCard(old_card, card.clone(), board_id));
+}
+
+/// Applies the edit to every card in the range and records it as a single undoable batch
+fn handle_edit_card_range(
+    app: &mut App,
+    card_ids: Vec<(u64, u64)>,
+    description: &str,
+    edit: impl Fn(&mut Card),
+) -> AppReturn {
+    let current_board_id = if let Some(current_board_id) = app.state.current_board_id {
+        current_board_id
+    } else {
+        return AppReturn::Continue;
+    };
+    let date_modified = chrono::Local::now()
+        .format(app.config.date_time_format.to_parser_string())
+        .to_string();
+    let boards: &mut Boards = if app.filtered_boards.is_empty() {
+        &mut app.boards
+    } else {
+        &mut app.filtered_boards
+    };
+    let mut batch = Vec::new();
+    let mut found_card_count = 0;
+    if let Some(current_board) = boards.get_mut_board_with_id(current_board_id) {
+        for card_id in card_ids {
+            if let Some(card) = current_board.cards.get_mut_card_with_id(card_id) {
+                found_card_count += 1;
+                let temp_old_card = card.clone();
+                edit(card);
+                // Cards the edit did not change keep their date_modified
+                if *card == temp_old_card {
+                    continue;
+                }
+                card.date_modified.clone_from(&date_modified);
+                batch.push(ActionHistory::EditCard(
+                    temp_old_card,
+                    card.clone(),
+                    current_board_id,
+                ));
+            }
+        }
+    }
+    if found_card_count == 0 {
+        app.send_error_toast(&tr(MessageId::ToastErrorCouldNotFindSelectedCards), None);
+        return AppReturn::Continue;
+    }
+    if batch.is_empty() {
+        app.state.card_range_selection = None;
+        app.close_popup();
+        app.send_info_toast(&tr(MessageId::ToastSelectedCardsAlreadyHaveThatValue), None);
+        return AppReturn::Continue;
+    }
+    let info_msg = format!("{} for {} cards", description, batch.len());
+    app.action_history_manager
+        .new_action(ActionHistory::Batch(batch));
+    app.state.card_range_selection = None;
+    app.close_popup();
+    if app
+        .state
+        .boards_with_hidden_completed_cards
+        .contains(&current_board_id)
+    {
+        refresh_visible_cards_for_board(app, current_board_id);
+    }
+    info!("{}", info_msg);
+    app.send_info_toast(&info_msg, None);
+    AppReturn::Continue
+}
+
+fn handle_delete_card_range(app: &mut App, card_ids: Vec<(u64, u64)>) -> AppReturn {
+    let current_board_id = if let Some(current_board_id) = app.state.current_board_id {
+        current_board_id
+    } else {
+        return AppReturn::Continue;
+    };
+    let boards: &mut Boards = if app.filtered_boards.is_empty() {
+        &mut app.boards
+    } else {
+        &mut app.filtered_boards
+    };
+    let current_board = if let Some(current_board) = boards.get_mut_board_with_id(current_board_id)
+    {
+        current_board
+    } else {
+        debug!("No board available to delete cards from");
+        return AppReturn::Continue;
+    };
+    let first_card_index = card_ids
+        .first()
+        .and_then(|card_id| current_board.cards.get_card_index(*card_id))
+        .unwrap_or(0);
+    let mut batch = Vec::new();
+    for card_id in card_ids {
+        if let Some(card) = current_board.cards.remove_card_with_id(card_id) {
+            warn!("Deleted card {}", card.name);
+            batch.push(ActionHistory::DeleteCard(card, current_board_id));
+        }
+    }
+    app.state.current_card_id = if first_card_index > 0 {
+        current_board
+            .cards
+            .get_card_with_index(first_card_index - 1)
+            .map(|card| card.id)
+    } else {
+        current_board.cards.get_first_card_id()
+    };
+    // The filtered copy only shows the cards, they are deleted from the board itself as well
+    if !app.filtered_boards.is_empty() {
+        if let Some(board) = app.boards.get_mut_board_with_id(current_board_id) {
+            for action in &batch {
+                if let ActionHistory::DeleteCard(card, _) = action {
+                    board.cards.remove_card_with_id(card.id);
+                }
+            }
+        }
+    }
+    let info_msg = format!("Deleted {} cards", batch.len());
+    app.action_history_manager
+        .new_action(ActionHistory::Batch(batch));
+    app.state.card_range_selection = None;
+    app.state.selected_card_ids.clear();
+    refresh_visible_cards_for_board(app, current_board_id);
+    app.send_undoable_toast(&info_msg);
+    AppReturn::Continue
+}
+
+/// Moves the current card to the first or last position of its board in one step
+/// Warns and returns true when the current board keeps its cards sorted by priority, a card
+/// moved by hand would only be put back by the next sort
+fn refuse_move_on_auto_sorted_board(app: &mut App) -> bool {
+    let auto_sorted = app
+        .state
+        .current_board_id
+        .and_then(|board_id| app.boards.get_board_with_id(board_id))
+        .is_some_and(|board| board.auto_sort_by_priority);
+    if auto_sorted {
+        app.send_warning_toast(
+            &tr(MessageId::ToastCannotMoveCardsOnAnAutoSortedBoard),
+            None,
+        );
+    }
+    auto_sorted
+}
+
+fn handle_move_card_to_board_edge(app: &mut App, to_top: bool) -> AppReturn {
+    if !View::views_with_kanban_board().contains(&app.state.current_view)
+        || app.state.focus != Focus::Body
+    {
+        return AppReturn::Continue;
+    }
+    let (current_board_id, current_card_id) =
+        match (app.state.current_board_id, app.state.current_card_id) {
+            (Some(board_id), Some(card_id)) => (board_id, card_id),
+            _ => return AppReturn::Continue,
+        };
+    if refuse_move_on_auto_sorted_board(app) {
+        return AppReturn::Continue;
+    }
+    let boards: &mut Boards = if app.filtered_boards.is_empty() {
+        &mut app.boards
+    } else {
+        &mut app.filtered_boards
+    };
+    let current_board = if let Some(board) = boards.get_mut_board_with_id(current_board_id) {
+        board
+    } else {
+        debug!("Cannot move card without a current board index");
+        return AppReturn::Continue;
+    };
+    let moved_from_index = if let Some(index) = current_board.cards.get_card_index(current_card_id)
+    {
+        index
+    } else {
+        debug!("Cannot move card without a current card index");
+        return AppReturn::Continue;
+    };
+    let moved_to_index = if to_top {
+        0
+    } else {
+        current_board.cards.len() - 1
+    };
+    if moved_from_index == moved_to_index {
+        let edge = if to_top { "top" } else { "bottom" };
+        app.send_warning_toast(
+            &format!("Card is already at the {} of the board", edge),
+            None,
+        );
+        return AppReturn::Continue;
+    }
+    let all_cards = current_board.cards.get_mut_all_cards();
+    let card = all_cards.remove(moved_from_index);
+    all_cards.insert(moved_to_index, card);
+    app.action_history_manager
+        .new_action(ActionHistory::MoveCardWithinBoard(
+            current_board_id,
+            moved_from_index,
+            moved_to_index,
+        ));
+    refresh_visible_cards_for_board(app, current_board_id);
+    AppReturn::Continue
+}
+
+/// Moves every card in the range to the end of the neighbouring board, keeping their order
+fn handle_move_card_range(app: &mut App, card_ids: Vec<(u64, u64)>, move_right: bool) -> AppReturn {
+    let moved_from_board_id = if let Some(current_board_id) = app.state.current_board_id {
+        current_board_id
+    } else {
+        return AppReturn::Continue;
+    };
+    let boards: &mut Boards = if app.filtered_boards.is_empty() {
+        &mut app.boards
+    } else {
+        &mut app.filtered_boards
+    };
+    let moved_from_board_index = if let Some(index) = boards.get_board_index(moved_from_board_id) {
+        index
+    } else {
+        debug!("Moved from board index is none");
+        app.send_error_toast("Something went wrong, could not find the board", None);
+        return AppReturn::Continue;
+    };
+    let moved_to_board_index = if move_right {
+        moved_from_board_index + 1
+    } else if moved_from_board_index > 0 {
+        moved_from_board_index - 1
+    } else {
+        app.send_error_toast(
+            &tr(MessageId::ToastCannotMoveCardsLeftAsItIsTheFirstBoard),
+            None,
+        );
+        return AppReturn::Continue;
+    };
+    let (moved_to_board_id, moved_to_board_name) =
+        if let Some(board) = boards.get_board_with_index(moved_to_board_index) {
+            (board.id, board.name.clone())
+        } else {
+            app.send_error_toast(
+                &tr(MessageId::ToastCannotMoveCardsRightAsItIsTheLastBoard),
+                None,
+            );
+            return AppReturn::Continue;
+        };
+    let mut batch = Vec::new();
+    let mut moved_card_ids = Vec::new();
+    for card_id in card_ids {
+        let moved_from_board = boards.get_mut_board_with_id(moved_from_board_id).unwrap();
+        let card_index = if let Some(card_index) = moved_from_board.cards.get_card_index(card_id) {
+            card_index
+        } else {
+            continue;
+        };
+        let card = moved_from_board.cards.remove_card_with_id(card_id).unwrap();
+        let moved_to_board = boards.get_mut_board_with_id(moved_to_board_id).unwrap();
+        moved_to_board.cards.add_card(card.clone());
+        moved_card_ids.push(card_id);
+        batch.push(ActionHistory::MoveCardBetweenBoards(
+            card,
+            moved_from_board_id,
+            moved_to_board_id,
+            card_index,
+            moved_to_board.cards.len() - 1,
+        ));
+    }
+    let info_msg = format!(
+        "Moved {} cards to board \"{}\"",
+        batch.len(),
+        moved_to_board_name
+    );
+    app.action_history_manager
+        .new_action(ActionHistory::Batch(batch));
+    // The moved cards stay together so the selection follows them to the new board, the
+    // current card too when it was not one of the marked cards
+    if let Some(range_selection) = &mut app.state.card_range_selection {
+        range_selection.board_id = moved_to_board_id;
+    }
+    if !app
+        .state
+        .current_card_id
+        .is_some_and(|current_card_id| moved_card_ids.contains(&current_card_id))
+    {
+        app.state.current_card_id = moved_card_ids.first().copied();
+    }
+    app.state.current_board_id = Some(moved_to_board_id);
+    refresh_visible_cards_for_board(app, moved_to_board_id);
+    refresh_visible_cards_for_board(app, moved_from_board_id);
+    info!("{}", info_msg);
+    app.send_info_toast(&info_msg, None);
+    AppReturn::Continue
+}
+
+fn toggle_focus_between_submit_and_extra(app: &mut App) {
+    app.state.set_focus(match app.state.focus {
+        Focus::SubmitButton => Focus::ExtraFocus,
+        _ => Focus::SubmitButton,
+    });
+}
+
+pub async fn handle_mouse_action(app: &mut App<'_>, mouse_action: Mouse) -> AppReturn {
+    let mut left_button_pressed = false;
+    let mut right_button_pressed = false;
+    let mut middle_button_pressed = false;
+    let mut mouse_scroll_up = false;
+    let mut mouse_scroll_down = false;
+    let mut mouse_scroll_left = false;
+    let mut mouse_scroll_right = false;
+    match mouse_action {
+        Mouse::Move(x, y) => {
+            app.state.previous_mouse_coordinates = app.state.current_mouse_coordinates;
+            app.state.current_mouse_coordinates = clamp_mouse_coordinates(app, x, y);
+            app.state.mouse_layout_generation = app.state.layout_generation;
+        }
+        Mouse::Drag(x, y) => {
+            app.state.current_mouse_coordinates = clamp_mouse_coordinates(app, x, y);
+            app.state.mouse_layout_generation = app.state.layout_generation;
+            let is_invalid_state = !View::views_with_kanban_board()
+                .contains(&app.state.current_view)
+                || app.state.hovered_card.is_none()
+                || app.state.hovered_board.is_none();
+            if is_invalid_state {
+                return AppReturn::Continue;
+            }
+            if !app.state.card_drag_mode {
+                app.state.card_drag_mode = true;
+            }
+        }
+        Mouse::LeftPress => left_button_pressed = true,
+        Mouse::RightPress => right_button_pressed = true,
+        Mouse::MiddlePress => middle_button_pressed = true,
+        Mouse::ScrollUp => mouse_scroll_up = true,
+        Mouse::ScrollDown => mouse_scroll_down = true,
+        Mouse::ScrollLeft => mouse_scroll_left = true,
+        Mouse::ScrollRight => mouse_scroll_right = true,
+        Mouse::Unknown => {}
+    }
+
+    // Hover results were worked out on a layout the pointer has not been over since the resize,
+    // acting on them would hit whatever moved under the pointer
+    if app.state.mouse_layout_generation != app.state.layout_generation
+        && !matches!(mouse_action, Mouse::Move(..) | Mouse::Drag(..))
+    {
+        debug!(
+            "Ignoring {} from before the terminal was resized",
+            mouse_action
+        );
+        reset_card_drag_mode(app);
+        return AppReturn::Continue;
+    }
+
+    if let Some(mouse_action) = &app.state.last_mouse_action {
+        match mouse_action {
+            Mouse::Drag(_, _) => {
+                if left_button_pressed || right_button_pressed || middle_button_pressed {
+                    left_button_pressed = false;
+                    right_button_pressed = false;
+                    middle_button_pressed = false;
+                    let is_drop_target_visible =
+                        app.state.hovered_board.is_some_and(|hovered_board| {
+                            app.visible_boards_and_cards.contains_key(&hovered_board)
+                        });
+                    if app.state.hovered_card.is_some() && is_drop_target_visible {
+                        move_dragged_card(app);
+                        reset_card_drag_mode(app);
+                        refresh_visible_boards_and_cards(app);
+                    }
+                    reset_card_drag_mode(app);
+                }
+            }
+            Mouse::Unknown => {
+                reset_card_drag_mode(app);
+            }
+            _ => {}
+        }
+    }
+
+    if right_button_pressed {
+        return handle_go_to_previous_view(app).await;
+    }
+
+    if app.state.focus == Focus::Log {
+        if mouse_scroll_down {
+            app.log_next();
+        }
+        if mouse_scroll_up {
+            app.log_prv();
+        }
+    }
+
+    if middle_button_pressed {
+        if app.state.z_stack.is_empty() {
+            app.set_popup(PopUp::CommandPalette);
+        } else {
+            match app.state.z_stack.last().unwrap() {
+                PopUp::CommandPalette => {
+                    app.close_popup();
+                    app.widgets.command_palette.reset(&mut app.state);
+                    app.state.app_status = AppStatus::Initialized;
+                }
+                PopUp::ViewCard => {
+                    if app.state.card_being_edited.is_some() {
+                        app.set_popup(PopUp::ConfirmDiscardCardChanges);
+                    } else {
+                        app.set_popup(PopUp::CommandPalette);
+                    }
+                }
+                PopUp::ConfirmDiscardCardChanges => {
+                    app.close_popup();
+                    app.set_popup(PopUp::CommandPalette);
+                }
+                _ => {
+                    app.set_popup(PopUp::CommandPalette);
+                }
+            }
+        }
+        return AppReturn::Continue;
+    }
+
+    if let (Some(popup), Some(mouse_focus)) = (app.state.z_stack.last(), app.state.mouse_focus) {
+        match popup {
+            PopUp::CommandPalette => {
+                if left_button_pressed {
+                    match mouse_focus {
+                        Focus::CommandPaletteCommand => {
+                            return CommandPaletteWidget::handle_command(app).await;
+                        }
+                        Focus::CommandPaletteCard => {
+                            handle_command_palette_card_selection(app);
+                            app.close_popup();
+                            app.widgets.command_palette.reset(&mut app.state);
+                            app.state.app_status = AppStatus::Initialized;
                         }
                         Focus::CommandPaletteBoard => {
                             handle_command_palette_board_selection(app);
                             app.close_popup();
-                            app.widgets.command_palette.reset(&mut app.state);
-                            app.state.app_status = AppStatus::Initialized;
+                            app.widgets.command_palette.reset(&mut app.state);
+                            app.state.app_status = AppStatus::Initialized;
+                        }
+                        Focus::CloseButton => {
+                            app.close_popup();
+                            app.widgets.command_palette.reset(&mut app.state);
+                            app.state.app_status = AppStatus::Initialized;
+                        }
+                        _ => {}
+                    }
+                } else if mouse_scroll_up {
+                    match mouse_focus {
+                        Focus::CommandPaletteCommand => {
+                            app.command_palette_command_search_prv();
+                        }
+                        Focus::CommandPaletteCard => app.command_palette_card_search_prv(),
+                        Focus::CommandPaletteBoard => app.command_palette_board_search_prv(),
+                        _ => {}
+                    }
+                } else if mouse_scroll_down {
+                    match mouse_focus {
+                        Focus::CommandPaletteCommand => {
+                            app.command_palette_command_search_next();
+                        }
+                        Focus::CommandPaletteCard => {
+                            app.command_palette_card_search_next();
+                        }
+                        Focus::CommandPaletteBoard => {
+                            app.command_palette_board_search_next();
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            PopUp::SelectDefaultView => {
+                if left_button_pressed {
+                    match mouse_focus {
+                        Focus::SelectDefaultView => {
+                            handle_default_view_selection(app);
+                            app.close_popup();
+                        }
+                        Focus::CloseButton => {
+                            app.close_popup();
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            PopUp::ChangeView => {
+                if left_button_pressed {
+                    match mouse_focus {
+                        Focus::ChangeViewPopup => {
+                            handle_change_view(app);
+                            app.close_popup();
+                        }
+                        Focus::CloseButton => {
+                            app.close_popup();
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            PopUp::CardStatusSelector => {
+                if left_button_pressed {
+                    match mouse_focus {
+                        Focus::ChangeCardStatusPopup => {
+                            return handle_change_card_status(app, None);
+                        }
+                        Focus::CloseButton => {
+                            app.close_popup();
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            PopUp::EditGeneralConfig => {
+                if left_button_pressed {
+                    match mouse_focus {
+                        Focus::EditGeneralConfigPopup => {
+                            app.state.app_status = AppStatus::UserInput;
+                        }
+                        Focus::CloseButton => {
+                            app.state.app_status = AppStatus::Initialized;
+                            app.close_popup();
+                        }
+                        Focus::SubmitButton => {
+                            if app.state.current_view == View::CreateTheme {
+                                handle_create_theme_action(app);
+                            } else {
+                                handle_edit_general_config(app);
+                            }
+                            app.state.app_status = AppStatus::Initialized;
+                            app.close_popup();
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            PopUp::EditSpecificKeyBinding => {
+                if left_button_pressed {
+                    match mouse_focus {
+                        Focus::EditSpecificKeyBindingPopup => {
+                            app.state.app_status = AppStatus::KeyBindMode;
+                        }
+                        Focus::CloseButton => {
+                            app.state.app_status = AppStatus::Initialized;
+                            if !return_to_command_palette_after_keybinding_edit(app) {
+                                app.close_popup();
+                            }
+                        }
+                        Focus::SubmitButton => {
+                            handle_edit_specific_keybinding(app);
+                            app.state.app_status = AppStatus::Initialized;
+                            if !return_to_command_palette_after_keybinding_edit(app) {
+                                app.close_popup();
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            PopUp::ChangeDateFormatPopup => {
+                if left_button_pressed {
+                    match mouse_focus {
+                        Focus::ChangeDateFormatPopup => {
+                            handle_change_date_format(app);
+                        }
+                        Focus::CloseButton => {
+                            app.close_popup();
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            PopUp::ChangeTheme => {
+                if left_button_pressed {
+                    match mouse_focus {
+                        Focus::ThemeSelector => {
+                            handle_change_theme(app, app.state.default_theme_mode);
+                            app.close_popup();
+                        }
+                        Focus::CloseButton => {
+                            let config_theme = {
+                                let all_themes = Theme::all_default_themes();
+                                let default_theme = app.config.default_theme.clone();
+                                all_themes.iter().find(|t| t.name == default_theme).cloned()
+                            };
+                            if let Some(theme) = config_theme {
+                                app.current_theme = theme;
+                            }
+                            app.close_popup();
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            PopUp::EditThemeStyle => {
+                if left_button_pressed {
+                    match mouse_focus {
+                        Focus::CloseButton => {
+                            app.state.app_status = AppStatus::Initialized;
+                            app.close_popup();
+                        }
+                        Focus::SubmitButton
+                        | Focus::StyleEditorFG
+                        | Focus::StyleEditorBG
+                        | Focus::StyleEditorModifier
+                        | Focus::ExtraFocus => {
+                            handle_create_theme_action(app);
+                        }
+                        _ => {}
+                    }
+                } else if mouse_scroll_up {
+                    handle_theme_maker_scroll_up(app);
+                } else if mouse_scroll_down {
+                    handle_theme_maker_scroll_down(app);
+                }
+            }
+            PopUp::SaveThemePrompt => {
+                if left_button_pressed {
+                    match mouse_focus {
+                        Focus::SubmitButton | Focus::ExtraFocus => {
+                            handle_save_theme_prompt(app);
+                        }
+                        Focus::CloseButton => {
+                            app.close_popup();
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            PopUp::CustomHexColorPromptFG => {
+                if left_button_pressed {
+                    match mouse_focus {
+                        Focus::SubmitButton => {
+                            handle_custom_hex_color_prompt(app, true);
+                        }
+                        Focus::TextInput => {
+                            app.state.app_status = AppStatus::UserInput;
+                        }
+                        Focus::CloseButton => {
+                            app.close_popup();
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            PopUp::CustomHexColorPromptBG => {
+                if left_button_pressed {
+                    match mouse_focus {
+                        Focus::SubmitButton => {
+                            handle_custom_hex_color_prompt(app, false);
+                        }
+                        Focus::TextInput => {
+                            app.state.app_status = AppStatus::UserInput;
+                        }
+                        Focus::CloseButton => {
+                            app.close_popup();
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            PopUp::ImportTrelloPrompt
+            | PopUp::ImportJsonPrompt
+            | PopUp::ImportBoardJsonPrompt
+            | PopUp::ImportGithubPrompt => {
+                if left_button_pressed {
+                    match mouse_focus {
+                        Focus::SubmitButton => {
+                            return handle_import_prompt(app).await;
+                        }
+                        Focus::TextInput => {
+                            app.state.app_status = AppStatus::UserInput;
+                        }
+                        Focus::CloseButton => {
+                            app.close_popup();
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            PopUp::SearchCards => {
+                if left_button_pressed {
+                    match mouse_focus {
+                        Focus::TextInput => {
+                            app.state.app_status = AppStatus::UserInput;
+                        }
+                        Focus::CloseButton => {
+                            clear_card_search(app);
+                            app.close_popup();
                         }
+                        _ => {}
+                    }
+                }
+            }
+            PopUp::ViewCard => {
+                if left_button_pressed {
+                    match mouse_focus {
                         Focus::CloseButton => {
                             app.close_popup();
-                            app.widgets.command_palette.reset(&mut app.state);
+                        }
+                        Focus::CardName
+                        | Focus::CardDescription
+                        | Focus::CardEstimate
+                        | Focus::CardEstimatedTime
+                        | Focus::CardActualTime
+                        | Focus::CardWaitingOn
+                        | Focus::CardComments
+                        | Focus::CardChecklist => return handle_edit_new_card(app),
+                        Focus::CardTags => {
+                            if app.state.card_being_edited.is_none() {
+                                handle_edit_new_card(app);
+                            }
+                            if left_button_pressed {
+                                handle_tag_picker_action(app);
+                            } else if mouse_scroll_up {
+                                app.tag_picker_prv();
+                            } else if mouse_scroll_down {
+                                app.tag_picker_next();
+                            }
+                            return AppReturn::Continue;
+                        }
+                        Focus::CardPriority => {
+                            if app.state.card_being_edited.is_none() {
+                                handle_edit_new_card(app);
+                            }
+                            app.set_popup(PopUp::CardPrioritySelector);
+                            return AppReturn::Continue;
+                        }
+                        Focus::CardColor => {
+                            if app.state.card_being_edited.is_none() {
+                                handle_edit_new_card(app);
+                            }
+                            open_card_highlight_color_selector(app);
+                            return AppReturn::Continue;
+                        }
+                        Focus::CardStatus => {
+                            if app.state.card_being_edited.is_none() {
+                                handle_edit_new_card(app);
+                            }
+                            app.set_popup(PopUp::CardStatusSelector);
+                            return AppReturn::Continue;
+                        }
+                        Focus::CardStartDate | Focus::CardDueDate => {
+                            if app.state.card_being_edited.is_none() {
+                                handle_edit_new_card(app);
+                            }
+                            open_card_date_picker(app);
+                            return AppReturn::Continue;
+                        }
+                        Focus::SubmitButton => return handle_edit_card_submit(app),
+                        Focus::SaveCardAsTemplate => {
+                            handle_save_card_as_template(app);
+                            return AppReturn::Continue;
+                        }
+                        _ => {}
+                    }
+                } else if mouse_scroll_down && (mouse_focus == Focus::CardDescription) {
+                    app.state.text_buffers.card_description.scroll((1, 0))
+                } else if mouse_scroll_up && (mouse_focus == Focus::CardDescription) {
+                    app.state.text_buffers.card_description.scroll((-1, 0))
+                }
+            }
+            PopUp::CardPrioritySelector => {
+                if left_button_pressed {
+                    match mouse_focus {
+                        Focus::CloseButton => {
                             app.state.app_status = AppStatus::Initialized;
+                            if app.state.card_being_edited.is_some() {
+                                app.set_popup(PopUp::ConfirmDiscardCardChanges);
+                            }
+                        }
+                        Focus::ChangeCardPriorityPopup => {
+                            return handle_change_card_priority(app, None)
                         }
                         _ => {}
                     }
-                } else if mouse_scroll_up {
+                }
+            }
+            PopUp::CardHighlightColorSelector => {
+                if left_button_pressed {
                     match mouse_focus {
-                        Focus::CommandPaletteCommand => {
-                            app.command_palette_command_search_prv();
+                        Focus::CloseButton => {
+                            app.state.app_status = AppStatus::Initialized;
+                            app.close_popup();
+                        }
+                        Focus::ChangeCardHighlightColorPopup => {
+                            return handle_change_card_highlight_color(app)
                         }
-                        Focus::CommandPaletteCard => app.command_palette_card_search_prv(),
-                        Focus::CommandPaletteBoard => app.command_palette_board_search_prv(),
                         _ => {}
                     }
-                } else if mouse_scroll_down {
+                }
+            }
+            PopUp::BoardAccentColorSelector => {
+                if left_button_pressed {
                     match mouse_focus {
-                        Focus::CommandPaletteCommand => {
-                            app.command_palette_command_search_next();
+                        Focus::CloseButton => {
+                            app.state.app_status = AppStatus::Initialized;
+                            app.close_popup();
                         }
-                        Focus::CommandPaletteCard => {
-                            app.command_palette_card_search_next();
+                        Focus::ChangeBoardAccentColorPopup => {
+                            return handle_change_board_accent_color(app)
                         }
-                        Focus::CommandPaletteBoard => {
-                            app.command_palette_board_search_next();
+                        _ => {}
+                    }
+                }
+            }
+            PopUp::SortCardsBy => {
+                if left_button_pressed {
+                    match mouse_focus {
+                        Focus::CloseButton => {
+                            app.state.app_status = AppStatus::Initialized;
+                            app.close_popup();
                         }
+                        Focus::SortCardsByPopup => return handle_sort_cards_by(app),
                         _ => {}
                     }
                 }
             }
-            PopUp::SelectDefaultView => {
+            PopUp::CalendarDayCards => {
                 if left_button_pressed {
                     match mouse_focus {
-                        Focus::SelectDefaultView => {
-                            handle_default_view_selection(app);
+                        Focus::CloseButton => {
+                            app.state.app_status = AppStatus::Initialized;
                             app.close_popup();
                         }
+                        Focus::CalendarDayCardsPopup => handle_calendar_day_cards(app),
+                        _ => {}
+                    }
+                }
+            }
+            PopUp::WaitingOnList => {
+                if left_button_pressed {
+                    match mouse_focus {
                         Focus::CloseButton => {
+                            app.state.app_status = AppStatus::Initialized;
                             app.close_popup();
                         }
+                        Focus::WaitingOnListPopup => handle_waiting_on_list(app),
                         _ => {}
                     }
                 }
             }
-            PopUp::ChangeView => {
+            PopUp::SelectCardTemplate => {
                 if left_button_pressed {
                     match mouse_focus {
-                        Focus::ChangeViewPopup => {
-                            handle_change_view(app);
+                        Focus::CloseButton => {
+                            app.state.app_status = AppStatus::Initialized;
                             app.close_popup();
                         }
+                        Focus::SelectCardTemplatePopup => handle_select_card_template(app),
+                        _ => {}
+                    }
+                }
+            }
+            PopUp::MoveCardToBoard => {
+                if left_button_pressed {
+                    match mouse_focus {
                         Focus::CloseButton => {
+                            app.state.app_status = AppStatus::Initialized;
                             app.close_popup();
                         }
+                        Focus::MoveCardToBoardPopup => return handle_move_card_to_board(app),
                         _ => {}
                     }
                 }
             }
-            PopUp::CardStatusSelector => {
+            PopUp::SetLogLevel => {
                 if left_button_pressed {
                     match mouse_focus {
-                        Focus::ChangeCardStatusPopup => {
-                            return handle_change_card_status(app, None);
+                        Focus::CloseButton => {
+                            app.state.app_status = AppStatus::Initialized;
+                            app.close_popup();
                         }
+                        Focus::SetLogLevelPopup => return handle_set_log_level(app),
+                        _ => {}
+                    }
+                }
+            }
+            PopUp::FilterByDateRange => {
+                if left_button_pressed {
+                    match mouse_focus {
                         Focus::CloseButton => {
+                            app.state.app_status = AppStatus::Initialized;
                             app.close_popup();
                         }
+                        Focus::FilterByDateRangePopup => handle_filter_by_date_range(app),
                         _ => {}
                     }
+                } else if mouse_scroll_up && mouse_focus == Focus::FilterByDateRangePopup {
+                    app.filter_by_date_range_popup_prv();
+                } else if mouse_scroll_down && mouse_focus == Focus::FilterByDateRangePopup {
+                    app.filter_by_date_range_popup_next();
                 }
             }
-            PopUp::EditGeneralConfig => {
+            PopUp::CustomDateRangePrompt => {
                 if left_button_pressed {
                     match mouse_focus {
-                        Focus::EditGeneralConfigPopup => {
+                        Focus::SubmitButton => {
+                            handle_custom_date_range_prompt(app);
+                        }
+                        Focus::TextInput => {
                             app.state.app_status = AppStatus::UserInput;
                         }
+                        Focus::CloseButton => {
+                            app.close_popup();
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            PopUp::FilterByPriority => {
+                if left_button_pressed {
+                    match mouse_focus {
+                        Focus::CloseButton => {
+                            app.state.app_status = AppStatus::Initialized;
+                            app.close_popup();
+                        }
+                        Focus::FilterByPriorityPopup => handle_toggle_priority_filter(app),
+                        _ => {}
+                    }
+                } else if mouse_scroll_up && mouse_focus == Focus::FilterByPriorityPopup {
+                    app.filter_by_priority_popup_prv();
+                } else if mouse_scroll_down && mouse_focus == Focus::FilterByPriorityPopup {
+                    app.filter_by_priority_popup_next();
+                }
+            }
+            PopUp::FilterByStatus => {
+                if left_button_pressed {
+                    match mouse_focus {
                         Focus::CloseButton => {
                             app.state.app_status = AppStatus::Initialized;
                             app.close_popup();
                         }
+                        Focus::FilterByStatusPopup => handle_toggle_status_filter(app),
+                        _ => {}
+                    }
+                } else if mouse_scroll_up && mouse_focus == Focus::FilterByStatusPopup {
+                    app.filter_by_status_popup_prv();
+                } else if mouse_scroll_down && mouse_focus == Focus::FilterByStatusPopup {
+                    app.filter_by_status_popup_next();
+                }
+            }
+            PopUp::ConfirmDiscardCardChanges => {
+                if left_button_pressed {
+                    match mouse_focus {
+                        Focus::CloseButton | Focus::ExtraFocus => {
+                            app.close_popup();
+                        }
                         Focus::SubmitButton => {
-                            if app.state.current_view == View::CreateTheme {
-                                handle_create_theme_action(app);
-                            } else {
-                                handle_edit_general_config(app);
-                            }
                             app.state.app_status = AppStatus::Initialized;
                             app.close_popup();
+                            return handle_edit_card_submit(app);
                         }
                         _ => {}
                     }
                 }
             }
-            PopUp::EditSpecificKeyBinding => {
+            PopUp::ConfirmKeepDraft => {
                 if left_button_pressed {
                     match mouse_focus {
-                        Focus::EditSpecificKeyBindingPopup => {
-                            app.state.app_status = AppStatus::KeyBindMode;
+                        Focus::CloseButton | Focus::SubmitButton | Focus::ExtraFocus => {
+                            app.state.set_focus(mouse_focus);
+                            handle_keep_draft_prompt(app);
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            PopUp::ConfirmClearWaitingOn => {
+                if left_button_pressed {
+                    match mouse_focus {
+                        Focus::CloseButton | Focus::SubmitButton | Focus::ExtraFocus => {
+                            app.state.set_focus(mouse_focus);
+                            handle_clear_waiting_on_prompt(app);
                         }
+                        _ => {}
+                    }
+                }
+            }
+            PopUp::ConfirmRestoreThemesFromBackup => {
+                if left_button_pressed {
+                    match mouse_focus {
+                        Focus::CloseButton | Focus::ExtraFocus => {
+                            app.close_popup();
+                        }
+                        Focus::SubmitButton => {
+                            app.state.set_focus(Focus::SubmitButton);
+                            handle_restore_themes_from_backup_prompt(app);
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            PopUp::ImportOptions => {
+                if left_button_pressed {
+                    match mouse_focus {
                         Focus::CloseButton => {
-                            app.state.app_status = AppStatus::Initialized;
+                            app.close_popup();
+                        }
+                        Focus::SubmitButton | Focus::ExtraFocus => {
+                            app.state.set_focus(mouse_focus);
+                            handle_import_options(app);
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            PopUp::RecoverSaveFile => {
+                if left_button_pressed {
+                    match mouse_focus {
+                        Focus::CloseButton | Focus::ExtraFocus => {
                             app.close_popup();
                         }
                         Focus::SubmitButton => {
-                            handle_edit_specific_keybinding(app);
-                            app.state.app_status = AppStatus::Initialized;
+                            app.state.set_focus(Focus::SubmitButton);
+                            handle_save_recovery_prompt(app);
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            PopUp::MirrorSyncSummary => {
+                if left_button_pressed {
+                    match mouse_focus {
+                        Focus::CloseButton | Focus::ExtraFocus => {
+                            app.close_popup();
+                        }
+                        Focus::SubmitButton => {
+                            app.state.set_focus(Focus::SubmitButton);
+                            handle_mirror_sync_prompt(app);
+                        }
+                        Focus::MirrorSyncConflicts => {
+                            toggle_selected_mirror_conflict_resolution(app);
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            PopUp::WhatsNew | PopUp::GithubImportSummary | PopUp::BoardStats => {
+                if left_button_pressed && mouse_focus == Focus::CloseButton {
+                    app.close_popup();
+                }
+            }
+            PopUp::ConflictMarkerWarning => {
+                if left_button_pressed {
+                    match mouse_focus {
+                        Focus::CloseButton => {
+                            app.close_popup();
+                        }
+                        Focus::ConflictMarkerCards => {
+                            jump_to_conflict_marker_card(app);
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            PopUp::Notifications => {
+                if left_button_pressed {
+                    match mouse_focus {
+                        Focus::CloseButton => {
                             app.close_popup();
                         }
+                        Focus::SubmitButton => {
+                            app.state.notifications.mark_all_read();
+                        }
                         _ => {}
                     }
                 }
             }
-            PopUp::ChangeDateFormatPopup => {
+            PopUp::ConfirmDuplicateCardName => {
                 if left_button_pressed {
                     match mouse_focus {
-                        Focus::ChangeDateFormatPopup => {
-                            handle_change_date_format(app);
-                        }
                         Focus::CloseButton => {
                             app.close_popup();
                         }
+                        Focus::SubmitButton | Focus::ExtraFocus | Focus::CancelButton => {
+                            app.state.set_focus(mouse_focus);
+                            handle_duplicate_card_name_prompt(app);
+                        }
                         _ => {}
                     }
                 }
             }
-            PopUp::ChangeTheme => {
+            PopUp::FindReplace => {
                 if left_button_pressed {
                     match mouse_focus {
-                        Focus::ThemeSelector => {
-                            handle_change_theme(app, app.state.default_theme_mode);
+                        Focus::CloseButton | Focus::CancelButton => {
                             app.close_popup();
                         }
-                        Focus::CloseButton => {
-                            let config_theme = {
-                                let all_themes = Theme::all_default_themes();
-                                let default_theme = app.config.default_theme.clone();
-                                all_themes.iter().find(|t| t.name == default_theme).cloned()
-                            };
-                            if let Some(theme) = config_theme {
-                                app.current_theme = theme;
-                            }
-                            app.close_popup();
+                        Focus::SubmitButton => {
+                            return handle_find_replace(app);
+                        }
+                        Focus::FindReplaceQuery | Focus::FindReplaceReplacement => {
+                            app.state.set_focus(mouse_focus);
+                            app.state.app_status = AppStatus::UserInput;
                         }
                         _ => {}
                     }
                 }
             }
-            PopUp::EditThemeStyle => {
+            PopUp::DuplicateBoards => {
                 if left_button_pressed {
                     match mouse_focus {
                         Focus::CloseButton => {
-                            app.state.app_status = AppStatus::Initialized;
                             app.close_popup();
                         }
-                        Focus::SubmitButton
-                        | Focus::StyleEditorFG
-                        | Focus::StyleEditorBG
-                        | Focus::StyleEditorModifier
-                        | Focus::ExtraFocus => {
-                            handle_create_theme_action(app);
+                        Focus::SubmitButton | Focus::ExtraFocus | Focus::CancelButton => {
+                            app.state.set_focus(mouse_focus);
+                            handle_duplicate_boards_action(app);
                         }
                         _ => {}
                     }
-                } else if mouse_scroll_up {
-                    handle_theme_maker_scroll_up(app);
-                } else if mouse_scroll_down {
-                    handle_theme_maker_scroll_down(app);
                 }
             }
-            PopUp::SaveThemePrompt => {
+            PopUp::ConfirmQuit => {
                 if left_button_pressed {
                     match mouse_focus {
-                        Focus::SubmitButton | Focus::ExtraFocus => {
-                            handle_save_theme_prompt(app);
-                        }
                         Focus::CloseButton => {
                             app.close_popup();
                         }
+                        Focus::SubmitButton | Focus::ExtraFocus | Focus::CancelButton => {
+                            app.state.set_focus(mouse_focus);
+                            return handle_confirm_quit(app).await;
+                        }
                         _ => {}
                     }
                 }
             }
-            PopUp::CustomHexColorPromptFG => {
+            PopUp::FilterByTag => {
                 if left_button_pressed {
                     match mouse_focus {
-                        Focus::SubmitButton => {
-                            handle_custom_hex_color_prompt(app, true);
-                        }
-                        Focus::TextInput => {
-                            app.state.app_status = AppStatus::UserInput;
+                        Focus::FilterByTagPopup => {
+                            handle_filter_by_tag(app);
                         }
                         Focus::CloseButton => {
+                            app.state.filter_tags = None;
+                            app.state.all_available_tags = None;
+                            app.state.app_list_states.filter_by_tag_list.select(None);
+                            app.close_popup();
+                        }
+                        Focus::SubmitButton => {
+                            handle_filter_by_tag(app);
                             app.close_popup();
                         }
                         _ => {}
                     }
+                    if app.state.mouse_focus == Some(Focus::FilterByTagPopup) {
+                        handle_filter_by_tag(app);
+                    } else if app.state.mouse_focus == Some(Focus::CloseButton) {
+                        app.state.filter_tags = None;
+                        app.state.all_available_tags = None;
+                        app.state.app_list_states.filter_by_tag_list.select(None);
+                        app.close_popup();
+                    } else if app.state.mouse_focus == Some(Focus::SubmitButton) {
+                        handle_filter_by_tag(app);
+                        app.close_popup();
+                    }
+                } else if mouse_scroll_up && app.state.mouse_focus == Some(Focus::FilterByTagPopup)
+                {
+                    app.filter_by_tag_popup_prv()
+                } else if mouse_scroll_down
+                    && app.state.mouse_focus == Some(Focus::FilterByTagPopup)
+                {
+                    app.filter_by_tag_popup_next()
                 }
             }
-            PopUp::CustomHexColorPromptBG => {
+            PopUp::DateTimePicker => {
                 if left_button_pressed {
-                    match mouse_focus {
-                        Focus::SubmitButton => {
-                            handle_custom_hex_color_prompt(app, false);
-                        }
-                        Focus::TextInput => {
-                            app.state.app_status = AppStatus::UserInput;
-                        }
-                        Focus::CloseButton => {
-                            app.close_popup();
-                        }
+                    handle_date_time_picker_action(app, None, Some(Action::Accept));
+                } else if mouse_scroll_up {
+                    match app.state.focus {
+                        Focus::DTPMonth => app.widgets.date_time_picker.month_next(),
+                        Focus::DTPYear => app.widgets.date_time_picker.year_next(),
+                        Focus::DTPHour => app.widgets.date_time_picker.move_hours_prv(),
+                        Focus::DTPMinute => app.widgets.date_time_picker.move_minutes_prv(),
+                        Focus::DTPSecond => app.widgets.date_time_picker.move_seconds_prv(),
+                        _ => {}
+                    }
+                } else if mouse_scroll_down {
+                    match app.state.focus {
+                        Focus::DTPMonth => app.widgets.date_time_picker.month_prv(),
+                        Focus::DTPYear => app.widgets.date_time_picker.year_prv(),
+                        Focus::DTPHour => app.widgets.date_time_picker.move_hours_next(),
+                        Focus::DTPMinute => app.widgets.date_time_picker.move_minutes_next(),
+                        Focus::DTPSecond => app.widgets.date_time_picker.move_seconds_next(),
                         _ => {}
                     }
                 }
-            }
-            PopUp::ViewCard => {
-                if left_button_pressed {
-                    match mouse_focus {
-                        Focus::CloseButton => {
-                            app.close_popup();
-                        }
-                        Focus::CardName | Focus::CardDescription | Focus::CardComments => {
-                            return handle_edit_new_card(app)
-                        }
-                        Focus::CardTags => {
-                            if app.state.card_being_edited.is_none() {
-                                handle_edit_new_card(app);
-                            }
-                            if left_button_pressed {
-                                handle_tag_picker_action(app);
-                            } else if mouse_scroll_up {
-                                app.tag_picker_prv();
-                            } else if mouse_scroll_down {
-                                app.tag_picker_next();
-                            }
-                            return AppReturn::Continue;
-                        }
-                        Focus::CardPriority => {
-                            if app.state.card_being_edited.is_none() {
-                                handle_edit_new_card(app);
-                            }
-                            app.set_popup(PopUp::CardPrioritySelector);
-                            return AppReturn::Continue;
-                        }
-                        Focus::CardStatus => {
-                            if app.state.card_being_edited.is_none() {
-                                handle_edit_new_card(app);
-                            }
-                            app.set_popup(PopUp::CardStatusSelector);
-                            return AppReturn::Continue;
-                        }
-                        Focus::CardDueDate => {
-                            if app.state.card_being_edited.is_none() {
-                                handle_edit_new_card(app);
-                            }
-                            app.set_popup(PopUp::DateTimePicker);
-                            return AppReturn::Continue;
-                        }
-                        Focus::SubmitButton => return handle_edit_card_submit(app),
-                        _ => {}
-                    }
-                } else if mouse_scroll_down && (mouse_focus == Focus::CardDescription) {
-                    app.state.text_buffers.card_description.scroll((1, 0))
-                } else if mouse_scroll_up && (mouse_focus == Focus::CardDescription) {
-                    app.state.text_buffers.card_description.scroll((-1, 0))
+            }
+            PopUp::TagPicker => {
+                // This is never reached as tag picker does not handle its own actions
+            }
+        }
+    } else {
+        match app.state.current_view {
+            View::Zen
+            | View::TitleBody
+            | View::BodyHelp
+            | View::BodyLog
+            | View::TitleBodyHelp
+            | View::TitleBodyLog
+            | View::TitleBodyHelpLog
+            | View::BodyHelpLog
+            | View::ConfigMenu
+            | View::EditKeybindings
+            | View::HelpMenu
+            | View::NewBoard
+            | View::EditBoard
+            | View::EditBoardNotes
+            | View::NewCard => {
+                if left_button_pressed {
+                    if let Some(value) = handle_left_click_for_view(app).await {
+                        return value;
+                    }
+                } else if mouse_scroll_up
+                    || mouse_scroll_down
+                    || mouse_scroll_right
+                    || mouse_scroll_left
+                {
+                    handle_scroll_for_view(
+                        app,
+                        mouse_scroll_up,
+                        mouse_scroll_down,
+                        mouse_scroll_right,
+                        mouse_scroll_left,
+                    );
+                }
+            }
+            View::MainMenu
+            | View::LogsOnly
+            | View::LoadLocalSave
+            | View::CreateTheme
+            | View::ArchivedCards
+            | View::Calendar
+            | View::Stats
+            | View::Swimlane => {
+                if left_button_pressed {
+                    if let Some(value) = handle_left_click_for_view(app).await {
+                        return value;
+                    }
+                }
+            }
+            View::Login => {
+                if left_button_pressed {
+                    handle_login_action(app).await
+                }
+            }
+            View::SignUp => {
+                if left_button_pressed {
+                    handle_signup_action(app).await
+                }
+            }
+            View::ResetPassword => {
+                if left_button_pressed {
+                    handle_reset_password_action(app).await
+                }
+            }
+            View::LoadCloudSave => {
+                if left_button_pressed {
+                    if app.state.mouse_focus == Some(Focus::CloseButton) {
+                        handle_go_to_previous_view(app).await;
+                    } else if app.state.mouse_focus == Some(Focus::LoadSave)
+                        && app.state.app_list_states.load_save.selected().is_some()
+                        && app.state.cloud_data.is_some()
+                    {
+                        app.dispatch(IoEvent::LoadCloudPreview).await;
+                    }
+                }
+            }
+        }
+    }
+    app.state.last_mouse_action = Some(mouse_action);
+    AppReturn::Continue
+}
+
+/// Keeps reported coordinates inside the last rendered frame, a resize can leave the terminal
+/// reporting positions the frame no longer covers
+fn clamp_mouse_coordinates(app: &App, x: u16, y: u16) -> (u16, u16) {
+    let frame_area = app.state.last_frame_area;
+    if frame_area.is_empty() {
+        return (x, y);
+    }
+    (
+        x.min(frame_area.right().saturating_sub(1)),
+        y.min(frame_area.bottom().saturating_sub(1)),
+    )
+}
+
+/// Whether the card still exists and is in the window of cards on screen, a click can be
+/// handled after the board it landed on scrolled away
+fn is_card_visible(app: &App, board_id: (u64, u64), card_id: (u64, u64)) -> bool {
+    app.visible_boards_and_cards
+        .get(&board_id)
+        .is_some_and(|card_ids| card_ids.contains(&card_id))
+        && app
+            .boards
+            .get_board_with_id(board_id)
+            .and_then(|board| board.cards.get_card_with_id(card_id))
+            .is_some()
+}
+
+async fn handle_left_click_for_view(app: &mut App<'_>) -> Option<AppReturn> {
+    let prv_view = app.state.current_view;
+    app.state.mouse_focus?;
+    // TODO: investigate if we really need to have mouse focus separate from focus
+    let mouse_focus = app.state.mouse_focus.unwrap();
+    match mouse_focus {
+        Focus::Title => {
+            app.set_view(View::MainMenu);
+            if app.state.app_list_states.main_menu.selected().is_none() {
+                app.main_menu_next()
+            }
+        }
+        Focus::Body => {
+            let is_card_visible = app
+                .state
+                .current_board_id
+                .zip(app.state.current_card_id)
+                .is_some_and(|(board_id, card_id)| is_card_visible(app, board_id, card_id));
+            if !is_card_visible {
+                app.send_error_toast("No card selected", None);
+                return Some(AppReturn::Continue);
+            }
+            app.set_popup(PopUp::ViewCard);
+        }
+        Focus::Help => {
+            app.set_view(View::HelpMenu);
+        }
+        Focus::Log => {
+            app.set_view(View::LogsOnly);
+        }
+        Focus::ConfigTable => {
+            return Some(handle_config_menu_action(app));
+        }
+        Focus::EditKeybindingsTable => {
+            handle_edit_keybindings_action(app);
+        }
+        Focus::CloseButton => match prv_view {
+            View::Zen
+            | View::TitleBody
+            | View::BodyHelp
+            | View::BodyLog
+            | View::TitleBodyHelp
+            | View::TitleBodyLog
+            | View::BodyHelpLog
+            | View::TitleBodyHelpLog
+            | View::MainMenu => {
+                return Some(handle_exit(app).await);
+            }
+            View::EditBoard => {
+                reset_edit_board_form(app);
+                handle_go_to_previous_view(app).await;
+            }
+            View::EditBoardNotes => {
+                reset_edit_board_notes_form(app);
+                handle_go_to_previous_view(app).await;
+            }
+            View::CreateTheme => {
+                app.state.theme_being_edited = Theme::default();
+                handle_go_to_previous_view(app).await;
+            }
+            _ => {
+                handle_go_to_previous_view(app).await;
+            }
+        },
+        Focus::SubmitButton => {
+            app.state.set_focus(Focus::SubmitButton);
+            match prv_view {
+                View::EditKeybindings => {
+                    handle_edit_keybindings_action(app);
+                }
+                View::NewBoard => {
+                    let stayed_in_form = handle_new_board_action(app, app.config.rapid_card_entry);
+                    if !stayed_in_form {
+                        app.state.app_status = AppStatus::Initialized;
+                    }
+                }
+                View::EditBoard => {
+                    handle_edit_board_action(app);
+                    app.state.app_status = AppStatus::Initialized;
+                }
+                View::EditBoardNotes => {
+                    handle_edit_board_notes_action(app);
+                    app.state.app_status = AppStatus::Initialized;
+                }
+                View::NewCard => {
+                    let stayed_in_form =
+                        handle_new_card_action(app, app.config.rapid_card_entry, false);
+                    if !stayed_in_form {
+                        app.state.app_status = AppStatus::Initialized;
+                    }
+                }
+                View::ConfigMenu => {
+                    return Some(handle_config_menu_action(app));
+                }
+                View::CreateTheme => {
+                    return Some(handle_create_theme_action(app));
                 }
+                _ => {}
             }
-            PopUp::CardPrioritySelector => {
-                if left_button_pressed {
-                    match mouse_focus {
-                        Focus::CloseButton => {
-                            app.state.app_status = AppStatus::Initialized;
-                            if app.state.card_being_edited.is_some() {
-                                app.set_popup(PopUp::ConfirmDiscardCardChanges);
-                            }
-                        }
-                        Focus::ChangeCardPriorityPopup => {
-                            return handle_change_card_priority(app, None)
+        }
+        Focus::ExtraFocus => {
+            if prv_view == View::ConfigMenu {
+                return Some(handle_config_menu_action(app));
+            } else if prv_view == View::CreateTheme {
+                return Some(handle_create_theme_action(app));
+            }
+        }
+        Focus::MainMenu => {
+            return Some(handle_main_menu_action(app).await);
+        }
+        Focus::NewBoardName
+        | Focus::NewBoardDescription
+        | Focus::BoardNotes
+        | Focus::CardName
+        | Focus::CardDescription => {
+            app.state.app_status = AppStatus::UserInput;
+            info!("Taking user input");
+        }
+        Focus::CardStartDate | Focus::CardDueDate => {
+            if app.state.card_being_edited.is_none() {
+                handle_edit_new_card(app);
+            }
+            open_card_date_picker(app);
+        }
+        Focus::UseTemplate => {
+            app.state.app_status = AppStatus::Initialized;
+            open_card_template_selector(app);
+        }
+        Focus::LoadSave => {
+            if app.state.app_list_states.load_save.selected().is_some() {
+                app.dispatch(IoEvent::LoadLocalPreview).await;
+            }
+        }
+        Focus::ThemeEditor => {
+            return Some(handle_create_theme_action(app));
+        }
+        _ => {}
+    }
+    None
+}
+
+fn handle_scroll_for_view(
+    app: &mut App<'_>,
+    mouse_scroll_up: bool,
+    mouse_scroll_down: bool,
+    mouse_scroll_right: bool,
+    mouse_scroll_left: bool,
+) {
+    if mouse_scroll_up {
+        if app.state.mouse_focus == Some(Focus::Body) {
+            scroll_up(app);
+        } else if app.state.mouse_focus == Some(Focus::Help) {
+            app.help_prv();
+        } else if app.state.mouse_focus == Some(Focus::ConfigTable) {
+            app.config_prv();
+        } else if app.state.mouse_focus == Some(Focus::EditKeybindingsTable) {
+            app.edit_keybindings_prv();
+        } else if app.state.mouse_focus == Some(Focus::NewBoardDescription) {
+            app.state.text_buffers.board_description.scroll((-1, 0))
+        } else if app.state.mouse_focus == Some(Focus::BoardNotes) {
+            app.state.text_buffers.board_notes.scroll((-1, 0))
+        } else if app.state.mouse_focus == Some(Focus::CardDescription) {
+            app.state.text_buffers.card_description.scroll((-1, 0))
+        }
+    } else if mouse_scroll_down {
+        if app.state.mouse_focus == Some(Focus::Body) {
+            scroll_down(app);
+        } else if app.state.mouse_focus == Some(Focus::Help) {
+            app.help_next();
+        } else if app.state.mouse_focus == Some(Focus::ConfigTable) {
+            app.config_next();
+        } else if app.state.mouse_focus == Some(Focus::EditKeybindingsTable) {
+            app.edit_keybindings_next();
+        } else if app.state.mouse_focus == Some(Focus::NewBoardDescription) {
+            app.state.text_buffers.board_description.scroll((1, 0))
+        } else if app.state.mouse_focus == Some(Focus::BoardNotes) {
+            app.state.text_buffers.board_notes.scroll((1, 0))
+        } else if app.state.mouse_focus == Some(Focus::CardDescription) {
+            app.state.text_buffers.card_description.scroll((1, 0))
+        }
+    } else if mouse_scroll_right && app.state.mouse_focus == Some(Focus::Body) {
+        scroll_right(app);
+    } else if mouse_scroll_left && app.state.mouse_focus == Some(Focus::Body) {
+        scroll_left(app);
+    }
+}
+
+fn move_dragged_card(app: &mut App<'_>) {
+    let card_being_dragged = app.state.hovered_card.unwrap();
+    let hovered_board_id = app.state.hovered_board.unwrap();
+    let card_being_dragged_board = card_being_dragged.0;
+    let card_being_dragged_id = card_being_dragged.1;
+    if hovered_board_id == card_being_dragged_board {
+        if app.state.current_card_id.is_none() {
+            debug!("Could not find current card");
+            return;
+        }
+        let hovered_card_id = app.state.current_card_id.unwrap();
+        // same board so swap cards
+        let hovered_board = app.boards.get_board_with_id(hovered_board_id);
+        if hovered_board.is_none() {
+            debug!("Could not find hovered board");
+            return;
+        }
+        let hovered_board = hovered_board.unwrap();
+        let dragged_card_index = hovered_board.cards.get_card_index(card_being_dragged_id);
+        if dragged_card_index.is_none() {
+            debug!("Could not find dragged card");
+            return;
+        }
+        let hovered_card_index = hovered_board.cards.get_card_index(hovered_card_id);
+        if hovered_card_index.is_none() {
+            debug!("Could not find hovered card");
+            return;
+        }
+        let dragged_card_index = dragged_card_index.unwrap();
+        let hovered_card_index = hovered_card_index.unwrap();
+        if dragged_card_index == hovered_card_index {
+            debug!("No need to move card as it is already in the same position");
+            return;
+        }
+        let dragged_card_name = hovered_board
+            .cards
+            .get_card_with_index(dragged_card_index)
+            .unwrap()
+            .name
+            .clone();
+        // swap cards
+        app.boards.get_mut_boards().iter_mut().for_each(|board| {
+            if board.id == hovered_board_id {
+                board.cards.swap(dragged_card_index, hovered_card_index);
+            }
+        });
+        app.action_history_manager
+            .new_action(ActionHistory::MoveCardWithinBoard(
+                hovered_board_id,
+                dragged_card_index,
+                hovered_card_index,
+            ));
+        let info_msg = &format!(
+            "Moved card \"{}\" from index {} to index {}",
+            dragged_card_name, dragged_card_index, hovered_card_index
+        );
+        info!("{}", info_msg);
+        app.send_info_toast(info_msg, None);
+    } else {
+        let app_boards = app.boards.clone();
+        // different board so remove dragged card from current board and add it to the hovered board at the index of the hovered card and push everything else down
+        let dragged_card_board_id = card_being_dragged.0;
+        let hovered_board_id = app.state.hovered_board;
+        if hovered_board_id.is_none() {
+            debug!("Could not find hovered board");
+            return;
+        }
+        let hovered_board_id = hovered_board_id.unwrap();
+        let dragged_card_board = app_boards.get_board_with_id(dragged_card_board_id);
+        if dragged_card_board.is_none() {
+            debug!("Could not find dragged card board");
+            return;
+        }
+        let dragged_card_board = dragged_card_board.unwrap();
+        let hovered_board = app_boards.get_board_with_id(hovered_board_id);
+        if hovered_board.is_none() {
+            debug!("Could not find hovered board");
+            return;
+        }
+        let hovered_board = hovered_board.unwrap();
+        let dragged_card_id = card_being_dragged.1;
+        let hovered_card_id = app.state.current_card_id;
+        let dragged_card_index = dragged_card_board.cards.get_card_index(dragged_card_id);
+        if dragged_card_index.is_none() {
+            debug!("Could not find dragged card");
+            return;
+        }
+        let dragged_card_index = dragged_card_index.unwrap();
+        let dragged_card = dragged_card_board
+            .cards
+            .get_card_with_index(dragged_card_index)
+            .unwrap()
+            .clone();
+        let dragged_card_name = dragged_card.name.clone();
+        if hovered_card_id.is_none() {
+            // check if hovered board is empty
+            if hovered_board.cards.is_empty() {
+                debug!("hovered board is empty");
+                // add dragged card to hovered board
+                app.boards.get_mut_boards().iter_mut().for_each(|board| {
+                    if board.id == hovered_board_id {
+                        board.cards.add_card_at_index(0, dragged_card.clone());
+                    }
+                });
+                // remove dragged card from current board
+                app.boards.get_mut_boards().iter_mut().for_each(|board| {
+                    // check if index is valid
+                    if board.id == dragged_card_board_id {
+                        if board.cards.len() > dragged_card_index {
+                            board.cards.remove_card_with_id(dragged_card_id);
+                        } else {
+                            debug!("Invalid Index for dragged card, board_id: {:?}, dragged_card_index: {}", dragged_card_board_id, dragged_card_index);
                         }
-                        _ => {}
                     }
-                }
+                });
+                app.action_history_manager
+                    .new_action(ActionHistory::MoveCardBetweenBoards(
+                        dragged_card,
+                        dragged_card_board_id,
+                        hovered_board_id,
+                        dragged_card_index,
+                        0,
+                    ));
+                let info_msg = &format!(
+                    "Moved card \"{}\" to board \"{}\"",
+                    dragged_card_name, hovered_board.name
+                );
+                info!("{}", info_msg);
+                app.send_info_toast(info_msg, None);
+                return;
+            } else {
+                debug!("Could not find hovered card");
+                let error_msg = "Moving card failed, could not find hovered card";
+                error!("{}", error_msg);
+                app.send_error_toast(error_msg, None);
+                return;
             }
-            PopUp::ConfirmDiscardCardChanges => {
-                if left_button_pressed {
-                    match mouse_focus {
-                        Focus::CloseButton | Focus::ExtraFocus => {
-                            app.close_popup();
-                        }
-                        Focus::SubmitButton => {
-                            app.state.app_status = AppStatus::Initialized;
-                            app.close_popup();
-                            return handle_edit_card_submit(app);
-                        }
-                        _ => {}
-                    }
+        }
+        let hovered_card_id = hovered_card_id.unwrap();
+        if hovered_card_id == dragged_card_id {
+            // the hovered board is empty just move the dragged card to the hovered
+            // board (Special case) as it was the last card that was hovered
+            app.boards.get_mut_boards().iter_mut().for_each(|board| {
+                if board.id == hovered_board_id {
+                    board.cards.add_card_at_index(0, dragged_card.clone());
                 }
-            }
-            PopUp::FilterByTag => {
-                if left_button_pressed {
-                    match mouse_focus {
-                        Focus::FilterByTagPopup => {
-                            handle_filter_by_tag(app);
-                        }
-                        Focus::CloseButton => {
-                            app.state.filter_tags = None;
-                            app.state.all_available_tags = None;
-                            app.state.app_list_states.filter_by_tag_list.select(None);
-                            app.close_popup();
-                        }
-                        Focus::SubmitButton => {
-                            handle_filter_by_tag(app);
-                            app.close_popup();
-                        }
-                        _ => {}
+            });
+            // remove dragged card from current board
+            app.boards.get_mut_boards().iter_mut().for_each(|board| {
+                if board.id == dragged_card_board_id {
+                    board.cards.remove_card_with_id(dragged_card_id);
+                }
+            });
+            app.action_history_manager
+                .new_action(ActionHistory::MoveCardBetweenBoards(
+                    dragged_card,
+                    dragged_card_board_id,
+                    hovered_board_id,
+                    dragged_card_index,
+                    0,
+                ));
+            let info_msg = &format!(
+                "Moved card \"{}\" to board \"{}\"",
+                dragged_card_name, hovered_board.name
+            );
+            info!("{}", info_msg);
+            app.send_info_toast(info_msg, None);
+            return;
+        }
+        let hovered_card_index = hovered_board.cards.get_card_index(hovered_card_id);
+        if hovered_card_index.is_none() {
+            // case when card was hovered over another board so a card from another board was the last hovered card
+            if hovered_board.cards.is_empty() {
+                debug!("hovered board is empty");
+                // add dragged card to hovered board
+                app.boards.get_mut_boards().iter_mut().for_each(|board| {
+                    if board.id == hovered_board_id {
+                        board.cards.add_card_at_index(0, dragged_card.clone());
                     }
-                    if app.state.mouse_focus == Some(Focus::FilterByTagPopup) {
-                        handle_filter_by_tag(app);
-                    } else if app.state.mouse_focus == Some(Focus::CloseButton) {
-                        app.state.filter_tags = None;
-                        app.state.all_available_tags = None;
-                        app.state.app_list_states.filter_by_tag_list.select(None);
-                        app.close_popup();
-                    } else if app.state.mouse_focus == Some(Focus::SubmitButton) {
-                        handle_filter_by_tag(app);
-                        app.close_popup();
+                });
+                app.boards.get_mut_boards().iter_mut().for_each(|board| {
+                    if board.id == dragged_card_board_id {
+                        board.cards.remove_card_with_id(dragged_card_id);
                     }
-                } else if mouse_scroll_up && app.state.mouse_focus == Some(Focus::FilterByTagPopup)
-                {
-                    app.filter_by_tag_popup_prv()
-                } else if mouse_scroll_down
-                    && app.state.mouse_focus == Some(Focus::FilterByTagPopup)
-                {
-                    app.filter_by_tag_popup_next()
+                });
+                app.action_history_manager
+                    .new_action(ActionHistory::MoveCardBetweenBoards(
+                        dragged_card,
+                        dragged_card_board_id,
+                        hovered_board_id,
+                        dragged_card_index,
+                        0,
+                    ));
+                let info_msg = &format!(
+                    "Moved card \"{}\" to board \"{}\"",
+                    dragged_card_name, hovered_board.name
+                );
+                info!("{}", info_msg);
+                app.send_info_toast(info_msg, None);
+            } else {
+                // the hovered board is empty just move the dragged card to the hovered board
+                app.boards.get_mut_boards().iter_mut().for_each(|board| {
+                    if board.id == hovered_board_id {
+                        board.cards.add_card_at_index(0, dragged_card.clone());
+                    }
+                });
+                // remove dragged card from current board
+                app.boards.get_mut_boards().iter_mut().for_each(|board| {
+                    if board.id == dragged_card_board_id {
+                        board.cards.remove_card_with_id(dragged_card_id);
+                    }
+                });
+                app.action_history_manager
+                    .new_action(ActionHistory::MoveCardBetweenBoards(
+                        dragged_card,
+                        dragged_card_board_id,
+                        hovered_board_id,
+                        dragged_card_index,
+                        0,
+                    ));
+                let info_msg = &format!(
+                    "Moved card \"{}\" to board \"{}\"",
+                    dragged_card_name, hovered_board.name
+                );
+                info!("{}", info_msg);
+            }
+        } else {
+            let hovered_card_index = hovered_card_index.unwrap();
+            let dragged_card = dragged_card_board
+                .cards
+                .get_card_with_index(dragged_card_index)
+                .unwrap();
+            // remove dragged card from current board
+            app.boards.get_mut_boards().iter_mut().for_each(|board| {
+                if board.id == dragged_card_board_id {
+                    board.cards.remove_card_with_id(dragged_card_id);
+                }
+            });
+            // add dragged card to hovered board
+            app.boards.get_mut_boards().iter_mut().for_each(|board| {
+                if board.id == hovered_board_id {
+                    board
+                        .cards
+                        .add_card_at_index(hovered_card_index, dragged_card.clone());
                 }
+            });
+            app.action_history_manager
+                .new_action(ActionHistory::MoveCardBetweenBoards(
+                    dragged_card.clone(),
+                    dragged_card_board_id,
+                    hovered_board_id,
+                    dragged_card_index,
+                    hovered_card_index,
+                ));
+            let info_msg = &format!(
+                "Moved card \"{}\" to board \"{}\"",
+                dragged_card_name, hovered_board.name
+            );
+            info!("{}", info_msg);
+            app.send_info_toast(info_msg, None);
+        }
+    }
+}
+
+/// Steps the help and log panes by a row, bounded by what the last rendered view could fit. The
+/// height is kept in the config file so the next session starts with it
+fn resize_secondary_panes(app: &mut App, grow: bool) {
+    let max_height = app
+        .state
+        .max_secondary_pane_height
+        .unwrap_or(DEFAULT_SECONDARY_PANE_HEIGHT);
+    let current_height = app
+        .state
+        .secondary_pane_height
+        .clamp(MIN_SECONDARY_PANE_HEIGHT, max_height);
+    let new_height = if grow {
+        (current_height + 1).min(max_height)
+    } else {
+        current_height
+            .saturating_sub(1)
+            .max(MIN_SECONDARY_PANE_HEIGHT)
+    };
+    app.state.secondary_pane_height = new_height;
+    if new_height == current_height {
+        let bound = if grow { "largest" } else { "smallest" };
+        app.send_warning_toast(
+            &format!("Help and log panes are already at their {}", bound),
+            None,
+        );
+        return;
+    }
+    store_in_config_file(app, "help and log pane height", |config| {
+        config.secondary_pane_height = Some(new_height);
+    });
+}
+
+pub fn reset_card_drag_mode(app: &mut App) {
+    app.state.card_drag_mode = false;
+    app.state.hovered_board = None;
+    app.state.hovered_card = None;
+    app.state.hovered_card_dimensions = None;
+}
+
+fn handle_config_menu_action(app: &mut App) -> AppReturn {
+    fn reset_config(app: &mut App, reset_keybindings: bool, warning_message: &str) {
+        let keybindings = app.config.keybindings.clone();
+        app.config = AppConfig::default();
+        app.current_theme = Theme::default();
+        app.apply_log_filters();
+        if !reset_keybindings {
+            app.config.keybindings = keybindings;
+        }
+        app.state.set_focus(Focus::ConfigTable);
+        app.state.app_table_states.config.select(Some(0));
+        let write_config_status = app.write_config(&app.config);
+        if write_config_status.is_err() {
+            error!(
+                "Error writing config file: {}",
+                write_config_status.clone().unwrap_err()
+            );
+            app.send_error_toast(
+                &format!(
+                    "Error writing config file: {}",
+                    write_config_status.unwrap_err()
+                ),
+                None,
+            );
+        } else {
+            warn!("{}", warning_message);
+            app.send_warning_toast(warning_message, None);
+        }
+    }
+
+    if app.state.focus == Focus::SubmitButton {
+        reset_config(app, true, "Reset Config and KeyBindings to default");
+        return AppReturn::Continue;
+    } else if app.state.focus == Focus::ExtraFocus {
+        reset_config(app, false, "Reset Config to default");
+        return AppReturn::Continue;
+    }
+    let config_enum = if let Some(config_enum) = get_selected_config_enum(app) {
+        config_enum
+    } else {
+        debug!(
+            "Config item being edited {} is not in the AppConfig list",
+            app.state.app_table_states.config.selected().unwrap_or(0)
+        );
+        return AppReturn::Continue;
+    };
+    match config_enum.edit_mode() {
+        ConfigEditMode::Keybindings => {
+            app.set_view(View::EditKeybindings);
+            if app
+                .state
+                .app_table_states
+                .edit_keybindings
+                .selected()
+                .is_none()
+            {
+                app.edit_keybindings_next();
             }
-            PopUp::DateTimePicker => {
-                if left_button_pressed {
-                    handle_date_time_picker_action(app, None, Some(Action::Accept));
-                } else if mouse_scroll_up {
-                    match app.state.focus {
-                        Focus::DTPMonth => app.widgets.date_time_picker.month_next(),
-                        Focus::DTPYear => app.widgets.date_time_picker.year_next(),
-                        Focus::DTPHour => app.widgets.date_time_picker.move_hours_prv(),
-                        Focus::DTPMinute => app.widgets.date_time_picker.move_minutes_prv(),
-                        Focus::DTPSecond => app.widgets.date_time_picker.move_seconds_prv(),
-                        _ => {}
-                    }
-                } else if mouse_scroll_down {
-                    match app.state.focus {
-                        Focus::DTPMonth => app.widgets.date_time_picker.month_prv(),
-                        Focus::DTPYear => app.widgets.date_time_picker.year_prv(),
-                        Focus::DTPHour => app.widgets.date_time_picker.move_hours_next(),
-                        Focus::DTPMinute => app.widgets.date_time_picker.move_minutes_next(),
-                        Focus::DTPSecond => app.widgets.date_time_picker.move_seconds_next(),
-                        _ => {}
-                    }
+        }
+        ConfigEditMode::Toggle => {
+            AppConfig::edit_config(
+                app,
+                config_enum,
+                &app.config.get_toggled_value_as_string(config_enum),
+            );
+            if config_enum == ConfigEnum::DatePickerCalenderFormat {
+                app.widgets
+                    .date_time_picker
+                    .set_calender_type(app.config.date_picker_calender_format.clone());
+            }
+        }
+        ConfigEditMode::Selector => match config_enum {
+            ConfigEnum::DefaultView => {
+                if app.state.app_list_states.default_view.selected().is_none() {
+                    app.select_default_view_next();
                 }
+                app.set_popup(PopUp::SelectDefaultView);
             }
-            PopUp::TagPicker => {
-                // This is never reached as tag picker does not handle its own actions
+            ConfigEnum::DefaultTheme => {
+                app.state.default_theme_mode = true;
+                app.set_popup(PopUp::ChangeTheme);
+            }
+            _ => {
+                app.set_popup(PopUp::ChangeDateFormatPopup);
             }
+        },
+        ConfigEditMode::Number | ConfigEditMode::Text => {
+            app.set_popup(PopUp::EditGeneralConfig);
+        }
+    }
+    AppReturn::Continue
+}
+
+fn get_selected_config_enum(app: &App) -> Option<ConfigEnum> {
+    let selected_index = app.state.app_table_states.config.selected().unwrap_or(0);
+    let config_item = app
+        .config
+        .to_view_list()
+        .get(selected_index)?
+        .first()?
+        .to_owned();
+    match ConfigEnum::from_str(&config_item) {
+        Ok(config_enum) => Some(config_enum),
+        Err(_) => {
+            error!("Error checking which config item is being edited");
+            None
         }
+    }
+}
+
+fn is_config_value_step_allowed(app: &App, key: Key) -> bool {
+    matches!(key, Key::Char('+') | Key::Char('-'))
+        && app.state.z_stack.is_empty()
+        && app.state.current_view == View::ConfigMenu
+        && app.state.focus == Focus::ConfigTable
+}
+
+/// Moves the selected numeric config row one step within its min/max, other rows are left alone
+fn handle_config_value_step(app: &mut App, increment: bool) {
+    let config_enum = if let Some(config_enum) = get_selected_config_enum(app) {
+        config_enum
     } else {
-        match app.state.current_view {
-            View::Zen
-            | View::TitleBody
-            | View::BodyHelp
-            | View::BodyLog
-            | View::TitleBodyHelp
-            | View::TitleBodyLog
-            | View::TitleBodyHelpLog
-            | View::BodyHelpLog
-            | View::ConfigMenu
-            | View::EditKeybindings
-            | View::HelpMenu
-            | View::NewBoard
-            | View::NewCard => {
-                if left_button_pressed {
-                    if let Some(value) = handle_left_click_for_view(app).await {
-                        return value;
-                    }
-                } else if mouse_scroll_up
-                    || mouse_scroll_down
-                    || mouse_scroll_right
-                    || mouse_scroll_left
-                {
-                    handle_scroll_for_view(
-                        app,
-                        mouse_scroll_up,
-                        mouse_scroll_down,
-                        mouse_scroll_right,
-                        mouse_scroll_left,
-                    );
+        return;
+    };
+    if config_enum.edit_mode() != ConfigEditMode::Number {
+        return;
+    }
+    let stepped_value = if let Some(value) = app
+        .config
+        .get_stepped_value_as_string(config_enum, increment)
+    {
+        value
+    } else {
+        return;
+    };
+    if stepped_value == app.config.get_value_as_string(config_enum) {
+        let bound = if increment { "maximum" } else { "minimum" };
+        app.send_warning_toast(
+            &format!("{} is already at its {}", config_enum, bound),
+            None,
+        );
+        return;
+    }
+    AppConfig::edit_config(app, config_enum, &stepped_value);
+}
+
+async fn handle_main_menu_action(app: &mut App<'_>) -> AppReturn {
+    if let Some(selected_index) = app.state.app_list_states.main_menu.selected() {
+        let selected_item = app.main_menu.from_index(selected_index);
+        match selected_item {
+            MainMenuItem::Quit => return handle_exit(app).await,
+            MainMenuItem::Config => {
+                app.set_view(View::ConfigMenu);
+                if app.state.app_table_states.config.selected().is_none() {
+                    app.config_next();
                 }
             }
-            View::MainMenu | View::LogsOnly | View::LoadLocalSave | View::CreateTheme => {
-                if left_button_pressed {
-                    if let Some(value) = handle_left_click_for_view(app).await {
-                        return value;
-                    }
-                }
+            MainMenuItem::View => {
+                app.set_view(app.config.default_view);
             }
-            View::Login => {
-                if left_button_pressed {
-                    handle_login_action(app).await
-                }
+            MainMenuItem::Help => {
+                app.set_view(View::HelpMenu);
             }
-            View::SignUp => {
-                if left_button_pressed {
-                    handle_signup_action(app).await
-                }
+            MainMenuItem::Stats => {
+                open_stats(app);
             }
-            View::ResetPassword => {
-                if left_button_pressed {
-                    handle_reset_password_action(app).await
-                }
+            MainMenuItem::LoadSaveLocal => {
+                app.set_view(View::LoadLocalSave);
             }
-            View::LoadCloudSave => {
-                if left_button_pressed {
-                    if app.state.mouse_focus == Some(Focus::CloseButton) {
-                        handle_go_to_previous_view(app).await;
-                    } else if app.state.mouse_focus == Some(Focus::LoadSave)
-                        && app.state.app_list_states.load_save.selected().is_some()
-                        && app.state.cloud_data.is_some()
-                    {
-                        app.dispatch(IoEvent::LoadCloudPreview).await;
-                    }
+            MainMenuItem::LoadSaveCloud => {
+                if app.main_menu.logged_in {
+                    app.set_view(View::LoadCloudSave);
+                    reset_preview_boards(app);
+                    app.dispatch(IoEvent::GetCloudData).await;
                 }
             }
         }
     }
-    app.state.last_mouse_action = Some(mouse_action);
     AppReturn::Continue
 }
 
-async fn handle_left_click_for_view(app: &mut App<'_>) -> Option<AppReturn> {
-    let prv_view = app.state.current_view;
-    app.state.mouse_focus?;
-    // TODO: investigate if we really need to have mouse focus separate from focus
-    let mouse_focus = app.state.mouse_focus.unwrap();
-    match mouse_focus {
-        Focus::Title => {
-            app.set_view(View::MainMenu);
-            if app.state.app_list_states.main_menu.selected().is_none() {
-                app.main_menu_next()
-            }
-        }
-        Focus::Body => {
-            if !(app.state.current_board_id.is_some() && app.state.current_card_id.is_some()) {
-                app.send_error_toast("No card selected", None);
-                return Some(AppReturn::Continue);
-            }
-            app.set_popup(PopUp::ViewCard);
+fn handle_default_view_selection(app: &mut App) {
+    let all_views = View::all_views_as_string();
+    let current_selected_view = app
+        .state
+        .app_list_states
+        .default_view
+        .selected()
+        .unwrap_or(0);
+    if current_selected_view < all_views.len() {
+        let selected_view = &all_views[current_selected_view];
+        app.config.default_view = View::from_string(selected_view).unwrap_or(View::MainMenu);
+        AppConfig::edit_config(app, ConfigEnum::DefaultView, selected_view);
+        app.state.app_list_states.default_view.select(Some(0));
+        if app.state.app_table_states.config.selected().is_none() {
+            app.config_next();
         }
-        Focus::Help => {
-            app.set_view(View::HelpMenu);
+        if !app.state.z_stack.is_empty() {
+            app.state.z_stack.pop();
         }
-        Focus::Log => {
-            app.set_view(View::LogsOnly);
+    } else {
+        debug!(
+            "Selected view {} is not in the list of all View",
+            current_selected_view
+        );
+    }
+}
+
+fn handle_change_date_format(app: &mut App) {
+    let all_date_formats = DateTimeFormat::get_all_date_formats();
+    let current_selected_format = app
+        .state
+        .app_list_states
+        .date_format_selector
+        .selected()
+        .unwrap_or(0);
+    if current_selected_format < all_date_formats.len() {
+        let selected_format = &all_date_formats[current_selected_format];
+        app.config.date_time_format = *selected_format;
+        AppConfig::edit_config(
+            app,
+            ConfigEnum::DateFormat,
+            selected_format.to_human_readable_string(),
+        );
+        app.state
+            .app_list_states
+            .date_format_selector
+            .select(Some(0));
+        if app.state.app_table_states.config.selected().is_none() {
+            app.config_next();
         }
-        Focus::ConfigTable => {
-            return Some(handle_config_menu_action(app));
+        if !app.state.z_stack.is_empty() {
+            app.state.z_stack.pop();
         }
-        Focus::EditKeybindingsTable => {
-            handle_edit_keybindings_action(app);
+    } else {
+        debug!(
+            "Selected format {} is not in the list of all date formats",
+            current_selected_format
+        );
+    }
+}
+
+fn handle_change_view(app: &mut App) {
+    let current_index = app
+        .state
+        .app_list_states
+        .default_view
+        .selected()
+        .unwrap_or(0);
+    let all_views = View::all_views_as_string()
+        .iter()
+        .filter_map(|s| View::from_string(s))
+        .collect::<Vec<View>>();
+
+    let current_index = if current_index >= all_views.len() {
+        all_views.len() - 1
+    } else {
+        current_index
+    };
+    let selected_view = all_views[current_index];
+    if selected_view == View::ArchivedCards {
+        open_archived_cards(app);
+    } else if selected_view == View::Stats {
+        open_stats(app);
+    } else if selected_view == View::Calendar {
+        open_calendar(app);
+    } else {
+        app.set_view(selected_view);
+    }
+}
+
+fn handle_edit_keybindings_action(app: &mut App) {
+    if app
+        .state
+        .app_table_states
+        .edit_keybindings
+        .selected()
+        .is_some()
+        && app.state.focus != Focus::SubmitButton
+    {
+        app.set_popup(PopUp::EditSpecificKeyBinding);
+    } else if app.state.focus == Focus::SubmitButton {
+        app.config.keybindings = KeyBindings::default();
+        warn!("Reset keybindings to default");
+        app.send_warning_toast("Reset keybindings to default", None);
+        app.state.set_focus(Focus::EditKeybindingsTable);
+        app.state.app_table_states.edit_keybindings.select(Some(0));
+        let write_config_status = app.write_config(&app.config);
+        if let Err(error_message) = write_config_status {
+            error!("Error writing config: {}", error_message);
+            app.send_error_toast(&format!("Error writing config: {}", error_message), None);
         }
-        Focus::CloseButton => match prv_view {
-            View::Zen
-            | View::TitleBody
-            | View::BodyHelp
-            | View::BodyLog
-            | View::TitleBodyHelp
-            | View::TitleBodyLog
-            | View::BodyHelpLog
-            | View::TitleBodyHelpLog
-            | View::MainMenu => {
-                return Some(handle_exit(app).await);
+    }
+}
+
+pub async fn handle_go_to_previous_view(app: &mut App<'_>) -> AppReturn {
+    if let Some(popup) = app.state.z_stack.last() {
+        match popup {
+            PopUp::EditGeneralConfig => {
+                if app.state.current_view == View::CreateTheme {
+                    app.close_popup();
+                } else {
+                    app.set_view(View::ConfigMenu);
+                    if app.state.app_table_states.config.selected().is_none() {
+                        app.config_next()
+                    }
+                }
+                app.state.text_buffers.general_config.reset();
             }
-            View::NewBoard => {
-                reset_new_board_form(app);
-                handle_go_to_previous_view(app).await;
+            PopUp::EditSpecificKeyBinding => {
+                if return_to_command_palette_after_keybinding_edit(app) {
+                    return AppReturn::Continue;
+                }
+                app.set_view(View::EditKeybindings);
+                app.state.app_table_states.edit_keybindings.select(Some(0));
             }
-            View::NewCard => {
-                reset_new_card_form(app);
-                handle_go_to_previous_view(app).await;
+            PopUp::ViewCard => {
+                app.close_popup();
             }
-            View::CreateTheme => {
-                app.state.theme_being_edited = Theme::default();
-                handle_go_to_previous_view(app).await;
+            PopUp::ConfirmDiscardCardChanges => {
+                app.close_popup();
             }
-            _ => {
-                handle_go_to_previous_view(app).await;
+            PopUp::FilterByTag => {
+                app.state.filter_tags = None;
+                app.state.all_available_tags = None;
+                app.state.app_list_states.filter_by_tag_list.select(None);
             }
-        },
-        Focus::SubmitButton => {
-            app.state.set_focus(Focus::SubmitButton);
-            match prv_view {
-                View::EditKeybindings => {
-                    handle_edit_keybindings_action(app);
-                }
-                View::NewBoard => {
-                    handle_new_board_action(app);
-                    app.state.app_status = AppStatus::Initialized;
-                }
-                View::NewCard => {
-                    handle_new_card_action(app);
-                    app.state.app_status = AppStatus::Initialized;
-                }
-                View::ConfigMenu => {
-                    return Some(handle_config_menu_action(app));
-                }
-                View::CreateTheme => {
-                    return Some(handle_create_theme_action(app));
+            PopUp::SearchCards => clear_card_search(app),
+            PopUp::ChangeTheme => {
+                let config_theme = {
+                    let all_themes = Theme::all_default_themes();
+                    let default_theme = app.config.default_theme.clone();
+                    all_themes.iter().find(|t| t.name == default_theme).cloned()
+                };
+                if let Some(theme) = config_theme {
+                    app.current_theme = theme;
                 }
-                _ => {}
-            }
-        }
-        Focus::ExtraFocus => {
-            if prv_view == View::ConfigMenu {
-                return Some(handle_config_menu_action(app));
-            } else if prv_view == View::CreateTheme {
-                return Some(handle_create_theme_action(app));
-            }
-        }
-        Focus::MainMenu => {
-            return Some(handle_main_menu_action(app).await);
-        }
-        Focus::NewBoardName
-        | Focus::NewBoardDescription
-        | Focus::CardName
-        | Focus::CardDescription => {
-            app.state.app_status = AppStatus::UserInput;
-            info!("Taking user input");
-        }
-        Focus::CardDueDate => {
-            if app.state.card_being_edited.is_none() {
-                handle_edit_new_card(app);
-            }
-            app.set_popup(PopUp::DateTimePicker);
-        }
-        Focus::LoadSave => {
-            if app.state.app_list_states.load_save.selected().is_some() {
-                app.dispatch(IoEvent::LoadLocalPreview).await;
             }
+            _ => {}
         }
-        Focus::ThemeEditor => {
-            return Some(handle_create_theme_action(app));
+        app.close_popup();
+        if app.state.app_status == AppStatus::UserInput {
+            app.state.app_status = AppStatus::Initialized;
         }
-        _ => {}
+        return AppReturn::Continue;
     }
-    None
-}
-
-fn handle_scroll_for_view(
-    app: &mut App<'_>,
-    mouse_scroll_up: bool,
-    mouse_scroll_down: bool,
-    mouse_scroll_right: bool,
-    mouse_scroll_left: bool,
-) {
-    if mouse_scroll_up {
-        if app.state.mouse_focus == Some(Focus::Body) {
-            scroll_up(app);
-        } else if app.state.mouse_focus == Some(Focus::Help) {
-            app.help_prv();
-        } else if app.state.mouse_focus == Some(Focus::ConfigTable) {
-            app.config_prv();
-        } else if app.state.mouse_focus == Some(Focus::EditKeybindingsTable) {
-            app.edit_keybindings_prv();
-        } else if app.state.mouse_focus == Some(Focus::NewBoardDescription) {
-            app.state.text_buffers.board_description.scroll((-1, 0))
-        } else if app.state.mouse_focus == Some(Focus::CardDescription) {
-            app.state.text_buffers.card_description.scroll((-1, 0))
-        }
-    } else if mouse_scroll_down {
-        if app.state.mouse_focus == Some(Focus::Body) {
-            scroll_down(app);
-        } else if app.state.mouse_focus == Some(Focus::Help) {
-            app.help_next();
-        } else if app.state.mouse_focus == Some(Focus::ConfigTable) {
-            app.config_next();
-        } else if app.state.mouse_focus == Some(Focus::EditKeybindingsTable) {
-            app.edit_keybindings_next();
-        } else if app.state.mouse_focus == Some(Focus::NewBoardDescription) {
-            app.state.text_buffers.board_description.scroll((1, 0))
-        } else if app.state.mouse_focus == Some(Focus::CardDescription) {
-            app.state.text_buffers.card_description.scroll((1, 0))
-        }
-    } else if mouse_scroll_right && app.state.mouse_focus == Some(Focus::Body) {
-        scroll_right(app);
-    } else if mouse_scroll_left && app.state.mouse_focus == Some(Focus::Body) {
-        scroll_left(app);
+    // The first Esc only drops the marked cards
+    if View::views_with_kanban_board().contains(&app.state.current_view)
+        && !app.state.selected_card_ids.is_empty()
+    {
+        app.state.selected_card_ids.clear();
+        app.send_info_toast(&tr(MessageId::ToastClearedMarkedCards), None);
+        return AppReturn::Continue;
     }
-}
-
-fn move_dragged_card(app: &mut App<'_>) {
-    let card_being_dragged = app.state.hovered_card.unwrap();
-    let hovered_board_id = app.state.hovered_board.unwrap();
-    let card_being_dragged_board = card_being_dragged.0;
-    let card_being_dragged_id = card_being_dragged.1;
-    if hovered_board_id == card_being_dragged_board {
-        if app.state.current_card_id.is_none() {
-            debug!("Could not find current card");
-            return;
-        }
-        let hovered_card_id = app.state.current_card_id.unwrap();
-        // same board so swap cards
-        let hovered_board = app.boards.get_board_with_id(hovered_board_id);
-        if hovered_board.is_none() {
-            debug!("Could not find hovered board");
-            return;
+    match app.state.current_view {
+        View::MainMenu => handle_exit(app).await,
+        View::EditKeybindings => {
+            app.set_view(View::ConfigMenu);
+            if app.state.app_table_states.config.selected().is_none() {
+                app.config_next()
+            }
+            AppReturn::Continue
         }
-        let hovered_board = hovered_board.unwrap();
-        let dragged_card_index = hovered_board.cards.get_card_index(card_being_dragged_id);
-        if dragged_card_index.is_none() {
-            debug!("Could not find dragged card");
-            return;
+        View::LoadLocalSave => {
+            app.state.app_list_states.load_save = ListState::default();
+            go_to_previous_view_without_extras(app);
+            AppReturn::Continue
         }
-        let hovered_card_index = hovered_board.cards.get_card_index(hovered_card_id);
-        if hovered_card_index.is_none() {
-            debug!("Could not find hovered card");
-            return;
+        View::Login => {
+            reset_login_form(app);
+            go_to_previous_view_without_extras(app);
+            AppReturn::Continue
         }
-        let dragged_card_index = dragged_card_index.unwrap();
-        let hovered_card_index = hovered_card_index.unwrap();
-        if dragged_card_index == hovered_card_index {
-            debug!("No need to move card as it is already in the same position");
-            return;
+        View::SignUp => {
+            reset_signup_form(app);
+            go_to_previous_view_without_extras(app);
+            AppReturn::Continue
         }
-        let dragged_card_name = hovered_board
-            .cards
-            .get_card_with_index(dragged_card_index)
-            .unwrap()
-            .name
-            .clone();
-        // swap cards
-        app.boards.get_mut_boards().iter_mut().for_each(|board| {
-            if board.id == hovered_board_id {
-                board.cards.swap(dragged_card_index, hovered_card_index);
-            }
-        });
-        app.action_history_manager
-            .new_action(ActionHistory::MoveCardWithinBoard(
-                hovered_board_id,
-                dragged_card_index,
-                hovered_card_index,
-            ));
-        let info_msg = &format!(
-            "Moved card \"{}\" from index {} to index {}",
-            dragged_card_name, dragged_card_index, hovered_card_index
-        );
-        info!("{}", info_msg);
-        app.send_info_toast(info_msg, None);
-    } else {
-        let app_boards = app.boards.clone();
-        // different board so remove dragged card from current board and add it to the hovered board at the index of the hovered card and push everything else down
-        let dragged_card_board_id = card_being_dragged.0;
-        let hovered_board_id = app.state.hovered_board;
-        if hovered_board_id.is_none() {
-            debug!("Could not find hovered board");
-            return;
+        View::ResetPassword => {
+            reset_reset_password_form(app);
+            go_to_previous_view_without_extras(app);
+            AppReturn::Continue
         }
-        let hovered_board_id = hovered_board_id.unwrap();
-        let dragged_card_board = app_boards.get_board_with_id(dragged_card_board_id);
-        if dragged_card_board.is_none() {
-            debug!("Could not find dragged card board");
-            return;
+        View::CreateTheme => {
+            app.state.theme_being_edited = Theme::default();
+            go_to_previous_view_without_extras(app);
+            AppReturn::Continue
         }
-        let dragged_card_board = dragged_card_board.unwrap();
-        let hovered_board = app_boards.get_board_with_id(hovered_board_id);
-        if hovered_board.is_none() {
-            debug!("Could not find hovered board");
-            return;
+        _ => {
+            go_to_previous_view_without_extras(app);
+            AppReturn::Continue
         }
-        let hovered_board = hovered_board.unwrap();
-        let dragged_card_id = card_being_dragged.1;
-        let hovered_card_id = app.state.current_card_id;
-        let dragged_card_index = dragged_card_board.cards.get_card_index(dragged_card_id);
-        if dragged_card_index.is_none() {
-            debug!("Could not find dragged card");
-            return;
+    }
+}
+
+fn go_to_previous_view_without_extras(app: &mut App) {
+    if app.state.prev_view == Some(app.state.current_view) {
+        app.set_view(View::MainMenu);
+        if app.state.app_list_states.main_menu.selected().is_none() {
+            app.main_menu_next();
         }
-        let dragged_card_index = dragged_card_index.unwrap();
-        let dragged_card = dragged_card_board
-            .cards
-            .get_card_with_index(dragged_card_index)
-            .unwrap()
-            .clone();
-        let dragged_card_name = dragged_card.name.clone();
-        if hovered_card_id.is_none() {
-            // check if hovered board is empty
-            if hovered_board.cards.is_empty() {
-                debug!("hovered board is empty");
-                // add dragged card to hovered board
-                app.boards.get_mut_boards().iter_mut().for_each(|board| {
-                    if board.id == hovered_board_id {
-                        board.cards.add_card_at_index(0, dragged_card.clone());
-                    }
-                });
-                // remove dragged card from current board
-                app.boards.get_mut_boards().iter_mut().for_each(|board| {
-                    // check if index is valid
-                    if board.id == dragged_card_board_id {
-                        if board.cards.len() > dragged_card_index {
-                            board.cards.remove_card_with_id(dragged_card_id);
-                        } else {
-                            debug!("Invalid Index for dragged card, board_id: {:?}, dragged_card_index: {}", dragged_card_board_id, dragged_card_index);
-                        }
-                    }
-                });
-                app.action_history_manager
-                    .new_action(ActionHistory::MoveCardBetweenBoards(
-                        dragged_card,
-                        dragged_card_board_id,
-                        hovered_board_id,
-                        dragged_card_index,
-                        0,
-                    ));
-                let info_msg = &format!(
-                    "Moved card \"{}\" to board \"{}\"",
-                    dragged_card_name, hovered_board.name
-                );
-                info!("{}", info_msg);
-                app.send_info_toast(info_msg, None);
-                return;
-            } else {
-                debug!("Could not find hovered card");
-                let error_msg = "Moving card failed, could not find hovered card";
-                error!("{}", error_msg);
-                app.send_error_toast(error_msg, None);
-                return;
-            }
+    } else {
+        let prev_view = app.state.prev_view.unwrap_or(View::MainMenu);
+        app.set_view(prev_view);
+        if app.state.app_list_states.main_menu.selected().is_none() {
+            app.main_menu_next();
         }
-        let hovered_card_id = hovered_card_id.unwrap();
-        if hovered_card_id == dragged_card_id {
-            // the hovered board is empty just move the dragged card to the hovered
-            // board (Special case) as it was the last card that was hovered
-            app.boards.get_mut_boards().iter_mut().for_each(|board| {
-                if board.id == hovered_board_id {
-                    board.cards.add_card_at_index(0, dragged_card.clone());
-                }
-            });
-            // remove dragged card from current board
-            app.boards.get_mut_boards().iter_mut().for_each(|board| {
-                if board.id == dragged_card_board_id {
-                    board.cards.remove_card_with_id(dragged_card_id);
-                }
-            });
-            app.action_history_manager
-                .new_action(ActionHistory::MoveCardBetweenBoards(
-                    dragged_card,
-                    dragged_card_board_id,
-                    hovered_board_id,
-                    dragged_card_index,
-                    0,
-                ));
-            let info_msg = &format!(
-                "Moved card \"{}\" to board \"{}\"",
-                dragged_card_name, hovered_board.name
-            );
-            info!("{}", info_msg);
-            app.send_info_toast(info_msg, None);
-            return;
+    }
+}
+
+/// Describes what changing the cards to the selected status will do, shown in the status
+/// selector before the change is accepted
+pub fn get_card_status_change_preview(
+    current_statuses: &[StatusSelection],
+    selected_status: &StatusSelection,
+    completed_cards_hidden: bool,
+    date_time_format: DateTimeFormat,
+) -> Vec<String> {
+    let changing_statuses = current_statuses
+        .iter()
+        .filter(|status| *status != selected_status)
+        .collect::<Vec<&StatusSelection>>();
+    if changing_statuses.is_empty() {
+        return vec![format!("Already {}, nothing will change", selected_status)];
+    }
+    let mut preview = Vec::new();
+    let subject = if current_statuses.len() > 1 {
+        preview.push(format!(
+            "{} of {} selected cards will change to {}",
+            changing_statuses.len(),
+            current_statuses.len(),
+            selected_status
+        ));
+        "Cards"
+    } else {
+        preview.push(format!("{} → {}", changing_statuses[0], selected_status));
+        "Card"
+    };
+    // Moving between custom statuses that are both complete or both not keeps the date
+    let completion_changes = changing_statuses
+        .iter()
+        .any(|status| status.card_status != selected_status.card_status);
+    let date_completed = selected_status
+        .card_status
+        .get_date_completed(date_time_format);
+    if completion_changes && date_completed != FIELD_NOT_SET {
+        preview.push(format!("Completion date will be set to {}", date_completed));
+    } else if completion_changes
+        && changing_statuses
+            .iter()
+            .any(|status| status.card_status == CardStatus::Complete)
+    {
+        preview.push("Completion date will be cleared".to_string());
+    }
+    let becomes_hidden = !selected_status
+        .card_status
+        .is_displayable(completed_cards_hidden);
+    let becomes_visible = changing_statuses
+        .iter()
+        .any(|status| !status.card_status.is_displayable(completed_cards_hidden));
+    if becomes_hidden {
+        preview.push(format!(
+            "{} will be hidden, completed cards are hidden on this board",
+            subject
+        ));
+    } else if becomes_visible {
+        preview.push(format!(
+            "{} will be shown again, completed cards are hidden on this board",
+            subject
+        ));
+    }
+    preview
+}
+
+/// Describes what changing the cards to the selected priority will do, shown in the priority
+/// selector before the change is accepted. `auto_sort_move` is the current and the new index
+/// of a single card on a board that is kept sorted by priority
+pub fn get_card_priority_change_preview(
+    current_priorities: &[CardPriority],
+    selected_priority: &CardPriority,
+    auto_sort_move: Option<(usize, usize)>,
+) -> Vec<String> {
+    let changing_priorities = current_priorities
+        .iter()
+        .filter(|priority| *priority != selected_priority)
+        .collect::<Vec<&CardPriority>>();
+    if changing_priorities.is_empty() {
+        return vec![format!(
+            "Already {} priority, nothing will change",
+            selected_priority
+        )];
+    }
+    let mut preview = if current_priorities.len() > 1 {
+        vec![format!(
+            "{} of {} selected cards will change to {} priority",
+            changing_priorities.len(),
+            current_priorities.len(),
+            selected_priority
+        )]
+    } else {
+        vec![format!(
+            "{} → {} priority",
+            changing_priorities[0], selected_priority
+        )]
+    };
+    if let Some((current_index, new_index)) = auto_sort_move {
+        if current_index != new_index {
+            preview.push(format!(
+                "Board auto sort will move this card to position {}",
+                new_index + 1
+            ));
         }
-        let hovered_card_index = hovered_board.cards.get_card_index(hovered_card_id);
-        if hovered_card_index.is_none() {
-            // case when card was hovered over another board so a card from another board was the last hovered card
-            if hovered_board.cards.is_empty() {
-                debug!("hovered board is empty");
-                // add dragged card to hovered board
-                app.boards.get_mut_boards().iter_mut().for_each(|board| {
-                    if board.id == hovered_board_id {
-                        board.cards.add_card_at_index(0, dragged_card.clone());
-                    }
-                });
-                app.boards.get_mut_boards().iter_mut().for_each(|board| {
-                    if board.id == dragged_card_board_id {
-                        board.cards.remove_card_with_id(dragged_card_id);
-                    }
-                });
-                app.action_history_manager
-                    .new_action(ActionHistory::MoveCardBetweenBoards(
-                        dragged_card,
-                        dragged_card_board_id,
-                        hovered_board_id,
-                        dragged_card_index,
-                        0,
-                    ));
-                let info_msg = &format!(
-                    "Moved card \"{}\" to board \"{}\"",
-                    dragged_card_name, hovered_board.name
-                );
-                info!("{}", info_msg);
-                app.send_info_toast(info_msg, None);
-            } else {
-                // the hovered board is empty just move the dragged card to the hovered board
-                app.boards.get_mut_boards().iter_mut().for_each(|board| {
-                    if board.id == hovered_board_id {
-                        board.cards.add_card_at_index(0, dragged_card.clone());
-                    }
-                });
-                // remove dragged card from current board
-                app.boards.get_mut_boards().iter_mut().for_each(|board| {
-                    if board.id == dragged_card_board_id {
-                        board.cards.remove_card_with_id(dragged_card_id);
-                    }
-                });
-                app.action_history_manager
-                    .new_action(ActionHistory::MoveCardBetweenBoards(
-                        dragged_card,
-                        dragged_card_board_id,
-                        hovered_board_id,
-                        dragged_card_index,
-                        0,
-                    ));
-                let info_msg = &format!(
-                    "Moved card \"{}\" to board \"{}\"",
-                    dragged_card_name, hovered_board.name
-                );
-                info!("{}", info_msg);
-            }
-        } else {
-            let hovered_card_index = hovered_card_index.unwrap();
-            let dragged_card = dragged_card_board
-                .cards
-                .get_card_with_index(dragged_card_index)
-                .unwrap();
-            // remove dragged card from current board
-            app.boards.get_mut_boards().iter_mut().for_each(|board| {
-                if board.id == dragged_card_board_id {
-                    board.cards.remove_card_with_id(dragged_card_id);
-                }
-            });
-            // add dragged card to hovered board
-            app.boards.get_mut_boards().iter_mut().for_each(|board| {
-                if board.id == hovered_board_id {
-                    board
-                        .cards
-                        .add_card_at_index(hovered_card_index, dragged_card.clone());
-                }
-            });
-            app.action_history_manager
-                .new_action(ActionHistory::MoveCardBetweenBoards(
-                    dragged_card.clone(),
-                    dragged_card_board_id,
-                    hovered_board_id,
-                    dragged_card_index,
-                    hovered_card_index,
-                ));
-            let info_msg = &format!(
-                "Moved card \"{}\" to board \"{}\"",
-                dragged_card_name, hovered_board.name
-            );
-            info!("{}", info_msg);
-            app.send_info_toast(info_msg, None);
+    }
+    preview
+}
+
+/// Takes the current card off the board, it stays in the save and shows up in Archived Cards
+pub fn handle_archive_card(app: &mut App) {
+    let (Some(current_board_id), Some(current_card_id)) =
+        (app.state.current_board_id, app.state.current_card_id)
+    else {
+        app.send_warning_toast(&tr(MessageId::ToastNoCardSelectedToArchive), None);
+        return;
+    };
+    let Some(current_card) = app
+        .boards
+        .get_mut_board_with_id(current_board_id)
+        .and_then(|board| board.cards.get_mut_card_with_id(current_card_id))
+    else {
+        app.send_error_toast(&tr(MessageId::ToastCouldNotFindTheCardToArchive), None);
+        return;
+    };
+    let temp_old_card = current_card.clone();
+    current_card.archive();
+    record_card_edit(
+        &mut app.action_history_manager,
+        temp_old_card,
+        current_card,
+        current_board_id,
+        app.config.date_time_format,
+    );
+    let archived_card = current_card.clone();
+    // A filtered view holds its own copies, the archived card has to leave it as well
+    if let Some(filtered_card) = app
+        .filtered_boards
+        .get_mut_board_with_id(current_board_id)
+        .and_then(|board| board.cards.get_mut_card_with_id(current_card_id))
+    {
+        *filtered_card = archived_card.clone();
+    }
+    refresh_visible_cards_for_board(app, current_board_id);
+    info!("Archived card \"{}\"", archived_card.name);
+    app.send_info_toast(&format!("Archived card \"{}\"", archived_card.name), None);
+}
+
+pub fn open_archived_cards(app: &mut App) {
+    app.set_view(View::ArchivedCards);
+    let has_archived_cards = !app.boards.get_archived_cards().is_empty();
+    app.state
+        .app_list_states
+        .archived_cards
+        .select(has_archived_cards.then_some(0));
+}
+
+/// Puts the current card on the system clipboard as plain text
+pub fn handle_copy_card_to_clipboard(app: &mut App) {
+    let (Some(current_board_id), Some(current_card_id)) =
+        (app.state.current_board_id, app.state.current_card_id)
+    else {
+        app.send_warning_toast(&tr(MessageId::ToastNoCardSelectedToCopy), None);
+        return;
+    };
+    let Some(current_card) = app
+        .boards
+        .get_board_with_id(current_board_id)
+        .and_then(|board| board.cards.get_card_with_id(current_card_id))
+    else {
+        app.send_error_toast(&tr(MessageId::ToastCouldNotFindTheCardToCopy), None);
+        return;
+    };
+    let card_name = current_card.name.clone();
+    match copy_to_clipboard(&card_to_plain_text(current_card)) {
+        Ok(()) => app.send_info_toast(
+            &format!("Copied card \"{}\" to the clipboard", card_name),
+            None,
+        ),
+        Err(err) => {
+            error!("Could not copy card \"{}\": {}", card_name, err);
+            app.send_error_toast(&err, None);
+        }
+    }
+}
+
+/// Puts the current board and its cards on the system clipboard as plain text, with
+/// `visible_only` only the cards the filters let through and a note saying so
+pub fn handle_copy_board_to_clipboard(app: &mut App, visible_only: bool) {
+    let Some(current_board) = app
+        .state
+        .current_board_id
+        .and_then(|board_id| app.boards.get_board_with_id(board_id))
+    else {
+        app.send_warning_toast(&tr(MessageId::ToastNoBoardSelectedToCopy), None);
+        return;
+    };
+    let board_name = current_board.name.clone();
+    let board_text = if visible_only {
+        let mut shown_board = current_board.clone();
+        shown_board.cards = app.get_displayable_cards(current_board);
+        let filter_note = app
+            .get_card_filter()
+            .export_note(app.config.date_time_format);
+        board_to_plain_text(&shown_board, filter_note.as_deref())
+    } else {
+        board_to_plain_text(current_board, None)
+    };
+    match copy_to_clipboard(&board_text) {
+        Ok(()) => app.send_info_toast(
+            &format!("Copied board \"{}\" to the clipboard", board_name),
+            None,
+        ),
+        Err(err) => {
+            error!("Could not copy board \"{}\": {}", board_name, err);
+            app.send_error_toast(&err, None);
         }
     }
 }
 
-pub fn reset_card_drag_mode(app: &mut App) {
-    app.state.card_drag_mode = false;
-    app.state.hovered_board = None;
-    app.state.hovered_card = None;
-    app.state.hovered_card_dimensions = None;
+/// Puts a copy of the current card right below it and selects the copy
+pub fn handle_duplicate_card(app: &mut App) {
+    let (Some(current_board_id), Some(current_card_id)) =
+        (app.state.current_board_id, app.state.current_card_id)
+    else {
+        app.send_warning_toast(&tr(MessageId::ToastNoCardSelectedToDuplicate), None);
+        return;
+    };
+    let Some((card_index, current_card)) =
+        app.boards
+            .get_board_with_id(current_board_id)
+            .and_then(|board| {
+                let card_index = board.cards.get_card_index(current_card_id)?;
+                Some((card_index, board.cards.get_card_with_index(card_index)?))
+            })
+    else {
+        app.send_error_toast(&tr(MessageId::ToastCouldNotFindTheCardToDuplicate), None);
+        return;
+    };
+    let current_card_name = current_card.name.clone();
+    let duplicate_card =
+        current_card.duplicate(|id| app.boards.contains_id(id), app.config.date_time_format);
+    if let Some(board) = app.boards.get_mut_board_with_id(current_board_id) {
+        board
+            .cards
+            .add_card_at_index(card_index + 1, duplicate_card.clone());
+    }
+    // A filtered view holds its own copies, the copy goes below the original there as well
+    if let Some(filtered_board) = app.filtered_boards.get_mut_board_with_id(current_board_id) {
+        let filtered_index = filtered_board
+            .cards
+            .get_card_index(current_card_id)
+            .map_or(filtered_board.cards.len(), |index| index + 1);
+        filtered_board
+            .cards
+            .add_card_at_index(filtered_index, duplicate_card.clone());
+    }
+    app.action_history_manager
+        .new_action(ActionHistory::CreateCard(
+            duplicate_card.clone(),
+            current_board_id,
+        ));
+    app.state.current_card_id = Some(duplicate_card.id);
+    refresh_visible_cards_for_board(app, current_board_id);
+    info!("Duplicated card \"{}\"", duplicate_card.name);
+    app.send_info_toast(
+        &tr_args(
+            MessageId::ToastCardDuplicated,
+            &[("card", &current_card_name)],
+        ),
+        None,
+    );
 }
 
-fn handle_config_menu_action(app: &mut App) -> AppReturn {
-    fn reset_config(app: &mut App, reset_keybindings: bool, warning_message: &str) {
-        let keybindings = app.config.keybindings.clone();
-        app.config = AppConfig::default();
-        app.current_theme = Theme::default();
-        if !reset_keybindings {
-            app.config.keybindings = keybindings;
+/// Swaps the current board with its neighbour among the shown boards, hidden archived boards
+/// keep their place
+pub fn handle_move_board(app: &mut App, move_right: bool) {
+    let Some(current_board_id) = app.state.current_board_id else {
+        app.send_error_toast("No board selected", None);
+        return;
+    };
+    let shown_board_ids = get_shown_board_ids(app);
+    let (Some(shown_index), Some(moved_from_index)) = (
+        shown_board_ids
+            .iter()
+            .position(|board_id| *board_id == current_board_id),
+        app.boards.get_board_index(current_board_id),
+    ) else {
+        app.send_error_toast("No board selected", None);
+        return;
+    };
+    let board_name = app.boards.get_boards()[moved_from_index].name.clone();
+    let neighbour_board_id = if move_right {
+        shown_board_ids.get(shown_index + 1)
+    } else {
+        shown_index
+            .checked_sub(1)
+            .and_then(|index| shown_board_ids.get(index))
+    };
+    let Some(moved_to_index) =
+        neighbour_board_id.and_then(|board_id| app.boards.get_board_index(*board_id))
+    else {
+        let edge = if move_right { "last" } else { "first" };
+        app.send_error_toast(
+            &format!("'{}' is already the {} board", board_name, edge),
+            None,
+        );
+        return;
+    };
+    if app.swap_boards(moved_from_index, moved_to_index).is_none() {
+        app.send_error_toast(&format!("Could not move '{}'", board_name), None);
+        return;
+    }
+    app.action_history_manager
+        .new_action(ActionHistory::MoveBoard(moved_from_index, moved_to_index));
+    let direction = if move_right { "right" } else { "left" };
+    app.send_info_toast(
+        &format!("'{}' moved to the {}", board_name, direction),
+        None,
+    );
+}
+
+pub fn handle_duplicate_board(app: &mut App) {
+    let Some(current_board_id) = app.state.current_board_id else {
+        app.send_warning_toast(&tr(MessageId::ToastNoBoardSelectedToDuplicate), None);
+        return;
+    };
+    let Some(board_index) = app.boards.get_board_index(current_board_id) else {
+        app.send_error_toast(&tr(MessageId::ToastCouldNotFindTheBoardToDuplicate), None);
+        return;
+    };
+    let current_board = &app.boards.get_boards()[board_index];
+    let duplicate_board = current_board.duplicate(|id| app.boards.contains_id(id));
+    let board_name = current_board.name.clone();
+    // A filtered view holds its own copy of the board with only the matching cards, the copy
+    // shows the same ones
+    if let Some(filtered_index) = app.filtered_boards.get_board_index(current_board_id) {
+        let filtered_card_ids = app.filtered_boards.get_boards()[filtered_index]
+            .cards
+            .get_all_card_ids();
+        let mut filtered_duplicate = duplicate_board.clone();
+        filtered_duplicate.cards = current_board
+            .cards
+            .get_all_cards()
+            .iter()
+            .zip(duplicate_board.cards.get_all_cards())
+            .filter(|(card, _)| filtered_card_ids.contains(&card.id))
+            .map(|(_, duplicate_card)| duplicate_card.clone())
+            .collect();
+        app.filtered_boards
+            .get_mut_boards()
+            .insert(filtered_index + 1, filtered_duplicate);
+    }
+    app.boards
+        .get_mut_boards()
+        .insert(board_index + 1, duplicate_board.clone());
+    app.action_history_manager
+        .new_action(ActionHistory::CreateBoard(duplicate_board.clone()));
+    refresh_visible_boards_and_cards(app);
+    info!(
+        "Duplicated board \"{}\" as \"{}\"",
+        board_name, duplicate_board.name
+    );
+    app.send_info_toast(
+        &tr_args(MessageId::ToastBoardDuplicated, &[("board", &board_name)]),
+        None,
+    );
+}
+
+/// Moves through the cards of the current board in the Swimlane view, going on to the next
+/// status lane at the end of a cell
+fn swimlane_go_vertical(app: &mut App, forward: bool) {
+    let grid = app.get_swimlane_grid();
+    let Some(current_board_id) = app
+        .state
+        .current_board_id
+        .filter(|board_id| grid.board_ids.contains(board_id))
+        .or_else(|| grid.board_ids.first().copied())
+    else {
+        app.send_error_toast(&tr(MessageId::ToastNoBoardsToShow), None);
+        return;
+    };
+    let current_status = app.get_current_swimlane_status(&grid);
+    match grid.step(
+        current_board_id,
+        &current_status,
+        app.state.current_card_id,
+        forward,
+    ) {
+        Some((status, card_id)) => {
+            app.state.swimlane_status = status;
+            select_swimlane_card(app, current_board_id, card_id);
         }
-        app.state.set_focus(Focus::ConfigTable);
-        app.state.app_table_states.config.select(Some(0));
-        let write_config_status = write_config(&app.config);
-        if write_config_status.is_err() {
-            error!(
-                "Error writing config file: {}",
-                write_config_status.clone().unwrap_err()
-            );
-            app.send_error_toast(
-                &format!(
-                    "Error writing config file: {}",
-                    write_config_status.unwrap_err()
-                ),
+        None if forward => {
+            app.send_info_toast(
+                &tr(MessageId::ToastCannotGoDownAlreadyAtTheLastStatus),
                 None,
             );
-        } else {
-            warn!("{}", warning_message);
-            app.send_warning_toast(warning_message, None);
+        }
+        None => {
+            app.send_info_toast(&tr(MessageId::ToastCannotGoUpAlreadyAtTheFirstStatus), None);
         }
     }
+}
 
-    if app.state.focus == Focus::SubmitButton {
-        reset_config(app, true, "Reset Config and KeyBindings to default");
-        return AppReturn::Continue;
-    } else if app.state.focus == Focus::ExtraFocus {
-        reset_config(app, false, "Reset Config to default");
-        return AppReturn::Continue;
+/// Moves to the next or previous board in the Swimlane view and stays in the same status lane
+fn swimlane_go_horizontal(app: &mut App, forward: bool) {
+    let current_status = app.get_current_swimlane_status(&app.get_swimlane_grid());
+    let previous_board_id = app.state.current_board_id;
+    if forward {
+        go_right(app);
+    } else {
+        go_left(app);
+    }
+    let Some(current_board_id) = app.state.current_board_id else {
+        return;
+    };
+    if previous_board_id == Some(current_board_id) {
+        return;
     }
-    let app_config_list = &app.config.to_view_list();
-    if app.state.app_table_states.config.selected().unwrap_or(0) < app_config_list.len() {
-        let default_config_item = String::from("");
-        let config_item = &app_config_list
-            [app.state.app_table_states.config.selected().unwrap_or(0)]
+    let card_id = app
+        .get_swimlane_grid()
+        .cell(&current_status, current_board_id)
         .first()
-        .unwrap_or(&default_config_item);
-        let config_enum = ConfigEnum::from_str(config_item);
-        if config_enum.is_err() {
-            error!("Error checking which config item is being edited");
-            return AppReturn::Continue;
-        }
-        let config_enum = config_enum.unwrap();
-        match config_enum {
-            ConfigEnum::Keybindings => {
-                app.set_view(View::EditKeybindings);
-                if app
-                    .state
-                    .app_table_states
-                    .edit_keybindings
-                    .selected()
-                    .is_none()
-                {
-                    app.edit_keybindings_next();
-                }
-            }
-            ConfigEnum::DefaultView => {
-                if app.state.app_list_states.default_view.selected().is_none() {
-                    app.select_default_view_next();
-                }
-                app.set_popup(PopUp::SelectDefaultView);
-            }
-            ConfigEnum::AlwaysLoadLastSave
-            | ConfigEnum::SaveOnExit
-            | ConfigEnum::DisableScrollBar
-            | ConfigEnum::DisableAnimations
-            | ConfigEnum::AutoLogin
-            | ConfigEnum::ShowLineNumbers
-            | ConfigEnum::EnableMouseSupport => {
-                AppConfig::edit_config(
-                    app,
-                    config_enum,
-                    &app.config.get_toggled_value_as_string(config_enum),
-                );
-            }
-            ConfigEnum::DefaultTheme => {
-                app.state.default_theme_mode = true;
-                app.set_popup(PopUp::ChangeTheme);
-            }
-            ConfigEnum::DateFormat => {
-                app.set_popup(PopUp::ChangeDateFormatPopup);
-            }
-            ConfigEnum::DatePickerCalenderFormat => {
-                AppConfig::edit_config(
-                    app,
-                    config_enum,
-                    &app.config.get_toggled_value_as_string(config_enum),
-                );
-                app.widgets
-                    .date_time_picker
-                    .set_calender_type(app.config.date_picker_calender_format.clone());
-            }
-            _ => {
-                app.set_popup(PopUp::EditGeneralConfig);
-            }
-        }
+        .copied();
+    app.state.swimlane_status = current_status;
+    select_swimlane_card(app, current_board_id, card_id);
+}
+
+/// Selects a card from the Swimlane view, scrolling the card window of its board to it so
+/// the card actions treat it as visible
+fn select_swimlane_card(app: &mut App, board_id: (u64, u64), card_id: Option<(u64, u64)>) {
+    app.state.current_board_id = Some(board_id);
+    app.state.current_card_id = card_id;
+    let Some(card_id) = card_id else {
+        return;
+    };
+    let boards: &Boards = if app.filtered_boards.is_empty() {
+        &app.boards
     } else {
-        debug!(
-            "Config item being edited {} is not in the AppConfig list",
-            app.state.app_table_states.config.selected().unwrap_or(0)
-        );
+        &app.filtered_boards
+    };
+    let Some(board) = boards.get_board_with_id(board_id) else {
+        return;
+    };
+    let card_ids = app.get_displayable_cards(board).get_all_card_ids();
+    let no_of_cards_to_show = app.get_no_of_cards_to_show().into();
+    if let Some(visible_card_ids) = app.visible_boards_and_cards.get_mut(&board_id) {
+        *visible_card_ids =
+            scroll_window_to(&card_ids, visible_card_ids, card_id, no_of_cards_to_show);
     }
-    AppReturn::Continue
 }
 
-async fn handle_main_menu_action(app: &mut App<'_>) -> AppReturn {
-    if let Some(selected_index) = app.state.app_list_states.main_menu.selected() {
-        let selected_item = app.main_menu.from_index(selected_index);
-        match selected_item {
-            MainMenuItem::Quit => return handle_exit(app).await,
-            MainMenuItem::Config => {
-                app.set_view(View::ConfigMenu);
-                if app.state.app_table_states.config.selected().is_none() {
-                    app.config_next();
-                }
-            }
-            MainMenuItem::View => {
-                app.set_view(app.config.default_view);
-            }
-            MainMenuItem::Help => {
-                app.set_view(View::HelpMenu);
-            }
-            MainMenuItem::LoadSaveLocal => {
-                app.set_view(View::LoadLocalSave);
-            }
-            MainMenuItem::LoadSaveCloud => {
-                if app.main_menu.logged_in {
-                    app.set_view(View::LoadCloudSave);
-                    reset_preview_boards(app);
-                    app.dispatch(IoEvent::GetCloudData).await;
-                }
-            }
-        }
-    }
-    AppReturn::Continue
+/// Starts the calendar on today
+pub fn open_calendar(app: &mut App) {
+    app.set_view(View::Calendar);
+    app.state.calendar_date = chrono::Local::now().date_naive();
 }
 
-fn handle_default_view_selection(app: &mut App) {
-    let all_views = View::all_views_as_string();
-    let current_selected_view = app
-        .state
-        .app_list_states
-        .default_view
-        .selected()
-        .unwrap_or(0);
-    if current_selected_view < all_views.len() {
-        let selected_view = &all_views[current_selected_view];
-        app.config.default_view = View::from_string(selected_view).unwrap_or(View::MainMenu);
-        AppConfig::edit_config(app, ConfigEnum::DefaultView, selected_view);
-        app.state.app_list_states.default_view.select(Some(0));
-        if app.state.app_table_states.config.selected().is_none() {
-            app.config_next();
-        }
-        if !app.state.z_stack.is_empty() {
-            app.state.z_stack.pop();
-        }
+/// Moves the selected calendar day, the shown month follows it
+fn shift_calendar_date_by_days(app: &mut App, days: i64) {
+    app.state.calendar_date += chrono::Duration::days(days);
+}
+
+/// Shows the next or previous month, keeping the selected day of the month where it exists
+fn shift_calendar_date_by_months(app: &mut App, forward: bool) {
+    let months = chrono::Months::new(1);
+    let shifted_date = if forward {
+        app.state.calendar_date.checked_add_months(months)
     } else {
-        debug!(
-            "Selected view {} is not in the list of all View",
-            current_selected_view
+        app.state.calendar_date.checked_sub_months(months)
+    };
+    if let Some(shifted_date) = shifted_date {
+        app.state.calendar_date = shifted_date;
+    }
+}
+
+/// Lists the cards due on the selected calendar day, warns instead when nothing is due
+fn open_calendar_day_cards(app: &mut App) {
+    if cards_due_on(&app.boards, app.state.calendar_date).is_empty() {
+        app.send_warning_toast(
+            &format!(
+                "Nothing is due on {}",
+                app.state.calendar_date.format("%-d %B %Y")
+            ),
+            None,
         );
+        return;
     }
+    app.set_popup(PopUp::CalendarDayCards);
+    app.state.app_status = AppStatus::Initialized;
+    app.state.app_list_states.calendar_day_cards.select(Some(0));
 }
 
-fn handle_change_date_format(app: &mut App) {
-    let all_date_formats = DateTimeFormat::get_all_date_formats();
-    let current_selected_format = app
+/// Opens the selected due card, the calendar stays behind it
+fn handle_calendar_day_cards(app: &mut App) {
+    let due_cards = cards_due_on(&app.boards, app.state.calendar_date);
+    let Some(due_card) = app
         .state
         .app_list_states
-        .date_format_selector
+        .calendar_day_cards
         .selected()
-        .unwrap_or(0);
-    if current_selected_format < all_date_formats.len() {
-        let selected_format = &all_date_formats[current_selected_format];
-        app.config.date_time_format = *selected_format;
-        AppConfig::edit_config(
-            app,
-            ConfigEnum::DateFormat,
-            selected_format.to_human_readable_string(),
-        );
-        app.state
-            .app_list_states
-            .date_format_selector
-            .select(Some(0));
-        if app.state.app_table_states.config.selected().is_none() {
-            app.config_next();
-        }
-        if !app.state.z_stack.is_empty() {
-            app.state.z_stack.pop();
-        }
-    } else {
-        debug!(
-            "Selected format {} is not in the list of all date formats",
-            current_selected_format
+        .and_then(|index| due_cards.get(index))
+    else {
+        app.send_error_toast(&tr(MessageId::ToastCouldNotFindTheSelectedCard), None);
+        return;
+    };
+    app.close_popup();
+    app.state.current_board_id = Some(due_card.board_id);
+    app.state.current_card_id = Some(due_card.card_id);
+    app.set_popup(PopUp::ViewCard);
+}
+
+/// Lists the cards waiting on someone grouped by who, warns instead when none are waiting
+pub fn open_waiting_on_list(app: &mut App) {
+    let Some(first_card_index) = WaitingOnGroups::new(&app.boards).first_card_index() else {
+        app.send_warning_toast(&tr(MessageId::ToastNoCardsAreWaitingOnAnyone), None);
+        return;
+    };
+    app.set_popup(PopUp::WaitingOnList);
+    app.state.app_status = AppStatus::Initialized;
+    app.state
+        .app_list_states
+        .waiting_on_list
+        .select(Some(first_card_index));
+}
+
+/// Opens the selected waiting card, nothing happens on a group header
+fn handle_waiting_on_list(app: &mut App) {
+    let waiting_on_groups = WaitingOnGroups::new(&app.boards);
+    let Some(waiting_card) = app
+        .state
+        .app_list_states
+        .waiting_on_list
+        .selected()
+        .and_then(|index| waiting_on_groups.card_at(index))
+    else {
+        return;
+    };
+    app.close_popup();
+    app.state.current_board_id = Some(waiting_card.board_id);
+    app.state.current_card_id = Some(waiting_card.card_id);
+    app.set_popup(PopUp::ViewCard);
+}
+
+/// Lists the saved card templates for the new card form, warns instead when there are none
+fn open_card_template_selector(app: &mut App) {
+    app.state.card_templates =
+        load_card_templates(&app.config.save_directory.join(CARD_TEMPLATES_FILE_NAME));
+    if app.state.card_templates.is_empty() {
+        app.send_warning_toast(
+            "No card templates yet, use 'Save as Template' on a card to make one",
+            None,
         );
+        return;
     }
+    app.set_popup(PopUp::SelectCardTemplate);
+    app.state.app_status = AppStatus::Initialized;
+    app.state.app_list_states.card_templates.select(Some(0));
 }
 
-fn handle_change_view(app: &mut App) {
-    let current_index = app
+/// Fills the new card form from the selected template, the form has no fields for the tags and
+/// priority so they are kept with the template until the card is made
+fn handle_select_card_template(app: &mut App) {
+    let Some(template) = app
         .state
         .app_list_states
-        .default_view
+        .card_templates
         .selected()
-        .unwrap_or(0);
-    let all_views = View::all_views_as_string()
+        .and_then(|index| app.state.card_templates.get(index))
+        .cloned()
+    else {
+        app.send_error_toast(&tr(MessageId::ToastCouldNotFindTheSelectedTemplate), None);
+        return;
+    };
+    app.close_popup();
+    app.state.text_buffers.card_name =
+        TextBox::from_string_with_newline_sep(template.name.clone(), true);
+    app.state.text_buffers.card_description =
+        TextBox::from_string_with_newline_sep(template.description.clone(), false);
+    app.state.text_buffers.card_tags = template
+        .tags
         .iter()
-        .filter_map(|s| View::from_string(s))
-        .collect::<Vec<View>>();
+        .map(|tag| TextBox::from_string_with_newline_sep(tag.clone(), true))
+        .collect();
+    app.send_info_toast(&format!("Using template '{}'", template.name), None);
+    app.state.new_card_template = Some(template);
+    app.state.set_focus(Focus::CardName);
+}
 
-    let current_index = if current_index >= all_views.len() {
-        all_views.len() - 1
+/// Keeps the shown card, with any unsaved edits, as a template for new cards
+fn handle_save_card_as_template(app: &mut App) {
+    let card = if let Some((_, card)) = &app.state.card_being_edited {
+        Some(card.clone())
     } else {
-        current_index
+        app.state
+            .current_board_id
+            .zip(app.state.current_card_id)
+            .and_then(|(board_id, card_id)| {
+                app.boards
+                    .get_board_with_id(board_id)
+                    .and_then(|board| board.cards.get_card_with_id(card_id))
+                    .cloned()
+            })
     };
-    let selected_view = all_views[current_index];
-    app.set_view(selected_view);
-}
-
-fn handle_edit_keybindings_action(app: &mut App) {
-    if app
-        .state
-        .app_table_states
-        .edit_keybindings
-        .selected()
-        .is_some()
-        && app.state.focus != Focus::SubmitButton
-    {
-        app.set_popup(PopUp::EditSpecificKeyBinding);
-    } else if app.state.focus == Focus::SubmitButton {
-        app.config.keybindings = KeyBindings::default();
-        warn!("Reset keybindings to default");
-        app.send_warning_toast("Reset keybindings to default", None);
-        app.state.set_focus(Focus::EditKeybindingsTable);
-        app.state.app_table_states.edit_keybindings.select(Some(0));
-        let write_config_status = write_config(&app.config);
-        if let Err(error_message) = write_config_status {
-            error!("Error writing config: {}", error_message);
-            app.send_error_toast(&format!("Error writing config: {}", error_message), None);
+    let Some(card) = card else {
+        app.send_error_toast(
+            &tr(MessageId::ToastCouldNotFindTheCardToSaveAsATemplate),
+            None,
+        );
+        return;
+    };
+    let template = card_template_from(&card);
+    match save_card_template(
+        &app.config.save_directory.join(CARD_TEMPLATES_FILE_NAME),
+        template,
+    ) {
+        Ok(replaced) => {
+            let message = if replaced {
+                format!("Updated the card template '{}'", card.name)
+            } else {
+                format!("Saved '{}' as a card template", card.name)
+            };
+            app.send_info_toast(&message, None);
+        }
+        Err(e) => {
+            error!("Could not save card template: {}", e);
+            app.send_error_toast(&tr(MessageId::ToastCouldNotSaveTheCardTemplate), None);
         }
     }
 }
 
-pub async fn handle_go_to_previous_view(app: &mut App<'_>) -> AppReturn {
-    if let Some(popup) = app.state.z_stack.last() {
-        match popup {
-            PopUp::EditGeneralConfig => {
-                if app.state.current_view == View::CreateTheme {
-                    app.close_popup();
-                } else {
-                    app.set_view(View::ConfigMenu);
-                    if app.state.app_table_states.config.selected().is_none() {
-                        app.config_next()
-                    }
-                }
-                app.state.text_buffers.general_config.reset();
-            }
-            PopUp::EditSpecificKeyBinding => {
-                app.set_view(View::EditKeybindings);
-                app.state.app_table_states.edit_keybindings.select(Some(0));
-            }
-            PopUp::ViewCard => {
-                app.close_popup();
-            }
-            PopUp::ConfirmDiscardCardChanges => {
-                app.close_popup();
-            }
-            PopUp::FilterByTag => {
-                app.state.filter_tags = None;
-                app.state.all_available_tags = None;
-                app.state.app_list_states.filter_by_tag_list.select(None);
-            }
-            PopUp::ChangeTheme => {
-                let config_theme = {
-                    let all_themes = Theme::all_default_themes();
-                    let default_theme = app.config.default_theme.clone();
-                    all_themes.iter().find(|t| t.name == default_theme).cloned()
-                };
-                if let Some(theme) = config_theme {
-                    app.current_theme = theme;
-                }
-            }
-            _ => {}
-        }
-        app.close_popup();
-        if app.state.app_status == AppStatus::UserInput {
-            app.state.app_status = AppStatus::Initialized;
-        }
-        return AppReturn::Continue;
-    }
-    match app.state.current_view {
-        View::MainMenu => handle_exit(app).await,
-        View::EditKeybindings => {
-            app.set_view(View::ConfigMenu);
-            if app.state.app_table_states.config.selected().is_none() {
-                app.config_next()
-            }
-            AppReturn::Continue
-        }
-        View::LoadLocalSave => {
-            app.state.app_list_states.load_save = ListState::default();
-            go_to_previous_view_without_extras(app);
-            AppReturn::Continue
-        }
-        View::Login => {
-            reset_login_form(app);
-            go_to_previous_view_without_extras(app);
-            AppReturn::Continue
-        }
-        View::SignUp => {
-            reset_signup_form(app);
-            go_to_previous_view_without_extras(app);
-            AppReturn::Continue
-        }
-        View::ResetPassword => {
-            reset_reset_password_form(app);
-            go_to_previous_view_without_extras(app);
-            AppReturn::Continue
-        }
-        View::CreateTheme => {
-            app.state.theme_being_edited = Theme::default();
-            go_to_previous_view_without_extras(app);
-            AppReturn::Continue
-        }
-        _ => {
-            go_to_previous_view_without_extras(app);
-            AppReturn::Continue
-        }
-    }
+/// Reads the stats history again so saves made since the view was last open show up
+pub fn open_stats(app: &mut App) {
+    app.set_view(View::Stats);
+    app.state.stats_history =
+        load_stats_history(&app.config.save_directory.join(STATS_HISTORY_FILE_NAME));
 }
 
-fn go_to_previous_view_without_extras(app: &mut App) {
-    if app.state.prev_view == Some(app.state.current_view) {
-        app.set_view(View::MainMenu);
-        if app.state.app_list_states.main_menu.selected().is_none() {
-            app.main_menu_next();
-        }
-    } else {
-        let prev_view = app.state.prev_view.unwrap_or(View::MainMenu);
-        app.set_view(prev_view);
-        if app.state.app_list_states.main_menu.selected().is_none() {
-            app.main_menu_next();
-        }
+/// Puts the selected archived card back on its board with the status it had before
+fn handle_restore_archived_card(app: &mut App) {
+    let archived_cards = app.boards.get_archived_cards();
+    let Some((board_id, card_id)) = app
+        .state
+        .app_list_states
+        .archived_cards
+        .selected()
+        .and_then(|index| archived_cards.get(index))
+        .map(|(board_id, card)| (*board_id, card.id))
+    else {
+        app.send_warning_toast(&tr(MessageId::ToastNoArchivedCardSelected), None);
+        return;
+    };
+    let archived_card_count = archived_cards.len();
+    let Some(card) = app
+        .boards
+        .get_mut_board_with_id(board_id)
+        .and_then(|board| board.cards.get_mut_card_with_id(card_id))
+    else {
+        return;
+    };
+    let temp_old_card = card.clone();
+    card.restore_from_archive();
+    record_card_edit(
+        &mut app.action_history_manager,
+        temp_old_card,
+        card,
+        board_id,
+        app.config.date_time_format,
+    );
+    let restored_card = card.clone();
+    if let Some(filtered_card) = app
+        .filtered_boards
+        .get_mut_board_with_id(board_id)
+        .and_then(|board| board.cards.get_mut_card_with_id(card_id))
+    {
+        *filtered_card = restored_card.clone();
     }
+    // Keep the selection on the card that moved up into the restored one's row
+    let remaining_card_count = archived_card_count - 1;
+    let selected_index = app.state.app_list_states.archived_cards.selected();
+    app.state.app_list_states.archived_cards.select(
+        selected_index
+            .filter(|_| remaining_card_count > 0)
+            .map(|index| index.min(remaining_card_count - 1)),
+    );
+    refresh_visible_boards_and_cards(app);
+    info!(
+        "Restored card \"{}\" as {}",
+        restored_card.name, restored_card.card_status
+    );
+    app.send_info_toast(
+        &format!(
+            "Restored card \"{}\" as {}",
+            restored_card.name, restored_card.card_status
+        ),
+        None,
+    );
 }
 
 fn handle_change_card_status(app: &mut App, status: Option<CardStatus>) -> AppReturn {
+    let status_selections = app.get_current_board_status_selections();
     let selected_status = if let Some(status) = status {
-        status
+        let board_status = app
+            .state
+            .current_board_id
+            .and_then(|board_id| app.boards.get_board_with_id(board_id))
+            .map(|board| board.find_status_selection(&status));
+        match board_status {
+            Some(Some(board_status)) => board_status,
+            Some(None) => {
+                app.send_warning_toast(
+                    &format!("This board has no status for \"{}\"", status),
+                    None,
+                );
+                return AppReturn::Continue;
+            }
+            None => StatusSelection {
+                card_status: status,
+                custom_status: None,
+            },
+        }
     } else {
         let current_index = app
             .state
             .app_list_states
             .card_status_selector
             .selected()
-            .unwrap_or(0);
-        let all_statuses = CardStatus::all();
-
-        let current_index = if current_index >= all_statuses.len() {
-            all_statuses.len() - 1
-        } else {
-            current_index
-        };
-        all_statuses[current_index].clone()
+            .unwrap_or(0)
+            .min(status_selections.len() - 1);
+        status_selections[current_index].clone()
     };
 
+    let is_completing = selected_status.card_status == CardStatus::Complete;
     if let Some(card_being_edited) = &mut app.state.card_being_edited {
-        let card = &mut card_being_edited.1;
-        if selected_status == CardStatus::Complete {
-            card.date_completed = chrono::Local::now()
-                .format(app.config.date_time_format.to_parser_string())
-                .to_string();
-        } else {
-            card.date_completed = FIELD_NOT_SET.to_string();
-        }
-        card.card_status = selected_status;
+        selected_status.apply(&mut card_being_edited.1, app.config.date_time_format);
+        let waiting_card = (is_completing && card_being_edited.1.waiting_on.is_some())
+            .then_some((card_being_edited.0, card_being_edited.1.id));
         app.close_popup();
         app.state.set_focus(Focus::CardStatus);
+        if let Some(waiting_card) = waiting_card {
+            app.state.waiting_on_to_clear = Some(waiting_card);
+            app.set_popup(PopUp::ConfirmClearWaitingOn);
+        }
         return AppReturn::Continue;
+    } else if let Some(card_ids) = app.get_selected_card_ids() {
+        let date_time_format = app.config.date_time_format;
+        let description = format!("Changed status to \"{}\"", selected_status);
+        return handle_edit_card_range(app, card_ids, &description, |card| {
+            selected_status.apply(card, date_time_format);
+        });
     } else if let Some(current_board_id) = app.state.current_board_id {
         let mut card_found = String::new();
+        let mut waiting_card = None;
         let boards: &mut Boards = if app.filtered_boards.is_empty() {
             &mut app.boards
         } else {
@@ -3999,24 +7358,18 @@ fn handle_change_card_status(app: &mut App, status: Option<CardStatus>) -> AppRe
                 if let Some(current_card) =
                     current_board.cards.get_mut_card_with_id(current_card_id)
                 {
+                    if is_completing && current_card.waiting_on.is_some() {
+                        waiting_card = Some((current_board_id, current_card_id));
+                    }
                     let temp_old_card = current_card.clone();
-                    current_card.card_status = selected_status.clone();
-                    if current_card.card_status == CardStatus::Complete {
-                        current_card.date_completed = chrono::Local::now()
-                            .format(app.config.date_time_format.to_parser_string())
-                            .to_string();
-                    } else {
-                        current_card.date_completed = FIELD_NOT_SET.to_string();
-                    }
-                    current_card.date_modified = chrono::Local::now()
-                        .format(app.config.date_time_format.to_parser_string())
-                        .to_string();
-                    app.action_history_manager
-                        .new_action(ActionHistory::EditCard(
-                            temp_old_card,
-                            current_card.clone(),
-                            current_board_id,
-                        ));
+                    selected_status.apply(current_card, app.config.date_time_format);
+                    record_card_edit(
+                        &mut app.action_history_manager,
+                        temp_old_card,
+                        current_card,
+                        current_board_id,
+                        app.config.date_time_format,
+                    );
                     info!(
                         "Changed status to \"{}\" for card \"{}\"",
                         selected_status, current_card.name
@@ -4027,6 +7380,13 @@ fn handle_change_card_status(app: &mut App, status: Option<CardStatus>) -> AppRe
             }
         }
         if !card_found.is_empty() {
+            if app
+                .state
+                .boards_with_hidden_completed_cards
+                .contains(&current_board_id)
+            {
+                refresh_visible_cards_for_board(app, current_board_id);
+            }
             app.send_info_toast(
                 &format!(
                     "Changed status to \"{}\" for card \"{}\"",
@@ -4034,6 +7394,10 @@ fn handle_change_card_status(app: &mut App, status: Option<CardStatus>) -> AppRe
                 ),
                 None,
             );
+            if let Some(waiting_card) = waiting_card {
+                app.state.waiting_on_to_clear = Some(waiting_card);
+                app.set_popup(PopUp::ConfirmClearWaitingOn);
+            }
         } else {
             app.send_error_toast("Error Could not find current card", None);
         }
@@ -4041,6 +7405,246 @@ fn handle_change_card_status(app: &mut App, status: Option<CardStatus>) -> AppRe
     AppReturn::Continue
 }
 
+/// Opens the highlight color picker for the current card with its current color preselected,
+/// returns false if there is no current card. While the card is being edited the picked color
+/// goes to the unsaved card
+pub fn open_card_highlight_color_selector(app: &mut App) -> bool {
+    let current_highlight_color = if let Some((_, card_being_edited)) = &app.state.card_being_edited
+    {
+        card_being_edited.highlight_color
+    } else if let (Some(current_board_id), Some(current_card_id)) =
+        (app.state.current_board_id, app.state.current_card_id)
+    {
+        match app
+            .boards
+            .get_board_with_id(current_board_id)
+            .and_then(|board| board.cards.get_card_with_id(current_card_id))
+        {
+            Some(current_card) => current_card.highlight_color,
+            None => return false,
+        }
+    } else {
+        return false;
+    };
+    let selected_index = TextColorOptions::card_highlight_options()
+        .iter()
+        .position(|color| *color == current_highlight_color)
+        .unwrap_or(0);
+    app.set_popup(PopUp::CardHighlightColorSelector);
+    if app.state.card_being_edited.is_none() {
+        app.state.app_status = AppStatus::Initialized;
+    }
+    app.state
+        .app_list_states
+        .card_highlight_color_selector
+        .select(Some(selected_index));
+    true
+}
+
+fn handle_change_card_highlight_color(app: &mut App) -> AppReturn {
+    let all_colors = TextColorOptions::card_highlight_options();
+    let current_index = app
+        .state
+        .app_list_states
+        .card_highlight_color_selector
+        .selected()
+        .unwrap_or(0)
+        .min(all_colors.len() - 1);
+    let selected_color = all_colors[current_index];
+    let selected_color_name = selected_color
+        .map(|color| color.to_string())
+        .unwrap_or_else(|| "None".to_string());
+
+    if let Some((_, card_being_edited)) = &mut app.state.card_being_edited {
+        card_being_edited.highlight_color = selected_color;
+        app.close_popup();
+        app.state.set_focus(Focus::CardColor);
+        return AppReturn::Continue;
+    }
+    let (current_board_id, current_card_id) =
+        match (app.state.current_board_id, app.state.current_card_id) {
+            (Some(current_board_id), Some(current_card_id)) => (current_board_id, current_card_id),
+            _ => {
+                app.send_error_toast("Error Could not find current card", None);
+                return AppReturn::Continue;
+            }
+        };
+    let mut card_found = String::new();
+    if let Some(current_card) = app
+        .boards
+        .get_mut_board_with_id(current_board_id)
+        .and_then(|board| board.cards.get_mut_card_with_id(current_card_id))
+    {
+        let temp_old_card = current_card.clone();
+        current_card.highlight_color = selected_color;
+        record_card_edit(
+            &mut app.action_history_manager,
+            temp_old_card,
+            current_card,
+            current_board_id,
+            app.config.date_time_format,
+        );
+        info!(
+            "Changed highlight color to \"{}\" for card \"{}\"",
+            selected_color_name, current_card.name
+        );
+        card_found.clone_from(&current_card.name);
+    }
+    if card_found.is_empty() {
+        app.send_error_toast("Error Could not find current card", None);
+        return AppReturn::Continue;
+    }
+    if let Some(filtered_card) = app
+        .filtered_boards
+        .get_mut_board_with_id(current_board_id)
+        .and_then(|board| board.cards.get_mut_card_with_id(current_card_id))
+    {
+        filtered_card.highlight_color = selected_color;
+    }
+    app.close_popup();
+    app.send_info_toast(
+        &format!(
+            "Changed highlight color to \"{}\" for card \"{}\"",
+            selected_color_name, card_found
+        ),
+        None,
+    );
+    AppReturn::Continue
+}
+
+/// Opens the accent color picker for the current board with its current color preselected,
+/// returns false if there is no current board
+pub fn open_board_accent_color_selector(app: &mut App) -> bool {
+    let Some(current_board) = app
+        .state
+        .current_board_id
+        .and_then(|board_id| app.boards.get_board_with_id(board_id))
+    else {
+        return false;
+    };
+    let selected_index = TextColorOptions::card_highlight_options()
+        .iter()
+        .position(|color| *color == current_board.accent_color)
+        .unwrap_or(0);
+    app.set_popup(PopUp::BoardAccentColorSelector);
+    app.state.app_status = AppStatus::Initialized;
+    app.state
+        .app_list_states
+        .board_accent_color_selector
+        .select(Some(selected_index));
+    true
+}
+
+fn handle_change_board_accent_color(app: &mut App) -> AppReturn {
+    let all_colors = TextColorOptions::card_highlight_options();
+    let current_index = app
+        .state
+        .app_list_states
+        .board_accent_color_selector
+        .selected()
+        .unwrap_or(0)
+        .min(all_colors.len() - 1);
+    let selected_color = all_colors[current_index];
+    let Some(current_board) = app
+        .state
+        .current_board_id
+        .and_then(|board_id| app.boards.get_mut_board_with_id(board_id))
+    else {
+        app.send_error_toast(&tr(MessageId::ToastErrorCouldNotFindCurrentBoard), None);
+        return AppReturn::Continue;
+    };
+    let old_board = current_board.clone();
+    current_board.accent_color = selected_color;
+    let new_board = current_board.clone();
+    app.action_history_manager
+        .new_action(ActionHistory::EditBoard(old_board, new_board.clone()));
+    if let Some(filtered_board) = app.filtered_boards.get_mut_board_with_id(new_board.id) {
+        filtered_board.accent_color = selected_color;
+    }
+    app.close_popup();
+    let info_msg = match selected_color {
+        Some(color) => format!(
+            "Changed accent color to \"{}\" for board \"{}\"",
+            color, new_board.name
+        ),
+        None => format!("Reset accent color for board \"{}\"", new_board.name),
+    };
+    info!("{}", info_msg);
+    app.send_info_toast(&info_msg, None);
+    AppReturn::Continue
+}
+
+/// Turns keeping the cards of the current board sorted by priority on or off. Turning it on sorts
+/// the board right away, the flag and the sort are undone together
+pub fn toggle_auto_sort_by_priority(app: &mut App) {
+    let Some(current_board) = app
+        .state
+        .current_board_id
+        .and_then(|board_id| app.boards.get_mut_board_with_id(board_id))
+    else {
+        app.send_error_toast(&tr(MessageId::ToastErrorCouldNotFindCurrentBoard), None);
+        return;
+    };
+    let old_board = current_board.clone();
+    current_board.auto_sort_by_priority = !current_board.auto_sort_by_priority;
+    let original_card_ids = current_board.cards.get_all_card_ids();
+    if current_board.auto_sort_by_priority {
+        current_board.sort_cards(CardSortOrder::Priority);
+    }
+    let sorted_card_ids = current_board.cards.get_all_card_ids();
+    let new_board = current_board.clone();
+    if let Some(filtered_board) = app.filtered_boards.get_mut_board_with_id(new_board.id) {
+        filtered_board.auto_sort_by_priority = new_board.auto_sort_by_priority;
+        filtered_board.cards.reorder(&sorted_card_ids);
+    }
+    let edit_board = ActionHistory::EditBoard(old_board, new_board.clone());
+    let action = if sorted_card_ids == original_card_ids {
+        edit_board
+    } else {
+        ActionHistory::Batch(vec![
+            edit_board,
+            ActionHistory::SortCards(new_board.id, original_card_ids, sorted_card_ids),
+        ])
+    };
+    app.action_history_manager.new_action(action);
+    let info_msg = if new_board.auto_sort_by_priority {
+        format!(
+            "Cards in \"{}\" are now kept sorted by priority",
+            new_board.name
+        )
+    } else {
+        format!(
+            "Turned off auto sort by priority for \"{}\"",
+            new_board.name
+        )
+    };
+    info!("{}", info_msg);
+    app.send_info_toast(&info_msg, None);
+    refresh_visible_cards_for_board(app, new_board.id);
+}
+
+/// Steps the color of the card open in the card view to the next or previous named color, the
+/// change is saved with the rest of the card edits
+fn cycle_card_color(app: &mut App, forward: bool) {
+    if app.state.card_being_edited.is_none() {
+        handle_edit_new_card(app);
+    }
+    let Some((_, card_being_edited)) = &mut app.state.card_being_edited else {
+        return;
+    };
+    let all_colors = TextColorOptions::card_highlight_options();
+    let current_index = all_colors
+        .iter()
+        .position(|color| *color == card_being_edited.highlight_color)
+        .unwrap_or(0);
+    let next_index = if forward {
+        (current_index + 1) % all_colors.len()
+    } else {
+        (current_index + all_colors.len() - 1) % all_colors.len()
+    };
+    card_being_edited.highlight_color = all_colors[next_index];
+}
+
 fn handle_change_card_priority(app: &mut App, priority: Option<CardPriority>) -> AppReturn {
     let selected_priority = if let Some(priority) = priority {
         priority
@@ -4066,6 +7670,11 @@ fn handle_change_card_priority(app: &mut App, priority: Option<CardPriority>) ->
         app.close_popup();
         app.state.set_focus(Focus::CardPriority);
         return AppReturn::Continue;
+    } else if let Some(card_ids) = app.get_selected_card_ids() {
+        let description = format!("Changed priority to \"{}\"", selected_priority);
+        return handle_edit_card_range(app, card_ids, &description, |card| {
+            card.priority = selected_priority.clone();
+        });
     } else if let Some(current_board_id) = app.state.current_board_id {
         let mut card_found = String::new();
         let boards: &mut Boards = if app.filtered_boards.is_empty() {
@@ -4080,15 +7689,13 @@ fn handle_change_card_priority(app: &mut App, priority: Option<CardPriority>) ->
                 {
                     let temp_old_card = current_card.clone();
                     current_card.priority = selected_priority.clone();
-                    current_card.date_modified = chrono::Local::now()
-                        .format(app.config.date_time_format.to_parser_string())
-                        .to_string();
-                    app.action_history_manager
-                        .new_action(ActionHistory::EditCard(
-                            temp_old_card,
-                            current_card.clone(),
-                            current_board_id,
-                        ));
+                    record_card_edit(
+                        &mut app.action_history_manager,
+                        temp_old_card,
+                        current_card,
+                        current_board_id,
+                        app.config.date_time_format,
+                    );
                     info!(
                         "Changed priority to \"{}\" for card \"{}\"",
                         selected_priority, current_card.name
@@ -4177,23 +7784,25 @@ fn handle_edit_specific_keybinding(app: &mut App) {
             app.send_error_toast("Selected Keybinding not found", None);
             app.state.app_table_states.edit_keybindings.select(None);
         }
-        app.set_view(View::EditKeybindings);
-        if app
-            .state
-            .app_table_states
-            .edit_keybindings
-            .selected()
-            .is_none()
-        {
-            app.edit_keybindings_next()
+        if !app.state.keybinding_edit_from_command_palette {
+            app.set_view(View::EditKeybindings);
+            if app
+                .state
+                .app_table_states
+                .edit_keybindings
+                .selected()
+                .is_none()
+            {
+                app.edit_keybindings_next()
+            }
         }
         app.state.edited_keybinding = None;
-        let write_config_status = write_config(&app.config);
+        let write_config_status = app.write_config(&app.config);
         if let Err(error_message) = write_config_status {
             error!("Error writing config: {}", error_message);
             app.send_error_toast(&format!("Error writing config: {}", error_message), None);
         }
-    } else {
+    } else if !app.state.keybinding_edit_from_command_palette {
         app.set_view(View::EditKeybindings);
         if app
             .state
@@ -4207,7 +7816,40 @@ fn handle_edit_specific_keybinding(app: &mut App) {
     }
 }
 
-fn handle_new_board_action(app: &mut App) {
+/// Closes the keybinding editor and reopens the command palette when the edit was started from it
+fn return_to_command_palette_after_keybinding_edit(app: &mut App) -> bool {
+    if !app.state.keybinding_edit_from_command_palette {
+        return false;
+    }
+    app.state.keybinding_edit_from_command_palette = false;
+    app.state.edited_keybinding = None;
+    app.state.app_table_states.edit_keybindings.select(None);
+    app.close_popup();
+    app.set_popup(PopUp::CommandPalette);
+    true
+}
+
+fn is_rapid_entry_submit_allowed(app: &App) -> bool {
+    app.state.z_stack.is_empty() && matches!(app.state.current_view, View::NewCard | View::NewBoard)
+}
+
+/// Submits the new card/board form and keeps it open for the next entry,
+/// regardless of the rapid_card_entry config
+fn handle_rapid_entry_submit(app: &mut App) {
+    let previous_focus = app.state.focus;
+    app.state.set_focus(Focus::SubmitButton);
+    let stayed_in_form = match app.state.current_view {
+        View::NewCard => handle_new_card_action(app, true, false),
+        View::NewBoard => handle_new_board_action(app, true),
+        _ => false,
+    };
+    if !stayed_in_form && is_rapid_entry_submit_allowed(app) {
+        app.state.set_focus(previous_focus);
+    }
+}
+
+/// Returns true if the form was kept open for rapid entry of the next board
+fn handle_new_board_action(app: &mut App, rapid_entry: bool) -> bool {
     if app.state.focus == Focus::SubmitButton {
         let new_board_name = app.state.text_buffers.board_name.get_joined_lines();
         let new_board_name = new_board_name.trim();
@@ -4220,12 +7862,24 @@ fn handle_new_board_action(app: &mut App) {
                 break;
             }
         }
+        let mut created_board_id = None;
         if !new_board_name.is_empty() && !same_name_exists {
-            let new_board = Board::new(new_board_name, new_board_description);
+            let mut new_board = Board::new(new_board_name, new_board_description);
+            new_board.id = app.boards.generate_unused_id();
             app.boards.add_board(new_board.clone());
             app.action_history_manager
                 .new_action(ActionHistory::CreateBoard(new_board.clone()));
-            app.state.current_board_id = Some(new_board.id);
+            created_board_id = Some(new_board.id);
+            if rapid_entry {
+                reveal_created_item(app, new_board.id, None);
+                reset_new_board_form(app);
+                app.state.set_focus(Focus::NewBoardName);
+                app.state.app_status = AppStatus::UserInput;
+                app.send_info_toast(&format!("Created board '{}'", new_board.name), None);
+                return true;
+            }
+            // Cleared before leaving so the submitted board is not offered as a draft
+            reset_new_board_form(app);
             app.set_view(
                 *app.state
                     .prev_view
@@ -4234,7 +7888,7 @@ fn handle_new_board_action(app: &mut App) {
             );
         } else {
             warn!("New board name is empty or already exists");
-            app.send_warning_toast("New board name is empty or already exists", None);
+            app.send_warning_toast(&tr(MessageId::ToastNewBoardNameInvalid), None);
         }
         app.set_view(
             *app.state
@@ -4245,15 +7899,158 @@ fn handle_new_board_action(app: &mut App) {
         if let Some(previous_focus) = &app.state.prev_focus {
             app.state.set_focus(*previous_focus);
         }
-        refresh_visible_boards_and_cards(app);
+        match created_board_id {
+            Some(board_id) => reveal_created_item(app, board_id, None),
+            None => refresh_visible_boards_and_cards(app),
+        }
         reset_new_board_form(app);
     } else if app.state.app_status == AppStatus::Initialized {
         app.state.app_status = AppStatus::UserInput;
     }
     if !app.filtered_boards.is_empty() {
         app.state.filter_tags = None;
-        app.send_warning_toast("Filter Reset", None);
+        app.send_warning_toast(&tr(MessageId::ToastFilterReset), None);
+    }
+    false
+}
+
+/// Opens the edit board form pre-filled from the current board, returns false if there is none
+pub fn open_edit_board_form(app: &mut App) -> bool {
+    let Some(board) = app
+        .state
+        .current_board_id
+        .and_then(|board_id| app.boards.get_board_with_id(board_id))
+    else {
+        return false;
+    };
+    let (board_id, board_name, board_description) =
+        (board.id, board.name.clone(), board.description.clone());
+    app.state.board_being_edited = Some(board_id);
+    app.state.text_buffers.board_name.reset();
+    app.state.text_buffers.board_name.insert_str(board_name);
+    // insert_str would reverse the order of the lines, so the description gets a new TextBox
+    app.state.text_buffers.board_description =
+        TextBox::from_string_with_newline_sep(board_description, false);
+    app.state.prev_focus = Some(app.state.focus);
+    app.set_view(View::EditBoard);
+    app.state.set_focus(Focus::NewBoardName);
+    true
+}
+
+/// Applies the edit board form to the board it was opened for, the form stays open only when
+/// the new name is empty or taken
+fn handle_edit_board_action(app: &mut App) {
+    if app.state.focus != Focus::SubmitButton {
+        if app.state.app_status == AppStatus::Initialized {
+            app.state.app_status = AppStatus::UserInput;
+        }
+        return;
+    }
+    let Some(board_id) = app.state.board_being_edited else {
+        app.send_error_toast(&tr(MessageId::ToastCouldNotFindTheBoardBeingEdited), None);
+        return;
+    };
+    let new_board_name = app.state.text_buffers.board_name.get_joined_lines();
+    let new_board_name = new_board_name.trim().to_string();
+    let new_board_description = app.state.text_buffers.board_description.get_joined_lines();
+    let new_board_description = new_board_description.trim().to_string();
+    let same_name_exists = app
+        .boards
+        .get_boards()
+        .iter()
+        .any(|board| board.id != board_id && board.name == new_board_name);
+    if new_board_name.is_empty() || same_name_exists {
+        warn!("Edited board name is empty or already exists");
+        app.send_warning_toast(&tr(MessageId::ToastNewBoardNameInvalid), None);
+        return;
+    }
+    let Some(board) = app.boards.get_mut_board_with_id(board_id) else {
+        app.send_error_toast(&tr(MessageId::ToastCouldNotFindTheBoardBeingEdited), None);
+        return;
+    };
+    if board.name != new_board_name || board.description != new_board_description {
+        let old_board = board.clone();
+        let mut new_board = board.clone();
+        new_board.name = new_board_name;
+        new_board.description = new_board_description;
+        app.action_history_manager
+            .new_action(ActionHistory::EditBoard(old_board, new_board.clone()));
+        if let Some(board) = app.boards.get_mut_board_with_id(board_id) {
+            board.name.clone_from(&new_board.name);
+            board.description.clone_from(&new_board.description);
+        }
+        app.send_info_toast(&format!("Edited board '{}'", new_board.name), None);
+    }
+    app.set_view(
+        *app.state
+            .prev_view
+            .as_ref()
+            .unwrap_or(&app.config.default_view),
+    );
+    if let Some(previous_focus) = &app.state.prev_focus {
+        app.state.set_focus(*previous_focus);
+    }
+    refresh_visible_boards_and_cards(app);
+    reset_edit_board_form(app);
+}
+
+/// Notes are only copied into the text buffer here, boards never keep a buffer of their own
+pub fn open_edit_board_notes_form(app: &mut App) -> bool {
+    let Some(board) = app
+        .state
+        .current_board_id
+        .and_then(|board_id| app.boards.get_board_with_id(board_id))
+    else {
+        return false;
+    };
+    let (board_id, board_notes) = (board.id, board.notes.clone());
+    app.state.board_being_edited = Some(board_id);
+    app.state.text_buffers.board_notes = TextBox::from_string_with_newline_sep(board_notes, false);
+    app.state.prev_focus = Some(app.state.focus);
+    app.set_view(View::EditBoardNotes);
+    app.state.set_focus(Focus::BoardNotes);
+    true
+}
+
+/// Saves the notes through the same history entry as the edit board form so undo covers them
+fn handle_edit_board_notes_action(app: &mut App) {
+    if app.state.focus != Focus::SubmitButton {
+        if app.state.app_status == AppStatus::Initialized {
+            app.state.app_status = AppStatus::UserInput;
+        }
+        return;
     }
+    let Some(board_id) = app.state.board_being_edited else {
+        app.send_error_toast(&tr(MessageId::ToastCouldNotFindTheBoardBeingEdited), None);
+        return;
+    };
+    let new_board_notes = app.state.text_buffers.board_notes.get_joined_lines();
+    let new_board_notes = new_board_notes.trim_end().to_string();
+    let Some(board) = app.boards.get_mut_board_with_id(board_id) else {
+        app.send_error_toast(&tr(MessageId::ToastCouldNotFindTheBoardBeingEdited), None);
+        return;
+    };
+    if board.notes != new_board_notes {
+        let old_board = board.clone();
+        let mut new_board = board.clone();
+        new_board.notes = new_board_notes;
+        app.action_history_manager
+            .new_action(ActionHistory::EditBoard(old_board, new_board.clone()));
+        if let Some(board) = app.boards.get_mut_board_with_id(board_id) {
+            board.notes.clone_from(&new_board.notes);
+        }
+        app.send_info_toast(&format!("Saved notes for board '{}'", new_board.name), None);
+    }
+    app.set_view(
+        *app.state
+            .prev_view
+            .as_ref()
+            .unwrap_or(&app.config.default_view),
+    );
+    if let Some(previous_focus) = &app.state.prev_focus {
+        app.state.set_focus(*previous_focus);
+    }
+    reset_edit_board_notes_form(app);
 }
 
 fn handle_general_actions_view_card(app: &mut App) -> AppReturn {
@@ -4272,21 +8069,107 @@ fn handle_general_actions_view_card(app: &mut App) -> AppReturn {
             app.set_popup(PopUp::CardStatusSelector);
             AppReturn::Continue
         }
-        Focus::CardName | Focus::CardDescription | Focus::CardTags | Focus::CardComments => {
-            handle_edit_new_card(app)
+        Focus::CardColor => {
+            if app.state.card_being_edited.is_none() {
+                handle_edit_new_card(app);
+            }
+            open_card_highlight_color_selector(app);
+            AppReturn::Continue
         }
-        Focus::CardDueDate => {
+        Focus::CardName
+        | Focus::CardDescription
+        | Focus::CardEstimate
+        | Focus::CardEstimatedTime
+        | Focus::CardActualTime
+        | Focus::CardWaitingOn
+        | Focus::CardTags
+        | Focus::CardComments => handle_edit_new_card(app),
+        Focus::CardChecklist => handle_new_checklist_item(app),
+        Focus::CardStartDate | Focus::CardDueDate => {
             if app.state.card_being_edited.is_none() {
                 handle_edit_new_card(app);
             }
-            app.set_popup(PopUp::DateTimePicker);
+            open_card_date_picker(app);
             AppReturn::Continue
         }
         Focus::SubmitButton => handle_edit_card_submit(app),
+        Focus::SaveCardAsTemplate => {
+            handle_save_card_as_template(app);
+            AppReturn::Continue
+        }
         _ => AppReturn::Continue,
     }
 }
 
+/// Starts editing the card and adds an empty item after the selected one, ready to be typed in
+fn handle_new_checklist_item(app: &mut App) -> AppReturn {
+    handle_edit_new_card(app);
+    if let Some((_, card_being_edited)) = &mut app.state.card_being_edited {
+        let insert_index = app
+            .state
+            .app_list_states
+            .card_view_checklist
+            .selected()
+            .filter(|selected| *selected < card_being_edited.checklist.len())
+            .map_or(card_being_edited.checklist.len(), |selected| selected + 1);
+        card_being_edited
+            .checklist
+            .insert(insert_index, ChecklistItem::new(""));
+        app.state
+            .text_buffers
+            .prepare_checklist_for_card(card_being_edited);
+        app.state
+            .app_list_states
+            .card_view_checklist
+            .select(Some(insert_index));
+    }
+    AppReturn::Continue
+}
+
+/// Ticks or unticks the selected item, on the unsaved card while it is being edited and straight
+/// on the board otherwise so it can be undone like any other card edit
+fn handle_toggle_checklist_item(app: &mut App) {
+    let selected_index =
+        if let Some(selected_index) = app.state.app_list_states.card_view_checklist.selected() {
+            selected_index
+        } else {
+            app.send_warning_toast(&tr(MessageId::ToastNoChecklistItemSelected), None);
+            return;
+        };
+    if let Some((_, card_being_edited)) = &mut app.state.card_being_edited {
+        if let Some(checklist_item) = card_being_edited.checklist.get_mut(selected_index) {
+            checklist_item.completed = !checklist_item.completed;
+        }
+        return;
+    }
+    let (current_board_id, current_card_id) =
+        match (app.state.current_board_id, app.state.current_card_id) {
+            (Some(board_id), Some(card_id)) => (board_id, card_id),
+            _ => return,
+        };
+    let boards: &mut Boards = if app.filtered_boards.is_empty() {
+        &mut app.boards
+    } else {
+        &mut app.filtered_boards
+    };
+    if let Some(current_card) = boards
+        .get_mut_board_with_id(current_board_id)
+        .and_then(|board| board.cards.get_mut_card_with_id(current_card_id))
+    {
+        let temp_old_card = current_card.clone();
+        if let Some(checklist_item) = current_card.checklist.get_mut(selected_index) {
+            checklist_item.completed = !checklist_item.completed;
+        }
+        record_card_edit(
+            &mut app.action_history_manager,
+            temp_old_card,
+            current_card,
+            current_board_id,
+            app.config.date_time_format,
+        );
+    }
+}
+
 fn handle_tag_picker_action(app: &mut App) {
     if let (Some((_, card_being_edited)), Some(card_tag_selected), Some(selected_tag_index)) = (
         &mut app.state.card_being_edited,
@@ -4303,6 +8186,21 @@ fn handle_tag_picker_action(app: &mut App) {
     }
 }
 
+/// Opens the date picker on the focused start or due date, starting from the date already set
+/// on the card being edited
+fn open_card_date_picker(app: &mut App) {
+    app.state.picking_card_start_date = app.state.focus == Focus::CardStartDate;
+    if let Some((_, card)) = &app.state.card_being_edited {
+        let card_date = if app.state.picking_card_start_date {
+            card.start_date.as_deref()
+        } else {
+            Some(card.due_date.as_str())
+        };
+        app.widgets.date_time_picker.selected_date_time = card_date.and_then(parse_date_time);
+    }
+    app.set_popup(PopUp::DateTimePicker);
+}
+
 fn handle_date_time_picker_action(app: &mut App, key: Option<Key>, action: Option<Action>) {
     let action = key.map_or_else(|| action, |key| app.config.keybindings.key_to_action(&key));
     if let Some(action) = action {
@@ -4349,18 +8247,20 @@ fn handle_date_time_picker_action(app: &mut App, key: Option<Key>, action: Optio
                 | Focus::DTPMinute
                 | Focus::DTPSecond => {
                     if let Some((_, card)) = &mut app.state.card_being_edited {
-                        if let Some(selected_date) = app.widgets.date_time_picker.selected_date_time
-                        {
-                            card.due_date = selected_date
-                                .format(app.config.date_time_format.to_parser_string())
-                                .to_string();
+                        let picked_date = app
+                            .widgets
+                            .date_time_picker
+                            .selected_date_time
+                            .unwrap_or_else(|| chrono::Local::now().naive_local())
+                            .format(app.config.date_time_format.to_parser_string())
+                            .to_string();
+                        if app.state.picking_card_start_date {
+                            debug!("Changed start date to {}", picked_date);
+                            card.start_date = Some(picked_date);
                         } else {
-                            card.due_date = chrono::Local::now()
-                                .naive_local()
-                                .format(app.config.date_time_format.to_parser_string())
-                                .to_string()
+                            debug!("Changed due date to {}", picked_date);
+                            card.due_date = picked_date;
                         }
-                        debug!("Changed due date to {}", card.due_date);
                     }
                     app.widgets.date_time_picker.close_date_picker();
                 }
@@ -4388,10 +8288,112 @@ fn handle_date_time_picker_action(app: &mut App, key: Option<Key>, action: Optio
     }
 }
 
-fn handle_new_card_action(app: &mut App) {
+/// Closing the prompt any other way than Discard keeps the draft
+fn handle_keep_draft_prompt(app: &mut App) {
+    if app.state.focus == Focus::ExtraFocus {
+        if let Some(draft) = app.state.form_drafts.discard_pending() {
+            info!("Discarded the draft left in {}", draft.view);
+            app.send_info_toast(&tr(MessageId::ToastDraftDiscarded), None);
+        }
+    }
+    app.close_popup();
+}
+
+/// Only Clear takes the field off the completed card, closing the prompt any other way keeps it.
+/// A card still being edited is cleared there and saved with the rest of the edit
+fn handle_clear_waiting_on_prompt(app: &mut App) {
+    let waiting_on_to_clear = app.state.waiting_on_to_clear;
+    let clear = app.state.focus == Focus::SubmitButton;
+    app.close_popup();
+    let Some((board_id, card_id)) = waiting_on_to_clear.filter(|_| clear) else {
+        return;
+    };
+    if let Some((_, card_being_edited)) = app
+        .state
+        .card_being_edited
+        .as_mut()
+        .filter(|(edited_board_id, card)| *edited_board_id == board_id && card.id == card_id)
+    {
+        card_being_edited.waiting_on = None;
+        app.state.text_buffers.card_waiting_on.reset();
+        app.state.set_focus(Focus::CardStatus);
+        return;
+    }
+    let Some(card) = app
+        .boards
+        .get_mut_board_with_id(board_id)
+        .and_then(|board| board.cards.get_mut_card_with_id(card_id))
+    else {
+        app.send_error_toast("Error Could not find current card", None);
+        return;
+    };
+    let old_card = card.clone();
+    card.waiting_on = None;
+    record_card_edit(
+        &mut app.action_history_manager,
+        old_card,
+        card,
+        board_id,
+        app.config.date_time_format,
+    );
+    let card_name = card.name.clone();
+    if let Some(filtered_card) = app
+        .filtered_boards
+        .get_mut_board_with_id(board_id)
+        .and_then(|board| board.cards.get_mut_card_with_id(card_id))
+    {
+        filtered_card.waiting_on = None;
+    }
+    app.send_info_toast(
+        &format!("Card \"{}\" is no longer waiting on anyone", card_name),
+        None,
+    );
+}
+
+fn handle_duplicate_card_name_prompt(app: &mut App) {
+    let duplicate_card_name_match = app.state.duplicate_card_name_match;
+    app.close_popup();
+    let duplicate = if let Some(duplicate) = duplicate_card_name_match {
+        duplicate
+    } else {
+        debug!("No duplicate card name match found");
+        return;
+    };
+    match app.state.focus {
+        Focus::SubmitButton => {
+            handle_new_card_action(app, duplicate.rapid_entry, true);
+        }
+        Focus::ExtraFocus => {
+            reset_new_card_form(app);
+            app.set_view(
+                *app.state
+                    .prev_view
+                    .as_ref()
+                    .unwrap_or(&app.config.default_view),
+            );
+            refresh_visible_boards_and_cards(app);
+            app.state.current_board_id = Some(duplicate.board_id);
+            app.state.current_card_id = Some(duplicate.card_id);
+            app.set_popup(PopUp::ViewCard);
+        }
+        _ => {
+            app.state.set_focus(Focus::CardName);
+        }
+    }
+}
+
+/// Returns true if the form was kept open for rapid entry of the next card
+fn handle_new_card_action(app: &mut App, rapid_entry: bool, skip_duplicate_check: bool) -> bool {
     if app.state.focus == Focus::SubmitButton {
         let new_card_name = app.state.text_buffers.card_name.get_joined_lines();
-        let new_card_name = new_card_name.trim();
+        let (new_card_name, quick_add_estimate) = match take_estimate_token(new_card_name.trim()) {
+            Ok(name_and_estimate) => name_and_estimate,
+            Err(e) => {
+                app.send_error_toast(&e, None);
+                return false;
+            }
+        };
+        let new_card_name = new_card_name.as_str();
         let new_card_description = app.state.text_buffers.card_description.get_joined_lines();
         let new_card_description = new_card_description.trim();
 
@@ -4415,48 +8417,96 @@ fn handle_new_card_action(app: &mut App) {
             }
         } else {
             debug!("Current board not found");
-            app.send_error_toast("Something went wrong", None);
+            app.send_error_toast(&tr(MessageId::ToastSomethingWentWrong), None);
             app.set_view(
                 *app.state
                     .prev_view
                     .as_ref()
                     .unwrap_or(&app.config.default_view),
             );
-            return;
+            return false;
         }
 
         if new_card_name.is_empty() || same_name_exists {
             warn!("New card name is empty or already exists");
-            app.send_warning_toast("New card name is empty or already exists", None);
-            return;
+            app.send_warning_toast(&tr(MessageId::ToastNewCardNameInvalid), None);
+            return false;
+        }
+
+        if app.config.check_for_duplicate_card_names && !skip_duplicate_check {
+            if let Some((board_id, card_id)) = app.boards.find_card_with_name(new_card_name) {
+                app.state.duplicate_card_name_match = Some(DuplicateCardNameMatch {
+                    board_id,
+                    card_id,
+                    rapid_entry,
+                });
+                app.set_popup(PopUp::ConfirmDuplicateCardName);
+                return false;
+            }
         }
 
-        let new_card = Card::new(
+        let new_card_template = app.state.new_card_template.clone();
+        let (new_card_priority, new_card_tags) = match &new_card_template {
+            Some(template) => (
+                template.priority.clone(),
+                app.state
+                    .text_buffers
+                    .card_tags
+                    .iter()
+                    .map(|tag| tag.get_joined_lines().trim().to_string())
+                    .filter(|tag| !tag.is_empty())
+                    .collect(),
+            ),
+            None => (CardPriority::Low, vec![]),
+        };
+        let mut new_card = Card::new(
             new_card_name,
             new_card_description,
             new_card_due_date,
-            CardPriority::Low,
-            vec![],
+            new_card_priority,
+            new_card_tags,
             vec![],
             app.config.date_time_format,
         );
+        if let Some(template) = new_card_template {
+            new_card.checklist = template.checklist;
+            new_card.estimate = template.estimate;
+            new_card.estimated_minutes = template.estimated_minutes;
+            new_card.highlight_color = template.highlight_color;
+            new_card.links = template.links;
+        }
+        if quick_add_estimate.is_some() {
+            new_card.estimate = quick_add_estimate;
+        }
+        new_card.id = app.boards.generate_unused_id();
+        let new_card_name = new_card.name.clone();
+        let new_card_id = new_card.id;
         let current_board = app.boards.get_mut_board_with_id(current_board_id);
         if let Some(current_board) = current_board {
             current_board.cards.add_card(new_card.clone());
-            app.state.current_card_id = Some(new_card.id);
             app.action_history_manager
                 .new_action(ActionHistory::CreateCard(new_card, current_board.id));
         } else {
             debug!("Current board not found");
-            app.send_error_toast("Something went wrong", None);
+            app.send_error_toast(&tr(MessageId::ToastSomethingWentWrong), None);
             app.set_view(
                 *app.state
                     .prev_view
                     .as_ref()
                     .unwrap_or(&app.config.default_view),
             );
-            return;
+            return false;
+        }
+        if rapid_entry {
+            reveal_created_item(app, current_board_id, Some(new_card_id));
+            reset_new_card_form(app);
+            app.state.set_focus(Focus::CardName);
+            app.state.app_status = AppStatus::UserInput;
+            app.send_info_toast(&format!("Created card '{}'", new_card_name), None);
+            return true;
         }
+        // Cleared before leaving so the submitted card is not offered as a draft
+        reset_new_card_form(app);
         app.set_view(
             *app.state
                 .prev_view
@@ -4467,78 +8517,33 @@ fn handle_new_card_action(app: &mut App) {
         if let Some(previous_focus) = &app.state.prev_focus {
             app.state.set_focus(*previous_focus);
         }
-        refresh_visible_boards_and_cards(app);
-        reset_new_card_form(app);
+        reveal_created_item(app, current_board_id, Some(new_card_id));
     } else if app.state.focus == Focus::CardDueDate {
         app.set_popup(PopUp::DateTimePicker);
+    } else if app.state.focus == Focus::UseTemplate {
+        open_card_template_selector(app);
     } else if app.state.app_status == AppStatus::Initialized {
-        app.state.app_status = AppStatus::UserInput;
-    }
-    if !app.filtered_boards.is_empty() {
-        app.state.filter_tags = None;
-        app.state.all_available_tags = None;
-        app.state.app_list_states.filter_by_tag_list.select(None);
-        app.send_warning_toast("Filter Reset", None);
-    }
-}
-
-fn scroll_up(app: &mut App) {
-    if app.visible_boards_and_cards.is_empty() {
-        refresh_visible_boards_and_cards(app);
-        return;
-    }
-    if app.state.current_board_id.is_none() {
-        debug!("No current board id found");
-        return;
-    }
-    let current_board_id = app.state.current_board_id.unwrap();
-    let boards = if app.filtered_boards.is_empty() {
-        &app.boards
-    } else {
-        &app.filtered_boards
-    };
-    let current_board = boards.get_board_with_id(current_board_id);
-    if current_board.is_none() {
-        debug!("No current board found in all boards");
-        return;
-    }
-    let current_board = current_board.unwrap();
-    let current_visible_cards = app.visible_boards_and_cards.get(&current_board_id);
-    if current_visible_cards.is_none() {
-        debug!("No current visible cards found");
-        refresh_visible_boards_and_cards(app);
-        return;
-    }
-    let current_visible_cards = current_visible_cards.unwrap();
-    if current_visible_cards.is_empty() {
-        debug!("Current visible cards is empty");
-        return;
-    }
-    let all_card_ids = &current_board.cards.get_all_card_ids();
-    let current_window_start_index = all_card_ids
-        .iter()
-        .position(|&c| c == current_visible_cards[0]);
-    if current_window_start_index.is_none() {
-        debug!("No current window start index found");
-        return;
-    }
-    let current_window_start_index = current_window_start_index.unwrap();
-    if current_window_start_index == 0 {
-        return;
+        app.state.app_status = AppStatus::UserInput;
     }
-    let new_window_start_index = current_window_start_index - 1;
-    let new_window_end_index = new_window_start_index + app.config.no_of_cards_to_show as usize;
-    let new_window = all_card_ids[new_window_start_index..new_window_end_index].to_vec();
-    let board_in_visible = app.visible_boards_and_cards.get_mut(&current_board_id);
-    if board_in_visible.is_none() {
-        debug!("Board not found in visible boards");
-        return;
+    if !app.filtered_boards.is_empty() {
+        app.state.filter_tags = None;
+        app.state.all_available_tags = None;
+        app.state.app_list_states.filter_by_tag_list.select(None);
+        app.send_warning_toast(&tr(MessageId::ToastFilterReset), None);
     }
-    let board_in_visible = board_in_visible.unwrap();
-    *board_in_visible = new_window;
+    false
+}
+
+fn scroll_up(app: &mut App) {
+    scroll_current_board_cards(app, false);
 }
 
 fn scroll_down(app: &mut App) {
+    scroll_current_board_cards(app, true);
+}
+
+/// Moves the card window of the current board by one card without changing the selection
+fn scroll_current_board_cards(app: &mut App, down: bool) {
     if app.visible_boards_and_cards.is_empty() {
         refresh_visible_boards_and_cards(app);
         return;
@@ -4547,12 +8552,12 @@ fn scroll_down(app: &mut App) {
         debug!("No current board id found");
         return;
     }
+    let current_board_id = app.state.current_board_id.unwrap();
     let boards = if app.filtered_boards.is_empty() {
         &app.boards
     } else {
         &app.filtered_boards
     };
-    let current_board_id = app.state.current_board_id.unwrap();
     let current_board = boards.get_board_with_id(current_board_id);
     if current_board.is_none() {
         debug!("No current board found in all boards");
@@ -4570,136 +8575,59 @@ fn scroll_down(app: &mut App) {
         debug!("Current visible cards is empty");
         return;
     }
-    let all_card_ids = &current_board.cards.get_all_card_ids();
-    let current_window_end_index = all_card_ids
-        .iter()
-        .position(|&c| c == current_visible_cards[current_visible_cards.len() - 1]);
-    if current_window_end_index.is_none() {
-        debug!("No current window end index found");
-        return;
-    }
-    let current_window_end_index = current_window_end_index.unwrap();
-    if current_window_end_index == all_card_ids.len() - 1 {
-        return;
-    }
-    let new_window_end_index = current_window_end_index + 1;
-    let new_window_start_index =
-        new_window_end_index - (app.config.no_of_cards_to_show - 1) as usize;
-    let new_window = all_card_ids[new_window_start_index..=new_window_end_index].to_vec();
-    let board_in_visible = app.visible_boards_and_cards.get_mut(&current_board_id);
-    if board_in_visible.is_none() {
-        debug!("Board not found in visible boards");
-        return;
-    }
-    let board_in_visible = board_in_visible.unwrap();
-    *board_in_visible = new_window;
+    let displayable_card_ids = app.get_displayable_cards(current_board).get_all_card_ids();
+    let no_of_cards_to_show = app.get_no_of_cards_to_show().into();
+    let new_window = if down {
+        shift_window_forward(
+            &displayable_card_ids,
+            current_visible_cards,
+            no_of_cards_to_show,
+        )
+    } else {
+        shift_window_back(
+            &displayable_card_ids,
+            current_visible_cards,
+            no_of_cards_to_show,
+        )
+    };
+    app.visible_boards_and_cards
+        .entry(current_board_id)
+        .and_modify(|cards| *cards = new_window);
 }
 
 fn scroll_right(app: &mut App) {
-    if app.state.current_board_id.is_none() {
-        debug!("No current board id found");
-        return;
-    }
-    let last_board_in_visible = app.visible_boards_and_cards.keys().last();
-    if last_board_in_visible.is_none() {
-        debug!("No last board in visible boards found");
-        return;
-    }
-    let boards = if app.filtered_boards.is_empty() {
-        &app.boards
-    } else {
-        &app.filtered_boards
-    };
-    let last_board_in_visible = last_board_in_visible.unwrap();
-    let last_board_index = boards.get_board_index(*last_board_in_visible);
-    if last_board_index.is_none() {
-        debug!("No last board index found");
-        return;
-    }
-    let last_board_index = last_board_index.unwrap();
-    if last_board_index == boards.len() - 1 {
-        return;
-    }
-    let next_board_index = last_board_index + 1;
-    let next_board = boards.get_board_with_index(next_board_index);
-    if next_board.is_none() {
-        debug!("No next board found");
-        return;
-    }
-    let next_board = next_board.unwrap();
-    let next_board_card_ids = next_board.cards.get_all_card_ids();
-    let next_board_card_ids = if next_board_card_ids.len() > app.config.no_of_cards_to_show as usize
-    {
-        next_board_card_ids[0..app.config.no_of_cards_to_show as usize].to_vec()
-    } else {
-        next_board_card_ids
-    };
-    let mut new_visible_boards_and_cards = app.visible_boards_and_cards.clone();
-    new_visible_boards_and_cards.insert(next_board.id, next_board_card_ids);
-    let first_board_in_visible = app.visible_boards_and_cards.keys().next();
-    if first_board_in_visible.is_none() {
-        debug!("No first board in visible boards found");
-        return;
-    }
-    let first_board_in_visible = first_board_in_visible.unwrap();
-    new_visible_boards_and_cards.remove(first_board_in_visible);
-    app.visible_boards_and_cards = new_visible_boards_and_cards;
+    scroll_visible_boards(app, true);
 }
 
 fn scroll_left(app: &mut App) {
+    scroll_visible_boards(app, false);
+}
+
+/// Moves the board window by one board, the selection stays where it was
+fn scroll_visible_boards(app: &mut App, right: bool) {
     if app.state.current_board_id.is_none() {
         debug!("No current board id found");
         return;
     }
-    let first_board_in_visible = app.visible_boards_and_cards.keys().next();
-    if first_board_in_visible.is_none() {
-        debug!("No first board in visible boards found");
+    if app.visible_boards_and_cards.is_empty() {
+        debug!("No visible boards found");
         return;
     }
-    let boards = if app.filtered_boards.is_empty() {
-        &app.boards
+    let all_board_ids = get_shown_board_ids(app);
+    let visible_board_ids = app
+        .visible_boards_and_cards
+        .keys()
+        .copied()
+        .collect::<Vec<(u64, u64)>>();
+    let no_of_boards_to_show = app.config.no_of_boards_to_show.into();
+    let new_visible_board_ids = if right {
+        shift_window_forward(&all_board_ids, &visible_board_ids, no_of_boards_to_show)
     } else {
-        &app.filtered_boards
+        shift_window_back(&all_board_ids, &visible_board_ids, no_of_boards_to_show)
     };
-    let first_board_in_visible = first_board_in_visible.unwrap();
-    let first_board_index = boards.get_board_index(*first_board_in_visible);
-    if first_board_index.is_none() {
-        debug!("No first board index found");
-        return;
-    }
-    let first_board_index = first_board_index.unwrap();
-    if first_board_index == 0 {
-        return;
-    }
-    let previous_board_index = first_board_index - 1;
-    let previous_board = boards.get_board_with_index(previous_board_index);
-    if previous_board.is_none() {
-        debug!("No previous board found");
-        return;
-    }
-    let previous_board = previous_board.unwrap();
-    let previous_board_card_ids = previous_board.cards.get_all_card_ids();
-    let previous_board_card_ids =
-        if previous_board_card_ids.len() > app.config.no_of_cards_to_show as usize {
-            previous_board_card_ids[0..app.config.no_of_cards_to_show as usize].to_vec()
-        } else {
-            previous_board_card_ids
-        };
-    let mut new_visible_boards_and_cards = LinkedHashMap::new();
-    new_visible_boards_and_cards.insert(previous_board.id, previous_board_card_ids);
-    let last_board_in_visible = app.visible_boards_and_cards.keys().last();
-    if last_board_in_visible.is_none() {
-        debug!("No last board in visible boards found");
-        return;
-    }
-    let last_board_in_visible = last_board_in_visible.unwrap();
-    for (board_id, card_ids) in app.visible_boards_and_cards.iter() {
-        if board_id == last_board_in_visible {
-            break;
-        }
-        new_visible_boards_and_cards.insert(*board_id, card_ids.clone());
+    if new_visible_board_ids != visible_board_ids {
+        set_visible_boards(app, &new_visible_board_ids);
     }
-    app.visible_boards_and_cards = new_visible_boards_and_cards;
 }
 
 fn reset_mouse(app: &mut App) {
@@ -4730,7 +8658,7 @@ fn handle_change_theme(app: &mut App, default_theme_mode: bool) -> AppReturn {
             AppReturn::Continue
         } else {
             debug!("No config index found");
-            app.send_error_toast("Something went wrong", None);
+            app.send_error_toast(&tr(MessageId::ToastSomethingWentWrong), None);
             app.close_popup();
             AppReturn::Continue
         }
@@ -4739,7 +8667,7 @@ fn handle_change_theme(app: &mut App, default_theme_mode: bool) -> AppReturn {
             Some(index) => index,
             None => {
                 debug!("No selected item index found");
-                app.send_error_toast("Something went wrong", None);
+                app.send_error_toast(&tr(MessageId::ToastSomethingWentWrong), None);
                 app.close_popup();
                 return AppReturn::Continue;
             }
@@ -4810,7 +8738,7 @@ fn handle_create_theme_action(app: &mut App) -> AppReturn {
                             app.state.app_table_states.theme_editor.selected();
                         if theme_style_being_edited_index.is_none() {
                             debug!("No theme style being edited index found");
-                            app.send_error_toast("Something went wrong", None);
+                            app.send_error_toast(&tr(MessageId::ToastSomethingWentWrong), None);
                             app.close_popup();
                             return AppReturn::Continue;
                         }
@@ -4826,7 +8754,7 @@ fn handle_create_theme_action(app: &mut App) -> AppReturn {
                                 .theme_editor_fg_hex
                                 .get_joined_lines();
 
-                            if let Some((r, g, b)) = parse_hex_to_rgb(&fg_hex_value) {
+                            if let Some((r, g, b)) = parse_hex_to_rgb(fg_hex_value.trim()) {
                                 Color::Rgb(r, g, b)
                             } else {
                                 app.send_error_toast("Invalid hex value", None);
@@ -4844,7 +8772,7 @@ fn handle_create_theme_action(app: &mut App) -> AppReturn {
                                 .theme_editor_bg_hex
                                 .get_joined_lines();
 
-                            if let Some((r, g, b)) = parse_hex_to_rgb(&bg_hex_value) {
+                            if let Some((r, g, b)) = parse_hex_to_rgb(bg_hex_value.trim()) {
                                 Color::Rgb(r, g, b)
                             } else {
                                 app.send_error_toast("Invalid hex value", None);
@@ -4862,7 +8790,7 @@ fn handle_create_theme_action(app: &mut App) -> AppReturn {
                                 "No theme enum found for index {}",
                                 theme_style_being_edited_index
                             );
-                            app.send_error_toast("Something went wrong", None);
+                            app.send_error_toast(&tr(MessageId::ToastSomethingWentWrong), None);
                             app.close_popup();
                             return AppReturn::Continue;
                         }
@@ -4892,6 +8820,7 @@ fn handle_create_theme_action(app: &mut App) -> AppReturn {
                             TextColorOptions::iter().collect::<Vec<TextColorOptions>>();
                         let selected_color = &all_color_options[selected_index];
                         if let TextColorOptions::HEX(_, _, _) = selected_color {
+                            prefill_custom_hex_color(app, true);
                             app.set_popup(PopUp::CustomHexColorPromptFG);
                             app.state.set_focus(Focus::TextInput);
                             return AppReturn::Continue;
@@ -4908,6 +8837,7 @@ fn handle_create_theme_action(app: &mut App) -> AppReturn {
                             TextColorOptions::iter().collect::<Vec<TextColorOptions>>();
                         let selected_color = &all_color_options[selected_index];
                         if let TextColorOptions::HEX(_, _, _) = selected_color {
+                            prefill_custom_hex_color(app, false);
                             app.set_popup(PopUp::CustomHexColorPromptBG);
                             app.state.set_focus(Focus::TextInput);
                             return AppReturn::Continue;
@@ -4971,7 +8901,7 @@ fn handle_next_focus(app: &mut App) {
         && app.state.z_stack.last() == Some(&PopUp::ViewCard)
         && next_focus == Focus::SubmitButton
     {
-        next_focus = Focus::CardName;
+        next_focus = Focus::SaveCardAsTemplate;
     }
     if app.state.z_stack.last() == Some(&PopUp::DateTimePicker)
         && !app.widgets.date_time_picker.time_picker_active
@@ -4980,87 +8910,568 @@ fn handle_next_focus(app: &mut App) {
             Focus::DTPHour | Focus::DTPMinute | Focus::DTPSecond => {
                 next_focus = Focus::DTPCalender;
             }
-            _ => {}
+            _ => {}
+        }
+    }
+
+    if next_focus != Focus::NoFocus {
+        app.state.set_focus(next_focus);
+    }
+    if app.state.z_stack.last() == Some(&PopUp::CommandPalette) {
+        CommandPaletteWidget::reset_list_states(&mut app.state)
+    }
+}
+
+fn handle_prv_focus(app: &mut App) {
+    if app.config.enable_mouse_support {
+        reset_mouse(app)
+    }
+    let available_targets = if let Some(popup) = app.state.z_stack.checked_control_last() {
+        PopUp::get_available_targets(popup)
+    } else {
+        View::get_available_targets(&app.state.current_view)
+    };
+    if !available_targets.contains(&app.state.focus) {
+        if available_targets.is_empty() {
+            app.state.set_focus(Focus::NoFocus);
+        } else {
+            app.state
+                .set_focus(available_targets[available_targets.len() - 1]);
+        }
+        return;
+    }
+    let mut prv_focus = app.state.focus.prev(&available_targets);
+
+    // Special Handling
+    if app.state.card_being_edited.is_none()
+        && app.state.z_stack.last() == Some(&PopUp::ViewCard)
+        && prv_focus == Focus::SubmitButton
+    {
+        prv_focus = Focus::CardComments;
+    }
+    if app.state.z_stack.last() == Some(&PopUp::DateTimePicker)
+        && !app.widgets.date_time_picker.time_picker_active
+    {
+        match prv_focus {
+            Focus::DTPHour | Focus::DTPMinute | Focus::DTPSecond => {
+                prv_focus = Focus::DTPCalender;
+            }
+            _ => {}
+        }
+    }
+
+    if prv_focus != Focus::NoFocus {
+        app.state.set_focus(prv_focus);
+    }
+    if app.state.z_stack.last() == Some(&PopUp::CommandPalette) {
+        CommandPaletteWidget::reset_list_states(&mut app.state)
+    }
+}
+
+fn handle_save_theme_prompt(app: &mut App) {
+    if app.state.focus == Focus::SubmitButton {
+        let theme_name = app.state.theme_being_edited.name.clone();
+        let save_theme_status = save_theme(app.state.theme_being_edited.clone());
+        if save_theme_status.is_err() {
+            debug!("Failed to save theme: {}", save_theme_status.unwrap_err());
+            app.send_error_toast("Failed to save theme", None);
+            return;
+        } else {
+            app.send_info_toast(&format!("Saved theme {}", theme_name), None);
+        }
+    } else {
+        app.send_warning_toast("Theme not saved to file, it will be lost on exit, You can still use it in the current session",
+        Some(Duration::from_secs(5)));
+    }
+    let default_view = app.config.default_view;
+    app.set_view(default_view);
+    app.all_themes.push(app.state.theme_being_edited.clone());
+    app.state.theme_being_edited = Theme::default();
+    app.state.text_buffers.theme_editor_fg_hex.reset();
+    app.state.text_buffers.theme_editor_bg_hex.reset();
+    app.close_popup();
+    handle_prv_focus(app);
+}
+
+/// Compares the markdown mirror with the loaded boards and asks before applying what changed,
+/// quiet skips the toasts for when there is nothing to do
+pub fn sync_from_mirror(app: &mut App, quiet: bool) {
+    let Some(mirror_directory) = app.config.mirror_directory.clone() else {
+        if !quiet {
+            app.send_warning_toast(
+                "Markdown mirror directory is not set, set it in the config menu",
+                None,
+            );
+        }
+        return;
+    };
+    // The quiet sync runs in the background at startup, its result is kept as a notification
+    let record_completion = |app: &mut App, outcome: IoOutcome, details: &str| {
+        if quiet {
+            app.record_io_completion(IoCompletionKind::MirrorSync, outcome, details);
+        }
+    };
+    match get_mirror_diff(&app.boards, &mirror_directory) {
+        Ok(Some(diff)) if diff.is_empty() => {
+            if !quiet {
+                app.send_info_toast(&tr(MessageId::ToastMarkdownMirrorHasNoChangesToSync), None);
+            }
+            record_completion(app, IoOutcome::Skipped, "No changes in the mirror");
+        }
+        Ok(Some(diff)) => {
+            record_completion(
+                app,
+                IoOutcome::Succeeded,
+                &format!(
+                    "{} new, {} edited, {} moved, {} removed, {} conflicts waiting for review",
+                    diff.added_count(),
+                    diff.edited_count(),
+                    diff.moved_count(),
+                    diff.removed_count(),
+                    diff.conflicts().len()
+                ),
+            );
+            let first_conflict = if diff.conflicts().is_empty() {
+                None
+            } else {
+                Some(0)
+            };
+            app.state
+                .app_list_states
+                .mirror_sync_conflicts
+                .select(first_conflict);
+            app.state.mirror_sync_diff = Some(diff);
+            app.set_popup(PopUp::MirrorSyncSummary);
+            app.state.set_focus(Focus::SubmitButton);
+        }
+        Ok(None) => {
+            // Nothing to sync against yet, start the mirror from the loaded boards
+            match write_mirror(
+                app.boards.get_boards(),
+                &mirror_directory,
+                app.state.include_notes_in_exports,
+            ) {
+                Ok(_) => {
+                    if !quiet {
+                        app.send_info_toast(&tr(MessageId::ToastMarkdownMirrorCreated), None);
+                    }
+                    record_completion(
+                        app,
+                        IoOutcome::Succeeded,
+                        &format!("Created the mirror in {}", mirror_directory.display()),
+                    );
+                }
+                Err(e) => {
+                    error!("Could not write markdown mirror: {}", e);
+                    app.send_error_toast(&tr(MessageId::ToastCouldNotWriteMarkdownMirror), None);
+                    record_completion(app, IoOutcome::Failed, &e);
+                }
+            }
+        }
+        Err(e) => {
+            error!("Could not read markdown mirror: {}", e);
+            app.send_error_toast(&format!("Could not read markdown mirror: {}", e), None);
+            record_completion(app, IoOutcome::Failed, &e);
+        }
+    }
+}
+
+fn handle_toggle_notifications(app: &mut App) {
+    if app.state.z_stack.last() == Some(&PopUp::Notifications) {
+        app.close_popup();
+        return;
+    }
+    // Newest entries are listed first
+    let first_notification = if app.state.notifications.entries().is_empty() {
+        None
+    } else {
+        Some(0)
+    };
+    app.state
+        .app_list_states
+        .notifications
+        .select(first_notification);
+    app.set_popup(PopUp::Notifications);
+}
+
+pub fn open_whats_new(app: &mut App, entries: Vec<&'static ChangelogEntry>) {
+    app.state.whats_new_entries = entries;
+    app.state.whats_new_scroll = 0;
+    app.set_popup(PopUp::WhatsNew);
+}
+
+/// Applies an update to the running config and to the config file. The file is read back first
+/// so settings overridden for this session are not persisted
+fn store_in_config_file(app: &mut App, what: &str, update: impl Fn(&mut AppConfig)) {
+    update(&mut app.config);
+    if app.state.safe_mode {
+        return;
+    }
+    match get_config(true) {
+        Ok(mut config_on_disk) => {
+            update(&mut config_on_disk);
+            if let Err(write_error) = app.write_config(&config_on_disk) {
+                error!("Could not store the {}: {}", what, write_error);
+            }
+        }
+        Err(config_error) => {
+            error!("Could not store the {}: {}", what, config_error);
+        }
+    }
+}
+
+/// Stores the running version and shows what changed when it is newer than the last one seen.
+/// Fresh installs already start at the current version and downgrades only update it
+pub fn show_whats_new_after_upgrade(app: &mut App) {
+    let current_version = env!("CARGO_PKG_VERSION");
+    let last_seen_version = app.config.last_seen_version.clone();
+    if last_seen_version.as_deref() == Some(current_version) {
+        return;
+    }
+    let is_upgrade = match &last_seen_version {
+        Some(last_seen_version) => {
+            match (
+                parse_version(last_seen_version),
+                parse_version(current_version),
+            ) {
+                (Some(last_seen_version), Some(current_version)) => {
+                    current_version > last_seen_version
+                }
+                _ => false,
+            }
+        }
+        // Configs written before the version was tracked
+        None => true,
+    };
+    store_in_config_file(app, "last seen version", |config| {
+        config.last_seen_version = Some(current_version.to_string());
+    });
+    if is_upgrade {
+        let entries = get_entries_since(last_seen_version.as_deref(), current_version);
+        if !entries.is_empty() {
+            open_whats_new(app, entries);
+        }
+    }
+}
+
+fn toggle_selected_mirror_conflict_resolution(app: &mut App) {
+    let Some(selected_index) = app.state.app_list_states.mirror_sync_conflicts.selected() else {
+        return;
+    };
+    if let Some(conflict) = app
+        .state
+        .mirror_sync_diff
+        .as_mut()
+        .and_then(|diff| diff.get_mut_conflict(selected_index))
+    {
+        conflict.resolution.toggle();
+    }
+}
+
+fn handle_mirror_sync_prompt(app: &mut App) {
+    if app.state.focus != Focus::SubmitButton {
+        app.close_popup();
+        return;
+    }
+    let Some(diff) = app.state.mirror_sync_diff.take() else {
+        app.close_popup();
+        return;
+    };
+    apply_mirror_diff(&mut app.boards, &diff, app.config.date_time_format);
+    refresh_visible_boards_and_cards(app);
+    // The mirror now matches the boards, so it becomes the base of the next sync
+    if let Some(mirror_directory) = app.config.mirror_directory.clone() {
+        if let Err(e) = write_mirror(
+            app.boards.get_boards(),
+            &mirror_directory,
+            app.state.include_notes_in_exports,
+        ) {
+            error!("Could not write markdown mirror: {}", e);
+            app.send_error_toast(&tr(MessageId::ToastCouldNotWriteMarkdownMirror), None);
+        }
+    }
+    let mut message = format!(
+        "Synced from markdown mirror: {} new, {} edited, {} moved, {} removed, {} conflicts",
+        diff.added_count(),
+        diff.edited_count(),
+        diff.moved_count(),
+        diff.removed_count(),
+        diff.conflicts().len()
+    );
+    app.close_popup();
+    let conflict_marker_cards = check_for_conflict_markers(app);
+    if !conflict_marker_cards.is_empty() {
+        message.push_str(&format!(
+            ", conflict markers left in {}",
+            conflict_marker_cards.join(", ")
+        ));
+    }
+    info!("{}", message);
+    app.send_info_toast(&message, None);
+}
+
+/// Scans card descriptions for merge conflict markers once boards were synced or loaded from
+/// elsewhere, the affected cards are badged and listed in a warning popup. Returns their names
+/// for the sync summary
+pub fn check_for_conflict_markers(app: &mut App) -> Vec<String> {
+    app.state.cards_with_conflict_markers = app.boards.get_cards_with_conflict_markers();
+    let card_names = app
+        .state
+        .cards_with_conflict_markers
+        .iter()
+        .filter_map(|(board_id, card_id)| {
+            app.boards
+                .get_board_with_id(*board_id)?
+                .cards
+                .get_card_with_id(*card_id)
+                .map(|card| format!("'{}'", card.name))
+        })
+        .collect::<Vec<String>>();
+    if !card_names.is_empty() {
+        warn!("Conflict markers found in {}", card_names.join(", "));
+        app.state
+            .app_list_states
+            .conflict_marker_cards
+            .select(Some(0));
+        app.set_popup(PopUp::ConflictMarkerWarning);
+        app.state.set_focus(Focus::ConflictMarkerCards);
+    }
+    card_names
+}
+
+/// Looks for boards that were copied by a bad restore and opens the review popup for them
+pub fn find_duplicate_boards(app: &mut App) {
+    let pairs = duplicate_boards::find_duplicate_boards(app.boards.get_boards());
+    if pairs.is_empty() {
+        app.send_info_toast(&tr(MessageId::ToastNoDuplicateBoardsFound), None);
+        return;
+    }
+    info!("Found {} duplicate board pairs", pairs.len());
+    app.state.duplicate_board_pairs = pairs;
+    app.state
+        .app_list_states
+        .duplicate_board_pairs
+        .select(Some(0));
+    app.set_popup(PopUp::DuplicateBoards);
+    app.state.set_focus(Focus::SubmitButton);
+}
+
+// Merge on the submit button, keep both on the extra one and delete the copy on cancel
+fn handle_duplicate_boards_action(app: &mut App) {
+    let Some(selected_index) = app.state.app_list_states.duplicate_board_pairs.selected() else {
+        return;
+    };
+    let Some(pair) = app.state.duplicate_board_pairs.get(selected_index).cloned() else {
+        debug!("Selected index is out of bounds");
+        return;
+    };
+    let (Some(kept_board), Some(copy_board)) = (
+        app.boards.get_board_with_id(pair.kept_board_id).cloned(),
+        app.boards.get_board_with_id(pair.copy_board_id).cloned(),
+    ) else {
+        app.send_error_toast(
+            &tr(MessageId::ToastOneOfTheDuplicateBoardsNoLongerExists),
+            None,
+        );
+        app.state.duplicate_board_pairs.remove(selected_index);
+        return;
+    };
+    match app.state.focus {
+        Focus::SubmitButton => {
+            let merge = duplicate_boards::merge_boards(&kept_board, &copy_board);
+            let mut batch = Vec::new();
+            for (old_card, new_card) in &merge.flagged_cards {
+                batch.push(ActionHistory::EditCard(
+                    old_card.clone(),
+                    new_card.clone(),
+                    kept_board.id,
+                ));
+            }
+            for card in &merge.added_cards {
+                batch.push(ActionHistory::CreateCard(card.clone(), kept_board.id));
+            }
+            batch.push(ActionHistory::DeleteBoard(copy_board.clone()));
+            if let Some(board) = app.boards.get_mut_board_with_id(kept_board.id) {
+                board.cards = merge.merged_board.cards;
+            }
+            app.boards.remove_board_with_id(copy_board.id);
+            app.action_history_manager
+                .new_action(ActionHistory::Batch(batch));
+            let diverged_cards = merge
+                .added_cards
+                .iter()
+                .filter(|card| card.tags.iter().any(|tag| tag == DIVERGED_CARD_TAG))
+                .count();
+            let mut message = format!(
+                "Merged the copy of '{}': {} card{} added, {} duplicate{} dropped",
+                kept_board.name,
+                merge.added_cards.len() - diverged_cards,
+                if merge.added_cards.len() - diverged_cards == 1 {
+                    ""
+                } else {
+                    "s"
+                },
+                merge.dropped_cards,
+                if merge.dropped_cards == 1 { "" } else { "s" }
+            );
+            if diverged_cards > 0 {
+                message.push_str(&format!(
+                    ", {} edited differently kept twice and tagged '{}'",
+                    diverged_cards, DIVERGED_CARD_TAG
+                ));
+            }
+            info!("{}", message);
+            app.send_undoable_toast(&message);
+        }
+        Focus::ExtraFocus => {
+            app.send_info_toast(
+                &format!("Kept both boards named '{}'", kept_board.name),
+                None,
+            );
+        }
+        Focus::CancelButton => {
+            app.boards.remove_board_with_id(copy_board.id);
+            app.action_history_manager
+                .new_action(ActionHistory::Batch(vec![ActionHistory::DeleteBoard(
+                    copy_board.clone(),
+                )]));
+            let message = format!(
+                "Deleted the older copy of '{}' with {} card{}",
+                copy_board.name,
+                copy_board.cards.len(),
+                if copy_board.cards.len() == 1 { "" } else { "s" }
+            );
+            warn!("{}", message);
+            app.send_undoable_toast(&message);
         }
+        _ => return,
     }
 
-    if next_focus != Focus::NoFocus {
-        app.state.set_focus(next_focus);
-    }
-    if app.state.z_stack.last() == Some(&PopUp::CommandPalette) {
-        CommandPaletteWidget::reset_list_states(&mut app.state)
+    // Pairs that still point at the removed copy are gone with it
+    app.state.duplicate_board_pairs.remove(selected_index);
+    let boards = &app.boards;
+    app.state.duplicate_board_pairs.retain(|pair| {
+        boards.get_board_with_id(pair.kept_board_id).is_some()
+            && boards.get_board_with_id(pair.copy_board_id).is_some()
+    });
+    refresh_visible_boards_and_cards(app);
+    if app.state.duplicate_board_pairs.is_empty() {
+        app.close_popup();
+    } else {
+        let last_index = app.state.duplicate_board_pairs.len() - 1;
+        app.state
+            .app_list_states
+            .duplicate_board_pairs
+            .select(Some(selected_index.min(last_index)));
     }
 }
 
-fn handle_prv_focus(app: &mut App) {
-    if app.config.enable_mouse_support {
-        reset_mouse(app)
-    }
-    let available_targets = if let Some(popup) = app.state.z_stack.checked_control_last() {
-        PopUp::get_available_targets(popup)
-    } else {
-        View::get_available_targets(&app.state.current_view)
+fn jump_to_conflict_marker_card(app: &mut App) {
+    let Some(selected_index) = app.state.app_list_states.conflict_marker_cards.selected() else {
+        return;
     };
-    if !available_targets.contains(&app.state.focus) {
-        if available_targets.is_empty() {
-            app.state.set_focus(Focus::NoFocus);
-        } else {
-            app.state
-                .set_focus(available_targets[available_targets.len() - 1]);
-        }
+    let Some((board_id, card_id)) = app
+        .state
+        .cards_with_conflict_markers
+        .get(selected_index)
+        .copied()
+    else {
+        debug!("Selected index is out of bounds");
+        return;
+    };
+    app.close_popup();
+    app.state.current_board_id = Some(board_id);
+    app.state.current_card_id = Some(card_id);
+    refresh_visible_boards_and_cards(app);
+    app.state.set_focus(Focus::Body);
+}
+
+/// Lists the theme files that could not be loaded so a corrupt theme does not just go missing
+pub fn warn_about_skipped_theme_files(app: &mut App, skipped_files: &[String]) {
+    if skipped_files.is_empty() {
         return;
     }
-    let mut prv_focus = app.state.focus.prev(&available_targets);
+    app.send_warning_toast(
+        &format!(
+            "Skipped {} corrupt theme files: {}",
+            skipped_files.len(),
+            skipped_files.join(", ")
+        ),
+        None,
+    );
+}
 
-    // Special Handling
-    if app.state.card_being_edited.is_none()
-        && app.state.z_stack.last() == Some(&PopUp::ViewCard)
-        && prv_focus == Focus::SubmitButton
-    {
-        prv_focus = Focus::CardComments;
+fn handle_restore_themes_from_backup_prompt(app: &mut App) {
+    if app.state.focus != Focus::SubmitButton {
+        app.close_popup();
+        return;
     }
-    if app.state.z_stack.last() == Some(&PopUp::DateTimePicker)
-        && !app.widgets.date_time_picker.time_picker_active
-    {
-        match prv_focus {
-            Focus::DTPHour | Focus::DTPMinute | Focus::DTPSecond => {
-                prv_focus = Focus::DTPCalender;
-            }
-            _ => {}
+    match restore_themes_from_backup() {
+        Ok(saved_themes) => {
+            let mut all_themes = Theme::all_default_themes();
+            all_themes.extend(saved_themes.themes);
+            app.all_themes = all_themes;
+            app.send_info_toast(&tr(MessageId::ToastRestoredThemesFromBackup), None);
+            warn_about_skipped_theme_files(app, &saved_themes.skipped_files);
+        }
+        Err(e) => {
+            error!("Could not restore themes from backup: {}", e);
+            app.send_error_toast(&tr(MessageId::ToastCouldNotRestoreThemesFromBackup), None);
         }
     }
+    app.close_popup();
+}
 
-    if prv_focus != Focus::NoFocus {
-        app.state.set_focus(prv_focus);
-    }
-    if app.state.z_stack.last() == Some(&PopUp::CommandPalette) {
-        CommandPaletteWidget::reset_list_states(&mut app.state)
+/// Saves before exiting instead of dispatching the save, the IO task would not get to it. A
+/// failed save keeps the app open so nothing is lost
+async fn handle_confirm_quit(app: &mut App<'_>) -> AppReturn {
+    match app.state.focus {
+        Focus::SubmitButton => match auto_save(app).await {
+            Ok(()) => AppReturn::Exit,
+            Err(err) => {
+                error!("Cannot save before quitting: {}", err);
+                app.send_error_toast(&format!("Cannot save before quitting: {}", err), None);
+                app.close_popup();
+                AppReturn::Continue
+            }
+        },
+        Focus::ExtraFocus => {
+            warn!("Quitting without saving changes");
+            AppReturn::Exit
+        }
+        _ => {
+            app.close_popup();
+            AppReturn::Continue
+        }
     }
 }
 
-fn handle_save_theme_prompt(app: &mut App) {
-    if app.state.focus == Focus::SubmitButton {
-        let theme_name = app.state.theme_being_edited.name.clone();
-        let save_theme_status = save_theme(app.state.theme_being_edited.clone());
-        if save_theme_status.is_err() {
-            debug!("Failed to save theme: {}", save_theme_status.unwrap_err());
-            app.send_error_toast("Failed to save theme", None);
-            return;
-        } else {
-            app.send_info_toast(&format!("Saved theme {}", theme_name), None);
+/// Declining keeps the empty boards, the corrupt save is left on disk either way
+fn handle_save_recovery_prompt(app: &mut App) {
+    let candidate = app.state.save_recovery_candidate.take();
+    if app.state.focus != Focus::SubmitButton {
+        app.close_popup();
+        return;
+    }
+    if let Some(candidate) = candidate {
+        match load_save_recovery_candidate(&candidate) {
+            Ok(boards) => {
+                app.boards.set_boards(boards);
+                app.action_history_manager.reset();
+                app.mark_boards_changed();
+                refresh_visible_boards_and_cards(app);
+                app.send_info_toast(
+                    &format!("👍 Recovered data from {:?}", candidate.recovery_path),
+                    None,
+                );
+            }
+            Err(e) => {
+                error!("Could not recover save file: {}", e);
+                app.send_error_toast(&tr(MessageId::ToastCouldNotRecoverSaveFile), None);
+            }
         }
-    } else {
-        app.send_warning_toast("Theme not saved to file, it will be lost on exit, You can still use it in the current session",
-        Some(Duration::from_secs(5)));
     }
-    let default_view = app.config.default_view;
-    app.set_view(default_view);
-    app.all_themes.push(app.state.theme_being_edited.clone());
-    app.state.theme_being_edited = Theme::default();
-    app.state.text_buffers.theme_editor_fg_hex.reset();
-    app.state.text_buffers.theme_editor_bg_hex.reset();
     app.close_popup();
-    handle_prv_focus(app);
 }
 
 fn handle_custom_hex_color_prompt(app: &mut App, fg: bool) -> AppReturn {
@@ -5083,7 +9494,13 @@ fn handle_custom_hex_color_prompt(app: &mut App, fg: bool) -> AppReturn {
 
     // validate hex value
     if parse_hex_to_rgb(hex_value).is_none() {
-        app.send_error_toast("Invalid hex value", None);
+        app.send_error_toast(
+            &format!(
+                "\"{}\" is not a hex color, expected #RRGGBB or #RGB",
+                hex_value
+            ),
+            None,
+        );
         return AppReturn::Continue;
     };
 
@@ -5091,6 +9508,130 @@ fn handle_custom_hex_color_prompt(app: &mut App, fg: bool) -> AppReturn {
     AppReturn::Continue
 }
 
+/// Starts the hex prompt from the color the style being edited has now, so a small adjustment
+/// does not need the whole value retyped. Text already in the prompt is left alone
+fn prefill_custom_hex_color(app: &mut App, fg: bool) {
+    let text_buffer = if fg {
+        &app.state.text_buffers.theme_editor_fg_hex
+    } else {
+        &app.state.text_buffers.theme_editor_bg_hex
+    };
+    if !text_buffer.get_joined_lines().trim().is_empty() {
+        return;
+    }
+    let Some(theme_enum) = app
+        .state
+        .app_table_states
+        .theme_editor
+        .selected()
+        .and_then(|index| ThemeEnum::iter().nth(index))
+    else {
+        return;
+    };
+    let style = app.state.theme_being_edited.get_style(theme_enum);
+    let Some(color) = (if fg { style.fg } else { style.bg }) else {
+        return;
+    };
+    let color = TextColorOptions::from(color);
+    if color == TextColorOptions::None {
+        return;
+    }
+    let (r, g, b) = color.to_rgb();
+    let text_buffer = if fg {
+        &mut app.state.text_buffers.theme_editor_fg_hex
+    } else {
+        &mut app.state.text_buffers.theme_editor_bg_hex
+    };
+    text_buffer.reset();
+    text_buffer.insert_str(format!("#{:02x}{:02x}{:02x}", r, g, b));
+}
+
+/// Hands the path over to the IO thread, the file is only checked to exist here and a malformed
+/// file is reported from there
+async fn handle_import_prompt(app: &mut App<'_>) -> AppReturn {
+    if app.state.z_stack.last() == Some(&PopUp::ImportGithubPrompt) {
+        return handle_import_github_prompt(app).await;
+    }
+    let from_trello = app.state.z_stack.last() == Some(&PopUp::ImportTrelloPrompt);
+    let single_board = app.state.z_stack.last() == Some(&PopUp::ImportBoardJsonPrompt);
+    let import_path = app.state.text_buffers.import_path.get_joined_lines();
+    let import_path = import_path.trim();
+    if import_path.is_empty() {
+        if from_trello {
+            app.send_error_toast(
+                &tr(MessageId::ToastEnterThePathOfATrelloExportToImport),
+                None,
+            );
+        } else if single_board {
+            app.send_error_toast(
+                &tr(MessageId::ToastEnterThePathOfAnExportedBoardToImport),
+                None,
+            );
+        } else {
+            app.send_error_toast(&tr(MessageId::ToastEnterThePathOfASaveFileToImport), None);
+        }
+        return AppReturn::Continue;
+    }
+    let import_path = PathBuf::from(import_path);
+    if !import_path.is_file() {
+        app.send_error_toast(&format!("No file found at {}", import_path.display()), None);
+        return AppReturn::Continue;
+    }
+    app.close_popup();
+    if from_trello {
+        app.dispatch(IoEvent::ImportTrelloJson(import_path)).await;
+    } else if single_board {
+        app.dispatch(IoEvent::ImportBoardFromJson(import_path))
+            .await;
+    } else {
+        app.dispatch(IoEvent::ImportFromJson(import_path)).await;
+    }
+    AppReturn::Continue
+}
+
+/// The input is the repository, then optionally the board new cards go to. The token is read
+/// from the default environment variable, the CLI can name another one
+async fn handle_import_github_prompt(app: &mut App<'_>) -> AppReturn {
+    let user_input = app.state.text_buffers.import_path.get_joined_lines();
+    let (repo, board_name) = match user_input.trim().split_once(char::is_whitespace) {
+        Some((repo, board_name)) => (repo.to_string(), board_name.trim().to_string()),
+        None => (
+            user_input.trim().to_string(),
+            GITHUB_DEFAULT_BOARD_NAME.to_string(),
+        ),
+    };
+    if repo.is_empty() {
+        app.send_error_toast(&tr(MessageId::ToastEnterAGithubRepositoryAsOwnerName), None);
+        return AppReturn::Continue;
+    }
+    if let Err(err) = parse_github_repo(&repo) {
+        app.send_error_toast(&err, None);
+        return AppReturn::Continue;
+    }
+    app.close_popup();
+    app.dispatch(IoEvent::ImportGithubIssues(
+        repo,
+        GITHUB_DEFAULT_TOKEN_ENV.to_string(),
+        board_name,
+    ))
+    .await;
+    AppReturn::Continue
+}
+
+/// Merge is on the submit button and replacing every board on the extra one
+fn handle_import_options(app: &mut App) {
+    let replace_existing = app.state.focus == Focus::ExtraFocus;
+    if let Some(pending_import) = app.state.pending_json_import.take() {
+        let source = pending_import
+            .path
+            .file_name()
+            .map(|file_name| file_name.to_string_lossy().to_string())
+            .unwrap_or_else(|| pending_import.path.display().to_string());
+        add_imported_boards(app, pending_import.boards, &source, replace_existing);
+    }
+    app.close_popup();
+}
+
 fn handle_theme_maker_scroll_up(app: &mut App) {
     let style_index = if app.state.focus == Focus::StyleEditorFG {
         0
@@ -5193,6 +9734,34 @@ fn handle_edit_new_card(app: &mut App) -> AppReturn {
     // To avoid reversing the order of the description we create a new TextBox, as insert_str reverses the order (adds them one by one)
     app.state.text_buffers.card_description =
         TextBox::from_string_with_newline_sep(card.description.clone(), false);
+    app.state.text_buffers.card_estimate.reset();
+    if let Some(estimate) = card.estimate {
+        app.state
+            .text_buffers
+            .card_estimate
+            .insert_str(format_estimate(estimate));
+    }
+    app.state.text_buffers.card_estimated_time.reset();
+    if let Some(estimated_minutes) = card.estimated_minutes {
+        app.state
+            .text_buffers
+            .card_estimated_time
+            .insert_str(estimated_minutes.to_string());
+    }
+    app.state.text_buffers.card_actual_time.reset();
+    if let Some(actual_minutes) = card.actual_minutes {
+        app.state
+            .text_buffers
+            .card_actual_time
+            .insert_str(actual_minutes.to_string());
+    }
+    app.state.text_buffers.card_waiting_on.reset();
+    if let Some(waiting_on) = &card.waiting_on {
+        app.state
+            .text_buffers
+            .card_waiting_on
+            .insert_str(waiting_on);
+    }
     app.state.text_buffers.card_tags = Vec::new();
     card.tags.iter().for_each(|tag| {
         app.state
@@ -5210,6 +9779,7 @@ fn handle_edit_new_card(app: &mut App) -> AppReturn {
                 true,
             ));
     });
+    app.state.text_buffers.prepare_checklist_for_card(card);
     if card.due_date != FIELD_NOT_SET && !card.due_date.is_empty() {
         if let Ok(current_format) = date_format_finder(card.due_date.trim()) {
             app.widgets.date_time_picker.selected_date_time = match NaiveDateTime::parse_from_str(
@@ -5255,41 +9825,82 @@ fn handle_edit_card_submit(app: &mut App) -> AppReturn {
         debug!("No card being edited found");
         return AppReturn::Continue;
     };
-    let card_due_date = edited_card.due_date.clone();
-    let parsed_due_date = date_format_converter(card_due_date.trim(), app.config.date_time_format);
-    let parsed_date = match parsed_due_date {
-        Ok(date) => {
-            if date.is_empty() {
+    // An untouched due date is kept verbatim, re-parsing it could rewrite it in another format
+    if edited_card.due_date != card.due_date {
+        let card_due_date = edited_card.due_date.clone();
+        let parsed_due_date =
+            date_format_converter(card_due_date.trim(), app.config.date_time_format);
+        let parsed_date = match parsed_due_date {
+            Ok(date) => {
+                if date.is_empty() {
+                    FIELD_NOT_SET.to_string()
+                } else {
+                    date
+                }
+            }
+            Err(_) => {
+                if card_due_date.trim() != FIELD_NOT_SET {
+                    send_warning_toast = true;
+                    warning_due_date = card_due_date;
+                }
                 FIELD_NOT_SET.to_string()
-            } else {
-                date
             }
-        }
-        Err(_) => {
-            if card_due_date.trim() != FIELD_NOT_SET {
-                send_warning_toast = true;
-                warning_due_date = card_due_date;
+        };
+        edited_card.due_date = parsed_date;
+    }
+    if !edited_card.has_valid_date_range() {
+        app.send_error_toast(&tr(MessageId::ToastStartDateMustNotBeAfterTheDueDate), None);
+        return AppReturn::Continue;
+    }
+    edited_card.estimate =
+        match parse_estimate(&app.state.text_buffers.card_estimate.get_joined_lines()) {
+            Ok(estimate) => estimate,
+            Err(error_message) => {
+                app.send_error_toast(&error_message, None);
+                return AppReturn::Continue;
             }
-            FIELD_NOT_SET.to_string()
+        };
+    edited_card.estimated_minutes = match parse_minutes(
+        &app.state
+            .text_buffers
+            .card_estimated_time
+            .get_joined_lines(),
+    ) {
+        Ok(estimated_minutes) => estimated_minutes,
+        Err(error_message) => {
+            app.send_error_toast(&format!("Estimated time: {}", error_message), None);
+            return AppReturn::Continue;
         }
     };
-    edited_card.due_date = parsed_date;
-    edited_card.date_modified = chrono::Local::now()
-        .format(app.config.date_time_format.to_parser_string())
-        .to_string();
-    app.action_history_manager
-        .new_action(ActionHistory::EditCard(
-            card.clone(),
-            edited_card.clone(),
-            board.id,
-        ));
-    *card = edited_card;
-
-    let description = app.state.text_buffers.card_description.get_joined_lines();
-    card.description = description;
-
+    edited_card.actual_minutes =
+        match parse_minutes(&app.state.text_buffers.card_actual_time.get_joined_lines()) {
+            Ok(actual_minutes) => actual_minutes,
+            Err(error_message) => {
+                app.send_error_toast(&format!("Actual time: {}", error_message), None);
+                return AppReturn::Continue;
+            }
+        };
+    edited_card.waiting_on =
+        parse_waiting_on(&app.state.text_buffers.card_waiting_on.get_joined_lines());
+    edited_card.description = app.state.text_buffers.card_description.get_joined_lines();
     let card_name = app.state.text_buffers.card_name.get_joined_lines();
-    card.name.clone_from(&card_name);
+    edited_card.name.clone_from(&card_name);
+    let old_card = card.clone();
+    *card = edited_card;
+    record_card_edit(
+        &mut app.action_history_manager,
+        old_card,
+        card,
+        board.id,
+        app.config.date_time_format,
+    );
+    let card_id = card.id;
+    // The badge stays until an edit takes the markers out
+    if !card.has_conflict_markers() {
+        app.state
+            .cards_with_conflict_markers
+            .retain(|(_, conflict_card_id)| *conflict_card_id != card_id);
+    }
     app.state.card_being_edited = None;
     if send_warning_toast {
         let all_date_formats = DateTimeFormat::get_all_date_formats()
@@ -5367,46 +9978,143 @@ fn handle_filter_by_tag(app: &mut App) {
     }
 }
 
-fn filter_boards(app: &mut App) {
-    if app.state.filter_tags.is_none() {
-        app.send_warning_toast("No tags selected to filter", None);
+fn filter_boards(app: &mut App) {
+    if app.state.filter_tags.is_none() {
+        app.send_warning_toast("No tags selected to filter", None);
+        app.close_popup();
+        return;
+    }
+    app.state.current_board_id = None;
+    app.state.current_card_id = None;
+    let card_filter = CardFilter {
+        tags: app.state.filter_tags.clone().unwrap(),
+        ..Default::default()
+    };
+    app.filtered_boards = Boards::from(card_filter.apply(
+        app.boards.get_boards(),
+        app.config.date_time_format,
+        chrono::Local::now().naive_local(),
+    ));
+    app.state.text_buffers.card_search.reset();
+    refresh_visible_boards_and_cards(app);
+    app.send_info_toast(
+        &format!(
+            "Filtered by {} tags",
+            app.state.filter_tags.clone().unwrap().len()
+        ),
+        None,
+    );
+    app.close_popup();
+    app.state.app_list_states.filter_by_tag_list.select(None);
+}
+
+/// Replaces any tag filter, a search left in the buffer from last time is applied again
+pub fn open_card_search(app: &mut App) {
+    app.state.filter_tags = None;
+    app.state.all_available_tags = None;
+    app.state.app_list_states.filter_by_tag_list.select(None);
+    app.set_popup(PopUp::SearchCards);
+    apply_card_search(app);
+}
+
+// Runs on every keystroke, the boards are rebuilt from scratch so deleting characters widens
+// the results again
+fn apply_card_search(app: &mut App) {
+    let query = app.state.text_buffers.card_search.get_joined_lines();
+    let query = query.trim();
+    app.state.current_board_id = None;
+    app.state.current_card_id = None;
+    if query.is_empty() {
+        app.filtered_boards.reset();
+        refresh_visible_boards_and_cards(app);
+        return;
+    }
+    let card_filter = CardFilter {
+        search: Some(query.to_string()),
+        ..Default::default()
+    };
+    app.filtered_boards = Boards::from(card_filter.apply(
+        app.boards.get_boards(),
+        app.config.date_time_format,
+        chrono::Local::now().naive_local(),
+    ));
+    refresh_visible_boards_and_cards(app);
+}
+
+fn clear_card_search(app: &mut App) {
+    app.state.text_buffers.card_search.reset();
+    app.filtered_boards.reset();
+    refresh_visible_boards_and_cards(app);
+}
+
+/// Replaces every match in the description being edited, the popup stays open when the query
+/// is empty or matches nothing so it can be corrected
+fn handle_find_replace(app: &mut App) -> AppReturn {
+    if app.state.focus == Focus::CancelButton {
+        app.close_popup();
+        return AppReturn::Continue;
+    }
+    let query = app.state.text_buffers.find_replace_query.get_joined_lines();
+    if query.is_empty() {
+        app.send_error_toast(&tr(MessageId::ToastEnterTheTextToFind), None);
+        app.state.set_focus(Focus::FindReplaceQuery);
+        return AppReturn::Continue;
+    }
+    let replacement = app
+        .state
+        .text_buffers
+        .find_replace_replacement
+        .get_joined_lines();
+    let replaced = app
+        .state
+        .text_buffers
+        .card_description
+        .replace_all(&query, &replacement);
+    if replaced == 0 {
+        app.send_warning_toast(&format!("No matches for \"{}\"", query), None);
+        return AppReturn::Continue;
+    }
+    app.send_info_toast(
+        &format!(
+            "Replaced {} match{} of \"{}\"",
+            replaced,
+            if replaced == 1 { "" } else { "es" },
+            query
+        ),
+        None,
+    );
+    app.close_popup();
+    AppReturn::Continue
+}
+
+fn handle_search_cards_accept(app: &mut App) -> AppReturn {
+    let query = app.state.text_buffers.card_search.get_joined_lines();
+    let query = query.trim();
+    if query.is_empty() {
         app.close_popup();
-        return;
+        return AppReturn::Continue;
     }
-    let all_boards = app.boards.clone();
-    app.state.current_board_id = None;
-    app.state.current_card_id = None;
-    let filter_tags = app.state.filter_tags.clone().unwrap();
-    let mut filtered_boards = Vec::new();
-    for board in all_boards.get_boards() {
-        let mut filtered_cards = Vec::new();
-        for card in board.cards.get_all_cards() {
-            let mut card_tags = card.tags.clone();
-            card_tags.retain(|tag| filter_tags.contains(&tag.to_lowercase()));
-            if !card_tags.is_empty() {
-                filtered_cards.push(card.clone());
-            }
-        }
-        if !filtered_cards.is_empty() {
-            filtered_boards.push(Board {
-                id: board.id,
-                name: board.name.clone(),
-                description: board.description.clone(),
-                cards: Cards::from(filtered_cards),
-            });
-        }
+    if app.filtered_boards.is_empty() {
+        app.send_warning_toast(&format!("No cards match \"{}\"", query), None);
+        return AppReturn::Continue;
     }
-    app.filtered_boards = Boards::from(filtered_boards);
-    refresh_visible_boards_and_cards(app);
+    let card_count = app
+        .filtered_boards
+        .get_boards()
+        .iter()
+        .map(|board| board.cards.len())
+        .sum::<usize>();
     app.send_info_toast(
         &format!(
-            "Filtered by {} tags",
-            app.state.filter_tags.clone().unwrap().len()
+            "Showing {} card{} matching \"{}\"",
+            card_count,
+            if card_count == 1 { "" } else { "s" },
+            query
         ),
         None,
     );
     app.close_popup();
-    app.state.app_list_states.filter_by_tag_list.select(None);
+    AppReturn::Continue
 }
 
 fn handle_command_palette_card_selection(app: &mut App) {
@@ -5431,10 +10139,29 @@ fn handle_command_palette_card_selection(app: &mut App) {
         debug!("Selected index is out of bounds");
         return;
     }
-    let card_id = all_card_details[card_details_index].1;
+    let card_id = all_card_details[card_details_index].card_id;
     app.state.current_board_id = Some(app.boards.find_board_with_card_id(card_id).unwrap().1.id);
     app.state.current_card_id = Some(card_id);
     app.set_popup(PopUp::ViewCard);
+    // Start on the match, the description viewport follows its cursor
+    match all_card_details[card_details_index].field {
+        CardSearchField::Description { line } => {
+            app.state
+                .text_buffers
+                .card_description
+                .move_cursor(CursorMove::Jump(line as u16, 0));
+            app.state.set_focus(Focus::CardDescription);
+        }
+        CardSearchField::Comment { comment, .. } => {
+            app.state
+                .app_list_states
+                .card_view_comment_list
+                .select(Some(comment));
+            app.state.set_focus(Focus::CardComments);
+        }
+        CardSearchField::WaitingOn => app.state.set_focus(Focus::CardWaitingOn),
+        CardSearchField::Name | CardSearchField::Tags => {}
+    }
 }
 
 fn handle_command_palette_board_selection(app: &mut App) {
@@ -5459,7 +10186,7 @@ fn handle_command_palette_board_selection(app: &mut App) {
         debug!("Selected index is out of bounds");
         return;
     }
-    let board_id = all_board_details[board_details_index].1;
+    let board_id = all_board_details[board_details_index].board_id;
     let mut number_of_times_to_go_right = 0;
     for (board_index, board) in app.boards.get_boards().iter().enumerate() {
         if board.id == board_id {
@@ -5512,12 +10239,26 @@ pub async fn handle_send_reset_password_link_action(app: &mut App<'_>) {
 fn reset_new_board_form(app: &mut App) {
     app.state.text_buffers.board_name.reset();
     app.state.text_buffers.board_description.reset();
+    app.state.form_drafts.clear_restored();
+}
+
+fn reset_edit_board_form(app: &mut App) {
+    app.state.board_being_edited = None;
+    reset_new_board_form(app);
+}
+
+fn reset_edit_board_notes_form(app: &mut App) {
+    app.state.board_being_edited = None;
+    app.state.text_buffers.board_notes.reset();
 }
 
 fn reset_new_card_form(app: &mut App) {
     app.state.text_buffers.card_name.reset();
     app.state.text_buffers.card_description.reset();
+    app.state.text_buffers.card_tags = Vec::new();
+    app.state.new_card_template = None;
     app.widgets.date_time_picker.reset();
+    app.state.form_drafts.clear_restored();
 }
 
 fn reset_login_form(app: &mut App) {
@@ -5644,3 +10385,860 @@ pub fn reset_preview_boards(app: &mut App) {
     app.state.preview_file_name = None;
     app.state.preview_visible_boards_and_cards = LinkedHashMap::new();
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn an_edit_that_changes_nothing_is_not_recorded() {
+        let mut action_history_manager = ActionHistoryManager::default();
+        let mut card = Card {
+            date_modified: "2024/01/01-00:00:00".to_string(),
+            ..Card::default()
+        };
+        let old_card = card.clone();
+        record_card_edit(
+            &mut action_history_manager,
+            old_card.clone(),
+            &mut card,
+            (0, 0),
+            DateTimeFormat::default(),
+        );
+        assert_eq!(card, old_card);
+        assert!(action_history_manager.history.is_empty());
+
+        card.priority = CardPriority::High;
+        record_card_edit(
+            &mut action_history_manager,
+            old_card.clone(),
+            &mut card,
+            (0, 0),
+            DateTimeFormat::default(),
+        );
+        assert_ne!(card.date_modified, old_card.date_modified);
+        assert_eq!(action_history_manager.history.len(), 1);
+    }
+
+    #[test]
+    fn deleting_a_range_in_a_filtered_view_deletes_the_shown_cards() {
+        let (mut app, _io_rx) = App::for_tests();
+        let mut board = Board::new("Todo", "");
+        for name in ["a", "b", "c", "d"] {
+            board.cards.add_card(Card {
+                name: name.to_string(),
+                ..Card::default()
+            });
+        }
+        let board_id = board.id;
+        let card_ids = board.cards.get_all_card_ids();
+        let mut filtered_board = board.clone();
+        filtered_board
+            .cards
+            .remove_card_with_id(card_ids[1])
+            .unwrap();
+        app.boards.set_boards(Boards::from(vec![board]));
+        app.filtered_boards = Boards::from(vec![filtered_board]);
+        app.state.current_board_id = Some(board_id);
+
+        handle_delete_card_range(&mut app, vec![card_ids[2], card_ids[3]]);
+        let remaining = |boards: &Boards| {
+            boards
+                .get_board_with_id(board_id)
+                .unwrap()
+                .cards
+                .get_all_card_ids()
+        };
+        assert_eq!(remaining(&app.filtered_boards), vec![card_ids[0]]);
+        assert_eq!(remaining(&app.boards), vec![card_ids[0], card_ids[1]]);
+        assert_eq!(app.state.current_card_id, Some(card_ids[0]));
+
+        app.undo();
+        assert_eq!(remaining(&app.boards).len(), 4);
+    }
+
+    fn app_with_new_card_form<'a>(card_name: &str) -> (App<'a>, (u64, u64)) {
+        let (mut app, _io_rx) = App::for_tests();
+        let board = Board::new("Todo", "");
+        let board_id = board.id;
+        app.boards.set_boards(Boards::from(vec![board]));
+        app.state.current_board_id = Some(board_id);
+        app.state.text_buffers.card_name.insert_str(card_name);
+        app.state.set_focus(Focus::SubmitButton);
+        (app, board_id)
+    }
+
+    #[test]
+    fn a_quick_add_estimate_token_sets_the_estimate() {
+        let (mut app, board_id) = app_with_new_card_form("Plan sprint ~2,5");
+        assert!(handle_new_card_action(&mut app, true, false));
+        let cards = app
+            .boards
+            .get_board_with_id(board_id)
+            .unwrap()
+            .cards
+            .clone();
+        let card = &cards.get_all_cards()[0];
+        assert_eq!(card.name, "Plan sprint");
+        assert_eq!(card.estimate, Some(2.5));
+    }
+
+    #[test]
+    fn a_negative_quick_add_estimate_creates_no_card() {
+        let (mut app, board_id) = app_with_new_card_form("Plan sprint =-2");
+        assert!(!handle_new_card_action(&mut app, true, false));
+        let board = app.boards.get_board_with_id(board_id).unwrap();
+        assert!(board.cards.is_empty());
+        assert_eq!(
+            app.state.text_buffers.card_name.get_joined_lines(),
+            "Plan sprint =-2"
+        );
+    }
+
+    fn app_with_priority_cards<'a>() -> (App<'a>, (u64, u64), Vec<(u64, u64)>) {
+        let (mut app, _io_rx) = App::for_tests();
+        let mut board = Board::new("Todo", "");
+        for (name, priority) in [
+            ("low", CardPriority::Low),
+            ("high", CardPriority::High),
+            ("medium", CardPriority::Medium),
+        ] {
+            board.cards.add_card(Card {
+                name: name.to_string(),
+                priority,
+                ..Card::default()
+            });
+        }
+        let board_id = board.id;
+        let card_ids = board.cards.get_all_card_ids();
+        app.boards.set_boards(Boards::from(vec![board]));
+        app.state.current_view = View::Zen;
+        app.state.set_focus(Focus::Body);
+        refresh_visible_boards_and_cards(&mut app);
+        (app, board_id, card_ids)
+    }
+
+    #[test]
+    fn turning_on_auto_sort_sorts_the_board_and_undo_restores_it() {
+        let (mut app, board_id, card_ids) = app_with_priority_cards();
+        let board = |app: &App| app.boards.get_board_with_id(board_id).unwrap().clone();
+
+        toggle_auto_sort_by_priority(&mut app);
+        assert!(board(&app).auto_sort_by_priority);
+        assert_eq!(
+            board(&app).cards.get_all_card_ids(),
+            vec![card_ids[1], card_ids[2], card_ids[0]]
+        );
+
+        app.undo();
+        assert!(!board(&app).auto_sort_by_priority);
+        assert_eq!(board(&app).cards.get_all_card_ids(), card_ids);
+    }
+
+    #[test]
+    fn moving_a_card_on_an_auto_sorted_board_is_refused() {
+        let (mut app, board_id, _) = app_with_priority_cards();
+        toggle_auto_sort_by_priority(&mut app);
+        let sorted_card_ids = app
+            .boards
+            .get_board_with_id(board_id)
+            .unwrap()
+            .cards
+            .get_all_card_ids();
+        let history_len = app.action_history_manager.history.len();
+        app.state.current_card_id = Some(sorted_card_ids[2]);
+
+        handle_move_card_to_board_edge(&mut app, true);
+        handle_move_card_to_board_edge(&mut app, false);
+        assert_eq!(
+            app.boards
+                .get_board_with_id(board_id)
+                .unwrap()
+                .cards
+                .get_all_card_ids(),
+            sorted_card_ids
+        );
+        assert_eq!(app.action_history_manager.history.len(), history_len);
+        let refusal = tr(MessageId::ToastCannotMoveCardsOnAnAutoSortedBoard);
+        assert!(app
+            .widgets
+            .toast_widget
+            .toasts
+            .iter()
+            .any(|toast| toast.message == refusal));
+    }
+
+    #[test]
+    fn an_auto_sorted_board_stays_sorted_after_a_priority_change() {
+        let (mut app, board_id, card_ids) = app_with_priority_cards();
+        toggle_auto_sort_by_priority(&mut app);
+        app.boards
+            .get_mut_board_with_id(board_id)
+            .unwrap()
+            .cards
+            .get_mut_card_with_id(card_ids[0])
+            .unwrap()
+            .priority = CardPriority::High;
+
+        refresh_visible_cards_for_board(&mut app, board_id);
+        assert_eq!(
+            app.boards
+                .get_board_with_id(board_id)
+                .unwrap()
+                .cards
+                .get_all_card_ids(),
+            vec![card_ids[1], card_ids[0], card_ids[2]]
+        );
+    }
+
+    #[test]
+    fn resizing_the_help_and_log_panes_stores_the_height() {
+        let (mut app, _io_rx) = App::for_tests();
+        app.state.max_secondary_pane_height = Some(DEFAULT_SECONDARY_PANE_HEIGHT + 1);
+
+        resize_secondary_panes(&mut app, true);
+        assert_eq!(
+            app.config.secondary_pane_height,
+            Some(DEFAULT_SECONDARY_PANE_HEIGHT + 1)
+        );
+        // Already at the largest, nothing changes
+        resize_secondary_panes(&mut app, true);
+        assert_eq!(
+            app.state.secondary_pane_height,
+            DEFAULT_SECONDARY_PANE_HEIGHT + 1
+        );
+        resize_secondary_panes(&mut app, false);
+        assert_eq!(
+            app.config.secondary_pane_height,
+            Some(DEFAULT_SECONDARY_PANE_HEIGHT)
+        );
+    }
+
+    #[test]
+    fn skipped_theme_files_are_listed_in_one_toast() {
+        let (mut app, _io_rx) = App::for_tests();
+        app.widgets.toast_widget.toasts.clear();
+        warn_about_skipped_theme_files(&mut app, &[]);
+        assert!(app.widgets.toast_widget.toasts.is_empty());
+
+        warn_about_skipped_theme_files(
+            &mut app,
+            &[
+                "kanban_theme_a.json".to_string(),
+                "kanban_theme_b.json".to_string(),
+            ],
+        );
+        assert_eq!(app.widgets.toast_widget.toasts.len(), 1);
+        assert_eq!(
+            app.widgets.toast_widget.toasts[0].message,
+            "Skipped 2 corrupt theme files: kanban_theme_a.json, kanban_theme_b.json"
+        );
+    }
+
+    #[test]
+    fn rapid_card_entry_clears_the_form_and_refocuses_the_name() {
+        let (mut app, board_id) = app_with_new_card_form("First");
+        app.state
+            .text_buffers
+            .card_description
+            .insert_str("Details");
+        app.widgets.date_time_picker.selected_date_time =
+            NaiveDateTime::parse_from_str("2030/01/02-10:00:00", "%Y/%m/%d-%H:%M:%S").ok();
+
+        assert!(handle_new_card_action(&mut app, true, false));
+        assert!(app.state.text_buffers.card_name.is_empty());
+        assert!(app.state.text_buffers.card_description.is_empty());
+        assert!(app.state.text_buffers.card_tags.is_empty());
+        assert!(app.state.new_card_template.is_none());
+        assert_eq!(app.state.focus, Focus::CardName);
+        assert_eq!(app.state.app_status, AppStatus::UserInput);
+
+        // The due date picked for the first card is not carried over to the next one
+        app.state.text_buffers.card_name.insert_str("Second");
+        app.state.set_focus(Focus::SubmitButton);
+        assert!(handle_new_card_action(&mut app, true, false));
+        let board = app.boards.get_board_with_id(board_id).unwrap();
+        let cards = board.cards.get_all_cards();
+        assert_eq!(cards.len(), 2);
+        assert_ne!(cards[0].due_date, FIELD_NOT_SET);
+        assert_eq!(cards[1].due_date, FIELD_NOT_SET);
+        assert_eq!(app.action_history_manager.history.len(), 2);
+    }
+
+    #[test]
+    fn rapid_board_entry_clears_the_form_and_refocuses_the_name() {
+        let (mut app, _io_rx) = App::for_tests();
+        app.state.text_buffers.board_name.insert_str("Backlog");
+        app.state.text_buffers.board_description.insert_str("Later");
+        app.state.set_focus(Focus::SubmitButton);
+
+        assert!(handle_new_board_action(&mut app, true));
+        assert!(app.state.text_buffers.board_name.is_empty());
+        assert!(app.state.text_buffers.board_description.is_empty());
+        assert_eq!(app.state.focus, Focus::NewBoardName);
+        assert_eq!(app.state.app_status, AppStatus::UserInput);
+        assert_eq!(app.boards.get_boards().len(), 1);
+    }
+
+    #[test]
+    fn every_config_row_routes_to_its_editor() {
+        for config_enum in ConfigEnum::iter() {
+            let (mut app, _io_rx) = App::for_tests();
+            app.set_view(View::ConfigMenu);
+            app.state.set_focus(Focus::ConfigTable);
+            let row = app
+                .config
+                .to_view_list()
+                .iter()
+                .position(|row| row[0] == config_enum.to_string())
+                .unwrap_or_else(|| panic!("{} has no row in the config menu", config_enum));
+            app.state.app_table_states.config.select(Some(row));
+            let value_before = app.config.get_value_as_string(config_enum);
+
+            handle_config_menu_action(&mut app);
+            let popup = app.state.z_stack.last().copied();
+            match config_enum.edit_mode() {
+                ConfigEditMode::Toggle => {
+                    assert_eq!(popup, None, "{} opened a popup", config_enum);
+                    assert_ne!(
+                        app.config.get_value_as_string(config_enum),
+                        value_before,
+                        "{} was not toggled",
+                        config_enum
+                    );
+                }
+                ConfigEditMode::Selector => {
+                    let expected_popup = match config_enum {
+                        ConfigEnum::DefaultView => PopUp::SelectDefaultView,
+                        ConfigEnum::DefaultTheme => PopUp::ChangeTheme,
+                        ConfigEnum::DateFormat => PopUp::ChangeDateFormatPopup,
+                        _ => panic!("{} has no selector popup", config_enum),
+                    };
+                    assert_eq!(popup, Some(expected_popup), "{}", config_enum);
+                }
+                ConfigEditMode::Number | ConfigEditMode::Text => {
+                    assert_eq!(popup, Some(PopUp::EditGeneralConfig), "{}", config_enum);
+                }
+                ConfigEditMode::Keybindings => {
+                    assert_eq!(popup, None);
+                    assert_eq!(app.state.current_view, View::EditKeybindings);
+                }
+            }
+        }
+        // Toggling the row above switched the process wide setting on
+        crate::ui::text_box::set_unicode_normalization(
+            AppConfig::default().normalize_unicode_input,
+        );
+    }
+
+    #[test]
+    fn plus_and_minus_only_step_number_rows() {
+        let (mut app, _io_rx) = App::for_tests();
+        let select_row = |app: &mut App, config_enum: ConfigEnum| {
+            let row = app
+                .config
+                .to_view_list()
+                .iter()
+                .position(|row| row[0] == config_enum.to_string())
+                .unwrap();
+            app.state.app_table_states.config.select(Some(row));
+        };
+
+        select_row(&mut app, ConfigEnum::CardPreviewLines);
+        let preview_lines = app.config.card_preview_lines;
+        handle_config_value_step(&mut app, true);
+        assert_eq!(app.config.card_preview_lines, preview_lines + 1);
+        handle_config_value_step(&mut app, false);
+        assert_eq!(app.config.card_preview_lines, preview_lines);
+
+        select_row(&mut app, ConfigEnum::SaveOnExit);
+        let save_on_exit = app.config.save_on_exit;
+        handle_config_value_step(&mut app, true);
+        assert_eq!(app.config.save_on_exit, save_on_exit);
+    }
+
+    #[test]
+    fn undoing_a_delete_restores_the_original_ids() {
+        let (mut app, _io_rx) = App::for_tests();
+        let mut board = Board::new("Todo", "");
+        board.cards.add_card(Card::default());
+        board.cards.add_card(Card::default());
+        let board_id = board.id;
+        let card_ids = board.cards.get_all_card_ids();
+        app.boards.set_boards(Boards::from(vec![board]));
+        refresh_visible_boards_and_cards(&mut app);
+        app.set_view(View::Zen);
+        app.state.set_focus(Focus::Body);
+        app.state.current_board_id = Some(board_id);
+        app.state.current_card_id = Some(card_ids[1]);
+        let delete_key = app.config.keybindings.delete_card[0];
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+
+        runtime.block_on(handle_general_actions(&mut app, delete_key));
+        assert_eq!(
+            app.boards.get_board_with_id(board_id).unwrap().cards.len(),
+            1
+        );
+        app.undo();
+        let board = app.boards.get_board_with_id(board_id).unwrap();
+        assert_eq!(board.cards.get_all_card_ids(), card_ids);
+
+        app.state.current_card_id = None;
+        app.state.current_board_id = Some(board_id);
+        app.state.set_focus(Focus::Body);
+        runtime.block_on(handle_general_actions(&mut app, delete_key));
+        assert!(app.boards.get_board_with_id(board_id).is_none());
+        app.undo();
+        let board = app.boards.get_board_with_id(board_id).unwrap();
+        assert_eq!(board.cards.get_all_card_ids(), card_ids);
+    }
+
+    fn status(card_status: CardStatus) -> StatusSelection {
+        StatusSelection {
+            card_status,
+            custom_status: None,
+        }
+    }
+
+    #[test]
+    fn the_status_preview_describes_completing_a_card() {
+        let preview = get_card_status_change_preview(
+            &[status(CardStatus::Active)],
+            &status(CardStatus::Complete),
+            true,
+            DateTimeFormat::default(),
+        );
+        assert_eq!(preview.len(), 3);
+        assert_eq!(preview[0], "Active → Complete");
+        assert!(preview[1].starts_with("Completion date will be set to "));
+        assert_eq!(
+            preview[2],
+            "Card will be hidden, completed cards are hidden on this board"
+        );
+    }
+
+    #[test]
+    fn the_status_preview_describes_reopening_cards() {
+        let preview = get_card_status_change_preview(
+            &[
+                status(CardStatus::Complete),
+                status(CardStatus::Active),
+                status(CardStatus::Complete),
+            ],
+            &status(CardStatus::Active),
+            true,
+            DateTimeFormat::default(),
+        );
+        assert_eq!(
+            preview,
+            vec![
+                "2 of 3 selected cards will change to Active",
+                "Completion date will be cleared",
+                "Cards will be shown again, completed cards are hidden on this board",
+            ]
+        );
+        let preview = get_card_status_change_preview(
+            &[status(CardStatus::Complete)],
+            &status(CardStatus::Active),
+            false,
+            DateTimeFormat::default(),
+        );
+        assert_eq!(
+            preview,
+            vec!["Complete → Active", "Completion date will be cleared"]
+        );
+    }
+
+    #[test]
+    fn the_status_preview_keeps_the_date_between_custom_statuses() {
+        let review = StatusSelection {
+            card_status: CardStatus::Active,
+            custom_status: Some("Review".to_string()),
+        };
+        let preview = get_card_status_change_preview(
+            &[status(CardStatus::Active)],
+            &review,
+            false,
+            DateTimeFormat::default(),
+        );
+        assert_eq!(preview, vec!["Active → Review"]);
+        let preview = get_card_status_change_preview(
+            std::slice::from_ref(&review),
+            &review,
+            false,
+            DateTimeFormat::default(),
+        );
+        assert_eq!(preview, vec!["Already Review, nothing will change"]);
+    }
+
+    #[test]
+    fn the_priority_preview_counts_the_changing_cards() {
+        assert_eq!(
+            get_card_priority_change_preview(&[CardPriority::Low], &CardPriority::Low, None),
+            vec!["Already Low priority, nothing will change"]
+        );
+        assert_eq!(
+            get_card_priority_change_preview(&[CardPriority::Low], &CardPriority::High, None),
+            vec!["Low → High priority"]
+        );
+        assert_eq!(
+            get_card_priority_change_preview(
+                &[CardPriority::Low, CardPriority::High, CardPriority::Medium],
+                &CardPriority::High,
+                None
+            ),
+            vec!["2 of 3 selected cards will change to High priority"]
+        );
+    }
+
+    #[test]
+    fn the_priority_preview_shows_where_auto_sort_moves_the_card() {
+        let (app, board_id, card_ids) = app_with_priority_cards();
+        let low_card_id = card_ids[0];
+        let mut board = app.boards.get_board_with_id(board_id).unwrap().clone();
+        assert_eq!(
+            board.get_auto_sorted_index(low_card_id, &CardPriority::High),
+            None
+        );
+        board.auto_sort_by_priority = true;
+        board.apply_auto_sort();
+        let current_index = board.cards.get_card_index(low_card_id).unwrap();
+        assert_eq!(current_index, 2);
+        let new_index = board
+            .get_auto_sorted_index(low_card_id, &CardPriority::High)
+            .unwrap();
+        assert_eq!(
+            get_card_priority_change_preview(
+                &[CardPriority::Low],
+                &CardPriority::High,
+                Some((current_index, new_index))
+            ),
+            // The sort is stable so it goes below the card that already was High
+            vec![
+                "Low → High priority",
+                "Board auto sort will move this card to position 2"
+            ]
+        );
+        // Medium still sorts below the other Medium card, so the card stays where it is
+        let new_index = board
+            .get_auto_sorted_index(low_card_id, &CardPriority::Medium)
+            .unwrap();
+        assert_eq!(new_index, current_index);
+        assert_eq!(
+            get_card_priority_change_preview(
+                &[CardPriority::Low],
+                &CardPriority::Medium,
+                Some((current_index, new_index))
+            ),
+            vec!["Low → Medium priority"]
+        );
+    }
+
+    #[test]
+    fn whats_new_is_not_shown_on_the_first_run() {
+        let (mut app, _io_rx) = App::for_tests();
+        show_whats_new_after_upgrade(&mut app);
+        assert!(app.state.z_stack.is_empty());
+        assert_eq!(
+            app.config.last_seen_version.as_deref(),
+            Some(env!("CARGO_PKG_VERSION"))
+        );
+    }
+
+    #[test]
+    fn whats_new_lists_the_releases_since_the_last_seen_one() {
+        for last_seen_version in [Some("0.0.1"), None] {
+            let (mut app, _io_rx) = App::for_tests();
+            app.config.last_seen_version = last_seen_version.map(String::from);
+            show_whats_new_after_upgrade(&mut app);
+            assert_eq!(app.state.z_stack.last(), Some(&PopUp::WhatsNew));
+            assert_eq!(
+                app.state.whats_new_entries.len(),
+                get_entries_since(last_seen_version, env!("CARGO_PKG_VERSION")).len()
+            );
+            assert_eq!(
+                app.config.last_seen_version.as_deref(),
+                Some(env!("CARGO_PKG_VERSION"))
+            );
+        }
+    }
+
+    #[test]
+    fn a_downgrade_only_updates_the_last_seen_version() {
+        let (mut app, _io_rx) = App::for_tests();
+        app.config.last_seen_version = Some("999.0.0".to_string());
+        show_whats_new_after_upgrade(&mut app);
+        assert!(app.state.z_stack.is_empty());
+        assert_eq!(
+            app.config.last_seen_version.as_deref(),
+            Some(env!("CARGO_PKG_VERSION"))
+        );
+    }
+
+    /// A board with more cards than fit in its window, the first card is selected
+    fn app_with_long_board<'a>(
+        wrap_around_navigation: bool,
+    ) -> (App<'a>, (u64, u64), Vec<(u64, u64)>) {
+        let (mut app, _io_rx) = App::for_tests();
+        app.config.wrap_around_navigation = wrap_around_navigation;
+        let mut board = Board::new("Todo", "");
+        for card_number in 0..app.config.no_of_cards_to_show * 2 {
+            board.cards.add_card(Card {
+                name: format!("Card {}", card_number),
+                ..Card::default()
+            });
+        }
+        let board_id = board.id;
+        let card_ids = board.cards.get_all_card_ids();
+        app.boards.set_boards(Boards::from(vec![board]));
+        app.state.current_view = View::Zen;
+        app.state.set_focus(Focus::Body);
+        refresh_visible_boards_and_cards(&mut app);
+        app.state.current_board_id = Some(board_id);
+        app.state.current_card_id = Some(card_ids[0]);
+        app.widgets.toast_widget.toasts.clear();
+        (app, board_id, card_ids)
+    }
+
+    fn toast_messages(app: &App) -> Vec<String> {
+        app.widgets
+            .toast_widget
+            .toasts
+            .iter()
+            .map(|toast| toast.message.clone())
+            .collect()
+    }
+
+    #[test]
+    fn card_navigation_wraps_to_the_other_end_of_the_board() {
+        let (mut app, board_id, card_ids) = app_with_long_board(true);
+        let last_card_id = *card_ids.last().unwrap();
+
+        go_up(&mut app);
+        assert_eq!(app.state.current_card_id, Some(last_card_id));
+        assert!(app.visible_boards_and_cards[&board_id].contains(&last_card_id));
+        go_down(&mut app);
+        assert_eq!(app.state.current_card_id, Some(card_ids[0]));
+        assert!(app.visible_boards_and_cards[&board_id].contains(&card_ids[0]));
+        assert!(toast_messages(&app).is_empty());
+    }
+
+    #[test]
+    fn card_navigation_stops_at_the_ends_with_a_toast() {
+        let (mut app, board_id, card_ids) = app_with_long_board(false);
+        let last_card_id = *card_ids.last().unwrap();
+
+        go_up(&mut app);
+        assert_eq!(app.state.current_card_id, Some(card_ids[0]));
+        assert_eq!(
+            toast_messages(&app),
+            vec!["Cannot go up: Already at the first card"]
+        );
+        app.widgets.toast_widget.toasts.clear();
+
+        for _ in 1..card_ids.len() {
+            go_down(&mut app);
+        }
+        assert_eq!(app.state.current_card_id, Some(last_card_id));
+        assert!(app.visible_boards_and_cards[&board_id].contains(&last_card_id));
+        assert!(toast_messages(&app).is_empty());
+        go_down(&mut app);
+        assert_eq!(app.state.current_card_id, Some(last_card_id));
+        assert_eq!(
+            toast_messages(&app),
+            vec!["Cannot go down: Already at the last card"]
+        );
+    }
+
+    /// Twice as many boards and cards as fit on screen with the first board selected, so anything
+    /// added at the end starts out of view
+    fn app_with_full_screen_of_boards<'a>() -> App<'a> {
+        let (mut app, _io_rx) = App::for_tests();
+        let boards = (0..app.config.no_of_boards_to_show * 2)
+            .map(|board_number| {
+                let mut board = Board::new(&format!("Board {}", board_number), "");
+                for card_number in 0..app.config.no_of_cards_to_show * 2 {
+                    board.cards.add_card(Card {
+                        name: format!("Card {}", card_number),
+                        ..Card::default()
+                    });
+                }
+                board
+            })
+            .collect::<Vec<Board>>();
+        let first_board_id = boards[0].id;
+        app.boards.set_boards(Boards::from(boards));
+        app.set_view(View::Zen);
+        app.state.prev_view = Some(View::Zen);
+        app.state.set_focus(Focus::Body);
+        app.state.current_board_id = Some(first_board_id);
+        refresh_visible_boards_and_cards(&mut app);
+        app
+    }
+
+    fn last_board_id(app: &App) -> (u64, u64) {
+        app.boards.get_boards().last().unwrap().id
+    }
+
+    fn last_card_id(app: &App, board_id: (u64, u64)) -> (u64, u64) {
+        *app.boards
+            .get_board_with_id(board_id)
+            .unwrap()
+            .cards
+            .get_all_card_ids()
+            .last()
+            .unwrap()
+    }
+
+    /// The item is selected, on screen, flashing and the windows kept their size. A revealed
+    /// board has its first card selected
+    fn assert_revealed(app: &App, board_id: (u64, u64), card_id: Option<(u64, u64)>) {
+        assert_eq!(app.state.current_board_id, Some(board_id));
+        let board = app.boards.get_board_with_id(board_id).unwrap();
+        assert_eq!(
+            app.state.current_card_id,
+            card_id.or(board.cards.get_first_card_id())
+        );
+        assert_eq!(
+            app.visible_boards_and_cards.len(),
+            app.config.no_of_boards_to_show as usize
+        );
+        let visible_card_ids = &app.visible_boards_and_cards[&board_id];
+        if let Some(card_id) = card_id {
+            assert!(visible_card_ids.contains(&card_id));
+            assert_eq!(
+                visible_card_ids.len(),
+                app.get_no_of_cards_to_show() as usize
+            );
+        }
+        assert!(app.state.is_created_item_flashing(board_id, card_id));
+    }
+
+    fn submit_new_board(app: &mut App, name: &str, rapid_entry: bool) -> bool {
+        app.set_view(View::NewBoard);
+        app.state.text_buffers.board_name.insert_str(name);
+        app.state.set_focus(Focus::SubmitButton);
+        handle_new_board_action(app, rapid_entry)
+    }
+
+    fn submit_new_card(app: &mut App, board_id: (u64, u64), name: &str, rapid_entry: bool) -> bool {
+        app.set_view(View::NewCard);
+        app.state.current_board_id = Some(board_id);
+        app.state.text_buffers.card_name.insert_str(name);
+        app.state.set_focus(Focus::SubmitButton);
+        handle_new_card_action(app, rapid_entry, false)
+    }
+
+    #[test]
+    fn a_board_from_the_new_board_form_is_selected_and_shown() {
+        for rapid_entry in [false, true] {
+            let mut app = app_with_full_screen_of_boards();
+            assert_eq!(
+                submit_new_board(&mut app, "Fresh", rapid_entry),
+                rapid_entry
+            );
+            let board_id = last_board_id(&app);
+            assert_eq!(
+                app.boards.get_board_with_id(board_id).unwrap().name,
+                "Fresh"
+            );
+            assert_revealed(&app, board_id, None);
+        }
+    }
+
+    #[test]
+    fn a_card_from_the_new_card_form_is_selected_and_shown() {
+        for rapid_entry in [false, true] {
+            let mut app = app_with_full_screen_of_boards();
+            let board_id = last_board_id(&app);
+            assert!(!app.visible_boards_and_cards.contains_key(&board_id));
+            submit_new_card(&mut app, board_id, "Fresh", rapid_entry);
+            let card_id = last_card_id(&app, board_id);
+            assert_revealed(&app, board_id, Some(card_id));
+        }
+    }
+
+    #[test]
+    fn undoing_a_delete_selects_and_shows_the_restored_item() {
+        let mut app = app_with_full_screen_of_boards();
+        let board_id = last_board_id(&app);
+        let card_id = last_card_id(&app, board_id);
+        let card = app
+            .boards
+            .get_mut_board_with_id(board_id)
+            .unwrap()
+            .cards
+            .remove_card_with_id(card_id)
+            .unwrap();
+        app.action_history_manager
+            .new_action(ActionHistory::DeleteCard(card, board_id));
+        refresh_visible_boards_and_cards(&mut app);
+        app.undo();
+        assert_revealed(&app, board_id, Some(card_id));
+
+        let board = app.boards.get_board_with_id(board_id).unwrap().clone();
+        app.boards.remove_board_with_id(board_id);
+        app.action_history_manager
+            .new_action(ActionHistory::DeleteBoard(board));
+        app.state.current_board_id = app.boards.get_first_board_id();
+        refresh_visible_boards_and_cards(&mut app);
+        app.undo();
+        assert_revealed(&app, board_id, None);
+    }
+
+    #[test]
+    fn redoing_a_creation_selects_and_shows_the_created_item() {
+        let mut app = app_with_full_screen_of_boards();
+        assert!(!submit_new_board(&mut app, "Fresh", false));
+        let board_id = last_board_id(&app);
+        app.undo();
+        assert!(app.boards.get_board_with_id(board_id).is_none());
+        app.redo();
+        assert_revealed(&app, board_id, None);
+
+        let board_id = app.boards.get_boards()[app.boards.len() - 2].id;
+        submit_new_card(&mut app, board_id, "Fresh", false);
+        let card_id = last_card_id(&app, board_id);
+        app.undo();
+        app.state.current_board_id = app.boards.get_first_board_id();
+        app.state.current_card_id = None;
+        refresh_visible_boards_and_cards(&mut app);
+        app.redo();
+        assert_revealed(&app, board_id, Some(card_id));
+    }
+
+    #[test]
+    fn a_created_card_hidden_by_a_filter_is_not_selected_or_flashed() {
+        let mut app = app_with_full_screen_of_boards();
+        let board_id = last_board_id(&app);
+        app.state
+            .boards_with_hidden_completed_cards
+            .insert(board_id);
+        let card = Card {
+            name: "Done already".to_string(),
+            card_status: CardStatus::Complete,
+            ..Card::default()
+        };
+        let card_id = card.id;
+        app.boards
+            .get_mut_board_with_id(board_id)
+            .unwrap()
+            .cards
+            .add_card(card.clone());
+        reveal_created_item(&mut app, board_id, Some(card_id));
+        assert_eq!(app.state.current_board_id, Some(board_id));
+        assert_ne!(app.state.current_card_id, Some(card_id));
+        assert!(app.state.created_item_flash.is_none());
+    }
+
+    #[test]
+    fn nothing_flashes_with_animations_disabled() {
+        let mut app = app_with_full_screen_of_boards();
+        app.config.disable_animations = true;
+        submit_new_board(&mut app, "Fresh", false);
+        let board_id = last_board_id(&app);
+        assert_eq!(app.state.current_board_id, Some(board_id));
+        assert!(app.visible_boards_and_cards.contains_key(&board_id));
+        assert!(app.state.created_item_flash.is_none());
+    }
+}
@@ -0,0 +1,139 @@
+//! Cards laid out by due day for the Calendar view
+//!
+//! ```
+//! use chrono::NaiveDate;
+//! use rust_kanban::{
+//!     app::{
+//!         calendar::{cards_due_on, cards_due_per_day, month_grid},
+//!         kanban::{Board, Boards},
+//!     },
+//!     ui::widgets::date_time_picker::CalenderType,
+//! };
+//! use serde_json::json;
+//!
+//! let date = |day: u32| NaiveDate::from_ymd_opt(2024, 5, day).unwrap();
+//!
+//! // May 2024 starts on a Wednesday
+//! let weeks = month_grid(date(15), &CalenderType::MondayFirst);
+//! assert_eq!(weeks.len(), 5);
+//! assert_eq!(weeks[0][..3], [None, None, Some(date(1))]);
+//! assert_eq!(weeks[4][4], Some(date(31)));
+//! assert_eq!(weeks[4][5], None);
+//! let weeks = month_grid(date(15), &CalenderType::SundayFirst);
+//! assert_eq!(weeks[0][3], Some(date(1)));
+//! assert_eq!(weeks[0][2], None);
+//!
+//! let card = |id: u64, status: &str, due_date: &str| {
+//!     json!({
+//!         "id": [id, 1], "name": format!("Card {}", id), "description": "",
+//!         "date_created": "", "date_modified": "", "due_date": due_date,
+//!         "date_completed": "", "priority": "Low", "card_status": status,
+//!         "tags": [], "comments": [],
+//!     })
+//! };
+//! let board = Board::from_json(&json!({
+//!     "name": "Release", "description": "",
+//!     "cards": [
+//!         card(1, "Active", "01/05/2024"),
+//!         card(2, "Complete", "01/05/2024-17:30:00"),
+//!         card(3, "Archived", "01/05/2024"),
+//!         card(4, "Active", "01/06/2024"),
+//!         card(5, "Active", "Not Set"),
+//!     ],
+//! }))
+//! .unwrap();
+//! let boards = Boards::from(vec![board]);
+//!
+//! // Archived cards and cards due in other months are left out
+//! let due_per_day = cards_due_per_day(&boards, date(15));
+//! assert_eq!(due_per_day.len(), 1);
+//! let names = due_per_day[&date(1)]
+//!     .iter()
+//!     .map(|due_card| due_card.card_name.as_str())
+//!     .collect::<Vec<&str>>();
+//! assert_eq!(names, ["Card 1", "Card 2"]);
+//! assert_eq!(cards_due_on(&boards, date(1)).len(), 2);
+//! assert!(cards_due_on(&boards, date(2)).is_empty());
+//! ```
+
+use crate::{
+    app::kanban::{Boards, CardStatus},
+    ui::widgets::date_time_picker::CalenderType,
+    util::parse_date_time,
+};
+use chrono::{Datelike, Duration, NaiveDate};
+use std::collections::BTreeMap;
+
+/// A card that is due on a given day, with enough to show it and find it again
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DueCard {
+    pub board_id: (u64, u64),
+    pub card_id: (u64, u64),
+    pub card_name: String,
+    pub board_name: String,
+}
+
+/// The weeks of the month `date` falls in, padded with `None` before the first and after the
+/// last day so every week has seven days starting on the configured first day of the week
+pub fn month_grid(date: NaiveDate, calender_type: &CalenderType) -> Vec<[Option<NaiveDate>; 7]> {
+    let first_day = date.with_day(1).unwrap_or(date);
+    let leading_days = match calender_type {
+        CalenderType::MondayFirst => first_day.weekday().num_days_from_monday(),
+        CalenderType::SundayFirst => first_day.weekday().num_days_from_sunday(),
+    } as usize;
+    let mut weeks = vec![[None; 7]];
+    let mut day = first_day;
+    let mut column = leading_days;
+    while day.month() == first_day.month() {
+        if column == 7 {
+            weeks.push([None; 7]);
+            column = 0;
+        }
+        weeks.last_mut().unwrap()[column] = Some(day);
+        column += 1;
+        day += Duration::days(1);
+    }
+    weeks
+}
+
+/// Every card due in the month `date` falls in, keyed by its due day. Cards keep their board
+/// order within a day and archived cards are left out
+pub fn cards_due_per_day(boards: &Boards, date: NaiveDate) -> BTreeMap<NaiveDate, Vec<DueCard>> {
+    let mut due_per_day: BTreeMap<NaiveDate, Vec<DueCard>> = BTreeMap::new();
+    for (due_date, due_card) in due_cards(boards) {
+        if due_date.year() == date.year() && due_date.month() == date.month() {
+            due_per_day.entry(due_date).or_default().push(due_card);
+        }
+    }
+    due_per_day
+}
+
+/// The cards due on `date`, in board order
+pub fn cards_due_on(boards: &Boards, date: NaiveDate) -> Vec<DueCard> {
+    due_cards(boards)
+        .filter(|(due_date, _)| *due_date == date)
+        .map(|(_, due_card)| due_card)
+        .collect()
+}
+
+fn due_cards(boards: &Boards) -> impl Iterator<Item = (NaiveDate, DueCard)> + '_ {
+    boards.get_boards().iter().flat_map(|board| {
+        board
+            .cards
+            .get_all_cards()
+            .iter()
+            .filter(|card| card.card_status != CardStatus::Archived)
+            .filter_map(move |card| {
+                let due_date = parse_date_time(&card.due_date)?.date();
+                Some((
+                    due_date,
+                    DueCard {
+                        board_id: board.id,
+                        card_id: card.id,
+                        card_name: card.name.clone(),
+                        board_name: board.name.clone(),
+                    },
+                ))
+            })
+    })
+}
@@ -0,0 +1,110 @@
+//! How long cards have gone without a change, for the aging cue on the kanban board
+
+use crate::{app::kanban::Card, util::parse_date_time};
+use chrono::NaiveDate;
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AgeTier {
+    Fresh,
+    Aging,
+    Old,
+}
+
+/// A card is aging once it has gone `aging_days` without a change and old at twice that, an
+/// `aging_days` of 0 keeps every card fresh
+pub fn age_tier(age_days: i64, aging_days: u16) -> AgeTier {
+    let aging_days = i64::from(aging_days);
+    if aging_days == 0 || age_days < aging_days {
+        AgeTier::Fresh
+    } else if age_days < aging_days * 2 {
+        AgeTier::Aging
+    } else {
+        AgeTier::Old
+    }
+}
+
+/// The day a card was last touched, keyed by card id. A card is only parsed again once its
+/// date_modified changes, so drawing the board never re-parses dates that did not move
+#[derive(Debug, Clone, Default)]
+pub struct CardAgeCache {
+    last_touched: HashMap<(u64, u64), (String, Option<NaiveDate>)>,
+}
+
+impl CardAgeCache {
+    /// Whole days since the card was last modified, falling back to when it was created for
+    /// cards with no usable date_modified
+    pub fn age_in_days(&mut self, card: &Card, today: NaiveDate) -> Option<i64> {
+        let cached_date_modified = self
+            .last_touched
+            .get(&card.id)
+            .map(|(date_modified, _)| date_modified);
+        let is_stale = cached_date_modified != Some(&card.date_modified);
+        if is_stale {
+            let last_touched = parse_date_time(&card.date_modified)
+                .or_else(|| parse_date_time(&card.date_created))
+                .map(|date_time| date_time.date());
+            self.last_touched
+                .insert(card.id, (card.date_modified.clone(), last_touched));
+        }
+        let (_, last_touched) = self.last_touched.get(&card.id)?;
+        last_touched.map(|last_touched| today.signed_duration_since(last_touched).num_days())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn card_touched(date_created: &str, date_modified: &str) -> Card {
+        Card {
+            date_created: date_created.to_string(),
+            date_modified: date_modified.to_string(),
+            ..Card::default()
+        }
+    }
+
+    fn date(date: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(date, "%Y/%m/%d").unwrap()
+    }
+
+    #[test]
+    fn age_tiers_start_at_the_threshold_and_double_it() {
+        assert_eq!(age_tier(6, 7), AgeTier::Fresh);
+        assert_eq!(age_tier(7, 7), AgeTier::Aging);
+        assert_eq!(age_tier(13, 7), AgeTier::Aging);
+        assert_eq!(age_tier(14, 7), AgeTier::Old);
+        assert_eq!(age_tier(-3, 7), AgeTier::Fresh);
+    }
+
+    #[test]
+    fn an_aging_threshold_of_zero_keeps_every_card_fresh() {
+        for age_days in [0, 1, 365, 10_000] {
+            assert_eq!(age_tier(age_days, 0), AgeTier::Fresh);
+        }
+    }
+
+    #[test]
+    fn the_age_falls_back_to_the_creation_date() {
+        let mut cache = CardAgeCache::default();
+        let today = date("2024/05/20");
+        let card = card_touched("2024/05/01-09:00:00", "2024/05/10-09:00:00");
+        assert_eq!(cache.age_in_days(&card, today), Some(10));
+        let card = card_touched("2024/05/01-09:00:00", "Not Set");
+        assert_eq!(cache.age_in_days(&card, today), Some(19));
+        let card = card_touched("Not Set", "Not Set");
+        assert_eq!(cache.age_in_days(&card, today), None);
+    }
+
+    #[test]
+    fn the_cache_reparses_only_when_the_card_changes() {
+        let mut cache = CardAgeCache::default();
+        let mut card = card_touched("2024/05/01-09:00:00", "2024/05/10-09:00:00");
+        assert_eq!(cache.age_in_days(&card, date("2024/05/20")), Some(10));
+        // A later day reuses the parsed date
+        assert_eq!(cache.age_in_days(&card, date("2024/05/25")), Some(15));
+        card.date_modified = "2024/05/24-18:00:00".to_string();
+        assert_eq!(cache.age_in_days(&card, date("2024/05/25")), Some(1));
+        assert_eq!(cache.last_touched.len(), 1);
+    }
+}
@@ -0,0 +1,172 @@
+//! A field by field summary of what an edit changed on a card. Long text is reduced to line
+//! counts so the result stays small enough for a popup
+
+use crate::{
+    app::kanban::{format_estimate, Card},
+    constants::FIELD_NOT_SET,
+};
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiffLine {
+    pub field: &'static str,
+    pub change: String,
+}
+
+impl DiffLine {
+    fn new(field: &'static str, change: String) -> Self {
+        Self { field, change }
+    }
+}
+
+impl std::fmt::Display for DiffLine {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}: {}", self.field, self.change)
+    }
+}
+
+pub fn card_diff(old_card: &Card, new_card: &Card) -> Vec<DiffLine> {
+    let mut diff_lines = vec![];
+    if old_card.name != new_card.name {
+        diff_lines.push(DiffLine::new("Name", "changed".to_string()));
+    }
+    if old_card.description != new_card.description {
+        let (added, removed) = count_changed_lines(&old_card.description, &new_card.description);
+        diff_lines.push(DiffLine::new(
+            "Description",
+            format!("+{}/-{} lines", added, removed),
+        ));
+    }
+    if old_card.start_date != new_card.start_date {
+        diff_lines.push(DiffLine::new(
+            "Start date",
+            value_change(
+                old_card.start_date.as_deref().unwrap_or(FIELD_NOT_SET),
+                new_card.start_date.as_deref().unwrap_or(FIELD_NOT_SET),
+            ),
+        ));
+    }
+    if old_card.due_date != new_card.due_date {
+        diff_lines.push(DiffLine::new(
+            "Due date",
+            value_change(&old_card.due_date, &new_card.due_date),
+        ));
+    }
+    if old_card.estimate != new_card.estimate {
+        diff_lines.push(DiffLine::new(
+            "Estimate",
+            value_change(
+                &old_card
+                    .estimate
+                    .map_or(FIELD_NOT_SET.to_string(), format_estimate),
+                &new_card
+                    .estimate
+                    .map_or(FIELD_NOT_SET.to_string(), format_estimate),
+            ),
+        ));
+    }
+    if old_card.estimated_minutes != new_card.estimated_minutes {
+        diff_lines.push(DiffLine::new(
+            "Estimated time (min)",
+            value_change(
+                &old_card
+                    .estimated_minutes
+                    .map_or(FIELD_NOT_SET.to_string(), |minutes| minutes.to_string()),
+                &new_card
+                    .estimated_minutes
+                    .map_or(FIELD_NOT_SET.to_string(), |minutes| minutes.to_string()),
+            ),
+        ));
+    }
+    if old_card.actual_minutes != new_card.actual_minutes {
+        diff_lines.push(DiffLine::new(
+            "Actual time (min)",
+            value_change(
+                &old_card
+                    .actual_minutes
+                    .map_or(FIELD_NOT_SET.to_string(), |minutes| minutes.to_string()),
+                &new_card
+                    .actual_minutes
+                    .map_or(FIELD_NOT_SET.to_string(), |minutes| minutes.to_string()),
+            ),
+        ));
+    }
+    if old_card.get_status_name() != new_card.get_status_name() {
+        diff_lines.push(DiffLine::new(
+            "Status",
+            value_change(&old_card.get_status_name(), &new_card.get_status_name()),
+        ));
+    }
+    if old_card.priority != new_card.priority {
+        diff_lines.push(DiffLine::new(
+            "Priority",
+            value_change(
+                &old_card.priority.to_string(),
+                &new_card.priority.to_string(),
+            ),
+        ));
+    }
+    if old_card.tags != new_card.tags {
+        diff_lines.push(DiffLine::new(
+            "Tags",
+            list_change(&old_card.tags, &new_card.tags),
+        ));
+    }
+    if old_card.comments != new_card.comments {
+        diff_lines.push(DiffLine::new(
+            "Comments",
+            list_change(&old_card.comments, &new_card.comments),
+        ));
+    }
+    if old_card.highlight_color != new_card.highlight_color {
+        diff_lines.push(DiffLine::new("Highlight", "changed".to_string()));
+    }
+    if old_card.waiting_on != new_card.waiting_on {
+        diff_lines.push(DiffLine::new(
+            "Waiting on",
+            value_change(
+                old_card.waiting_on.as_deref().unwrap_or(FIELD_NOT_SET),
+                new_card.waiting_on.as_deref().unwrap_or(FIELD_NOT_SET),
+            ),
+        ));
+    }
+    diff_lines
+}
+
+fn value_change(old_value: &str, new_value: &str) -> String {
+    format!("{} → {}", old_value, new_value)
+}
+
+/// Order is ignored, a line that only moved counts as neither added nor removed
+fn count_changed_lines(old_text: &str, new_text: &str) -> (usize, usize) {
+    let mut line_counts: HashMap<&str, isize> = HashMap::new();
+    for line in old_text.lines() {
+        *line_counts.entry(line).or_default() -= 1;
+    }
+    for line in new_text.lines() {
+        *line_counts.entry(line).or_default() += 1;
+    }
+    line_counts
+        .values()
+        .fold((0, 0), |(added, removed), count| match count {
+            count if *count > 0 => (added + count.unsigned_abs(), removed),
+            count => (added, removed + count.unsigned_abs()),
+        })
+}
+
+fn list_change(old_items: &[String], new_items: &[String]) -> String {
+    let added = new_items
+        .iter()
+        .filter(|item| !old_items.contains(item))
+        .count();
+    let removed = old_items
+        .iter()
+        .filter(|item| !new_items.contains(item))
+        .count();
+    match (added, removed) {
+        (0, 0) => "reordered".to_string(),
+        (added, 0) => format!("{} added", added),
+        (0, removed) => format!("{} removed", removed),
+        (added, removed) => format!("{} added, {} removed", added, removed),
+    }
+}
@@ -0,0 +1,249 @@
+//! Every filter that can narrow the kanban view in one place, so the view, the tag filter, card
+//! search and the exports that only write what is shown all pick the same cards
+
+use crate::{
+    app::kanban::{Board, Card, CardPriority, CardStatus, Cards},
+    model::{date_range_filter::DateRangeFilter, date_time_format::DateTimeFormat},
+    ui::widgets::command_palette::CommandPaletteWidget,
+};
+use chrono::NaiveDateTime;
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct CardFilter {
+    /// Lowercase tags, a card needs at least one of them. Empty does not filter by tag
+    pub tags: Vec<String>,
+    /// Fuzzy matched against the card name and description
+    pub search: Option<String>,
+    pub statuses: Option<Vec<CardStatus>>,
+    pub priorities: Option<Vec<CardPriority>>,
+    pub date_range: Option<DateRangeFilter>,
+    /// Only the board with this name, compared ignoring case
+    pub board_name: Option<String>,
+}
+
+impl CardFilter {
+    pub fn is_empty(&self) -> bool {
+        !self.narrows_cards() && self.board_name.is_none()
+    }
+
+    fn narrows_cards(&self) -> bool {
+        !self.tags.is_empty()
+            || self.search.is_some()
+            || self.statuses.is_some()
+            || self.priorities.is_some()
+            || self.date_range.is_some()
+    }
+
+    pub fn matches_board(&self, board: &Board) -> bool {
+        match &self.board_name {
+            Some(board_name) => board.name.eq_ignore_ascii_case(board_name.trim()),
+            None => true,
+        }
+    }
+
+    /// Whether the card passes every criterion, `now` is only used by the due date range
+    pub fn matches(
+        &self,
+        card: &Card,
+        date_time_format: DateTimeFormat,
+        now: NaiveDateTime,
+    ) -> bool {
+        let tags_match = self.tags.is_empty()
+            || card
+                .tags
+                .iter()
+                .any(|tag| self.tags.contains(&tag.to_lowercase()));
+        let search_matches = match &self.search {
+            Some(query) => {
+                CommandPaletteWidget::fuzzy_match_score(query, &card.name).is_some()
+                    || CommandPaletteWidget::fuzzy_match_score(query, &card.description).is_some()
+                    || card.waiting_on.as_ref().is_some_and(|waiting_on| {
+                        CommandPaletteWidget::fuzzy_match_score(query, waiting_on).is_some()
+                    })
+            }
+            None => true,
+        };
+        let status_matches = match &self.statuses {
+            Some(statuses) => statuses.contains(&card.card_status),
+            None => true,
+        };
+        let priority_matches = match &self.priorities {
+            Some(priorities) => priorities.contains(&card.priority),
+            None => true,
+        };
+        let due_date_matches = match &self.date_range {
+            Some(date_range) => DateRangeFilter::parse_due_date(&card.due_date, date_time_format)
+                .is_some_and(|due| date_range.contains(due, now)),
+            None => true,
+        };
+        tags_match && search_matches && status_matches && priority_matches && due_date_matches
+    }
+
+    /// Copies of the boards holding only the matching cards. A board without a matching card
+    /// is dropped, unless the filter only picks a board
+    pub fn apply(
+        &self,
+        boards: &[Board],
+        date_time_format: DateTimeFormat,
+        now: NaiveDateTime,
+    ) -> Vec<Board> {
+        let mut filtered_boards = Vec::new();
+        for board in boards.iter().filter(|board| self.matches_board(board)) {
+            let filtered_cards = board
+                .cards
+                .get_all_cards()
+                .iter()
+                .filter(|card| self.matches(card, date_time_format, now))
+                .cloned()
+                .collect::<Vec<Card>>();
+            if filtered_cards.is_empty() && self.narrows_cards() {
+                continue;
+            }
+            filtered_boards.push(Board {
+                id: board.id,
+                name: board.name.clone(),
+                description: board.description.clone(),
+                cards: Cards::from(filtered_cards),
+                notes: board.notes.clone(),
+                archived: board.archived,
+                auto_sort_by_priority: board.auto_sort_by_priority,
+                statuses: board.statuses.clone(),
+                accent_color: board.accent_color,
+            });
+        }
+        filtered_boards
+    }
+
+    /// Human readable description of every criterion, empty when nothing is filtered
+    pub fn describe(&self, date_time_format: DateTimeFormat) -> Vec<String> {
+        let mut criteria = vec![];
+        if let Some(board_name) = &self.board_name {
+            criteria.push(format!("board {}", board_name));
+        }
+        if !self.tags.is_empty() {
+            criteria.push(format!("tags {}", self.tags.join(", ")));
+        }
+        if let Some(query) = &self.search {
+            criteria.push(format!("search \"{}\"", query));
+        }
+        if let Some(statuses) = &self.statuses {
+            let statuses = statuses
+                .iter()
+                .map(|status| status.to_string())
+                .collect::<Vec<String>>();
+            criteria.push(format!("status {}", statuses.join(", ")));
+        }
+        if let Some(priorities) = &self.priorities {
+            let priorities = priorities
+                .iter()
+                .map(|priority| priority.to_string())
+                .collect::<Vec<String>>();
+            criteria.push(format!("priority {}", priorities.join(", ")));
+        }
+        if let Some(date_range) = &self.date_range {
+            criteria.push(date_range.describe(date_time_format));
+        }
+        criteria
+    }
+
+    /// Written at the top of an export made with this filter so the reader knows cards were
+    /// left out, None when nothing is filtered
+    pub fn export_note(&self, date_time_format: DateTimeFormat) -> Option<String> {
+        if self.is_empty() {
+            return None;
+        }
+        Some(format!(
+            "Only cards matching {}, other cards are left out",
+            self.describe(date_time_format).join("; ")
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::markdown_export::boards_to_markdown;
+    use chrono::NaiveDate;
+    use serde_json::{json, Value};
+
+    fn card(id: u64, name: &str, priority: &str, tags: &[&str]) -> Value {
+        json!({
+            "id": [id, 1], "name": name, "description": "", "date_created": "",
+            "date_modified": "", "due_date": "", "date_completed": "", "priority": priority,
+            "card_status": "Active", "tags": tags, "comments": [],
+        })
+    }
+
+    fn board(id: u64, name: &str, cards: Vec<Value>) -> Board {
+        let mut board =
+            Board::from_json(&json!({ "name": name, "description": "", "cards": cards })).unwrap();
+        board.id = (id, 0);
+        board
+    }
+
+    fn client_boards() -> Vec<Board> {
+        vec![
+            board(
+                1,
+                "Client X",
+                vec![
+                    card(1, "Invoice", "High", &["client-x"]),
+                    card(2, "Kickoff", "Low", &["Client-X", "meeting"]),
+                    card(3, "Lunch", "High", &["personal"]),
+                ],
+            ),
+            board(2, "Internal", vec![card(4, "Retro", "Low", &["meeting"])]),
+        ]
+    }
+
+    fn now() -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2024, 5, 15)
+            .unwrap()
+            .and_hms_opt(12, 0, 0)
+            .unwrap()
+    }
+
+    #[test]
+    fn the_export_holds_exactly_the_cards_the_filter_shows() {
+        let boards = client_boards();
+        let format = DateTimeFormat::default();
+        let card_filter = CardFilter {
+            tags: vec!["client-x".to_string()],
+            priorities: Some(vec![CardPriority::High, CardPriority::Low]),
+            ..Default::default()
+        };
+        // Boards left without a matching card are dropped, like in the filtered view
+        let filtered_boards = card_filter.apply(&boards, format, now());
+        assert_eq!(filtered_boards.len(), 1);
+
+        let shown_cards = boards
+            .iter()
+            .flat_map(|board| board.cards.get_all_cards())
+            .filter(|card| card_filter.matches(card, format, now()))
+            .map(|card| card.name.clone())
+            .collect::<Vec<String>>();
+        assert_eq!(shown_cards, ["Invoice", "Kickoff"]);
+        let export_note = card_filter.export_note(format);
+        let markdown = boards_to_markdown(&filtered_boards, false, export_note.as_deref());
+        let exported_cards = markdown
+            .lines()
+            .filter_map(|line| line.strip_prefix("### "))
+            .collect::<Vec<&str>>();
+        assert_eq!(exported_cards, shown_cards);
+        assert!(markdown.contains("tags client-x; priority High, Low"));
+    }
+
+    #[test]
+    fn a_board_filter_alone_keeps_the_board_even_when_empty() {
+        let boards = vec![board(2, "Internal", vec![])];
+        let format = DateTimeFormat::default();
+        let board_only = CardFilter {
+            board_name: Some("internal".to_string()),
+            ..Default::default()
+        };
+        let filtered_boards = board_only.apply(&boards, format, now());
+        assert_eq!(filtered_boards.len(), 1);
+        assert_eq!(filtered_boards[0].name, "Internal");
+        assert!(CardFilter::default().export_note(format).is_none());
+    }
+}
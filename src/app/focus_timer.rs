@@ -0,0 +1,81 @@
+//! A pomodoro style countdown on one card. It only lives for the session and is measured
+//! against the wall clock, so time spent suspended still counts down
+
+use chrono::{DateTime, Duration, Local};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FocusTimerState {
+    Running { ends_at: DateTime<Local> },
+    Paused { remaining: Duration },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FocusTimer {
+    pub board_id: (u64, u64),
+    pub card_id: (u64, u64),
+    pub card_name: String,
+    state: FocusTimerState,
+}
+
+impl FocusTimer {
+    pub fn start(
+        board_id: (u64, u64),
+        card_id: (u64, u64),
+        card_name: String,
+        length: Duration,
+        now: DateTime<Local>,
+    ) -> Self {
+        Self {
+            board_id,
+            card_id,
+            card_name,
+            state: FocusTimerState::Running {
+                ends_at: now + length,
+            },
+        }
+    }
+
+    pub fn is_paused(&self) -> bool {
+        matches!(self.state, FocusTimerState::Paused { .. })
+    }
+
+    pub fn remaining(&self, now: DateTime<Local>) -> Duration {
+        match self.state {
+            FocusTimerState::Running { ends_at } => (ends_at - now).max(Duration::zero()),
+            FocusTimerState::Paused { remaining } => remaining,
+        }
+    }
+
+    pub fn has_expired(&self, now: DateTime<Local>) -> bool {
+        match self.state {
+            FocusTimerState::Running { ends_at } => now >= ends_at,
+            FocusTimerState::Paused { .. } => false,
+        }
+    }
+
+    pub fn pause(&mut self, now: DateTime<Local>) {
+        if !self.is_paused() {
+            self.state = FocusTimerState::Paused {
+                remaining: self.remaining(now),
+            };
+        }
+    }
+
+    pub fn resume(&mut self, now: DateTime<Local>) {
+        if let FocusTimerState::Paused { remaining } = self.state {
+            self.state = FocusTimerState::Running {
+                ends_at: now + remaining,
+            };
+        }
+    }
+
+    /// "mm:ss" of the time left, rounded up so the last second reads 00:01 and not 00:00
+    pub fn remaining_as_string(&self, now: DateTime<Local>) -> String {
+        let remaining = self.remaining(now);
+        let mut seconds = remaining.num_seconds();
+        if remaining > Duration::seconds(seconds) {
+            seconds += 1;
+        }
+        format!("{:02}:{:02}", seconds / 60, seconds % 60)
+    }
+}
@@ -0,0 +1,252 @@
+//! What happens to the text typed into the new board and new card forms when they are left
+//! without submitting. The text is taken out of the shared buffers as soon as the form is
+//! left, so it can never turn up in another form, and waits there until it is kept as a
+//! draft or discarded. A kept draft is put back the next time its form is opened, for a new
+//! card only when it is opened on the same board
+
+use crate::{
+    app::state::TextBuffers,
+    ui::{text_box::TextBox, View},
+};
+
+/// Text typed into a form that was left without submitting
+#[derive(Debug, Clone, PartialEq)]
+pub struct FormDraft {
+    pub view: View,
+    /// The board a new card was being added to, None for a new board
+    pub board_id: Option<(u64, u64)>,
+    pub name: String,
+    pub description: String,
+}
+
+/// The kept drafts, one per form, and the one waiting to be kept or discarded
+#[derive(Debug, Clone, Default)]
+pub struct FormDrafts {
+    new_board: Option<FormDraft>,
+    new_card: Option<FormDraft>,
+    pending: Option<FormDraft>,
+    /// The form currently showing a draft that was put back into it
+    restored: Option<View>,
+    /// The board the open new card form adds to, the current board can change before leaving
+    board_id: Option<(u64, u64)>,
+}
+
+/// The views whose text is kept as a draft when they are left
+pub fn is_form_with_drafts(view: View) -> bool {
+    matches!(view, View::NewBoard | View::NewCard)
+}
+
+/// Email, password and reset link fields are cleared on every view change instead
+pub fn clear_account_fields(buffers: &mut TextBuffers) {
+    buffers.email_id.reset();
+    buffers.password.reset();
+    buffers.confirm_password.reset();
+    buffers.reset_password_link.reset();
+}
+
+impl FormDrafts {
+    /// Takes whatever was typed into the form out of the buffers, it waits in
+    /// [`FormDrafts::pending`] when there was anything. A draft still waiting from before is
+    /// kept rather than lost
+    pub fn leave(&mut self, view: View, buffers: &mut TextBuffers) {
+        if self.restored == Some(view) {
+            self.restored = None;
+        }
+        if let Some(draft) = take_from_buffers(view, self.board_id.take(), buffers) {
+            self.keep_pending();
+            self.pending = Some(draft);
+        }
+    }
+
+    /// Puts the kept draft of the form back into the buffers. Anything already in them was not
+    /// typed into this form, since leaving it empties them, so it is dropped first. Returns
+    /// true when there was such text
+    pub fn enter(
+        &mut self,
+        view: View,
+        board_id: Option<(u64, u64)>,
+        buffers: &mut TextBuffers,
+    ) -> bool {
+        let had_stale_text = take_from_buffers(view, board_id, buffers).is_some();
+        self.board_id = board_id;
+        let slot = match view {
+            View::NewBoard => &mut self.new_board,
+            View::NewCard => &mut self.new_card,
+            _ => return had_stale_text,
+        };
+        if let Some(draft) = slot.take_if(|draft| draft.board_id == board_id) {
+            put_into_buffers(&draft, buffers);
+            self.restored = Some(view);
+        }
+        had_stale_text
+    }
+
+    /// The draft waiting to be kept or discarded
+    pub fn pending(&self) -> Option<&FormDraft> {
+        self.pending.as_ref()
+    }
+
+    /// Keeps the waiting draft for the next time its form is opened, replacing the older draft
+    /// of that form
+    pub fn keep_pending(&mut self) -> Option<&FormDraft> {
+        let draft = self.pending.take()?;
+        let slot = match draft.view {
+            View::NewBoard => &mut self.new_board,
+            _ => &mut self.new_card,
+        };
+        Some(slot.insert(draft))
+    }
+
+    pub fn discard_pending(&mut self) -> Option<FormDraft> {
+        self.pending.take()
+    }
+
+    /// The form is showing a draft that was put back into it
+    pub fn is_restored(&self, view: View) -> bool {
+        self.restored == Some(view)
+    }
+
+    /// For forms cleared without being left, like after a rapid entry submit
+    pub fn clear_restored(&mut self) {
+        self.restored = None;
+    }
+}
+
+fn take_from_buffers(
+    view: View,
+    board_id: Option<(u64, u64)>,
+    buffers: &mut TextBuffers,
+) -> Option<FormDraft> {
+    let (name_buffer, description_buffer) = match view {
+        View::NewBoard => (&mut buffers.board_name, &mut buffers.board_description),
+        View::NewCard => (&mut buffers.card_name, &mut buffers.card_description),
+        _ => return None,
+    };
+    let name = name_buffer.get_joined_lines();
+    let description = description_buffer.get_joined_lines();
+    name_buffer.reset();
+    description_buffer.reset();
+    if name.trim().is_empty() && description.trim().is_empty() {
+        return None;
+    }
+    Some(FormDraft {
+        view,
+        board_id: board_id.filter(|_| view == View::NewCard),
+        name,
+        description,
+    })
+}
+
+fn put_into_buffers(draft: &FormDraft, buffers: &mut TextBuffers) {
+    let name = TextBox::from_string_with_newline_sep(draft.name.clone(), true);
+    let description = TextBox::from_string_with_newline_sep(draft.description.clone(), false);
+    match draft.view {
+        View::NewBoard => {
+            buffers.board_name = name;
+            buffers.board_description = description;
+        }
+        _ => {
+            buffers.card_name = name;
+            buffers.card_description = description;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BOARD: Option<(u64, u64)> = Some((1, 0));
+    const OTHER_BOARD: Option<(u64, u64)> = Some((2, 0));
+
+    fn typed_and_left(drafts: &mut FormDrafts, buffers: &mut TextBuffers, card_name: &str) {
+        drafts.enter(View::NewCard, BOARD, buffers);
+        buffers.card_name.insert_str(card_name);
+        drafts.leave(View::NewCard, buffers);
+    }
+
+    #[test]
+    fn leaving_an_untouched_form_leaves_nothing_behind() {
+        let mut buffers = TextBuffers::default();
+        let mut drafts = FormDrafts::default();
+        drafts.enter(View::NewCard, BOARD, &mut buffers);
+        drafts.leave(View::NewCard, &mut buffers);
+        assert!(drafts.pending().is_none());
+    }
+
+    #[test]
+    fn leaving_mid_entry_clears_the_form_until_the_draft_is_kept_or_discarded() {
+        let mut buffers = TextBuffers::default();
+        let mut drafts = FormDrafts::default();
+        drafts.enter(View::NewCard, BOARD, &mut buffers);
+        buffers.card_name.insert_str("Fix login");
+        buffers.card_description.insert_str("Half written");
+        drafts.leave(View::NewCard, &mut buffers);
+        let pending = drafts.pending().unwrap();
+        assert_eq!(pending.name, "Fix login");
+        assert_eq!(pending.description, "Half written");
+        assert_eq!(pending.board_id, BOARD);
+        assert_eq!(buffers.card_name.get_joined_lines(), "");
+
+        drafts.discard_pending();
+        assert!(!drafts.enter(View::NewCard, BOARD, &mut buffers));
+        assert_eq!(buffers.card_name.get_joined_lines(), "");
+        assert!(!drafts.is_restored(View::NewCard));
+    }
+
+    #[test]
+    fn a_kept_draft_only_comes_back_in_the_same_form_on_the_same_board() {
+        let mut buffers = TextBuffers::default();
+        let mut drafts = FormDrafts::default();
+        typed_and_left(&mut drafts, &mut buffers, "Fix login");
+        drafts.keep_pending();
+
+        drafts.enter(View::NewBoard, None, &mut buffers);
+        assert_eq!(buffers.board_name.get_joined_lines(), "");
+        drafts.leave(View::NewBoard, &mut buffers);
+        drafts.enter(View::NewCard, OTHER_BOARD, &mut buffers);
+        assert_eq!(buffers.card_name.get_joined_lines(), "");
+        drafts.leave(View::NewCard, &mut buffers);
+
+        drafts.enter(View::NewCard, BOARD, &mut buffers);
+        assert_eq!(buffers.card_name.get_joined_lines(), "Fix login");
+        assert!(drafts.is_restored(View::NewCard));
+    }
+
+    #[test]
+    fn a_submitted_form_has_nothing_left_to_keep() {
+        let mut buffers = TextBuffers::default();
+        let mut drafts = FormDrafts::default();
+        typed_and_left(&mut drafts, &mut buffers, "Fix login");
+        drafts.keep_pending();
+        drafts.enter(View::NewCard, BOARD, &mut buffers);
+
+        // Submitting clears the buffers before the form is left
+        buffers.card_name.reset();
+        drafts.leave(View::NewCard, &mut buffers);
+        assert!(drafts.pending().is_none());
+        assert!(!drafts.is_restored(View::NewCard));
+        drafts.enter(View::NewCard, BOARD, &mut buffers);
+        assert_eq!(buffers.card_name.get_joined_lines(), "");
+    }
+
+    #[test]
+    fn text_left_in_the_buffers_by_another_view_is_cleared_on_entry() {
+        let mut buffers = TextBuffers::default();
+        let mut drafts = FormDrafts::default();
+        buffers.board_name.insert_str("Roadmap");
+        assert!(drafts.enter(View::NewBoard, None, &mut buffers));
+        assert_eq!(buffers.board_name.get_joined_lines(), "");
+        assert!(!drafts.is_restored(View::NewBoard));
+    }
+
+    #[test]
+    fn account_fields_are_cleared() {
+        let mut buffers = TextBuffers::default();
+        buffers.email_id.insert_str("me@example.com");
+        buffers.password.insert_str("hunter2");
+        clear_account_fields(&mut buffers);
+        assert_eq!(buffers.email_id.get_joined_lines(), "");
+        assert_eq!(buffers.password.get_joined_lines(), "");
+    }
+}
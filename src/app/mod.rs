@@ -2,28 +2,51 @@ use crate::{
     app::{
         actions::Action,
         app_helper::{
-            handle_edit_keybinding_mode, handle_general_actions, handle_mouse_action,
+            handle_edit_keybinding_mode, handle_general_actions, handle_mouse_action, handle_paste,
             handle_user_input_mode, prepare_config_for_new_app,
+            refresh_visible_cards_for_all_boards, reveal_created_item, show_board,
         },
-        kanban::{Board, Boards, Card, CardPriority, CardStatus},
-        state::{AppStatus, Focus, KeyBindingEnum, KeyBindings},
+        calendar::cards_due_on,
+        card_filter::CardFilter,
+        kanban::{
+            Board, Boards, Card, CardPriority, CardSortOrder, CardStatus, Cards, StatusSelection,
+        },
+        state::{AppStatus, DueDateReminder, Focus, KeyBindingEnum, KeyBindings, PathCheckState},
+        swimlane::SwimlaneGrid,
+        visibility::nearest_first,
+        waiting_on::WaitingOnGroups,
     },
     constants::{
-        DEFAULT_CARD_WARNING_DUE_DATE_DAYS, DEFAULT_NO_OF_BOARDS_PER_PAGE,
-        DEFAULT_NO_OF_CARDS_PER_BOARD, DEFAULT_TICKRATE, DEFAULT_TOAST_DURATION, DEFAULT_VIEW,
-        FIELD_NA, IO_EVENT_WAIT_TIME, MAX_NO_BOARDS_PER_PAGE, MAX_NO_CARDS_PER_BOARD, MAX_TICKRATE,
-        MAX_WARNING_DUE_DATE_DAYS, MIN_NO_BOARDS_PER_PAGE, MIN_NO_CARDS_PER_BOARD, MIN_TICKRATE,
+        CARD_MOVE_MERGE_WINDOW, DEFAULT_AUTO_ARCHIVE_COMPLETED_DAYS, DEFAULT_CARD_AGING_DAYS,
+        DEFAULT_CARD_PREVIEW_LINES, DEFAULT_CARD_WARNING_DUE_DATE_DAYS, DEFAULT_LANGUAGE,
+        DEFAULT_NO_OF_BOARDS_PER_PAGE, DEFAULT_NO_OF_CARDS_PER_BOARD, DEFAULT_SAVE_FILES_TO_KEEP,
+        DEFAULT_SECONDARY_PANE_HEIGHT, DEFAULT_TICKRATE, DEFAULT_TOAST_DURATION, DEFAULT_VIEW,
+        DUE_DATE_REMINDER_CHECK_INTERVAL, FIELD_NA, HIGH_CONTRAST_THEME_NAME,
+        MAX_AUTO_ARCHIVE_COMPLETED_DAYS, MAX_CARD_AGING_DAYS, MAX_CARD_PREVIEW_LINES,
+        MAX_NO_BOARDS_PER_PAGE, MAX_NO_CARDS_PER_BOARD, MAX_SAVE_FILES_TO_KEEP, MAX_TICKRATE,
+        MAX_WARNING_DUE_DATE_DAYS, MIN_AUTO_ARCHIVE_COMPLETED_DAYS, MIN_AUTO_SAVE_INTERVAL_SECONDS,
+        MIN_CARD_AGING_DAYS, MIN_CARD_PREVIEW_LINES, MIN_NO_BOARDS_PER_PAGE,
+        MIN_NO_CARDS_PER_BOARD, MIN_SAVE_FILES_TO_KEEP, MIN_SECONDARY_PANE_HEIGHT, MIN_TICKRATE,
         MIN_WARNING_DUE_DATE_DAYS,
     },
+    i18n::{load_language, tr, tr_args, MessageId},
     inputs::{key::Key, mouse::Mouse},
     io::{
         data_handler::{self, get_available_local_save_files, get_default_save_directory},
-        io_handler::refresh_visible_boards_and_cards,
-        logger::{get_logs, RUST_KANBAN_LOGGER},
-        IoEvent,
+        desktop_notification::send_desktop_notification,
+        event_log::{BoardEvent, BoardEventOrigin},
+        io_handler::{get_shown_board_ids, refresh_visible_boards_and_cards},
+        logger::{
+            get_logs, log_filters_to_string, parse_log_filters, set_log_filters,
+            RUST_KANBAN_LOGGER, SELECTABLE_LOG_LEVELS,
+        },
+        IoCompletion, IoCompletionKind, IoEvent, IoOutcome,
     },
+    model::date_range_filter::DateRangeFilter,
     ui::{
-        text_box::TextBox,
+        color_depth::{detect_terminal_color_depth, ColorDepth},
+        rendering::common::get_card_days_left,
+        text_box::{set_unicode_normalization, TextBox},
         theme::Theme,
         widgets::{
             date_time_picker::CalenderType,
@@ -34,7 +57,7 @@ use crate::{
     },
 };
 use linked_hash_map::LinkedHashMap;
-use log::{debug, error, warn};
+use log::{debug, error, info, warn};
 use ratatui::widgets::TableState;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
@@ -51,9 +74,20 @@ use strum::{EnumString, IntoEnumIterator};
 use strum_macros::EnumIter;
 
 pub mod actions;
+pub mod analytics;
 pub mod app_helper;
-pub mod kanban;
+pub mod calendar;
+pub mod card_aging;
+pub mod card_diff;
+pub mod card_filter;
+pub mod focus_timer;
+pub mod form_drafts;
 pub mod state;
+pub mod swimlane;
+pub mod visibility;
+pub mod waiting_on;
+
+pub use crate::model::{date_time_format::DateTimeFormat, kanban};
 
 #[derive(Debug, PartialEq, Eq)]
 pub enum AppReturn {
@@ -61,7 +95,9 @@ pub enum AppReturn {
     Continue,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+// Edits hold both card snapshots, history is small enough that boxing them is not worth it
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum ActionHistory {
     /// card, board_id
     DeleteCard(Card, (u64, u64)),
@@ -77,25 +113,225 @@ pub enum ActionHistory {
     CreateBoard(Board),
     /// old_card, new_card, board_id
     EditCard(Card, Card, (u64, u64)),
+    /// old_board, new_board, only the name and description are restored so cards changed since
+    /// are kept
+    EditBoard(Board, Board),
+    /// board_id, card ids in the order before the sort, card ids in the sorted order
+    SortCards((u64, u64), Vec<(u64, u64)>, Vec<(u64, u64)>),
+    /// moved_from_index, moved_to_index, the two boards are swapped
+    MoveBoard(usize, usize),
+    /// actions applied together, undone in reverse order
+    Batch(Vec<ActionHistory>),
+}
+
+/// board_id, card_id of a board or card an action adds
+type CreatedItem = ((u64, u64), Option<(u64, u64)>);
+
+impl ActionHistory {
+    /// The action that reverts this one
+    pub fn inverted(&self) -> ActionHistory {
+        match self {
+            ActionHistory::DeleteCard(card, board_id) => {
+                ActionHistory::CreateCard(card.clone(), *board_id)
+            }
+            ActionHistory::CreateCard(card, board_id) => {
+                ActionHistory::DeleteCard(card.clone(), *board_id)
+            }
+            ActionHistory::DeleteBoard(board) => ActionHistory::CreateBoard(board.clone()),
+            ActionHistory::CreateBoard(board) => ActionHistory::DeleteBoard(board.clone()),
+            ActionHistory::MoveCardBetweenBoards(
+                card,
+                moved_from_board_id,
+                moved_to_board_id,
+                moved_from_index,
+                moved_to_index,
+            ) => ActionHistory::MoveCardBetweenBoards(
+                card.clone(),
+                *moved_to_board_id,
+                *moved_from_board_id,
+                *moved_to_index,
+                *moved_from_index,
+            ),
+            ActionHistory::MoveCardWithinBoard(board_id, moved_from_index, moved_to_index) => {
+                ActionHistory::MoveCardWithinBoard(*board_id, *moved_to_index, *moved_from_index)
+            }
+            ActionHistory::EditCard(old_card, new_card, board_id) => {
+                ActionHistory::EditCard(new_card.clone(), old_card.clone(), *board_id)
+            }
+            ActionHistory::EditBoard(old_board, new_board) => {
+                ActionHistory::EditBoard(new_board.clone(), old_board.clone())
+            }
+            ActionHistory::SortCards(board_id, original_card_ids, sorted_card_ids) => {
+                ActionHistory::SortCards(
+                    *board_id,
+                    sorted_card_ids.clone(),
+                    original_card_ids.clone(),
+                )
+            }
+            ActionHistory::MoveBoard(moved_from_index, moved_to_index) => {
+                ActionHistory::MoveBoard(*moved_to_index, *moved_from_index)
+            }
+            ActionHistory::Batch(actions) => ActionHistory::Batch(
+                actions
+                    .iter()
+                    .rev()
+                    .map(|action| action.inverted())
+                    .collect(),
+            ),
+        }
+    }
+    /// The board and card the action adds, so they can be selected once it is applied
+    pub fn created_item(&self) -> Option<CreatedItem> {
+        match self {
+            ActionHistory::CreateCard(card, board_id) => Some((*board_id, Some(card.id))),
+            ActionHistory::CreateBoard(board) => Some((board.id, None)),
+            _ => None,
+        }
+    }
+    /// A card moved by `self` and then on by `next` as one move from where it started to where
+    /// it ended up, None when `next` does not pick up the same card where `self` left it
+    ///
+    /// ```
+    /// use rust_kanban::app::{kanban::Card, ActionHistory};
+    ///
+    /// let card = Card::default();
+    /// let first_step = ActionHistory::MoveCardBetweenBoards(card.clone(), (1, 1), (2, 2), 3, 0);
+    /// let second_step = ActionHistory::MoveCardBetweenBoards(card.clone(), (2, 2), (3, 3), 0, 5);
+    /// assert_eq!(
+    ///     first_step.merged_card_move(&second_step),
+    ///     Some(ActionHistory::MoveCardBetweenBoards(card.clone(), (1, 1), (3, 3), 3, 5))
+    /// );
+    ///
+    /// let back_again = ActionHistory::MoveCardBetweenBoards(card, (1, 1), (2, 2), 3, 0);
+    /// assert_eq!(second_step.merged_card_move(&back_again), None);
+    /// ```
+    pub fn merged_card_move(&self, next: &ActionHistory) -> Option<ActionHistory> {
+        match (self, next) {
+            (
+                ActionHistory::MoveCardBetweenBoards(
+                    card,
+                    moved_from_board_id,
+                    moved_to_board_id,
+                    moved_from_index,
+                    _,
+                ),
+                ActionHistory::MoveCardBetweenBoards(
+                    next_card,
+                    next_moved_from_board_id,
+                    next_moved_to_board_id,
+                    _,
+                    next_moved_to_index,
+                ),
+            ) if card.id == next_card.id && moved_to_board_id == next_moved_from_board_id => {
+                Some(ActionHistory::MoveCardBetweenBoards(
+                    card.clone(),
+                    *moved_from_board_id,
+                    *next_moved_to_board_id,
+                    *moved_from_index,
+                    *next_moved_to_index,
+                ))
+            }
+            _ => None,
+        }
+    }
 }
 
 #[derive(Default)]
 pub struct ActionHistoryManager {
     pub history: Vec<ActionHistory>,
     pub history_index: usize,
+    /// Events for the event log, drained after every key or mouse action
+    pub pending_events: Vec<BoardEvent>,
+    next_action_id: u64,
+    /// Id of the newest action while it is still the one an undo would revert
+    pub latest_action_id: Option<u64>,
+    /// When the newest card move step was recorded, see [`ActionHistoryManager::new_card_move_step`]
+    last_card_move_step_time: Option<Instant>,
+    /// The history index the boards were last saved at, None when no save happened since the
+    /// history started or the saved state was cut off by a new action
+    save_point: Option<usize>,
+    /// Set by every recorded change and cleared by a load or save, see [`App::has_unsaved_changes`]
+    boards_changed: bool,
 }
 
 impl ActionHistoryManager {
     pub fn new_action(&mut self, action: ActionHistory) {
         if self.history_index != self.history.len() {
             self.history.truncate(self.history_index);
+            if self
+                .save_point
+                .is_some_and(|save_point| save_point > self.history_index)
+            {
+                self.save_point = None;
+            }
         }
+        self.pending_events.push(BoardEvent::from_action_history(
+            &action,
+            BoardEventOrigin::Action,
+        ));
         self.history.push(action);
         self.history_index += 1;
+        self.latest_action_id = Some(self.next_action_id);
+        self.next_action_id += 1;
+        self.last_card_move_step_time = None;
+        self.boards_changed = true;
+    }
+    /// Records a single board step of a card. A step following the previous step of the same
+    /// card within CARD_MOVE_MERGE_WINDOW extends it, so a card walked across several boards is
+    /// undone in one go
+    pub fn new_card_move_step(&mut self, action: ActionHistory) {
+        let step_time = Instant::now();
+        let merged_action = self
+            .last_card_move_step_time
+            .filter(|last_step_time| {
+                step_time.duration_since(*last_step_time)
+                    < Duration::from_millis(CARD_MOVE_MERGE_WINDOW)
+            })
+            // Nothing was undone or recorded in between
+            .filter(|_| self.latest_action_id.is_some())
+            // Merging into the action the boards were saved after would move the save point
+            .filter(|_| self.save_point != Some(self.history_index))
+            .and_then(|_| self.history.last())
+            .and_then(|last_action| last_action.merged_card_move(&action));
+        match merged_action {
+            Some(merged_action) => {
+                self.pending_events.push(BoardEvent::from_action_history(
+                    &action,
+                    BoardEventOrigin::Action,
+                ));
+                if let Some(last_action) = self.history.last_mut() {
+                    *last_action = merged_action;
+                }
+                self.boards_changed = true;
+            }
+            None => self.new_action(action),
+        }
+        self.last_card_move_step_time = Some(step_time);
     }
     pub fn reset(&mut self) {
         self.history.clear();
         self.history_index = 0;
+        self.latest_action_id = None;
+        self.save_point = None;
+    }
+    /// Records that the boards as they are now were saved
+    pub fn mark_save_point(&mut self) {
+        self.save_point = Some(self.history_index);
+        self.boards_changed = false;
+    }
+    /// After an undo or redo the boards only match the save when it led back to the save point
+    fn update_boards_changed_after_history_move(&mut self) {
+        self.boards_changed = self.undo_steps_to_save_point() != Some(0);
+    }
+    /// How many undos lead back to the last save, None when it can not be reached by undoing
+    pub fn undo_steps_to_save_point(&self) -> Option<usize> {
+        self.save_point
+            .filter(|save_point| *save_point <= self.history_index)
+            .map(|save_point| self.history_index - save_point)
+    }
+    /// How many actions there are to redo
+    pub fn redo_steps(&self) -> usize {
+        self.history.len() - self.history_index
     }
 }
 
@@ -110,7 +346,6 @@ pub struct App<'a> {
     pub preview_boards_and_cards: Option<Boards>,
     pub config: AppConfig,
     pub visible_boards_and_cards: LinkedHashMap<(u64, u64), Vec<(u64, u64)>>,
-    pub last_io_event_time: Option<Instant>,
     pub all_themes: Vec<Theme>,
     pub current_theme: Theme,
     pub action_history_manager: ActionHistoryManager,
@@ -120,26 +355,48 @@ pub struct App<'a> {
 
 impl App<'_> {
     pub fn new(io_tx: tokio::sync::mpsc::Sender<IoEvent>, debug_mode: bool) -> Self {
+        let (config, config_errors, toasts) = prepare_config_for_new_app(Theme::default());
+        let mut app = Self::with_config(io_tx, debug_mode, config);
+        app.widgets.toast_widget.toasts = toasts;
+        for error in config_errors {
+            app.send_error_toast(error, None);
+        }
+        app
+    }
+
+    /// Builds the app around an already loaded config, without reading or writing the config file
+    pub fn with_config(
+        io_tx: tokio::sync::mpsc::Sender<IoEvent>,
+        debug_mode: bool,
+        config: AppConfig,
+    ) -> Self {
         let actions = vec![Action::Quit];
         let is_loading = false;
-        let state = AppState::default();
+        let state = AppState {
+            detected_color_depth: detect_terminal_color_depth(),
+            secondary_pane_height: config
+                .secondary_pane_height
+                .unwrap_or(DEFAULT_SECONDARY_PANE_HEIGHT),
+            ..AppState::default()
+        };
         let boards = Boards::default();
         let filtered_boards = Boards::default();
         let all_themes = Theme::all_default_themes();
         let mut theme = Theme::default();
-        let (config, config_errors, toasts) = prepare_config_for_new_app(theme.clone());
+        load_language(&config.language);
+        set_unicode_normalization(config.normalize_unicode_input);
+        set_log_filters(&parse_log_filters(&config.log_filters).unwrap_or_default());
         let default_theme = config.default_theme.clone();
         let theme_in_all = all_themes.iter().find(|t| t.name == default_theme);
         if let Some(theme_in_all) = theme_in_all {
             theme = theme_in_all.clone();
         }
-        let mut widgets = Widgets::new(
+        let widgets = Widgets::new(
             theme.clone(),
             debug_mode,
             config.date_picker_calender_format.clone(),
         );
-        widgets.toast_widget.toasts = toasts;
-        let mut app = Self {
+        Self {
             io_tx,
             actions,
             is_loading,
@@ -150,55 +407,117 @@ impl App<'_> {
             preview_boards_and_cards: None,
             config,
             visible_boards_and_cards: LinkedHashMap::new(),
-            last_io_event_time: None,
             all_themes,
             current_theme: theme,
             action_history_manager: ActionHistoryManager::default(),
             main_menu: MainMenu::default(),
             widgets,
-        };
-        if !config_errors.is_empty() {
-            for error in config_errors {
-                app.send_error_toast(error, None);
-            }
         }
-        app
+    }
+
+    /// An initialized app on the default config in safe mode, so nothing it does is written to
+    /// the config file. The receiver gets every dispatched IO event
+    #[cfg(test)]
+    pub(crate) fn for_tests() -> (Self, tokio::sync::mpsc::Receiver<IoEvent>) {
+        let (io_tx, io_rx) = tokio::sync::mpsc::channel(crate::constants::IO_EVENT_QUEUE_SIZE);
+        let mut app = Self::with_config(io_tx, false, AppConfig::default());
+        app.state.safe_mode = true;
+        app.initialized();
+        (app, io_rx)
+    }
+
+    /// The color depth frames are drawn with, the configured one or the detected one on Auto
+    pub fn color_depth(&self) -> ColorDepth {
+        self.config
+            .color_depth
+            .resolve(self.state.detected_color_depth)
     }
 
     pub async fn do_action(&mut self, key: Key) -> AppReturn {
-        if self.state.app_status == AppStatus::UserInput {
+        let app_return = if self.state.app_status == AppStatus::UserInput {
             handle_user_input_mode(self, key).await
         } else if self.state.app_status == AppStatus::KeyBindMode {
             handle_edit_keybinding_mode(self, key).await
         } else {
             handle_general_actions(self, key).await
+        };
+        self.prompt_for_pending_form_draft();
+        self.flush_board_events().await;
+        app_return
+    }
+    /// Hands the events recorded since the last flush to the IO task, they are dropped when the
+    /// event log is disabled
+    async fn flush_board_events(&mut self) {
+        let events = std::mem::take(&mut self.action_history_manager.pending_events);
+        if self.config.enable_event_log && !events.is_empty() {
+            self.dispatch(IoEvent::AppendBoardEvents(events)).await;
         }
     }
     pub async fn dispatch(&mut self, action: IoEvent) {
         self.is_loading = true;
-        if self
-            .last_io_event_time
-            .unwrap_or_else(|| Instant::now() - Duration::from_millis(IO_EVENT_WAIT_TIME + 10))
-            + Duration::from_millis(IO_EVENT_WAIT_TIME)
-            > Instant::now()
-        {
-            tokio::time::sleep(Duration::from_millis(IO_EVENT_WAIT_TIME)).await;
-        }
-        self.last_io_event_time = Some(Instant::now());
-        if let Err(e) = self.io_tx.send(action).await {
-            self.is_loading = false;
-            debug!("Error from dispatch {}", e);
-            error!("Error in handling request please, restart the app");
-            self.send_error_toast("Error in handling request please, restart the app", None);
-        };
+        // Never wait on the IO handler here, this is called from the input path
+        match self.io_tx.try_send(action) {
+            Ok(()) => self.state.last_io_event_time = Some(Instant::now()),
+            Err(tokio::sync::mpsc::error::TrySendError::Full(dropped_action)) => {
+                self.is_loading = false;
+                debug!("IO queue is full, dropped {:?}", dropped_action);
+            }
+            Err(e) => {
+                self.is_loading = false;
+                debug!("Error from dispatch {}", e);
+                error!("Error in handling request please, restart the app");
+                self.send_error_toast("Error in handling request please, restart the app", None);
+            }
+        }
     }
 
     pub async fn handle_mouse(&mut self, mouse_action: Mouse) -> AppReturn {
-        if self.config.enable_mouse_support {
+        let app_return = if self.config.enable_mouse_support {
             handle_mouse_action(self, mouse_action).await
         } else {
             AppReturn::Continue
+        };
+        self.prompt_for_pending_form_draft();
+        self.flush_board_events().await;
+        app_return
+    }
+    pub async fn handle_paste(&mut self, text: &str) -> AppReturn {
+        handle_paste(self, text);
+        self.flush_board_events().await;
+        AppReturn::Continue
+    }
+    /// Cards shown per board, single line densities fit as many as the board had rows for
+    /// on the last render
+    pub fn get_no_of_cards_to_show(&self) -> u16 {
+        match self.state.card_rows_available {
+            Some(card_rows) if self.config.card_density.is_single_line() => {
+                card_rows.max(MIN_NO_CARDS_PER_BOARD)
+            }
+            _ => self.config.no_of_cards_to_show,
+        }
+    }
+    /// Swaps the loaded config for the defaults, nothing is written so the file on disk stays as
+    /// it was for when the app is started normally again
+    pub fn enter_safe_mode(&mut self) {
+        self.state.safe_mode = true;
+        self.config = AppConfig::default();
+        load_language(&self.config.language);
+        set_unicode_normalization(self.config.normalize_unicode_input);
+        self.apply_log_filters();
+        let default_theme = self.config.default_theme.clone();
+        if let Some(theme) = self.all_themes.iter().find(|t| t.name == default_theme) {
+            self.current_theme = theme.clone();
         }
+        warn!("Started in safe mode");
+    }
+    /// Config changes made in safe mode only last for the session, writing them would replace the
+    /// user's config with the defaults
+    pub fn write_config(&self, config: &AppConfig) -> Result<(), String> {
+        if self.state.safe_mode {
+            info!("Safe mode, config changes are not written to disk");
+            return Ok(());
+        }
+        data_handler::write_config(config)
     }
     pub fn get_first_keybinding(&self, keybinding_enum: KeyBindingEnum) -> Option<String> {
         self.config
@@ -221,6 +540,27 @@ impl App<'_> {
     pub fn loaded(&mut self) {
         self.is_loading = false;
     }
+    /// Remembers the current boards as the ones on disk, called after they are loaded from or
+    /// written to a local save
+    pub fn mark_boards_saved(&mut self) {
+        self.state.saved_board_hashes = self.boards.get_board_hashes();
+        self.action_history_manager.mark_save_point();
+    }
+    /// For boards replaced without a recorded action that are not on disk yet, like a cloud save
+    /// or a recovered save file
+    pub fn mark_boards_changed(&mut self) {
+        self.action_history_manager.boards_changed = true;
+    }
+    /// Whether a change was recorded since the last local load or save. Undoing back to the
+    /// saved state clears it, the boards themselves are only compared on quit
+    pub fn has_unsaved_changes(&self) -> bool {
+        self.action_history_manager.boards_changed
+    }
+    /// Compares the boards against the last local load or save, for when a change was recorded
+    /// but may have been reverted by hand
+    fn boards_differ_from_save(&self) -> bool {
+        self.boards.get_board_hashes() != self.state.saved_board_hashes
+    }
     pub fn get_current_focus(&self) -> &Focus {
         &self.state.focus
     }
@@ -264,6 +604,22 @@ impl App<'_> {
             ));
         }
     }
+    /// Warning toast for the action that was just added to the history, the undo from toast
+    /// keybinding only reverts the action while this toast is on screen
+    pub fn send_undoable_toast(&mut self, message: &str) {
+        let message = match self.get_first_keybinding(KeyBindingEnum::UndoFromToast) {
+            Some(undo_key) => format!("{} — press {} to undo", message, undo_key),
+            None => message.to_string(),
+        };
+        let mut toast = Toast::new(
+            message,
+            Duration::from_secs(DEFAULT_TOAST_DURATION),
+            ToastType::Warning,
+            self.current_theme.clone(),
+        );
+        toast.undo_action_id = self.action_history_manager.latest_action_id;
+        self.widgets.toast_widget.toasts.push(toast);
+    }
     pub fn send_warning_toast(&mut self, message: &str, custom_duration: Option<Duration>) {
         if let Some(duration) = custom_duration {
             self.widgets.toast_widget.toasts.push(Toast::new(
@@ -283,295 +639,682 @@ impl App<'_> {
     }
     pub fn undo(&mut self) {
         if self.action_history_manager.history_index == 0 {
-            self.send_error_toast("No more actions to undo", None);
+            self.send_error_toast(&tr(MessageId::ToastNoMoreActionsToUndo), None);
         } else {
-            let history_index = self.action_history_manager.history_index - 1;
-            let history = self.action_history_manager.history[history_index].clone();
-            match history {
-                ActionHistory::DeleteCard(card, board_id) => {
-                    if let Some(board) = self.boards.get_mut_board_with_id(board_id) {
-                        board.cards.add_card(card.clone());
-                        self.action_history_manager.history_index -= 1;
-                        refresh_visible_boards_and_cards(self);
-                        self.send_info_toast(&format!("Undo Delete Card '{}'", card.name), None);
-                    } else {
-                        self.send_error_toast(&format!("Could not undo delete card '{}' as the board with id '{:?}' was not found", card.name, board_id), None);
-                    }
-                }
-                ActionHistory::CreateCard(card, board_id) => {
-                    if let Some(board) = self.boards.get_mut_board_with_id(board_id) {
-                        board.cards.remove_card_with_id(card.id);
-                        refresh_visible_boards_and_cards(self);
-                        self.action_history_manager.history_index -= 1;
-                        self.send_info_toast(&format!("Undo Create Card '{}'", card.name), None);
-                    } else {
-                        self.send_error_toast(&format!("Could not undo create card '{}' as the board with id '{:?}' was not found", card.name, board_id), None);
-                    }
+            self.undo_latest();
+        }
+    }
+    /// Undoes the newest action, false when there is none or it could not be applied. The
+    /// history index only moves when the whole action was undone
+    fn undo_latest(&mut self) -> bool {
+        let Some(history_index) = self.action_history_manager.history_index.checked_sub(1) else {
+            return false;
+        };
+        let history = self.action_history_manager.history[history_index].clone();
+        let event = BoardEvent::from_action_history(&history, BoardEventOrigin::Undo);
+        let created_item = history.inverted().created_item();
+        match self.undo_action(history) {
+            Ok(summary) => {
+                self.show_history_change(created_item);
+                self.send_info_toast(&summary, None);
+            }
+            Err(e) => {
+                self.send_error_toast(&e, None);
+                return false;
+            }
+        }
+        self.action_history_manager.history_index -= 1;
+        self.action_history_manager
+            .update_boards_changed_after_history_move();
+        self.action_history_manager.latest_action_id = None;
+        self.action_history_manager.pending_events.push(event);
+        true
+    }
+    /// Undoes up to `steps` actions as one operation, the toasts of each undo are replaced by a
+    /// single summary
+    pub fn undo_steps(&mut self, steps: usize) {
+        if self.action_history_manager.history_index == 0 {
+            self.send_error_toast(&tr(MessageId::ToastNoMoreActionsToUndo), None);
+            return;
+        }
+        let undone = self.step_through_history(steps, true);
+        self.send_history_summary_toast(true, undone, steps);
+    }
+    /// Redoes up to `steps` actions as one operation, see [`App::undo_steps`]
+    pub fn redo_steps(&mut self, steps: usize) {
+        if self.action_history_manager.redo_steps() == 0 {
+            self.send_error_toast(&tr(MessageId::ToastNoMoreActionsToRedo), None);
+            return;
+        }
+        let redone = self.step_through_history(steps, false);
+        self.send_history_summary_toast(false, redone, steps);
+    }
+    /// Undoes every action made since the boards were last saved
+    pub fn undo_to_save_point(&mut self) {
+        match self.action_history_manager.undo_steps_to_save_point() {
+            Some(0) => {
+                self.send_info_toast(&tr(MessageId::ToastNothingChangedSinceTheLastSave), None)
+            }
+            Some(steps) => self.undo_steps(steps),
+            None => {
+                self.send_warning_toast(&tr(MessageId::ToastTheLastSaveIsNotInTheUndoHistory), None)
+            }
+        }
+    }
+    pub fn redo_all(&mut self) {
+        self.redo_steps(self.action_history_manager.redo_steps());
+    }
+    /// Applies up to `steps` undos or redos, stopping at the first that fails, returns how many
+    /// were applied
+    fn step_through_history(&mut self, steps: usize, undo: bool) -> usize {
+        let toasts_before = self.widgets.toast_widget.toasts.len();
+        let mut applied = 0;
+        while applied < steps {
+            let stepped = if undo {
+                self.undo_latest()
+            } else {
+                self.redo_latest()
+            };
+            if !stepped {
+                break;
+            }
+            applied += 1;
+        }
+        self.widgets.toast_widget.toasts.truncate(toasts_before);
+        refresh_visible_boards_and_cards(self);
+        applied
+    }
+    fn send_history_summary_toast(&mut self, undo: bool, applied: usize, steps: usize) {
+        let (summary_message, available) = match (undo, applied == 1) {
+            (true, true) => (
+                MessageId::ToastUndidOneAction,
+                self.action_history_manager.history_index,
+            ),
+            (true, false) => (
+                MessageId::ToastUndidActions,
+                self.action_history_manager.history_index,
+            ),
+            (false, true) => (
+                MessageId::ToastRedidOneAction,
+                self.action_history_manager.redo_steps(),
+            ),
+            (false, false) => (
+                MessageId::ToastRedidActions,
+                self.action_history_manager.redo_steps(),
+            ),
+        };
+        let summary = tr_args(summary_message, &[("count", &applied.to_string())]);
+        if applied < steps && available > 0 {
+            self.send_warning_toast(
+                &tr_args(
+                    MessageId::ToastHistoryStepCouldNotBeApplied,
+                    &[("summary", &summary)],
+                ),
+                None,
+            );
+        } else {
+            self.send_info_toast(&summary, None);
+        }
+    }
+
+    /// Selects what an undo or redo brought back, or refreshes the shown cards when it brought
+    /// back nothing
+    fn show_history_change(&mut self, created_item: Option<CreatedItem>) {
+        match created_item {
+            Some((board_id, card_id)) => reveal_created_item(self, board_id, card_id),
+            None => refresh_visible_boards_and_cards(self),
+        }
+    }
+
+    /// Applies the actions of a batch in order, all or nothing. When one of them fails the boards
+    /// are put back the way they were before the batch
+    fn apply_history_batch(
+        &mut self,
+        actions: Vec<ActionHistory>,
+        undo: bool,
+    ) -> Result<String, String> {
+        let boards_before = self.boards.clone();
+        let filtered_boards_before = self.filtered_boards.clone();
+        let verb = if undo { "undo" } else { "redo" };
+        let action_count = actions.len();
+        for action in actions {
+            let applied = if undo {
+                self.undo_action(action)
+            } else {
+                self.redo_action(action)
+            };
+            if let Err(e) = applied {
+                self.boards = boards_before;
+                self.filtered_boards = filtered_boards_before;
+                return Err(format!(
+                    "Could not {} {} changes, nothing was changed: {}",
+                    verb, action_count, e
+                ));
+            }
+        }
+        Ok(format!(
+            "{}{} {} changes",
+            verb[..1].to_uppercase(),
+            &verb[1..],
+            action_count
+        ))
+    }
+
+    /// Reverts an action on the boards, the message tells what was undone or why it could not
+    /// be. Nothing is refreshed or shown here, see [`App::undo_latest`]
+    fn undo_action(&mut self, history: ActionHistory) -> Result<String, String> {
+        match history {
+            ActionHistory::DeleteCard(card, board_id) => {
+                let board = self.boards.get_mut_board_with_id(board_id).ok_or_else(|| {
+                    format!(
+                        "Could not undo delete card '{}' as the board with id '{:?}' was not found",
+                        card.name, board_id
+                    )
+                })?;
+                board.cards.add_card(card.clone());
+                Ok(format!("Undo Delete Card '{}'", card.name))
+            }
+            ActionHistory::CreateCard(card, board_id) => {
+                let board = self.boards.get_mut_board_with_id(board_id).ok_or_else(|| {
+                    format!(
+                        "Could not undo create card '{}' as the board with id '{:?}' was not found",
+                        card.name, board_id
+                    )
+                })?;
+                board.cards.remove_card_with_id(card.id);
+                Ok(format!("Undo Create Card '{}'", card.name))
+            }
+            ActionHistory::MoveCardBetweenBoards(
+                card,
+                moved_from_board_id,
+                moved_to_board_id,
+                moved_from_index,
+                moved_to_index,
+            ) => {
+                let moved_to_board = self.boards.get_board_with_id(moved_to_board_id);
+                let moved_from_board = self.boards.get_board_with_id(moved_from_board_id);
+                let (Some(_), Some(moved_from_board)) = (moved_to_board, moved_from_board) else {
+                    debug!("Could not undo move card '{}' as the move to board with id '{:?}' or the move from board with id '{:?}' was not found", card.name, moved_to_board_id, moved_from_board_id);
+                    return Err(format!(
+                        "Could not undo move card '{}' as the board was not found",
+                        card.name
+                    ));
+                };
+                if moved_from_index > moved_from_board.cards.len() {
+                    debug!("bad index for undo move card, from board {:?}, to board {:?}, from index {}, to index {}", moved_from_board_id, moved_to_board_id, moved_from_index, moved_to_index);
+                    return Err(format!(
+                        "Could not undo move card '{}' as the index's were invalid",
+                        card.name
+                    ));
                 }
-                ActionHistory::MoveCardBetweenBoards(
-                    card,
-                    moved_from_board_id,
-                    moved_to_board_id,
-                    moved_from_index,
-                    moved_to_index,
-                ) => {
-                    let moved_to_board = self.boards.get_board_with_id(moved_to_board_id);
-                    let moved_from_board = self.boards.get_board_with_id(moved_from_board_id);
-                    if moved_to_board.is_none() || moved_from_board.is_none() {
-                        debug!("Could not undo move card '{}' as the move to board with id '{:?}' or the move from board with id '{:?}' was not found", card.name, moved_to_board_id, moved_from_board_id);
-                        return;
-                    }
 
-                    let moved_from_board = moved_from_board.unwrap();
-                    if moved_from_index > moved_from_board.cards.len() {
-                        debug!("bad index for undo move card, from board {:?}, to board {:?}, from index {}, to index {}", moved_from_board_id, moved_to_board_id, moved_from_index, moved_to_index);
-                        self.send_error_toast(
-                            &format!(
-                                "Could not undo move card '{}' as the index's were invalid",
-                                card.name
-                            ),
-                            None,
-                        );
-                    }
+                let moved_to_board = self
+                    .boards
+                    .get_mut_board_with_id(moved_to_board_id)
+                    .unwrap();
+                moved_to_board.cards.remove_card_with_id(card.id);
 
-                    let moved_to_board = self
-                        .boards
-                        .get_mut_board_with_id(moved_to_board_id)
-                        .unwrap();
-                    moved_to_board.cards.remove_card_with_id(card.id);
-
-                    let moved_from_board = self
-                        .boards
-                        .get_mut_board_with_id(moved_from_board_id)
-                        .unwrap();
-                    moved_from_board
-                        .cards
-                        .add_card_at_index(moved_from_index, card.clone());
-
-                    refresh_visible_boards_and_cards(self);
-                    self.action_history_manager.history_index -= 1;
-                    self.send_info_toast(&format!("Undo Move Card '{}'", card.name), None);
-                }
-                ActionHistory::MoveCardWithinBoard(board_id, moved_from_index, moved_to_index) => {
-                    if let Some(board) = self.boards.get_mut_board_with_id(board_id) {
-                        if moved_from_index >= board.cards.len()
-                            || moved_to_index >= board.cards.len()
-                        {
-                            self.send_error_toast(
-                                &format!(
-                                    "Could not undo move card '{}' as the index's were invalid",
-                                    FIELD_NA
-                                ),
-                                None,
-                            );
-                            return;
-                        }
-                        let card_name = board
-                            .cards
-                            .get_mut_card_with_index(moved_to_index)
-                            .unwrap()
-                            .name
-                            .clone();
-                        board.cards.swap(moved_from_index, moved_to_index);
-                        refresh_visible_boards_and_cards(self);
-                        self.action_history_manager.history_index -= 1;
-                        self.send_info_toast(&format!("Undo Move Card '{}'", card_name), None);
-                    } else {
-                        self.send_error_toast(&format!("Could not undo move card '{}' as the board with id '{:?}' was not found",FIELD_NA, board_id), None);
-                    }
+                let moved_from_board = self
+                    .boards
+                    .get_mut_board_with_id(moved_from_board_id)
+                    .unwrap();
+                moved_from_board
+                    .cards
+                    .add_card_at_index(moved_from_index, card.clone());
+                Ok(format!("Undo Move Card '{}'", card.name))
+            }
+            ActionHistory::MoveCardWithinBoard(board_id, moved_from_index, moved_to_index) => {
+                let board = self.boards.get_mut_board_with_id(board_id).ok_or_else(|| {
+                    format!(
+                        "Could not undo move card '{}' as the board with id '{:?}' was not found",
+                        FIELD_NA, board_id
+                    )
+                })?;
+                if moved_from_index >= board.cards.len() || moved_to_index >= board.cards.len() {
+                    return Err(format!(
+                        "Could not undo move card '{}' as the index's were invalid",
+                        FIELD_NA
+                    ));
                 }
-                ActionHistory::DeleteBoard(board) => {
-                    self.boards.add_board(board.clone());
-                    refresh_visible_boards_and_cards(self);
-                    self.action_history_manager.history_index -= 1;
-                    self.send_info_toast(&format!("Undo Delete Board '{}'", board.name), None);
-                }
-                ActionHistory::CreateBoard(board) => {
-                    self.boards.remove_board_with_id(board.id);
-                    refresh_visible_boards_and_cards(self);
-                    self.action_history_manager.history_index -= 1;
-                    self.send_info_toast(&format!("Undo Create Board '{}'", board.name), None);
-                }
-                ActionHistory::EditCard(old_card, _, board_id) => {
-                    let mut card_name = String::new();
-                    let mut card_found = false;
-                    if let Some(board) = self.boards.get_mut_board_with_id(board_id) {
-                        if let Some(card) = board.cards.get_mut_card_with_id(old_card.id) {
-                            *card = old_card.clone();
-                            card_name.clone_from(&card.name);
-                            card_found = true;
-                        } else {
-                            self.send_error_toast(
-                                &format!(
-                                    "Could not undo edit card '{}' as the card was not found",
-                                    old_card.name
-                                ),
-                                None,
-                            );
-                        }
-                    } else {
-                        self.send_error_toast(&format!("Could not undo edit card '{}' as the board with id '{:?}' was not found", old_card.name, board_id), None);
-                    }
-                    if card_found {
-                        self.action_history_manager.history_index -= 1;
-                    }
-                    if !card_name.is_empty() {
-                        self.send_info_toast(&format!("Undo Edit Card '{}'", card_name), None);
-                        refresh_visible_boards_and_cards(self);
-                    }
+                let all_cards = board.cards.get_mut_all_cards();
+                let card = all_cards.remove(moved_to_index);
+                let card_name = card.name.clone();
+                all_cards.insert(moved_from_index, card);
+                Ok(format!("Undo Move Card '{}'", card_name))
+            }
+            ActionHistory::DeleteBoard(board) => {
+                let board_name = board.name.clone();
+                self.boards.add_board(board);
+                Ok(format!("Undo Delete Board '{}'", board_name))
+            }
+            ActionHistory::CreateBoard(board) => {
+                self.boards.remove_board_with_id(board.id);
+                Ok(format!("Undo Create Board '{}'", board.name))
+            }
+            ActionHistory::EditCard(old_card, _, board_id) => {
+                let board = self.boards.get_mut_board_with_id(board_id).ok_or_else(|| {
+                    format!(
+                        "Could not undo edit card '{}' as the board with id '{:?}' was not found",
+                        old_card.name, board_id
+                    )
+                })?;
+                let card = board
+                    .cards
+                    .get_mut_card_with_id(old_card.id)
+                    .ok_or_else(|| {
+                        format!(
+                            "Could not undo edit card '{}' as the card was not found",
+                            old_card.name
+                        )
+                    })?;
+                *card = old_card.clone();
+                Ok(format!("Undo Edit Card '{}'", old_card.name))
+            }
+            ActionHistory::EditBoard(old_board, _) => {
+                if self.restore_board_details(&old_board) {
+                    Ok(format!("Undo Edit Board '{}'", old_board.name))
+                } else {
+                    Err(format!(
+                        "Could not undo edit board '{}' as the board was not found",
+                        old_board.name
+                    ))
                 }
             }
+            ActionHistory::SortCards(board_id, original_card_ids, _) => self
+                .restore_card_order(board_id, &original_card_ids)
+                .map(|board_name| format!("Undo Sort Cards in '{}'", board_name))
+                .ok_or_else(|| {
+                    format!(
+                        "Could not undo sort cards as the board with id '{:?}' was not found",
+                        board_id
+                    )
+                }),
+            ActionHistory::MoveBoard(moved_from_index, moved_to_index) => self
+                .swap_boards(moved_to_index, moved_from_index)
+                .map(|board_name| format!("Undo Move Board '{}'", board_name))
+                .ok_or_else(|| "Could not undo move board as the index's were invalid".to_string()),
+            ActionHistory::Batch(actions) => {
+                self.apply_history_batch(actions.into_iter().rev().collect(), true)
+            }
         }
     }
 
     pub fn redo(&mut self) {
         if self.action_history_manager.history_index == self.action_history_manager.history.len() {
-            self.send_error_toast("No more actions to redo", None);
+            self.send_error_toast(&tr(MessageId::ToastNoMoreActionsToRedo), None);
         } else {
-            let history_index = self.action_history_manager.history_index;
-            let history = self.action_history_manager.history[history_index].clone();
-            match history {
-                ActionHistory::DeleteCard(card, board_id) => {
-                    if let Some(board) = self.boards.get_mut_board_with_id(board_id) {
-                        board.cards.remove_card_with_id(card.id);
-                        refresh_visible_boards_and_cards(self);
-                        self.action_history_manager.history_index += 1;
-                        self.send_info_toast(&format!("Redo Delete Card '{}'", card.name), None);
-                    } else {
-                        self.send_error_toast(&format!("Could not redo delete card '{}' as the board with id '{:?}' was not found", card.name, board_id), None);
-                    }
-                }
-                ActionHistory::CreateCard(card, board_id) => {
-                    if let Some(board) = self.boards.get_mut_board_with_id(board_id) {
-                        board.cards.add_card(card.clone());
-                        refresh_visible_boards_and_cards(self);
-                        self.action_history_manager.history_index += 1;
-                        self.send_info_toast(&format!("Redo Create Card '{}'", card.name), None);
-                    } else {
-                        self.send_error_toast(&format!("Could not redo create card '{}' as the board with id '{:?}' was not found", card.name, board_id), None);
-                    }
+            self.redo_latest();
+        }
+    }
+    /// Redoes the next undone action, false when there is none or it could not be applied. The
+    /// history index only moves when the whole action was redone
+    fn redo_latest(&mut self) -> bool {
+        let history_index = self.action_history_manager.history_index;
+        let Some(history) = self
+            .action_history_manager
+            .history
+            .get(history_index)
+            .cloned()
+        else {
+            return false;
+        };
+        let event = BoardEvent::from_action_history(&history, BoardEventOrigin::Redo);
+        let created_item = history.created_item();
+        match self.redo_action(history) {
+            Ok(summary) => {
+                self.show_history_change(created_item);
+                self.send_info_toast(&summary, None);
+            }
+            Err(e) => {
+                self.send_error_toast(&e, None);
+                return false;
+            }
+        }
+        self.action_history_manager.history_index += 1;
+        self.action_history_manager
+            .update_boards_changed_after_history_move();
+        self.action_history_manager.latest_action_id = None;
+        self.action_history_manager.pending_events.push(event);
+        true
+    }
+
+    /// Applies an action to the boards again, see [`App::undo_action`]
+    fn redo_action(&mut self, history: ActionHistory) -> Result<String, String> {
+        match history {
+            ActionHistory::DeleteCard(card, board_id) => {
+                let board = self.boards.get_mut_board_with_id(board_id).ok_or_else(|| {
+                    format!(
+                        "Could not redo delete card '{}' as the board with id '{:?}' was not found",
+                        card.name, board_id
+                    )
+                })?;
+                board.cards.remove_card_with_id(card.id);
+                Ok(format!("Redo Delete Card '{}'", card.name))
+            }
+            ActionHistory::CreateCard(card, board_id) => {
+                let board = self.boards.get_mut_board_with_id(board_id).ok_or_else(|| {
+                    format!(
+                        "Could not redo create card '{}' as the board with id '{:?}' was not found",
+                        card.name, board_id
+                    )
+                })?;
+                board.cards.add_card(card.clone());
+                Ok(format!("Redo Create Card '{}'", card.name))
+            }
+            ActionHistory::MoveCardBetweenBoards(
+                card,
+                moved_from_board_id,
+                moved_to_board_id,
+                moved_from_index,
+                moved_to_index,
+            ) => {
+                let moved_to_board = self.boards.get_board_with_id(moved_to_board_id);
+                let moved_from_board = self.boards.get_board_with_id(moved_from_board_id);
+                let (Some(moved_to_board), Some(_)) = (moved_to_board, moved_from_board) else {
+                    debug!("Could not redo move card '{}' as the move to board with id '{:?}' or the move from board with id '{:?}' was not found", card.name, moved_to_board_id, moved_from_board_id);
+                    return Err(format!(
+                        "Could not redo move card '{}' as the board was not found",
+                        card.name
+                    ));
+                };
+                if moved_to_index > moved_to_board.cards.len() {
+                    debug!("bad index for redo move card, from board {:?}, to board {:?}, from index {}, to index {}", moved_from_board_id, moved_to_board_id, moved_from_index, moved_to_index);
+                    return Err(format!(
+                        "Could not redo move card '{}' as the index's were invalid",
+                        card.name
+                    ));
                 }
-                ActionHistory::MoveCardBetweenBoards(
-                    card,
-                    moved_from_board_id,
-                    moved_to_board_id,
-                    moved_from_index,
-                    moved_to_index,
-                ) => {
-                    let moved_to_board = self.boards.get_board_with_id(moved_to_board_id);
-                    let moved_from_board = self.boards.get_board_with_id(moved_from_board_id);
-                    if moved_to_board.is_none() || moved_from_board.is_none() {
-                        debug!("Could not undo move card '{}' as the move to board with id '{:?}' or the move from board with id '{:?}' was not found", card.name, moved_to_board_id, moved_from_board_id);
-                        return;
-                    }
 
-                    let moved_to_board = moved_to_board.unwrap();
-                    if moved_to_index > moved_to_board.cards.len() {
-                        debug!("bad index for redo move card, from board {:?}, to board {:?}, from index {}, to index {}", moved_from_board_id, moved_to_board_id, moved_from_index, moved_to_index);
-                        self.send_error_toast(
-                            &format!(
-                                "Could not redo move card '{}' as the index's were invalid",
-                                card.name
-                            ),
-                            None,
-                        );
-                        return;
-                    }
+                let moved_from_board = self
+                    .boards
+                    .get_mut_board_with_id(moved_from_board_id)
+                    .unwrap();
+                moved_from_board.cards.remove_card_with_id(card.id);
 
-                    let moved_from_board = self
-                        .boards
-                        .get_mut_board_with_id(moved_from_board_id)
-                        .unwrap();
-                    moved_from_board.cards.remove_card_with_id(card.id);
-
-                    let moved_to_board = self
-                        .boards
-                        .get_mut_board_with_id(moved_to_board_id)
-                        .unwrap();
-                    moved_to_board
-                        .cards
-                        .add_card_at_index(moved_to_index, card.clone());
-
-                    refresh_visible_boards_and_cards(self);
-                    self.action_history_manager.history_index += 1;
-                    self.send_info_toast(&format!("Redo Move Card '{}'", card.name), None);
-                }
-                ActionHistory::MoveCardWithinBoard(board_id, moved_from_index, moved_to_index) => {
-                    if let Some(board) = self.boards.get_mut_board_with_id(board_id) {
-                        if moved_from_index >= board.cards.len()
-                            || moved_to_index >= board.cards.len()
-                        {
-                            self.send_error_toast(
-                                &format!(
-                                    "Could not redo move card '{}' as the index's were invalid",
-                                    FIELD_NA
-                                ),
-                                None,
-                            );
-                            return;
-                        }
-                        let card_name = board
-                            .cards
-                            .get_card_with_index(moved_to_index)
-                            .unwrap()
-                            .name
-                            .clone();
-                        board.cards.swap(moved_from_index, moved_to_index);
-                        refresh_visible_boards_and_cards(self);
-                        self.action_history_manager.history_index += 1;
-                        self.send_info_toast(&format!("Redo Move Card '{}'", card_name), None);
-                    } else {
-                        self.send_error_toast(&format!("Could not redo move card '{}' as the board with id '{:?}' was not found", FIELD_NA, board_id), None);
-                    }
+                let moved_to_board = self
+                    .boards
+                    .get_mut_board_with_id(moved_to_board_id)
+                    .unwrap();
+                moved_to_board
+                    .cards
+                    .add_card_at_index(moved_to_index, card.clone());
+                Ok(format!("Redo Move Card '{}'", card.name))
+            }
+            ActionHistory::MoveCardWithinBoard(board_id, moved_from_index, moved_to_index) => {
+                let board = self.boards.get_mut_board_with_id(board_id).ok_or_else(|| {
+                    format!(
+                        "Could not redo move card '{}' as the board with id '{:?}' was not found",
+                        FIELD_NA, board_id
+                    )
+                })?;
+                if moved_from_index >= board.cards.len() || moved_to_index >= board.cards.len() {
+                    return Err(format!(
+                        "Could not redo move card '{}' as the index's were invalid",
+                        FIELD_NA
+                    ));
                 }
-                ActionHistory::DeleteBoard(board) => {
-                    self.boards.remove_board_with_id(board.id);
-                    refresh_visible_boards_and_cards(self);
-                    self.action_history_manager.history_index += 1;
-                    self.send_info_toast(&format!("Redo Delete Board '{}'", board.name), None);
-                }
-                ActionHistory::CreateBoard(board) => {
-                    self.boards.add_board(board.clone());
-                    refresh_visible_boards_and_cards(self);
-                    self.action_history_manager.history_index += 1;
-                    self.send_info_toast(&format!("Redo Create Board '{}'", board.name), None);
-                }
-                ActionHistory::EditCard(_, new_card, board_id) => {
-                    let mut card_name = String::new();
-                    let mut card_found = false;
-                    if let Some(board) = self.boards.get_mut_board_with_id(board_id) {
-                        if let Some(card) = board.cards.get_mut_card_with_id(new_card.id) {
-                            *card = new_card.clone();
-                            card_name.clone_from(&card.name);
-                            card_found = true;
-                        } else {
-                            self.send_error_toast(
-                                &format!(
-                                    "Could not redo edit card '{}' as the card was not found",
-                                    new_card.name
-                                ),
-                                None,
-                            );
-                        }
-                    } else {
-                        self.send_error_toast(&format!("Could not redo edit card '{}' as the board with id '{:?}' was not found", new_card.name, board_id), None);
-                    }
-                    if card_found {
-                        self.action_history_manager.history_index += 1;
-                    }
-                    if !card_name.is_empty() {
-                        self.send_info_toast(&format!("Redo Edit Card '{}'", card_name), None);
-                        refresh_visible_boards_and_cards(self);
-                    }
+                let all_cards = board.cards.get_mut_all_cards();
+                let card = all_cards.remove(moved_from_index);
+                let card_name = card.name.clone();
+                all_cards.insert(moved_to_index, card);
+                Ok(format!("Redo Move Card '{}'", card_name))
+            }
+            ActionHistory::DeleteBoard(board) => {
+                self.boards.remove_board_with_id(board.id);
+                Ok(format!("Redo Delete Board '{}'", board.name))
+            }
+            ActionHistory::CreateBoard(board) => {
+                let board_name = board.name.clone();
+                self.boards.add_board(board);
+                Ok(format!("Redo Create Board '{}'", board_name))
+            }
+            ActionHistory::EditCard(_, new_card, board_id) => {
+                let board = self.boards.get_mut_board_with_id(board_id).ok_or_else(|| {
+                    format!(
+                        "Could not redo edit card '{}' as the board with id '{:?}' was not found",
+                        new_card.name, board_id
+                    )
+                })?;
+                let card = board
+                    .cards
+                    .get_mut_card_with_id(new_card.id)
+                    .ok_or_else(|| {
+                        format!(
+                            "Could not redo edit card '{}' as the card was not found",
+                            new_card.name
+                        )
+                    })?;
+                *card = new_card.clone();
+                Ok(format!("Redo Edit Card '{}'", new_card.name))
+            }
+            ActionHistory::EditBoard(_, new_board) => {
+                if self.restore_board_details(&new_board) {
+                    Ok(format!("Redo Edit Board '{}'", new_board.name))
+                } else {
+                    Err(format!(
+                        "Could not redo edit board '{}' as the board was not found",
+                        new_board.name
+                    ))
                 }
             }
+            ActionHistory::SortCards(board_id, _, sorted_card_ids) => self
+                .restore_card_order(board_id, &sorted_card_ids)
+                .map(|board_name| format!("Redo Sort Cards in '{}'", board_name))
+                .ok_or_else(|| {
+                    format!(
+                        "Could not redo sort cards as the board with id '{:?}' was not found",
+                        board_id
+                    )
+                }),
+            ActionHistory::MoveBoard(moved_from_index, moved_to_index) => self
+                .swap_boards(moved_from_index, moved_to_index)
+                .map(|board_name| format!("Redo Move Board '{}'", board_name))
+                .ok_or_else(|| "Could not redo move board as the index's were invalid".to_string()),
+            ActionHistory::Batch(actions) => self.apply_history_batch(actions, false),
+        }
+    }
+    /// Copies the name, description, notes and archived flag of a board edit back, the cards are
+    /// left alone. A filtered view holds its own copy of the board so it is updated as well,
+    /// returns false when the board no longer exists
+    fn restore_board_details(&mut self, board_details: &Board) -> bool {
+        let Some(board) = self.boards.get_mut_board_with_id(board_details.id) else {
+            return false;
+        };
+        for board in [
+            Some(board),
+            self.filtered_boards.get_mut_board_with_id(board_details.id),
+        ]
+        .into_iter()
+        .flatten()
+        {
+            board.name.clone_from(&board_details.name);
+            board.description.clone_from(&board_details.description);
+            board.notes.clone_from(&board_details.notes);
+            board.archived = board_details.archived;
+            board.accent_color = board_details.accent_color;
+            board.auto_sort_by_priority = board_details.auto_sort_by_priority;
+        }
+        true
+    }
+    /// Puts the cards of a board back in the given order, in the filtered copy as well. Returns
+    /// the board name or None when the board no longer exists
+    fn restore_card_order(
+        &mut self,
+        board_id: (u64, u64),
+        card_ids: &[(u64, u64)],
+    ) -> Option<String> {
+        let board = self.boards.get_mut_board_with_id(board_id)?;
+        board.cards.reorder(card_ids);
+        let board_name = board.name.clone();
+        if let Some(filtered_board) = self.filtered_boards.get_mut_board_with_id(board_id) {
+            filtered_board.cards.reorder(card_ids);
+        }
+        Some(board_name)
+    }
+    /// Swaps two boards, in the filtered copy as well, and selects the board that ends up at
+    /// `moved_to_index`. Returns its name or None when either index is out of bounds
+    pub fn swap_boards(
+        &mut self,
+        moved_from_index: usize,
+        moved_to_index: usize,
+    ) -> Option<String> {
+        self.boards.swap(moved_from_index, moved_to_index).ok()?;
+        let moved_board = &self.boards.get_boards()[moved_to_index];
+        let (moved_board_id, board_name) = (moved_board.id, moved_board.name.clone());
+        let other_board_id = self.boards.get_boards()[moved_from_index].id;
+        if let (Some(filtered_from_index), Some(filtered_to_index)) = (
+            self.filtered_boards.get_board_index(other_board_id),
+            self.filtered_boards.get_board_index(moved_board_id),
+        ) {
+            // Both indexes were just looked up so this cannot be out of bounds
+            let _ = self
+                .filtered_boards
+                .swap(filtered_from_index, filtered_to_index);
+        }
+        // The window keeps its position, so the two boards trade places in it as well
+        let visible_board_ids = self
+            .visible_boards_and_cards
+            .keys()
+            .map(|board_id| match *board_id {
+                board_id if board_id == moved_board_id => other_board_id,
+                board_id if board_id == other_board_id => moved_board_id,
+                board_id => board_id,
+            })
+            .collect::<Vec<(u64, u64)>>();
+        let current_card_id = self.state.current_card_id;
+        refresh_visible_boards_and_cards(self);
+        self.state.current_card_id = current_card_id;
+        show_board(self, moved_board_id, &visible_board_ids);
+        Some(board_name)
+    }
+    /// Sorts the cards of a board and records the previous order so the sort can be undone.
+    /// The current card stays selected when it is still visible
+    pub fn sort_cards_in_board(&mut self, board_id: (u64, u64), order: CardSortOrder) {
+        let Some(board) = self.boards.get_mut_board_with_id(board_id) else {
+            self.send_error_toast(&tr(MessageId::ToastCouldNotFindTheBoardToSort), None);
+            return;
+        };
+        let original_card_ids = board.cards.get_all_card_ids();
+        board.sort_cards(order);
+        let sorted_card_ids = board.cards.get_all_card_ids();
+        let board_name = board.name.clone();
+        if sorted_card_ids == original_card_ids {
+            self.send_info_toast(
+                &format!(
+                    "Cards in \"{}\" are already sorted by {}",
+                    board_name, order
+                ),
+                None,
+            );
+            return;
+        }
+        if let Some(filtered_board) = self.filtered_boards.get_mut_board_with_id(board_id) {
+            filtered_board.cards.reorder(&sorted_card_ids);
+        }
+        self.action_history_manager
+            .new_action(ActionHistory::SortCards(
+                board_id,
+                original_card_ids,
+                sorted_card_ids,
+            ));
+        let current_card_id = self.state.current_card_id;
+        refresh_visible_boards_and_cards(self);
+        if let Some(visible_card_ids) = self.visible_boards_and_cards.get(&board_id) {
+            self.state.current_board_id = Some(board_id);
+            self.state.current_card_id = current_card_id
+                .filter(|card_id| visible_card_ids.contains(card_id))
+                .or_else(|| visible_card_ids.first().copied());
+        }
+        info!("Sorted cards in board \"{}\" by {}", board_name, order);
+        self.send_info_toast(
+            &format!("Sorted cards in \"{}\" by {}", board_name, order),
+            None,
+        );
+    }
+    /// Boards the current card can be moved to, the ones nearest to its board first
+    pub fn get_move_card_to_board_targets(&self) -> Vec<(u64, u64)> {
+        let Some(current_board_id) = self.state.current_board_id else {
+            return vec![];
+        };
+        nearest_first(&get_shown_board_ids(self), current_board_id)
+    }
+    /// Moves the current card to the end of another board as one undoable step and keeps it
+    /// selected there, in the filtered copy as well when it is shown
+    pub fn move_current_card_to_board(&mut self, moved_to_board_id: (u64, u64)) {
+        let (Some(moved_from_board_id), Some(card_id)) =
+            (self.state.current_board_id, self.state.current_card_id)
+        else {
+            self.send_warning_toast("No card selected", None);
+            return;
+        };
+        let Some(moved_to_board_name) = self
+            .boards
+            .get_board_with_id(moved_to_board_id)
+            .map(|board| board.name.clone())
+        else {
+            self.send_error_toast(
+                &tr(MessageId::ToastCouldNotFindTheBoardToMoveTheCardTo),
+                None,
+            );
+            return;
+        };
+        let Some(moved_from_board) = self.boards.get_mut_board_with_id(moved_from_board_id) else {
+            self.send_error_toast(&tr(MessageId::ToastCouldNotFindTheBoardOfTheCard), None);
+            return;
+        };
+        let Some(moved_from_index) = moved_from_board.cards.get_card_index(card_id) else {
+            self.send_error_toast(&tr(MessageId::ToastCouldNotFindTheCardToMove), None);
+            return;
+        };
+        let Some(card) = moved_from_board.cards.remove_card_with_id(card_id) else {
+            return;
+        };
+        let card_name = card.name.clone();
+        let moved_to_board = self
+            .boards
+            .get_mut_board_with_id(moved_to_board_id)
+            .unwrap();
+        moved_to_board.cards.add_card(card.clone());
+        let moved_to_index = moved_to_board.cards.len() - 1;
+        if let Some(filtered_card) = self
+            .filtered_boards
+            .get_mut_board_with_id(moved_from_board_id)
+            .and_then(|filtered_board| filtered_board.cards.remove_card_with_id(card_id))
+        {
+            if let Some(filtered_board) = self
+                .filtered_boards
+                .get_mut_board_with_id(moved_to_board_id)
+            {
+                filtered_board.cards.add_card(filtered_card);
+            }
         }
+        self.action_history_manager
+            .new_action(ActionHistory::MoveCardBetweenBoards(
+                card,
+                moved_from_board_id,
+                moved_to_board_id,
+                moved_from_index,
+                moved_to_index,
+            ));
+        reveal_created_item(self, moved_to_board_id, Some(card_id));
+        let info_msg = format!(
+            "Moved card \"{}\" to board \"{}\"",
+            card_name, moved_to_board_name
+        );
+        info!("{}", info_msg);
+        self.send_info_toast(&info_msg, None);
     }
-    fn select_next(current_index: Option<usize>, items_len: usize) -> usize {
+    /// Every list steps through here, past the last item it wraps or stays put depending on
+    /// the wrap around navigation setting
+    fn select_next(&self, current_index: Option<usize>, items_len: usize) -> usize {
         match current_index {
             Some(i) => {
                 if i >= items_len - 1 {
-                    0
+                    if self.config.wrap_around_navigation {
+                        0
+                    } else {
+                        items_len - 1
+                    }
                 } else {
                     i + 1
                 }
@@ -579,11 +1322,15 @@ impl App<'_> {
             None => 0,
         }
     }
-    fn select_previous(current_index: Option<usize>, items_len: usize) -> usize {
+    fn select_previous(&self, current_index: Option<usize>, items_len: usize) -> usize {
         match current_index {
             Some(i) => {
                 if i == 0 {
-                    items_len - 1
+                    if self.config.wrap_around_navigation {
+                        items_len - 1
+                    } else {
+                        0
+                    }
                 } else {
                     i - 1
                 }
@@ -592,30 +1339,32 @@ impl App<'_> {
         }
     }
     pub fn config_next(&mut self) {
-        let i = Self::select_next(
+        let i = self.select_next(
             self.state.app_table_states.config.selected(),
             self.config.to_view_list().len(),
         );
         self.state.app_table_states.config.select(Some(i));
     }
     pub fn config_prv(&mut self) {
-        let i = Self::select_previous(
+        let i = self.select_previous(
             self.state.app_table_states.config.selected(),
             self.config.to_view_list().len(),
         );
         self.state.app_table_states.config.select(Some(i));
     }
     pub fn main_menu_next(&mut self) {
-        let i = Self::select_next(
+        let main_menu_len = self.main_menu.all().len();
+        let i = self.select_next(
             self.state.app_list_states.main_menu.selected(),
-            self.main_menu.all().len(),
+            main_menu_len,
         );
         self.state.app_list_states.main_menu.select(Some(i));
     }
     pub fn main_menu_prv(&mut self) {
-        let i = Self::select_previous(
+        let main_menu_len = self.main_menu.all().len();
+        let i = self.select_previous(
             self.state.app_list_states.main_menu.selected(),
-            self.main_menu.all().len(),
+            main_menu_len,
         );
         self.state.app_list_states.main_menu.select(Some(i));
     }
@@ -625,7 +1374,7 @@ impl App<'_> {
         } else {
             get_available_local_save_files(&self.config).map_or(0, |files| files.len())
         };
-        let i = Self::select_next(self.state.app_list_states.load_save.selected(), items_len);
+        let i = self.select_next(self.state.app_list_states.load_save.selected(), items_len);
         self.state.app_list_states.load_save.select(Some(i));
     }
     pub fn load_save_prv(&mut self, cloud_mode: bool) {
@@ -634,12 +1383,12 @@ impl App<'_> {
         } else {
             get_available_local_save_files(&self.config).map_or(0, |files| files.len())
         };
-        let i = Self::select_previous(self.state.app_list_states.load_save.selected(), items_len);
+        let i = self.select_previous(self.state.app_list_states.load_save.selected(), items_len);
         self.state.app_list_states.load_save.select(Some(i));
     }
     pub fn edit_keybindings_next(&mut self) {
         let keybinding_count = self.config.keybindings.iter().count();
-        let i = Self::select_next(
+        let i = self.select_next(
             self.state.app_table_states.edit_keybindings.selected(),
             keybinding_count,
         );
@@ -647,7 +1396,7 @@ impl App<'_> {
     }
     pub fn edit_keybindings_prv(&mut self) {
         let keybinding_count = self.config.keybindings.iter().count();
-        let i = Self::select_previous(
+        let i = self.select_previous(
             self.state.app_table_states.edit_keybindings.selected(),
             keybinding_count,
         );
@@ -655,7 +1404,7 @@ impl App<'_> {
     }
     pub fn help_next(&mut self) {
         let all_keybindings: Vec<_> = self.config.keybindings.iter().collect();
-        let i = Self::select_next(
+        let i = self.select_next(
             self.state.app_table_states.help.selected(),
             all_keybindings.len() / 2,
         );
@@ -663,21 +1412,21 @@ impl App<'_> {
     }
     pub fn help_prv(&mut self) {
         let all_keybindings: Vec<_> = self.config.keybindings.iter().collect();
-        let i = Self::select_previous(
+        let i = self.select_previous(
             self.state.app_table_states.help.selected(),
             all_keybindings.len() / 2,
         );
         self.state.app_table_states.help.select(Some(i));
     }
     pub fn select_default_view_next(&mut self) {
-        let i = Self::select_next(
+        let i = self.select_next(
             self.state.app_list_states.default_view.selected(),
             View::all_views_as_string().len(),
         );
         self.state.app_list_states.default_view.select(Some(i));
     }
     pub fn select_default_view_prv(&mut self) {
-        let i = Self::select_previous(
+        let i = self.select_previous(
             self.state.app_list_states.default_view.selected(),
             View::all_views_as_string().len(),
         );
@@ -685,7 +1434,7 @@ impl App<'_> {
     }
     pub fn command_palette_command_search_prv(&mut self) {
         if let Some(results) = &self.widgets.command_palette.command_search_results {
-            let i = Self::select_previous(
+            let i = self.select_previous(
                 self.state
                     .app_list_states
                     .command_palette_command_search
@@ -700,7 +1449,7 @@ impl App<'_> {
     }
     pub fn command_palette_command_search_next(&mut self) {
         if let Some(results) = &self.widgets.command_palette.command_search_results {
-            let i = Self::select_next(
+            let i = self.select_next(
                 self.state
                     .app_list_states
                     .command_palette_command_search
@@ -715,7 +1464,7 @@ impl App<'_> {
     }
     pub fn command_palette_card_search_next(&mut self) {
         if let Some(results) = &self.widgets.command_palette.card_search_results {
-            let i = Self::select_next(
+            let i = self.select_next(
                 self.state
                     .app_list_states
                     .command_palette_card_search
@@ -730,7 +1479,7 @@ impl App<'_> {
     }
     pub fn command_palette_card_search_prv(&mut self) {
         if let Some(results) = &self.widgets.command_palette.card_search_results {
-            let i = Self::select_previous(
+            let i = self.select_previous(
                 self.state
                     .app_list_states
                     .command_palette_card_search
@@ -745,7 +1494,7 @@ impl App<'_> {
     }
     pub fn command_palette_board_search_next(&mut self) {
         if let Some(results) = &self.widgets.command_palette.board_search_results {
-            let i = Self::select_next(
+            let i = self.select_next(
                 self.state
                     .app_list_states
                     .command_palette_board_search
@@ -760,7 +1509,7 @@ impl App<'_> {
     }
     pub fn command_palette_board_search_prv(&mut self) {
         if let Some(results) = &self.widgets.command_palette.board_search_results {
-            let i = Self::select_previous(
+            let i = self.select_previous(
                 self.state
                     .app_list_states
                     .command_palette_board_search
@@ -774,9 +1523,9 @@ impl App<'_> {
         }
     }
     pub fn select_card_status_prv(&mut self) {
-        let i = Self::select_previous(
+        let i = self.select_previous(
             self.state.app_list_states.card_status_selector.selected(),
-            CardStatus::all().len(),
+            self.get_current_board_status_selections().len(),
         );
         self.state
             .app_list_states
@@ -784,9 +1533,9 @@ impl App<'_> {
             .select(Some(i));
     }
     pub fn select_card_status_next(&mut self) {
-        let i = Self::select_next(
+        let i = self.select_next(
             self.state.app_list_states.card_status_selector.selected(),
-            CardStatus::all().len(),
+            self.get_current_board_status_selections().len(),
         );
         self.state
             .app_list_states
@@ -794,7 +1543,7 @@ impl App<'_> {
             .select(Some(i));
     }
     pub fn select_change_theme_next(&mut self) {
-        let i = Self::select_next(
+        let i = self.select_next(
             self.state.app_list_states.theme_selector.selected(),
             self.all_themes.len(),
         );
@@ -802,7 +1551,7 @@ impl App<'_> {
         self.current_theme = self.all_themes[i].clone();
     }
     pub fn select_change_theme_prv(&mut self) {
-        let i = Self::select_previous(
+        let i = self.select_previous(
             self.state.app_list_states.theme_selector.selected(),
             self.all_themes.len(),
         );
@@ -812,7 +1561,7 @@ impl App<'_> {
     pub fn select_create_theme_next(&mut self) {
         // popup doesn't matter here, as we only want the length of the rows
         let theme_rows_len = Theme::default().to_rows(self, true).1.len();
-        let i = Self::select_next(
+        let i = self.select_next(
             self.state.app_table_states.theme_editor.selected(),
             theme_rows_len,
         );
@@ -821,56 +1570,56 @@ impl App<'_> {
     pub fn select_create_theme_prv(&mut self) {
         // popup doesn't matter here, as we only want the length of the rows
         let theme_rows_len = Theme::default().to_rows(self, true).1.len();
-        let i = Self::select_previous(
+        let i = self.select_previous(
             self.state.app_table_states.theme_editor.selected(),
             theme_rows_len,
         );
         self.state.app_table_states.theme_editor.select(Some(i));
     }
     pub fn select_edit_style_fg_next(&mut self) {
-        let i = Self::select_next(
+        let i = self.select_next(
             self.state.app_list_states.edit_specific_style[0].selected(),
             TextColorOptions::iter().count(),
         );
         self.state.app_list_states.edit_specific_style[0].select(Some(i));
     }
     pub fn select_edit_style_fg_prv(&mut self) {
-        let i = Self::select_previous(
+        let i = self.select_previous(
             self.state.app_list_states.edit_specific_style[0].selected(),
             TextColorOptions::iter().count(),
         );
         self.state.app_list_states.edit_specific_style[0].select(Some(i));
     }
     pub fn select_edit_style_bg_next(&mut self) {
-        let i = Self::select_next(
+        let i = self.select_next(
             self.state.app_list_states.edit_specific_style[1].selected(),
             TextColorOptions::iter().count(),
         );
         self.state.app_list_states.edit_specific_style[1].select(Some(i));
     }
     pub fn select_edit_style_bg_prv(&mut self) {
-        let i = Self::select_previous(
+        let i = self.select_previous(
             self.state.app_list_states.edit_specific_style[1].selected(),
             TextColorOptions::iter().count(),
         );
         self.state.app_list_states.edit_specific_style[1].select(Some(i));
     }
     pub fn select_edit_style_modifier_next(&mut self) {
-        let i = Self::select_next(
+        let i = self.select_next(
             self.state.app_list_states.edit_specific_style[2].selected(),
             TextModifierOptions::iter().count(),
         );
         self.state.app_list_states.edit_specific_style[2].select(Some(i));
     }
     pub fn select_edit_style_modifier_prv(&mut self) {
-        let i = Self::select_previous(
+        let i = self.select_previous(
             self.state.app_list_states.edit_specific_style[2].selected(),
             TextModifierOptions::iter().count(),
         );
         self.state.app_list_states.edit_specific_style[2].select(Some(i));
     }
     pub fn select_card_priority_next(&mut self) {
-        let i = Self::select_next(
+        let i = self.select_next(
             self.state.app_list_states.card_priority_selector.selected(),
             CardPriority::all().len(),
         );
@@ -880,7 +1629,7 @@ impl App<'_> {
             .select(Some(i));
     }
     pub fn select_card_priority_prv(&mut self) {
-        let i = Self::select_previous(
+        let i = self.select_previous(
             self.state.app_list_states.card_priority_selector.selected(),
             CardPriority::all().len(),
         );
@@ -889,14 +1638,468 @@ impl App<'_> {
             .card_priority_selector
             .select(Some(i));
     }
-    pub fn filter_by_tag_popup_next(&mut self) {
-        let all_tags_len = self
-            .state
-            .all_available_tags
-            .as_ref()
-            .map_or(0, |tags| tags.len());
+    /// Card ids covered by the active range selection, None unless it spans more than one card
+    pub fn get_card_range_selection(&self) -> Option<Vec<(u64, u64)>> {
+        let range_selection = self.state.card_range_selection?;
+        let current_card_id = self.state.current_card_id?;
+        if self.state.current_board_id != Some(range_selection.board_id) {
+            return None;
+        }
+        let boards = if self.filtered_boards.is_empty() {
+            &self.boards
+        } else {
+            &self.filtered_boards
+        };
+        let board_cards =
+            self.get_displayable_cards(boards.get_board_with_id(range_selection.board_id)?);
+        let anchor_index = board_cards.get_card_index(range_selection.anchor_card_id)?;
+        let current_index = board_cards.get_card_index(current_card_id)?;
+        if anchor_index == current_index {
+            return None;
+        }
+        let start_index = anchor_index.min(current_index);
+        let end_index = anchor_index.max(current_index) + 1;
+        Some(
+            board_cards
+                .get_cards_with_range(start_index, end_index)
+                .get_all_card_ids(),
+        )
+    }
+    /// Marked cards still on the current board in board order, empty when nothing is marked
+    /// there
+    pub fn get_marked_card_ids(&self) -> Vec<(u64, u64)> {
+        let Some(current_board_id) = self.state.current_board_id else {
+            return Vec::new();
+        };
+        if self.state.selected_card_ids.is_empty() {
+            return Vec::new();
+        }
+        let boards = if self.filtered_boards.is_empty() {
+            &self.boards
+        } else {
+            &self.filtered_boards
+        };
+        let Some(board) = boards.get_board_with_id(current_board_id) else {
+            return Vec::new();
+        };
+        self.get_displayable_cards(board)
+            .get_all_card_ids()
+            .into_iter()
+            .filter(|card_id| self.state.selected_card_ids.contains(card_id))
+            .collect()
+    }
+    /// Cards the bulk actions apply to, the marked cards over the range selection. None when
+    /// neither is active so the action falls back to the current card
+    pub fn get_selected_card_ids(&self) -> Option<Vec<(u64, u64)>> {
+        let marked_card_ids = self.get_marked_card_ids();
+        if marked_card_ids.is_empty() {
+            self.get_card_range_selection()
+        } else {
+            Some(marked_card_ids)
+        }
+    }
+    /// The cards a status or priority change would apply to, the card being edited, the
+    /// selected cards or the current card in that order
+    pub fn get_cards_targeted_by_selector(&self) -> (Vec<Card>, bool) {
+        let Some(current_board_id) = self.state.current_board_id else {
+            return (Vec::new(), false);
+        };
+        let completed_cards_hidden = self
+            .state
+            .boards_with_hidden_completed_cards
+            .contains(&current_board_id);
+        if let Some(card_being_edited) = &self.state.card_being_edited {
+            return (vec![card_being_edited.1.clone()], completed_cards_hidden);
+        }
+        let boards = if self.filtered_boards.is_empty() {
+            &self.boards
+        } else {
+            &self.filtered_boards
+        };
+        let Some(board) = boards.get_board_with_id(current_board_id) else {
+            return (Vec::new(), completed_cards_hidden);
+        };
+        let card_ids = if let Some(card_ids) = self.get_selected_card_ids() {
+            card_ids
+        } else if let Some(current_card_id) = self.state.current_card_id {
+            vec![current_card_id]
+        } else {
+            Vec::new()
+        };
+        let cards = card_ids
+            .iter()
+            .filter_map(|card_id| board.cards.get_card_with_id(*card_id))
+            .cloned()
+            .collect();
+        (cards, completed_cards_hidden)
+    }
+    /// The statuses the status selector offers, the ones of the current board
+    pub fn get_current_board_status_selections(&self) -> Vec<StatusSelection> {
+        self.state
+            .current_board_id
+            .and_then(|board_id| self.boards.get_board_with_id(board_id))
+            .map(|board| board.get_status_selections())
+            .unwrap_or_else(|| Board::default().get_status_selections())
+    }
+    pub fn select_card_highlight_color_next(&mut self) {
+        let i = self.select_next(
+            self.state
+                .app_list_states
+                .card_highlight_color_selector
+                .selected(),
+            TextColorOptions::card_highlight_options().len(),
+        );
+        self.state
+            .app_list_states
+            .card_highlight_color_selector
+            .select(Some(i));
+    }
+    pub fn select_card_highlight_color_prv(&mut self) {
+        let i = self.select_previous(
+            self.state
+                .app_list_states
+                .card_highlight_color_selector
+                .selected(),
+            TextColorOptions::card_highlight_options().len(),
+        );
+        self.state
+            .app_list_states
+            .card_highlight_color_selector
+            .select(Some(i));
+    }
+    pub fn select_board_accent_color_next(&mut self) {
+        let i = self.select_next(
+            self.state
+                .app_list_states
+                .board_accent_color_selector
+                .selected(),
+            TextColorOptions::card_highlight_options().len(),
+        );
+        self.state
+            .app_list_states
+            .board_accent_color_selector
+            .select(Some(i));
+    }
+    pub fn select_board_accent_color_prv(&mut self) {
+        let i = self.select_previous(
+            self.state
+                .app_list_states
+                .board_accent_color_selector
+                .selected(),
+            TextColorOptions::card_highlight_options().len(),
+        );
+        self.state
+            .app_list_states
+            .board_accent_color_selector
+            .select(Some(i));
+    }
+    pub fn sort_cards_by_popup_next(&mut self) {
+        let i = self.select_next(
+            self.state.app_list_states.sort_cards_by_selector.selected(),
+            CardSortOrder::all().len(),
+        );
+        self.state
+            .app_list_states
+            .sort_cards_by_selector
+            .select(Some(i));
+    }
+    pub fn log_level_popup_next(&mut self) {
+        let i = self.select_next(
+            self.state.app_list_states.log_level_selector.selected(),
+            SELECTABLE_LOG_LEVELS.len(),
+        );
+        self.state
+            .app_list_states
+            .log_level_selector
+            .select(Some(i));
+    }
+    pub fn log_level_popup_prv(&mut self) {
+        let i = self.select_previous(
+            self.state.app_list_states.log_level_selector.selected(),
+            SELECTABLE_LOG_LEVELS.len(),
+        );
+        self.state
+            .app_list_states
+            .log_level_selector
+            .select(Some(i));
+    }
+    pub fn move_card_to_board_popup_next(&mut self) {
+        let targets_len = self.get_move_card_to_board_targets().len();
+        if targets_len == 0 {
+            return;
+        }
+        let i = self.select_next(
+            self.state
+                .app_list_states
+                .move_card_to_board_selector
+                .selected(),
+            targets_len,
+        );
+        self.state
+            .app_list_states
+            .move_card_to_board_selector
+            .select(Some(i));
+    }
+    pub fn move_card_to_board_popup_prv(&mut self) {
+        let targets_len = self.get_move_card_to_board_targets().len();
+        if targets_len == 0 {
+            return;
+        }
+        let i = self.select_previous(
+            self.state
+                .app_list_states
+                .move_card_to_board_selector
+                .selected(),
+            targets_len,
+        );
+        self.state
+            .app_list_states
+            .move_card_to_board_selector
+            .select(Some(i));
+    }
+    pub fn calendar_day_cards_popup_next(&mut self) {
+        let due_cards_len = cards_due_on(&self.boards, self.state.calendar_date).len();
+        if due_cards_len == 0 {
+            return;
+        }
+        let i = self.select_next(
+            self.state.app_list_states.calendar_day_cards.selected(),
+            due_cards_len,
+        );
+        self.state
+            .app_list_states
+            .calendar_day_cards
+            .select(Some(i));
+    }
+    pub fn calendar_day_cards_popup_prv(&mut self) {
+        let due_cards_len = cards_due_on(&self.boards, self.state.calendar_date).len();
+        if due_cards_len == 0 {
+            return;
+        }
+        let i = self.select_previous(
+            self.state.app_list_states.calendar_day_cards.selected(),
+            due_cards_len,
+        );
+        self.state
+            .app_list_states
+            .calendar_day_cards
+            .select(Some(i));
+    }
+    pub fn waiting_on_list_popup_next(&mut self) {
+        self.step_waiting_on_list(true);
+    }
+    pub fn waiting_on_list_popup_prv(&mut self) {
+        self.step_waiting_on_list(false);
+    }
+    fn step_waiting_on_list(&mut self, forward: bool) {
+        let waiting_on_groups = WaitingOnGroups::new(&self.boards);
+        let selected = self.state.app_list_states.waiting_on_list.selected();
+        if let Some(i) = waiting_on_groups
+            .step(selected, forward)
+            .or_else(|| waiting_on_groups.first_card_index())
+        {
+            self.state.app_list_states.waiting_on_list.select(Some(i));
+        }
+    }
+    pub fn card_templates_popup_next(&mut self) {
+        let templates_len = self.state.card_templates.len();
+        if templates_len == 0 {
+            return;
+        }
+        let i = self.select_next(
+            self.state.app_list_states.card_templates.selected(),
+            templates_len,
+        );
+        self.state.app_list_states.card_templates.select(Some(i));
+    }
+    pub fn card_templates_popup_prv(&mut self) {
+        let templates_len = self.state.card_templates.len();
+        if templates_len == 0 {
+            return;
+        }
+        let i = self.select_previous(
+            self.state.app_list_states.card_templates.selected(),
+            templates_len,
+        );
+        self.state.app_list_states.card_templates.select(Some(i));
+    }
+    pub fn sort_cards_by_popup_prv(&mut self) {
+        let i = self.select_previous(
+            self.state.app_list_states.sort_cards_by_selector.selected(),
+            CardSortOrder::all().len(),
+        );
+        self.state
+            .app_list_states
+            .sort_cards_by_selector
+            .select(Some(i));
+    }
+    /// Every filter narrowing the kanban view. Tags and search only count while the filtered
+    /// boards they built are shown
+    pub fn get_card_filter(&self) -> CardFilter {
+        let mut card_filter = CardFilter {
+            statuses: self.state.active_status_filter.clone(),
+            priorities: self.state.active_priority_filter.clone(),
+            date_range: self.state.active_date_filter,
+            ..Default::default()
+        };
+        if !self.filtered_boards.is_empty() {
+            card_filter.tags = self.state.filter_tags.clone().unwrap_or_default();
+            let search_query = self.state.text_buffers.card_search.get_joined_lines();
+            if !search_query.trim().is_empty() {
+                card_filter.search = Some(search_query.trim().to_string());
+            }
+        }
+        card_filter
+    }
+    /// The cards of a board that are shown, completed ones may be hidden and a card has to pass
+    /// every filter in [`App::get_card_filter`]
+    pub fn get_displayable_cards(&self, board: &Board) -> Cards {
+        let displayable_cards = board.get_displayable_cards(
+            self.state
+                .boards_with_hidden_completed_cards
+                .contains(&board.id),
+        );
+        let card_filter = self.get_card_filter();
+        if card_filter.is_empty() {
+            return displayable_cards;
+        }
+        let now = chrono::Local::now().naive_local();
+        displayable_cards
+            .get_all_cards()
+            .iter()
+            .filter(|card| card_filter.matches(card, self.config.date_time_format, now))
+            .cloned()
+            .collect()
+    }
+    /// Copies of the boards the kanban view goes through with only the cards it shows, for
+    /// exporting what is on screen
+    pub fn get_shown_boards(&self) -> Vec<Board> {
+        get_shown_board_ids(self)
+            .into_iter()
+            .filter_map(|board_id| self.boards.get_board_with_id(board_id))
+            .map(|board| Board {
+                id: board.id,
+                name: board.name.clone(),
+                description: board.description.clone(),
+                cards: self.get_displayable_cards(board),
+                notes: board.notes.clone(),
+                archived: board.archived,
+                auto_sort_by_priority: board.auto_sort_by_priority,
+                statuses: board.statuses.clone(),
+                accent_color: board.accent_color,
+            })
+            .collect()
+    }
+    /// The boards in the kanban window with every card they show split by status, the card
+    /// windows are left out so a lane holds all of its cards
+    pub fn get_swimlane_grid(&self) -> SwimlaneGrid {
+        let boards = if self.filtered_boards.is_empty() {
+            &self.boards
+        } else {
+            &self.filtered_boards
+        };
+        SwimlaneGrid::new(
+            self.visible_boards_and_cards
+                .keys()
+                .filter_map(|board_id| boards.get_board_with_id(*board_id))
+                .map(|board| (board.id, self.get_displayable_cards(board)))
+                .collect(),
+        )
+    }
+    /// The lane of the selected card, or the last selected lane when no card is selected
+    pub fn get_current_swimlane_status(&self, grid: &SwimlaneGrid) -> CardStatus {
+        self.state
+            .current_board_id
+            .zip(self.state.current_card_id)
+            .and_then(|(board_id, card_id)| grid.lane_of(board_id, card_id))
+            .unwrap_or_else(|| self.state.swimlane_status.clone())
+    }
+    /// Human readable description of every filter narrowing the kanban view, empty when the
+    /// view is unfiltered
+    pub fn get_active_filter_criteria(&self) -> Vec<String> {
+        self.get_card_filter()
+            .describe(self.config.date_time_format)
+    }
+    pub fn filter_by_date_range_popup_next(&mut self) {
+        let i = self.select_next(
+            self.state
+                .app_list_states
+                .filter_by_date_range_list
+                .selected(),
+            DateRangeFilter::presets().len() + 1,
+        );
+        self.state
+            .app_list_states
+            .filter_by_date_range_list
+            .select(Some(i));
+    }
+    pub fn filter_by_date_range_popup_prv(&mut self) {
+        let i = self.select_previous(
+            self.state
+                .app_list_states
+                .filter_by_date_range_list
+                .selected(),
+            DateRangeFilter::presets().len() + 1,
+        );
+        self.state
+            .app_list_states
+            .filter_by_date_range_list
+            .select(Some(i));
+    }
+    pub fn filter_by_priority_popup_next(&mut self) {
+        let i = self.select_next(
+            self.state
+                .app_list_states
+                .filter_by_priority_list
+                .selected(),
+            CardPriority::all().len(),
+        );
+        self.state
+            .app_list_states
+            .filter_by_priority_list
+            .select(Some(i));
+    }
+    pub fn filter_by_priority_popup_prv(&mut self) {
+        let i = self.select_previous(
+            self.state
+                .app_list_states
+                .filter_by_priority_list
+                .selected(),
+            CardPriority::all().len(),
+        );
+        self.state
+            .app_list_states
+            .filter_by_priority_list
+            .select(Some(i));
+    }
+    pub fn filter_by_status_popup_next(&mut self) {
+        let i = self.select_next(
+            self.state.app_list_states.filter_by_status_list.selected(),
+            CardStatus::all().len(),
+        );
+        self.state
+            .app_list_states
+            .filter_by_status_list
+            .select(Some(i));
+    }
+    pub fn filter_by_status_popup_prv(&mut self) {
+        let i = self.select_previous(
+            self.state.app_list_states.filter_by_status_list.selected(),
+            CardStatus::all().len(),
+        );
+        self.state
+            .app_list_states
+            .filter_by_status_list
+            .select(Some(i));
+    }
+    pub fn filter_by_tag_popup_next(&mut self) {
+        let all_tags_len = self
+            .state
+            .all_available_tags
+            .as_ref()
+            .map_or(0, |tags| tags.len());
         if all_tags_len > 0 {
-            let i = Self::select_next(
+            let i = self.select_next(
                 self.state.app_list_states.filter_by_tag_list.selected(),
                 all_tags_len,
             );
@@ -905,69 +2108,456 @@ impl App<'_> {
                 .filter_by_tag_list
                 .select(Some(i));
         }
-    }
-    pub fn filter_by_tag_popup_prv(&mut self) {
-        let all_tags_len = self
-            .state
-            .all_available_tags
-            .as_ref()
-            .map_or(0, |tags| tags.len());
-        if all_tags_len > 0 {
-            let i = Self::select_previous(
-                self.state.app_list_states.filter_by_tag_list.selected(),
-                all_tags_len,
+    }
+    pub fn filter_by_tag_popup_prv(&mut self) {
+        let all_tags_len = self
+            .state
+            .all_available_tags
+            .as_ref()
+            .map_or(0, |tags| tags.len());
+        if all_tags_len > 0 {
+            let i = self.select_previous(
+                self.state.app_list_states.filter_by_tag_list.selected(),
+                all_tags_len,
+            );
+            self.state
+                .app_list_states
+                .filter_by_tag_list
+                .select(Some(i));
+        }
+    }
+    pub fn change_date_format_popup_next(&mut self) {
+        let i = self.select_next(
+            self.state.app_list_states.date_format_selector.selected(),
+            DateTimeFormat::get_all_date_formats().len(),
+        );
+        self.state
+            .app_list_states
+            .date_format_selector
+            .select(Some(i));
+    }
+    pub fn change_date_format_popup_prv(&mut self) {
+        let i = self.select_previous(
+            self.state.app_list_states.date_format_selector.selected(),
+            DateTimeFormat::get_all_date_formats().len(),
+        );
+        self.state
+            .app_list_states
+            .date_format_selector
+            .select(Some(i));
+    }
+    pub fn log_next(&mut self) {
+        let total_logs = get_logs().len();
+        let mut hot_log = RUST_KANBAN_LOGGER.hot_log.lock();
+        let i = self.select_next(hot_log.state.selected(), total_logs);
+        hot_log.state.select(Some(i));
+    }
+    pub fn log_prv(&mut self) {
+        let total_logs = get_logs().len();
+        let mut hot_log = RUST_KANBAN_LOGGER.hot_log.lock();
+        let i = self.select_previous(hot_log.state.selected(), total_logs);
+        hot_log.state.select(Some(i));
+    }
+    pub fn tag_picker_next(&mut self) {
+        let i = self.select_next(
+            self.state.app_list_states.tag_picker.selected(),
+            self.widgets.tag_picker.available_tags.len(),
+        );
+        self.state.app_list_states.tag_picker.select(Some(i));
+    }
+    pub fn tag_picker_prv(&mut self) {
+        let i = self.select_previous(
+            self.state.app_list_states.tag_picker.selected(),
+            self.widgets.tag_picker.available_tags.len(),
+        );
+        self.state.app_list_states.tag_picker.select(Some(i));
+    }
+    pub fn mirror_sync_conflict_next(&mut self) {
+        let conflict_count = self.mirror_sync_conflict_count();
+        if conflict_count == 0 {
+            return;
+        }
+        let i = self.select_next(
+            self.state.app_list_states.mirror_sync_conflicts.selected(),
+            conflict_count,
+        );
+        self.state
+            .app_list_states
+            .mirror_sync_conflicts
+            .select(Some(i));
+    }
+    pub fn mirror_sync_conflict_prv(&mut self) {
+        let conflict_count = self.mirror_sync_conflict_count();
+        if conflict_count == 0 {
+            return;
+        }
+        let i = self.select_previous(
+            self.state.app_list_states.mirror_sync_conflicts.selected(),
+            conflict_count,
+        );
+        self.state
+            .app_list_states
+            .mirror_sync_conflicts
+            .select(Some(i));
+    }
+    fn mirror_sync_conflict_count(&self) -> usize {
+        self.state
+            .mirror_sync_diff
+            .as_ref()
+            .map(|diff| diff.conflicts().len())
+            .unwrap_or(0)
+    }
+    pub fn notification_next(&mut self) {
+        let notification_count = self.state.notifications.entries().len();
+        if notification_count == 0 {
+            return;
+        }
+        let i = self.select_next(
+            self.state.app_list_states.notifications.selected(),
+            notification_count,
+        );
+        self.state.app_list_states.notifications.select(Some(i));
+    }
+    pub fn notification_prv(&mut self) {
+        let notification_count = self.state.notifications.entries().len();
+        if notification_count == 0 {
+            return;
+        }
+        let i = self.select_previous(
+            self.state.app_list_states.notifications.selected(),
+            notification_count,
+        );
+        self.state.app_list_states.notifications.select(Some(i));
+    }
+    pub fn conflict_marker_card_next(&mut self) {
+        let card_count = self.state.cards_with_conflict_markers.len();
+        if card_count == 0 {
+            return;
+        }
+        let i = self.select_next(
+            self.state.app_list_states.conflict_marker_cards.selected(),
+            card_count,
+        );
+        self.state
+            .app_list_states
+            .conflict_marker_cards
+            .select(Some(i));
+    }
+    pub fn duplicate_board_pair_next(&mut self) {
+        let pair_count = self.state.duplicate_board_pairs.len();
+        if pair_count == 0 {
+            return;
+        }
+        let i = self.select_next(
+            self.state.app_list_states.duplicate_board_pairs.selected(),
+            pair_count,
+        );
+        self.state
+            .app_list_states
+            .duplicate_board_pairs
+            .select(Some(i));
+    }
+    pub fn duplicate_board_pair_prv(&mut self) {
+        let pair_count = self.state.duplicate_board_pairs.len();
+        if pair_count == 0 {
+            return;
+        }
+        let i = self.select_previous(
+            self.state.app_list_states.duplicate_board_pairs.selected(),
+            pair_count,
+        );
+        self.state
+            .app_list_states
+            .duplicate_board_pairs
+            .select(Some(i));
+    }
+    pub fn archived_cards_next(&mut self) {
+        let archived_card_count = self.boards.get_archived_cards().len();
+        if archived_card_count == 0 {
+            return;
+        }
+        let i = self.select_next(
+            self.state.app_list_states.archived_cards.selected(),
+            archived_card_count,
+        );
+        self.state.app_list_states.archived_cards.select(Some(i));
+    }
+    pub fn archived_cards_prv(&mut self) {
+        let archived_card_count = self.boards.get_archived_cards().len();
+        if archived_card_count == 0 {
+            return;
+        }
+        let i = self.select_previous(
+            self.state.app_list_states.archived_cards.selected(),
+            archived_card_count,
+        );
+        self.state.app_list_states.archived_cards.select(Some(i));
+    }
+    /// Items of the card shown in the card view, the unsaved ones while it is being edited
+    fn card_view_checklist_len(&self) -> usize {
+        if let Some((_, card_being_edited)) = &self.state.card_being_edited {
+            return card_being_edited.checklist.len();
+        }
+        match (self.state.current_board_id, self.state.current_card_id) {
+            (Some(board_id), Some(card_id)) => self
+                .boards
+                .get_board_with_id(board_id)
+                .and_then(|board| board.cards.get_card_with_id(card_id))
+                .map_or(0, |card| card.checklist.len()),
+            _ => 0,
+        }
+    }
+    pub fn card_view_checklist_next(&mut self) {
+        let item_count = self.card_view_checklist_len();
+        if item_count == 0 {
+            return;
+        }
+        let i = self.select_next(
+            self.state.app_list_states.card_view_checklist.selected(),
+            item_count,
+        );
+        self.state
+            .app_list_states
+            .card_view_checklist
+            .select(Some(i));
+    }
+    pub fn card_view_checklist_prv(&mut self) {
+        let item_count = self.card_view_checklist_len();
+        if item_count == 0 {
+            return;
+        }
+        let i = self.select_previous(
+            self.state.app_list_states.card_view_checklist.selected(),
+            item_count,
+        );
+        self.state
+            .app_list_states
+            .card_view_checklist
+            .select(Some(i));
+    }
+    pub fn conflict_marker_card_prv(&mut self) {
+        let card_count = self.state.cards_with_conflict_markers.len();
+        if card_count == 0 {
+            return;
+        }
+        let i = self.select_previous(
+            self.state.app_list_states.conflict_marker_cards.selected(),
+            card_count,
+        );
+        self.state
+            .app_list_states
+            .conflict_marker_cards
+            .select(Some(i));
+    }
+    /// Ends the focus timer once its time is up, called on every tick so the toast shows up even
+    /// while typing
+    pub fn check_focus_timer(&mut self) {
+        let has_expired = self
+            .state
+            .focus_timer
+            .as_ref()
+            .is_some_and(|focus_timer| focus_timer.has_expired(chrono::Local::now()));
+        if !has_expired {
+            return;
+        }
+        if let Some(focus_timer) = self.state.focus_timer.take() {
+            info!("Focus timer on \"{}\" finished", focus_timer.card_name);
+            self.send_info_toast(
+                &format!(
+                    "Focus on \"{}\" is done, take a break",
+                    focus_timer.card_name
+                ),
+                Some(Duration::from_secs(10)),
+            );
+        }
+    }
+    /// Makes the logger use the per module levels from the config, called on start and whenever
+    /// they are edited
+    pub fn apply_log_filters(&self) {
+        match parse_log_filters(&self.config.log_filters) {
+            Ok(module_filters) => set_log_filters(&module_filters),
+            Err(e) => error!("Could not apply log filters: {}", e),
+        }
+    }
+    /// False in safe mode until a save is loaded by hand, the boards there are not the user's
+    /// and saving them automatically would make them the newest save
+    pub fn may_save_automatically(&self) -> bool {
+        !self.state.safe_mode || self.state.save_loaded_in_safe_mode
+    }
+    /// Whether the auto save interval has passed since the last IO event. Never due in safe mode
+    /// before a save is loaded, see may_save_automatically
+    pub fn is_auto_save_due(&self) -> bool {
+        let Some(auto_save_interval) = self.config.auto_save_interval_seconds else {
+            return false;
+        };
+        if !self.may_save_automatically() || self.state.app_status == AppStatus::Init {
+            return false;
+        }
+        self.state.last_io_event_time.is_some_and(|last_io_event| {
+            last_io_event.elapsed() >= Duration::from_secs(auto_save_interval)
+        })
+    }
+    /// Reminds once per card when its due date comes within the warning delta and once more when
+    /// it is overdue. Runs from the tick every DUE_DATE_REMINDER_CHECK_INTERVAL seconds, the
+    /// reminder is shown as a toast when no desktop notification could be sent
+    pub fn check_due_date_reminders(&mut self) {
+        if !self.config.enable_desktop_notifications
+            || self.state.safe_mode
+            || self.state.app_status == AppStatus::Init
+        {
+            return;
+        }
+        let checked_recently = self
+            .state
+            .last_due_date_reminder_check
+            .is_some_and(|last_check| {
+                last_check.elapsed() < Duration::from_secs(DUE_DATE_REMINDER_CHECK_INTERVAL)
+            });
+        if checked_recently {
+            return;
+        }
+        self.state.last_due_date_reminder_check = Some(Instant::now());
+        let mut reminders = Vec::new();
+        for board in self
+            .boards
+            .get_boards()
+            .iter()
+            .filter(|board| !board.archived)
+        {
+            for card in board.cards.get_all_cards() {
+                if matches!(
+                    card.card_status,
+                    CardStatus::Complete | CardStatus::Archived
+                ) {
+                    continue;
+                }
+                let Some(days_left) = get_card_days_left(card, self.config.date_time_format) else {
+                    continue;
+                };
+                let reminder = if days_left < 0 {
+                    DueDateReminder::Overdue
+                } else if days_left <= i64::from(self.config.warning_delta) {
+                    DueDateReminder::DueSoon
+                } else {
+                    continue;
+                };
+                if self.state.due_date_reminders_sent.get(&card.id) == Some(&reminder) {
+                    continue;
+                }
+                self.state.due_date_reminders_sent.insert(card.id, reminder);
+                let due_text = match days_left {
+                    0 => "is due today".to_string(),
+                    1 => "is due tomorrow".to_string(),
+                    -1 => "was due yesterday".to_string(),
+                    days_left if days_left < 0 => format!("was due {} days ago", -days_left),
+                    days_left => format!("is due in {} days", days_left),
+                };
+                reminders.push(format!("'{}' on '{}' {}", card.name, board.name, due_text));
+            }
+        }
+        if reminders.is_empty() {
+            return;
+        }
+        let title = if reminders.len() == 1 {
+            "Card due date reminder".to_string()
+        } else {
+            format!("{} cards need attention", reminders.len())
+        };
+        let body = reminders.join("\n");
+        info!("{}: {}", title, reminders.join(", "));
+        if let Err(err) = send_desktop_notification(&title, &body) {
+            warn!("Could not send due date reminder: {}", err);
+            self.send_warning_toast(&body, Some(Duration::from_secs(10)));
+        }
+    }
+    /// Archives the completed cards finished more than the configured number of days ago as one
+    /// undoable batch. Runs at startup and then once a day from the tick, never in safe mode
+    /// since the boards there are not the user's
+    pub fn auto_archive_completed_cards(&mut self) {
+        let auto_archive_days = self.config.auto_archive_completed_days;
+        if auto_archive_days == 0
+            || self.state.safe_mode
+            || self.state.app_status == AppStatus::Init
+            || self.boards.is_empty()
+            // Tried again on a later tick so an open edit is not archived from under the user
+            || self.state.card_being_edited.is_some()
+        {
+            return;
+        }
+        let now = chrono::Local::now();
+        let today = now.date_naive();
+        if self.state.last_auto_archive_date == Some(today) {
+            return;
+        }
+        self.state.last_auto_archive_date = Some(today);
+        let date_modified = now
+            .format(self.config.date_time_format.to_parser_string())
+            .to_string();
+        let mut batch = Vec::new();
+        for board in self.boards.get_mut_boards() {
+            let board_id = board.id;
+            for card in board.cards.get_mut_all_cards() {
+                let is_past_threshold = card.card_status == CardStatus::Complete
+                    && card
+                        .days_since_completed(today)
+                        .is_some_and(|days| days > i64::from(auto_archive_days));
+                if !is_past_threshold {
+                    continue;
+                }
+                let old_card = card.clone();
+                card.archive();
+                card.date_modified.clone_from(&date_modified);
+                batch.push(ActionHistory::EditCard(old_card, card.clone(), board_id));
+            }
+        }
+        if batch.is_empty() {
+            self.record_io_completion(
+                IoCompletionKind::AutoArchive,
+                IoOutcome::Skipped,
+                &format!("No completed cards older than {} days", auto_archive_days),
             );
-            self.state
-                .app_list_states
-                .filter_by_tag_list
-                .select(Some(i));
+            return;
         }
-    }
-    pub fn change_date_format_popup_next(&mut self) {
-        let i = Self::select_next(
-            self.state.app_list_states.date_format_selector.selected(),
-            DateTimeFormat::get_all_date_formats().len(),
-        );
-        self.state
-            .app_list_states
-            .date_format_selector
-            .select(Some(i));
-    }
-    pub fn change_date_format_popup_prv(&mut self) {
-        let i = Self::select_previous(
-            self.state.app_list_states.date_format_selector.selected(),
-            DateTimeFormat::get_all_date_formats().len(),
+        // A filtered view holds its own copies, the archived cards have to leave it as well
+        for action in &batch {
+            if let ActionHistory::EditCard(_, archived_card, board_id) = action {
+                if let Some(filtered_card) = self
+                    .filtered_boards
+                    .get_mut_board_with_id(*board_id)
+                    .and_then(|board| board.cards.get_mut_card_with_id(archived_card.id))
+                {
+                    *filtered_card = archived_card.clone();
+                }
+            }
+        }
+        let summary = format!(
+            "Archived {} completed {} older than {} days",
+            batch.len(),
+            if batch.len() == 1 { "card" } else { "cards" },
+            auto_archive_days
         );
-        self.state
-            .app_list_states
-            .date_format_selector
-            .select(Some(i));
-    }
-    pub fn log_next(&mut self) {
-        let total_logs = get_logs().len();
-        let mut hot_log = RUST_KANBAN_LOGGER.hot_log.lock();
-        let i = Self::select_next(hot_log.state.selected(), total_logs);
-        hot_log.state.select(Some(i));
-    }
-    pub fn log_prv(&mut self) {
-        let total_logs = get_logs().len();
-        let mut hot_log = RUST_KANBAN_LOGGER.hot_log.lock();
-        let i = Self::select_previous(hot_log.state.selected(), total_logs);
-        hot_log.state.select(Some(i));
-    }
-    pub fn tag_picker_next(&mut self) {
-        let i = Self::select_next(
-            self.state.app_list_states.tag_picker.selected(),
-            self.widgets.tag_picker.available_tags.len(),
+        self.action_history_manager
+            .new_action(ActionHistory::Batch(batch));
+        refresh_visible_cards_for_all_boards(self);
+        self.record_io_completion(
+            IoCompletionKind::AutoArchive,
+            IoOutcome::Succeeded,
+            &summary,
         );
-        self.state.app_list_states.tag_picker.select(Some(i));
+        self.send_info_toast(&summary, None);
     }
-    pub fn tag_picker_prv(&mut self) {
-        let i = Self::select_previous(
-            self.state.app_list_states.tag_picker.selected(),
-            self.widgets.tag_picker.available_tags.len(),
-        );
-        self.state.app_list_states.tag_picker.select(Some(i));
+    /// Keeps the result of a background IO operation for the notifications pane, so it outlives
+    /// the toast that announced it
+    pub fn record_io_completion(
+        &mut self,
+        kind: IoCompletionKind,
+        outcome: IoOutcome,
+        details: &str,
+    ) {
+        info!("{} {}: {}", kind, outcome, details);
+        self.state
+            .notifications
+            .push(IoCompletion::new(kind, outcome, details));
     }
     pub fn set_popup(&mut self, popup: PopUp) {
         if self.state.z_stack.contains(&popup) {
@@ -1030,16 +2620,73 @@ impl App<'_> {
             PopUp::CardPrioritySelector => {
                 self.state.set_focus(Focus::ChangeCardPriorityPopup);
             }
+            PopUp::CardHighlightColorSelector => {
+                self.state.set_focus(Focus::ChangeCardHighlightColorPopup);
+            }
+            PopUp::BoardAccentColorSelector => {
+                self.state.set_focus(Focus::ChangeBoardAccentColorPopup);
+            }
+            PopUp::ConfirmKeepDraft => {
+                // Keeping is the default so a stray accept never loses typed text
+                self.state.set_focus(Focus::SubmitButton);
+                self.state.app_status = AppStatus::Initialized;
+            }
+            PopUp::SortCardsBy => {
+                self.state.set_focus(Focus::SortCardsByPopup);
+            }
+            PopUp::MoveCardToBoard => {
+                self.state.set_focus(Focus::MoveCardToBoardPopup);
+            }
+            PopUp::CalendarDayCards => {
+                self.state.set_focus(Focus::CalendarDayCardsPopup);
+            }
+            PopUp::WaitingOnList => {
+                self.state.set_focus(Focus::WaitingOnListPopup);
+            }
+            PopUp::SelectCardTemplate => {
+                self.state.set_focus(Focus::SelectCardTemplatePopup);
+            }
+            PopUp::ConfirmClearWaitingOn => {
+                self.state.set_focus(Focus::SubmitButton);
+                self.state.app_status = AppStatus::Initialized;
+            }
+            PopUp::SetLogLevel => {
+                self.state.set_focus(Focus::SetLogLevelPopup);
+            }
+            PopUp::FilterByDateRange => {
+                self.state.set_focus(Focus::FilterByDateRangePopup);
+            }
+            PopUp::FilterByPriority => {
+                self.state.set_focus(Focus::FilterByPriorityPopup);
+            }
+            PopUp::FilterByStatus => {
+                self.state.set_focus(Focus::FilterByStatusPopup);
+            }
+            PopUp::FindReplace => {
+                self.state.set_focus(Focus::FindReplaceQuery);
+                self.state.app_status = AppStatus::UserInput;
+            }
             PopUp::EditGeneralConfig => {
                 self.state.set_focus(Focus::EditGeneralConfigPopup);
             }
-            PopUp::CustomHexColorPromptBG | PopUp::CustomHexColorPromptFG => {
+            PopUp::CustomHexColorPromptBG
+            | PopUp::CustomHexColorPromptFG
+            | PopUp::CustomDateRangePrompt
+            | PopUp::ImportTrelloPrompt
+            | PopUp::ImportJsonPrompt
+            | PopUp::ImportBoardJsonPrompt
+            | PopUp::ImportGithubPrompt
+            | PopUp::SearchCards => {
                 self.state.set_focus(Focus::TextInput);
                 self.state.app_status = AppStatus::UserInput;
             }
             PopUp::DateTimePicker => {
                 self.widgets.date_time_picker.open_date_picker();
             }
+            PopUp::ImportOptions => {
+                // Merging loses nothing, so it is what a stray Enter picks
+                self.state.set_focus(Focus::SubmitButton);
+            }
             _ => {
                 debug!("No special logic for setting popup: {:?}", popup);
             }
@@ -1049,8 +2696,22 @@ impl App<'_> {
     pub fn close_popup(&mut self) {
         if let Some(popup) = self.state.z_stack.pop() {
             match popup {
-                PopUp::CustomHexColorPromptBG | PopUp::CustomHexColorPromptFG => {
+                PopUp::CustomHexColorPromptBG
+                | PopUp::CustomHexColorPromptFG
+                | PopUp::SearchCards => {
+                    self.state.app_status = AppStatus::Initialized;
+                }
+                PopUp::CustomDateRangePrompt => {
+                    self.state.app_status = AppStatus::Initialized;
+                    self.state.text_buffers.custom_date_range.reset();
+                }
+                PopUp::ImportTrelloPrompt
+                | PopUp::ImportJsonPrompt
+                | PopUp::ImportBoardJsonPrompt
+                | PopUp::ImportGithubPrompt => {
                     self.state.app_status = AppStatus::Initialized;
+                    self.state.text_buffers.import_path.reset();
+                    self.state.path_check_state = PathCheckState::default();
                 }
                 PopUp::ViewCard => {
                     self.state.app_status = AppStatus::Initialized;
@@ -1069,6 +2730,51 @@ impl App<'_> {
                         self.state.card_being_edited = None;
                     }
                 }
+                PopUp::ConfirmDuplicateCardName => {
+                    self.state.duplicate_card_name_match = None;
+                }
+                PopUp::ConfirmKeepDraft => {
+                    if let Some(draft) = self.state.form_drafts.keep_pending() {
+                        let message = if draft.view == View::NewBoard {
+                            "Kept the new board draft for next time"
+                        } else {
+                            "Kept the new card draft for next time on this board"
+                        };
+                        self.send_info_toast(message, None);
+                    }
+                }
+                PopUp::ConfirmClearWaitingOn => {
+                    self.state.waiting_on_to_clear = None;
+                }
+                PopUp::SelectCardTemplate => {
+                    self.state.set_focus(Focus::UseTemplate);
+                }
+                PopUp::FindReplace => {
+                    // Back to editing the description the popup was opened from
+                    self.state.text_buffers.find_replace_query.reset();
+                    self.state.text_buffers.find_replace_replacement.reset();
+                    self.state.set_focus(Focus::CardDescription);
+                    self.state.app_status = AppStatus::UserInput;
+                }
+                PopUp::ConfirmRestoreThemesFromBackup => {
+                    self.state.theme_backup_summary = None;
+                }
+                PopUp::ImportOptions => {
+                    self.state.pending_json_import = None;
+                }
+                PopUp::RecoverSaveFile => {
+                    self.state.save_recovery_candidate = None;
+                }
+                PopUp::MirrorSyncSummary => {
+                    self.state.mirror_sync_diff = None;
+                }
+                PopUp::DuplicateBoards => {
+                    self.state.duplicate_board_pairs.clear();
+                    self.state
+                        .app_list_states
+                        .duplicate_board_pairs
+                        .select(None);
+                }
                 PopUp::DateTimePicker => {
                     self.widgets.date_time_picker.close_date_picker();
                 }
@@ -1087,7 +2793,24 @@ impl App<'_> {
         } else {
             self.state.prev_view = Some(self.state.current_view);
         }
+        let left_view = self.state.current_view;
         self.state.current_view = view;
+        if left_view != view {
+            self.leave_form(left_view);
+            if form_drafts::is_form_with_drafts(view) {
+                let board_id = self
+                    .state
+                    .current_board_id
+                    .filter(|_| view == View::NewCard);
+                if self
+                    .state
+                    .form_drafts
+                    .enter(view, board_id, &mut self.state.text_buffers)
+                {
+                    debug!("Cleared text left in the buffers before opening {}", view);
+                }
+            }
+        }
         let available_focus_targets = self.state.current_view.get_available_targets();
         if !available_focus_targets.contains(&self.state.focus) {
             if available_focus_targets.is_empty() {
@@ -1127,6 +2850,37 @@ impl App<'_> {
         }
     }
 
+    /// Nothing typed into a form outlives it, the new board and new card forms hand their text
+    /// to the drafts and the account forms are always cleared
+    fn leave_form(&mut self, view: View) {
+        match view {
+            View::NewBoard | View::NewCard => {
+                self.state
+                    .form_drafts
+                    .leave(view, &mut self.state.text_buffers);
+                if view == View::NewCard {
+                    self.widgets.date_time_picker.reset();
+                    self.state.new_card_template = None;
+                }
+            }
+            View::Login | View::SignUp | View::ResetPassword => {
+                form_drafts::clear_account_fields(&mut self.state.text_buffers);
+                self.state.show_password = false;
+            }
+            _ => {}
+        }
+    }
+
+    /// Asks whether to keep what was typed into a form that was just left, once the action
+    /// that left it is done with the popups
+    fn prompt_for_pending_form_draft(&mut self) {
+        if self.state.form_drafts.pending().is_some()
+            && !self.state.z_stack.contains(&PopUp::ConfirmKeepDraft)
+        {
+            self.set_popup(PopUp::ConfirmKeepDraft);
+        }
+    }
+
     pub fn get_first_next_focus_keybinding(&self) -> &Key {
         self.config
             .keybindings
@@ -1187,6 +2941,7 @@ pub enum MainMenuItem {
     View,
     Config,
     Help,
+    Stats,
     LoadSaveLocal,
     LoadSaveCloud,
     Quit,
@@ -1194,14 +2949,16 @@ pub enum MainMenuItem {
 
 impl Display for MainMenuItem {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        match *self {
-            MainMenuItem::View => write!(f, "View your Boards"),
-            MainMenuItem::Config => write!(f, "Configure"),
-            MainMenuItem::Help => write!(f, "Help"),
-            MainMenuItem::LoadSaveLocal => write!(f, "Load a Save (local)"),
-            MainMenuItem::LoadSaveCloud => write!(f, "Load a Save (cloud)"),
-            MainMenuItem::Quit => write!(f, "Quit"),
-        }
+        let message_id = match *self {
+            MainMenuItem::View => MessageId::MainMenuView,
+            MainMenuItem::Config => MessageId::MainMenuConfig,
+            MainMenuItem::Help => MessageId::MainMenuHelp,
+            MainMenuItem::Stats => MessageId::MainMenuStats,
+            MainMenuItem::LoadSaveLocal => MessageId::MainMenuLoadSaveLocal,
+            MainMenuItem::LoadSaveCloud => MessageId::MainMenuLoadSaveCloud,
+            MainMenuItem::Quit => MessageId::MainMenuQuit,
+        };
+        write!(f, "{}", tr(message_id))
     }
 }
 
@@ -1218,6 +2975,7 @@ impl Default for MainMenu {
                 MainMenuItem::View,
                 MainMenuItem::Config,
                 MainMenuItem::Help,
+                MainMenuItem::Stats,
                 MainMenuItem::LoadSaveLocal,
                 MainMenuItem::Quit,
             ],
@@ -1233,6 +2991,7 @@ impl MainMenu {
                 MainMenuItem::View,
                 MainMenuItem::Config,
                 MainMenuItem::Help,
+                MainMenuItem::Stats,
                 MainMenuItem::LoadSaveLocal,
                 MainMenuItem::LoadSaveCloud,
                 MainMenuItem::Quit,
@@ -1244,6 +3003,7 @@ impl MainMenu {
                 MainMenuItem::View,
                 MainMenuItem::Config,
                 MainMenuItem::Help,
+                MainMenuItem::Stats,
                 MainMenuItem::LoadSaveLocal,
                 MainMenuItem::Quit,
             ];
@@ -1258,9 +3018,10 @@ impl MainMenu {
                 0 => MainMenuItem::View,
                 1 => MainMenuItem::Config,
                 2 => MainMenuItem::Help,
-                3 => MainMenuItem::LoadSaveLocal,
-                4 => MainMenuItem::LoadSaveCloud,
-                5 => MainMenuItem::Quit,
+                3 => MainMenuItem::Stats,
+                4 => MainMenuItem::LoadSaveLocal,
+                5 => MainMenuItem::LoadSaveCloud,
+                6 => MainMenuItem::Quit,
                 _ => MainMenuItem::Quit,
             }
         } else {
@@ -1268,111 +3029,183 @@ impl MainMenu {
                 0 => MainMenuItem::View,
                 1 => MainMenuItem::Config,
                 2 => MainMenuItem::Help,
-                3 => MainMenuItem::LoadSaveLocal,
-                4 => MainMenuItem::Quit,
+                3 => MainMenuItem::Stats,
+                4 => MainMenuItem::LoadSaveLocal,
+                5 => MainMenuItem::Quit,
                 _ => MainMenuItem::Quit,
             }
         }
     }
 }
 
+/// How much of a card is drawn on the kanban board
 #[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, EnumString)]
-pub enum DateTimeFormat {
-    DayMonthYear,
+pub enum CardDensity {
     #[default]
-    DayMonthYearTime,
-    MonthDayYear,
-    MonthDayYearTime,
-    YearMonthDay,
-    YearMonthDayTime,
+    Comfortable,
+    Compact,
+    Minimal,
 }
 
-impl DateTimeFormat {
-    pub fn to_human_readable_string(&self) -> &str {
+impl CardDensity {
+    pub fn next(&self) -> CardDensity {
         match self {
-            DateTimeFormat::DayMonthYear => "DD/MM/YYYY",
-            DateTimeFormat::DayMonthYearTime => "DD/MM/YYYY-HH:MM:SS",
-            DateTimeFormat::MonthDayYear => "MM/DD/YYYY",
-            DateTimeFormat::MonthDayYearTime => "MM/DD/YYYY-HH:MM:SS",
-            DateTimeFormat::YearMonthDay => "YYYY/MM/DD",
-            DateTimeFormat::YearMonthDayTime => "YYYY/MM/DD-HH:MM:SS",
+            CardDensity::Comfortable => CardDensity::Compact,
+            CardDensity::Compact => CardDensity::Minimal,
+            CardDensity::Minimal => CardDensity::Comfortable,
         }
     }
-    pub fn to_parser_string(&self) -> &str {
+
+    /// Compact and Minimal cards take a single row
+    pub fn is_single_line(&self) -> bool {
+        !matches!(self, CardDensity::Comfortable)
+    }
+
+    /// How many card face tokens besides the name a card draws, counted in layout order
+    /// whether or not the card has a value for them, None when every token is drawn
+    pub fn card_face_token_limit(&self) -> Option<usize> {
         match self {
-            DateTimeFormat::DayMonthYear => "%d/%m/%Y",
-            DateTimeFormat::DayMonthYearTime => "%d/%m/%Y-%H:%M:%S",
-            DateTimeFormat::MonthDayYear => "%m/%d/%Y",
-            DateTimeFormat::MonthDayYearTime => "%m/%d/%Y-%H:%M:%S",
-            DateTimeFormat::YearMonthDay => "%Y/%m/%d",
-            DateTimeFormat::YearMonthDayTime => "%Y/%m/%d-%H:%M:%S",
-        }
-    }
-    pub fn from_json_string(json_string: &str) -> Option<DateTimeFormat> {
-        match DateTimeFormat::from_str(json_string) {
-            Ok(date_time_format) => Some(date_time_format),
-            Err(_) => None,
-        }
-    }
-    pub fn from_human_readable_string(human_readable_string: &str) -> Option<DateTimeFormat> {
-        match human_readable_string {
-            "DD/MM/YYYY" => Some(DateTimeFormat::DayMonthYear),
-            "DD/MM/YYYY-HH:MM:SS" => Some(DateTimeFormat::DayMonthYearTime),
-            "MM/DD/YYYY" => Some(DateTimeFormat::MonthDayYear),
-            "MM/DD/YYYY-HH:MM:SS" => Some(DateTimeFormat::MonthDayYearTime),
-            "YYYY/MM/DD" => Some(DateTimeFormat::YearMonthDay),
-            "YYYY/MM/DD-HH:MM:SS" => Some(DateTimeFormat::YearMonthDayTime),
-            _ => None,
+            CardDensity::Comfortable => None,
+            CardDensity::Compact => Some(2),
+            CardDensity::Minimal => Some(0),
         }
     }
-    pub fn get_all_date_formats() -> Vec<DateTimeFormat> {
-        vec![
-            DateTimeFormat::DayMonthYear,
-            DateTimeFormat::DayMonthYearTime,
-            DateTimeFormat::MonthDayYear,
-            DateTimeFormat::MonthDayYearTime,
-            DateTimeFormat::YearMonthDay,
-            DateTimeFormat::YearMonthDayTime,
-        ]
+}
+
+impl Display for CardDensity {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            CardDensity::Comfortable => write!(f, "Comfortable"),
+            CardDensity::Compact => write!(f, "Compact"),
+            CardDensity::Minimal => write!(f, "Minimal"),
+        }
     }
-    pub fn all_formats_with_time() -> Vec<DateTimeFormat> {
-        vec![
-            DateTimeFormat::DayMonthYearTime,
-            DateTimeFormat::MonthDayYearTime,
-            DateTimeFormat::YearMonthDayTime,
-        ]
+}
+
+/// How cards that went untouched past the aging threshold show it
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, EnumString)]
+pub enum CardAgingCue {
+    Dim,
+    AgeSuffix,
+    #[default]
+    Both,
+}
+
+impl CardAgingCue {
+    pub fn next(&self) -> CardAgingCue {
+        match self {
+            CardAgingCue::Dim => CardAgingCue::AgeSuffix,
+            CardAgingCue::AgeSuffix => CardAgingCue::Both,
+            CardAgingCue::Both => CardAgingCue::Dim,
+        }
+    }
+
+    pub fn dims(&self) -> bool {
+        !matches!(self, CardAgingCue::AgeSuffix)
+    }
+
+    pub fn shows_age_suffix(&self) -> bool {
+        !matches!(self, CardAgingCue::Dim)
+    }
+}
+
+impl Display for CardAgingCue {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            CardAgingCue::Dim => write!(f, "Dim"),
+            CardAgingCue::AgeSuffix => write!(f, "AgeSuffix"),
+            CardAgingCue::Both => write!(f, "Both"),
+        }
     }
-    pub fn all_formats_without_time() -> Vec<DateTimeFormat> {
+}
+
+/// One field of a card face, the card face layout config lists them in the order they are drawn
+#[derive(
+    Serialize, Deserialize, Debug, Clone, Copy, PartialEq, EnumString, EnumIter, strum::Display,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum CardFaceToken {
+    Name,
+    Description,
+    Due,
+    DueRelative,
+    Priority,
+    Status,
+    Tags,
+    Id,
+    Estimate,
+}
+
+impl CardFaceToken {
+    /// The layout cards were drawn with before it could be configured
+    pub fn default_layout() -> Vec<CardFaceToken> {
         vec![
-            DateTimeFormat::DayMonthYear,
-            DateTimeFormat::MonthDayYear,
-            DateTimeFormat::YearMonthDay,
+            CardFaceToken::Name,
+            CardFaceToken::Description,
+            CardFaceToken::Due,
+            CardFaceToken::Priority,
+            CardFaceToken::Status,
         ]
     }
-    pub fn add_time_to_date_format(date_format: DateTimeFormat) -> DateTimeFormat {
-        match date_format {
-            DateTimeFormat::DayMonthYear => DateTimeFormat::DayMonthYearTime,
-            DateTimeFormat::MonthDayYear => DateTimeFormat::MonthDayYearTime,
-            DateTimeFormat::YearMonthDay => DateTimeFormat::YearMonthDayTime,
-            _ => date_format,
+
+    /// Parses the tokens in order, positions in the errors start at 1 to match what people count
+    pub fn parse_layout<S: AsRef<str>>(tokens: &[S]) -> Result<Vec<CardFaceToken>, String> {
+        if tokens.is_empty() {
+            return Err("The card face layout needs at least one token".to_string());
         }
-    }
-    pub fn remove_time_from_date_format(date_format: DateTimeFormat) -> DateTimeFormat {
-        match date_format {
-            DateTimeFormat::DayMonthYearTime => DateTimeFormat::DayMonthYear,
-            DateTimeFormat::MonthDayYearTime => DateTimeFormat::MonthDayYear,
-            DateTimeFormat::YearMonthDayTime => DateTimeFormat::YearMonthDay,
-            _ => date_format,
+        let mut layout: Vec<CardFaceToken> = Vec::with_capacity(tokens.len());
+        for (index, token) in tokens.iter().enumerate() {
+            let token = token.as_ref().trim();
+            let parsed_token = CardFaceToken::from_str(token).map_err(|_| {
+                format!(
+                    "Unknown card face token \"{}\" at position {}, expected one of: {}",
+                    token,
+                    index + 1,
+                    CardFaceToken::iter()
+                        .map(|token| token.to_string())
+                        .collect::<Vec<String>>()
+                        .join(", ")
+                )
+            })?;
+            if let Some(first_position) = layout.iter().position(|t| *t == parsed_token) {
+                return Err(format!(
+                    "Card face token \"{}\" is listed twice, at positions {} and {}",
+                    token,
+                    first_position + 1,
+                    index + 1
+                ));
+            }
+            layout.push(parsed_token);
         }
+        Ok(layout)
     }
-}
 
-impl Display for DateTimeFormat {
-    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        write!(f, "{}", self.to_human_readable_string())
+    /// The comma separated form used in the config menu
+    pub fn layout_to_string(layout: &[CardFaceToken]) -> String {
+        layout
+            .iter()
+            .map(|token| token.to_string())
+            .collect::<Vec<String>>()
+            .join(", ")
+    }
+
+    pub fn parse_layout_string(value: &str) -> Result<Vec<CardFaceToken>, String> {
+        let tokens = value
+            .split(',')
+            .filter(|token| !token.trim().is_empty())
+            .collect::<Vec<&str>>();
+        CardFaceToken::parse_layout(&tokens)
     }
 }
 
+/// Settings overridden by the high contrast preset, restored when the preset is turned off
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct HighContrastRestorePoint {
+    pub default_theme: String,
+    pub disable_animations: bool,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct AppConfig {
     pub always_load_last_save: bool,
@@ -1383,13 +3216,69 @@ pub struct AppConfig {
     pub disable_animations: bool,
     pub disable_scroll_bar: bool,
     pub enable_mouse_support: bool,
+    /// Append every board and card mutation to events.jsonl in the save directory
+    #[serde(default)]
+    pub enable_event_log: bool,
+    /// Whether lists and board and card navigation jump to the other end past the last item
+    pub wrap_around_navigation: bool,
     pub keybindings: KeyBindings,
+    pub language: String,
     pub no_of_boards_to_show: u16,
     pub no_of_cards_to_show: u16,
+    pub card_preview_lines: u16,
+    #[serde(default)]
+    pub card_density: CardDensity,
+    /// Days without a change before a card starts to show its age, 0 turns the cue off
+    #[serde(default)]
+    pub card_aging_days: u16,
+    #[serde(default)]
+    pub card_aging_cue: CardAgingCue,
+    /// Overrides the color depth detected from the terminal, RGB theme colors are brought down
+    /// to the nearest color the depth has
+    #[serde(default)]
+    pub color_depth: ColorDepth,
+    /// Completed cards finished longer ago than this many days are archived, 0 turns it off
+    #[serde(default)]
+    pub auto_archive_completed_days: u16,
+    #[serde(default = "CardFaceToken::default_layout")]
+    pub card_face_layout: Vec<CardFaceToken>,
+    pub rapid_card_entry: bool,
+    pub check_for_duplicate_card_names: bool,
+    pub normalize_unicode_input: bool,
+    /// Imported boards and cards keep their ids so references from other tools stay valid, only
+    /// ids that are already taken get new ones. Off gives every import new ids
+    #[serde(default)]
+    pub keep_ids_on_import: bool,
+    /// Sends a desktop notification when a card's due date comes within the warning delta and
+    /// again when it passes
+    #[serde(default)]
+    pub enable_desktop_notifications: bool,
+    pub high_contrast_mode: bool,
+    #[serde(default)]
+    pub settings_before_high_contrast: Option<HighContrastRestorePoint>,
+    /// Version the what's new popup was last shown for, None for configs written before it
+    #[serde(default)]
+    pub last_seen_version: Option<String>,
+    /// Rows of the help and log panes from the last resize, None uses the default height
+    #[serde(default)]
+    pub secondary_pane_height: Option<u16>,
     pub date_picker_calender_format: CalenderType,
     pub save_directory: PathBuf,
+    #[serde(default)]
+    pub mirror_directory: Option<PathBuf>,
     pub save_on_exit: bool,
+    /// Newest local save files kept after every save, older ones are deleted, 0 keeps them all
+    #[serde(default)]
+    pub save_files_to_keep: u16,
+    /// Seconds between periodic saves while the app is open, None only saves on exit
+    #[serde(default)]
+    pub auto_save_interval_seconds: Option<u64>,
+    /// Per module log levels like "io=debug,ui=warn", empty logs every module at the default level
+    #[serde(default)]
+    pub log_filters: String,
     pub show_line_numbers: bool,
+    /// Whether cards on the board show how many of their checklist items are done
+    pub show_checklist_progress: bool,
     pub tickrate: u16,
     pub warning_delta: u16,
 }
@@ -1407,13 +3296,37 @@ impl Default for AppConfig {
             disable_animations: false,
             disable_scroll_bar: false,
             enable_mouse_support: true,
+            enable_event_log: false,
+            wrap_around_navigation: true,
             keybindings: KeyBindings::default(),
+            language: DEFAULT_LANGUAGE.to_string(),
             no_of_boards_to_show: DEFAULT_NO_OF_BOARDS_PER_PAGE,
             no_of_cards_to_show: DEFAULT_NO_OF_CARDS_PER_BOARD,
+            card_preview_lines: DEFAULT_CARD_PREVIEW_LINES,
+            card_density: CardDensity::default(),
+            card_aging_days: DEFAULT_CARD_AGING_DAYS,
+            card_aging_cue: CardAgingCue::default(),
+            color_depth: ColorDepth::default(),
+            auto_archive_completed_days: DEFAULT_AUTO_ARCHIVE_COMPLETED_DAYS,
+            card_face_layout: CardFaceToken::default_layout(),
+            rapid_card_entry: false,
+            check_for_duplicate_card_names: true,
+            normalize_unicode_input: false,
+            keep_ids_on_import: false,
+            enable_desktop_notifications: false,
+            high_contrast_mode: false,
+            settings_before_high_contrast: None,
+            last_seen_version: Some(env!("CARGO_PKG_VERSION").to_string()),
+            secondary_pane_height: None,
             date_picker_calender_format: CalenderType::default(),
             save_directory: get_default_save_directory(),
+            mirror_directory: None,
             save_on_exit: true,
+            save_files_to_keep: DEFAULT_SAVE_FILES_TO_KEEP,
+            auto_save_interval_seconds: None,
+            log_filters: String::new(),
             show_line_numbers: true,
+            show_checklist_progress: true,
             tickrate: DEFAULT_TICKRATE,
             warning_delta: DEFAULT_CARD_WARNING_DUE_DATE_DAYS,
         }
@@ -1446,16 +3359,60 @@ impl AppConfig {
                     }
                     ConfigEnum::DefaultTheme => (self.default_theme.clone(), 14),
                     ConfigEnum::DateFormat => (self.date_time_format.to_string(), 15),
-                    ConfigEnum::Keybindings => ("".to_string(), 16),
+                    ConfigEnum::RapidCardEntry => (self.rapid_card_entry.to_string(), 16),
+                    ConfigEnum::CheckForDuplicateCardNames => {
+                        (self.check_for_duplicate_card_names.to_string(), 17)
+                    }
+                    ConfigEnum::Language => (self.language.clone(), 18),
+                    ConfigEnum::HighContrastMode => (self.high_contrast_mode.to_string(), 19),
+                    ConfigEnum::CardPreviewLines => (self.card_preview_lines.to_string(), 20),
+                    ConfigEnum::MirrorDirectory => {
+                        (self.get_value_as_string(ConfigEnum::MirrorDirectory), 21)
+                    }
+                    ConfigEnum::CardDensity => (self.card_density.to_string(), 22),
+                    ConfigEnum::EnableEventLog => (self.enable_event_log.to_string(), 23),
+                    ConfigEnum::WrapAroundNavigation => {
+                        (self.wrap_around_navigation.to_string(), 24)
+                    }
+                    ConfigEnum::CardAgingDays => (self.card_aging_days.to_string(), 25),
+                    ConfigEnum::CardAgingCue => (self.card_aging_cue.to_string(), 26),
+                    ConfigEnum::CardFaceLayout => {
+                        (CardFaceToken::layout_to_string(&self.card_face_layout), 27)
+                    }
+                    ConfigEnum::ShowChecklistProgress => {
+                        (self.show_checklist_progress.to_string(), 28)
+                    }
+                    ConfigEnum::NormalizeUnicodeInput => {
+                        (self.normalize_unicode_input.to_string(), 29)
+                    }
+                    ConfigEnum::AutoArchiveCompletedDays => {
+                        (self.auto_archive_completed_days.to_string(), 30)
+                    }
+                    ConfigEnum::EnableDesktopNotifications => {
+                        (self.enable_desktop_notifications.to_string(), 31)
+                    }
+                    ConfigEnum::AutoSaveInterval => {
+                        (self.get_value_as_string(ConfigEnum::AutoSaveInterval), 32)
+                    }
+                    ConfigEnum::LogFilters => (self.log_filters.clone(), 33),
+                    ConfigEnum::ColorDepth => (self.color_depth.to_string(), 34),
+                    ConfigEnum::SaveFilesToKeep => (self.save_files_to_keep.to_string(), 35),
+                    ConfigEnum::KeepIdsOnImport => (self.keep_ids_on_import.to_string(), 36),
+                    ConfigEnum::Keybindings => ("".to_string(), 37),
                 };
-                (enum_variant.to_string(), value.to_string(), index)
+                (
+                    enum_variant.to_string(),
+                    value.to_string(),
+                    enum_variant.edit_mode().to_string(),
+                    index,
+                )
             })
-            .collect::<Vec<(String, String, usize)>>();
+            .collect::<Vec<(String, String, String, usize)>>();
 
-        view_list.sort_by(|a, b| a.2.cmp(&b.2));
+        view_list.sort_by(|a, b| a.3.cmp(&b.3));
         view_list
             .iter()
-            .map(|(key, value, _)| vec![key.to_owned(), value.to_owned()])
+            .map(|(key, value, hint, _)| vec![key.to_owned(), value.to_owned(), hint.to_owned()])
             .collect::<Vec<Vec<String>>>()
     }
 
@@ -1465,10 +3422,13 @@ impl AppConfig {
             ConfigEnum::AutoLogin => self.auto_login.to_string(),
             ConfigEnum::DateFormat => self.date_time_format.to_string(),
             ConfigEnum::DefaultTheme => self.default_theme.clone(),
+            ConfigEnum::Language => self.language.clone(),
             ConfigEnum::DefaultView => self.default_view.to_string(),
             ConfigEnum::DisableAnimations => self.disable_animations.to_string(),
             ConfigEnum::DisableScrollBar => self.disable_scroll_bar.to_string(),
             ConfigEnum::EnableMouseSupport => self.enable_mouse_support.to_string(),
+            ConfigEnum::EnableEventLog => self.enable_event_log.to_string(),
+            ConfigEnum::WrapAroundNavigation => self.wrap_around_navigation.to_string(),
             ConfigEnum::Keybindings => {
                 // This should never be called
                 debug!("Keybindings should not be called from get_value_as_str");
@@ -1476,10 +3436,37 @@ impl AppConfig {
             }
             ConfigEnum::NoOfBoardsToShow => self.no_of_boards_to_show.to_string(),
             ConfigEnum::NoOfCardsToShow => self.no_of_cards_to_show.to_string(),
+            ConfigEnum::CardPreviewLines => self.card_preview_lines.to_string(),
+            ConfigEnum::CardDensity => self.card_density.to_string(),
+            ConfigEnum::CardAgingDays => self.card_aging_days.to_string(),
+            ConfigEnum::AutoArchiveCompletedDays => self.auto_archive_completed_days.to_string(),
+            ConfigEnum::SaveFilesToKeep => self.save_files_to_keep.to_string(),
+            ConfigEnum::CardAgingCue => self.card_aging_cue.to_string(),
+            ConfigEnum::ColorDepth => self.color_depth.to_string(),
+            ConfigEnum::CardFaceLayout => CardFaceToken::layout_to_string(&self.card_face_layout),
+            ConfigEnum::RapidCardEntry => self.rapid_card_entry.to_string(),
+            ConfigEnum::CheckForDuplicateCardNames => {
+                self.check_for_duplicate_card_names.to_string()
+            }
+            ConfigEnum::NormalizeUnicodeInput => self.normalize_unicode_input.to_string(),
+            ConfigEnum::KeepIdsOnImport => self.keep_ids_on_import.to_string(),
+            ConfigEnum::EnableDesktopNotifications => self.enable_desktop_notifications.to_string(),
+            ConfigEnum::HighContrastMode => self.high_contrast_mode.to_string(),
             ConfigEnum::DatePickerCalenderFormat => self.date_picker_calender_format.to_string(),
             ConfigEnum::SaveDirectory => self.save_directory.to_string_lossy().to_string(),
+            ConfigEnum::MirrorDirectory => self
+                .mirror_directory
+                .as_ref()
+                .map(|path| path.to_string_lossy().to_string())
+                .unwrap_or_default(),
             ConfigEnum::SaveOnExit => self.save_on_exit.to_string(),
+            ConfigEnum::AutoSaveInterval => self
+                .auto_save_interval_seconds
+                .map(|seconds| seconds.to_string())
+                .unwrap_or_default(),
+            ConfigEnum::LogFilters => self.log_filters.clone(),
             ConfigEnum::ShowLineNumbers => self.show_line_numbers.to_string(),
+            ConfigEnum::ShowChecklistProgress => self.show_checklist_progress.to_string(),
             ConfigEnum::Tickrate => self.tickrate.to_string(),
             ConfigEnum::WarningDelta => self.warning_delta.to_string(),
         }
@@ -1492,12 +3479,28 @@ impl AppConfig {
             ConfigEnum::DisableAnimations => (!self.disable_animations).to_string(),
             ConfigEnum::DisableScrollBar => (!self.disable_scroll_bar).to_string(),
             ConfigEnum::EnableMouseSupport => (!self.enable_mouse_support).to_string(),
+            ConfigEnum::EnableEventLog => (!self.enable_event_log).to_string(),
+            ConfigEnum::WrapAroundNavigation => (!self.wrap_around_navigation).to_string(),
             ConfigEnum::SaveOnExit => (!self.save_on_exit).to_string(),
             ConfigEnum::ShowLineNumbers => (!self.show_line_numbers).to_string(),
+            ConfigEnum::ShowChecklistProgress => (!self.show_checklist_progress).to_string(),
+            ConfigEnum::RapidCardEntry => (!self.rapid_card_entry).to_string(),
+            ConfigEnum::CheckForDuplicateCardNames => {
+                (!self.check_for_duplicate_card_names).to_string()
+            }
+            ConfigEnum::NormalizeUnicodeInput => (!self.normalize_unicode_input).to_string(),
+            ConfigEnum::KeepIdsOnImport => (!self.keep_ids_on_import).to_string(),
+            ConfigEnum::EnableDesktopNotifications => {
+                (!self.enable_desktop_notifications).to_string()
+            }
+            ConfigEnum::HighContrastMode => (!self.high_contrast_mode).to_string(),
             ConfigEnum::DatePickerCalenderFormat => match self.date_picker_calender_format {
                 CalenderType::MondayFirst => CalenderType::SundayFirst.to_string(),
                 CalenderType::SundayFirst => CalenderType::MondayFirst.to_string(),
             },
+            ConfigEnum::CardDensity => self.card_density.next().to_string(),
+            ConfigEnum::CardAgingCue => self.card_aging_cue.next().to_string(),
+            ConfigEnum::ColorDepth => self.color_depth.next().to_string(),
             _ => {
                 debug!("Invalid config enum to toggle: {}", config_enum);
                 "".to_string()
@@ -1505,16 +3508,50 @@ impl AppConfig {
         }
     }
 
+    /// Current numeric value moved one step up or down, clamped to the allowed range
+    pub fn get_stepped_value_as_string(
+        &self,
+        config_enum: ConfigEnum,
+        increment: bool,
+    ) -> Option<String> {
+        let (min_value, max_value) = config_enum.value_range()?;
+        let current_value = self.get_value_as_string(config_enum).parse::<u16>().ok()?;
+        let stepped_value = if increment {
+            current_value.saturating_add(1)
+        } else {
+            current_value.saturating_sub(1)
+        };
+        Some(stepped_value.clamp(min_value, max_value).to_string())
+    }
+
     pub fn edit_config(app: &mut App, config_enum: ConfigEnum, edited_value: &str) {
         let mut config_copy = app.config.clone();
         let result = config_enum.edit_config(&mut config_copy, edited_value);
         if result.is_ok() {
-            let write_status = data_handler::write_config(&config_copy);
+            let write_status = app.write_config(&config_copy);
             if write_status.is_ok() {
                 app.config = config_copy;
-                app.send_info_toast("Config updated", None);
+                if config_enum == ConfigEnum::Language {
+                    load_language(&app.config.language);
+                }
+                if config_enum == ConfigEnum::NormalizeUnicodeInput {
+                    set_unicode_normalization(app.config.normalize_unicode_input);
+                }
+                if config_enum == ConfigEnum::LogFilters {
+                    app.apply_log_filters();
+                }
+                if config_enum == ConfigEnum::CardDensity {
+                    refresh_visible_cards_for_all_boards(app);
+                }
+                if config_enum == ConfigEnum::HighContrastMode {
+                    let default_theme = app.config.default_theme.clone();
+                    if let Some(theme) = app.all_themes.iter().find(|t| t.name == default_theme) {
+                        app.current_theme = theme.clone();
+                    }
+                }
+                app.send_info_toast(&tr(MessageId::ToastConfigUpdated), None);
             } else {
-                app.send_error_toast("Could not write to config file", None);
+                app.send_error_toast(&tr(MessageId::ToastCouldNotWriteConfig), None);
             }
         } else {
             let error_message = format!("Could not edit config: {}", result.unwrap_err());
@@ -1523,6 +3560,26 @@ impl AppConfig {
         }
     }
 
+    /// Applies or reverts the high contrast preset, the overridden settings are kept so
+    /// turning the preset off puts them back as they were
+    pub fn set_high_contrast_mode(&mut self, enabled: bool) {
+        if enabled == self.high_contrast_mode {
+            return;
+        }
+        if enabled {
+            self.settings_before_high_contrast = Some(HighContrastRestorePoint {
+                default_theme: self.default_theme.clone(),
+                disable_animations: self.disable_animations,
+            });
+            self.default_theme = HIGH_CONTRAST_THEME_NAME.to_string();
+            self.disable_animations = true;
+        } else if let Some(restore_point) = self.settings_before_high_contrast.take() {
+            self.default_theme = restore_point.default_theme;
+            self.disable_animations = restore_point.disable_animations;
+        }
+        self.high_contrast_mode = enabled;
+    }
+
     pub fn edit_keybinding(
         &mut self,
         key_index: usize,
@@ -1572,6 +3629,12 @@ impl AppConfig {
             KeyBindingEnum::ChangeCardStatusToStale => {
                 self.keybindings.change_card_status_to_stale = value.to_vec();
             }
+            KeyBindingEnum::ArchiveCard => {
+                self.keybindings.archive_card = value.to_vec();
+            }
+            KeyBindingEnum::ArchiveBoard => {
+                self.keybindings.archive_board = value.to_vec();
+            }
             KeyBindingEnum::ChangeCardPriorityToHigh => {
                 self.keybindings.change_card_priority_to_high = value.to_vec();
             }
@@ -1593,18 +3656,48 @@ impl AppConfig {
             KeyBindingEnum::Down => {
                 self.keybindings.down = value.to_vec();
             }
+            KeyBindingEnum::DuplicateBoard => {
+                self.keybindings.duplicate_board = value.to_vec();
+            }
+            KeyBindingEnum::DuplicateCard => {
+                self.keybindings.duplicate_card = value.to_vec();
+            }
+            KeyBindingEnum::EditBoard => {
+                self.keybindings.edit_board = value.to_vec();
+            }
+            KeyBindingEnum::ExportToMarkdown => {
+                self.keybindings.export_to_markdown = value.to_vec();
+            }
+            KeyBindingEnum::FilterByDateRange => {
+                self.keybindings.filter_by_date_range = value.to_vec();
+            }
+            KeyBindingEnum::FilterByPriority => {
+                self.keybindings.filter_by_priority = value.to_vec();
+            }
+            KeyBindingEnum::FilterByStatus => {
+                self.keybindings.filter_by_status = value.to_vec();
+            }
             KeyBindingEnum::GoToMainMenu => {
                 self.keybindings.go_to_main_menu = value.to_vec();
             }
             KeyBindingEnum::GoToPreviousViewOrCancel => {
                 self.keybindings.go_to_previous_view_or_cancel = value.to_vec();
             }
+            KeyBindingEnum::GrowSecondaryPane => {
+                self.keybindings.grow_secondary_pane = value.to_vec();
+            }
             KeyBindingEnum::HideUiElement => {
                 self.keybindings.hide_ui_element = value.to_vec();
             }
             KeyBindingEnum::Left => {
                 self.keybindings.left = value.to_vec();
             }
+            KeyBindingEnum::MoveBoardLeft => {
+                self.keybindings.move_board_left = value.to_vec();
+            }
+            KeyBindingEnum::MoveBoardRight => {
+                self.keybindings.move_board_right = value.to_vec();
+            }
             KeyBindingEnum::MoveCardDown => {
                 self.keybindings.move_card_down = value.to_vec();
             }
@@ -1614,6 +3707,15 @@ impl AppConfig {
             KeyBindingEnum::MoveCardRight => {
                 self.keybindings.move_card_right = value.to_vec();
             }
+            KeyBindingEnum::MoveCardToBoard => {
+                self.keybindings.move_card_to_board = value.to_vec();
+            }
+            KeyBindingEnum::MoveCardToBottom => {
+                self.keybindings.move_card_to_bottom = value.to_vec();
+            }
+            KeyBindingEnum::MoveCardToTop => {
+                self.keybindings.move_card_to_top = value.to_vec();
+            }
             KeyBindingEnum::MoveCardUp => {
                 self.keybindings.move_card_up = value.to_vec();
             }
@@ -1647,18 +3749,60 @@ impl AppConfig {
             KeyBindingEnum::SaveState => {
                 self.keybindings.save_state = value.to_vec();
             }
+            KeyBindingEnum::SelectCardRangeDown => {
+                self.keybindings.select_card_range_down = value.to_vec();
+            }
+            KeyBindingEnum::SelectCardRangeUp => {
+                self.keybindings.select_card_range_up = value.to_vec();
+            }
+            KeyBindingEnum::SetCardHighlightColor => {
+                self.keybindings.set_card_highlight_color = value.to_vec();
+            }
+            KeyBindingEnum::ShrinkSecondaryPane => {
+                self.keybindings.shrink_secondary_pane = value.to_vec();
+            }
+            KeyBindingEnum::ShowBoardStats => {
+                self.keybindings.show_board_stats = value.to_vec();
+            }
+            KeyBindingEnum::SkipTutorialStep => {
+                self.keybindings.skip_tutorial_step = value.to_vec();
+            }
+            KeyBindingEnum::SortCards => {
+                self.keybindings.sort_cards = value.to_vec();
+            }
+            KeyBindingEnum::StartHistoryStepCount => {
+                self.keybindings.start_history_step_count = value.to_vec();
+            }
             KeyBindingEnum::StopUserInput => {
                 self.keybindings.stop_user_input = value.to_vec();
             }
             KeyBindingEnum::TakeUserInput => {
                 self.keybindings.take_user_input = value.to_vec();
             }
+            KeyBindingEnum::ToggleCardSelection => {
+                self.keybindings.toggle_card_selection = value.to_vec();
+            }
             KeyBindingEnum::ToggleCommandPalette => {
                 self.keybindings.toggle_command_palette = value.to_vec();
             }
+            KeyBindingEnum::ToggleCompletedCardsVisibility => {
+                self.keybindings.toggle_completed_cards_visibility = value.to_vec();
+            }
+            KeyBindingEnum::ToggleArchivedView => {
+                self.keybindings.toggle_archived_view = value.to_vec();
+            }
+            KeyBindingEnum::ToggleFocusTimer => {
+                self.keybindings.toggle_focus_timer = value.to_vec();
+            }
+            KeyBindingEnum::ToggleNotifications => {
+                self.keybindings.toggle_notifications = value.to_vec();
+            }
             KeyBindingEnum::Undo => {
                 self.keybindings.undo = value.to_vec();
             }
+            KeyBindingEnum::UndoFromToast => {
+                self.keybindings.undo_from_toast = value.to_vec();
+            }
             KeyBindingEnum::Up => {
                 self.keybindings.up = value.to_vec();
             }
@@ -1755,7 +3899,16 @@ impl AppConfig {
                         }
                     }
                     if keybindings.is_empty() {
-                        Self::handle_invalid_keybinding(key);
+                        // Actions that are unbound by default are written back as an empty list
+                        let unbound_by_default = serde_json::to_value(KeyBindings::default())
+                            .ok()
+                            .and_then(|default_keybindings| {
+                                default_keybindings[key].as_array().map(Vec::is_empty)
+                            })
+                            .unwrap_or_default();
+                        if !value_array.is_empty() || !unbound_by_default {
+                            Self::handle_invalid_keybinding(key);
+                        }
                     } else {
                         default_keybindings.edit_keybinding(key, keybindings);
                     }
@@ -1797,6 +3950,51 @@ impl AppConfig {
                     default_config.save_directory
                 }
             };
+        let mirror_directory =
+            match serde_json_object[ConfigEnum::MirrorDirectory.to_json_key()].as_str() {
+                Some(path) if !path.is_empty() => {
+                    let path = PathBuf::from(path);
+                    if path.is_dir() {
+                        Some(path)
+                    } else {
+                        error!(
+                            "Invalid path: {}, Disabling the markdown mirror",
+                            path.to_string_lossy()
+                        );
+                        None
+                    }
+                }
+                _ => None,
+            };
+        let auto_save_interval_seconds =
+            match serde_json_object[ConfigEnum::AutoSaveInterval.to_json_key()].as_u64() {
+                Some(0) | None => None,
+                Some(seconds) if seconds < MIN_AUTO_SAVE_INTERVAL_SECONDS => {
+                    error!(
+                        "Invalid value: {} for {}, It must be at least {}, Disabling auto save",
+                        seconds,
+                        ConfigEnum::AutoSaveInterval.to_json_key(),
+                        MIN_AUTO_SAVE_INTERVAL_SECONDS
+                    );
+                    None
+                }
+                Some(seconds) => Some(seconds),
+            };
+        let log_filters = match serde_json_object[ConfigEnum::LogFilters.to_json_key()].as_str() {
+            Some(log_filters) => match parse_log_filters(log_filters) {
+                Ok(_) => log_filters.to_string(),
+                Err(e) => {
+                    error!(
+                        "Invalid value: {} for {}, {}, Logging every module at the default level",
+                        log_filters,
+                        ConfigEnum::LogFilters.to_json_key(),
+                        e
+                    );
+                    String::new()
+                }
+            },
+            None => String::new(),
+        };
         let default_view = match serde_json_object[ConfigEnum::DefaultView.to_json_key()].as_str() {
             Some(view) => {
                 let view = View::from_str(view);
@@ -1838,15 +4036,69 @@ impl AppConfig {
             ConfigEnum::ShowLineNumbers,
             default_config.show_line_numbers,
         );
+        let show_checklist_progress = AppConfig::get_bool_or_default(
+            &serde_json_object,
+            ConfigEnum::ShowChecklistProgress,
+            default_config.show_checklist_progress,
+        );
         let disable_animations = AppConfig::get_bool_or_default(
             &serde_json_object,
             ConfigEnum::DisableAnimations,
             default_config.disable_animations,
         );
-        let enable_mouse_support = AppConfig::get_bool_or_default(
+        let enable_mouse_support = AppConfig::get_bool_or_default(
+            &serde_json_object,
+            ConfigEnum::EnableMouseSupport,
+            default_config.enable_mouse_support,
+        );
+        let rapid_card_entry = AppConfig::get_bool_or_default(
+            &serde_json_object,
+            ConfigEnum::RapidCardEntry,
+            default_config.rapid_card_entry,
+        );
+        let check_for_duplicate_card_names = AppConfig::get_bool_or_default(
+            &serde_json_object,
+            ConfigEnum::CheckForDuplicateCardNames,
+            default_config.check_for_duplicate_card_names,
+        );
+        let high_contrast_mode = AppConfig::get_bool_or_default(
+            &serde_json_object,
+            ConfigEnum::HighContrastMode,
+            default_config.high_contrast_mode,
+        );
+        let settings_before_high_contrast =
+            serde_json::from_value::<Option<HighContrastRestorePoint>>(
+                serde_json_object["settings_before_high_contrast"].clone(),
+            )
+            .unwrap_or_else(|e| {
+                error!("Invalid high contrast restore point, ignoring it");
+                debug!("Error: {}", e);
+                None
+            });
+        let enable_event_log = AppConfig::get_bool_or_default(
+            &serde_json_object,
+            ConfigEnum::EnableEventLog,
+            default_config.enable_event_log,
+        );
+        let wrap_around_navigation = AppConfig::get_bool_or_default(
+            &serde_json_object,
+            ConfigEnum::WrapAroundNavigation,
+            default_config.wrap_around_navigation,
+        );
+        let normalize_unicode_input = AppConfig::get_bool_or_default(
+            &serde_json_object,
+            ConfigEnum::NormalizeUnicodeInput,
+            default_config.normalize_unicode_input,
+        );
+        let keep_ids_on_import = AppConfig::get_bool_or_default(
+            &serde_json_object,
+            ConfigEnum::KeepIdsOnImport,
+            default_config.keep_ids_on_import,
+        );
+        let enable_desktop_notifications = AppConfig::get_bool_or_default(
             &serde_json_object,
-            ConfigEnum::EnableMouseSupport,
-            default_config.enable_mouse_support,
+            ConfigEnum::EnableDesktopNotifications,
+            default_config.enable_desktop_notifications,
         );
         let warning_delta = AppConfig::get_u16_or_default(
             &serde_json_object,
@@ -1869,6 +4121,34 @@ impl AppConfig {
             Some(MIN_NO_CARDS_PER_BOARD),
             Some(MAX_NO_CARDS_PER_BOARD),
         );
+        let card_preview_lines = AppConfig::get_u16_or_default(
+            &serde_json_object,
+            ConfigEnum::CardPreviewLines,
+            default_config.card_preview_lines,
+            Some(MIN_CARD_PREVIEW_LINES),
+            Some(MAX_CARD_PREVIEW_LINES),
+        );
+        let card_aging_days = AppConfig::get_u16_or_default(
+            &serde_json_object,
+            ConfigEnum::CardAgingDays,
+            default_config.card_aging_days,
+            Some(MIN_CARD_AGING_DAYS),
+            Some(MAX_CARD_AGING_DAYS),
+        );
+        let auto_archive_completed_days = AppConfig::get_u16_or_default(
+            &serde_json_object,
+            ConfigEnum::AutoArchiveCompletedDays,
+            default_config.auto_archive_completed_days,
+            Some(MIN_AUTO_ARCHIVE_COMPLETED_DAYS),
+            Some(MAX_AUTO_ARCHIVE_COMPLETED_DAYS),
+        );
+        let save_files_to_keep = AppConfig::get_u16_or_default(
+            &serde_json_object,
+            ConfigEnum::SaveFilesToKeep,
+            default_config.save_files_to_keep,
+            Some(MIN_SAVE_FILES_TO_KEEP),
+            Some(MAX_SAVE_FILES_TO_KEEP),
+        );
         let no_of_boards_to_show = AppConfig::get_u16_or_default(
             &serde_json_object,
             ConfigEnum::NoOfBoardsToShow,
@@ -1884,6 +4164,13 @@ impl AppConfig {
                 default_config.default_theme
             }
         };
+        let language = match serde_json_object[ConfigEnum::Language.to_json_key()].as_str() {
+            Some(language) => language.to_string(),
+            None => {
+                error!("Language is not a string, Resetting to default language");
+                default_config.language
+            }
+        };
         let date_format = match serde_json_object[ConfigEnum::DateFormat.to_json_key()].as_str() {
             Some(date_format) => match DateTimeFormat::from_str(date_format) {
                 Ok(date_format) => date_format,
@@ -1919,8 +4206,60 @@ impl AppConfig {
                     CalenderType::default()
                 }
             };
+        let card_density = match serde_json_object[ConfigEnum::CardDensity.to_json_key()].as_str() {
+            Some(card_density) => CardDensity::from_str(card_density).unwrap_or_else(|_| {
+                error!(
+                    "Invalid card density: {}, Resetting to default card density",
+                    card_density
+                );
+                CardDensity::default()
+            }),
+            None => CardDensity::default(),
+        };
+        let card_aging_cue = match serde_json_object[ConfigEnum::CardAgingCue.to_json_key()]
+            .as_str()
+        {
+            Some(card_aging_cue) => CardAgingCue::from_str(card_aging_cue).unwrap_or_else(|_| {
+                error!(
+                    "Invalid card aging cue: {}, Resetting to default card aging cue",
+                    card_aging_cue
+                );
+                CardAgingCue::default()
+            }),
+            None => CardAgingCue::default(),
+        };
+        let color_depth = match serde_json_object[ConfigEnum::ColorDepth.to_json_key()].as_str() {
+            Some(color_depth) => ColorDepth::from_str(color_depth).unwrap_or_else(|_| {
+                error!(
+                    "Invalid color depth: {}, Resetting to default color depth",
+                    color_depth
+                );
+                ColorDepth::default()
+            }),
+            None => ColorDepth::default(),
+        };
+        let card_face_layout =
+            match serde_json_object[ConfigEnum::CardFaceLayout.to_json_key()].as_array() {
+                Some(tokens) => {
+                    let tokens = tokens
+                        .iter()
+                        .map(|token| token.as_str().map_or(token.to_string(), str::to_string))
+                        .collect::<Vec<String>>();
+                    CardFaceToken::parse_layout(&tokens).unwrap_or_else(|e| {
+                        error!(
+                            "Invalid card face layout: {}, Resetting to default card face layout",
+                            e
+                        );
+                        CardFaceToken::default_layout()
+                    })
+                }
+                None => CardFaceToken::default_layout(),
+            };
         Ok(Self {
             save_directory,
+            mirror_directory,
+            auto_save_interval_seconds,
+            log_filters,
             default_view,
             always_load_last_save,
             save_on_exit,
@@ -1930,17 +4269,65 @@ impl AppConfig {
             keybindings,
             tickrate,
             no_of_cards_to_show,
+            card_preview_lines,
+            card_density,
+            card_aging_days,
+            card_aging_cue,
+            color_depth,
+            auto_archive_completed_days,
+            card_face_layout,
             no_of_boards_to_show,
+            save_files_to_keep,
             date_picker_calender_format,
             enable_mouse_support,
+            enable_event_log,
+            wrap_around_navigation,
             default_theme,
+            language,
             date_time_format: date_format,
             show_line_numbers,
+            show_checklist_progress,
             disable_animations,
+            rapid_card_entry,
+            check_for_duplicate_card_names,
+            normalize_unicode_input,
+            keep_ids_on_import,
+            enable_desktop_notifications,
+            high_contrast_mode,
+            settings_before_high_contrast,
+            last_seen_version: serde_json_object["last_seen_version"]
+                .as_str()
+                .map(|version| version.to_string()),
+            secondary_pane_height: serde_json_object["secondary_pane_height"]
+                .as_u64()
+                .and_then(|height| u16::try_from(height).ok())
+                .map(|height| height.max(MIN_SECONDARY_PANE_HEIGHT)),
         })
     }
 }
 
+/// How a row of the config menu is edited
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum ConfigEditMode {
+    Toggle,
+    Selector,
+    Number,
+    Text,
+    Keybindings,
+}
+
+impl fmt::Display for ConfigEditMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ConfigEditMode::Toggle => write!(f, "Enter to toggle"),
+            ConfigEditMode::Selector => write!(f, "Enter to pick"),
+            ConfigEditMode::Number => write!(f, "+/- to step, Enter to type"),
+            ConfigEditMode::Text => write!(f, "Enter to type"),
+            ConfigEditMode::Keybindings => write!(f, "Enter to open"),
+        }
+    }
+}
+
 #[derive(PartialEq, Copy, Clone, EnumIter)]
 pub enum ConfigEnum {
     AlwaysLoadLastSave,
@@ -1951,13 +4338,34 @@ pub enum ConfigEnum {
     DisableAnimations,
     DisableScrollBar,
     EnableMouseSupport,
+    EnableEventLog,
+    WrapAroundNavigation,
     Keybindings,
+    Language,
     NoOfBoardsToShow,
     NoOfCardsToShow,
+    CardPreviewLines,
+    CardDensity,
+    CardAgingDays,
+    CardAgingCue,
+    ColorDepth,
+    AutoArchiveCompletedDays,
+    CardFaceLayout,
+    RapidCardEntry,
+    CheckForDuplicateCardNames,
+    NormalizeUnicodeInput,
+    KeepIdsOnImport,
+    EnableDesktopNotifications,
+    HighContrastMode,
     DatePickerCalenderFormat,
     SaveDirectory,
+    MirrorDirectory,
     SaveOnExit,
+    SaveFilesToKeep,
+    AutoSaveInterval,
+    LogFilters,
     ShowLineNumbers,
+    ShowChecklistProgress,
     Tickrate,
     WarningDelta,
 }
@@ -1973,13 +4381,34 @@ impl fmt::Display for ConfigEnum {
             ConfigEnum::DisableAnimations => write!(f, "Disable Animations"),
             ConfigEnum::DisableScrollBar => write!(f, "Disable Scroll Bar"),
             ConfigEnum::EnableMouseSupport => write!(f, "Enable Mouse Support"),
+            ConfigEnum::EnableEventLog => write!(f, "Enable Event Log"),
+            ConfigEnum::WrapAroundNavigation => write!(f, "Wrap Around Navigation"),
             ConfigEnum::Keybindings => write!(f, "Edit Keybindings"),
+            ConfigEnum::Language => write!(f, "Language"),
             ConfigEnum::NoOfBoardsToShow => write!(f, "Number of Boards to Show"),
             ConfigEnum::NoOfCardsToShow => write!(f, "Number of Cards to Show"),
+            ConfigEnum::CardPreviewLines => write!(f, "Card Preview Lines"),
+            ConfigEnum::CardDensity => write!(f, "Card Density"),
+            ConfigEnum::CardAgingDays => write!(f, "Card Aging Days"),
+            ConfigEnum::AutoArchiveCompletedDays => write!(f, "Auto Archive Completed Days"),
+            ConfigEnum::CardAgingCue => write!(f, "Card Aging Cue"),
+            ConfigEnum::ColorDepth => write!(f, "Color Depth"),
+            ConfigEnum::CardFaceLayout => write!(f, "Card Face Layout"),
+            ConfigEnum::RapidCardEntry => write!(f, "Rapid Card Entry"),
+            ConfigEnum::CheckForDuplicateCardNames => write!(f, "Check For Duplicate Card Names"),
+            ConfigEnum::NormalizeUnicodeInput => write!(f, "Normalize Unicode Input"),
+            ConfigEnum::KeepIdsOnImport => write!(f, "Keep Ids On Import"),
+            ConfigEnum::EnableDesktopNotifications => write!(f, "Enable Desktop Notifications"),
+            ConfigEnum::HighContrastMode => write!(f, "High Contrast Mode"),
             ConfigEnum::DatePickerCalenderFormat => write!(f, "Date Picker Calender Format"),
             ConfigEnum::SaveDirectory => write!(f, "Save Directory"),
+            ConfigEnum::MirrorDirectory => write!(f, "Markdown Mirror Directory"),
             ConfigEnum::SaveOnExit => write!(f, "Auto Save on Exit"),
+            ConfigEnum::SaveFilesToKeep => write!(f, "Save Files to Keep"),
+            ConfigEnum::AutoSaveInterval => write!(f, "Auto Save Interval (seconds)"),
+            ConfigEnum::LogFilters => write!(f, "Log Filters"),
             ConfigEnum::ShowLineNumbers => write!(f, "Show Line Numbers"),
+            ConfigEnum::ShowChecklistProgress => write!(f, "Show Checklist Progress"),
             ConfigEnum::Tickrate => write!(f, "Tickrate"),
             ConfigEnum::WarningDelta => write!(f, "Number of Days to Warn Before Due Date"),
         }
@@ -1993,43 +4422,214 @@ impl FromStr for ConfigEnum {
             "Auto Load Last Save" => Ok(ConfigEnum::AlwaysLoadLastSave),
             "Auto Login" => Ok(ConfigEnum::AutoLogin),
             "Auto Save on Exit" => Ok(ConfigEnum::SaveOnExit),
+            "Auto Save Interval (seconds)" => Ok(ConfigEnum::AutoSaveInterval),
             "Date Format" => Ok(ConfigEnum::DateFormat),
             "Default Theme" => Ok(ConfigEnum::DefaultTheme),
+            "Language" => Ok(ConfigEnum::Language),
             "Disable Animations" => Ok(ConfigEnum::DisableAnimations),
             "Disable Scroll Bar" => Ok(ConfigEnum::DisableScrollBar),
             "Edit Keybindings" => Ok(ConfigEnum::Keybindings),
             "Enable Mouse Support" => Ok(ConfigEnum::EnableMouseSupport),
             "Number of Boards to Show" => Ok(ConfigEnum::NoOfBoardsToShow),
             "Number of Cards to Show" => Ok(ConfigEnum::NoOfCardsToShow),
+            "Card Preview Lines" => Ok(ConfigEnum::CardPreviewLines),
+            "Card Density" => Ok(ConfigEnum::CardDensity),
+            "Card Aging Days" => Ok(ConfigEnum::CardAgingDays),
+            "Auto Archive Completed Days" => Ok(ConfigEnum::AutoArchiveCompletedDays),
+            "Card Aging Cue" => Ok(ConfigEnum::CardAgingCue),
+            "Color Depth" => Ok(ConfigEnum::ColorDepth),
+            "Card Face Layout" => Ok(ConfigEnum::CardFaceLayout),
             "Date Picker Calender Format" => Ok(ConfigEnum::DatePickerCalenderFormat),
             "Number of Days to Warn Before Due Date" => Ok(ConfigEnum::WarningDelta),
             "Save Directory" => Ok(ConfigEnum::SaveDirectory),
+            "Save Files to Keep" => Ok(ConfigEnum::SaveFilesToKeep),
+            "Markdown Mirror Directory" => Ok(ConfigEnum::MirrorDirectory),
             "Select Default View" => Ok(ConfigEnum::DefaultView),
             "Show Line Numbers" => Ok(ConfigEnum::ShowLineNumbers),
+            "Show Checklist Progress" => Ok(ConfigEnum::ShowChecklistProgress),
             "Tickrate" => Ok(ConfigEnum::Tickrate),
+            "Rapid Card Entry" => Ok(ConfigEnum::RapidCardEntry),
+            "Check For Duplicate Card Names" => Ok(ConfigEnum::CheckForDuplicateCardNames),
+            "High Contrast Mode" => Ok(ConfigEnum::HighContrastMode),
+            "Enable Event Log" => Ok(ConfigEnum::EnableEventLog),
+            "Log Filters" => Ok(ConfigEnum::LogFilters),
+            "Wrap Around Navigation" => Ok(ConfigEnum::WrapAroundNavigation),
+            "Normalize Unicode Input" => Ok(ConfigEnum::NormalizeUnicodeInput),
+            "Keep Ids On Import" => Ok(ConfigEnum::KeepIdsOnImport),
+            "Enable Desktop Notifications" => Ok(ConfigEnum::EnableDesktopNotifications),
             _ => Err(format!("Invalid ConfigEnum: {}", s)),
         }
     }
 }
 
 impl ConfigEnum {
+    /// Used for the rendered name, Display is kept stable for matching config rows
+    pub fn message_id(&self) -> MessageId {
+        match self {
+            ConfigEnum::AlwaysLoadLastSave => MessageId::ConfigAlwaysLoadLastSave,
+            ConfigEnum::AutoLogin => MessageId::ConfigAutoLogin,
+            ConfigEnum::DateFormat => MessageId::ConfigDateFormat,
+            ConfigEnum::DefaultTheme => MessageId::ConfigDefaultTheme,
+            ConfigEnum::DefaultView => MessageId::ConfigDefaultView,
+            ConfigEnum::DisableAnimations => MessageId::ConfigDisableAnimations,
+            ConfigEnum::DisableScrollBar => MessageId::ConfigDisableScrollBar,
+            ConfigEnum::EnableMouseSupport => MessageId::ConfigEnableMouseSupport,
+            ConfigEnum::EnableEventLog => MessageId::ConfigEnableEventLog,
+            ConfigEnum::WrapAroundNavigation => MessageId::ConfigWrapAroundNavigation,
+            ConfigEnum::Keybindings => MessageId::ConfigKeybindings,
+            ConfigEnum::Language => MessageId::ConfigLanguage,
+            ConfigEnum::NoOfBoardsToShow => MessageId::ConfigNoOfBoardsToShow,
+            ConfigEnum::NoOfCardsToShow => MessageId::ConfigNoOfCardsToShow,
+            ConfigEnum::CardPreviewLines => MessageId::ConfigCardPreviewLines,
+            ConfigEnum::CardDensity => MessageId::ConfigCardDensity,
+            ConfigEnum::CardAgingDays => MessageId::ConfigCardAgingDays,
+            ConfigEnum::AutoArchiveCompletedDays => MessageId::ConfigAutoArchiveCompletedDays,
+            ConfigEnum::CardAgingCue => MessageId::ConfigCardAgingCue,
+            ConfigEnum::ColorDepth => MessageId::ConfigColorDepth,
+            ConfigEnum::CardFaceLayout => MessageId::ConfigCardFaceLayout,
+            ConfigEnum::RapidCardEntry => MessageId::ConfigRapidCardEntry,
+            ConfigEnum::CheckForDuplicateCardNames => MessageId::ConfigCheckForDuplicateCardNames,
+            ConfigEnum::NormalizeUnicodeInput => MessageId::ConfigNormalizeUnicodeInput,
+            ConfigEnum::KeepIdsOnImport => MessageId::ConfigKeepIdsOnImport,
+            ConfigEnum::EnableDesktopNotifications => MessageId::ConfigEnableDesktopNotifications,
+            ConfigEnum::HighContrastMode => MessageId::ConfigHighContrastMode,
+            ConfigEnum::DatePickerCalenderFormat => MessageId::ConfigDatePickerCalenderFormat,
+            ConfigEnum::SaveDirectory => MessageId::ConfigSaveDirectory,
+            ConfigEnum::MirrorDirectory => MessageId::ConfigMirrorDirectory,
+            ConfigEnum::SaveOnExit => MessageId::ConfigSaveOnExit,
+            ConfigEnum::SaveFilesToKeep => MessageId::ConfigSaveFilesToKeep,
+            ConfigEnum::AutoSaveInterval => MessageId::ConfigAutoSaveInterval,
+            ConfigEnum::LogFilters => MessageId::ConfigLogFilters,
+            ConfigEnum::ShowLineNumbers => MessageId::ConfigShowLineNumbers,
+            ConfigEnum::ShowChecklistProgress => MessageId::ConfigShowChecklistProgress,
+            ConfigEnum::Tickrate => MessageId::ConfigTickrate,
+            ConfigEnum::WarningDelta => MessageId::ConfigWarningDelta,
+        }
+    }
+
+    pub fn edit_mode(&self) -> ConfigEditMode {
+        match self {
+            ConfigEnum::AlwaysLoadLastSave
+            | ConfigEnum::AutoLogin
+            | ConfigEnum::DisableAnimations
+            | ConfigEnum::DisableScrollBar
+            | ConfigEnum::EnableMouseSupport
+            | ConfigEnum::EnableEventLog
+            | ConfigEnum::WrapAroundNavigation
+            | ConfigEnum::SaveOnExit
+            | ConfigEnum::ShowLineNumbers
+            | ConfigEnum::ShowChecklistProgress
+            | ConfigEnum::RapidCardEntry
+            | ConfigEnum::CheckForDuplicateCardNames
+            | ConfigEnum::NormalizeUnicodeInput
+            | ConfigEnum::KeepIdsOnImport
+            | ConfigEnum::EnableDesktopNotifications
+            | ConfigEnum::HighContrastMode
+            | ConfigEnum::DatePickerCalenderFormat
+            | ConfigEnum::CardDensity
+            | ConfigEnum::CardAgingCue
+            | ConfigEnum::ColorDepth => ConfigEditMode::Toggle,
+            ConfigEnum::DefaultView | ConfigEnum::DateFormat | ConfigEnum::DefaultTheme => {
+                ConfigEditMode::Selector
+            }
+            ConfigEnum::NoOfBoardsToShow
+            | ConfigEnum::NoOfCardsToShow
+            | ConfigEnum::CardPreviewLines
+            | ConfigEnum::CardAgingDays
+            | ConfigEnum::AutoArchiveCompletedDays
+            | ConfigEnum::SaveFilesToKeep
+            | ConfigEnum::Tickrate
+            | ConfigEnum::WarningDelta => ConfigEditMode::Number,
+            ConfigEnum::SaveDirectory
+            | ConfigEnum::MirrorDirectory
+            | ConfigEnum::AutoSaveInterval
+            | ConfigEnum::LogFilters
+            | ConfigEnum::Language
+            | ConfigEnum::CardFaceLayout => ConfigEditMode::Text,
+            ConfigEnum::Keybindings => ConfigEditMode::Keybindings,
+        }
+    }
+
+    /// Inclusive (min, max) for the numeric config values
+    pub fn value_range(&self) -> Option<(u16, u16)> {
+        match self {
+            ConfigEnum::WarningDelta => {
+                Some((MIN_WARNING_DUE_DATE_DAYS, MAX_WARNING_DUE_DATE_DAYS))
+            }
+            ConfigEnum::Tickrate => Some((MIN_TICKRATE, MAX_TICKRATE)),
+            ConfigEnum::NoOfCardsToShow => Some((MIN_NO_CARDS_PER_BOARD, MAX_NO_CARDS_PER_BOARD)),
+            ConfigEnum::CardPreviewLines => Some((MIN_CARD_PREVIEW_LINES, MAX_CARD_PREVIEW_LINES)),
+            ConfigEnum::CardAgingDays => Some((MIN_CARD_AGING_DAYS, MAX_CARD_AGING_DAYS)),
+            ConfigEnum::AutoArchiveCompletedDays => Some((
+                MIN_AUTO_ARCHIVE_COMPLETED_DAYS,
+                MAX_AUTO_ARCHIVE_COMPLETED_DAYS,
+            )),
+            ConfigEnum::NoOfBoardsToShow => Some((MIN_NO_BOARDS_PER_PAGE, MAX_NO_BOARDS_PER_PAGE)),
+            ConfigEnum::SaveFilesToKeep => Some((MIN_SAVE_FILES_TO_KEEP, MAX_SAVE_FILES_TO_KEEP)),
+            _ => None,
+        }
+    }
+
+    /// Short name for typing "<alias> <value>" into the command palette, only settings with a
+    /// value range can be set this way
+    pub fn quick_entry_alias(&self) -> Option<&'static str> {
+        match self {
+            ConfigEnum::WarningDelta => Some("warning"),
+            ConfigEnum::Tickrate => Some("tickrate"),
+            ConfigEnum::NoOfCardsToShow => Some("cards"),
+            ConfigEnum::CardPreviewLines => Some("preview"),
+            ConfigEnum::CardAgingDays => Some("aging"),
+            ConfigEnum::AutoArchiveCompletedDays => Some("autoarchive"),
+            ConfigEnum::NoOfBoardsToShow => Some("boards"),
+            ConfigEnum::SaveFilesToKeep => Some("keepsaves"),
+            _ => None,
+        }
+    }
+
+    pub fn from_quick_entry_alias(alias: &str) -> Option<Self> {
+        ConfigEnum::iter().find(|config_enum| {
+            config_enum.value_range().is_some() && config_enum.quick_entry_alias() == Some(alias)
+        })
+    }
+
     pub fn to_json_key(&self) -> &str {
         match self {
             ConfigEnum::AlwaysLoadLastSave => "always_load_last_save",
             ConfigEnum::AutoLogin => "auto_login",
             ConfigEnum::DateFormat => "date_format",
             ConfigEnum::DefaultTheme => "default_theme",
+            ConfigEnum::Language => "language",
             ConfigEnum::DefaultView => "default_view",
             ConfigEnum::DisableAnimations => "disable_animations",
             ConfigEnum::DisableScrollBar => "disable_scroll_bar",
             ConfigEnum::EnableMouseSupport => "enable_mouse_support",
+            ConfigEnum::EnableEventLog => "enable_event_log",
+            ConfigEnum::WrapAroundNavigation => "wrap_around_navigation",
             ConfigEnum::Keybindings => "keybindings",
             ConfigEnum::NoOfBoardsToShow => "no_of_boards_to_show",
             ConfigEnum::NoOfCardsToShow => "no_of_cards_to_show",
+            ConfigEnum::CardPreviewLines => "card_preview_lines",
+            ConfigEnum::CardDensity => "card_density",
+            ConfigEnum::CardAgingDays => "card_aging_days",
+            ConfigEnum::AutoArchiveCompletedDays => "auto_archive_completed_days",
+            ConfigEnum::CardAgingCue => "card_aging_cue",
+            ConfigEnum::ColorDepth => "color_depth",
+            ConfigEnum::CardFaceLayout => "card_face_layout",
+            ConfigEnum::RapidCardEntry => "rapid_card_entry",
+            ConfigEnum::CheckForDuplicateCardNames => "check_for_duplicate_card_names",
+            ConfigEnum::NormalizeUnicodeInput => "normalize_unicode_input",
+            ConfigEnum::KeepIdsOnImport => "keep_ids_on_import",
+            ConfigEnum::EnableDesktopNotifications => "enable_desktop_notifications",
+            ConfigEnum::HighContrastMode => "high_contrast_mode",
             ConfigEnum::DatePickerCalenderFormat => "date_picker_calender_format",
             ConfigEnum::SaveDirectory => "save_directory",
+            ConfigEnum::MirrorDirectory => "mirror_directory",
             ConfigEnum::SaveOnExit => "save_on_exit",
+            ConfigEnum::SaveFilesToKeep => "save_files_to_keep",
+            ConfigEnum::AutoSaveInterval => "auto_save_interval_seconds",
+            ConfigEnum::LogFilters => "log_filters",
             ConfigEnum::ShowLineNumbers => "show_line_numbers",
+            ConfigEnum::ShowChecklistProgress => "show_checklist_progress",
             ConfigEnum::Tickrate => "tickrate",
             ConfigEnum::WarningDelta => "warning_delta",
         }
@@ -2045,6 +4645,28 @@ impl ConfigEnum {
                     Err(format!("Invalid path: {}", value))
                 }
             }
+            ConfigEnum::MirrorDirectory => {
+                // An empty value turns the mirror off
+                if value.is_empty() || PathBuf::from(value).is_dir() {
+                    Ok(())
+                } else {
+                    Err(format!("Invalid path: {}", value))
+                }
+            }
+            ConfigEnum::AutoSaveInterval => {
+                // Empty, 0 or None turn periodic saves off
+                if value.is_empty() || value == "0" || value.eq_ignore_ascii_case("none") {
+                    return Ok(());
+                }
+                match value.parse::<u64>() {
+                    Ok(seconds) if seconds >= MIN_AUTO_SAVE_INTERVAL_SECONDS => Ok(()),
+                    Ok(seconds) => Err(format!(
+                        "Invalid number: {}, It must be at least {} or 0 to turn auto save off",
+                        seconds, MIN_AUTO_SAVE_INTERVAL_SECONDS
+                    )),
+                    Err(_) => Err(format!("Invalid number: {}", value)),
+                }
+            }
             ConfigEnum::DefaultView => {
                 let view = View::from_string(value);
                 if view.is_some() {
@@ -2058,8 +4680,17 @@ impl ConfigEnum {
             | ConfigEnum::DisableAnimations
             | ConfigEnum::DisableScrollBar
             | ConfigEnum::EnableMouseSupport
+            | ConfigEnum::EnableEventLog
+            | ConfigEnum::WrapAroundNavigation
             | ConfigEnum::SaveOnExit
-            | ConfigEnum::ShowLineNumbers => {
+            | ConfigEnum::ShowLineNumbers
+            | ConfigEnum::ShowChecklistProgress
+            | ConfigEnum::RapidCardEntry
+            | ConfigEnum::CheckForDuplicateCardNames
+            | ConfigEnum::NormalizeUnicodeInput
+            | ConfigEnum::KeepIdsOnImport
+            | ConfigEnum::EnableDesktopNotifications
+            | ConfigEnum::HighContrastMode => {
                 let check = value.parse::<bool>();
                 if check.is_ok() {
                     Ok(())
@@ -2069,22 +4700,13 @@ impl ConfigEnum {
             }
             ConfigEnum::NoOfBoardsToShow
             | ConfigEnum::NoOfCardsToShow
+            | ConfigEnum::CardPreviewLines
+            | ConfigEnum::CardAgingDays
+            | ConfigEnum::AutoArchiveCompletedDays
+            | ConfigEnum::SaveFilesToKeep
             | ConfigEnum::Tickrate
             | ConfigEnum::WarningDelta => {
-                let min_value = match self {
-                    ConfigEnum::WarningDelta => MIN_WARNING_DUE_DATE_DAYS,
-                    ConfigEnum::Tickrate => MIN_TICKRATE,
-                    ConfigEnum::NoOfCardsToShow => MIN_NO_CARDS_PER_BOARD,
-                    ConfigEnum::NoOfBoardsToShow => MIN_NO_BOARDS_PER_PAGE,
-                    _ => 0,
-                };
-                let max_value = match self {
-                    ConfigEnum::WarningDelta => MAX_WARNING_DUE_DATE_DAYS,
-                    ConfigEnum::Tickrate => MAX_TICKRATE,
-                    ConfigEnum::NoOfCardsToShow => MAX_NO_CARDS_PER_BOARD,
-                    ConfigEnum::NoOfBoardsToShow => MAX_NO_BOARDS_PER_PAGE,
-                    _ => 0,
-                };
+                let (min_value, max_value) = self.value_range().unwrap_or((0, 0));
                 let check = value.parse::<u16>();
                 if check.is_ok() {
                     let value = check.unwrap();
@@ -2104,6 +4726,17 @@ impl ConfigEnum {
                 // TODO: check if theme exists
                 Ok(())
             }
+            ConfigEnum::Language => {
+                if !value.is_empty()
+                    && value
+                        .chars()
+                        .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+                {
+                    Ok(())
+                } else {
+                    Err(format!("Invalid Language: {}", value))
+                }
+            }
             ConfigEnum::DateFormat => {
                 let date_format = DateTimeFormat::from_human_readable_string(value);
                 if date_format.is_some() {
@@ -2120,6 +4753,29 @@ impl ConfigEnum {
                     Err(format!("Invalid CalenderFormat: {}", value))
                 }
             }
+            ConfigEnum::CardDensity => {
+                if CardDensity::from_str(value).is_ok() {
+                    Ok(())
+                } else {
+                    Err(format!("Invalid CardDensity: {}", value))
+                }
+            }
+            ConfigEnum::CardAgingCue => {
+                if CardAgingCue::from_str(value).is_ok() {
+                    Ok(())
+                } else {
+                    Err(format!("Invalid CardAgingCue: {}", value))
+                }
+            }
+            ConfigEnum::ColorDepth => {
+                if ColorDepth::from_str(value).is_ok() {
+                    Ok(())
+                } else {
+                    Err(format!("Invalid ColorDepth: {}", value))
+                }
+            }
+            ConfigEnum::CardFaceLayout => CardFaceToken::parse_layout_string(value).map(|_| ()),
+            ConfigEnum::LogFilters => parse_log_filters(value).map(|_| ()),
             ConfigEnum::Keybindings => {
                 debug!("Keybindings should not be called from validate_value");
                 // Keybindings are handled separately
@@ -2136,6 +4792,20 @@ impl ConfigEnum {
             ConfigEnum::SaveDirectory => {
                 config.save_directory = PathBuf::from(value);
             }
+            ConfigEnum::MirrorDirectory => {
+                config.mirror_directory = if value.is_empty() {
+                    None
+                } else {
+                    Some(PathBuf::from(value))
+                };
+            }
+            ConfigEnum::AutoSaveInterval => {
+                config.auto_save_interval_seconds =
+                    value.parse::<u64>().ok().filter(|seconds| *seconds > 0);
+            }
+            ConfigEnum::LogFilters => {
+                config.log_filters = log_filters_to_string(&parse_log_filters(value).unwrap());
+            }
             ConfigEnum::DefaultView => {
                 config.default_view = View::from_string(value).unwrap();
             }
@@ -2154,12 +4824,39 @@ impl ConfigEnum {
             ConfigEnum::ShowLineNumbers => {
                 config.show_line_numbers = value.parse::<bool>().unwrap();
             }
+            ConfigEnum::ShowChecklistProgress => {
+                config.show_checklist_progress = value.parse::<bool>().unwrap();
+            }
             ConfigEnum::DisableAnimations => {
                 config.disable_animations = value.parse::<bool>().unwrap();
             }
             ConfigEnum::EnableMouseSupport => {
                 config.enable_mouse_support = value.parse::<bool>().unwrap();
             }
+            ConfigEnum::RapidCardEntry => {
+                config.rapid_card_entry = value.parse::<bool>().unwrap();
+            }
+            ConfigEnum::CheckForDuplicateCardNames => {
+                config.check_for_duplicate_card_names = value.parse::<bool>().unwrap();
+            }
+            ConfigEnum::HighContrastMode => {
+                config.set_high_contrast_mode(value.parse::<bool>().unwrap());
+            }
+            ConfigEnum::EnableEventLog => {
+                config.enable_event_log = value.parse::<bool>().unwrap();
+            }
+            ConfigEnum::WrapAroundNavigation => {
+                config.wrap_around_navigation = value.parse::<bool>().unwrap();
+            }
+            ConfigEnum::NormalizeUnicodeInput => {
+                config.normalize_unicode_input = value.parse::<bool>().unwrap();
+            }
+            ConfigEnum::KeepIdsOnImport => {
+                config.keep_ids_on_import = value.parse::<bool>().unwrap();
+            }
+            ConfigEnum::EnableDesktopNotifications => {
+                config.enable_desktop_notifications = value.parse::<bool>().unwrap();
+            }
             ConfigEnum::WarningDelta => {
                 config.warning_delta = value.parse::<u16>().unwrap();
             }
@@ -2169,12 +4866,27 @@ impl ConfigEnum {
             ConfigEnum::NoOfCardsToShow => {
                 config.no_of_cards_to_show = value.parse::<u16>().unwrap();
             }
+            ConfigEnum::CardPreviewLines => {
+                config.card_preview_lines = value.parse::<u16>().unwrap();
+            }
+            ConfigEnum::CardAgingDays => {
+                config.card_aging_days = value.parse::<u16>().unwrap();
+            }
+            ConfigEnum::AutoArchiveCompletedDays => {
+                config.auto_archive_completed_days = value.parse::<u16>().unwrap();
+            }
+            ConfigEnum::SaveFilesToKeep => {
+                config.save_files_to_keep = value.parse::<u16>().unwrap();
+            }
             ConfigEnum::NoOfBoardsToShow => {
                 config.no_of_boards_to_show = value.parse::<u16>().unwrap();
             }
             ConfigEnum::DefaultTheme => {
                 config.default_theme = value.to_string();
             }
+            ConfigEnum::Language => {
+                config.language = value.to_string();
+            }
             ConfigEnum::DateFormat => {
                 config.date_time_format =
                     DateTimeFormat::from_human_readable_string(value).unwrap();
@@ -2182,6 +4894,18 @@ impl ConfigEnum {
             ConfigEnum::DatePickerCalenderFormat => {
                 config.date_picker_calender_format = CalenderType::try_from(value).unwrap();
             }
+            ConfigEnum::CardDensity => {
+                config.card_density = CardDensity::from_str(value).unwrap();
+            }
+            ConfigEnum::CardAgingCue => {
+                config.card_aging_cue = CardAgingCue::from_str(value).unwrap();
+            }
+            ConfigEnum::ColorDepth => {
+                config.color_depth = ColorDepth::from_str(value).unwrap();
+            }
+            ConfigEnum::CardFaceLayout => {
+                config.card_face_layout = CardFaceToken::parse_layout_string(value).unwrap();
+            }
             ConfigEnum::Keybindings => {
                 debug!("Keybindings should not be called from edit_config");
                 // Keybindings are handled separately
@@ -2191,9 +4915,659 @@ impl ConfigEnum {
     }
 }
 
+/// Asks before quitting with unsaved changes, unless they are saved on exit anyway
 pub async fn handle_exit(app: &mut App<'_>) -> AppReturn {
-    if app.config.save_on_exit {
+    if app.config.save_on_exit && app.may_save_automatically() {
         app.dispatch(IoEvent::AutoSave).await;
+    } else if app.has_unsaved_changes() && app.boards_differ_from_save() {
+        app.set_popup(PopUp::ConfirmQuit);
+        return AppReturn::Continue;
     }
     AppReturn::Exit
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::io::event_log::BoardEventKind;
+
+    fn named_card(name: &str) -> Card {
+        Card {
+            name: name.to_string(),
+            ..Card::default()
+        }
+    }
+
+    /// An app with one board holding the named cards, the toasts of setting it up are cleared
+    fn app_with_cards<'a>(card_names: &[&str]) -> (App<'a>, (u64, u64)) {
+        let (mut app, _io_rx) = App::for_tests();
+        let mut board = Board::new("Todo", "");
+        for card_name in card_names {
+            board.cards.add_card(named_card(card_name));
+        }
+        let board_id = board.id;
+        app.boards.set_boards(Boards::from(vec![board]));
+        refresh_visible_boards_and_cards(&mut app);
+        (app, board_id)
+    }
+
+    fn card_names(app: &App, board_id: (u64, u64)) -> Vec<String> {
+        app.boards
+            .get_board_with_id(board_id)
+            .unwrap()
+            .cards
+            .get_all_cards()
+            .iter()
+            .map(|card| card.name.clone())
+            .collect()
+    }
+
+    fn toast_messages(app: &App) -> Vec<String> {
+        app.widgets
+            .toast_widget
+            .toasts
+            .iter()
+            .map(|toast| toast.message.clone())
+            .collect()
+    }
+
+    /// Deletes the cards as one batch, the way a range delete records it
+    fn delete_as_batch(app: &mut App, board_id: (u64, u64), card_names: &[&str]) {
+        let board = app.boards.get_mut_board_with_id(board_id).unwrap();
+        let card_ids = board
+            .cards
+            .get_all_cards()
+            .iter()
+            .filter(|card| card_names.contains(&card.name.as_str()))
+            .map(|card| card.id)
+            .collect::<Vec<(u64, u64)>>();
+        let batch = card_ids
+            .into_iter()
+            .map(|card_id| {
+                let card = board.cards.remove_card_with_id(card_id).unwrap();
+                ActionHistory::DeleteCard(card, board_id)
+            })
+            .collect();
+        app.action_history_manager
+            .new_action(ActionHistory::Batch(batch));
+        app.widgets.toast_widget.toasts.clear();
+    }
+
+    #[test]
+    fn undoing_a_batch_restores_every_card_with_one_toast() {
+        let (mut app, board_id) = app_with_cards(&["a", "b", "c", "d"]);
+        delete_as_batch(&mut app, board_id, &["b", "c"]);
+        assert_eq!(card_names(&app, board_id), vec!["a", "d"]);
+
+        app.undo();
+        let mut names = card_names(&app, board_id);
+        names.sort();
+        assert_eq!(names, vec!["a", "b", "c", "d"]);
+        assert_eq!(app.action_history_manager.history_index, 0);
+        assert_eq!(toast_messages(&app), vec!["Undo 2 changes"]);
+
+        app.widgets.toast_widget.toasts.clear();
+        app.redo();
+        assert_eq!(card_names(&app, board_id), vec!["a", "d"]);
+        assert_eq!(app.action_history_manager.history_index, 1);
+        assert_eq!(toast_messages(&app), vec!["Redo 2 changes"]);
+    }
+
+    #[test]
+    fn a_batch_that_fails_part_way_changes_nothing() {
+        let (mut app, board_id) = app_with_cards(&["a", "b"]);
+        let missing_board_id = (board_id.0, board_id.1 + 1);
+        app.action_history_manager
+            .new_action(ActionHistory::Batch(vec![
+                ActionHistory::CreateCard(named_card("new"), board_id),
+                ActionHistory::CreateCard(named_card("lost"), missing_board_id),
+            ]));
+        let boards_before = app.boards.clone();
+
+        app.undo();
+        assert_eq!(app.boards, boards_before);
+        assert_eq!(app.action_history_manager.history_index, 1);
+        let toasts = toast_messages(&app);
+        assert_eq!(toasts.len(), 1);
+        assert!(toasts[0].starts_with("Could not undo 2 changes, nothing was changed"));
+    }
+
+    #[test]
+    fn a_failed_redo_leaves_the_history_where_it_was() {
+        let (mut app, board_id) = app_with_cards(&["a", "b", "c"]);
+        delete_as_batch(&mut app, board_id, &["a", "c"]);
+        app.undo();
+        // The board the redo would delete from is gone
+        app.boards.remove_board_with_id(board_id);
+        app.widgets.toast_widget.toasts.clear();
+
+        app.redo();
+        assert_eq!(app.action_history_manager.history_index, 0);
+        assert_eq!(toast_messages(&app).len(), 1);
+    }
+
+    #[test]
+    fn undoing_several_batches_gives_a_single_summary() {
+        let (mut app, board_id) = app_with_cards(&["a", "b", "c", "d"]);
+        delete_as_batch(&mut app, board_id, &["a", "b"]);
+        delete_as_batch(&mut app, board_id, &["c", "d"]);
+
+        app.undo_steps(2);
+        assert_eq!(card_names(&app, board_id).len(), 4);
+        assert_eq!(toast_messages(&app), vec!["Undid 2 actions"]);
+    }
+
+    #[test]
+    fn the_help_and_log_pane_height_carries_over_to_the_next_session() {
+        let config = AppConfig {
+            secondary_pane_height: Some(9),
+            ..AppConfig::default()
+        };
+        let json = serde_json::to_string(&config).unwrap();
+        let config = AppConfig::from_json_string(&json).unwrap();
+        assert_eq!(config.secondary_pane_height, Some(9));
+
+        let (io_tx, _io_rx) = tokio::sync::mpsc::channel(crate::constants::IO_EVENT_QUEUE_SIZE);
+        let app = App::with_config(io_tx, false, config);
+        assert_eq!(app.state.secondary_pane_height, 9);
+    }
+
+    /// Applies the action to the boards and records it, then undoes and redoes it. Each of the
+    /// three steps has to queue exactly one event for the log
+    fn assert_one_event_per_step(app: &mut App, action: ActionHistory, kind: BoardEventKind) {
+        app.redo_action(action.clone()).unwrap();
+        app.action_history_manager.pending_events.clear();
+        app.action_history_manager.new_action(action);
+        let events = std::mem::take(&mut app.action_history_manager.pending_events);
+        assert_eq!(events.len(), 1, "{}", kind);
+        assert_eq!(
+            (events[0].kind, events[0].origin),
+            (kind, BoardEventOrigin::Action)
+        );
+        for origin in [BoardEventOrigin::Undo, BoardEventOrigin::Redo] {
+            if origin == BoardEventOrigin::Undo {
+                app.undo();
+            } else {
+                app.redo();
+            }
+            let events = std::mem::take(&mut app.action_history_manager.pending_events);
+            assert_eq!(events.len(), 1, "{} {:?}", kind, origin);
+            assert_eq!(events[0].origin, origin);
+        }
+    }
+
+    #[test]
+    fn every_history_path_queues_exactly_one_event() {
+        let (mut app, todo_id) = app_with_cards(&["a", "b", "c"]);
+        let done = Board::new("Done", "");
+        let done_id = done.id;
+        app.boards.add_board(done);
+        let todo_cards = |app: &App| {
+            app.boards
+                .get_board_with_id(todo_id)
+                .unwrap()
+                .cards
+                .get_all_cards()
+                .to_vec()
+        };
+
+        let new_card = named_card("new");
+        assert_one_event_per_step(
+            &mut app,
+            ActionHistory::CreateCard(new_card.clone(), todo_id),
+            BoardEventKind::CardCreated,
+        );
+        assert_one_event_per_step(
+            &mut app,
+            ActionHistory::DeleteCard(new_card, todo_id),
+            BoardEventKind::CardDeleted,
+        );
+        let old_card = todo_cards(&app)[0].clone();
+        let new_card = Card {
+            name: "renamed".to_string(),
+            ..old_card.clone()
+        };
+        assert_one_event_per_step(
+            &mut app,
+            ActionHistory::EditCard(old_card, new_card, todo_id),
+            BoardEventKind::CardEdited,
+        );
+        assert_one_event_per_step(
+            &mut app,
+            ActionHistory::MoveCardWithinBoard(todo_id, 0, 1),
+            BoardEventKind::CardMoved,
+        );
+        let moved_card = todo_cards(&app)[0].clone();
+        assert_one_event_per_step(
+            &mut app,
+            ActionHistory::MoveCardBetweenBoards(moved_card, todo_id, done_id, 0, 0),
+            BoardEventKind::CardMoved,
+        );
+        let old_board = app.boards.get_board_with_id(todo_id).unwrap().clone();
+        let new_board = Board {
+            name: "Doing".to_string(),
+            ..old_board.clone()
+        };
+        assert_one_event_per_step(
+            &mut app,
+            ActionHistory::EditBoard(old_board, new_board),
+            BoardEventKind::BoardEdited,
+        );
+        let card_ids = app
+            .boards
+            .get_board_with_id(todo_id)
+            .unwrap()
+            .cards
+            .get_all_card_ids();
+        let sorted_card_ids = card_ids.iter().rev().copied().collect();
+        assert_one_event_per_step(
+            &mut app,
+            ActionHistory::SortCards(todo_id, card_ids, sorted_card_ids),
+            BoardEventKind::CardsSorted,
+        );
+        assert_one_event_per_step(
+            &mut app,
+            ActionHistory::MoveBoard(0, 1),
+            BoardEventKind::BoardMoved,
+        );
+        let later = Board::new("Later", "");
+        assert_one_event_per_step(
+            &mut app,
+            ActionHistory::CreateBoard(later.clone()),
+            BoardEventKind::BoardCreated,
+        );
+        assert_one_event_per_step(
+            &mut app,
+            ActionHistory::DeleteBoard(later),
+            BoardEventKind::BoardDeleted,
+        );
+        assert_one_event_per_step(
+            &mut app,
+            ActionHistory::Batch(vec![
+                ActionHistory::CreateCard(named_card("x"), todo_id),
+                ActionHistory::CreateCard(named_card("y"), done_id),
+            ]),
+            BoardEventKind::Batch,
+        );
+    }
+
+    #[test]
+    fn a_merged_card_move_step_still_queues_its_own_event() {
+        let (mut app, todo_id) = app_with_cards(&["a"]);
+        let card = app
+            .boards
+            .get_board_with_id(todo_id)
+            .unwrap()
+            .cards
+            .get_all_cards()[0]
+            .clone();
+        let middle_id = (todo_id.0, todo_id.1 + 1);
+        let done_id = (todo_id.0, todo_id.1 + 2);
+        let manager = &mut app.action_history_manager;
+        manager.new_card_move_step(ActionHistory::MoveCardBetweenBoards(
+            card.clone(),
+            todo_id,
+            middle_id,
+            0,
+            0,
+        ));
+        manager.new_card_move_step(ActionHistory::MoveCardBetweenBoards(
+            card, middle_id, done_id, 0, 0,
+        ));
+        assert_eq!(manager.history.len(), 1);
+        assert_eq!(manager.pending_events.len(), 2);
+        assert!(manager
+            .pending_events
+            .iter()
+            .all(|event| event.kind == BoardEventKind::CardMoved));
+    }
+
+    #[test]
+    fn single_line_densities_show_as_many_cards_as_the_board_has_rows() {
+        let (mut app, _io_rx) = App::for_tests();
+        let configured_count = app.config.no_of_cards_to_show;
+        assert_eq!(app.get_no_of_cards_to_show(), configured_count);
+        app.state.card_rows_available = Some(15);
+        assert_eq!(app.get_no_of_cards_to_show(), configured_count);
+        for density in [CardDensity::Compact, CardDensity::Minimal] {
+            app.config.card_density = density;
+            app.state.card_rows_available = Some(15);
+            assert_eq!(app.get_no_of_cards_to_show(), 15);
+            // A board squashed to nothing still lists a card
+            app.state.card_rows_available = Some(0);
+            assert_eq!(app.get_no_of_cards_to_show(), MIN_NO_CARDS_PER_BOARD);
+            app.state.card_rows_available = None;
+            assert_eq!(app.get_no_of_cards_to_show(), configured_count);
+        }
+    }
+
+    #[test]
+    fn card_density_cycles_through_every_density() {
+        let mut density = CardDensity::default();
+        let mut seen = vec![];
+        for _ in 0..3 {
+            seen.push(density);
+            density = density.next();
+        }
+        assert_eq!(density, CardDensity::default());
+        assert_eq!(
+            seen,
+            vec![
+                CardDensity::Comfortable,
+                CardDensity::Compact,
+                CardDensity::Minimal
+            ]
+        );
+        assert_eq!(
+            seen.iter()
+                .map(|density| density.is_single_line())
+                .collect::<Vec<bool>>(),
+            vec![false, true, true]
+        );
+    }
+
+    #[test]
+    fn menus_wrap_or_stop_at_the_ends_without_a_toast() {
+        for wrap_around_navigation in [true, false] {
+            let (mut app, _io_rx) = App::for_tests();
+            app.config.wrap_around_navigation = wrap_around_navigation;
+            let main_menu_len = app.main_menu.all().len();
+            let config_len = app.config.to_view_list().len();
+
+            app.state
+                .app_list_states
+                .main_menu
+                .select(Some(main_menu_len - 1));
+            app.main_menu_next();
+            let expected_index = if wrap_around_navigation {
+                0
+            } else {
+                main_menu_len - 1
+            };
+            assert_eq!(
+                app.state.app_list_states.main_menu.selected(),
+                Some(expected_index)
+            );
+            app.state.app_list_states.main_menu.select(Some(0));
+            app.main_menu_prv();
+            let expected_index = if wrap_around_navigation {
+                main_menu_len - 1
+            } else {
+                0
+            };
+            assert_eq!(
+                app.state.app_list_states.main_menu.selected(),
+                Some(expected_index)
+            );
+
+            app.state
+                .app_table_states
+                .config
+                .select(Some(config_len - 1));
+            app.config_next();
+            let expected_index = if wrap_around_navigation {
+                0
+            } else {
+                config_len - 1
+            };
+            assert_eq!(
+                app.state.app_table_states.config.selected(),
+                Some(expected_index)
+            );
+            app.state.app_table_states.config.select(Some(0));
+            app.config_prv();
+            let expected_index = if wrap_around_navigation {
+                config_len - 1
+            } else {
+                0
+            };
+            assert_eq!(
+                app.state.app_table_states.config.selected(),
+                Some(expected_index)
+            );
+            // Away from the ends both modes just step
+            app.config_next();
+            app.config_prv();
+            assert_eq!(
+                app.state.app_table_states.config.selected(),
+                Some(expected_index)
+            );
+            assert!(toast_messages(&app).is_empty());
+        }
+    }
+
+    #[test]
+    fn card_face_layouts_are_validated_with_the_position_of_the_problem() {
+        assert_eq!(
+            CardFaceToken::parse_layout(&["priority", " name ", "due_relative", "tags"]),
+            Ok(vec![
+                CardFaceToken::Priority,
+                CardFaceToken::Name,
+                CardFaceToken::DueRelative,
+                CardFaceToken::Tags,
+            ])
+        );
+        let unknown_token = CardFaceToken::parse_layout(&["name", "colour"]).unwrap_err();
+        assert!(unknown_token.starts_with(
+            "Unknown card face token \"colour\" at position 2, expected one of: name,"
+        ));
+        assert_eq!(
+            CardFaceToken::parse_layout(&["name", "tags", "name"]),
+            Err("Card face token \"name\" is listed twice, at positions 1 and 3".to_string())
+        );
+        assert!(CardFaceToken::parse_layout::<&str>(&[]).is_err());
+        assert!(CardFaceToken::parse_layout_string(" , ").is_err());
+    }
+
+    #[test]
+    fn the_card_face_layout_round_trips_through_the_config_menu_form() {
+        let layout = vec![
+            CardFaceToken::Id,
+            CardFaceToken::Name,
+            CardFaceToken::Estimate,
+        ];
+        let layout_string = CardFaceToken::layout_to_string(&layout);
+        assert_eq!(layout_string, "id, name, estimate");
+        assert_eq!(
+            CardFaceToken::parse_layout_string(&layout_string),
+            Ok(layout)
+        );
+        assert_eq!(
+            AppConfig::default().card_face_layout,
+            CardFaceToken::default_layout()
+        );
+    }
+
+    #[test]
+    fn quitting_in_safe_mode_writes_no_save() {
+        let (mut app, mut io_rx) = App::for_tests();
+        let save_directory =
+            std::env::temp_dir().join(format!("rust_kanban_safe_mode_exit_{}", std::process::id()));
+        std::fs::remove_dir_all(&save_directory).ok();
+        std::fs::create_dir_all(&save_directory).unwrap();
+        app.config.save_directory = save_directory.clone();
+        assert!(app.config.save_on_exit);
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+
+        assert_eq!(runtime.block_on(handle_exit(&mut app)), AppReturn::Exit);
+        assert!(io_rx.try_recv().is_err());
+        app.boards
+            .set_boards(Boards::from(vec![Board::new("Safe mode board", "")]));
+        runtime
+            .block_on(crate::io::io_handler::auto_save(&mut app))
+            .unwrap();
+        assert_eq!(std::fs::read_dir(&save_directory).unwrap().count(), 0);
+        assert!(!app.is_auto_save_due());
+
+        app.state.save_loaded_in_safe_mode = true;
+        assert_eq!(runtime.block_on(handle_exit(&mut app)), AppReturn::Exit);
+        assert!(matches!(io_rx.try_recv(), Ok(IoEvent::AutoSave)));
+        std::fs::remove_dir_all(&save_directory).ok();
+    }
+
+    /// Adds a card to the board and records it the way the new card form does
+    fn record_new_card(app: &mut App, board_id: (u64, u64), card_name: &str) -> (u64, u64) {
+        let card = named_card(card_name);
+        let card_id = card.id;
+        app.boards
+            .get_mut_board_with_id(board_id)
+            .unwrap()
+            .cards
+            .add_card(card.clone());
+        app.action_history_manager
+            .new_action(ActionHistory::CreateCard(card, board_id));
+        card_id
+    }
+
+    #[test]
+    fn recorded_changes_are_unsaved_until_saved_or_undone_to_the_save_point() {
+        let (mut app, board_id) = app_with_cards(&["Saved"]);
+        app.mark_boards_saved();
+        assert!(!app.has_unsaved_changes());
+
+        record_new_card(&mut app, board_id, "Unsaved");
+        assert!(app.has_unsaved_changes());
+        app.undo();
+        assert!(!app.has_unsaved_changes());
+        app.redo();
+        assert!(app.has_unsaved_changes());
+        app.mark_boards_saved();
+        assert!(!app.has_unsaved_changes());
+        app.undo();
+        assert!(app.has_unsaved_changes());
+    }
+
+    #[test]
+    fn navigation_keys_do_not_mark_changes() {
+        let (mut app, _board_id) = app_with_cards(&["First", "Second"]);
+        app.mark_boards_saved();
+        app.set_view(View::Zen);
+        app.state.set_focus(Focus::Body);
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        for key in [app.config.keybindings.down[0], app.config.keybindings.up[0]] {
+            runtime.block_on(app.do_action(key));
+        }
+        assert!(!app.has_unsaved_changes());
+    }
+
+    #[test]
+    fn quitting_compares_the_boards_before_asking() {
+        let (mut app, board_id) = app_with_cards(&["Saved"]);
+        app.config.save_on_exit = false;
+        app.mark_boards_saved();
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+
+        let card_id = record_new_card(&mut app, board_id, "Unsaved");
+        assert_eq!(runtime.block_on(handle_exit(&mut app)), AppReturn::Continue);
+        assert_eq!(app.state.z_stack.last(), Some(&PopUp::ConfirmQuit));
+        app.close_popup();
+
+        // Taken out again by hand, the flag is still set but nothing differs from the save
+        app.boards
+            .get_mut_board_with_id(board_id)
+            .unwrap()
+            .cards
+            .remove_card_with_id(card_id);
+        assert!(app.has_unsaved_changes());
+        assert_eq!(runtime.block_on(handle_exit(&mut app)), AppReturn::Exit);
+    }
+
+    #[test]
+    fn save_edits_undo_to_the_save_point_and_redo_all() {
+        let (mut app, board_id) = app_with_cards(&["Saved"]);
+        app.mark_boards_saved();
+        for card_name in ["First", "Second", "Third"] {
+            record_new_card(&mut app, board_id, card_name);
+        }
+        assert_eq!(
+            app.action_history_manager.undo_steps_to_save_point(),
+            Some(3)
+        );
+        app.widgets.toast_widget.toasts.clear();
+
+        app.undo_to_save_point();
+        assert_eq!(card_names(&app, board_id), vec!["Saved"]);
+        assert_eq!(
+            app.action_history_manager.undo_steps_to_save_point(),
+            Some(0)
+        );
+        assert_eq!(app.action_history_manager.redo_steps(), 3);
+        assert!(!app.has_unsaved_changes());
+        assert_eq!(toast_messages(&app), vec!["Undid 3 actions"]);
+        app.widgets.toast_widget.toasts.clear();
+
+        app.redo_all();
+        assert_eq!(
+            card_names(&app, board_id),
+            vec!["Saved", "First", "Second", "Third"]
+        );
+        assert_eq!(
+            app.action_history_manager.undo_steps_to_save_point(),
+            Some(3)
+        );
+        assert_eq!(app.action_history_manager.redo_steps(), 0);
+        assert_eq!(toast_messages(&app), vec!["Redid 3 actions"]);
+    }
+
+    #[test]
+    fn new_actions_keep_the_save_point_only_while_it_can_be_undone_to() {
+        let (mut app, board_id) = app_with_cards(&[]);
+        record_new_card(&mut app, board_id, "Saved");
+        app.mark_boards_saved();
+
+        // Recorded right at the save, which stays reachable
+        app.undo_to_save_point();
+        record_new_card(&mut app, board_id, "After save");
+        assert_eq!(
+            app.action_history_manager.undo_steps_to_save_point(),
+            Some(1)
+        );
+
+        // Undoing past the save and recording something new cuts the saved state off
+        app.undo_steps(2);
+        assert!(card_names(&app, board_id).is_empty());
+        record_new_card(&mut app, board_id, "Other");
+        assert_eq!(app.action_history_manager.undo_steps_to_save_point(), None);
+        app.widgets.toast_widget.toasts.clear();
+        app.undo_to_save_point();
+        assert_eq!(card_names(&app, board_id), vec!["Other"]);
+        assert_eq!(
+            toast_messages(&app),
+            vec!["The last save can not be reached by undoing, it is not in the undo history"]
+        );
+    }
+
+    #[test]
+    fn a_started_count_captures_digits_bound_to_other_actions() {
+        let (mut app, board_id) = app_with_cards(&["Saved"]);
+        for card_number in 1..=6 {
+            record_new_card(&mut app, board_id, &format!("Card {}", card_number));
+        }
+        app.set_view(View::Zen);
+        app.state.set_focus(Focus::Body);
+        refresh_visible_boards_and_cards(&mut app);
+        let keybindings = app.config.keybindings.clone();
+        assert_eq!(
+            keybindings.key_to_action(&Key::Char('5')),
+            Some(Action::ChangeCardPriorityToMedium)
+        );
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+
+        // Without the count key a bound digit keeps doing its action
+        runtime.block_on(handle_general_actions(&mut app, Key::Char('5')));
+        assert_eq!(app.state.history_step_count, None);
+        assert_eq!(app.action_history_manager.history_index, 7);
+        app.undo();
+
+        for key in [
+            keybindings.start_history_step_count[0],
+            Key::Char('5'),
+            keybindings.undo[0],
+        ] {
+            runtime.block_on(handle_general_actions(&mut app, key));
+        }
+        assert_eq!(card_names(&app, board_id), vec!["Saved", "Card 1"]);
+        assert_eq!(app.action_history_manager.history_index, 1);
+        assert_eq!(app.state.history_step_count, None);
+    }
+}
@@ -1,19 +1,47 @@
 use crate::{
-    app::{actions::Action, kanban::Card},
-    constants::{DEFAULT_VIEW, MOUSE_OUT_OF_BOUNDS_COORDINATES},
+    app::{
+        actions::Action,
+        card_aging::CardAgeCache,
+        focus_timer::FocusTimer,
+        form_drafts::FormDrafts,
+        kanban::{Board, Card, CardPriority, CardStatus},
+    },
+    changelog::ChangelogEntry,
+    constants::{
+        CREATED_ITEM_FLASH_DURATION, DEFAULT_SECONDARY_PANE_HEIGHT, DEFAULT_VIEW,
+        MAX_NOTIFICATIONS, MOUSE_OUT_OF_BOUNDS_COORDINATES,
+    },
     inputs::{key::Key, mouse::Mouse},
-    io::io_handler::CloudData,
-    ui::{text_box::TextBox, theme::Theme, PopUp, View},
+    io::{
+        data_handler::{SaveRecoveryCandidate, ThemeBackupSummary},
+        io_handler::CloudData,
+        markdown_mirror::MirrorDiff,
+        IoCompletion,
+    },
+    model::{
+        cloud_sync::CloudSyncMetadata,
+        date_range_filter::DateRangeFilter,
+        duplicate_boards::DuplicateBoardPair,
+        github_issues::GithubImportFailure,
+        stats_history::{StatsMetric, StatsRecord},
+    },
+    ui::{color_depth::ColorDepth, text_box::TextBox, theme::Theme, PopUp, View},
     util::get_term_bg_color,
 };
+use chrono::NaiveDate;
 use linked_hash_map::LinkedHashMap;
 use log::debug;
-use ratatui::widgets::{ListState, TableState};
+use ratatui::{
+    layout::Rect,
+    widgets::{ListState, TableState},
+};
 use serde::{Deserialize, Serialize};
 use std::{
+    collections::{HashMap, HashSet},
     ops::{Deref, DerefMut},
+    path::PathBuf,
     str::FromStr,
-    time::Instant,
+    time::{Duration, Instant},
     vec,
 };
 use strum::{Display, EnumString, IntoEnumIterator};
@@ -21,27 +49,116 @@ use strum_macros::EnumIter;
 
 #[derive(Debug, Clone)]
 pub struct AppState<'a> {
+    /// Only cards due in this range are shown, cards without a readable due date are left out
+    pub active_date_filter: Option<DateRangeFilter>,
+    /// Only cards with one of these priorities are shown, None shows every priority
+    pub active_priority_filter: Option<Vec<CardPriority>>,
+    /// Only cards with one of these statuses are shown, None shows every status
+    pub active_status_filter: Option<Vec<CardStatus>>,
     pub all_available_tags: Option<Vec<(String, u32)>>,
     pub app_list_states: AppListStates,
     pub app_status: AppStatus,
     pub app_table_states: AppTableStates,
+    pub board_being_edited: Option<(u64, u64)>, // board_id
+    pub boards_with_hidden_completed_cards: HashSet<(u64, u64)>,
+    /// Archived boards are listed with the others instead of being hidden
+    pub show_archived_boards: bool,
+    /// Read from the stats history file when the Stats view is opened
+    pub stats_history: Vec<StatsRecord>,
+    pub stats_metric: StatsMetric,
+    /// What the terminal looked capable of at startup, used when the color depth is Auto
+    pub detected_color_depth: ColorDepth,
+    /// The selected day in the Calendar view, the month around it is the one shown
+    pub calendar_date: NaiveDate,
+    /// The lane selected in the Swimlane view, kept for when the selected cell has no cards
+    pub swimlane_status: CardStatus,
+    /// Text left behind in the new board and new card forms
+    pub form_drafts: FormDrafts,
     pub card_being_edited: Option<((u64, u64), Card)>, // (board_id, card)
     pub card_drag_mode: bool,
+    /// The open date picker edits the start date of the card instead of its due date
+    pub picking_card_start_date: bool,
     pub cloud_data: Option<Vec<CloudData>>,
     pub current_board_id: Option<(u64, u64)>,
     pub current_card_id: Option<(u64, u64)>,
     pub current_mouse_coordinates: (u16, u16),
     pub debug_menu_toggled: bool,
     pub default_theme_mode: bool,
+    pub card_range_selection: Option<CardRangeSelection>,
+    /// Cards marked one by one for bulk actions, all on the board they were marked on
+    pub selected_card_ids: Vec<(u64, u64)>,
+    /// Digits typed on a kanban view, the next undo or redo is repeated that many times
+    pub history_step_count: Option<usize>,
+    pub duplicate_card_name_match: Option<DuplicateCardNameMatch>,
+    /// The completed card (board_id, card_id) the clear waiting on prompt is asking about
+    pub waiting_on_to_clear: Option<((u64, u64), (u64, u64))>,
+    /// Read from the card templates file when the template selector is opened
+    pub card_templates: Vec<Card>,
+    /// The template picked in the new card form, the fields without a text box come from it
+    pub new_card_template: Option<Card>,
+    pub theme_backup_summary: Option<ThemeBackupSummary>,
+    pub save_recovery_candidate: Option<SaveRecoveryCandidate>,
+    pub pending_json_import: Option<PendingJsonImport>,
+    pub github_import_report: Option<GithubImportReport>,
+    pub github_import_report_scroll: u16,
+    pub mirror_sync_diff: Option<MirrorDiff>,
+    pub notifications: Notifications,
+    /// (board_id, card_id) of cards found with conflict markers after the last sync, badged until
+    /// the markers are edited out
+    pub cards_with_conflict_markers: Vec<((u64, u64), (u64, u64))>,
+    /// Pairs from the last duplicate board scan that have not been dealt with yet
+    pub duplicate_board_pairs: Vec<DuplicateBoardPair>,
+    pub whats_new_entries: Vec<&'static ChangelogEntry>,
+    pub whats_new_scroll: u16,
+    pub focus_timer: Option<FocusTimer>,
+    pub card_age_cache: CardAgeCache,
+    /// Day completed cards were last checked for auto archiving, None until the first check
+    pub last_auto_archive_date: Option<NaiveDate>,
+    /// When cards were last checked for due date reminders, None until the first check
+    pub last_due_date_reminder_check: Option<Instant>,
+    /// When the last IO event was dispatched, periodic auto saves count from here
+    pub last_io_event_time: Option<Instant>,
+    /// Latest reminder sent for each card this session, a card is only reminded again when it
+    /// moves from due soon to overdue
+    pub due_date_reminders_sent: HashMap<(u64, u64), DueDateReminder>,
+    /// What the last acknowledged cloud sync uploaded, read from the latest save when None
+    pub cloud_sync_metadata: Option<CloudSyncMetadata>,
+    /// Set once the server rejected a partial upload, the rest of the session uploads in full
+    pub partial_cloud_sync_unsupported: bool,
+    /// Board content hashes when the boards were last loaded or saved locally, the boards have
+    /// unsaved changes while they differ
+    pub saved_board_hashes: HashMap<(u64, u64), u64>,
     pub edited_keybinding: Option<Vec<Key>>,
+    pub keybinding_edit_from_command_palette: bool,
     pub encryption_key_from_arguments: Option<String>,
+    /// Started with --safe-mode, the config, custom themes and saves on disk are left alone
+    pub safe_mode: bool,
+    /// A save was loaded by hand in safe mode, saving no longer replaces the user's saves with
+    /// the empty safe mode boards
+    pub save_loaded_in_safe_mode: bool,
+    /// Set by --with-notes, board notes are left out of the markdown mirror otherwise
+    pub include_notes_in_exports: bool,
     pub filter_tags: Option<Vec<String>>,
     pub focus: Focus,
     pub hovered_board: Option<(u64, u64)>,
     pub hovered_card_dimensions: Option<(u16, u16)>,
+    /// Rows inside a board on the last render, single line card densities fill them
+    pub card_rows_available: Option<u16>,
+    /// Height of each help and log pane, resized with the keyboard for this session only
+    pub secondary_pane_height: u16,
+    /// Tallest the panes can get in the current view before the body drops under its minimum
+    pub max_secondary_pane_height: Option<u16>,
     pub hovered_card: Option<((u64, u64), (u64, u64))>,
     pub last_mouse_action: Option<Mouse>,
+    /// Size of the last rendered frame, mouse coordinates are clamped to it
+    pub last_frame_area: Rect,
+    /// Bumped whenever a frame is rendered at a new size, hover results from an older layout
+    /// can then be told apart from the current one
+    pub layout_generation: u64,
+    /// Layout generation the mouse coordinates were last reported against
+    pub mouse_layout_generation: u64,
     pub last_reset_password_link_sent_time: Option<Instant>,
+    pub created_item_flash: Option<CreatedItemFlash>,
     pub mouse_focus: Option<Focus>,
     pub mouse_list_index: Option<u16>,
     pub z_stack: ZStack,
@@ -50,10 +167,13 @@ pub struct AppState<'a> {
     pub preview_file_name: Option<String>,
     pub preview_visible_boards_and_cards: LinkedHashMap<(u64, u64), Vec<(u64, u64)>>,
     pub previous_mouse_coordinates: (u16, u16),
+    pub profile_next_frame: bool,
     pub term_background_color: (u8, u8, u8),
     pub theme_being_edited: Theme,
     pub current_view: View,
     pub ui_render_time: Vec<u128>,
+    /// How long the widget loop kept the app locked over the last second, shown in the debug panel
+    pub widget_lock_time_per_second: Option<Duration>,
     pub user_login_data: UserLoginData,
     pub path_check_state: PathCheckState,
     pub text_buffers: TextBuffers<'a>,
@@ -71,32 +191,95 @@ impl AppState<'_> {
     pub fn get_theme_being_edited(&self) -> Theme {
         self.theme_being_edited.clone()
     }
+    /// Whether the board (`card_id` None) or card is still being flashed after its creation
+    pub fn is_created_item_flashing(
+        &self,
+        board_id: (u64, u64),
+        card_id: Option<(u64, u64)>,
+    ) -> bool {
+        self.created_item_flash.is_some_and(|flash| {
+            flash.board_id == board_id
+                && flash.card_id == card_id
+                && flash.started_at.elapsed() < Duration::from_millis(CREATED_ITEM_FLASH_DURATION)
+        })
+    }
 }
 
 impl Default for AppState<'_> {
     fn default() -> AppState<'static> {
         AppState {
+            active_date_filter: None,
+            active_priority_filter: None,
+            active_status_filter: None,
             all_available_tags: None,
             app_list_states: AppListStates::default(),
             app_status: AppStatus::default(),
             app_table_states: AppTableStates::default(),
+            board_being_edited: None,
+            boards_with_hidden_completed_cards: HashSet::new(),
+            show_archived_boards: false,
+            stats_history: Vec::new(),
+            stats_metric: StatsMetric::default(),
+            calendar_date: chrono::Local::now().date_naive(),
+            swimlane_status: CardStatus::Active,
+            form_drafts: FormDrafts::default(),
+            detected_color_depth: ColorDepth::TrueColor,
             card_being_edited: None,
             card_drag_mode: false,
+            picking_card_start_date: false,
             cloud_data: None,
             current_board_id: None,
             current_card_id: None,
             current_mouse_coordinates: MOUSE_OUT_OF_BOUNDS_COORDINATES, // make sure it's out of bounds when mouse mode is disabled
             debug_menu_toggled: false,
             default_theme_mode: false,
+            card_range_selection: None,
+            selected_card_ids: Vec::new(),
+            history_step_count: None,
+            duplicate_card_name_match: None,
+            waiting_on_to_clear: None,
+            card_templates: Vec::new(),
+            new_card_template: None,
+            theme_backup_summary: None,
+            save_recovery_candidate: None,
+            pending_json_import: None,
+            github_import_report: None,
+            github_import_report_scroll: 0,
+            mirror_sync_diff: None,
+            notifications: Notifications::default(),
+            cards_with_conflict_markers: vec![],
+            duplicate_board_pairs: vec![],
+            whats_new_entries: vec![],
+            whats_new_scroll: 0,
+            focus_timer: None,
+            card_age_cache: CardAgeCache::default(),
+            last_auto_archive_date: None,
+            last_due_date_reminder_check: None,
+            last_io_event_time: None,
+            due_date_reminders_sent: HashMap::new(),
+            cloud_sync_metadata: None,
+            partial_cloud_sync_unsupported: false,
+            saved_board_hashes: HashMap::new(),
             edited_keybinding: None,
+            keybinding_edit_from_command_palette: false,
             encryption_key_from_arguments: None,
+            safe_mode: false,
+            save_loaded_in_safe_mode: false,
+            include_notes_in_exports: false,
             filter_tags: None,
             focus: Focus::NoFocus,
             hovered_board: None,
             hovered_card_dimensions: None,
+            card_rows_available: None,
+            secondary_pane_height: DEFAULT_SECONDARY_PANE_HEIGHT,
+            max_secondary_pane_height: None,
             hovered_card: None,
             last_mouse_action: None,
+            last_frame_area: Rect::default(),
+            layout_generation: 0,
+            mouse_layout_generation: 0,
             last_reset_password_link_sent_time: None,
+            created_item_flash: None,
             mouse_focus: None,
             mouse_list_index: None,
             z_stack: ZStack::default(),
@@ -105,10 +288,12 @@ impl Default for AppState<'_> {
             preview_file_name: None,
             preview_visible_boards_and_cards: LinkedHashMap::new(),
             previous_mouse_coordinates: MOUSE_OUT_OF_BOUNDS_COORDINATES,
+            profile_next_frame: false,
             term_background_color: get_term_bg_color(),
             theme_being_edited: Theme::default(),
             current_view: DEFAULT_VIEW,
             ui_render_time: Vec::new(),
+            widget_lock_time_per_second: None,
             user_login_data: UserLoginData {
                 email_id: None,
                 auth_token: None,
@@ -168,8 +353,13 @@ impl DerefMut for ZStack {
 
 #[derive(Debug, Clone, Default)]
 pub struct AppListStates {
+    /// Index into the archived cards, the board header rows are not counted
+    pub archived_cards: ListState,
+    pub board_accent_color_selector: ListState,
+    pub card_highlight_color_selector: ListState,
     pub card_priority_selector: ListState,
     pub card_status_selector: ListState,
+    pub card_view_checklist: ListState,
     pub card_view_comment_list: ListState,
     pub card_view_list: ListState,
     pub card_view_tag_list: ListState,
@@ -180,10 +370,23 @@ pub struct AppListStates {
     pub date_format_selector: ListState,
     pub default_view: ListState,
     pub edit_specific_style: [ListState; 3],
+    pub filter_by_date_range_list: ListState,
+    pub filter_by_priority_list: ListState,
+    pub filter_by_status_list: ListState,
     pub filter_by_tag_list: ListState,
     pub load_save: ListState,
     pub logs: ListState,
     pub main_menu: ListState,
+    pub mirror_sync_conflicts: ListState,
+    pub notifications: ListState,
+    pub conflict_marker_cards: ListState,
+    pub duplicate_board_pairs: ListState,
+    pub sort_cards_by_selector: ListState,
+    pub move_card_to_board_selector: ListState,
+    pub calendar_day_cards: ListState,
+    pub waiting_on_list: ListState,
+    pub card_templates: ListState,
+    pub log_level_selector: ListState,
     pub theme_selector: ListState,
 }
 
@@ -199,15 +402,27 @@ pub struct AppTableStates {
 pub struct TextBuffers<'a> {
     pub board_name: TextBox<'a>,
     pub board_description: TextBox<'a>,
+    pub board_notes: TextBox<'a>,
     pub card_name: TextBox<'a>,
     pub card_description: TextBox<'a>,
+    pub card_estimate: TextBox<'a>,
+    pub card_estimated_time: TextBox<'a>,
+    pub card_actual_time: TextBox<'a>,
+    pub card_waiting_on: TextBox<'a>,
     pub card_tags: Vec<TextBox<'a>>,
     pub card_comments: Vec<TextBox<'a>>,
+    pub card_checklist: Vec<TextBox<'a>>,
     pub email_id: TextBox<'a>,
     pub password: TextBox<'a>,
     pub confirm_password: TextBox<'a>,
     pub reset_password_link: TextBox<'a>,
     pub general_config: TextBox<'a>,
+    pub import_path: TextBox<'a>,
+    pub card_search: TextBox<'a>,
+    /// Typed into the custom date range prompt of the date filter
+    pub custom_date_range: TextBox<'a>,
+    pub find_replace_query: TextBox<'a>,
+    pub find_replace_replacement: TextBox<'a>,
     pub command_palette: TextBox<'a>,
     pub theme_editor_fg_hex: TextBox<'a>,
     pub theme_editor_bg_hex: TextBox<'a>,
@@ -218,15 +433,26 @@ impl<'a> Default for TextBuffers<'a> {
         TextBuffers {
             board_name: TextBox::new(vec!["".to_string()], true),
             board_description: TextBox::new(vec!["".to_string()], false),
+            board_notes: TextBox::new(vec!["".to_string()], false),
             card_name: TextBox::new(vec!["".to_string()], true),
             card_description: TextBox::new(vec!["".to_string()], false),
+            card_estimate: TextBox::new(vec!["".to_string()], true),
+            card_estimated_time: TextBox::new(vec!["".to_string()], true),
+            card_actual_time: TextBox::new(vec!["".to_string()], true),
+            card_waiting_on: TextBox::new(vec!["".to_string()], true),
             card_tags: Vec::new(),
             card_comments: Vec::new(),
+            card_checklist: Vec::new(),
             email_id: TextBox::new(vec!["".to_string()], true),
             password: TextBox::new(vec!["".to_string()], true),
             confirm_password: TextBox::new(vec!["".to_string()], true),
             reset_password_link: TextBox::new(vec!["".to_string()], true),
             general_config: TextBox::new(vec!["".to_string()], true),
+            import_path: TextBox::new(vec!["".to_string()], true),
+            card_search: TextBox::new(vec!["".to_string()], true),
+            custom_date_range: TextBox::new(vec!["".to_string()], true),
+            find_replace_query: TextBox::new(vec!["".to_string()], true),
+            find_replace_replacement: TextBox::new(vec!["".to_string()], true),
             command_palette: TextBox::new(vec!["".to_string()], true),
             theme_editor_fg_hex: TextBox::new(vec!["".to_string()], true),
             theme_editor_bg_hex: TextBox::new(vec!["".to_string()], true),
@@ -247,6 +473,13 @@ impl<'a> TextBuffers<'a> {
             .map(|comment| TextBox::new(vec![comment.clone()], true))
             .collect();
     }
+    pub fn prepare_checklist_for_card(&mut self, card: &Card) {
+        self.card_checklist = card
+            .checklist
+            .iter()
+            .map(|checklist_item| TextBox::new(vec![checklist_item.text.clone()], true))
+            .collect();
+    }
 }
 
 #[derive(Debug, Clone, Default)]
@@ -258,6 +491,64 @@ pub struct PathCheckState {
     pub path_check_mode: bool,
 }
 
+impl PathCheckState {
+    /// Rechecks the typed path when it changed, with include_files a file counts as an existing
+    /// path and files are offered as completions too, otherwise only directories are
+    pub fn check(&mut self, current_user_input: &str, include_files: bool) {
+        if current_user_input == self.path_last_checked && !self.recheck_required {
+            return;
+        }
+        self.recheck_required = false;
+        self.potential_completion = None;
+        self.path_last_checked = current_user_input.to_string();
+        let current_path = std::path::Path::new(current_user_input);
+        self.path_exists = if include_files {
+            current_path.is_file()
+        } else {
+            current_path.is_dir()
+        };
+        if self.path_exists {
+            return;
+        }
+        let mut split_input = current_user_input
+            .split(std::path::MAIN_SEPARATOR)
+            .collect::<Vec<&str>>();
+        // remove any empty strings
+        split_input.retain(|&x| !x.is_empty());
+        let Some(last_input) = split_input.pop() else {
+            return;
+        };
+        let (to_check, typed_prefix) = if split_input.is_empty() {
+            (
+                std::path::MAIN_SEPARATOR.to_string(),
+                std::path::MAIN_SEPARATOR.to_string() + last_input,
+            )
+        } else {
+            (
+                std::path::MAIN_SEPARATOR.to_string()
+                    + &split_input.join(std::path::MAIN_SEPARATOR_STR),
+                current_user_input.to_string(),
+            )
+        };
+        let Ok(dir) = std::fs::read_dir(&to_check) else {
+            return;
+        };
+        for entry in dir.flatten() {
+            let path = entry.path();
+            if !(include_files || path.is_dir()) {
+                continue;
+            }
+            let completion = path
+                .to_str()
+                .and_then(|path| path.strip_prefix(typed_prefix.as_str()));
+            if let Some(completion) = completion.filter(|completion| !completion.is_empty()) {
+                self.potential_completion = Some(completion.to_string());
+                break;
+            }
+        }
+    }
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct UserLoginData {
     pub auth_token: Option<String>,
@@ -266,6 +557,77 @@ pub struct UserLoginData {
     pub user_id: Option<String>,
 }
 
+/// A contiguous run of cards in one board, from the anchor card to the current card
+#[derive(Clone, Copy, Debug)]
+pub struct CardRangeSelection {
+    pub board_id: (u64, u64),
+    pub anchor_card_id: (u64, u64),
+}
+
+/// Results of background IO operations for the session, newest last
+#[derive(Clone, Debug, Default)]
+pub struct Notifications {
+    entries: Vec<IoCompletion>,
+    unread_count: usize,
+}
+
+impl Notifications {
+    pub fn push(&mut self, completion: IoCompletion) {
+        if self.entries.len() >= MAX_NOTIFICATIONS {
+            self.entries.remove(0);
+        }
+        self.entries.push(completion);
+        self.unread_count = (self.unread_count + 1).min(self.entries.len());
+    }
+    pub fn entries(&self) -> &[IoCompletion] {
+        &self.entries
+    }
+    pub fn unread_count(&self) -> usize {
+        self.unread_count
+    }
+    pub fn mark_all_read(&mut self) {
+        self.unread_count = 0;
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DueDateReminder {
+    /// Due within the warning delta
+    DueSoon,
+    Overdue,
+}
+
+/// Board, and card on it when there is one, that was just created or restored and is flashed
+/// until the time runs out
+#[derive(Clone, Copy, Debug)]
+pub struct CreatedItemFlash {
+    pub board_id: (u64, u64),
+    pub card_id: Option<(u64, u64)>,
+    pub started_at: Instant,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct DuplicateCardNameMatch {
+    pub board_id: (u64, u64),
+    pub card_id: (u64, u64),
+    pub rapid_entry: bool,
+}
+
+/// Boards read from a save file, held until the user picks how to import them
+#[derive(Clone, Debug)]
+pub struct PendingJsonImport {
+    pub path: PathBuf,
+    pub boards: Vec<Board>,
+}
+
+/// Outcome of the last GitHub import, kept for the summary popup when some issues failed
+#[derive(Clone, Debug)]
+pub struct GithubImportReport {
+    pub repo: String,
+    pub summary: String,
+    pub failures: Vec<GithubImportFailure>,
+}
+
 #[derive(Clone, PartialEq, Debug, Default)]
 pub enum AppStatus {
     #[default]
@@ -278,13 +640,24 @@ pub enum AppStatus {
 #[derive(Clone, PartialEq, Debug, Copy, Default)]
 pub enum Focus {
     Body,
+    BoardNotes,
+    CancelButton,
+    CardActualTime,
+    CardChecklist,
+    CardColor,
     CardComments,
     CardDescription,
     CardDueDate,
+    CardEstimate,
+    CardEstimatedTime,
     CardName,
     CardPriority,
+    CardStartDate,
     CardStatus,
     CardTags,
+    CardWaitingOn,
+    ChangeBoardAccentColorPopup,
+    ChangeCardHighlightColorPopup,
     ChangeCardPriorityPopup,
     ChangeCardStatusPopup,
     ChangeDateFormatPopup,
@@ -301,19 +674,34 @@ pub enum Focus {
     EditSpecificKeyBindingPopup,
     EmailIDField,
     ExtraFocus, // Used in cases where defining a new focus is not necessary
+    FilterByDateRangePopup,
+    FilterByPriorityPopup,
+    FilterByStatusPopup,
     FilterByTagPopup,
+    FindReplaceQuery,
+    FindReplaceReplacement,
     Help,
     LoadSave,
     Log,
     MainMenu,
+    MirrorSyncConflicts,
+    MoveCardToBoardPopup,
+    CalendarDayCardsPopup,
+    WaitingOnListPopup,
+    SelectCardTemplatePopup,
+    NotificationsList,
+    ConflictMarkerCards,
     NewBoardDescription,
     NewBoardName,
     #[default]
     NoFocus,
     PasswordField,
     ResetPasswordLinkField,
+    SaveCardAsTemplate,
     SelectDefaultView,
     SendResetPasswordLinkButton,
+    SetLogLevelPopup,
+    SortCardsByPopup,
     StyleEditorBG,
     StyleEditorFG,
     StyleEditorModifier,
@@ -322,6 +710,7 @@ pub enum Focus {
     ThemeEditor,
     ThemeSelector,
     Title,
+    UseTemplate,
     DTPCalender,
     DTPMonth,
     DTPYear,
@@ -337,6 +726,8 @@ pub struct KeyBindings {
     pub change_card_status_to_active: Vec<Key>,
     pub change_card_status_to_completed: Vec<Key>,
     pub change_card_status_to_stale: Vec<Key>,
+    pub archive_card: Vec<Key>,
+    pub archive_board: Vec<Key>,
     pub change_card_priority_to_high: Vec<Key>,
     pub change_card_priority_to_medium: Vec<Key>,
     pub change_card_priority_to_low: Vec<Key>,
@@ -344,13 +735,26 @@ pub struct KeyBindings {
     pub delete_board: Vec<Key>,
     pub delete_card: Vec<Key>,
     pub down: Vec<Key>,
+    pub duplicate_board: Vec<Key>,
+    pub duplicate_card: Vec<Key>,
+    pub edit_board: Vec<Key>,
+    pub export_to_markdown: Vec<Key>,
+    pub filter_by_date_range: Vec<Key>,
+    pub filter_by_priority: Vec<Key>,
+    pub filter_by_status: Vec<Key>,
     pub go_to_main_menu: Vec<Key>,
     pub go_to_previous_view_or_cancel: Vec<Key>,
+    pub grow_secondary_pane: Vec<Key>,
     pub hide_ui_element: Vec<Key>,
     pub left: Vec<Key>,
+    pub move_board_left: Vec<Key>,
+    pub move_board_right: Vec<Key>,
     pub move_card_down: Vec<Key>,
     pub move_card_left: Vec<Key>,
     pub move_card_right: Vec<Key>,
+    pub move_card_to_board: Vec<Key>,
+    pub move_card_to_bottom: Vec<Key>,
+    pub move_card_to_top: Vec<Key>,
     pub move_card_up: Vec<Key>,
     pub new_board: Vec<Key>,
     pub new_card: Vec<Key>,
@@ -362,10 +766,25 @@ pub struct KeyBindings {
     pub reset_ui: Vec<Key>,
     pub right: Vec<Key>,
     pub save_state: Vec<Key>,
+    pub select_card_range_down: Vec<Key>,
+    pub select_card_range_up: Vec<Key>,
+    pub set_card_highlight_color: Vec<Key>,
+    pub shrink_secondary_pane: Vec<Key>,
+    pub show_board_stats: Vec<Key>,
+    pub skip_tutorial_step: Vec<Key>,
+    pub sort_cards: Vec<Key>,
+    #[serde(default)]
+    pub start_history_step_count: Vec<Key>,
     pub stop_user_input: Vec<Key>,
     pub take_user_input: Vec<Key>,
+    pub toggle_card_selection: Vec<Key>,
     pub toggle_command_palette: Vec<Key>,
+    pub toggle_completed_cards_visibility: Vec<Key>,
+    pub toggle_archived_view: Vec<Key>,
+    pub toggle_focus_timer: Vec<Key>,
+    pub toggle_notifications: Vec<Key>,
     pub undo: Vec<Key>,
+    pub undo_from_toast: Vec<Key>,
     pub up: Vec<Key>,
 }
 
@@ -375,6 +794,8 @@ pub enum KeyBindingEnum {
     ChangeCardStatusToActive,
     ChangeCardStatusToCompleted,
     ChangeCardStatusToStale,
+    ArchiveCard,
+    ArchiveBoard,
     ChangeCardPriorityToHigh,
     ChangeCardPriorityToMedium,
     ChangeCardPriorityToLow,
@@ -382,13 +803,26 @@ pub enum KeyBindingEnum {
     DeleteBoard,
     DeleteCard,
     Down,
+    DuplicateBoard,
+    DuplicateCard,
+    EditBoard,
+    ExportToMarkdown,
+    FilterByDateRange,
+    FilterByPriority,
+    FilterByStatus,
     GoToMainMenu,
     GoToPreviousViewOrCancel,
+    GrowSecondaryPane,
     HideUiElement,
     Left,
+    MoveBoardLeft,
+    MoveBoardRight,
     MoveCardDown,
     MoveCardLeft,
     MoveCardRight,
+    MoveCardToBoard,
+    MoveCardToBottom,
+    MoveCardToTop,
     MoveCardUp,
     NewBoard,
     NewCard,
@@ -400,10 +834,24 @@ pub enum KeyBindingEnum {
     ResetUI,
     Right,
     SaveState,
+    SelectCardRangeDown,
+    SelectCardRangeUp,
+    SetCardHighlightColor,
+    ShrinkSecondaryPane,
+    ShowBoardStats,
+    SkipTutorialStep,
+    SortCards,
+    StartHistoryStepCount,
     StopUserInput,
     TakeUserInput,
+    ToggleCardSelection,
     ToggleCommandPalette,
+    ToggleCompletedCardsVisibility,
+    ToggleArchivedView,
+    ToggleFocusTimer,
+    ToggleNotifications,
     Undo,
+    UndoFromToast,
     Up,
 }
 
@@ -452,6 +900,8 @@ impl KeyBindings {
                     &self.change_card_status_to_completed
                 }
                 KeyBindingEnum::ChangeCardStatusToStale => &self.change_card_status_to_stale,
+                KeyBindingEnum::ArchiveCard => &self.archive_card,
+                KeyBindingEnum::ArchiveBoard => &self.archive_board,
                 KeyBindingEnum::ChangeCardPriorityToHigh => &self.change_card_priority_to_high,
                 KeyBindingEnum::ChangeCardPriorityToMedium => &self.change_card_priority_to_medium,
                 KeyBindingEnum::ChangeCardPriorityToLow => &self.change_card_priority_to_low,
@@ -459,13 +909,26 @@ impl KeyBindings {
                 KeyBindingEnum::DeleteBoard => &self.delete_board,
                 KeyBindingEnum::DeleteCard => &self.delete_card,
                 KeyBindingEnum::Down => &self.down,
+                KeyBindingEnum::DuplicateBoard => &self.duplicate_board,
+                KeyBindingEnum::DuplicateCard => &self.duplicate_card,
+                KeyBindingEnum::EditBoard => &self.edit_board,
+                KeyBindingEnum::ExportToMarkdown => &self.export_to_markdown,
+                KeyBindingEnum::FilterByDateRange => &self.filter_by_date_range,
+                KeyBindingEnum::FilterByPriority => &self.filter_by_priority,
+                KeyBindingEnum::FilterByStatus => &self.filter_by_status,
                 KeyBindingEnum::GoToMainMenu => &self.go_to_main_menu,
                 KeyBindingEnum::GoToPreviousViewOrCancel => &self.go_to_previous_view_or_cancel,
+                KeyBindingEnum::GrowSecondaryPane => &self.grow_secondary_pane,
                 KeyBindingEnum::HideUiElement => &self.hide_ui_element,
                 KeyBindingEnum::Left => &self.left,
+                KeyBindingEnum::MoveBoardLeft => &self.move_board_left,
+                KeyBindingEnum::MoveBoardRight => &self.move_board_right,
                 KeyBindingEnum::MoveCardDown => &self.move_card_down,
                 KeyBindingEnum::MoveCardLeft => &self.move_card_left,
                 KeyBindingEnum::MoveCardRight => &self.move_card_right,
+                KeyBindingEnum::MoveCardToBoard => &self.move_card_to_board,
+                KeyBindingEnum::MoveCardToBottom => &self.move_card_to_bottom,
+                KeyBindingEnum::MoveCardToTop => &self.move_card_to_top,
                 KeyBindingEnum::MoveCardUp => &self.move_card_up,
                 KeyBindingEnum::NewBoard => &self.new_board,
                 KeyBindingEnum::NewCard => &self.new_card,
@@ -477,10 +940,26 @@ impl KeyBindings {
                 KeyBindingEnum::ResetUI => &self.reset_ui,
                 KeyBindingEnum::Right => &self.right,
                 KeyBindingEnum::SaveState => &self.save_state,
+                KeyBindingEnum::SelectCardRangeDown => &self.select_card_range_down,
+                KeyBindingEnum::SelectCardRangeUp => &self.select_card_range_up,
+                KeyBindingEnum::SetCardHighlightColor => &self.set_card_highlight_color,
+                KeyBindingEnum::ShrinkSecondaryPane => &self.shrink_secondary_pane,
+                KeyBindingEnum::ShowBoardStats => &self.show_board_stats,
+                KeyBindingEnum::SkipTutorialStep => &self.skip_tutorial_step,
+                KeyBindingEnum::SortCards => &self.sort_cards,
+                KeyBindingEnum::StartHistoryStepCount => &self.start_history_step_count,
                 KeyBindingEnum::StopUserInput => &self.stop_user_input,
                 KeyBindingEnum::TakeUserInput => &self.take_user_input,
+                KeyBindingEnum::ToggleCardSelection => &self.toggle_card_selection,
                 KeyBindingEnum::ToggleCommandPalette => &self.toggle_command_palette,
+                KeyBindingEnum::ToggleCompletedCardsVisibility => {
+                    &self.toggle_completed_cards_visibility
+                }
+                KeyBindingEnum::ToggleArchivedView => &self.toggle_archived_view,
+                KeyBindingEnum::ToggleFocusTimer => &self.toggle_focus_timer,
+                KeyBindingEnum::ToggleNotifications => &self.toggle_notifications,
                 KeyBindingEnum::Undo => &self.undo,
+                KeyBindingEnum::UndoFromToast => &self.undo_from_toast,
                 KeyBindingEnum::Up => &self.up,
             };
             (enum_variant, value)
@@ -501,6 +980,8 @@ impl KeyBindings {
             KeyBindingEnum::ChangeCardStatusToActive => Action::ChangeCardStatusToActive,
             KeyBindingEnum::ChangeCardStatusToCompleted => Action::ChangeCardStatusToCompleted,
             KeyBindingEnum::ChangeCardStatusToStale => Action::ChangeCardStatusToStale,
+            KeyBindingEnum::ArchiveCard => Action::ArchiveCard,
+            KeyBindingEnum::ArchiveBoard => Action::ArchiveBoard,
             KeyBindingEnum::ChangeCardPriorityToHigh => Action::ChangeCardPriorityToHigh,
             KeyBindingEnum::ChangeCardPriorityToMedium => Action::ChangeCardPriorityToMedium,
             KeyBindingEnum::ChangeCardPriorityToLow => Action::ChangeCardPriorityToLow,
@@ -508,13 +989,26 @@ impl KeyBindings {
             KeyBindingEnum::DeleteBoard => Action::DeleteBoard,
             KeyBindingEnum::DeleteCard => Action::Delete,
             KeyBindingEnum::Down => Action::Down,
+            KeyBindingEnum::DuplicateBoard => Action::DuplicateBoard,
+            KeyBindingEnum::DuplicateCard => Action::DuplicateCard,
+            KeyBindingEnum::EditBoard => Action::EditBoard,
+            KeyBindingEnum::ExportToMarkdown => Action::ExportToMarkdown,
+            KeyBindingEnum::FilterByDateRange => Action::FilterByDateRange,
+            KeyBindingEnum::FilterByPriority => Action::FilterByPriority,
+            KeyBindingEnum::FilterByStatus => Action::FilterByStatus,
             KeyBindingEnum::GoToMainMenu => Action::GoToMainMenu,
             KeyBindingEnum::GoToPreviousViewOrCancel => Action::GoToPreviousViewOrCancel,
+            KeyBindingEnum::GrowSecondaryPane => Action::GrowSecondaryPane,
             KeyBindingEnum::HideUiElement => Action::HideUiElement,
             KeyBindingEnum::Left => Action::Left,
+            KeyBindingEnum::MoveBoardLeft => Action::MoveBoardLeft,
+            KeyBindingEnum::MoveBoardRight => Action::MoveBoardRight,
             KeyBindingEnum::MoveCardDown => Action::MoveCardDown,
             KeyBindingEnum::MoveCardLeft => Action::MoveCardLeft,
             KeyBindingEnum::MoveCardRight => Action::MoveCardRight,
+            KeyBindingEnum::MoveCardToBoard => Action::MoveCardToBoard,
+            KeyBindingEnum::MoveCardToBottom => Action::MoveCardToBottom,
+            KeyBindingEnum::MoveCardToTop => Action::MoveCardToTop,
             KeyBindingEnum::MoveCardUp => Action::MoveCardUp,
             KeyBindingEnum::NewBoard => Action::NewBoard,
             KeyBindingEnum::NewCard => Action::NewCard,
@@ -526,10 +1020,26 @@ impl KeyBindings {
             KeyBindingEnum::ResetUI => Action::ResetUI,
             KeyBindingEnum::Right => Action::Right,
             KeyBindingEnum::SaveState => Action::SaveState,
+            KeyBindingEnum::SelectCardRangeDown => Action::SelectCardRangeDown,
+            KeyBindingEnum::SelectCardRangeUp => Action::SelectCardRangeUp,
+            KeyBindingEnum::SetCardHighlightColor => Action::SetCardHighlightColor,
+            KeyBindingEnum::ShrinkSecondaryPane => Action::ShrinkSecondaryPane,
+            KeyBindingEnum::ShowBoardStats => Action::ShowBoardStats,
+            KeyBindingEnum::SkipTutorialStep => Action::SkipTutorialStep,
+            KeyBindingEnum::SortCards => Action::SortCards,
+            KeyBindingEnum::StartHistoryStepCount => Action::StartHistoryStepCount,
             KeyBindingEnum::StopUserInput => Action::StopUserInput,
             KeyBindingEnum::TakeUserInput => Action::TakeUserInput,
+            KeyBindingEnum::ToggleCardSelection => Action::ToggleCardSelection,
             KeyBindingEnum::ToggleCommandPalette => Action::ToggleCommandPalette,
+            KeyBindingEnum::ToggleCompletedCardsVisibility => {
+                Action::ToggleCompletedCardsVisibility
+            }
+            KeyBindingEnum::ToggleArchivedView => Action::ToggleArchivedView,
+            KeyBindingEnum::ToggleFocusTimer => Action::ToggleFocusTimer,
+            KeyBindingEnum::ToggleNotifications => Action::ToggleNotifications,
             KeyBindingEnum::Undo => Action::Undo,
+            KeyBindingEnum::UndoFromToast => Action::UndoFromToast,
             KeyBindingEnum::Up => Action::Up,
         }
     }
@@ -550,6 +1060,8 @@ impl KeyBindings {
                 KeyBindingEnum::ChangeCardStatusToStale => {
                     self.change_card_status_to_stale = keybinding
                 }
+                KeyBindingEnum::ArchiveCard => self.archive_card = keybinding,
+                KeyBindingEnum::ArchiveBoard => self.archive_board = keybinding,
                 KeyBindingEnum::ChangeCardPriorityToHigh => {
                     self.change_card_priority_to_high = keybinding
                 }
@@ -563,15 +1075,28 @@ impl KeyBindings {
                 KeyBindingEnum::DeleteBoard => self.delete_board = keybinding,
                 KeyBindingEnum::DeleteCard => self.delete_card = keybinding,
                 KeyBindingEnum::Down => self.down = keybinding,
+                KeyBindingEnum::DuplicateBoard => self.duplicate_board = keybinding,
+                KeyBindingEnum::DuplicateCard => self.duplicate_card = keybinding,
+                KeyBindingEnum::EditBoard => self.edit_board = keybinding,
+                KeyBindingEnum::ExportToMarkdown => self.export_to_markdown = keybinding,
+                KeyBindingEnum::FilterByDateRange => self.filter_by_date_range = keybinding,
+                KeyBindingEnum::FilterByPriority => self.filter_by_priority = keybinding,
+                KeyBindingEnum::FilterByStatus => self.filter_by_status = keybinding,
                 KeyBindingEnum::GoToMainMenu => self.go_to_main_menu = keybinding,
                 KeyBindingEnum::GoToPreviousViewOrCancel => {
                     self.go_to_previous_view_or_cancel = keybinding
                 }
+                KeyBindingEnum::GrowSecondaryPane => self.grow_secondary_pane = keybinding,
                 KeyBindingEnum::HideUiElement => self.hide_ui_element = keybinding,
                 KeyBindingEnum::Left => self.left = keybinding,
+                KeyBindingEnum::MoveBoardLeft => self.move_board_left = keybinding,
+                KeyBindingEnum::MoveBoardRight => self.move_board_right = keybinding,
                 KeyBindingEnum::MoveCardDown => self.move_card_down = keybinding,
                 KeyBindingEnum::MoveCardLeft => self.move_card_left = keybinding,
                 KeyBindingEnum::MoveCardRight => self.move_card_right = keybinding,
+                KeyBindingEnum::MoveCardToBoard => self.move_card_to_board = keybinding,
+                KeyBindingEnum::MoveCardToBottom => self.move_card_to_bottom = keybinding,
+                KeyBindingEnum::MoveCardToTop => self.move_card_to_top = keybinding,
                 KeyBindingEnum::MoveCardUp => self.move_card_up = keybinding,
                 KeyBindingEnum::NewBoard => self.new_board = keybinding,
                 KeyBindingEnum::NewCard => self.new_card = keybinding,
@@ -583,10 +1108,26 @@ impl KeyBindings {
                 KeyBindingEnum::ResetUI => self.reset_ui = keybinding,
                 KeyBindingEnum::Right => self.right = keybinding,
                 KeyBindingEnum::SaveState => self.save_state = keybinding,
+                KeyBindingEnum::SelectCardRangeDown => self.select_card_range_down = keybinding,
+                KeyBindingEnum::SelectCardRangeUp => self.select_card_range_up = keybinding,
+                KeyBindingEnum::SetCardHighlightColor => self.set_card_highlight_color = keybinding,
+                KeyBindingEnum::ShrinkSecondaryPane => self.shrink_secondary_pane = keybinding,
+                KeyBindingEnum::ShowBoardStats => self.show_board_stats = keybinding,
+                KeyBindingEnum::SkipTutorialStep => self.skip_tutorial_step = keybinding,
+                KeyBindingEnum::SortCards => self.sort_cards = keybinding,
+                KeyBindingEnum::StartHistoryStepCount => self.start_history_step_count = keybinding,
                 KeyBindingEnum::StopUserInput => self.stop_user_input = keybinding,
                 KeyBindingEnum::TakeUserInput => self.take_user_input = keybinding,
+                KeyBindingEnum::ToggleCardSelection => self.toggle_card_selection = keybinding,
                 KeyBindingEnum::ToggleCommandPalette => self.toggle_command_palette = keybinding,
+                KeyBindingEnum::ToggleCompletedCardsVisibility => {
+                    self.toggle_completed_cards_visibility = keybinding
+                }
+                KeyBindingEnum::ToggleArchivedView => self.toggle_archived_view = keybinding,
+                KeyBindingEnum::ToggleFocusTimer => self.toggle_focus_timer = keybinding,
+                KeyBindingEnum::ToggleNotifications => self.toggle_notifications = keybinding,
                 KeyBindingEnum::Undo => self.undo = keybinding,
+                KeyBindingEnum::UndoFromToast => self.undo_from_toast = keybinding,
                 KeyBindingEnum::Up => self.up = keybinding,
             }
         } else {
@@ -607,6 +1148,8 @@ impl KeyBindings {
             KeyBindingEnum::ChangeCardStatusToStale => {
                 Some(self.change_card_status_to_stale.clone())
             }
+            KeyBindingEnum::ArchiveCard => Some(self.archive_card.clone()),
+            KeyBindingEnum::ArchiveBoard => Some(self.archive_board.clone()),
             KeyBindingEnum::ChangeCardPriorityToHigh => {
                 Some(self.change_card_priority_to_high.clone())
             }
@@ -620,15 +1163,28 @@ impl KeyBindings {
             KeyBindingEnum::DeleteBoard => Some(self.delete_board.clone()),
             KeyBindingEnum::DeleteCard => Some(self.delete_card.clone()),
             KeyBindingEnum::Down => Some(self.down.clone()),
+            KeyBindingEnum::DuplicateBoard => Some(self.duplicate_board.clone()),
+            KeyBindingEnum::DuplicateCard => Some(self.duplicate_card.clone()),
+            KeyBindingEnum::EditBoard => Some(self.edit_board.clone()),
+            KeyBindingEnum::ExportToMarkdown => Some(self.export_to_markdown.clone()),
+            KeyBindingEnum::FilterByDateRange => Some(self.filter_by_date_range.clone()),
+            KeyBindingEnum::FilterByPriority => Some(self.filter_by_priority.clone()),
+            KeyBindingEnum::FilterByStatus => Some(self.filter_by_status.clone()),
             KeyBindingEnum::GoToMainMenu => Some(self.go_to_main_menu.clone()),
             KeyBindingEnum::GoToPreviousViewOrCancel => {
                 Some(self.go_to_previous_view_or_cancel.clone())
             }
+            KeyBindingEnum::GrowSecondaryPane => Some(self.grow_secondary_pane.clone()),
             KeyBindingEnum::HideUiElement => Some(self.hide_ui_element.clone()),
             KeyBindingEnum::Left => Some(self.left.clone()),
+            KeyBindingEnum::MoveBoardLeft => Some(self.move_board_left.clone()),
+            KeyBindingEnum::MoveBoardRight => Some(self.move_board_right.clone()),
             KeyBindingEnum::MoveCardDown => Some(self.move_card_down.clone()),
             KeyBindingEnum::MoveCardLeft => Some(self.move_card_left.clone()),
             KeyBindingEnum::MoveCardRight => Some(self.move_card_right.clone()),
+            KeyBindingEnum::MoveCardToBoard => Some(self.move_card_to_board.clone()),
+            KeyBindingEnum::MoveCardToBottom => Some(self.move_card_to_bottom.clone()),
+            KeyBindingEnum::MoveCardToTop => Some(self.move_card_to_top.clone()),
             KeyBindingEnum::MoveCardUp => Some(self.move_card_up.clone()),
             KeyBindingEnum::NewBoard => Some(self.new_board.clone()),
             KeyBindingEnum::NewCard => Some(self.new_card.clone()),
@@ -640,10 +1196,26 @@ impl KeyBindings {
             KeyBindingEnum::ResetUI => Some(self.reset_ui.clone()),
             KeyBindingEnum::Right => Some(self.right.clone()),
             KeyBindingEnum::SaveState => Some(self.save_state.clone()),
+            KeyBindingEnum::SelectCardRangeDown => Some(self.select_card_range_down.clone()),
+            KeyBindingEnum::SelectCardRangeUp => Some(self.select_card_range_up.clone()),
+            KeyBindingEnum::SetCardHighlightColor => Some(self.set_card_highlight_color.clone()),
+            KeyBindingEnum::ShrinkSecondaryPane => Some(self.shrink_secondary_pane.clone()),
+            KeyBindingEnum::ShowBoardStats => Some(self.show_board_stats.clone()),
+            KeyBindingEnum::SkipTutorialStep => Some(self.skip_tutorial_step.clone()),
+            KeyBindingEnum::SortCards => Some(self.sort_cards.clone()),
+            KeyBindingEnum::StartHistoryStepCount => Some(self.start_history_step_count.clone()),
             KeyBindingEnum::StopUserInput => Some(self.stop_user_input.clone()),
             KeyBindingEnum::TakeUserInput => Some(self.take_user_input.clone()),
+            KeyBindingEnum::ToggleCardSelection => Some(self.toggle_card_selection.clone()),
             KeyBindingEnum::ToggleCommandPalette => Some(self.toggle_command_palette.clone()),
+            KeyBindingEnum::ToggleCompletedCardsVisibility => {
+                Some(self.toggle_completed_cards_visibility.clone())
+            }
+            KeyBindingEnum::ToggleArchivedView => Some(self.toggle_archived_view.clone()),
+            KeyBindingEnum::ToggleFocusTimer => Some(self.toggle_focus_timer.clone()),
+            KeyBindingEnum::ToggleNotifications => Some(self.toggle_notifications.clone()),
             KeyBindingEnum::Undo => Some(self.undo.clone()),
+            KeyBindingEnum::UndoFromToast => Some(self.undo_from_toast.clone()),
             KeyBindingEnum::Up => Some(self.up.clone()),
         }
     }
@@ -656,6 +1228,8 @@ impl Default for KeyBindings {
             change_card_status_to_completed: vec![Key::Char('1')],
             change_card_status_to_active: vec![Key::Char('2')],
             change_card_status_to_stale: vec![Key::Char('3')],
+            archive_card: vec![Key::Char('A')],
+            archive_board: vec![Key::Char('B')],
             change_card_priority_to_high: vec![Key::Char('4')],
             change_card_priority_to_medium: vec![Key::Char('5')],
             change_card_priority_to_low: vec![Key::Char('6')],
@@ -663,13 +1237,26 @@ impl Default for KeyBindings {
             delete_board: vec![Key::Char('D')],
             delete_card: vec![Key::Char('d'), Key::Delete],
             down: vec![Key::Down],
+            duplicate_board: vec![],
+            duplicate_card: vec![Key::Ctrl('d')],
+            edit_board: vec![Key::Char('e')],
+            export_to_markdown: vec![Key::Ctrl('e')],
+            filter_by_date_range: vec![Key::Char('R')],
+            filter_by_priority: vec![Key::Char('P')],
+            filter_by_status: vec![Key::Char('F')],
             go_to_main_menu: vec![Key::Char('m')],
             go_to_previous_view_or_cancel: vec![Key::Esc],
+            grow_secondary_pane: vec![Key::Ctrl('=')],
             hide_ui_element: vec![Key::Char('h')],
             left: vec![Key::Left],
+            move_board_left: vec![Key::CtrlShiftLeft],
+            move_board_right: vec![Key::CtrlShiftRight],
             move_card_down: vec![Key::ShiftDown],
             move_card_left: vec![Key::ShiftLeft],
             move_card_right: vec![Key::ShiftRight],
+            move_card_to_board: vec![Key::Char('M')],
+            move_card_to_bottom: vec![Key::End],
+            move_card_to_top: vec![Key::Home],
             move_card_up: vec![Key::ShiftUp],
             new_board: vec![Key::Char('b')],
             new_card: vec![Key::Char('n')],
@@ -681,10 +1268,24 @@ impl Default for KeyBindings {
             reset_ui: vec![Key::Char('r')],
             right: vec![Key::Right],
             save_state: vec![Key::Ctrl('s')],
+            select_card_range_down: vec![Key::CtrlShiftDown],
+            select_card_range_up: vec![Key::CtrlShiftUp],
+            set_card_highlight_color: vec![Key::Char('C')],
+            shrink_secondary_pane: vec![Key::Ctrl('-')],
+            show_board_stats: vec![Key::Char('S')],
+            skip_tutorial_step: vec![Key::Char('>')],
+            sort_cards: vec![Key::Char('s')],
+            start_history_step_count: vec![Key::Char('#')],
             stop_user_input: vec![Key::Ins],
             take_user_input: vec![Key::Char('i')],
+            toggle_card_selection: vec![Key::Char('x')],
             toggle_command_palette: vec![Key::Ctrl('p')],
+            toggle_completed_cards_visibility: vec![Key::Char('H')],
+            toggle_archived_view: vec![Key::Char('V')],
+            toggle_focus_timer: vec![Key::Char('T')],
+            toggle_notifications: vec![Key::Char('N')],
             undo: vec![Key::Ctrl('z')],
+            undo_from_toast: vec![Key::Char('u')],
             up: vec![Key::Up],
         }
     }
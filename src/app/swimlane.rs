@@ -0,0 +1,152 @@
+//! Cards of the shown boards split into one lane per status for the Swimlane view
+//!
+//! ```
+//! use rust_kanban::app::{
+//!     kanban::{Card, CardStatus, Cards},
+//!     swimlane::SwimlaneGrid,
+//! };
+//!
+//! let card = |id: u64, card_status: CardStatus| {
+//!     let mut card = Card::default();
+//!     card.id = (id, 0);
+//!     card.card_status = card_status;
+//!     card
+//! };
+//! let grid = SwimlaneGrid::new(vec![
+//!     (
+//!         (1, 0),
+//!         Cards::from(vec![
+//!             card(1, CardStatus::Active),
+//!             card(2, CardStatus::Stale),
+//!             card(3, CardStatus::Active),
+//!         ]),
+//!     ),
+//!     ((2, 0), Cards::from(vec![card(4, CardStatus::Complete)])),
+//! ]);
+//! assert_eq!(grid.cell(&CardStatus::Active, (1, 0)), [(1, 0), (3, 0)]);
+//! assert!(grid.cell(&CardStatus::Active, (2, 0)).is_empty());
+//! assert_eq!(grid.lane_of((1, 0), (2, 0)), Some(CardStatus::Stale));
+//!
+//! // Down goes through the cell, then on to the next lane even when it is empty
+//! let active = CardStatus::Active;
+//! assert_eq!(
+//!     grid.step((1, 0), &active, Some((1, 0)), true),
+//!     Some((CardStatus::Active, Some((3, 0))))
+//! );
+//! assert_eq!(
+//!     grid.step((1, 0), &active, Some((3, 0)), true),
+//!     Some((CardStatus::Complete, None))
+//! );
+//! assert_eq!(
+//!     grid.step((1, 0), &CardStatus::Complete, None, true),
+//!     Some((CardStatus::Stale, Some((2, 0))))
+//! );
+//! // Up lands on the last card of the lane above
+//! assert_eq!(
+//!     grid.step((1, 0), &CardStatus::Complete, None, false),
+//!     Some((CardStatus::Active, Some((3, 0))))
+//! );
+//! // Nothing above the first lane or below the last one
+//! assert_eq!(grid.step((1, 0), &active, Some((1, 0)), false), None);
+//! assert_eq!(grid.step((2, 0), &CardStatus::Stale, None, true), None);
+//! ```
+
+use crate::app::kanban::{CardStatus, Cards};
+
+/// One row of the Swimlane view, with a cell of card ids for every board column
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Lane {
+    pub status: CardStatus,
+    /// In the same order as [`SwimlaneGrid::board_ids`]
+    pub cells: Vec<Vec<(u64, u64)>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SwimlaneGrid {
+    pub board_ids: Vec<(u64, u64)>,
+    pub lanes: Vec<Lane>,
+}
+
+impl SwimlaneGrid {
+    /// Takes the cards each board shows, cards keep their board order within a cell
+    pub fn new(columns: Vec<((u64, u64), Cards)>) -> Self {
+        let lanes = CardStatus::all()
+            .into_iter()
+            .map(|status| Lane {
+                cells: columns
+                    .iter()
+                    .map(|(_, cards)| {
+                        cards
+                            .get_all_cards()
+                            .iter()
+                            .filter(|card| card.card_status == status)
+                            .map(|card| card.id)
+                            .collect()
+                    })
+                    .collect(),
+                status,
+            })
+            .collect();
+        Self {
+            board_ids: columns.into_iter().map(|(board_id, _)| board_id).collect(),
+            lanes,
+        }
+    }
+
+    /// The cards of `board_id` in the `status` lane, empty when the board is not shown
+    pub fn cell(&self, status: &CardStatus, board_id: (u64, u64)) -> &[(u64, u64)] {
+        let Some(column) = self.board_ids.iter().position(|id| *id == board_id) else {
+            return &[];
+        };
+        self.lanes
+            .iter()
+            .find(|lane| lane.status == *status)
+            .map_or(&[], |lane| lane.cells[column].as_slice())
+    }
+
+    /// The lane a card of `board_id` is in
+    pub fn lane_of(&self, board_id: (u64, u64), card_id: (u64, u64)) -> Option<CardStatus> {
+        self.lanes
+            .iter()
+            .find(|lane| self.cell(&lane.status, board_id).contains(&card_id))
+            .map(|lane| lane.status.clone())
+    }
+
+    /// The lane and card one step down (or up) the column of `board_id`. Steps through the
+    /// cards of the cell first and then into the next lane, which may have no card to select.
+    /// None when there is no lane left to go to
+    pub fn step(
+        &self,
+        board_id: (u64, u64),
+        status: &CardStatus,
+        card_id: Option<(u64, u64)>,
+        forward: bool,
+    ) -> Option<(CardStatus, Option<(u64, u64)>)> {
+        let cell = self.cell(status, board_id);
+        if let Some(card_index) =
+            card_id.and_then(|card_id| cell.iter().position(|id| *id == card_id))
+        {
+            let next_index = if forward {
+                Some(card_index + 1)
+            } else {
+                card_index.checked_sub(1)
+            };
+            if let Some(next_card_id) = next_index.and_then(|index| cell.get(index)) {
+                return Some((status.clone(), Some(*next_card_id)));
+            }
+        }
+        let lane_index = self.lanes.iter().position(|lane| lane.status == *status)?;
+        let next_lane = if forward {
+            self.lanes.get(lane_index + 1)?
+        } else {
+            self.lanes.get(lane_index.checked_sub(1)?)?
+        };
+        let next_cell = self.cell(&next_lane.status, board_id);
+        let next_card_id = if forward {
+            next_cell.first()
+        } else {
+            next_cell.last()
+        };
+        Some((next_lane.status.clone(), next_card_id.copied()))
+    }
+}
@@ -0,0 +1,249 @@
+//! Which boards and cards fit on screen. Kept free of app state so the keyboard, mouse and
+//! refresh paths all window the same way
+
+/// The `n` wide window of `ids` starting at `start_index`, pulled back so it stays full when
+/// the start is near the end
+fn window_from<T: Copy>(ids: &[T], start_index: usize, n: usize) -> Vec<T> {
+    let start_index = start_index.min(ids.len().saturating_sub(n));
+    let end_index = (start_index + n).min(ids.len());
+    ids[start_index..end_index].to_vec()
+}
+
+/// Where `window` begins in `ids`, taken from its first item that is still present since items
+/// can be hidden or deleted after the window was made
+fn window_start_index<T: PartialEq>(ids: &[T], window: &[T]) -> Option<usize> {
+    window
+        .iter()
+        .find_map(|window_id| ids.iter().position(|id| id == window_id))
+}
+
+/// Window that starts at `current` (the first item when there is none or it is not in `ids`)
+fn window_around<T: Copy + PartialEq>(ids: &[T], current: Option<T>, n: usize) -> Vec<T> {
+    let start_index = current
+        .and_then(|current| ids.iter().position(|id| *id == current))
+        .unwrap_or(0);
+    window_from(ids, start_index, n)
+}
+
+pub fn board_window(
+    board_ids: &[(u64, u64)],
+    current_board: Option<(u64, u64)>,
+    n: usize,
+) -> Vec<(u64, u64)> {
+    window_around(board_ids, current_board, n)
+}
+
+pub fn card_window(
+    card_ids: &[(u64, u64)],
+    current_card: Option<(u64, u64)>,
+    n: usize,
+) -> Vec<(u64, u64)> {
+    window_around(card_ids, current_card, n)
+}
+
+/// Keeps the start of `window` while `current` is still inside it, otherwise falls back to
+/// a window starting at `current`
+pub fn keep_window_around<T: Copy + PartialEq>(
+    ids: &[T],
+    window: &[T],
+    current: Option<T>,
+    n: usize,
+) -> Vec<T> {
+    let start_index = window_start_index(ids, window).unwrap_or(0);
+    match current.and_then(|current| ids.iter().position(|id| *id == current)) {
+        Some(current_index) if current_index < start_index || current_index >= start_index + n => {
+            window_from(ids, current_index, n)
+        }
+        _ => window_from(ids, start_index, n),
+    }
+}
+
+/// `window` moved as little as possible to bring `current` into it, the same distance the
+/// navigation would have scrolled it
+pub fn scroll_window_to<T: Copy + PartialEq>(
+    ids: &[T],
+    window: &[T],
+    current: T,
+    n: usize,
+) -> Vec<T> {
+    let start_index = window_start_index(ids, window).unwrap_or(0);
+    match ids.iter().position(|id| *id == current) {
+        Some(current_index) if current_index < start_index => window_from(ids, current_index, n),
+        Some(current_index) if current_index >= start_index + n => {
+            window_from(ids, current_index + 1 - n, n)
+        }
+        _ => window_from(ids, start_index, n),
+    }
+}
+
+/// Every id but `current`, nearest to it first. At the same distance the one after `current`
+/// comes first
+///
+/// ```
+/// use rust_kanban::app::visibility::nearest_first;
+///
+/// assert_eq!(nearest_first(&[1, 2, 3, 4, 5], 2), [3, 1, 4, 5]);
+/// assert_eq!(nearest_first(&[1, 2, 3], 3), [2, 1]);
+/// assert_eq!(nearest_first(&[1, 2, 3], 7), [1, 2, 3]);
+/// ```
+pub fn nearest_first<T: Copy + PartialEq>(ids: &[T], current: T) -> Vec<T> {
+    let Some(current_index) = ids.iter().position(|id| *id == current) else {
+        return ids.to_vec();
+    };
+    let mut sorted_ids = ids
+        .iter()
+        .enumerate()
+        .filter(|(index, _)| *index != current_index)
+        .map(|(index, id)| ((index.abs_diff(current_index), index < current_index), *id))
+        .collect::<Vec<((usize, bool), T)>>();
+    sorted_ids.sort_by_key(|(distance, _)| *distance);
+    sorted_ids.into_iter().map(|(_, id)| id).collect()
+}
+
+/// `window` moved one item towards the end of `ids`, unchanged when the last item is already in
+/// it
+pub fn shift_window_forward<T: Copy + PartialEq>(ids: &[T], window: &[T], n: usize) -> Vec<T> {
+    let start_index = window_start_index(ids, window).unwrap_or(0);
+    window_from(ids, start_index + 1, n)
+}
+
+/// `window` moved one item towards the start of `ids`, unchanged when the first item is already
+/// in it
+pub fn shift_window_back<T: Copy + PartialEq>(ids: &[T], window: &[T], n: usize) -> Vec<T> {
+    let start_index = window_start_index(ids, window).unwrap_or(0);
+    window_from(ids, start_index.saturating_sub(1), n)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const IDS: [u32; 5] = [1, 2, 3, 4, 5];
+
+    fn board_ids(count: u64) -> Vec<(u64, u64)> {
+        (0..count).map(|index| (index, index)).collect()
+    }
+
+    #[test]
+    fn windows_of_an_empty_list_are_empty() {
+        assert!(board_window(&[], None, 3).is_empty());
+        assert!(board_window(&[], Some((1, 1)), 3).is_empty());
+        assert!(card_window(&[], Some((1, 1)), 3).is_empty());
+        assert!(keep_window_around::<u32>(&[], &[1, 2], Some(1), 3).is_empty());
+        assert!(scroll_window_to::<u32>(&[], &[], 1, 3).is_empty());
+        assert!(shift_window_forward::<u32>(&[], &[], 3).is_empty());
+        assert!(shift_window_back::<u32>(&[], &[], 3).is_empty());
+        assert!(nearest_first::<u32>(&[], 1).is_empty());
+    }
+
+    #[test]
+    fn windows_of_a_single_item_hold_that_item() {
+        let ids = board_ids(1);
+        assert_eq!(board_window(&ids, None, 3), ids);
+        assert_eq!(board_window(&ids, Some(ids[0]), 3), ids);
+        assert_eq!(card_window(&ids, Some(ids[0]), 1), ids);
+        assert_eq!(keep_window_around(&[7], &[7], Some(7), 3), [7]);
+        assert_eq!(scroll_window_to(&[7], &[7], 7, 3), [7]);
+        assert_eq!(shift_window_forward(&[7], &[7], 1), [7]);
+        assert_eq!(shift_window_back(&[7], &[7], 1), [7]);
+        assert!(nearest_first(&[7], 7).is_empty());
+    }
+
+    #[test]
+    fn a_window_of_zero_items_is_empty() {
+        let ids = board_ids(3);
+        assert!(board_window(&ids, Some(ids[1]), 0).is_empty());
+        assert!(card_window(&ids, None, 0).is_empty());
+        assert!(keep_window_around(&IDS, &[], Some(3), 0).is_empty());
+        assert!(shift_window_forward(&IDS, &[], 0).is_empty());
+    }
+
+    #[test]
+    fn a_window_larger_than_the_list_holds_the_whole_list() {
+        let ids = board_ids(3);
+        for current in [None, Some(ids[0]), Some(ids[2])] {
+            assert_eq!(board_window(&ids, current, 10), ids);
+            assert_eq!(card_window(&ids, current, 10), ids);
+        }
+        assert_eq!(keep_window_around(&IDS, &[3], Some(5), 10), IDS);
+        assert_eq!(scroll_window_to(&IDS, &[3], 1, 10), IDS);
+        assert_eq!(shift_window_forward(&IDS, &IDS, 10), IDS);
+        assert_eq!(shift_window_back(&IDS, &IDS, 10), IDS);
+    }
+
+    #[test]
+    fn windows_start_at_the_current_item() {
+        let ids = board_ids(5);
+        assert_eq!(board_window(&ids, None, 2), ids[0..2]);
+        assert_eq!(board_window(&ids, Some(ids[0]), 2), ids[0..2]);
+        assert_eq!(board_window(&ids, Some(ids[1]), 2), ids[1..3]);
+        assert_eq!(card_window(&ids, Some(ids[2]), 3), ids[2..5]);
+    }
+
+    #[test]
+    fn windows_near_the_end_are_pulled_back_to_stay_full() {
+        let ids = board_ids(5);
+        assert_eq!(board_window(&ids, Some(ids[4]), 2), ids[3..5]);
+        assert_eq!(card_window(&ids, Some(ids[3]), 3), ids[2..5]);
+    }
+
+    #[test]
+    fn an_unknown_current_item_starts_the_window_at_the_top() {
+        let ids = board_ids(5);
+        assert_eq!(board_window(&ids, Some((9, 9)), 2), ids[0..2]);
+        assert_eq!(card_window(&ids, Some((9, 9)), 2), ids[0..2]);
+    }
+
+    #[test]
+    fn the_window_is_kept_while_the_current_item_is_in_it() {
+        assert_eq!(keep_window_around(&IDS, &[2, 3, 4], Some(2), 3), [2, 3, 4]);
+        assert_eq!(keep_window_around(&IDS, &[2, 3, 4], Some(4), 3), [2, 3, 4]);
+        assert_eq!(keep_window_around(&IDS, &[2, 3, 4], None, 3), [2, 3, 4]);
+    }
+
+    #[test]
+    fn the_window_restarts_at_a_current_item_outside_it() {
+        assert_eq!(keep_window_around(&IDS, &[2, 3, 4], Some(1), 3), [1, 2, 3]);
+        assert_eq!(keep_window_around(&IDS, &[1, 2, 3], Some(5), 3), [3, 4, 5]);
+    }
+
+    #[test]
+    fn windows_of_filtered_lists_start_at_their_first_remaining_item() {
+        // 2 was hidden or deleted after the window was made
+        let filtered_ids = [1, 3, 4, 5];
+        assert_eq!(
+            keep_window_around(&filtered_ids, &[2, 3], Some(3), 2),
+            [3, 4]
+        );
+        assert_eq!(shift_window_forward(&filtered_ids, &[2, 3], 2), [4, 5]);
+        assert_eq!(shift_window_back(&filtered_ids, &[2, 3], 2), [1, 3]);
+        assert_eq!(scroll_window_to(&filtered_ids, &[2, 3], 5, 2), [4, 5]);
+        // Nothing of the window is left
+        assert_eq!(keep_window_around(&filtered_ids, &[2], None, 2), [1, 3]);
+    }
+
+    #[test]
+    fn scrolling_moves_the_window_only_as_far_as_needed() {
+        assert_eq!(scroll_window_to(&IDS, &[2, 3], 3, 2), [2, 3]);
+        assert_eq!(scroll_window_to(&IDS, &[2, 3], 4, 2), [3, 4]);
+        assert_eq!(scroll_window_to(&IDS, &[2, 3], 5, 2), [4, 5]);
+        assert_eq!(scroll_window_to(&IDS, &[3, 4], 2, 2), [2, 3]);
+        assert_eq!(scroll_window_to(&IDS, &[3, 4], 1, 2), [1, 2]);
+        assert_eq!(scroll_window_to(&IDS, &[3, 4], 9, 2), [3, 4]);
+    }
+
+    #[test]
+    fn shifting_stops_at_the_edges() {
+        assert_eq!(shift_window_forward(&IDS, &[1, 2], 2), [2, 3]);
+        assert_eq!(shift_window_forward(&IDS, &[4, 5], 2), [4, 5]);
+        assert_eq!(shift_window_back(&IDS, &[2, 3], 2), [1, 2]);
+        assert_eq!(shift_window_back(&IDS, &[1, 2], 2), [1, 2]);
+    }
+
+    #[test]
+    fn nearest_first_handles_the_edges() {
+        assert_eq!(nearest_first(&IDS, 1), [2, 3, 4, 5]);
+        assert_eq!(nearest_first(&IDS, 5), [4, 3, 2, 1]);
+        assert_eq!(nearest_first(&IDS, 3), [4, 2, 5, 1]);
+    }
+}
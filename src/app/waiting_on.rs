@@ -0,0 +1,156 @@
+//! Cards waiting on someone, grouped by who they wait on for the waiting list popup. Names
+//! are grouped ignoring case and the groups are sorted by name. Completed and archived cards
+//! are not waiting on anyone anymore and are left out
+//!
+//! ```
+//! use rust_kanban::app::{
+//!     kanban::{Board, Boards, Card, CardStatus},
+//!     waiting_on::{WaitingOnGroups, WaitingOnRow},
+//! };
+//!
+//! let card = |id: u64, name: &str, waiting_on: Option<&str>| {
+//!     let mut card = Card::default();
+//!     card.id = (id, 0);
+//!     card.name = name.to_string();
+//!     card.waiting_on = waiting_on.map(str::to_string);
+//!     card
+//! };
+//! let mut board = Board::default();
+//! board.id = (1, 0);
+//! board.cards.add_card(card(1, "Budget", Some("Bob")));
+//! board.cards.add_card(card(2, "Logo", Some("alice")));
+//! board.cards.add_card(card(3, "Not waiting", None));
+//! board.cards.add_card(card(4, "Contract", Some("bob")));
+//! let mut done = card(5, "Done already", Some("Bob"));
+//! done.card_status = CardStatus::Complete;
+//! board.cards.add_card(done);
+//! let boards = Boards::from(vec![board]);
+//!
+//! let waiting_list = WaitingOnGroups::new(&boards);
+//! let rows = waiting_list
+//!     .rows
+//!     .iter()
+//!     .map(|row| match row {
+//!         WaitingOnRow::Group { waiting_on, .. } => format!("# {}", waiting_on),
+//!         WaitingOnRow::Card(card) => card.card_name.clone(),
+//!     })
+//!     .collect::<Vec<String>>();
+//! assert_eq!(rows, ["# alice", "Logo", "# Bob", "Budget", "Contract"]);
+//!
+//! // Selection only ever lands on cards, wrapping around at the ends
+//! assert_eq!(waiting_list.first_card_index(), Some(1));
+//! assert_eq!(waiting_list.step(Some(1), true), Some(3));
+//! assert_eq!(waiting_list.step(Some(4), true), Some(1));
+//! assert_eq!(waiting_list.step(Some(1), false), Some(4));
+//! assert!(waiting_list.card_at(2).is_none());
+//! assert_eq!(waiting_list.card_at(4).unwrap().card_id, (4, 0));
+//!
+//! assert!(WaitingOnGroups::new(&Boards::default()).first_card_index().is_none());
+//! ```
+
+use crate::app::kanban::{Boards, CardStatus};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WaitingCard {
+    pub board_id: (u64, u64),
+    pub card_id: (u64, u64),
+    pub card_name: String,
+    pub board_name: String,
+}
+
+/// One row of the waiting list, a group header is followed by the cards in it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WaitingOnRow {
+    Group {
+        waiting_on: String,
+        card_count: usize,
+    },
+    Card(WaitingCard),
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WaitingOnGroups {
+    pub rows: Vec<WaitingOnRow>,
+}
+
+impl WaitingOnGroups {
+    /// Groups keep the spelling of their first card, cards keep their board order
+    pub fn new(boards: &Boards) -> Self {
+        let mut groups: Vec<(String, Vec<WaitingCard>)> = vec![];
+        for board in boards.get_boards() {
+            for card in board.cards.get_all_cards() {
+                if matches!(
+                    card.card_status,
+                    CardStatus::Complete | CardStatus::Archived
+                ) {
+                    continue;
+                }
+                let Some(waiting_on) = card
+                    .waiting_on
+                    .as_deref()
+                    .map(str::trim)
+                    .filter(|waiting_on| !waiting_on.is_empty())
+                else {
+                    continue;
+                };
+                let waiting_card = WaitingCard {
+                    board_id: board.id,
+                    card_id: card.id,
+                    card_name: card.name.clone(),
+                    board_name: board.name.clone(),
+                };
+                match groups
+                    .iter_mut()
+                    .find(|(group_name, _)| group_name.eq_ignore_ascii_case(waiting_on))
+                {
+                    Some((_, cards)) => cards.push(waiting_card),
+                    None => groups.push((waiting_on.to_string(), vec![waiting_card])),
+                }
+            }
+        }
+        groups.sort_by_key(|(waiting_on, _)| waiting_on.to_lowercase());
+        let rows = groups
+            .into_iter()
+            .flat_map(|(waiting_on, cards)| {
+                std::iter::once(WaitingOnRow::Group {
+                    waiting_on,
+                    card_count: cards.len(),
+                })
+                .chain(cards.into_iter().map(WaitingOnRow::Card))
+            })
+            .collect();
+        Self { rows }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    /// None when the row is a group header
+    pub fn card_at(&self, index: usize) -> Option<&WaitingCard> {
+        match self.rows.get(index)? {
+            WaitingOnRow::Card(waiting_card) => Some(waiting_card),
+            WaitingOnRow::Group { .. } => None,
+        }
+    }
+
+    pub fn first_card_index(&self) -> Option<usize> {
+        (0..self.rows.len()).find(|index| self.card_at(*index).is_some())
+    }
+
+    /// The next (or previous) card row from `selected`, skipping group headers and wrapping
+    /// around at the ends
+    pub fn step(&self, selected: Option<usize>, forward: bool) -> Option<usize> {
+        let row_count = self.rows.len();
+        let selected = selected.filter(|selected| *selected < row_count)?;
+        (1..=row_count)
+            .map(|offset| {
+                if forward {
+                    (selected + offset) % row_count
+                } else {
+                    (selected + row_count - offset) % row_count
+                }
+            })
+            .find(|index| self.card_at(*index).is_some())
+    }
+}
@@ -0,0 +1,148 @@
+/// Notable changes of a release, shown in the what's new popup
+#[derive(Debug)]
+pub struct ChangelogEntry {
+    pub version: &'static str,
+    pub changes: &'static [&'static str],
+}
+
+/// Newest release first, a compact take on Changelog.md
+pub const CHANGELOG: &[ChangelogEntry] = &[
+    ChangelogEntry {
+        version: "0.10.5",
+        changes: &[
+            "Boards can be moved left or right from the command palette",
+            "Release builds use LTO",
+            "Updated dependencies",
+        ],
+    },
+    ChangelogEntry {
+        version: "0.10.4",
+        changes: &[
+            "Tag picker to reuse tags from other cards",
+            "Board progress bars show a count instead of a percentage",
+            "Faster background rendering",
+            "Fixed text selection not being visible",
+            "Fixed tags turning lowercase in the filter by tag view",
+        ],
+    },
+    ChangelogEntry {
+        version: "0.10.3",
+        changes: &[
+            "View card popup follows the configured date format",
+            "Left and right switch between hours, minutes and seconds in the time picker",
+            "Fixed a crash when picking a date while editing a card",
+        ],
+    },
+    ChangelogEntry {
+        version: "0.10.2",
+        changes: &[
+            "Popups below the top one are drawn inactive",
+            "Fixed editing a specific keybinding not opening its popup",
+            "Fixed the config being editable while a popup is open",
+        ],
+    },
+];
+
+/// Major, minor and patch of a version like "0.10.5", anything after a '-' or '+' is ignored
+pub fn parse_version(version: &str) -> Option<(u64, u64, u64)> {
+    let core_version = version.trim().split(['-', '+']).next()?;
+    let mut parts = core_version.split('.').map(|part| part.parse::<u64>());
+    let major = parts.next()?.ok()?;
+    let minor = parts.next().unwrap_or(Ok(0)).ok()?;
+    let patch = parts.next().unwrap_or(Ok(0)).ok()?;
+    Some((major, minor, patch))
+}
+
+/// Entries newer than `last_seen_version` up to and including `current_version`, newest first.
+/// Without a last seen version only the current release is returned
+pub fn get_entries_since(
+    last_seen_version: Option<&str>,
+    current_version: &str,
+) -> Vec<&'static ChangelogEntry> {
+    let current_version = parse_version(current_version);
+    let last_seen_version = last_seen_version.and_then(parse_version);
+    CHANGELOG
+        .iter()
+        .filter(|entry| {
+            let entry_version = parse_version(entry.version);
+            entry_version <= current_version
+                && match last_seen_version {
+                    Some(last_seen_version) => entry_version > Some(last_seen_version),
+                    None => entry_version == current_version,
+                }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn versions(entries: &[&ChangelogEntry]) -> Vec<&'static str> {
+        entries.iter().map(|entry| entry.version).collect()
+    }
+
+    #[test]
+    fn the_changelog_is_newest_first_without_duplicates() {
+        let parsed_versions = CHANGELOG
+            .iter()
+            .map(|entry| {
+                parse_version(entry.version)
+                    .unwrap_or_else(|| panic!("{} is not a version", entry.version))
+            })
+            .collect::<Vec<(u64, u64, u64)>>();
+        for pair in parsed_versions.windows(2) {
+            assert!(
+                pair[0] > pair[1],
+                "{:?} is not newer than {:?}",
+                pair[0],
+                pair[1]
+            );
+        }
+    }
+
+    #[test]
+    fn every_changelog_entry_lists_changes() {
+        for entry in CHANGELOG {
+            assert!(
+                !entry.changes.is_empty(),
+                "{} has no changes",
+                entry.version
+            );
+            for change in entry.changes {
+                assert!(
+                    !change.trim().is_empty(),
+                    "{} has an empty change",
+                    entry.version
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn the_current_release_is_in_the_changelog() {
+        assert_eq!(CHANGELOG[0].version, env!("CARGO_PKG_VERSION"));
+    }
+
+    #[test]
+    fn versions_are_compared_by_number() {
+        assert_eq!(parse_version("0.10.5"), Some((0, 10, 5)));
+        assert_eq!(parse_version(" 1.2 "), Some((1, 2, 0)));
+        assert_eq!(parse_version("2.0.1-beta.1+build"), Some((2, 0, 1)));
+        assert_eq!(parse_version("0.x.1"), None);
+        assert_eq!(parse_version(""), None);
+        assert!(parse_version("0.10.0") > parse_version("0.9.12"));
+    }
+
+    #[test]
+    fn entries_since_cover_every_skipped_release() {
+        assert_eq!(
+            versions(&get_entries_since(Some("0.10.2"), "0.10.4")),
+            vec!["0.10.4", "0.10.3"]
+        );
+        assert_eq!(versions(&get_entries_since(None, "0.10.4")), vec!["0.10.4"]);
+        assert!(get_entries_since(Some("0.10.4"), "0.10.4").is_empty());
+        // A downgrade has nothing new to show
+        assert!(get_entries_since(Some("0.10.5"), "0.10.3").is_empty());
+    }
+}
@@ -0,0 +1,762 @@
+use crate::{
+    constants::{DEFAULT_LANGUAGE, LANGUAGE_DIR_NAME},
+    io::io_handler::get_config_dir,
+};
+use log::{debug, error, info, warn};
+use parking_lot::RwLock;
+use std::{collections::HashMap, fs, path::PathBuf, str::FromStr};
+use strum::IntoEnumIterator;
+use strum_macros::{Display, EnumIter, EnumString};
+
+/// Identifies a user facing string, the variant name is used as the key in language files
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EnumIter, EnumString, Display)]
+pub enum MessageId {
+    ActionChangeCardStatusToActive,
+    ActionChangeCardStatusToCompleted,
+    ActionChangeCardStatusToStale,
+    ActionArchiveCard,
+    ActionArchiveBoard,
+    ActionChangeCardPriorityToHigh,
+    ActionChangeCardPriorityToMedium,
+    ActionChangeCardPriorityToLow,
+    ActionClearAllToasts,
+    ActionDelete,
+    ActionDeleteBoard,
+    ActionDown,
+    ActionDuplicateBoard,
+    ActionDuplicateCard,
+    ActionEditBoard,
+    ActionExportToMarkdown,
+    ActionFilterByDateRange,
+    ActionFilterByPriority,
+    ActionFilterByStatus,
+    ActionAccept,
+    ActionGoToMainMenu,
+    ActionGoToPreviousViewOrCancel,
+    ActionGrowSecondaryPane,
+    ActionHideUiElement,
+    ActionLeft,
+    ActionMoveBoardLeft,
+    ActionMoveBoardRight,
+    ActionMoveCardDown,
+    ActionMoveCardLeft,
+    ActionMoveCardRight,
+    ActionMoveCardToBoard,
+    ActionMoveCardToBottom,
+    ActionMoveCardToTop,
+    ActionMoveCardUp,
+    ActionNewBoard,
+    ActionNewCard,
+    ActionNextFocus,
+    ActionOpenConfigMenu,
+    ActionPrvFocus,
+    ActionQuit,
+    ActionRedo,
+    ActionResetUI,
+    ActionRight,
+    ActionSaveState,
+    ActionSelectCardRangeDown,
+    ActionSelectCardRangeUp,
+    ActionSetCardHighlightColor,
+    ActionShrinkSecondaryPane,
+    ActionShowBoardStats,
+    ActionSkipTutorialStep,
+    ActionSortCards,
+    ActionStartHistoryStepCount,
+    ActionStopUserInput,
+    ActionTakeUserInput,
+    ActionToggleCardSelection,
+    ActionToggleCommandPalette,
+    ActionToggleCompletedCardsVisibility,
+    ActionToggleArchivedView,
+    ActionToggleFocusTimer,
+    ActionToggleNotifications,
+    ActionUndo,
+    ActionUndoFromToast,
+    ActionUp,
+    ConfigAlwaysLoadLastSave,
+    ConfigAutoLogin,
+    ConfigCardPreviewLines,
+    ConfigCardDensity,
+    ConfigCardAgingDays,
+    ConfigAutoArchiveCompletedDays,
+    ConfigAutoSaveInterval,
+    ConfigLogFilters,
+    ConfigCardAgingCue,
+    ConfigColorDepth,
+    ConfigCardFaceLayout,
+    ConfigDateFormat,
+    ConfigDefaultTheme,
+    ConfigDefaultView,
+    ConfigDisableAnimations,
+    ConfigDisableScrollBar,
+    ConfigEnableMouseSupport,
+    ConfigEnableEventLog,
+    ConfigWrapAroundNavigation,
+    ConfigKeybindings,
+    ConfigLanguage,
+    ConfigNoOfBoardsToShow,
+    ConfigNoOfCardsToShow,
+    ConfigRapidCardEntry,
+    ConfigCheckForDuplicateCardNames,
+    ConfigNormalizeUnicodeInput,
+    ConfigKeepIdsOnImport,
+    ConfigEnableDesktopNotifications,
+    ConfigHighContrastMode,
+    ConfigDatePickerCalenderFormat,
+    ConfigSaveDirectory,
+    ConfigSaveFilesToKeep,
+    ConfigMirrorDirectory,
+    ConfigSaveOnExit,
+    ConfigShowLineNumbers,
+    ConfigShowChecklistProgress,
+    ConfigTickrate,
+    ConfigWarningDelta,
+    MainMenuView,
+    MainMenuConfig,
+    MainMenuHelp,
+    MainMenuStats,
+    MainMenuLoadSaveLocal,
+    MainMenuLoadSaveCloud,
+    MainMenuQuit,
+    ButtonCancel,
+    ButtonClearWaitingOn,
+    ButtonCreateAnyway,
+    ButtonDiscard,
+    ButtonGoToExisting,
+    ButtonKeepDraft,
+    ButtonKeepWaitingOn,
+    ButtonNo,
+    ButtonQuitWithoutSaving,
+    ButtonSaveAndQuit,
+    ButtonYes,
+    TitleClearWaitingOn,
+    TitleConfigEditor,
+    TitleDuplicateCardName,
+    TitleKeepDraft,
+    TitleMainMenu,
+    TitleRestoreThemesFromBackup,
+    TitleRecoverSaveFile,
+    TitleMirrorSync,
+    TitleNotifications,
+    TitleWhatsNew,
+    TitleConflictMarkers,
+    TitleSaveChangesToCard,
+    TitleUnsavedChanges,
+    TitleAffectedCards,
+    TitleArchivedCards,
+    TitleBoardNotes,
+    TitleBoards,
+    TitleCalendar,
+    TitleCardTemplates,
+    TitleCardsByPriority,
+    TitleCardsByStatus,
+    TitleEstimateByStatus,
+    TitleConflicts,
+    TitleCustomDueDateRange,
+    TitleDuplicateBoards,
+    TitleDuplicates,
+    TitleFilterByDueDate,
+    TitleFilterByPriority,
+    TitleFilterByStatus,
+    TitleFindAndReplace,
+    TitleGithubImport,
+    TitleHelp,
+    TitleImportOptions,
+    TitleLogs,
+    TitleMostOverdue,
+    TitlePreview,
+    TitleSearchCards,
+    TitleSetLogLevel,
+    TitleStats,
+    TitleWaitingOn,
+    ToastConfigUpdated,
+    ToastCouldNotWriteConfig,
+    ToastFilterReset,
+    ToastNewBoardNameInvalid,
+    ToastNewCardNameInvalid,
+    ToastSomethingWentWrong,
+    ToastUiReset,
+    ToastCannotArchiveACardInThisView,
+    ToastCannotChangeBoardAccentInThisView,
+    ToastCannotChangeCardHighlightInThisView,
+    ToastCannotCopyABoardInThisView,
+    ToastCannotCopyACardInThisView,
+    ToastCannotDuplicateABoardInThisView,
+    ToastCannotDuplicateACardInThisView,
+    ToastCannotEditABoardInThisView,
+    ToastCannotEditBoardNotesInThisView,
+    ToastCannotFilterCardsInThisView,
+    ToastCannotGoDownAlreadyAtTheLastStatus,
+    ToastCannotGoUpAlreadyAtTheFirstStatus,
+    ToastCannotMoveCardsInThisView,
+    ToastCannotMoveCardsOnAnAutoSortedBoard,
+    ToastCannotMoveCardsLeftAsItIsTheFirstBoard,
+    ToastCannotMoveCardsRightAsItIsTheLastBoard,
+    ToastCannotRedoInThisView,
+    ToastCannotSearchCardsInThisView,
+    ToastCannotSortCardsInThisView,
+    ToastCannotUndoInThisView,
+    ToastClearedMarkedCards,
+    ToastCouldNotFindCurrentBoard,
+    ToastCouldNotFindTheBoardBeingEdited,
+    ToastCouldNotFindTheBoardOfTheCard,
+    ToastCouldNotFindTheBoardToDuplicate,
+    ToastCouldNotFindTheBoardToMoveTheCardTo,
+    ToastCouldNotFindTheBoardToSort,
+    ToastCouldNotFindTheCardToArchive,
+    ToastCouldNotFindTheCardToCopy,
+    ToastCouldNotFindTheCardToDuplicate,
+    ToastCouldNotFindTheCardToMove,
+    ToastCouldNotFindTheCardToSaveAsATemplate,
+    ToastCouldNotFindTheSelectedBoard,
+    ToastCouldNotFindTheSelectedCard,
+    ToastCouldNotFindTheSelectedTemplate,
+    ToastCouldNotReadThemeBackups,
+    ToastCouldNotRecoverSaveFile,
+    ToastCouldNotRestoreThemesFromBackup,
+    ToastCouldNotSaveTheCardTemplate,
+    ToastCouldNotWriteMarkdownMirror,
+    ToastDraftDiscarded,
+    ToastEnterAGithubRepositoryAsOwnerName,
+    ToastEnterThePathOfATrelloExportToImport,
+    ToastEnterThePathOfASaveFileToImport,
+    ToastEnterThePathOfAnExportedBoardToImport,
+    ToastEnterTheTextToFind,
+    ToastErrorCouldNotFindCurrentBoard,
+    ToastErrorCouldNotFindSelectedCards,
+    ToastMarkdownMirrorCreated,
+    ToastMarkdownMirrorHasNoChangesToSync,
+    ToastNoArchivedCardSelected,
+    ToastNoBoardAvailableToEdit,
+    ToastNoBoardSelectedToArchive,
+    ToastNoBoardSelectedToCopy,
+    ToastNoBoardSelectedToDuplicate,
+    ToastNoBoardsToShow,
+    ToastNoCardSelectedToArchive,
+    ToastNoCardSelectedToCopy,
+    ToastNoCardSelectedToDuplicate,
+    ToastNoCardSelectedToFocusOn,
+    ToastNoCardsAreWaitingOnAnyone,
+    ToastNoChecklistItemSelected,
+    ToastNoChecklistItemsToDelete,
+    ToastNoDuplicateBoardsFound,
+    ToastNoFocusTimerIsRunning,
+    ToastNoThemeBackupsFound,
+    ToastNothingChangedSinceTheLastSave,
+    ToastOneOfTheDuplicateBoardsNoLongerExists,
+    ToastRestoredThemesFromBackup,
+    ToastSelectedCardsAlreadyHaveThatValue,
+    ToastStartDateMustNotBeAfterTheDueDate,
+    ToastThereAreNoOtherBoardsToMoveTheCardTo,
+    ToastTutorialCompleteYouAreAllSet,
+    ToastBoardDuplicated,
+    ToastCannotDeleteACloudSaveOthersDependOn,
+    ToastCardDuplicated,
+    ToastErrorSyncingLocalData,
+    ToastHistoryStepCouldNotBeApplied,
+    ToastLocalDataSyncedToTheCloud,
+    ToastLogLevelSetTo,
+    ToastNoMoreActionsToRedo,
+    ToastNoMoreActionsToUndo,
+    ToastNothingChangedSinceTheLastSync,
+    ToastRedidActions,
+    ToastRedidOneAction,
+    ToastSyncingLocalDataPleaseWait,
+    ToastTheLastSaveIsNotInTheUndoHistory,
+    ToastUndidActions,
+    ToastUndidOneAction,
+}
+
+impl MessageId {
+    pub fn english(&self) -> &'static str {
+        match self {
+            MessageId::ActionChangeCardStatusToActive => "Change card status to active",
+            MessageId::ActionChangeCardStatusToCompleted => "Change card status to completed",
+            MessageId::ActionChangeCardStatusToStale => "Change card status to stale",
+            MessageId::ActionArchiveCard => "Archive card",
+            MessageId::ActionArchiveBoard => "Archive or unarchive board",
+            MessageId::ActionChangeCardPriorityToHigh => "Change card priority to high",
+            MessageId::ActionChangeCardPriorityToMedium => "Change card priority to medium",
+            MessageId::ActionChangeCardPriorityToLow => "Change card priority to low",
+            MessageId::ActionClearAllToasts => "Clear all toasts",
+            MessageId::ActionDelete => "Delete focused element",
+            MessageId::ActionDeleteBoard => "Delete Board",
+            MessageId::ActionDown => "Go down",
+            MessageId::ActionDuplicateBoard => "Duplicate current board",
+            MessageId::ActionDuplicateCard => "Duplicate card",
+            MessageId::ActionEditBoard => "Edit current board",
+            MessageId::ActionExportToMarkdown => "Export all boards to markdown",
+            MessageId::ActionFilterByDateRange => "Filter cards by due date",
+            MessageId::ActionFilterByPriority => "Filter cards by priority",
+            MessageId::ActionFilterByStatus => "Filter cards by status",
+            MessageId::ActionAccept => "Accept",
+            MessageId::ActionGoToMainMenu => "Go to main menu",
+            MessageId::ActionGoToPreviousViewOrCancel => "Go to previous View or cancel",
+            MessageId::ActionGrowSecondaryPane => "Grow help and log panes",
+            MessageId::ActionHideUiElement => "Hide Focused element",
+            MessageId::ActionLeft => "Go left",
+            MessageId::ActionMoveBoardLeft => "Move current board left",
+            MessageId::ActionMoveBoardRight => "Move current board right",
+            MessageId::ActionMoveCardDown => "Move card down",
+            MessageId::ActionMoveCardLeft => "Move card left",
+            MessageId::ActionMoveCardRight => "Move card right",
+            MessageId::ActionMoveCardToBoard => "Move card to another board",
+            MessageId::ActionMoveCardToBottom => "Move card to bottom",
+            MessageId::ActionMoveCardToTop => "Move card to top",
+            MessageId::ActionMoveCardUp => "Move card up",
+            MessageId::ActionNewBoard => "Create new board",
+            MessageId::ActionNewCard => "Create new card in current board",
+            MessageId::ActionNextFocus => "Focus next",
+            MessageId::ActionOpenConfigMenu => "Configure",
+            MessageId::ActionPrvFocus => "Focus previous",
+            MessageId::ActionQuit => "Quit",
+            MessageId::ActionRedo => "Redo",
+            MessageId::ActionResetUI => "Reset UI",
+            MessageId::ActionRight => "Go right",
+            MessageId::ActionSaveState => "Save Kanban state",
+            MessageId::ActionSelectCardRangeDown => "Extend card selection down",
+            MessageId::ActionSelectCardRangeUp => "Extend card selection up",
+            MessageId::ActionSetCardHighlightColor => "Set highlight color for current card",
+            MessageId::ActionShrinkSecondaryPane => "Shrink help and log panes",
+            MessageId::ActionShowBoardStats => "Show board statistics",
+            MessageId::ActionSkipTutorialStep => "Skip the current tutorial step",
+            MessageId::ActionSortCards => "Sort cards in current board",
+            MessageId::ActionStartHistoryStepCount => "Type how many actions to undo or redo",
+            MessageId::ActionStopUserInput => "Stop input mode",
+            MessageId::ActionTakeUserInput => "Enter input mode",
+            MessageId::ActionToggleCardSelection => "Mark card for bulk actions",
+            MessageId::ActionToggleCommandPalette => "Open command palette",
+            MessageId::ActionToggleCompletedCardsVisibility => {
+                "Toggle completed visibility for current board"
+            }
+            MessageId::ActionToggleArchivedView => "Toggle archived boards visibility",
+            MessageId::ActionToggleFocusTimer => "Start, pause or resume the focus timer",
+            MessageId::ActionToggleNotifications => "Toggle notifications",
+            MessageId::ActionUndo => "Undo",
+            MessageId::ActionUndoFromToast => "Undo the action announced by the latest toast",
+            MessageId::ActionUp => "Go up",
+            MessageId::ConfigAlwaysLoadLastSave => "Auto Load Last Save",
+            MessageId::ConfigAutoLogin => "Auto Login",
+            MessageId::ConfigCardPreviewLines => "Card Preview Lines",
+            MessageId::ConfigCardDensity => "Card Density",
+            MessageId::ConfigCardAgingDays => "Card Aging Days",
+            MessageId::ConfigAutoArchiveCompletedDays => "Auto Archive Completed Days",
+            MessageId::ConfigAutoSaveInterval => "Auto Save Interval (seconds)",
+            MessageId::ConfigLogFilters => "Log Filters",
+            MessageId::ConfigCardAgingCue => "Card Aging Cue",
+            MessageId::ConfigColorDepth => "Color Depth",
+            MessageId::ConfigCardFaceLayout => "Card Face Layout",
+            MessageId::ConfigDateFormat => "Date Format",
+            MessageId::ConfigDefaultTheme => "Default Theme",
+            MessageId::ConfigDefaultView => "Select Default View",
+            MessageId::ConfigDisableAnimations => "Disable Animations",
+            MessageId::ConfigDisableScrollBar => "Disable Scroll Bar",
+            MessageId::ConfigEnableMouseSupport => "Enable Mouse Support",
+            MessageId::ConfigEnableEventLog => "Enable Event Log",
+            MessageId::ConfigWrapAroundNavigation => "Wrap Around Navigation",
+            MessageId::ConfigKeybindings => "Edit Keybindings",
+            MessageId::ConfigLanguage => "Language",
+            MessageId::ConfigNoOfBoardsToShow => "Number of Boards to Show",
+            MessageId::ConfigNoOfCardsToShow => "Number of Cards to Show",
+            MessageId::ConfigRapidCardEntry => "Rapid Card Entry",
+            MessageId::ConfigCheckForDuplicateCardNames => "Check For Duplicate Card Names",
+            MessageId::ConfigNormalizeUnicodeInput => "Normalize Unicode Input",
+            MessageId::ConfigKeepIdsOnImport => "Keep Ids On Import",
+            MessageId::ConfigEnableDesktopNotifications => "Enable Desktop Notifications",
+            MessageId::ConfigHighContrastMode => "High Contrast Mode",
+            MessageId::ConfigDatePickerCalenderFormat => "Date Picker Calender Format",
+            MessageId::ConfigSaveDirectory => "Save Directory",
+            MessageId::ConfigSaveFilesToKeep => "Save Files to Keep",
+            MessageId::ConfigMirrorDirectory => "Markdown Mirror Directory",
+            MessageId::ConfigSaveOnExit => "Auto Save on Exit",
+            MessageId::ConfigShowLineNumbers => "Show Line Numbers",
+            MessageId::ConfigShowChecklistProgress => "Show Checklist Progress",
+            MessageId::ConfigTickrate => "Tickrate",
+            MessageId::ConfigWarningDelta => "Number of Days to Warn Before Due Date",
+            MessageId::MainMenuView => "View your Boards",
+            MessageId::MainMenuConfig => "Configure",
+            MessageId::MainMenuHelp => "Help",
+            MessageId::MainMenuStats => "Stats",
+            MessageId::MainMenuLoadSaveLocal => "Load a Save (local)",
+            MessageId::MainMenuLoadSaveCloud => "Load a Save (cloud)",
+            MessageId::MainMenuQuit => "Quit",
+            MessageId::ButtonCancel => "Cancel",
+            MessageId::ButtonClearWaitingOn => "Clear",
+            MessageId::ButtonCreateAnyway => "Create anyway",
+            MessageId::ButtonDiscard => "Discard",
+            MessageId::ButtonGoToExisting => "Go to existing",
+            MessageId::ButtonKeepDraft => "Keep draft",
+            MessageId::ButtonKeepWaitingOn => "Keep waiting",
+            MessageId::ButtonNo => "No",
+            MessageId::ButtonQuitWithoutSaving => "Quit without saving",
+            MessageId::ButtonSaveAndQuit => "Save and quit",
+            MessageId::ButtonYes => "Yes",
+            MessageId::TitleClearWaitingOn => "Clear Waiting On?",
+            MessageId::TitleConfigEditor => "Config Editor",
+            MessageId::TitleDuplicateCardName => "Duplicate Card Name",
+            MessageId::TitleKeepDraft => "Keep Unsubmitted Draft?",
+            MessageId::TitleMainMenu => "Main menu",
+            MessageId::TitleRestoreThemesFromBackup => "Restore Themes from Backup?",
+            MessageId::TitleRecoverSaveFile => "Recover Save File?",
+            MessageId::TitleMirrorSync => "Sync from Markdown Mirror?",
+            MessageId::TitleNotifications => "Notifications",
+            MessageId::TitleWhatsNew => "What's New",
+            MessageId::TitleConflictMarkers => "Conflict Markers Found",
+            MessageId::TitleSaveChangesToCard => "Save Changes to Card?",
+            MessageId::TitleUnsavedChanges => "Unsaved Changes",
+            MessageId::TitleAffectedCards => "Affected cards",
+            MessageId::TitleArchivedCards => "Archived Cards",
+            MessageId::TitleBoardNotes => "Board Notes",
+            MessageId::TitleBoards => "Boards",
+            MessageId::TitleCalendar => "Calendar",
+            MessageId::TitleCardTemplates => "Card Templates",
+            MessageId::TitleCardsByPriority => "Cards by Priority",
+            MessageId::TitleCardsByStatus => "Cards by Status",
+            MessageId::TitleEstimateByStatus => "Estimate by Status",
+            MessageId::TitleConflicts => "Conflicts",
+            MessageId::TitleCustomDueDateRange => "Custom Due Date Range",
+            MessageId::TitleDuplicateBoards => "Duplicate Boards",
+            MessageId::TitleDuplicates => "Duplicates",
+            MessageId::TitleFilterByDueDate => "Filter by Due Date",
+            MessageId::TitleFilterByPriority => "Filter by Priority (Space to toggle)",
+            MessageId::TitleFilterByStatus => "Filter by Status (Space to toggle)",
+            MessageId::TitleFindAndReplace => "Find and Replace",
+            MessageId::TitleGithubImport => "GitHub Import",
+            MessageId::TitleHelp => "Help",
+            MessageId::TitleImportOptions => "Import Options",
+            MessageId::TitleLogs => "Logs",
+            MessageId::TitleMostOverdue => "Most Overdue",
+            MessageId::TitlePreview => "Preview",
+            MessageId::TitleSearchCards => "Search Cards",
+            MessageId::TitleSetLogLevel => "Set Log Level",
+            MessageId::TitleStats => "Stats",
+            MessageId::TitleWaitingOn => "Waiting On",
+            MessageId::ToastConfigUpdated => "Config updated",
+            MessageId::ToastCouldNotWriteConfig => "Could not write to config file",
+            MessageId::ToastFilterReset => "Filter Reset",
+            MessageId::ToastNewBoardNameInvalid => "New board name is empty or already exists",
+            MessageId::ToastNewCardNameInvalid => "New card name is empty or already exists",
+            MessageId::ToastSomethingWentWrong => "Something went wrong",
+            MessageId::ToastUiReset => "UI reset, all toasts cleared",
+            MessageId::ToastCannotArchiveACardInThisView => "Cannot archive a card in this view",
+            MessageId::ToastCannotChangeBoardAccentInThisView => {
+                "Cannot change board accent in this view"
+            }
+            MessageId::ToastCannotChangeCardHighlightInThisView => {
+                "Cannot change card highlight in this view"
+            }
+            MessageId::ToastCannotCopyABoardInThisView => "Cannot copy a board in this view",
+            MessageId::ToastCannotCopyACardInThisView => "Cannot copy a card in this view",
+            MessageId::ToastCannotDuplicateABoardInThisView => {
+                "Cannot duplicate a board in this view"
+            }
+            MessageId::ToastCannotDuplicateACardInThisView => {
+                "Cannot duplicate a card in this view"
+            }
+            MessageId::ToastCannotEditABoardInThisView => "Cannot edit a board in this view",
+            MessageId::ToastCannotEditBoardNotesInThisView => {
+                "Cannot edit board notes in this view"
+            }
+            MessageId::ToastCannotFilterCardsInThisView => "Cannot filter cards in this view",
+            MessageId::ToastCannotGoDownAlreadyAtTheLastStatus => {
+                "Cannot go down: Already at the last status"
+            }
+            MessageId::ToastCannotGoUpAlreadyAtTheFirstStatus => {
+                "Cannot go up: Already at the first status"
+            }
+            MessageId::ToastCannotMoveCardsInThisView => "Cannot move cards in this view",
+            MessageId::ToastCannotMoveCardsOnAnAutoSortedBoard => {
+                "Cards on this board are kept sorted by priority, turn off auto sort to move them by hand"
+            }
+            MessageId::ToastCannotMoveCardsLeftAsItIsTheFirstBoard => {
+                "Cannot move cards left as it is the first board"
+            }
+            MessageId::ToastCannotMoveCardsRightAsItIsTheLastBoard => {
+                "Cannot move cards right as it is the last board"
+            }
+            MessageId::ToastCannotRedoInThisView => "Cannot redo in this view",
+            MessageId::ToastCannotSearchCardsInThisView => "Cannot search cards in this view",
+            MessageId::ToastCannotSortCardsInThisView => "Cannot sort cards in this view",
+            MessageId::ToastCannotUndoInThisView => "Cannot undo in this view",
+            MessageId::ToastClearedMarkedCards => "Cleared marked cards",
+            MessageId::ToastCouldNotFindCurrentBoard => "Could not find current board",
+            MessageId::ToastCouldNotFindTheBoardBeingEdited => {
+                "Could not find the board being edited"
+            }
+            MessageId::ToastCouldNotFindTheBoardOfTheCard => "Could not find the board of the card",
+            MessageId::ToastCouldNotFindTheBoardToDuplicate => {
+                "Could not find the board to duplicate"
+            }
+            MessageId::ToastCouldNotFindTheBoardToMoveTheCardTo => {
+                "Could not find the board to move the card to"
+            }
+            MessageId::ToastCouldNotFindTheBoardToSort => "Could not find the board to sort",
+            MessageId::ToastCouldNotFindTheCardToArchive => "Could not find the card to archive",
+            MessageId::ToastCouldNotFindTheCardToCopy => "Could not find the card to copy",
+            MessageId::ToastCouldNotFindTheCardToDuplicate => {
+                "Could not find the card to duplicate"
+            }
+            MessageId::ToastCouldNotFindTheCardToMove => "Could not find the card to move",
+            MessageId::ToastCouldNotFindTheCardToSaveAsATemplate => {
+                "Could not find the card to save as a template"
+            }
+            MessageId::ToastCouldNotFindTheSelectedBoard => "Could not find the selected board",
+            MessageId::ToastCouldNotFindTheSelectedCard => "Could not find the selected card",
+            MessageId::ToastCouldNotFindTheSelectedTemplate => {
+                "Could not find the selected template"
+            }
+            MessageId::ToastCouldNotReadThemeBackups => "Could not read theme backups",
+            MessageId::ToastCouldNotRecoverSaveFile => "Could not recover save file",
+            MessageId::ToastCouldNotRestoreThemesFromBackup => {
+                "Could not restore themes from backup"
+            }
+            MessageId::ToastCouldNotSaveTheCardTemplate => "Could not save the card template",
+            MessageId::ToastCouldNotWriteMarkdownMirror => "Could not write markdown mirror",
+            MessageId::ToastDraftDiscarded => "Draft discarded",
+            MessageId::ToastEnterAGithubRepositoryAsOwnerName => {
+                "Enter a GitHub repository as owner/name"
+            }
+            MessageId::ToastEnterThePathOfATrelloExportToImport => {
+                "Enter the path of a Trello export to import"
+            }
+            MessageId::ToastEnterThePathOfASaveFileToImport => {
+                "Enter the path of a save file to import"
+            }
+            MessageId::ToastEnterThePathOfAnExportedBoardToImport => {
+                "Enter the path of an exported board to import"
+            }
+            MessageId::ToastEnterTheTextToFind => "Enter the text to find",
+            MessageId::ToastErrorCouldNotFindCurrentBoard => "Error Could not find current board",
+            MessageId::ToastErrorCouldNotFindSelectedCards => "Error Could not find selected cards",
+            MessageId::ToastMarkdownMirrorCreated => "Markdown mirror created",
+            MessageId::ToastMarkdownMirrorHasNoChangesToSync => {
+                "Markdown mirror has no changes to sync"
+            }
+            MessageId::ToastNoArchivedCardSelected => "No archived card selected",
+            MessageId::ToastNoBoardAvailableToEdit => "No board available to edit",
+            MessageId::ToastNoBoardSelectedToArchive => "No board selected to archive",
+            MessageId::ToastNoBoardSelectedToCopy => "No board selected to copy",
+            MessageId::ToastNoBoardSelectedToDuplicate => "No board selected to duplicate",
+            MessageId::ToastNoBoardsToShow => "No boards to show",
+            MessageId::ToastNoCardSelectedToArchive => "No card selected to archive",
+            MessageId::ToastNoCardSelectedToCopy => "No card selected to copy",
+            MessageId::ToastNoCardSelectedToDuplicate => "No card selected to duplicate",
+            MessageId::ToastNoCardSelectedToFocusOn => "No card selected to focus on",
+            MessageId::ToastNoCardsAreWaitingOnAnyone => "No cards are waiting on anyone",
+            MessageId::ToastNoChecklistItemSelected => "No checklist item selected",
+            MessageId::ToastNoChecklistItemsToDelete => "No checklist items to delete",
+            MessageId::ToastNoDuplicateBoardsFound => "No duplicate boards found",
+            MessageId::ToastNoFocusTimerIsRunning => "No focus timer is running",
+            MessageId::ToastNoThemeBackupsFound => "No theme backups found",
+            MessageId::ToastNothingChangedSinceTheLastSave => "Nothing changed since the last save",
+            MessageId::ToastOneOfTheDuplicateBoardsNoLongerExists => {
+                "One of the duplicate boards no longer exists"
+            }
+            MessageId::ToastRestoredThemesFromBackup => "Restored themes from backup",
+            MessageId::ToastSelectedCardsAlreadyHaveThatValue => {
+                "Selected cards already have that value"
+            }
+            MessageId::ToastStartDateMustNotBeAfterTheDueDate => {
+                "Start date must not be after the due date"
+            }
+            MessageId::ToastThereAreNoOtherBoardsToMoveTheCardTo => {
+                "There are no other boards to move the card to"
+            }
+            MessageId::ToastTutorialCompleteYouAreAllSet => "Tutorial complete, you are all set",
+            MessageId::ToastBoardDuplicated => "Board '{board}' duplicated",
+            MessageId::ToastCannotDeleteACloudSaveOthersDependOn => "Cannot delete cloud_save_{save}, delete {dependents} first",
+            MessageId::ToastCardDuplicated => "Card '{card}' duplicated",
+            MessageId::ToastErrorSyncingLocalData => "Error syncing local data",
+            MessageId::ToastHistoryStepCouldNotBeApplied => "{summary}, the next one could not be applied",
+            MessageId::ToastLocalDataSyncedToTheCloud => "👍 Local data synced to the cloud",
+            MessageId::ToastLogLevelSetTo => "Log level set to {level}",
+            MessageId::ToastNoMoreActionsToRedo => "No more actions to redo",
+            MessageId::ToastNoMoreActionsToUndo => "No more actions to undo",
+            MessageId::ToastNothingChangedSinceTheLastSync => "Nothing changed since the last sync, use Force Full Sync to upload anyway",
+            MessageId::ToastRedidActions => "Redid {count} actions",
+            MessageId::ToastRedidOneAction => "Redid 1 action",
+            MessageId::ToastSyncingLocalDataPleaseWait => "Syncing local data, please wait...",
+            MessageId::ToastTheLastSaveIsNotInTheUndoHistory => "The last save can not be reached by undoing, it is not in the undo history",
+            MessageId::ToastUndidActions => "Undid {count} actions",
+            MessageId::ToastUndidOneAction => "Undid 1 action",
+        }
+    }
+}
+
+lazy_static! {
+    static ref TRANSLATIONS: RwLock<HashMap<MessageId, String>> = RwLock::new(HashMap::new());
+}
+
+/// Returns the text for the message in the current language, falling back to English
+pub fn tr(message_id: MessageId) -> String {
+    translate(&TRANSLATIONS.read(), message_id)
+}
+
+/// Like [`tr`] for messages with `{name}` placeholders, each is replaced by the value given for
+/// its name so a translation can put them in any order
+pub fn tr_args(message_id: MessageId, args: &[(&str, &str)]) -> String {
+    fill_placeholders(tr(message_id), args)
+}
+
+fn fill_placeholders(text: String, args: &[(&str, &str)]) -> String {
+    args.iter().fold(text, |text, (name, value)| {
+        text.replace(&format!("{{{}}}", name), value)
+    })
+}
+
+fn translate(translations: &HashMap<MessageId, String>, message_id: MessageId) -> String {
+    translations
+        .get(&message_id)
+        .cloned()
+        .unwrap_or_else(|| message_id.english().to_string())
+}
+
+pub fn get_language_file_path(language: &str) -> Result<PathBuf, String> {
+    let mut language_file_path = get_config_dir()?;
+    language_file_path.push(LANGUAGE_DIR_NAME);
+    language_file_path.push(format!("{}.json", language));
+    Ok(language_file_path)
+}
+
+/// Loads the override file for the language, messages missing from it fall back to English
+pub fn load_language(language: &str) {
+    let mut translations = HashMap::new();
+    if language != DEFAULT_LANGUAGE {
+        match read_language_file(language) {
+            Ok(parsed_translations) => translations = parsed_translations,
+            Err(e) => error!("Could not load language '{}': {}", language, e),
+        }
+        let fallen_back_keys = MessageId::iter()
+            .filter(|message_id| !translations.contains_key(message_id))
+            .map(|message_id| message_id.to_string())
+            .collect::<Vec<String>>();
+        if !fallen_back_keys.is_empty() {
+            info!(
+                "{} messages fell back to English for language '{}': {}",
+                fallen_back_keys.len(),
+                language,
+                fallen_back_keys.join(", ")
+            );
+        }
+    }
+    *TRANSLATIONS.write() = translations;
+}
+
+fn read_language_file(language: &str) -> Result<HashMap<MessageId, String>, String> {
+    let language_file_path = get_language_file_path(language)?;
+    let language_file = fs::read_to_string(&language_file_path).map_err(|e| {
+        debug!("Error reading {}: {}", language_file_path.display(), e);
+        format!("Could not read {}", language_file_path.display())
+    })?;
+    parse_language_file(&language_file)
+}
+
+pub fn parse_language_file(language_file: &str) -> Result<HashMap<MessageId, String>, String> {
+    let raw_translations: HashMap<String, String> =
+        serde_json::from_str(language_file).map_err(|e| format!("Invalid language file: {}", e))?;
+    let mut translations = HashMap::new();
+    for (key, value) in raw_translations {
+        match MessageId::from_str(&key) {
+            Ok(message_id) => {
+                translations.insert(message_id, value);
+            }
+            Err(_) => warn!("Unknown message id '{}' in language file", key),
+        }
+    }
+    Ok(translations)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_message_has_an_english_default() {
+        for message_id in MessageId::iter() {
+            let english = message_id.english();
+            assert!(!english.trim().is_empty(), "{} has no text", message_id);
+            assert_eq!(english, english.trim(), "{} has stray spaces", message_id);
+            assert_eq!(translate(&HashMap::new(), message_id), english);
+        }
+    }
+
+    #[test]
+    fn every_message_id_can_be_used_as_a_language_file_key() {
+        for message_id in MessageId::iter() {
+            assert_eq!(MessageId::from_str(&message_id.to_string()), Ok(message_id));
+        }
+    }
+
+    #[test]
+    fn a_partial_language_file_falls_back_to_english() {
+        let translations = parse_language_file(
+            r#"{
+                "ButtonYes": "Ja",
+                "ButtonNo": "Nein",
+                "NotAMessage": "Ignored"
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(translations.len(), 2);
+        assert_eq!(translate(&translations, MessageId::ButtonYes), "Ja");
+        assert_eq!(translate(&translations, MessageId::ButtonNo), "Nein");
+        assert_eq!(
+            translate(&translations, MessageId::ButtonCancel),
+            MessageId::ButtonCancel.english()
+        );
+        assert_eq!(
+            translate(&translations, MessageId::TitleHelp),
+            MessageId::TitleHelp.english()
+        );
+    }
+
+    #[test]
+    fn the_shipped_language_files_only_use_known_messages() {
+        let language_file = include_str!("../languages/de.json");
+        let raw_translations: HashMap<String, String> =
+            serde_json::from_str(language_file).unwrap();
+        let translations = parse_language_file(language_file).unwrap();
+        assert_eq!(translations.len(), raw_translations.len());
+    }
+
+    #[test]
+    fn an_invalid_language_file_is_rejected() {
+        assert!(parse_language_file("{\"ButtonYes\": ").is_err());
+        assert!(parse_language_file("[\"ButtonYes\"]").is_err());
+        assert!(parse_language_file("{}").unwrap().is_empty());
+    }
+
+    #[test]
+    fn placeholders_are_filled_by_name_in_any_order() {
+        let translations = parse_language_file(
+            r#"{ "ToastCannotDeleteACloudSaveOthersDependOn": "Erst {dependents} löschen, dann cloud_save_{save}" }"#,
+        )
+        .unwrap();
+        let args = [("save", "2"), ("dependents", "cloud_save_3")];
+        assert_eq!(
+            fill_placeholders(
+                translate(
+                    &translations,
+                    MessageId::ToastCannotDeleteACloudSaveOthersDependOn
+                ),
+                &args
+            ),
+            "Erst cloud_save_3 löschen, dann cloud_save_2"
+        );
+        assert_eq!(
+            fill_placeholders(
+                translate(
+                    &HashMap::new(),
+                    MessageId::ToastCannotDeleteACloudSaveOthersDependOn
+                ),
+                &args
+            ),
+            "Cannot delete cloud_save_2, delete cloud_save_3 first"
+        );
+        assert_eq!(
+            fill_placeholders("Log level set to {level}".to_string(), &[]),
+            "Log level set to {level}"
+        );
+    }
+}
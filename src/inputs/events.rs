@@ -31,6 +31,10 @@ impl Events {
                         if let Err(err) = event_tx.send(InputEvent::KeyBoardInput(key)).await {
                             error!("Oops!, {}", err);
                         }
+                    } else if let crossterm::event::Event::Paste(text) = event {
+                        if let Err(err) = event_tx.send(InputEvent::Paste(text)).await {
+                            error!("Oops!, {}", err);
+                        }
                     }
                 }
                 if let Err(err) = event_tx.send(InputEvent::Tick).await {
@@ -29,6 +29,7 @@ pub enum Key {
     CtrlAltUp,
     CtrlAltShiftUp,
     CtrlDown,
+    CtrlEnter,
     CtrlLeft,
     CtrlRight,
     CtrlUp,
@@ -110,8 +111,13 @@ impl Display for Key {
             Key::CtrlAltRight => write!(f, "<Ctrl+Alt+Right>"),
             Key::CtrlAltUp => write!(f, "<Ctrl+Alt+Up>"),
             Key::CtrlDown => write!(f, "<Ctrl+Down>"),
+            Key::CtrlEnter => write!(f, "<Ctrl+Enter>"),
             Key::CtrlLeft => write!(f, "<Ctrl+Left>"),
             Key::CtrlRight => write!(f, "<Ctrl+Right>"),
+            Key::CtrlShiftDown => write!(f, "<Ctrl+Shift+Down>"),
+            Key::CtrlShiftLeft => write!(f, "<Ctrl+Shift+Left>"),
+            Key::CtrlShiftRight => write!(f, "<Ctrl+Shift+Right>"),
+            Key::CtrlShiftUp => write!(f, "<Ctrl+Shift+Up>"),
             Key::CtrlUp => write!(f, "<Ctrl+Up>"),
             Key::Delete => write!(f, "<Delete>"),
             Key::Down => write!(f, "<Down>"),
@@ -166,6 +172,8 @@ impl From<event::KeyEvent> for Key {
             } => {
                 if ctrl && alt {
                     Key::CtrlAltUp
+                } else if ctrl && shift {
+                    Key::CtrlShiftUp
                 } else if ctrl {
                     Key::CtrlUp
                 } else if shift {
@@ -181,6 +189,8 @@ impl From<event::KeyEvent> for Key {
             } => {
                 if ctrl && alt {
                     Key::CtrlAltDown
+                } else if ctrl && shift {
+                    Key::CtrlShiftDown
                 } else if ctrl {
                     Key::CtrlDown
                 } else if shift {
@@ -196,6 +206,8 @@ impl From<event::KeyEvent> for Key {
             } => {
                 if ctrl && alt {
                     Key::CtrlAltLeft
+                } else if ctrl && shift {
+                    Key::CtrlShiftLeft
                 } else if ctrl {
                     Key::CtrlLeft
                 } else if shift {
@@ -211,6 +223,8 @@ impl From<event::KeyEvent> for Key {
             } => {
                 if ctrl && alt {
                     Key::CtrlAltRight
+                } else if ctrl && shift {
+                    Key::CtrlShiftRight
                 } else if ctrl {
                     Key::CtrlRight
                 } else if shift {
@@ -264,7 +278,13 @@ impl From<event::KeyEvent> for Key {
                 code: event::KeyCode::Enter,
                 kind: event::KeyEventKind::Press,
                 ..
-            } => Key::Enter,
+            } => {
+                if ctrl {
+                    Key::CtrlEnter
+                } else {
+                    Key::Enter
+                }
+            }
             event::KeyEvent {
                 code: event::KeyCode::BackTab,
                 kind: event::KeyEventKind::Press,
@@ -324,8 +344,13 @@ impl From<&str> for Key {
             "<Ctrl+Alt+Right>" => Key::CtrlAltRight,
             "<Ctrl+Alt+Up>" => Key::CtrlAltUp,
             "<Ctrl+Down>" => Key::CtrlDown,
+            "<Ctrl+Enter>" => Key::CtrlEnter,
             "<Ctrl+Left>" => Key::CtrlLeft,
             "<Ctrl+Right>" => Key::CtrlRight,
+            "<Ctrl+Shift+Down>" => Key::CtrlShiftDown,
+            "<Ctrl+Shift+Left>" => Key::CtrlShiftLeft,
+            "<Ctrl+Shift+Right>" => Key::CtrlShiftRight,
+            "<Ctrl+Shift+Up>" => Key::CtrlShiftUp,
             "<Ctrl+Up>" => Key::CtrlUp,
             "<Delete>" => Key::Delete,
             "<Down>" => Key::Down,
@@ -4,9 +4,11 @@ pub mod events;
 pub mod key;
 pub mod mouse;
 
-#[derive(Debug, Clone, PartialEq, Copy)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum InputEvent {
     KeyBoardInput(Key),
     MouseAction(Mouse),
+    /// Text pasted in the terminal with bracketed paste, delivered in one piece
+    Paste(String),
     Tick,
 }
@@ -0,0 +1,105 @@
+use log::debug;
+use std::{
+    io::{self, Write},
+    process::{Command, Stdio},
+};
+
+/// Clipboard tools tried in order, a terminal app has no clipboard of its own so the text is
+/// piped to whichever of these is installed and can reach a display
+const CLIPBOARD_COMMANDS: [(&str, &[&str]); 5] = [
+    ("pbcopy", &[]),
+    ("wl-copy", &[]),
+    ("xclip", &["-selection", "clipboard"]),
+    ("xsel", &["--clipboard", "--input"]),
+    ("clip.exe", &[]),
+];
+
+/// Puts `text` on the system clipboard. Fails when none of the clipboard tools worked, as on a
+/// headless machine reached over SSH
+pub fn copy_to_clipboard(text: &str) -> Result<(), String> {
+    for (program, args) in CLIPBOARD_COMMANDS {
+        match pipe_to_command(program, args, text) {
+            Ok(()) => {
+                debug!(
+                    "Copied {} bytes to the clipboard with {}",
+                    text.len(),
+                    program
+                );
+                return Ok(());
+            }
+            Err(err) => debug!("Could not copy to the clipboard with {}: {}", program, err),
+        }
+    }
+    let programs = CLIPBOARD_COMMANDS
+        .iter()
+        .map(|(program, _)| *program)
+        .collect::<Vec<&str>>()
+        .join(", ");
+    Err(format!("No clipboard available, tried {}", programs))
+}
+
+/// Tools that print the clipboard, tried in the same order as [`CLIPBOARD_COMMANDS`]
+const PASTE_COMMANDS: [(&str, &[&str]); 5] = [
+    ("pbpaste", &[]),
+    ("wl-paste", &["--no-newline"]),
+    ("xclip", &["-selection", "clipboard", "-o"]),
+    ("xsel", &["--clipboard", "--output"]),
+    (
+        "powershell.exe",
+        &["-NoProfile", "-Command", "Get-Clipboard"],
+    ),
+];
+
+/// Reads the text on the system clipboard. Fails when none of the clipboard tools worked or the
+/// clipboard does not hold text
+pub fn read_from_clipboard() -> Result<String, String> {
+    for (program, args) in PASTE_COMMANDS {
+        match read_from_command(program, args) {
+            Ok(text) => {
+                debug!(
+                    "Read {} bytes from the clipboard with {}",
+                    text.len(),
+                    program
+                );
+                return Ok(text);
+            }
+            Err(err) => debug!("Could not read the clipboard with {}: {}", program, err),
+        }
+    }
+    let programs = PASTE_COMMANDS
+        .iter()
+        .map(|(program, _)| *program)
+        .collect::<Vec<&str>>()
+        .join(", ");
+    Err(format!("No clipboard available, tried {}", programs))
+}
+
+fn read_from_command(program: &str, args: &[&str]) -> io::Result<String> {
+    let output = Command::new(program)
+        .args(args)
+        .stdin(Stdio::null())
+        .stderr(Stdio::null())
+        .output()?;
+    if !output.status.success() {
+        return Err(io::Error::other(format!("exited with {}", output.status)));
+    }
+    String::from_utf8(output.stdout).map_err(|_| io::Error::other("clipboard is not text"))
+}
+
+fn pipe_to_command(program: &str, args: &[&str], text: &str) -> io::Result<()> {
+    let mut child = Command::new(program)
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()?;
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin.write_all(text.as_bytes())?;
+    }
+    let status = child.wait()?;
+    if status.success() {
+        Ok(())
+    } else {
+        Err(io::Error::other(format!("exited with {}", status)))
+    }
+}
@@ -5,16 +5,35 @@ use crate::{
     },
     constants::{
         CONFIG_DIR_NAME, CONFIG_FILE_NAME, SAVE_DIR_NAME, SAVE_FILE_NAME, SAVE_FILE_REGEX,
-        THEME_DIR_NAME, THEME_FILE_NAME,
+        STATS_HISTORY_FILE_NAME, THEME_BACKUP_FILE_EXTENSION, THEME_DIR_NAME, THEME_FILE_NAME,
     },
     inputs::key::Key,
-    io::io_handler::{get_config_dir, make_file_system_safe_name, prepare_config_dir},
+    io::{
+        io_handler::{
+            get_config_dir, get_latest_save_file, make_file_system_safe_name, prepare_config_dir,
+        },
+        markdown_mirror,
+    },
+    model::{
+        cloud_sync::CloudSyncMetadata,
+        date_time_format::DateTimeFormat,
+        save_file::{load_cloud_sync_metadata, load_save, save_with_cloud_sync},
+        stats_history::{append_stats_record, StatsRecord},
+    },
     ui::theme::Theme,
 };
-use log::{debug, error, info};
+use log::{debug, error, info, warn};
 use regex::Regex;
-use serde::{Deserialize, Serialize};
-use std::{cmp::Ordering, collections::HashMap, env, fs, path::PathBuf};
+use std::{
+    cmp::Ordering,
+    collections::HashMap,
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+pub use crate::model::save_file::{
+    get_atomic_write_temp_path, get_leftover_temp_paths, write_file_atomically, ExportStruct,
+};
 
 pub fn get_config(ignore_overlapped_keybindings: bool) -> Result<AppConfig, String> {
     let config_dir_status = get_config_dir();
@@ -88,7 +107,8 @@ pub fn write_config(config: &AppConfig) -> Result<(), String> {
     let config_str = serde_json::to_string_pretty(&config).unwrap();
     prepare_config_dir()?;
     let config_dir = get_config_dir()?;
-    let write_result = fs::write(config_dir.join(CONFIG_FILE_NAME), config_str);
+    let write_result =
+        write_file_atomically(&config_dir.join(CONFIG_FILE_NAME), config_str.as_bytes());
     match write_result {
         Ok(_) => Ok(()),
         Err(e) => {
@@ -109,7 +129,13 @@ pub fn reset_config() {
     }
 }
 
-pub fn save_kanban_state_locally(boards: Vec<Board>, config: &AppConfig) -> Result<(), String> {
+/// Writes a new versioned save file and returns its path, the save always keeps board notes,
+/// with_notes only decides whether the markdown mirror gets them
+pub fn save_kanban_state_locally(
+    boards: Vec<Board>,
+    config: &AppConfig,
+    with_notes: bool,
+) -> Result<String, String> {
     let files = fs::read_dir(&config.save_directory);
     if files.is_err() {
         return Err("Error reading save directory".to_string());
@@ -157,10 +183,32 @@ pub fn save_kanban_state_locally(boards: Vec<Board>, config: &AppConfig) -> Resu
         chrono::Local::now().format("%d-%m-%Y"),
         version
     );
-    match export_kanban_to_json(&boards, config, file_name) {
-        Ok(_) => Ok(()),
-        Err(e) => Err(e),
+    // The bookkeeping describes the cloud and not one save, so every new save carries it over
+    let cloud_sync = get_latest_save_file(config)
+        .ok()
+        .and_then(|latest_save_file| {
+            load_cloud_sync_metadata(&config.save_directory.join(latest_save_file))
+        });
+    let file_path = export_kanban_to_json(&boards, config, file_name, cloud_sync.as_ref())?;
+    if let Some(mirror_directory) = &config.mirror_directory {
+        // The save already succeeded, a stale mirror is not worth failing it over
+        if let Err(e) =
+            markdown_mirror::update_mirror_after_save(&boards, mirror_directory, with_notes)
+        {
+            warn!("Markdown mirror was not updated: {}", e);
+        }
+    }
+    // Callers only save when the boards changed, records with unchanged counts are still skipped
+    let stats_date = chrono::Local::now()
+        .format(DateTimeFormat::add_time_to_date_format(config.date_time_format).to_parser_string())
+        .to_string();
+    if let Err(e) = append_stats_record(
+        &config.save_directory.join(STATS_HISTORY_FILE_NAME),
+        StatsRecord::from_boards(&boards, &stats_date),
+    ) {
+        warn!("Stats history was not updated: {}", e);
     }
+    Ok(file_path)
 }
 
 pub fn get_local_kanban_state(
@@ -172,36 +220,7 @@ pub fn get_local_kanban_state(
     if !preview_mode {
         info!("Loading local save file: {:?}", file_path);
     }
-    let file = fs::File::open(file_path);
-    if file.is_err() {
-        debug!("Error opening save file: {}", file.err().unwrap());
-        return Err("Error opening save file".to_string());
-    }
-    let file = file.unwrap();
-    let serde_object = serde_json::from_reader(file);
-    if serde_object.is_err() {
-        debug!("Error parsing save file: {}", serde_object.err().unwrap());
-        return Err("Error parsing save file".to_string());
-    }
-    let serde_object: serde_json::Value = serde_object.unwrap();
-    let boards = serde_object.get("boards");
-    if boards.is_none() {
-        debug!("Error parsing save file, no boards found");
-        return Err("Error parsing save file".to_string());
-    }
-    let boards = boards.unwrap();
-    let boards = boards.as_array();
-    if boards.is_none() {
-        debug!("Error parsing save file, boards is not an array");
-        return Err("Error parsing save file".to_string());
-    }
-    let boards = boards.unwrap();
-    let mut parsed_boards = Vec::new();
-    for board in boards {
-        let parsed_board = Board::from_json(board)?;
-        parsed_boards.push(parsed_board);
-    }
-    Ok(Boards::from(parsed_boards))
+    load_save(&file_path)
 }
 
 pub fn get_available_local_save_files(config: &AppConfig) -> Option<Vec<String>> {
@@ -271,31 +290,82 @@ pub fn get_available_local_save_files(config: &AppConfig) -> Option<Vec<String>>
     }
 }
 
+/// Deletes the oldest local save files past the configured number to keep and returns the names
+/// of the deleted files, nothing is deleted when every save is kept
+pub fn prune_old_save_files(config: &AppConfig) -> Result<Vec<String>, String> {
+    let files_to_keep = config.save_files_to_keep as usize;
+    if files_to_keep == 0 {
+        return Ok(Vec::new());
+    }
+    let Some(save_files) = get_available_local_save_files(config) else {
+        return Err("Error reading save directory".to_string());
+    };
+    let prune_count = save_files.len().saturating_sub(files_to_keep);
+    let mut pruned_files = Vec::new();
+    for file_name in save_files.into_iter().take(prune_count) {
+        fs::remove_file(config.save_directory.join(&file_name))
+            .map_err(|e| format!("Could not delete old save file {}: {}", file_name, e))?;
+        pruned_files.push(file_name);
+    }
+    Ok(pruned_files)
+}
+
 pub fn export_kanban_to_json(
     boards: &[Board],
     config: &AppConfig,
     file_name: String,
+    cloud_sync: Option<&CloudSyncMetadata>,
 ) -> Result<String, String> {
-    let version = env!("CARGO_PKG_VERSION");
-    let date = format!(
-        "{} ({})",
-        chrono::Local::now().format(config.date_time_format.to_parser_string()),
-        config.date_time_format.to_human_readable_string()
-    );
-    let export_struct = ExportStruct {
-        boards: boards.to_vec(),
-        export_date: date,
-        kanban_version: version.to_string(),
-    };
     let file_path = config.save_directory.join(file_name);
-    let write_status = fs::write(
-        file_path.clone(),
-        serde_json::to_string_pretty(&export_struct).unwrap(),
+    save_with_cloud_sync(boards, &file_path, config.date_time_format, cloud_sync)?;
+    Ok(file_path.to_str().unwrap().to_string())
+}
+
+/// A readable stand-in for a save file that could not be parsed
+#[derive(Clone, Debug)]
+pub struct SaveRecoveryCandidate {
+    pub corrupt_file_name: String,
+    pub recovery_path: PathBuf,
+    pub board_count: usize,
+}
+
+/// Looks for the temp files an interrupted write left behind first, then for the newest older
+/// save that still parses
+pub fn find_save_recovery_candidate(
+    corrupt_file_name: &str,
+    config: &AppConfig,
+) -> Option<SaveRecoveryCandidate> {
+    let leftover_temp_paths =
+        get_leftover_temp_paths(&config.save_directory.join(corrupt_file_name));
+    let save_files = get_available_local_save_files(config).unwrap_or_default();
+    let older_save_count = save_files
+        .iter()
+        .position(|file_name| file_name == corrupt_file_name)
+        .unwrap_or(save_files.len());
+    let older_save_paths = save_files[..older_save_count]
+        .iter()
+        .rev()
+        .map(|file_name| config.save_directory.join(file_name));
+    leftover_temp_paths
+        .into_iter()
+        .chain(older_save_paths)
+        .filter(|path| path.exists())
+        .find_map(|path| {
+            let boards = load_save(&path).ok()?;
+            Some(SaveRecoveryCandidate {
+                corrupt_file_name: corrupt_file_name.to_string(),
+                board_count: boards.len(),
+                recovery_path: path,
+            })
+        })
+}
+
+pub fn load_save_recovery_candidate(candidate: &SaveRecoveryCandidate) -> Result<Boards, String> {
+    info!(
+        "Recovering {} from {:?}",
+        candidate.corrupt_file_name, candidate.recovery_path
     );
-    match write_status {
-        Ok(_) => Ok(file_path.to_str().unwrap().to_string()),
-        Err(e) => Err(e.to_string()),
-    }
+    load_save(&candidate.recovery_path)
 }
 
 pub fn get_default_save_directory() -> PathBuf {
@@ -321,37 +391,120 @@ fn get_theme_dir() -> Result<PathBuf, String> {
     Ok(theme_dir)
 }
 
-pub fn get_saved_themes() -> Option<Vec<Theme>> {
-    let theme_dir = get_theme_dir();
-    if theme_dir.is_err() {
-        return None;
-    }
-    let theme_dir = theme_dir.unwrap();
-    let read_dir_status = fs::read_dir(&theme_dir);
-    let file_prefix = format!("{}_", THEME_FILE_NAME);
-    let regex_str = format!("^{}.*\\.json$", file_prefix);
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ThemeBackupSummary {
+    pub current_themes: usize,
+    pub backup_themes: usize,
+}
+
+fn get_theme_backup_path(theme_path: &Path) -> PathBuf {
+    let mut backup_path = theme_path.as_os_str().to_owned();
+    backup_path.push(format!(".{}", THEME_BACKUP_FILE_EXTENSION));
+    PathBuf::from(backup_path)
+}
+
+fn get_theme_file_paths(theme_dir: &Path, backups: bool) -> Result<Vec<PathBuf>, String> {
+    let regex_str = if backups {
+        format!(
+            "^{}_.*\\.json\\.{}$",
+            THEME_FILE_NAME, THEME_BACKUP_FILE_EXTENSION
+        )
+    } else {
+        format!("^{}_.*\\.json$", THEME_FILE_NAME)
+    };
     let re = Regex::new(&regex_str).unwrap();
-    match read_dir_status {
-        Ok(files) => {
-            let mut themes = Vec::new();
-            for file in files {
-                let file = file.unwrap();
-                let file_name = file.file_name().into_string().unwrap();
-                if re.is_match(&file_name) {
-                    let file_path = theme_dir.join(file_name);
-                    let read_status = fs::read_to_string(file_path);
-                    if read_status.is_err() {
-                        continue;
-                    }
-                    let read_status = read_status.unwrap();
-                    let theme: Theme = serde_json::from_str(&read_status).unwrap();
-                    themes.push(theme);
-                }
+    let files = fs::read_dir(theme_dir).map_err(|e| e.to_string())?;
+    let mut theme_file_paths = files
+        .filter_map(|file| file.ok())
+        .filter_map(|file| file.file_name().into_string().ok())
+        .filter(|file_name| re.is_match(file_name))
+        .map(|file_name| theme_dir.join(file_name))
+        .collect::<Vec<PathBuf>>();
+    theme_file_paths.sort();
+    Ok(theme_file_paths)
+}
+
+/// Themes read from the theme directory and the names of the files skipped because they could
+/// not be read or parsed
+#[derive(Debug, Default)]
+pub struct LoadedThemes {
+    pub themes: Vec<Theme>,
+    pub skipped_files: Vec<String>,
+}
+
+/// Reads every theme file on its own so a corrupt or truncated file only loses that theme
+fn read_theme_files(theme_file_paths: &[PathBuf]) -> LoadedThemes {
+    let mut loaded_themes = LoadedThemes::default();
+    for theme_file_path in theme_file_paths {
+        let theme = fs::read_to_string(theme_file_path)
+            .map_err(|e| format!("could not read it: {}", e))
+            .and_then(|theme_json| {
+                serde_json::from_str::<Theme>(&theme_json).map_err(|e| e.to_string())
+            });
+        match theme {
+            Ok(theme) => loaded_themes.themes.push(theme),
+            Err(e) => {
+                warn!(
+                    "Skipping corrupt theme file {}: {}",
+                    theme_file_path.display(),
+                    e
+                );
+                let file_name = theme_file_path
+                    .file_name()
+                    .map(|file_name| file_name.to_string_lossy().to_string())
+                    .unwrap_or_default();
+                loaded_themes.skipped_files.push(file_name);
             }
-            Some(themes)
         }
-        Err(_) => None,
     }
+    loaded_themes
+}
+
+fn load_themes_from_dir(theme_dir: &Path) -> Result<LoadedThemes, String> {
+    Ok(read_theme_files(&get_theme_file_paths(theme_dir, false)?))
+}
+
+pub fn get_saved_themes() -> Option<LoadedThemes> {
+    let theme_dir = get_theme_dir().ok()?;
+    load_themes_from_dir(&theme_dir).ok()
+}
+
+pub fn get_theme_backup_summary() -> Result<ThemeBackupSummary, String> {
+    let theme_dir = get_theme_dir()?;
+    if !theme_dir.exists() {
+        return Ok(ThemeBackupSummary::default());
+    }
+    let current_themes = load_themes_from_dir(&theme_dir)?.themes.len();
+    let backup_themes = read_theme_files(&get_theme_file_paths(&theme_dir, true)?)
+        .themes
+        .len();
+    Ok(ThemeBackupSummary {
+        current_themes,
+        backup_themes,
+    })
+}
+
+fn restore_themes_from_backup_in(theme_dir: &Path) -> Result<LoadedThemes, String> {
+    let backup_file_paths = get_theme_file_paths(theme_dir, true)?;
+    let mut restored_count = 0;
+    for backup_file_path in backup_file_paths {
+        if read_theme_files(std::slice::from_ref(&backup_file_path))
+            .themes
+            .is_empty()
+        {
+            continue;
+        }
+        let theme_file_path = backup_file_path.with_extension("");
+        fs::copy(&backup_file_path, &theme_file_path).map_err(|e| e.to_string())?;
+        restored_count += 1;
+    }
+    info!("Restored {} themes from backup", restored_count);
+    load_themes_from_dir(theme_dir)
+}
+
+/// Copies every readable theme backup over its theme file and returns the themes now on disk
+pub fn restore_themes_from_backup() -> Result<LoadedThemes, String> {
+    restore_themes_from_backup_in(&get_theme_dir()?)
 }
 
 pub fn save_theme(theme: Theme) -> Result<String, String> {
@@ -366,13 +519,16 @@ pub fn save_theme(theme: Theme) -> Result<String, String> {
         make_file_system_safe_name(&theme.name)
     );
     let theme_path = theme_dir.join(theme_name);
-    let write_status = fs::write(
-        theme_path.clone(),
-        serde_json::to_string_pretty(&theme).unwrap(),
-    );
-    if let Err(write_status) = write_status {
-        return Err(write_status.to_string());
+    if theme_path.exists() {
+        let backup_path = get_theme_backup_path(&theme_path);
+        if let Err(e) = fs::copy(&theme_path, &backup_path) {
+            return Err(format!("Could not back up theme file: {}", e));
+        }
     }
+    write_file_atomically(
+        &theme_path,
+        serde_json::to_string_pretty(&theme).unwrap().as_bytes(),
+    )?;
     Ok(theme_path.to_str().unwrap().to_string())
 }
 
@@ -384,9 +540,213 @@ fn write_default_config() {
     }
 }
 
-#[derive(Serialize, Deserialize, Debug)]
-pub struct ExportStruct {
-    pub boards: Vec<Board>,
-    pub export_date: String,
-    pub kanban_version: String,
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{kanban::Card, save_file::save, stats_history::load_stats_history};
+
+    fn temp_save_directory(name: &str) -> PathBuf {
+        let save_directory =
+            env::temp_dir().join(format!("rust_kanban_{}_{}", name, std::process::id()));
+        fs::remove_dir_all(&save_directory).ok();
+        fs::create_dir_all(&save_directory).unwrap();
+        save_directory
+    }
+
+    fn config_for(save_directory: &Path) -> AppConfig {
+        AppConfig {
+            save_directory: save_directory.to_path_buf(),
+            ..AppConfig::default()
+        }
+    }
+
+    /// Saves `board_names` to `path` and returns the bytes that were written
+    fn write_save(path: &Path, board_names: &[&str]) -> Vec<u8> {
+        let boards = board_names
+            .iter()
+            .map(|name| Board::new(name, ""))
+            .collect::<Vec<Board>>();
+        save(&boards, path, DateTimeFormat::default()).unwrap();
+        fs::read(path).unwrap()
+    }
+
+    fn truncate(path: &Path) {
+        let contents = fs::read(path).unwrap();
+        fs::write(path, &contents[..contents.len() / 2]).unwrap();
+    }
+
+    #[test]
+    fn a_complete_leftover_temp_file_is_offered_for_a_truncated_save() {
+        let save_directory = temp_save_directory("recover_from_temp");
+        let config = config_for(&save_directory);
+        write_save(&save_directory.join("kanban_01-01-2024_v1.json"), &["Old"]);
+        let corrupt_path = save_directory.join("kanban_01-01-2024_v2.json");
+        let contents = write_save(&corrupt_path, &["New", "Newer"]);
+        let temp_path = get_atomic_write_temp_path(&corrupt_path);
+        fs::write(&temp_path, contents).unwrap();
+        truncate(&corrupt_path);
+
+        let candidate = find_save_recovery_candidate("kanban_01-01-2024_v2.json", &config).unwrap();
+        assert_eq!(candidate.recovery_path, temp_path);
+        assert_eq!(candidate.board_count, 2);
+        let boards = load_save_recovery_candidate(&candidate).unwrap();
+        assert_eq!(boards.get_boards()[1].name, "Newer");
+        fs::remove_dir_all(&save_directory).ok();
+    }
+
+    #[test]
+    fn a_truncated_temp_file_falls_back_to_the_older_save() {
+        let save_directory = temp_save_directory("recover_from_older_save");
+        let config = config_for(&save_directory);
+        let older_path = save_directory.join("kanban_01-01-2024_v1.json");
+        write_save(&older_path, &["Old"]);
+        let corrupt_path = save_directory.join("kanban_01-01-2024_v2.json");
+        let contents = write_save(&corrupt_path, &["New", "Newer"]);
+        fs::write(get_atomic_write_temp_path(&corrupt_path), contents).unwrap();
+        truncate(&corrupt_path);
+        truncate(&get_leftover_temp_paths(&corrupt_path)[0]);
+
+        let candidate = find_save_recovery_candidate("kanban_01-01-2024_v2.json", &config).unwrap();
+        assert_eq!(candidate.recovery_path, older_path);
+        assert_eq!(candidate.board_count, 1);
+        fs::remove_dir_all(&save_directory).ok();
+    }
+
+    #[test]
+    fn there_is_no_candidate_when_nothing_parses() {
+        let save_directory = temp_save_directory("recover_nothing");
+        let config = config_for(&save_directory);
+        let corrupt_path = save_directory.join("kanban_01-01-2024_v1.json");
+        write_save(&corrupt_path, &["Only"]);
+        truncate(&corrupt_path);
+
+        assert!(find_save_recovery_candidate("kanban_01-01-2024_v1.json", &config).is_none());
+        fs::remove_dir_all(&save_directory).ok();
+    }
+
+    #[test]
+    fn pruning_keeps_the_newest_saves_and_other_files() {
+        let save_directory = temp_save_directory("prune_saves");
+        let config = AppConfig {
+            save_files_to_keep: 2,
+            ..config_for(&save_directory)
+        };
+        // The day comes before the version when ordering, v10 of a day is newer than v9
+        let save_names = [
+            "kanban_31-12-2023_v3.json",
+            "kanban_01-01-2024_v9.json",
+            "kanban_01-01-2024_v10.json",
+            "kanban_02-01-2024_v1.json",
+        ];
+        for save_name in save_names {
+            write_save(&save_directory.join(save_name), &["Board"]);
+        }
+        fs::write(save_directory.join(STATS_HISTORY_FILE_NAME), "[]").unwrap();
+
+        let pruned_files = prune_old_save_files(&config).unwrap();
+        assert_eq!(pruned_files, save_names[..2]);
+        assert_eq!(
+            get_available_local_save_files(&config).unwrap(),
+            save_names[2..]
+        );
+        assert!(save_directory.join(STATS_HISTORY_FILE_NAME).exists());
+        assert!(prune_old_save_files(&config).unwrap().is_empty());
+        fs::remove_dir_all(&save_directory).ok();
+    }
+
+    #[test]
+    fn pruning_is_off_when_every_save_is_kept() {
+        let save_directory = temp_save_directory("prune_saves_off");
+        let config = config_for(&save_directory);
+        for version in 1..=3 {
+            write_save(
+                &save_directory.join(format!("kanban_01-01-2024_v{}.json", version)),
+                &["Board"],
+            );
+        }
+
+        assert_eq!(config.save_files_to_keep, 0);
+        assert!(prune_old_save_files(&config).unwrap().is_empty());
+        assert_eq!(get_available_local_save_files(&config).unwrap().len(), 3);
+        fs::remove_dir_all(&save_directory).ok();
+    }
+
+    /// Writes a theme with the given name to the theme directory and returns its path
+    fn write_theme(theme_dir: &Path, name: &str) -> PathBuf {
+        let theme = Theme {
+            name: name.to_string(),
+            ..Theme::default()
+        };
+        let theme_path = theme_dir.join(format!("{}_{}.json", THEME_FILE_NAME, name));
+        fs::write(&theme_path, serde_json::to_string_pretty(&theme).unwrap()).unwrap();
+        theme_path
+    }
+
+    fn theme_names(loaded_themes: &LoadedThemes) -> Vec<&str> {
+        loaded_themes
+            .themes
+            .iter()
+            .map(|theme| theme.name.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn a_truncated_theme_file_is_skipped_and_reported() {
+        let theme_dir = temp_save_directory("truncated_theme");
+        truncate(&write_theme(&theme_dir, "broken"));
+
+        let loaded_themes = load_themes_from_dir(&theme_dir).unwrap();
+        assert!(loaded_themes.themes.is_empty());
+        assert_eq!(loaded_themes.skipped_files, ["kanban_theme_broken.json"]);
+        fs::remove_dir_all(&theme_dir).ok();
+    }
+
+    #[test]
+    fn one_bad_theme_file_does_not_lose_the_good_ones() {
+        let theme_dir = temp_save_directory("one_bad_theme");
+        write_theme(&theme_dir, "dawn");
+        fs::write(theme_dir.join("kanban_theme_bad.json"), "{\"name\": 5}").unwrap();
+        write_theme(&theme_dir, "dusk");
+
+        let loaded_themes = load_themes_from_dir(&theme_dir).unwrap();
+        assert_eq!(theme_names(&loaded_themes), ["dawn", "dusk"]);
+        assert_eq!(loaded_themes.skipped_files, ["kanban_theme_bad.json"]);
+        fs::remove_dir_all(&theme_dir).ok();
+    }
+
+    #[test]
+    fn restoring_copies_readable_backups_over_corrupt_themes() {
+        let theme_dir = temp_save_directory("restore_themes");
+        let dawn_path = write_theme(&theme_dir, "dawn");
+        fs::copy(&dawn_path, get_theme_backup_path(&dawn_path)).unwrap();
+        truncate(&dawn_path);
+        // A corrupt backup is not copied over the theme it backs up
+        let dusk_path = write_theme(&theme_dir, "dusk");
+        fs::write(get_theme_backup_path(&dusk_path), "{").unwrap();
+
+        let loaded_themes = restore_themes_from_backup_in(&theme_dir).unwrap();
+        assert_eq!(theme_names(&loaded_themes), ["dawn", "dusk"]);
+        assert!(loaded_themes.skipped_files.is_empty());
+        fs::remove_dir_all(&theme_dir).ok();
+    }
+
+    #[test]
+    fn saves_that_keep_the_card_counts_add_one_stats_record() {
+        let save_directory = temp_save_directory("stats_history_saves");
+        let config = config_for(&save_directory);
+        let mut board = Board::new("Todo", "");
+        board.cards.add_card(Card::default());
+        save_kanban_state_locally(vec![board.clone()], &config, false).unwrap();
+        board.description = "Renamed".to_string();
+        save_kanban_state_locally(vec![board.clone()], &config, false).unwrap();
+        let history_path = save_directory.join(STATS_HISTORY_FILE_NAME);
+        assert_eq!(load_stats_history(&history_path).len(), 1);
+
+        board.cards.add_card(Card::default());
+        save_kanban_state_locally(vec![board], &config, false).unwrap();
+        let history = load_stats_history(&history_path);
+        assert_eq!(history.len(), 2);
+        assert_eq!(history[1].total_cards, 2);
+        fs::remove_dir_all(&save_directory).ok();
+    }
 }
@@ -0,0 +1,64 @@
+#[cfg(feature = "desktop-notifications")]
+use log::debug;
+#[cfg(feature = "desktop-notifications")]
+use std::process::{Command, Stdio};
+
+/// Shows `title` and `body` as a desktop notification. Fails when no notifier could be run, the
+/// caller is expected to show the text some other way
+#[cfg(feature = "desktop-notifications")]
+pub fn send_desktop_notification(title: &str, body: &str) -> Result<(), String> {
+    let notifiers: [(&str, Vec<String>); 2] = [
+        (
+            "notify-send",
+            vec![
+                "--app-name=rust-kanban".to_string(),
+                title.to_string(),
+                body.to_string(),
+            ],
+        ),
+        (
+            "osascript",
+            vec![
+                "-e".to_string(),
+                format!(
+                    "display notification {} with title {}",
+                    to_applescript_string(body),
+                    to_applescript_string(title)
+                ),
+            ],
+        ),
+    ];
+    for (program, args) in &notifiers {
+        let status = Command::new(program)
+            .args(args)
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .status();
+        match status {
+            Ok(status) if status.success() => {
+                debug!("Sent desktop notification \"{}\" with {}", title, program);
+                return Ok(());
+            }
+            Ok(status) => debug!("Could not notify with {}: exited with {}", program, status),
+            Err(err) => debug!("Could not notify with {}: {}", program, err),
+        }
+    }
+    let programs = notifiers
+        .iter()
+        .map(|(program, _)| *program)
+        .collect::<Vec<&str>>()
+        .join(", ");
+    Err(format!("No desktop notifier available, tried {}", programs))
+}
+
+#[cfg(not(feature = "desktop-notifications"))]
+pub fn send_desktop_notification(_title: &str, _body: &str) -> Result<(), String> {
+    Err("Built without the desktop-notifications feature".to_string())
+}
+
+/// Quotes `text` as an AppleScript string literal
+#[cfg(feature = "desktop-notifications")]
+fn to_applescript_string(text: &str) -> String {
+    format!("\"{}\"", text.replace('\\', "\\\\").replace('"', "\\\""))
+}
@@ -0,0 +1,264 @@
+use crate::{
+    app::{kanban::Card, ActionHistory},
+    constants::{
+        EVENT_LOG_FILE_NAME, EVENT_LOG_MAX_FILE_SIZE, EVENT_LOG_ROTATED_FILE_NAME,
+        EVENT_LOG_SCHEMA_VERSION,
+    },
+};
+use serde::Serialize;
+use serde_json::{json, Value};
+use std::{fs, io::Write, path::Path};
+use strum::Display;
+
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq, Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum BoardEventKind {
+    BoardCreated,
+    BoardDeleted,
+    BoardEdited,
+    CardCreated,
+    CardDeleted,
+    CardEdited,
+    CardMoved,
+    CardPriorityChanged,
+    CardStatusChanged,
+    CardsSorted,
+    BoardMoved,
+    Batch,
+}
+
+/// Whether the mutation was made directly or by walking the undo history
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum BoardEventOrigin {
+    Action,
+    Undo,
+    Redo,
+}
+
+/// One line of the event log, `schema_version` is bumped whenever a field changes meaning
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct BoardEvent {
+    pub schema_version: u32,
+    pub timestamp: String,
+    pub kind: BoardEventKind,
+    pub origin: BoardEventOrigin,
+    pub board_id: Option<(u64, u64)>,
+    pub card_id: Option<(u64, u64)>,
+    pub payload: Value,
+}
+
+impl BoardEvent {
+    /// The event for a committed history entry. Undo passes the entry it reverts, the event
+    /// describes the compensating mutation that was applied
+    pub fn from_action_history(action: &ActionHistory, origin: BoardEventOrigin) -> Self {
+        if origin == BoardEventOrigin::Undo {
+            Self::from_applied_action(&action.inverted(), origin)
+        } else {
+            Self::from_applied_action(action, origin)
+        }
+    }
+
+    fn from_applied_action(action: &ActionHistory, origin: BoardEventOrigin) -> Self {
+        let (kind, board_id, card_id, payload) = match action {
+            ActionHistory::CreateCard(card, board_id) => (
+                BoardEventKind::CardCreated,
+                Some(*board_id),
+                Some(card.id),
+                card_summary(card),
+            ),
+            ActionHistory::DeleteCard(card, board_id) => (
+                BoardEventKind::CardDeleted,
+                Some(*board_id),
+                Some(card.id),
+                card_summary(card),
+            ),
+            ActionHistory::EditCard(old_card, new_card, board_id) => {
+                let (kind, payload) = card_edit_payload(old_card, new_card);
+                (kind, Some(*board_id), Some(new_card.id), payload)
+            }
+            ActionHistory::MoveCardBetweenBoards(
+                card,
+                moved_from_board_id,
+                moved_to_board_id,
+                moved_from_index,
+                moved_to_index,
+            ) => (
+                BoardEventKind::CardMoved,
+                Some(*moved_to_board_id),
+                Some(card.id),
+                json!({
+                    "name": card.name,
+                    "from_board_id": moved_from_board_id,
+                    "to_board_id": moved_to_board_id,
+                    "from_index": moved_from_index,
+                    "to_index": moved_to_index,
+                }),
+            ),
+            ActionHistory::MoveCardWithinBoard(board_id, moved_from_index, moved_to_index) => (
+                BoardEventKind::CardMoved,
+                Some(*board_id),
+                None,
+                json!({
+                    "from_board_id": board_id,
+                    "to_board_id": board_id,
+                    "from_index": moved_from_index,
+                    "to_index": moved_to_index,
+                }),
+            ),
+            ActionHistory::CreateBoard(board) => (
+                BoardEventKind::BoardCreated,
+                Some(board.id),
+                None,
+                json!({ "name": board.name, "card_count": board.cards.len() }),
+            ),
+            ActionHistory::DeleteBoard(board) => (
+                BoardEventKind::BoardDeleted,
+                Some(board.id),
+                None,
+                json!({ "name": board.name, "card_count": board.cards.len() }),
+            ),
+            ActionHistory::EditBoard(old_board, new_board) => (
+                BoardEventKind::BoardEdited,
+                Some(new_board.id),
+                None,
+                json!({
+                    "old_name": old_board.name,
+                    "new_name": new_board.name,
+                    "description_changed": old_board.description != new_board.description,
+                    "notes_changed": old_board.notes != new_board.notes,
+                    "accent_color_changed": old_board.accent_color != new_board.accent_color,
+                }),
+            ),
+            ActionHistory::SortCards(board_id, original_card_ids, sorted_card_ids) => (
+                BoardEventKind::CardsSorted,
+                Some(*board_id),
+                None,
+                json!({
+                    "from_order": original_card_ids,
+                    "to_order": sorted_card_ids,
+                }),
+            ),
+            ActionHistory::MoveBoard(moved_from_index, moved_to_index) => (
+                BoardEventKind::BoardMoved,
+                None,
+                None,
+                json!({
+                    "from_index": moved_from_index,
+                    "to_index": moved_to_index,
+                }),
+            ),
+            ActionHistory::Batch(actions) => {
+                let events = actions
+                    .iter()
+                    .map(|child| BoardEvent::from_applied_action(child, origin))
+                    .collect::<Vec<BoardEvent>>();
+                (
+                    BoardEventKind::Batch,
+                    None,
+                    None,
+                    json!({ "events": events }),
+                )
+            }
+        };
+        Self {
+            schema_version: EVENT_LOG_SCHEMA_VERSION,
+            timestamp: chrono::Local::now().to_rfc3339(),
+            kind,
+            origin,
+            board_id,
+            card_id,
+            payload,
+        }
+    }
+}
+
+fn card_summary(card: &Card) -> Value {
+    json!({
+        "name": card.name,
+        "status": card.get_status_name(),
+        "priority": card.priority.to_string(),
+    })
+}
+
+/// Status changes win over priority changes so a completed card is always reported as such
+fn card_edit_payload(old_card: &Card, new_card: &Card) -> (BoardEventKind, Value) {
+    let mut changed_fields = vec![];
+    if old_card.name != new_card.name {
+        changed_fields.push("name");
+    }
+    if old_card.description != new_card.description {
+        changed_fields.push("description");
+    }
+    if old_card.start_date != new_card.start_date {
+        changed_fields.push("start_date");
+    }
+    if old_card.due_date != new_card.due_date {
+        changed_fields.push("due_date");
+    }
+    if old_card.get_status_name() != new_card.get_status_name() {
+        changed_fields.push("status");
+    }
+    if old_card.priority != new_card.priority {
+        changed_fields.push("priority");
+    }
+    if old_card.tags != new_card.tags {
+        changed_fields.push("tags");
+    }
+    if old_card.comments != new_card.comments {
+        changed_fields.push("comments");
+    }
+    if old_card.highlight_color != new_card.highlight_color {
+        changed_fields.push("highlight_color");
+    }
+    if old_card.waiting_on != new_card.waiting_on {
+        changed_fields.push("waiting_on");
+    }
+    let mut payload = json!({
+        "name": new_card.name,
+        "changed_fields": changed_fields,
+    });
+    let kind = if old_card.get_status_name() != new_card.get_status_name() {
+        payload["from"] = json!(old_card.get_status_name());
+        payload["to"] = json!(new_card.get_status_name());
+        BoardEventKind::CardStatusChanged
+    } else if old_card.priority != new_card.priority {
+        payload["from"] = json!(old_card.priority.to_string());
+        payload["to"] = json!(new_card.priority.to_string());
+        BoardEventKind::CardPriorityChanged
+    } else {
+        BoardEventKind::CardEdited
+    };
+    (kind, payload)
+}
+
+/// Appends one JSON line per event, the log is moved aside once it grows past the size cap so
+/// there is at most one rotated file next to it
+pub fn append_events(save_directory: &Path, events: &[BoardEvent]) -> Result<(), String> {
+    let event_log_path = save_directory.join(EVENT_LOG_FILE_NAME);
+    if let Ok(metadata) = fs::metadata(&event_log_path) {
+        if metadata.len() >= EVENT_LOG_MAX_FILE_SIZE {
+            fs::rename(
+                &event_log_path,
+                save_directory.join(EVENT_LOG_ROTATED_FILE_NAME),
+            )
+            .map_err(|e| format!("Could not rotate the event log: {}", e))?;
+        }
+    }
+    let mut lines = String::new();
+    for event in events {
+        let line = serde_json::to_string(event)
+            .map_err(|e| format!("Could not serialize event: {}", e))?;
+        lines.push_str(&line);
+        lines.push('\n');
+    }
+    let mut event_log_file = fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&event_log_path)
+        .map_err(|e| format!("Could not open the event log: {}", e))?;
+    event_log_file
+        .write_all(lines.as_bytes())
+        .map_err(|e| format!("Could not write to the event log: {}", e))
+}
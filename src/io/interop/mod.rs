@@ -0,0 +1,4 @@
+//! Conversions between boards and the formats of other task tools, without calling the tools
+//! themselves
+
+pub mod taskwarrior;
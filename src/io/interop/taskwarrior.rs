@@ -0,0 +1,429 @@
+//! Turning cards into the JSON `task import` reads and reading back what `task export` writes.
+//! A task's uuid is the card id written as a uuid, so exporting a card twice gives the same task
+//! and importing that task again finds the card it came from
+
+use crate::{
+    constants::{FIELD_NA, FIELD_NOT_SET, TASKWARRIOR_DATE_FORMAT, TASKWARRIOR_DEFAULT_BOARD_NAME},
+    model::{
+        date_time_format::{parse_date_time, DateTimeFormat},
+        kanban::{Board, Boards, Card, CardPriority, CardStatus},
+    },
+};
+use chrono::{Local, NaiveDateTime, NaiveTime, TimeDelta, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// The parts of a task that have somewhere to go on a card, other attributes in an export are
+/// ignored
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct TaskwarriorTask {
+    pub uuid: String,
+    pub description: String,
+    pub status: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub entry: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub modified: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub end: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub due: Option<String>,
+    /// "H", "M" or "L"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub annotations: Vec<TaskwarriorAnnotation>,
+    /// A user defined attribute for the card description, a task's own description is its name
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kanban_description: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct TaskwarriorAnnotation {
+    pub entry: String,
+    pub description: String,
+}
+
+/// What an import changed, the boards passed in already have these changes
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct TaskwarriorImport {
+    /// Names of the boards made for projects no board was named after
+    pub created_boards: Vec<String>,
+    pub added_cards: usize,
+    pub updated_cards: usize,
+    pub unchanged_cards: usize,
+    /// Deleted tasks and recurring templates, which have no card to go to
+    pub skipped_tasks: usize,
+}
+
+impl TaskwarriorImport {
+    /// One line for the terminal
+    pub fn summary(&self) -> String {
+        let mut summary = format!(
+            "{} added, {} updated, {} unchanged",
+            self.added_cards, self.updated_cards, self.unchanged_cards
+        );
+        if self.skipped_tasks > 0 {
+            summary.push_str(&format!(", {} skipped", self.skipped_tasks));
+        }
+        summary
+    }
+}
+
+/// The uuid of the task a card becomes
+pub fn task_uuid(card_id: (u64, u64)) -> String {
+    Uuid::from_u64_pair(card_id.0, card_id.1)
+        .hyphenated()
+        .to_string()
+}
+
+/// The card id a task uuid stands for, None when it is not a uuid
+pub fn card_id_from_task_uuid(uuid: &str) -> Option<(u64, u64)> {
+    Uuid::parse_str(uuid.trim())
+        .ok()
+        .map(|uuid| uuid.as_u64_pair())
+}
+
+fn priority_to_task(priority: &CardPriority) -> &'static str {
+    match priority {
+        CardPriority::High => "H",
+        CardPriority::Medium => "M",
+        CardPriority::Low => "L",
+    }
+}
+
+// A task without a priority gets the one new cards start with
+fn priority_from_task(priority: Option<&str>) -> CardPriority {
+    match priority
+        .map(|priority| priority.trim().to_uppercase())
+        .as_deref()
+    {
+        Some("H") => CardPriority::High,
+        Some("M") => CardPriority::Medium,
+        _ => CardPriority::Low,
+    }
+}
+
+// Taskwarrior dates are in UTC, card dates are local times
+fn to_task_date(date_time: NaiveDateTime) -> Option<String> {
+    Local
+        .from_local_datetime(&date_time)
+        .earliest()
+        .map(|date_time| {
+            date_time
+                .with_timezone(&Utc)
+                .format(TASKWARRIOR_DATE_FORMAT)
+                .to_string()
+        })
+}
+
+fn from_task_date(date: &str) -> Option<NaiveDateTime> {
+    let date_time = NaiveDateTime::parse_from_str(date.trim(), TASKWARRIOR_DATE_FORMAT).ok()?;
+    Some(
+        Utc.from_utc_datetime(&date_time)
+            .with_timezone(&Local)
+            .naive_local(),
+    )
+}
+
+fn card_date_to_task(date: &str) -> Option<String> {
+    parse_date_time(date).and_then(to_task_date)
+}
+
+fn format_card_date_time(date_time: NaiveDateTime, date_time_format: DateTimeFormat) -> String {
+    date_time
+        .format(DateTimeFormat::add_time_to_date_format(date_time_format).to_parser_string())
+        .to_string()
+}
+
+// Due dates at midnight were most likely set without a time, they are written back without one
+fn format_due_date(due_date: NaiveDateTime, date_time_format: DateTimeFormat) -> String {
+    if due_date.time() == NaiveTime::MIN {
+        due_date
+            .format(
+                DateTimeFormat::remove_time_from_date_format(date_time_format).to_parser_string(),
+            )
+            .to_string()
+    } else {
+        format_card_date_time(due_date, date_time_format)
+    }
+}
+
+/// The task a card becomes, with the name of its board as the project. Comments become
+/// annotations a second apart so taskwarrior keeps them apart and in order
+pub fn card_to_task(card: &Card, project: &str) -> TaskwarriorTask {
+    let annotations_start = parse_date_time(&card.date_modified)
+        .or_else(|| parse_date_time(&card.date_created))
+        .unwrap_or_default();
+    let annotations = card
+        .comments
+        .iter()
+        .enumerate()
+        .filter_map(|(index, comment)| {
+            let entry = annotations_start + TimeDelta::seconds(index as i64);
+            Some(TaskwarriorAnnotation {
+                entry: to_task_date(entry)?,
+                description: comment.clone(),
+            })
+        })
+        .collect();
+    let is_completed = card.card_status == CardStatus::Complete;
+    let description = card.description.trim();
+    TaskwarriorTask {
+        uuid: task_uuid(card.id),
+        description: card.name.clone(),
+        status: if is_completed { "completed" } else { "pending" }.to_string(),
+        project: Some(project.to_string()),
+        entry: card_date_to_task(&card.date_created),
+        modified: card_date_to_task(&card.date_modified),
+        end: if is_completed {
+            card_date_to_task(&card.date_completed)
+        } else {
+            None
+        },
+        due: card_date_to_task(&card.due_date),
+        priority: Some(priority_to_task(&card.priority).to_string()),
+        tags: card.tags.clone(),
+        annotations,
+        kanban_description: if description.is_empty() || description == FIELD_NOT_SET {
+            None
+        } else {
+            Some(card.description.clone())
+        },
+    }
+}
+
+/// Every card that is on a board as a JSON array for `task import`, archived boards and cards
+/// are left out
+pub fn export_tasks(boards: &Boards) -> Result<String, String> {
+    let tasks: Vec<TaskwarriorTask> = boards
+        .get_boards()
+        .iter()
+        .filter(|board| !board.archived)
+        .flat_map(|board| {
+            board
+                .cards
+                .get_all_cards()
+                .iter()
+                .filter(|card| card.card_status != CardStatus::Archived)
+                .map(|card| card_to_task(card, &board.name))
+        })
+        .collect();
+    serde_json::to_string_pretty(&tasks).map_err(|e| format!("Could not write the tasks: {}", e))
+}
+
+/// Reads what `task export` writes, a JSON array or one task per line as older versions wrote
+pub fn parse_task_export(json: &str) -> Result<Vec<TaskwarriorTask>, String> {
+    let json = json.trim();
+    if json.starts_with('[') {
+        return serde_json::from_str(json).map_err(|e| format!("Not a taskwarrior export: {}", e));
+    }
+    json.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| {
+            serde_json::from_str(line.trim().trim_end_matches(','))
+                .map_err(|e| format!("Not a taskwarrior task on line {}: {}", index + 1, e))
+        })
+        .collect()
+}
+
+// Copies the task onto the card, the description is only touched when the task carries one so
+// tasks made in taskwarrior do not clear it
+fn apply_task(card: &mut Card, task: &TaskwarriorTask, date_time_format: DateTimeFormat) {
+    card.name = task.description.trim().to_string();
+    if let Some(description) = &task.kanban_description {
+        card.description.clone_from(description);
+    }
+    let task_due_date = task.due.as_deref().and_then(from_task_date);
+    if parse_date_time(&card.due_date) != task_due_date {
+        card.due_date = match task_due_date {
+            Some(due_date) => format_due_date(due_date, date_time_format),
+            None => FIELD_NOT_SET.to_string(),
+        };
+    }
+    card.priority = priority_from_task(task.priority.as_deref());
+    card.tags.clone_from(&task.tags);
+    let mut annotations = task.annotations.clone();
+    annotations.sort_by(|a, b| a.entry.cmp(&b.entry));
+    card.comments = annotations
+        .into_iter()
+        .map(|annotation| annotation.description)
+        .collect();
+
+    match (task.status.as_str(), &card.card_status) {
+        ("completed", CardStatus::Active | CardStatus::Stale) => {
+            card.card_status = CardStatus::Complete;
+            let completed_at = task
+                .end
+                .as_deref()
+                .and_then(from_task_date)
+                .unwrap_or_else(|| Local::now().naive_local());
+            card.date_completed = format_card_date_time(completed_at, date_time_format);
+        }
+        ("pending" | "waiting", CardStatus::Complete) => {
+            card.card_status = CardStatus::Active;
+            card.date_completed = FIELD_NA.to_string();
+        }
+        _ => {}
+    }
+}
+
+/// Updates the card each task was exported from and adds a card for every other task, to the
+/// board named after its project which is made when missing. Deleted tasks and recurring
+/// templates are skipped
+pub fn import_tasks(
+    boards: &mut Boards,
+    tasks: &[TaskwarriorTask],
+    date_time_format: DateTimeFormat,
+) -> TaskwarriorImport {
+    let mut import = TaskwarriorImport::default();
+    let modified_at = format_card_date_time(Local::now().naive_local(), date_time_format);
+
+    for task in tasks {
+        if matches!(task.status.as_str(), "deleted" | "recurring") {
+            import.skipped_tasks += 1;
+            continue;
+        }
+        let card_id = card_id_from_task_uuid(&task.uuid);
+        let existing_card = card_id.and_then(|card_id| {
+            boards
+                .get_mut_boards()
+                .iter_mut()
+                .find_map(|board| board.cards.get_mut_card_with_id(card_id))
+        });
+        if let Some(card) = existing_card {
+            let old_card = card.clone();
+            apply_task(card, task, date_time_format);
+            if *card == old_card {
+                import.unchanged_cards += 1;
+            } else {
+                card.date_modified.clone_from(&modified_at);
+                import.updated_cards += 1;
+            }
+            continue;
+        }
+
+        let board_name = task
+            .project
+            .as_deref()
+            .map(str::trim)
+            .filter(|project| !project.is_empty())
+            .unwrap_or(TASKWARRIOR_DEFAULT_BOARD_NAME);
+        let board_id = match boards
+            .get_boards()
+            .iter()
+            .find(|board| board.name == board_name)
+        {
+            Some(board) => board.id,
+            None => {
+                let mut board = Board::new(board_name, "Imported from taskwarrior");
+                board.id = boards.generate_unused_id();
+                import.created_boards.push(board_name.to_string());
+                let board_id = board.id;
+                boards.add_board(board);
+                board_id
+            }
+        };
+
+        let mut card = Card::new(
+            "",
+            "",
+            "",
+            CardPriority::Low,
+            Vec::new(),
+            Vec::new(),
+            date_time_format,
+        );
+        // Keeping the task's uuid as the id lets the next import find this card
+        card.id = match card_id {
+            Some(card_id) if !boards.contains_id(card_id) => card_id,
+            _ => boards.generate_unused_id(),
+        };
+        if let Some(created_at) = task.entry.as_deref().and_then(from_task_date) {
+            card.date_created = format_card_date_time(created_at, date_time_format);
+        }
+        apply_task(&mut card, task, date_time_format);
+        if let Some(board) = boards.get_mut_board_with_id(board_id) {
+            board.cards.add_card(card);
+            import.added_cards += 1;
+        }
+    }
+    import
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn home_boards() -> Boards {
+        let mut board = Board::new("Home", "");
+        board.cards.add_card(Card {
+            name: "Paint the fence".to_string(),
+            priority: CardPriority::Medium,
+            comments: vec!["Buy paint".to_string(), "Borrow a ladder".to_string()],
+            ..Default::default()
+        });
+        let mut boards = Boards::default();
+        boards.add_board(board);
+        boards
+    }
+
+    #[test]
+    fn exported_cards_map_to_task_fields() {
+        let boards = home_boards();
+        let tasks = parse_task_export(&export_tasks(&boards).unwrap()).unwrap();
+        assert_eq!(tasks.len(), 1);
+        assert_eq!(tasks[0].project.as_deref(), Some("Home"));
+        assert_eq!(tasks[0].priority.as_deref(), Some("M"));
+        // The card has no due date, so neither has the task
+        assert_eq!(tasks[0].due, None);
+    }
+
+    #[test]
+    fn an_exported_card_imported_again_finds_the_card_and_changes_nothing() {
+        let mut boards = home_boards();
+        let tasks = parse_task_export(&export_tasks(&boards).unwrap()).unwrap();
+        let before = boards.clone();
+        let import = import_tasks(&mut boards, &tasks, DateTimeFormat::default());
+        assert_eq!(import.unchanged_cards, 1);
+        assert_eq!(import.added_cards, 0);
+        assert_eq!(boards, before);
+    }
+
+    #[test]
+    fn annotations_come_back_as_comments_oldest_first() {
+        let mut boards = home_boards();
+        let mut task = parse_task_export(&export_tasks(&boards).unwrap()).unwrap()[0].clone();
+        task.priority = Some("H".to_string());
+        task.annotations.reverse();
+        let import = import_tasks(&mut boards, &[task], DateTimeFormat::default());
+        assert_eq!(import.updated_cards, 1);
+        let card = &boards.get_boards()[0].cards.get_all_cards()[0];
+        assert_eq!(card.priority, CardPriority::High);
+        assert_eq!(card.comments, vec!["Buy paint", "Borrow a ladder"]);
+    }
+
+    #[test]
+    fn new_tasks_go_to_a_board_for_their_project_and_deleted_ones_are_skipped() {
+        let export = r#"
+{"uuid":"5f8b1ad6-3c2a-4f0e-9b7d-2a1c6e4d8f10","description":"Renew passport","status":"pending"}
+{"uuid":"0c1d2e3f-4a5b-4c6d-8e7f-9a0b1c2d3e4f","description":"Old chore","status":"deleted"}
+"#;
+        let tasks = parse_task_export(export).unwrap();
+        let mut boards = Boards::default();
+        let import = import_tasks(&mut boards, &tasks, DateTimeFormat::default());
+        assert_eq!((import.added_cards, import.skipped_tasks), (1, 1));
+        assert_eq!(import.created_boards, vec![TASKWARRIOR_DEFAULT_BOARD_NAME]);
+
+        // Without a due date or priority the card gets none and the lowest
+        let card = &boards.get_boards()[0].cards.get_all_cards()[0];
+        assert_eq!(card.name, "Renew passport");
+        assert_eq!(card.due_date, FIELD_NOT_SET);
+        assert_eq!(card.priority, CardPriority::Low);
+    }
+}
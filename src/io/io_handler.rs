@@ -1,23 +1,48 @@
+pub use crate::model::save_file::make_file_system_safe_name;
 use crate::{
     app::{
-        app_helper::handle_go_to_previous_view, kanban::Boards, state::UserLoginData, App,
-        AppConfig,
+        app_helper::{
+            check_for_conflict_markers, handle_go_to_previous_view, show_whats_new_after_upgrade,
+            sync_from_mirror, warn_about_skipped_theme_files,
+        },
+        kanban::{Board, Boards},
+        state::{GithubImportReport, PendingJsonImport, UserLoginData},
+        visibility::{board_window, card_window},
+        ActionHistory, App, AppConfig,
     },
     constants::{
-        CONFIG_DIR_NAME, CONFIG_FILE_NAME, EMAIL_REGEX, ENCRYPTION_KEY_FILE_NAME,
-        MAX_PASSWORD_LENGTH, MIN_PASSWORD_LENGTH, MIN_TIME_BETWEEN_SENDING_RESET_LINK,
-        REFRESH_TOKEN_FILE_NAME, REFRESH_TOKEN_SEPARATOR, SAVE_DIR_NAME, SUPABASE_ANON_KEY,
-        SUPABASE_URL,
+        AUTO_SAVE_TOAST_DURATION, CONFIG_DIR_NAME, CONFIG_FILE_NAME, EMAIL_REGEX,
+        ENCRYPTION_KEY_FILE_NAME, GITHUB_API_URL, GITHUB_ISSUES_PER_PAGE,
+        GITHUB_MAX_RATE_LIMIT_RETRIES, GITHUB_MAX_RATE_LIMIT_WAIT, MAX_PASSWORD_LENGTH,
+        MIN_PASSWORD_LENGTH, MIN_TIME_BETWEEN_SENDING_RESET_LINK, REFRESH_TOKEN_FILE_NAME,
+        REFRESH_TOKEN_SEPARATOR, SAVE_DIR_NAME, SUPABASE_ANON_KEY, SUPABASE_URL,
     },
+    i18n::{tr, tr_args, MessageId},
     io::{
         data_handler::{
-            get_available_local_save_files, get_default_save_directory, get_local_kanban_state,
-            get_saved_themes, save_kanban_state_locally,
+            find_save_recovery_candidate, get_available_local_save_files,
+            get_default_save_directory, get_local_kanban_state, get_saved_themes,
+            prune_old_save_files, save_kanban_state_locally, write_file_atomically,
         },
-        IoEvent,
+        event_log::{append_events, BoardEvent},
+        IoCompletionKind, IoEvent, IoOutcome,
     },
-    ui::{TextColorOptions, View},
-    util::{print_debug, print_error, print_info},
+    model::{
+        cloud_sync::{
+            apply_delta, board_hashes, dependent_cloud_saves, merge_sync_ack, plan_sync,
+            resolve_cloud_save_chain, BoardsDelta, CloudSyncAck, CloudSyncMetadata, SyncPlan,
+        },
+        csv_export::boards_to_csv,
+        github_issues::{
+            import_github_issues, parse_github_issues_page, parse_github_repo, GithubImport,
+            GithubImportFailure, GithubIssue,
+        },
+        markdown_export::boards_to_markdown,
+        save_file::{load_cloud_sync_metadata, parse_save_boards, store_cloud_sync_metadata},
+        trello::parse_trello_export,
+    },
+    ui::{PopUp, TextColorOptions, View},
+    util::{generate_id, print_debug, print_error, print_info},
 };
 use aes_gcm::{
     aead::{generic_array::GenericArray, Aead, OsRng},
@@ -30,9 +55,10 @@ use linked_hash_map::LinkedHashMap;
 use log::{debug, error, info, warn};
 use ratatui::widgets::ListState;
 use reqwest::StatusCode;
-use serde::{Deserialize, Serialize};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use serde_json::json;
 use std::{
+    collections::HashSet,
     env,
     path::{Path, PathBuf},
     sync::Arc,
@@ -50,6 +76,7 @@ impl IoAsyncHandler<'_> {
 
     pub async fn handle_io_event(&mut self, io_event: IoEvent) {
         let result = match io_event {
+            IoEvent::AppendBoardEvents(events) => self.append_board_events(events).await,
             IoEvent::Initialize => self.do_initialize().await,
             IoEvent::SaveLocalData => self.save_local_data().await,
             IoEvent::LoadSaveLocal => self.load_save_file_local().await,
@@ -70,11 +97,24 @@ impl IoAsyncHandler<'_> {
                 self.reset_password(reset_link, new_password, confirm_password)
                     .await
             }
-            IoEvent::SyncLocalData => self.sync_local_data().await,
+            IoEvent::SyncLocalData => self.sync_local_data(false).await,
+            IoEvent::ForceSyncLocalData => self.sync_local_data(true).await,
             IoEvent::GetCloudData => self.get_cloud_data().await,
             IoEvent::LoadSaveCloud => self.load_save_file_cloud().await,
             IoEvent::LoadCloudPreview => self.preview_cloud_save().await,
             IoEvent::DeleteCloudSave => self.delete_cloud_save().await,
+            IoEvent::ImportTrelloJson(path) => self.import_trello_json(path).await,
+            IoEvent::ImportFromJson(path) => self.import_from_json(path).await,
+            IoEvent::ImportBoardFromJson(path) => self.import_board_from_json(path).await,
+            IoEvent::ImportGithubIssues(repo, token_env, board_name) => {
+                self.import_github_issues(repo, token_env, board_name).await
+            }
+            IoEvent::ExportToMarkdown(path) => self.export_to_markdown(path, false).await,
+            IoEvent::ExportToCsv(path) => self.export_to_csv(path).await,
+            IoEvent::ExportBoardToJson(board_id, path) => {
+                self.export_board_to_json(board_id, path).await
+            }
+            IoEvent::ExportVisibleToMarkdown(path) => self.export_to_markdown(path, true).await,
         };
 
         let mut app = self.app.lock().await;
@@ -99,12 +139,20 @@ impl IoAsyncHandler<'_> {
             error!("Cannot create save directory");
             app.send_error_toast("Cannot create save directory", None);
         }
-        prepare_boards(&mut app);
-        app.dispatch(IoEvent::ResetVisibleBoardsandCards).await;
-        let saved_themes = get_saved_themes();
-        if let Some(saved_themes) = saved_themes {
-            app.all_themes.extend(saved_themes);
+        if app.state.safe_mode {
+            app.send_warning_toast(
+                "Safe mode: no save or custom theme was loaded and config changes are not saved",
+                Some(Duration::from_secs(10)),
+            );
+        } else {
+            prepare_boards(&mut app);
+            let saved_themes = get_saved_themes();
+            if let Some(saved_themes) = saved_themes {
+                app.all_themes.extend(saved_themes.themes);
+                warn_about_skipped_theme_files(&mut app, &saved_themes.skipped_files);
+            }
         }
+        app.dispatch(IoEvent::ResetVisibleBoardsandCards).await;
         let default_theme = app.config.default_theme.clone();
         for theme in &app.all_themes {
             if theme.name == default_theme {
@@ -118,8 +166,14 @@ impl IoAsyncHandler<'_> {
             app.state.term_background_color = (0, 0, 0)
         }
         app.set_view(default_ui_view);
+        offer_save_recovery(&mut app);
+        show_whats_new_after_upgrade(&mut app);
         info!("👍 Application initialized");
         app.initialized();
+        app.auto_archive_completed_cards();
+        if !app.boards.is_empty() {
+            sync_from_mirror(&mut app, true);
+        }
         if app.config.save_directory == get_default_save_directory() {
             app.send_warning_toast(
                 "Save directory is set to a temporary directory,
@@ -174,11 +228,17 @@ impl IoAsyncHandler<'_> {
         let mut app = self.app.lock().await;
         if save_required(&mut app) {
             let board_data = app.boards.get_boards();
-            let status = save_kanban_state_locally(board_data.to_vec(), &app.config);
+            let status = save_kanban_state_locally(
+                board_data.to_vec(),
+                &app.config,
+                app.state.include_notes_in_exports,
+            );
             match status {
                 Ok(_) => {
+                    app.mark_boards_saved();
                     info!("👍 Local data saved");
                     app.send_info_toast("👍 Local data saved", None);
+                    prune_old_saves(&mut app);
                 }
                 Err(err) => {
                     debug!("Cannot save local data: {:?}", err);
@@ -187,6 +247,7 @@ impl IoAsyncHandler<'_> {
             }
             Ok(())
         } else {
+            app.mark_boards_saved();
             warn!("No changes to save");
             app.send_warning_toast("No changes to save", None);
             Ok(())
@@ -217,16 +278,21 @@ impl IoAsyncHandler<'_> {
             Ok(boards) => {
                 app.boards.set_boards(boards);
                 app.action_history_manager.reset();
+                app.mark_boards_saved();
+                app.state.save_loaded_in_safe_mode = app.state.safe_mode;
                 info!("👍 Save file {:?} loaded", save_file_name);
                 app.send_info_toast(&format!("👍 Save file {:?} loaded", save_file_name), None);
             }
             Err(err) => {
-                debug!("Cannot load save file: {:?}", err);
-                app.send_error_toast("Cannot load save file", None);
+                debug!("Cannot load save file {:?}: {:?}", save_file_name, err);
+                app.send_error_toast(&format!("Cannot load save file {:?}", save_file_name), None);
+                app.state.save_recovery_candidate =
+                    find_save_recovery_candidate(&save_file_name, &app.config);
             }
         }
         app.dispatch(IoEvent::ResetVisibleBoardsandCards).await;
         app.set_view(default_view);
+        offer_save_recovery(&mut app);
         Ok(())
     }
 
@@ -293,6 +359,236 @@ impl IoAsyncHandler<'_> {
         Ok(())
     }
 
+    async fn append_board_events(&mut self, events: Vec<BoardEvent>) -> Result<()> {
+        let save_directory = self.app.lock().await.config.save_directory.clone();
+        // The lock is not held while writing so the UI keeps drawing
+        if let Err(err) = append_events(&save_directory, &events) {
+            error!("{}", err);
+            let mut app = self.app.lock().await;
+            app.send_error_toast(&err, None);
+        }
+        Ok(())
+    }
+
+    async fn import_trello_json(&mut self, path: PathBuf) -> Result<()> {
+        info!("🚀 Importing Trello board from {}", path.display());
+        let mut app = self.app.lock().await;
+        let imported_boards = std::fs::read_to_string(&path)
+            .map_err(|e| format!("Could not read {}: {}", path.display(), e))
+            .and_then(|json| parse_trello_export(&json, app.config.date_time_format));
+        match imported_boards {
+            Ok(mut imported_boards) => {
+                for board in imported_boards.iter_mut() {
+                    if board.name.is_empty() {
+                        board.name = "Trello List".to_string();
+                    }
+                }
+                add_imported_boards(&mut app, imported_boards, "Trello", false);
+            }
+            Err(err) => {
+                error!("Trello import failed: {}", err);
+                app.send_error_toast(&format!("Trello import failed: {}", err), None);
+            }
+        }
+        Ok(())
+    }
+
+    async fn import_github_issues(
+        &mut self,
+        repo: String,
+        token_env: String,
+        board_name: String,
+    ) -> Result<()> {
+        info!("🚀 Importing GitHub issues from {}", repo);
+        {
+            let mut app = self.app.lock().await;
+            app.send_info_toast(&format!("Fetching issues from {}...", repo), None);
+        }
+        // The app is not locked while fetching, a large repository takes a few pages
+        let token = env::var(&token_env).ok().filter(|token| !token.is_empty());
+        let fetched_issues = fetch_github_issues(&repo, token.as_deref()).await;
+        let mut app = self.app.lock().await;
+        match fetched_issues {
+            Ok((issues, failures)) => {
+                let import = add_github_issues(&mut app, &issues, &board_name);
+                let summary = format!("GitHub import from {}: {}", repo, import.summary());
+                info!("{}", summary);
+                if failures.is_empty() {
+                    app.send_info_toast(&summary, None);
+                } else {
+                    warn!("{} GitHub issues could not be imported", failures.len());
+                    app.state.github_import_report = Some(GithubImportReport {
+                        repo,
+                        summary,
+                        failures,
+                    });
+                    app.state.github_import_report_scroll = 0;
+                    app.set_popup(PopUp::GithubImportSummary);
+                }
+            }
+            Err(err) => {
+                error!("GitHub import failed: {}", err);
+                app.send_error_toast(&format!("GitHub import failed: {}", err), None);
+            }
+        }
+        Ok(())
+    }
+
+    async fn import_from_json(&mut self, path: PathBuf) -> Result<()> {
+        info!("🚀 Importing boards from {}", path.display());
+        let mut app = self.app.lock().await;
+        let imported_boards = std::fs::read_to_string(&path)
+            .map_err(|e| format!("Could not read {}: {}", path.display(), e))
+            .and_then(|json| parse_save_boards(&json));
+        match imported_boards {
+            Ok(boards) if boards.is_empty() => {
+                app.send_warning_toast(
+                    &format!("{} has no boards to import", path.display()),
+                    None,
+                );
+            }
+            Ok(boards) => {
+                app.state.pending_json_import = Some(PendingJsonImport { path, boards });
+                app.set_popup(PopUp::ImportOptions);
+            }
+            Err(err) => {
+                error!("JSON import failed: {}", err);
+                app.send_error_toast(&format!("JSON import failed: {}", err), None);
+            }
+        }
+        Ok(())
+    }
+
+    async fn import_board_from_json(&mut self, path: PathBuf) -> Result<()> {
+        info!("🚀 Importing board from {}", path.display());
+        let mut app = self.app.lock().await;
+        let imported_board = std::fs::read_to_string(&path)
+            .map_err(|e| format!("Could not read {}: {}", path.display(), e))
+            .and_then(|json| {
+                serde_json::from_str::<Board>(&json)
+                    .map_err(|e| format!("{} is not an exported board: {}", path.display(), e))
+            });
+        match imported_board {
+            Ok(mut board) => {
+                assign_imported_ids(
+                    &mut board,
+                    &app.boards,
+                    &mut HashSet::new(),
+                    app.config.keep_ids_on_import,
+                );
+                let message = format!(
+                    "Imported board '{}' with {} cards from {}",
+                    board.name,
+                    board.cards.len(),
+                    path.display()
+                );
+                app.boards.add_board(board.clone());
+                app.action_history_manager
+                    .new_action(ActionHistory::CreateBoard(board));
+                refresh_visible_boards_and_cards(&mut app);
+                info!("{}", message);
+                app.send_info_toast(&message, None);
+            }
+            Err(err) => {
+                error!("Board import failed: {}", err);
+                app.send_error_toast(&format!("Board import failed: {}", err), None);
+            }
+        }
+        Ok(())
+    }
+
+    async fn export_board_to_json(&mut self, board_id: (u64, u64), path: PathBuf) -> Result<()> {
+        info!("🚀 Exporting board to {}", path.display());
+        let mut app = self.app.lock().await;
+        let board = if let Some(board) = app.boards.get_board_with_id(board_id) {
+            board.clone()
+        } else {
+            app.send_error_toast("No board Selected / Available", None);
+            return Ok(());
+        };
+        let written = serde_json::to_vec_pretty(&board)
+            .map_err(|e| e.to_string())
+            .and_then(|json| write_file_atomically(&path, &json));
+        match written {
+            Ok(_) => {
+                info!("Exported board '{}' to {}", board.name, path.display());
+                app.send_info_toast(
+                    &format!("Exported board '{}' to {}", board.name, path.display()),
+                    None,
+                );
+            }
+            Err(err) => {
+                error!("Board export failed: {}", err);
+                app.send_error_toast(&format!("Board export failed: {}", err), None);
+            }
+        }
+        Ok(())
+    }
+
+    async fn export_to_markdown(&mut self, path: PathBuf, visible_only: bool) -> Result<()> {
+        info!("🚀 Exporting boards to {}", path.display());
+        let mut app = self.app.lock().await;
+        let (boards, filter_note) = if visible_only {
+            (
+                app.get_shown_boards(),
+                app.get_card_filter()
+                    .export_note(app.config.date_time_format),
+            )
+        } else {
+            (app.boards.get_boards().clone(), None)
+        };
+        let markdown = boards_to_markdown(
+            &boards,
+            app.state.include_notes_in_exports,
+            filter_note.as_deref(),
+        );
+        match write_file_atomically(&path, markdown.as_bytes()) {
+            Ok(_) => {
+                let card_count = boards.iter().map(|board| board.cards.len()).sum::<usize>();
+                info!("Exported {} cards to {}", card_count, path.display());
+                app.send_info_toast(
+                    &format!(
+                        "Exported {} card{} to {}",
+                        card_count,
+                        if card_count == 1 { "" } else { "s" },
+                        path.display()
+                    ),
+                    None,
+                );
+            }
+            Err(err) => {
+                error!("Markdown export failed: {}", err);
+                app.send_error_toast(&format!("Markdown export failed: {}", err), None);
+            }
+        }
+        Ok(())
+    }
+
+    async fn export_to_csv(&mut self, path: PathBuf) -> Result<()> {
+        info!("🚀 Exporting cards to {}", path.display());
+        let mut app = self.app.lock().await;
+        let (csv, row_count) = boards_to_csv(app.boards.get_boards(), app.config.date_time_format);
+        match write_file_atomically(&path, csv.as_bytes()) {
+            Ok(_) => {
+                info!("Exported {} rows to {}", row_count, path.display());
+                app.send_info_toast(
+                    &format!(
+                        "Exported {} row{} to {}",
+                        row_count,
+                        if row_count == 1 { "" } else { "s" },
+                        path.display()
+                    ),
+                    None,
+                );
+            }
+            Err(err) => {
+                error!("CSV export failed: {}", err);
+                app.send_error_toast(&format!("CSV export failed: {}", err), None);
+            }
+        }
+        Ok(())
+    }
+
     async fn auto_save(&mut self) -> Result<()> {
         let mut app = self.app.lock().await;
         match auto_save(&mut app).await {
@@ -341,12 +637,12 @@ impl IoAsyncHandler<'_> {
                         break;
                     }
                     let mut visible_cards: Vec<(u64, u64)> = Vec::new();
-                    if board.cards.len() > app.config.no_of_cards_to_show.into() {
+                    if board.cards.len() > app.get_no_of_cards_to_show().into() {
                         for card in board
                             .cards
                             .get_all_cards()
                             .iter()
-                            .take(app.config.no_of_cards_to_show.into())
+                            .take(app.get_no_of_cards_to_show().into())
                         {
                             visible_cards.push(card.id);
                         }
@@ -472,6 +768,8 @@ impl IoAsyncHandler<'_> {
         if status == StatusCode::NO_CONTENT {
             let mut app = self.app.lock().await;
             app.state.user_login_data = UserLoginData::default();
+            app.state.cloud_sync_metadata = None;
+            app.state.partial_cloud_sync_unsupported = false;
             app.main_menu.logged_in = false;
             info!("👍 Logged out");
             app.send_info_toast("👍 Logged out", None);
@@ -912,16 +1210,16 @@ impl IoAsyncHandler<'_> {
         Ok(())
     }
 
-    async fn sync_local_data(&mut self) -> Result<()> {
+    /// Uploads only the boards that changed since the last acknowledged sync, as a delta on top
+    /// of that cloud save. Forced syncs, missing bookkeeping and servers that do not take deltas
+    /// upload every board
+    async fn sync_local_data(&mut self, force: bool) -> Result<()> {
         {
             let mut app = self.app.lock().await;
             if app.state.user_login_data.auth_token.is_none() {
                 error!("Not logged in");
                 app.send_error_toast("Not logged in", None);
                 return Ok(());
-            } else {
-                info!("Syncing local data, please wait...");
-                app.send_info_toast("Syncing local data, please wait...", None);
             }
         }
 
@@ -942,55 +1240,114 @@ impl IoAsyncHandler<'_> {
                 key.err()
             );
             app.send_error_toast("Error syncing local data, Could not get encryption key, If you have lost it please generate a new one using the -g flag", None);
+            app.record_io_completion(
+                IoCompletionKind::CloudSync,
+                IoOutcome::Failed,
+                "Could not get the encryption key",
+            );
             return Ok(());
         }
         let key = key.unwrap();
-        let encrypt_result = encrypt_save(&app.boards, &key);
-        if encrypt_result.is_err() {
-            error!("Error syncing local data");
-            debug!(
-                "Error syncing local data: {:?}, could not encrypt",
-                encrypt_result.err()
+        let auth_token = app.state.user_login_data.auth_token.clone().unwrap();
+        let user_id = app.state.user_login_data.user_id.clone().unwrap();
+        let boards = app.boards.get_boards().clone();
+        let cloud_sync_metadata = app.state.cloud_sync_metadata.clone().or_else(|| {
+            get_latest_save_file(&app.config)
+                .ok()
+                .and_then(|save_file| {
+                    load_cloud_sync_metadata(&app.config.save_directory.join(save_file))
+                })
+        });
+        let mut plan = if force || app.state.partial_cloud_sync_unsupported {
+            SyncPlan::Full
+        } else {
+            plan_sync(&boards, cloud_sync_metadata.as_ref(), &user_id, &save_ids)
+        };
+        if plan == SyncPlan::UpToDate {
+            info!("No changes since the last cloud sync, skipping");
+            app.send_info_toast(&tr(MessageId::ToastNothingChangedSinceTheLastSync), None);
+            app.record_io_completion(
+                IoCompletionKind::CloudSync,
+                IoOutcome::Skipped,
+                "No changes since the last sync",
             );
-            app.send_error_toast("Error syncing local data", None);
             return Ok(());
         }
-        let (encrypted_board_data, nonce) = encrypt_result.unwrap();
-        let auth_token = app.state.user_login_data.auth_token.clone().unwrap();
-        let user_id = app.state.user_login_data.user_id.clone().unwrap();
+        info!("Syncing local data, please wait...");
+        app.send_info_toast(&tr(MessageId::ToastSyncingLocalDataPleaseWait), None);
         drop(app);
-        let client = reqwest::Client::new();
-        let response = client
-            .post(format!("{}/rest/v1/user_data", SUPABASE_URL))
-            .header("apikey", SUPABASE_ANON_KEY)
-            .header("Content-Type", "application/json")
-            .header("Authorization", format!("Bearer {}", auth_token))
-            .body(
-                json!(
-                    {
-                        "user_id": user_id,
-                        "board_data": encrypted_board_data,
-                        "save_id": max_save_id,
-                        "nonce": nonce
-                    }
-                )
-                .to_string(),
-            )
-            .send()
-            .await?;
 
-        let status = response.status();
+        let mut upload =
+            upload_cloud_save(&plan, &boards, &key, &auth_token, &user_id, max_save_id).await;
+        if matches!(plan, SyncPlan::Partial { .. })
+            && matches!(&upload, Ok((status, _)) if *status == StatusCode::BAD_REQUEST)
+        {
+            warn!("The server does not take partial uploads, uploading every board");
+            self.app.lock().await.state.partial_cloud_sync_unsupported = true;
+            plan = SyncPlan::Full;
+            upload =
+                upload_cloud_save(&plan, &boards, &key, &auth_token, &user_id, max_save_id).await;
+        }
+
         let mut app = self.app.lock().await;
+        let (status, body) = match upload {
+            Ok(upload) => upload,
+            Err(err) => {
+                error!("Error syncing local data");
+                debug!("Error syncing local data: {}", err);
+                app.send_error_toast(&tr(MessageId::ToastErrorSyncingLocalData), None);
+                app.record_io_completion(IoCompletionKind::CloudSync, IoOutcome::Failed, &err);
+                return Ok(());
+            }
+        };
         if status == StatusCode::CREATED {
-            info!("👍 Local data synced to the cloud");
-            app.send_info_toast("👍 Local data synced to the cloud", None);
+            // The inserted row comes back as the acknowledgement, older servers answer empty
+            let ack = serde_json::from_str::<Vec<CloudSyncAck>>(&body)
+                .ok()
+                .and_then(|acks| acks.into_iter().next())
+                .unwrap_or(CloudSyncAck {
+                    save_id: max_save_id,
+                });
+            let metadata =
+                merge_sync_ack(cloud_sync_metadata.as_ref(), &plan, &boards, &ack, &user_id);
+            if let Ok(save_file) = get_latest_save_file(&app.config) {
+                if let Err(err) =
+                    store_cloud_sync_metadata(&app.config.save_directory.join(save_file), &metadata)
+                {
+                    warn!("Cloud sync bookkeeping was not saved: {}", err);
+                }
+            }
+            app.state.cloud_sync_metadata = Some(metadata);
+            let details = match &plan {
+                SyncPlan::Partial { delta, .. } => format!(
+                    "Uploaded {} changed and {} removed of {} boards as cloud save {}",
+                    delta.changed_boards.len(),
+                    delta.removed_board_ids.len(),
+                    boards.len(),
+                    ack.save_id
+                ),
+                _ => format!(
+                    "Synced {} boards and {} cards as cloud save {}",
+                    boards.len(),
+                    boards.iter().map(|board| board.cards.len()).sum::<usize>(),
+                    ack.save_id
+                ),
+            };
+            info!("👍 Local data synced to the cloud, {}", details);
+            app.send_info_toast(&tr(MessageId::ToastLocalDataSyncedToTheCloud), None);
+            app.record_io_completion(IoCompletionKind::CloudSync, IoOutcome::Succeeded, &details);
             if app.state.cloud_data.is_some() {
                 app.dispatch(IoEvent::GetCloudData).await;
             }
         } else {
             error!("Error syncing local data");
-            debug!("Error syncing local data: {:?}", response.text().await);
-            app.send_error_toast("Error syncing local data", None);
+            debug!("Error syncing local data: {:?}", body);
+            app.send_error_toast(&tr(MessageId::ToastErrorSyncingLocalData), None);
+            app.record_io_completion(
+                IoCompletionKind::CloudSync,
+                IoOutcome::Failed,
+                &format!("Server responded with {}", status),
+            );
         }
         Ok(())
     }
@@ -1103,6 +1460,18 @@ impl IoAsyncHandler<'_> {
             return Ok(());
         }
         let save = cloud_data[selected_index].clone();
+        let chain =
+            match resolve_cloud_save_chain(&cloud_save_chain_links(&cloud_data), save.save_id) {
+                Ok(chain) => chain,
+                Err(err) => {
+                    error!("Cannot preview cloud save {}: {}", save.save_id, err);
+                    app.send_error_toast(
+                        &format!("Cannot preview cloud save {}: {}", save.save_id, err),
+                        None,
+                    );
+                    return Ok(());
+                }
+            };
         let key = get_user_encryption_key(app.state.encryption_key_from_arguments.clone());
         if key.is_err() {
             error!("Error loading save file, Could not get user Encryption key .If lost please generate a new one by using the -g flag");
@@ -1114,7 +1483,7 @@ impl IoAsyncHandler<'_> {
             return Ok(());
         }
         let key = key.unwrap();
-        let decrypt_result = decrypt_save(save.board_data, key.as_slice(), &save.nonce);
+        let decrypt_result = decrypt_cloud_save_chain(&cloud_data, &chain, key.as_slice());
         if decrypt_result.is_err() {
             error!("Error loading save file, Could not decrypt save file. The save file must have been created with a different encryption key, either generate a new one with the -g flag or replace the current encryption key with the one used to create the save file");
             debug!("Error loading save file: {:?}", decrypt_result.err());
@@ -1136,12 +1505,12 @@ impl IoAsyncHandler<'_> {
                 break;
             }
             let mut visible_cards: Vec<(u64, u64)> = Vec::new();
-            if board.cards.len() > app.config.no_of_cards_to_show.into() {
+            if board.cards.len() > app.get_no_of_cards_to_show().into() {
                 for card in board
                     .cards
                     .get_all_cards()
                     .iter()
-                    .take(app.config.no_of_cards_to_show.into())
+                    .take(app.get_no_of_cards_to_show().into())
                 {
                     visible_cards.push(card.id);
                 }
@@ -1196,7 +1565,19 @@ impl IoAsyncHandler<'_> {
         }
         let save_file_number = local_files[save_file_index].save_id;
         info!("🚀 Loading save file: cloud_save_{}", save_file_number);
-        let encrypted_board_data = &local_files[save_file_index].board_data;
+        let chain =
+            match resolve_cloud_save_chain(&cloud_save_chain_links(&local_files), save_file_number)
+            {
+                Ok(chain) => chain,
+                Err(err) => {
+                    error!("Cannot load cloud save {}: {}", save_file_number, err);
+                    app.send_error_toast(
+                        &format!("Cannot load cloud save {}: {}", save_file_number, err),
+                        None,
+                    );
+                    return Ok(());
+                }
+            };
         let key = get_user_encryption_key(app.state.encryption_key_from_arguments.clone());
         if key.is_err() {
             error!("Error loading save file, Could not get user Encryption key. If lost please generate a new one by using the -g flag");
@@ -1208,11 +1589,7 @@ impl IoAsyncHandler<'_> {
             return Ok(());
         }
         let key = key.unwrap();
-        let decrypt_result = decrypt_save(
-            encrypted_board_data.to_string(),
-            key.as_slice(),
-            &local_files[save_file_index].nonce,
-        );
+        let decrypt_result = decrypt_cloud_save_chain(&local_files, &chain, key.as_slice());
         if decrypt_result.is_err() {
             error!("Error loading save file, Could not decrypt save file. The save file must have been created with a different encryption key, either generate a new one with the -g flag or replace the current encryption key with the one used to create the save file");
             debug!("Error loading save file: {:?}", decrypt_result.err());
@@ -1220,14 +1597,30 @@ impl IoAsyncHandler<'_> {
             return Ok(());
         }
         let decrypt_result = decrypt_result.unwrap();
+        // The loaded boards are exactly what the cloud holds for this save
+        if let Some(user_id) = app.state.user_login_data.user_id.clone() {
+            app.state.cloud_sync_metadata = Some(CloudSyncMetadata {
+                user_id,
+                save_id: save_file_number,
+                delta_depth: chain.len() - 1,
+                board_hashes: board_hashes(decrypt_result.get_boards()),
+            });
+        }
         app.boards.set_boards(decrypt_result);
-        info!("👍 Save file cloud_save_{} loaded", save_file_number);
-        app.send_info_toast(
-            &format!("👍 Save file cloud_save_{} loaded", save_file_number),
-            None,
-        );
+        app.mark_boards_changed();
+        app.state.save_loaded_in_safe_mode = app.state.safe_mode;
         app.dispatch(IoEvent::ResetVisibleBoardsandCards).await;
         app.set_view(default_view);
+        let mut message = format!("👍 Save file cloud_save_{} loaded", save_file_number);
+        let conflict_marker_cards = check_for_conflict_markers(&mut app);
+        if !conflict_marker_cards.is_empty() {
+            message.push_str(&format!(
+                ", conflict markers left in {}",
+                conflict_marker_cards.join(", ")
+            ));
+        }
+        info!("{}", message);
+        app.send_info_toast(&message, None);
         Ok(())
     }
 
@@ -1260,9 +1653,34 @@ impl IoAsyncHandler<'_> {
             app.send_error_toast("Cannot delete save file: No such file", None);
             return Ok(());
         }
-        drop(app);
         let save_file_id = cloud_saves[save_file_index].id;
         let save_number = cloud_saves[save_file_index].save_id;
+        // Partial saves on top of this one could not be loaded any more
+        let dependent_saves =
+            dependent_cloud_saves(&cloud_save_chain_links(&cloud_saves), save_number);
+        if !dependent_saves.is_empty() {
+            let dependent_save_names = dependent_saves
+                .iter()
+                .map(|save_id| format!("cloud_save_{}", save_id))
+                .collect::<Vec<String>>()
+                .join(", ");
+            error!(
+                "Cannot delete cloud_save_{}: {} depend on it",
+                save_number, dependent_save_names
+            );
+            app.send_error_toast(
+                &tr_args(
+                    MessageId::ToastCannotDeleteACloudSaveOthersDependOn,
+                    &[
+                        ("save", &save_number.to_string()),
+                        ("dependents", &dependent_save_names),
+                    ],
+                ),
+                None,
+            );
+            return Ok(());
+        }
+        drop(app);
         let delete_status =
             delete_a_save_from_database(&user_access_token, false, save_file_id, Some(save_number))
                 .await;
@@ -1320,7 +1738,7 @@ pub fn prepare_config_dir() -> Result<(), String> {
         let default_config = AppConfig::default();
         let config_json = serde_json::to_string_pretty(&default_config);
         if let Ok(config_json) = config_json {
-            let file_creation_status = std::fs::write(&config_file, config_json);
+            let file_creation_status = write_file_atomically(&config_file, config_json.as_bytes());
             if file_creation_status.is_err() {
                 return Err(String::from("Error creating config file"));
             }
@@ -1339,6 +1757,17 @@ fn prepare_save_dir() -> bool {
     true
 }
 
+/// Opened once the view is set so the view change does not take focus away from the popup
+fn offer_save_recovery(app: &mut App) {
+    if let Some(candidate) = &app.state.save_recovery_candidate {
+        warn!(
+            "{} is corrupt, offering {:?} instead",
+            candidate.corrupt_file_name, candidate.recovery_path
+        );
+        app.set_popup(PopUp::RecoverSaveFile);
+    }
+}
+
 fn prepare_boards(app: &mut App) {
     let boards = if app.config.always_load_last_save {
         let latest_save_file_info = get_latest_save_file(&app.config);
@@ -1357,6 +1786,8 @@ fn prepare_boards(app: &mut App) {
                     debug!("Cannot get local data: {:?}", err);
                     error!("👎 Cannot get local data, Data might be corrupted or is not in the correct format");
                     app.send_error_toast("👎 Cannot get local data, Data might be corrupted or is not in the correct format", None);
+                    app.state.save_recovery_candidate =
+                        find_save_recovery_candidate(&latest_save_file, &app.config);
                     Boards::default()
                 }
             }
@@ -1368,9 +1799,10 @@ fn prepare_boards(app: &mut App) {
         Boards::default()
     };
     app.boards.set_boards(boards);
+    app.mark_boards_saved();
 }
 
-fn get_latest_save_file(config: &AppConfig) -> Result<String, String> {
+pub fn get_latest_save_file(config: &AppConfig) -> Result<String, String> {
     let local_save_files = get_available_local_save_files(config);
     let local_save_files = if let Some(local_save_files) = local_save_files {
         local_save_files
@@ -1418,33 +1850,289 @@ fn get_latest_save_file(config: &AppConfig) -> Result<String, String> {
     Ok(latest_save_file)
 }
 
+/// Gives an imported board and its cards ids that no loaded board, card or id in `taken_ids`
+/// has. With keep_ids_on_import the imported ids are kept so references from other tools stay
+/// valid and only the taken ones are replaced, otherwise every id is new
+fn assign_imported_ids(
+    board: &mut Board,
+    boards: &Boards,
+    taken_ids: &mut HashSet<(u64, u64)>,
+    keep_ids_on_import: bool,
+) {
+    let mut keep_or_new_id = |id: (u64, u64)| {
+        let is_taken = |id: (u64, u64)| boards.contains_id(id) || taken_ids.contains(&id);
+        let id = if !keep_ids_on_import || is_taken(id) {
+            generate_id(is_taken)
+        } else {
+            id
+        };
+        taken_ids.insert(id);
+        id
+    };
+    board.id = keep_or_new_id(board.id);
+    for card in board.cards.get_mut_all_cards() {
+        card.id = keep_or_new_id(card.id);
+    }
+}
+
+/// Adds the boards as one undoable step, the current boards are removed first when
+/// replace_existing is set. The boards get new ids unless keep_ids_on_import is set, see
+/// assign_imported_ids
+pub fn add_imported_boards(
+    app: &mut App,
+    imported_boards: Vec<Board>,
+    source: &str,
+    replace_existing: bool,
+) {
+    let mut batch = Vec::new();
+    if replace_existing {
+        // Undo adds deleted boards back at the end in reverse batch order, pushing the last
+        // board first puts them back in their original order
+        for board in app.boards.get_boards().iter().rev() {
+            batch.push(ActionHistory::DeleteBoard(board.clone()));
+        }
+        app.boards.reset();
+        app.filtered_boards.reset();
+        app.state.filter_tags = None;
+        app.state.text_buffers.card_search.reset();
+        app.state.current_board_id = None;
+        app.state.current_card_id = None;
+    }
+    let removed_board_count = batch.len();
+    let mut taken_ids = HashSet::new();
+    let mut card_count = 0;
+    for mut board in imported_boards {
+        assign_imported_ids(
+            &mut board,
+            &app.boards,
+            &mut taken_ids,
+            app.config.keep_ids_on_import,
+        );
+        let base_name = board.name.clone();
+        let mut duplicate_count = 1;
+        while app
+            .boards
+            .get_boards()
+            .iter()
+            .any(|existing_board| existing_board.name == board.name)
+        {
+            duplicate_count += 1;
+            board.name = format!("{} ({})", base_name, duplicate_count);
+        }
+        card_count += board.cards.len();
+        app.boards.add_board(board.clone());
+        batch.push(ActionHistory::CreateBoard(board));
+    }
+    let board_count = batch.len() - removed_board_count;
+    app.action_history_manager
+        .new_action(ActionHistory::Batch(batch));
+    refresh_visible_boards_and_cards(app);
+    let message = if replace_existing {
+        format!(
+            "Replaced {} boards with {} boards and {} cards from {}",
+            removed_board_count, board_count, card_count, source
+        )
+    } else {
+        format!(
+            "Imported {} boards with {} cards from {}",
+            board_count, card_count, source
+        )
+    };
+    info!("{}", message);
+    app.send_info_toast(&message, None);
+}
+
+/// Folds fetched issues into the boards as one undoable step, see [`import_github_issues`]
+pub fn add_github_issues(app: &mut App, issues: &[GithubIssue], board_name: &str) -> GithubImport {
+    let date_time_format = app.config.date_time_format;
+    let import = import_github_issues(&mut app.boards, issues, board_name, date_time_format);
+    let mut batch = Vec::new();
+    if let Some(created_board) = &import.created_board {
+        batch.push(ActionHistory::CreateBoard(created_board.clone()));
+    }
+    for card in &import.added_cards {
+        batch.push(ActionHistory::CreateCard(
+            card.clone(),
+            import.target_board_id,
+        ));
+    }
+    for (old_card, new_card, board_id) in &import.updated_cards {
+        batch.push(ActionHistory::EditCard(
+            old_card.clone(),
+            new_card.clone(),
+            *board_id,
+        ));
+    }
+    if !batch.is_empty() {
+        app.action_history_manager
+            .new_action(ActionHistory::Batch(batch));
+    }
+    refresh_visible_boards_and_cards(app);
+    import
+}
+
+/// Fetches every open issue of `repo`, following GitHub's pagination. A short rate limit is
+/// waited out, a longer one fails the fetch with the time left until it resets
+pub async fn fetch_github_issues(
+    repo: &str,
+    token: Option<&str>,
+) -> Result<(Vec<GithubIssue>, Vec<GithubImportFailure>), String> {
+    let (owner, name) = parse_github_repo(repo)?;
+    let client = reqwest::Client::new();
+    let mut next_page_url = Some(format!(
+        "{}/repos/{}/{}/issues?state=open&per_page={}",
+        GITHUB_API_URL, owner, name, GITHUB_ISSUES_PER_PAGE
+    ));
+    let mut issues = Vec::new();
+    let mut failures = Vec::new();
+    while let Some(page_url) = next_page_url.take() {
+        let mut rate_limit_retries = 0;
+        let response = loop {
+            let mut request = client
+                .get(&page_url)
+                .header(
+                    "User-Agent",
+                    format!("rust-kanban/{}", env!("CARGO_PKG_VERSION")),
+                )
+                .header("Accept", "application/vnd.github+json")
+                .header("X-GitHub-Api-Version", "2022-11-28");
+            if let Some(token) = token {
+                request = request.header("Authorization", format!("Bearer {}", token));
+            }
+            let response = request
+                .send()
+                .await
+                .map_err(|e| format!("Could not reach GitHub: {}", e))?;
+            let status = response.status();
+            if status == StatusCode::FORBIDDEN || status == StatusCode::TOO_MANY_REQUESTS {
+                if let Some(wait) = get_github_rate_limit_wait(response.headers()) {
+                    if wait.as_secs() <= GITHUB_MAX_RATE_LIMIT_WAIT
+                        && rate_limit_retries < GITHUB_MAX_RATE_LIMIT_RETRIES
+                    {
+                        rate_limit_retries += 1;
+                        warn!(
+                            "GitHub rate limit reached, retrying in {} seconds",
+                            wait.as_secs()
+                        );
+                        tokio::time::sleep(wait).await;
+                        continue;
+                    }
+                    return Err(format!(
+                        "GitHub rate limit reached, it resets in {} minutes{}",
+                        wait.as_secs().div_ceil(60),
+                        if token.is_none() {
+                            ", a token raises the limit"
+                        } else {
+                            ""
+                        }
+                    ));
+                }
+            }
+            break response;
+        };
+        match response.status() {
+            StatusCode::OK => {}
+            StatusCode::UNAUTHORIZED => return Err("GitHub did not accept the token".to_string()),
+            StatusCode::NOT_FOUND => {
+                return Err(format!(
+                    "{}/{} was not found, private repositories need a token",
+                    owner, name
+                ))
+            }
+            status => return Err(format!("GitHub returned {}", status)),
+        }
+        next_page_url = get_github_next_page_url(response.headers());
+        let page = response
+            .text()
+            .await
+            .map_err(|e| format!("Could not read the response from GitHub: {}", e))?;
+        let (page_issues, page_failures) = parse_github_issues_page(&page)?;
+        debug!("Fetched {} issues from {}", page_issues.len(), page_url);
+        issues.extend(page_issues);
+        failures.extend(page_failures);
+    }
+    Ok((issues, failures))
+}
+
+// GitHub sends retry-after for secondary limits and the reset time for the primary one
+fn get_github_rate_limit_wait(headers: &reqwest::header::HeaderMap) -> Option<Duration> {
+    let get_number = |name: &str| {
+        headers
+            .get(name)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.trim().parse::<u64>().ok())
+    };
+    if let Some(retry_after) = get_number("retry-after") {
+        return Some(Duration::from_secs(retry_after));
+    }
+    if get_number("x-ratelimit-remaining") != Some(0) {
+        return None;
+    }
+    let reset_at = get_number("x-ratelimit-reset")?;
+    let now = chrono::Utc::now().timestamp().max(0) as u64;
+    Some(Duration::from_secs(reset_at.saturating_sub(now)))
+}
+
+// The link header looks like <url>; rel="next", <url>; rel="last"
+fn get_github_next_page_url(headers: &reqwest::header::HeaderMap) -> Option<String> {
+    let link = headers.get("link")?.to_str().ok()?;
+    link.split(',').find_map(|link_part| {
+        let (url, rel) = link_part.split_once(';')?;
+        rel.contains("rel=\"next\"").then(|| {
+            url.trim()
+                .trim_start_matches('<')
+                .trim_end_matches('>')
+                .to_string()
+        })
+    })
+}
+
+/// Ids of the boards the kanban view goes through, archived boards only while they are shown
+pub fn get_shown_board_ids(app: &App) -> Vec<(u64, u64)> {
+    let boards = if app.filtered_boards.is_empty() {
+        &app.boards
+    } else {
+        &app.filtered_boards
+    };
+    boards
+        .get_boards()
+        .iter()
+        .filter(|board| app.state.show_archived_boards || !board.archived)
+        .map(|board| board.id)
+        .collect()
+}
+
 pub fn refresh_visible_boards_and_cards(app: &mut App) {
+    // Cards created on or moved to a board with its own statuses are put in one of them here,
+    // auto sorted boards are sorted here too so every change keeps them in priority order
+    for boards in [&mut app.boards, &mut app.filtered_boards] {
+        for board in boards.get_mut_boards() {
+            board.fix_card_statuses();
+            board.apply_auto_sort();
+        }
+    }
     let mut visible_boards_and_cards: LinkedHashMap<(u64, u64), Vec<(u64, u64)>> =
         LinkedHashMap::new();
     let boards = if app.filtered_boards.is_empty() {
-        app.boards.get_boards()
+        &app.boards
     } else {
-        app.filtered_boards.get_boards()
+        &app.filtered_boards
     };
-    for (i, board) in boards.iter().enumerate() {
-        if (i) as u16 == app.config.no_of_boards_to_show {
-            break;
-        }
-        let mut visible_cards: Vec<(u64, u64)> = Vec::new();
-        if board.cards.len() > app.config.no_of_cards_to_show.into() {
-            for card in board
-                .cards
-                .get_all_cards()
-                .iter()
-                .take(app.config.no_of_cards_to_show.into())
-            {
-                visible_cards.push(card.id);
-            }
-        } else {
-            for card in board.cards.get_all_cards() {
-                visible_cards.push(card.id);
-            }
-        }
+    let visible_board_ids = board_window(
+        &get_shown_board_ids(app),
+        None,
+        app.config.no_of_boards_to_show.into(),
+    );
+    for board_id in visible_board_ids {
+        let Some(board) = boards.get_board_with_id(board_id) else {
+            continue;
+        };
+        let displayable_cards = app.get_displayable_cards(board);
+        let visible_cards = card_window(
+            &displayable_cards.get_all_card_ids(),
+            None,
+            app.get_no_of_cards_to_show().into(),
+        );
 
         let mut visible_board: LinkedHashMap<(u64, u64), Vec<(u64, u64)>> = LinkedHashMap::new();
         visible_board.insert(board.id, visible_cards);
@@ -1466,20 +2154,84 @@ pub fn refresh_visible_boards_and_cards(app: &mut App) {
     }
 }
 
-pub fn make_file_system_safe_name(name: &str) -> String {
-    let mut safe_name = name.to_string();
-    let unsafe_chars = vec!["/", "\\", ":", "*", "?", "\"", "<", ">", "|", " "];
-    for unsafe_char in unsafe_chars {
-        safe_name = safe_name.replace(unsafe_char, "");
+pub async fn auto_save(app: &mut App<'_>) -> Result<(), String> {
+    if !app.may_save_automatically() {
+        app.record_io_completion(
+            IoCompletionKind::AutoSave,
+            IoOutcome::Skipped,
+            "Safe mode, no save was loaded",
+        );
+        return Ok(());
+    }
+    if !save_required(app) {
+        app.mark_boards_saved();
+        app.record_io_completion(
+            IoCompletionKind::AutoSave,
+            IoOutcome::Skipped,
+            "No changes since the last save",
+        );
+        return Ok(());
+    }
+    match save_kanban_state_locally(
+        app.boards.get_boards().to_vec(),
+        &app.config,
+        app.state.include_notes_in_exports,
+    ) {
+        Ok(file_path) => {
+            app.mark_boards_saved();
+            let bytes_written = std::fs::metadata(&file_path)
+                .map(|metadata| metadata.len())
+                .unwrap_or(0);
+            app.record_io_completion(
+                IoCompletionKind::AutoSave,
+                IoOutcome::Succeeded,
+                &format!("Wrote {} bytes to {}", bytes_written, file_path),
+            );
+            app.send_info_toast(
+                "Auto-saved",
+                Some(Duration::from_secs(AUTO_SAVE_TOAST_DURATION)),
+            );
+            prune_old_saves(app);
+            Ok(())
+        }
+        Err(err) => {
+            app.record_io_completion(IoCompletionKind::AutoSave, IoOutcome::Failed, &err);
+            Err(err)
+        }
     }
-    safe_name
 }
 
-pub async fn auto_save(app: &mut App<'_>) -> Result<(), String> {
-    if save_required(app) {
-        save_kanban_state_locally(app.boards.get_boards().to_vec(), &app.config)
-    } else {
-        Ok(())
+/// Deletes the save files past the configured number to keep after a save, the result goes to the
+/// notifications pane as the cleanup runs in the background
+fn prune_old_saves(app: &mut App) {
+    if app.config.save_files_to_keep == 0 {
+        return;
+    }
+    match prune_old_save_files(&app.config) {
+        Ok(pruned_files) if pruned_files.is_empty() => app.record_io_completion(
+            IoCompletionKind::SaveCleanup,
+            IoOutcome::Skipped,
+            &format!(
+                "No more than {} save files to keep",
+                app.config.save_files_to_keep
+            ),
+        ),
+        Ok(pruned_files) => {
+            info!("Pruned old save files: {}", pruned_files.join(", "));
+            app.record_io_completion(
+                IoCompletionKind::SaveCleanup,
+                IoOutcome::Succeeded,
+                &format!(
+                    "Pruned {} old save files: {}",
+                    pruned_files.len(),
+                    pruned_files.join(", ")
+                ),
+            );
+        }
+        Err(err) => {
+            error!("{}", err);
+            app.record_io_completion(IoCompletionKind::SaveCleanup, IoOutcome::Failed, &err);
+        }
     }
 }
 
@@ -1504,6 +2256,9 @@ pub struct CloudData {
     pub board_data: String,
     pub nonce: String,
     pub save_id: usize,
+    /// Set for partial uploads, board_data then holds a delta on top of this save
+    #[serde(default)]
+    pub base_save_id: Option<usize>,
 }
 
 enum PasswordStatus {
@@ -1539,9 +2294,82 @@ fn check_for_safe_password(check_password: &str) -> PasswordStatus {
     password_status
 }
 
-fn encrypt_save(boards: &Boards, key: &[u8]) -> Result<(String, String), String> {
+/// Posts the boards or the delta of a partial sync, returning the status and the body
+async fn upload_cloud_save(
+    plan: &SyncPlan,
+    boards: &[Board],
+    key: &[u8],
+    auth_token: &str,
+    user_id: &str,
+    save_id: usize,
+) -> Result<(StatusCode, String), String> {
+    let (encrypt_result, base_save_id) = match plan {
+        SyncPlan::Partial {
+            base_save_id,
+            delta,
+        } => (encrypt_save(delta, key), Some(*base_save_id)),
+        _ => (encrypt_save(&Boards::from(boards.to_vec()), key), None),
+    };
+    let (encrypted_board_data, nonce) = encrypt_result?;
+    let mut body = json!(
+        {
+            "user_id": user_id,
+            "board_data": encrypted_board_data,
+            "save_id": save_id,
+            "nonce": nonce
+        }
+    );
+    if let Some(base_save_id) = base_save_id {
+        body["base_save_id"] = json!(base_save_id);
+    }
+    let response = reqwest::Client::new()
+        .post(format!("{}/rest/v1/user_data", SUPABASE_URL))
+        .header("apikey", SUPABASE_ANON_KEY)
+        .header("Content-Type", "application/json")
+        .header("Authorization", format!("Bearer {}", auth_token))
+        .header("Prefer", "return=representation")
+        .body(body.to_string())
+        .send()
+        .await
+        .map_err(|e| e.to_string())?;
+    let status = response.status();
+    let body = response.text().await.unwrap_or_default();
+    Ok((status, body))
+}
+
+fn cloud_save_chain_links(cloud_saves: &[CloudData]) -> Vec<(usize, Option<usize>)> {
+    cloud_saves
+        .iter()
+        .map(|save| (save.save_id, save.base_save_id))
+        .collect()
+}
+
+/// Decrypts the saves of a chain from [`resolve_cloud_save_chain`] and applies the deltas in order
+fn decrypt_cloud_save_chain(
+    cloud_saves: &[CloudData],
+    chain: &[usize],
+    key: &[u8],
+) -> Result<Boards, String> {
+    let mut boards = vec![];
+    for save_id in chain {
+        let save = cloud_saves
+            .iter()
+            .find(|save| save.save_id == *save_id)
+            .ok_or_else(|| format!("cloud save {} was deleted", save_id))?;
+        if save.base_save_id.is_some() {
+            let delta: BoardsDelta = decrypt_save(save.board_data.clone(), key, &save.nonce)?;
+            boards = apply_delta(boards, &delta);
+        } else {
+            let full_save: Boards = decrypt_save(save.board_data.clone(), key, &save.nonce)?;
+            boards = full_save.get_boards().clone();
+        }
+    }
+    Ok(Boards::from(boards))
+}
+
+fn encrypt_save(boards: &impl Serialize, key: &[u8]) -> Result<(String, String), String> {
     let base64_engine = base64::engine::general_purpose::URL_SAFE_NO_PAD;
-    let boards_json = serde_json::to_string(&boards);
+    let boards_json = serde_json::to_string(boards);
     if boards_json.is_err() {
         return Err("Error serializing boards".to_string());
     }
@@ -1560,11 +2388,11 @@ fn encrypt_save(boards: &Boards, key: &[u8]) -> Result<(String, String), String>
     Ok((encoded_boards, nonce_encoded))
 }
 
-fn decrypt_save(
+fn decrypt_save<T: DeserializeOwned>(
     encrypted_boards: String,
     key: &[u8],
     encoded_nonce: &str,
-) -> Result<Boards, String> {
+) -> Result<T, String> {
     let base64_engine = base64::engine::general_purpose::URL_SAFE_NO_PAD;
     let encrypted_boards = base64_engine.decode(encrypted_boards);
     if encrypted_boards.is_err() {
@@ -1601,7 +2429,7 @@ pub fn save_user_encryption_key(key: &[u8]) -> Result<String> {
     let key = base64_engine.encode(key);
     let mut config_dir = get_config_dir().unwrap();
     config_dir.push(ENCRYPTION_KEY_FILE_NAME);
-    let file_creation_status = std::fs::write(&config_dir, key);
+    let file_creation_status = write_file_atomically(&config_dir, key.as_bytes());
     if let Err(e) = file_creation_status {
         Err(anyhow!(e))
     } else {
@@ -2119,7 +2947,8 @@ async fn save_refresh_token_to_disk(
         REFRESH_TOKEN_SEPARATOR,
         encoded_email_id
     );
-    let file_creation_status = std::fs::write(&refresh_token_path, refresh_token_data);
+    let file_creation_status =
+        write_file_atomically(&refresh_token_path, refresh_token_data.as_bytes());
     if file_creation_status.is_err() {
         return Err(anyhow!("Error creating refresh token file"));
     }
@@ -2347,3 +3176,93 @@ async fn test_refresh_token_on_disk(
     };
     Ok(user_data)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::kanban::Card;
+    use crate::model::{date_time_format::DateTimeFormat, save_file::save};
+
+    fn sample_boards() -> Vec<Board> {
+        let mut todo = Board::new("Todo", "");
+        todo.cards.add_card(Card::default());
+        todo.cards.add_card(Card::default());
+        let mut done = Board::new("Done", "");
+        done.cards.add_card(Card::default());
+        vec![todo, done]
+    }
+
+    fn all_ids(boards: &[Board]) -> Vec<(u64, u64)> {
+        boards
+            .iter()
+            .flat_map(|board| {
+                std::iter::once(board.id)
+                    .chain(board.cards.get_all_cards().iter().map(|card| card.id))
+            })
+            .collect()
+    }
+
+    /// Writes the boards the way the JSON export does and reads them back the way the import does
+    fn export_and_parse(boards: &[Board], name: &str) -> Vec<Board> {
+        let path =
+            env::temp_dir().join(format!("rust_kanban_{}_{}.json", name, std::process::id()));
+        save(boards, &path, DateTimeFormat::default()).unwrap();
+        let parsed_boards = parse_save_boards(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        std::fs::remove_file(&path).ok();
+        parsed_boards
+    }
+
+    #[test]
+    fn imported_boards_get_new_ids_by_default() {
+        let boards = sample_boards();
+        let exported_ids = all_ids(&boards);
+        let (mut app, _io_rx) = App::for_tests();
+        assert!(!app.config.keep_ids_on_import);
+
+        add_imported_boards(
+            &mut app,
+            export_and_parse(&boards, "id_new_by_default"),
+            "test",
+            true,
+        );
+        let imported_ids = all_ids(app.boards.get_boards());
+        assert_eq!(imported_ids.len(), exported_ids.len());
+        assert!(imported_ids.iter().all(|id| !exported_ids.contains(id)));
+    }
+
+    #[test]
+    fn an_export_import_export_round_trip_keeps_every_id() {
+        let boards = sample_boards();
+        let exported_ids = all_ids(&boards);
+        let (mut app, _io_rx) = App::for_tests();
+        app.config.keep_ids_on_import = true;
+
+        add_imported_boards(
+            &mut app,
+            export_and_parse(&boards, "id_round_trip_first"),
+            "test",
+            true,
+        );
+        assert_eq!(all_ids(app.boards.get_boards()), exported_ids);
+        let reexported_boards = export_and_parse(app.boards.get_boards(), "id_round_trip_second");
+        assert_eq!(all_ids(&reexported_boards), exported_ids);
+    }
+
+    #[test]
+    fn importing_boards_that_are_already_loaded_gives_the_copies_new_ids() {
+        let boards = sample_boards();
+        let (mut app, _io_rx) = App::for_tests();
+        app.config.keep_ids_on_import = true;
+        app.boards.set_boards(Boards::from(boards.clone()));
+
+        add_imported_boards(&mut app, boards.clone(), "test", false);
+        let ids = all_ids(app.boards.get_boards());
+        let (loaded_ids, imported_ids) = ids.split_at(all_ids(&boards).len());
+        assert_eq!(loaded_ids, all_ids(&boards));
+        assert!(imported_ids.iter().all(|id| !loaded_ids.contains(id)));
+        assert_eq!(
+            imported_ids.iter().collect::<HashSet<_>>().len(),
+            imported_ids.len()
+        );
+    }
+}
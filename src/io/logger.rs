@@ -1,5 +1,8 @@
 // This logger implementation is highly inspired by the logger implementation in https://github.com/gin66/tui-logger
 
+//! Records are let through by the default level unless a per module filter covers their target.
+//! Level changes are written to the log themselves, whatever the new level is
+
 use chrono::{DateTime, Local};
 use log::{Level, LevelFilter, Log, Metadata, Record};
 use parking_lot::Mutex;
@@ -10,8 +13,20 @@ use std::{
         HashMap,
     },
     iter, mem,
+    str::FromStr,
 };
 
+/// Levels offered when changing the log level at runtime, most to least severe
+pub const SELECTABLE_LOG_LEVELS: [LevelFilter; 4] = [
+    LevelFilter::Error,
+    LevelFilter::Warn,
+    LevelFilter::Info,
+    LevelFilter::Debug,
+];
+
+/// Start of every log target in this crate, left out when naming modules in the log filters
+const LOG_TARGET_PREFIX: &str = "rust_kanban::";
+
 #[derive(Clone, Debug)]
 pub struct CircularBuffer<T> {
     pub buffer: Vec<T>,
@@ -36,6 +51,8 @@ pub struct ExtLogRecord {
 struct HotSelect {
     hash_table: HashMap<u64, LevelFilter>,
     default: LevelFilter,
+    /// Module and the level for everything in it, see [`parse_log_filters`]
+    module_filters: Vec<(String, LevelFilter)>,
 }
 
 #[derive(Debug)]
@@ -211,9 +228,73 @@ pub fn move_events() {
     RUST_KANBAN_LOGGER.move_events();
 }
 
+/// Can be called at any time, a change from the current level is recorded in the log
 pub fn set_default_level(level_filter: LevelFilter) {
-    RUST_KANBAN_LOGGER.hot_select.lock().default = level_filter;
-    RUST_KANBAN_LOGGER.inner.lock().default = level_filter;
+    RUST_KANBAN_LOGGER.set_default_level(level_filter);
+}
+
+pub fn get_default_level() -> LevelFilter {
+    RUST_KANBAN_LOGGER.hot_select.lock().default
+}
+
+/// Replaces the per module filters, a change is recorded in the log
+pub fn set_log_filters(module_filters: &[(String, LevelFilter)]) {
+    if let Some(max_level) = module_filters.iter().map(|(_, level)| *level).max() {
+        if max_level > log::max_level() {
+            log::set_max_level(max_level);
+        }
+    }
+    RUST_KANBAN_LOGGER.set_log_filters(module_filters);
+}
+
+/// Parses comma separated "module=level" pairs like "io=debug,ui=warn". Modules are paths in this
+/// crate with or without the leading "rust_kanban::", an empty string is no filters
+pub fn parse_log_filters(filters: &str) -> Result<Vec<(String, LevelFilter)>, String> {
+    filters
+        .split(',')
+        .map(str::trim)
+        .filter(|filter| !filter.is_empty())
+        .map(|filter| {
+            let (module, level) = filter
+                .split_once('=')
+                .ok_or_else(|| format!("Expected module=level, got \"{}\"", filter))?;
+            let module = module.trim();
+            let module = module.strip_prefix(LOG_TARGET_PREFIX).unwrap_or(module);
+            if module.is_empty() {
+                return Err(format!("Missing module in \"{}\"", filter));
+            }
+            let level = LevelFilter::from_str(level.trim())
+                .map_err(|_| format!("Invalid log level \"{}\"", level.trim()))?;
+            Ok((module.to_string(), level))
+        })
+        .collect()
+}
+
+pub fn log_filters_to_string(module_filters: &[(String, LevelFilter)]) -> String {
+    module_filters
+        .iter()
+        .map(|(module, level)| format!("{}={}", module, level.as_str().to_lowercase()))
+        .collect::<Vec<String>>()
+        .join(",")
+}
+
+/// The level records from `target` are let through at. The filter for the most specific module
+/// containing the target wins, `default` applies when none does
+pub fn level_for_target(
+    target: &str,
+    default: LevelFilter,
+    module_filters: &[(String, LevelFilter)],
+) -> LevelFilter {
+    let target = target.strip_prefix(LOG_TARGET_PREFIX).unwrap_or(target);
+    module_filters
+        .iter()
+        .filter(|(module, _)| {
+            target
+                .strip_prefix(module.as_str())
+                .is_some_and(|rest| rest.is_empty() || rest.starts_with("::"))
+        })
+        .max_by_key(|(module, _)| module.len())
+        .map_or(default, |(_, level)| *level)
 }
 
 pub fn set_level_for_target(target: &str, level_filter: LevelFilter) {
@@ -228,12 +309,73 @@ pub fn set_level_for_target(target: &str, level_filter: LevelFilter) {
 }
 
 impl RustKanbanLogger {
+    fn new() -> RustKanbanLogger {
+        let hs = HotSelect {
+            hash_table: HashMap::with_capacity(1000),
+            default: LevelFilter::Info,
+            module_filters: Vec::new(),
+        };
+        let hl = HotLog {
+            events: CircularBuffer::new(1000),
+            state: TableState::default(),
+        };
+        let tli = RustKanbanLoggerInner {
+            hot_depth: 1000,
+            events: CircularBuffer::new(10000),
+            total_events: 0,
+            default: LevelFilter::Info,
+            targets: LevelConfig::new(),
+        };
+        RustKanbanLogger {
+            hot_select: Mutex::new(hs),
+            hot_log: Mutex::new(hl),
+            inner: Mutex::new(tli),
+        }
+    }
+
+    fn set_default_level(&self, level_filter: LevelFilter) {
+        let previous_level = mem::replace(&mut self.hot_select.lock().default, level_filter);
+        self.inner.lock().default = level_filter;
+        if previous_level != level_filter {
+            self.record_level_change(format!(
+                "Log level changed from {} to {}",
+                previous_level, level_filter
+            ));
+        }
+    }
+
+    fn set_log_filters(&self, module_filters: &[(String, LevelFilter)]) {
+        let previous_filters = mem::replace(
+            &mut self.hot_select.lock().module_filters,
+            module_filters.to_vec(),
+        );
+        if previous_filters != module_filters {
+            self.record_level_change(format!(
+                "Log filters changed from \"{}\" to \"{}\"",
+                log_filters_to_string(&previous_filters),
+                log_filters_to_string(module_filters)
+            ));
+        }
+    }
+
     fn raw_log(&self, record: &Record) {
-        let log_entry = ExtLogRecord {
+        self.push_event(ExtLogRecord {
             timestamp: chrono::Local::now(),
             level: record.level(),
             msg: format!("{}", record.args()),
-        };
+        });
+    }
+
+    /// Skips the level check so the change shows up in a log captured at any level
+    fn record_level_change(&self, msg: String) {
+        self.push_event(ExtLogRecord {
+            timestamp: chrono::Local::now(),
+            level: Level::Info,
+            msg,
+        });
+    }
+
+    fn push_event(&self, log_entry: ExtLogRecord) {
         let mut hot_log = self.hot_log.lock();
         hot_log.events.push(log_entry);
         let last_index = hot_log.events.len() - 1;
@@ -248,7 +390,7 @@ impl Log for RustKanbanLogger {
         if let Some(&level_filter) = hs.hash_table.get(&h) {
             metadata.level() <= level_filter
         } else {
-            metadata.level() <= hs.default
+            metadata.level() <= level_for_target(metadata.target(), hs.default, &hs.module_filters)
         }
     }
 
@@ -262,28 +404,7 @@ impl Log for RustKanbanLogger {
 }
 
 lazy_static! {
-    pub static ref RUST_KANBAN_LOGGER: RustKanbanLogger = {
-        let hs = HotSelect {
-            hash_table: HashMap::with_capacity(1000),
-            default: LevelFilter::Info,
-        };
-        let hl = HotLog {
-            events: CircularBuffer::new(1000),
-            state: TableState::default(),
-        };
-        let tli = RustKanbanLoggerInner {
-            hot_depth: 1000,
-            events: CircularBuffer::new(10000),
-            total_events: 0,
-            default: LevelFilter::Info,
-            targets: LevelConfig::new(),
-        };
-        RustKanbanLogger {
-            hot_select: Mutex::new(hs),
-            hot_log: Mutex::new(hl),
-            inner: Mutex::new(tli),
-        }
-    };
+    pub static ref RUST_KANBAN_LOGGER: RustKanbanLogger = RustKanbanLogger::new();
 }
 
 pub fn init_logger(max_level: LevelFilter) -> Result<(), log::SetLoggerError> {
@@ -303,3 +424,94 @@ pub fn get_selected_index() -> usize {
         .selected()
         .unwrap_or(0)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn enabled(logger: &RustKanbanLogger, level: Level, target: &str) -> bool {
+        logger.enabled(&Metadata::builder().level(level).target(target).build())
+    }
+
+    #[test]
+    fn log_filters_parse_with_or_without_the_crate_prefix() {
+        assert_eq!(
+            parse_log_filters(" io=debug, rust_kanban::ui = WARN ,").unwrap(),
+            [
+                ("io".to_string(), LevelFilter::Debug),
+                ("ui".to_string(), LevelFilter::Warn),
+            ]
+        );
+        assert!(parse_log_filters("").unwrap().is_empty());
+        assert!(parse_log_filters("io").is_err());
+        assert!(parse_log_filters("io=loud").is_err());
+        assert!(parse_log_filters("=debug").is_err());
+    }
+
+    #[test]
+    fn the_most_specific_whole_module_filter_wins() {
+        let filters = [
+            ("io".to_string(), LevelFilter::Debug),
+            ("io::logger".to_string(), LevelFilter::Off),
+        ];
+        let level = |target| level_for_target(target, LevelFilter::Info, &filters);
+        assert_eq!(level("rust_kanban::io"), LevelFilter::Debug);
+        assert_eq!(level("rust_kanban::io::io_handler"), LevelFilter::Debug);
+        assert_eq!(level("rust_kanban::io::logger"), LevelFilter::Off);
+        // Only whole module names match
+        assert_eq!(level("rust_kanban::io_extra"), LevelFilter::Info);
+        assert_eq!(level("rust_kanban::app"), LevelFilter::Info);
+    }
+
+    #[test]
+    fn records_are_gated_by_the_default_level_unless_a_filter_covers_them() {
+        let logger = RustKanbanLogger::new();
+        logger.set_default_level(LevelFilter::Warn);
+        assert!(!enabled(
+            &logger,
+            Level::Info,
+            "rust_kanban::app::app_helper"
+        ));
+        assert!(enabled(
+            &logger,
+            Level::Warn,
+            "rust_kanban::app::app_helper"
+        ));
+
+        logger.set_log_filters(&[
+            ("io".to_string(), LevelFilter::Debug),
+            ("io::logger".to_string(), LevelFilter::Error),
+        ]);
+        assert!(enabled(
+            &logger,
+            Level::Debug,
+            "rust_kanban::io::io_handler"
+        ));
+        assert!(!enabled(&logger, Level::Warn, "rust_kanban::io::logger"));
+        assert!(!enabled(&logger, Level::Info, "rust_kanban::ui::rendering"));
+    }
+
+    #[test]
+    fn level_changes_are_logged_whatever_the_new_level() {
+        let logger = RustKanbanLogger::new();
+        logger.set_default_level(LevelFilter::Error);
+        logger.set_log_filters(&[("ui".to_string(), LevelFilter::Off)]);
+        // Setting the same values again records nothing
+        logger.set_default_level(LevelFilter::Error);
+        logger.set_log_filters(&[("ui".to_string(), LevelFilter::Off)]);
+        let messages = logger
+            .hot_log
+            .lock()
+            .events
+            .iter()
+            .map(|record| record.msg.clone())
+            .collect::<Vec<String>>();
+        assert_eq!(
+            messages,
+            [
+                "Log level changed from INFO to ERROR",
+                "Log filters changed from \"\" to \"ui=off\"",
+            ]
+        );
+    }
+}
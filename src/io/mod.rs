@@ -1,13 +1,43 @@
+use chrono::NaiveDateTime;
+use event_log::BoardEvent;
+use log::debug;
+use std::{fmt, path::PathBuf};
+use tokio::sync::mpsc::Receiver;
+
+pub mod clipboard;
 pub mod data_handler;
+pub mod desktop_notification;
+pub mod event_log;
+pub mod interop;
 pub mod io_handler;
 pub mod logger;
 
+pub use crate::model::markdown_mirror;
+
 #[derive(Debug, Clone)]
 pub enum IoEvent {
+    AppendBoardEvents(Vec<BoardEvent>),
     AutoSave,
     DeleteCloudSave,
     DeleteLocalSave,
+    /// Writes the board with the id and its cards to a standalone JSON file
+    ExportBoardToJson((u64, u64), PathBuf),
+    /// Writes every card to a CSV file, one row per card
+    ExportToCsv(PathBuf),
+    /// Writes every board to a readable markdown file
+    ExportToMarkdown(PathBuf),
+    /// Writes only the boards and cards the kanban view shows, with the filters in the header
+    ExportVisibleToMarkdown(PathBuf),
     GetCloudData,
+    /// Adds the board of a file written by ExportBoardToJson under fresh ids
+    ImportBoardFromJson(PathBuf),
+    /// Reads the boards of a save file and asks whether to merge them in or replace all boards
+    ImportFromJson(PathBuf),
+    /// Fetches the open issues of a GitHub repository into cards, with the repository as
+    /// owner/name, the environment variable holding the token and the board for new cards
+    ImportGithubIssues(String, String, String),
+    /// Adds the lists of a Trello board export as new boards
+    ImportTrelloJson(PathBuf),
     Initialize,
     LoadCloudPreview,
     LoadLocalPreview,
@@ -21,4 +51,197 @@ pub enum IoEvent {
     SendResetPasswordEmail(String),
     SignUp(String, String, String),
     SyncLocalData,
+    /// Uploads the boards even when nothing changed since the last sync
+    ForceSyncLocalData,
+}
+
+impl IoEvent {
+    /// Events that carry no data and only depend on the latest app state, running one of them
+    /// twice in a row does nothing the second run would not do anyway
+    fn is_coalescable(&self) -> bool {
+        matches!(
+            self,
+            IoEvent::AutoSave
+                | IoEvent::GetCloudData
+                | IoEvent::LoadCloudPreview
+                | IoEvent::LoadLocalPreview
+                | IoEvent::ResetVisibleBoardsandCards
+                | IoEvent::SaveLocalData
+                | IoEvent::SyncLocalData
+        )
+    }
+}
+
+/// Collapses runs of the same coalescable event to the latest one, everything else is kept in
+/// the order it was dispatched
+pub fn coalesce_io_events(io_events: Vec<IoEvent>) -> Vec<IoEvent> {
+    let mut coalesced_events: Vec<IoEvent> = Vec::with_capacity(io_events.len());
+    for io_event in io_events {
+        if let Some(last_event) = coalesced_events.last() {
+            if io_event.is_coalescable()
+                && std::mem::discriminant(last_event) == std::mem::discriminant(&io_event)
+            {
+                debug!("Coalesced repeated {:?}", io_event);
+                coalesced_events.pop();
+            }
+        }
+        coalesced_events.push(io_event);
+    }
+    coalesced_events
+}
+
+/// Takes every event queued behind `first_event` without waiting for more and coalesces them, a
+/// burst is whatever piled up while the previous events ran
+pub fn take_io_event_burst(first_event: IoEvent, io_rx: &mut Receiver<IoEvent>) -> Vec<IoEvent> {
+    let mut io_events = vec![first_event];
+    while let Ok(io_event) = io_rx.try_recv() {
+        io_events.push(io_event);
+    }
+    coalesce_io_events(io_events)
+}
+
+/// Background operations whose results are kept in the notifications pane
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IoCompletionKind {
+    AutoArchive,
+    AutoSave,
+    CloudSync,
+    MirrorSync,
+    SaveCleanup,
+}
+
+impl fmt::Display for IoCompletionKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            IoCompletionKind::AutoArchive => write!(f, "Auto Archive"),
+            IoCompletionKind::AutoSave => write!(f, "Auto Save"),
+            IoCompletionKind::CloudSync => write!(f, "Cloud Sync"),
+            IoCompletionKind::MirrorSync => write!(f, "Mirror Sync"),
+            IoCompletionKind::SaveCleanup => write!(f, "Save Cleanup"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IoOutcome {
+    Succeeded,
+    Skipped,
+    Failed,
+}
+
+impl fmt::Display for IoOutcome {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            IoOutcome::Succeeded => write!(f, "Succeeded"),
+            IoOutcome::Skipped => write!(f, "Skipped"),
+            IoOutcome::Failed => write!(f, "Failed"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct IoCompletion {
+    pub kind: IoCompletionKind,
+    pub outcome: IoOutcome,
+    pub details: String,
+    pub completed_at: NaiveDateTime,
+}
+
+impl IoCompletion {
+    pub fn new(kind: IoCompletionKind, outcome: IoOutcome, details: &str) -> Self {
+        Self {
+            kind,
+            outcome,
+            details: details.to_string(),
+            completed_at: chrono::Local::now().naive_local(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::sync::mpsc::{channel, Sender};
+
+    fn event_names(io_events: &[IoEvent]) -> Vec<String> {
+        io_events
+            .iter()
+            .map(|io_event| format!("{:?}", io_event))
+            .collect()
+    }
+
+    /// Receives the first queued event and the burst behind it, like the IO handler loop does
+    fn next_burst(io_rx: &mut Receiver<IoEvent>) -> Vec<IoEvent> {
+        let first_event = io_rx.try_recv().unwrap();
+        take_io_event_burst(first_event, io_rx)
+    }
+
+    fn send_all(io_tx: &Sender<IoEvent>, io_events: Vec<IoEvent>) {
+        for io_event in io_events {
+            io_tx.try_send(io_event).unwrap();
+        }
+    }
+
+    #[test]
+    fn a_burst_of_the_same_event_runs_once() {
+        let (io_tx, mut io_rx) = channel(16);
+        send_all(&io_tx, vec![IoEvent::LoadLocalPreview; 10]);
+        assert_eq!(
+            event_names(&next_burst(&mut io_rx)),
+            vec!["LoadLocalPreview"]
+        );
+        assert!(io_rx.try_recv().is_err());
+    }
+
+    #[test]
+    fn events_in_separate_bursts_are_not_coalesced() {
+        let (io_tx, mut io_rx) = channel(16);
+        send_all(&io_tx, vec![IoEvent::AutoSave; 3]);
+        assert_eq!(event_names(&next_burst(&mut io_rx)), vec!["AutoSave"]);
+        send_all(&io_tx, vec![IoEvent::AutoSave; 3]);
+        assert_eq!(event_names(&next_burst(&mut io_rx)), vec!["AutoSave"]);
+    }
+
+    #[test]
+    fn only_back_to_back_repeats_are_coalesced() {
+        let io_events = vec![
+            IoEvent::SaveLocalData,
+            IoEvent::SaveLocalData,
+            IoEvent::LoadLocalPreview,
+            IoEvent::SaveLocalData,
+            IoEvent::SyncLocalData,
+            IoEvent::SyncLocalData,
+        ];
+        assert_eq!(
+            event_names(&coalesce_io_events(io_events)),
+            vec![
+                "SaveLocalData",
+                "LoadLocalPreview",
+                "SaveLocalData",
+                "SyncLocalData"
+            ]
+        );
+    }
+
+    #[test]
+    fn events_with_data_are_never_coalesced() {
+        let io_events = vec![
+            IoEvent::ExportToCsv(PathBuf::from("a.csv")),
+            IoEvent::ExportToCsv(PathBuf::from("b.csv")),
+            IoEvent::DeleteLocalSave,
+            IoEvent::DeleteLocalSave,
+            IoEvent::ForceSyncLocalData,
+            IoEvent::ForceSyncLocalData,
+        ];
+        assert_eq!(coalesce_io_events(io_events.clone()).len(), io_events.len());
+    }
+
+    #[test]
+    fn a_lone_event_is_kept() {
+        assert!(coalesce_io_events(vec![]).is_empty());
+        assert_eq!(
+            event_names(&coalesce_io_events(vec![IoEvent::Initialize])),
+            vec!["Initialize"]
+        );
+    }
 }
@@ -1,9 +1,20 @@
+#[cfg(feature = "tui")]
 pub mod app;
+#[cfg(feature = "tui")]
+pub mod changelog;
 pub mod constants;
+#[cfg(feature = "tui")]
+pub mod i18n;
+#[cfg(feature = "tui")]
 pub mod inputs;
+#[cfg(feature = "tui")]
 pub mod io;
+pub mod model;
+#[cfg(feature = "tui")]
 pub mod ui;
+#[cfg(feature = "tui")]
 pub mod util;
 
+#[cfg(feature = "tui")]
 #[macro_use]
 extern crate lazy_static;
@@ -1,15 +1,24 @@
-use clap::Parser;
-use crossterm::{event::DisableMouseCapture, execute, terminal};
+use clap::{Parser, Subcommand, ValueEnum};
+use crossterm::{
+    event::{DisableBracketedPaste, DisableMouseCapture},
+    execute, terminal,
+};
 use eyre::Result;
 use log::LevelFilter;
 use ratatui::{backend::CrosstermBackend, Terminal};
 use rust_kanban::{
-    app::App,
-    constants::APP_TITLE,
-    io::{io_handler::IoAsyncHandler, logger, IoEvent},
-    util::{gen_new_key_main, print_error, reset_app_main, start_ui},
+    app::{card_filter::CardFilter, kanban::CardPriority, App},
+    constants::{
+        APP_TITLE, GITHUB_DEFAULT_BOARD_NAME, GITHUB_DEFAULT_TOKEN_ENV, IO_EVENT_QUEUE_SIZE,
+    },
+    io::{io_handler::IoAsyncHandler, logger, take_io_event_burst, IoEvent},
+    util::{
+        export_taskwarrior_main, gen_new_key_main, import_github_main, import_taskwarrior_main,
+        print_error, print_safe_mode_hint_after_crash, profile_startup_main, reset_app_main,
+        start_ui, write_panic_report,
+    },
 };
-use std::{io::stdout, sync::Arc};
+use std::{io::stdout, path::PathBuf, sync::Arc};
 
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None)]
@@ -26,6 +35,83 @@ struct CliArgs {
     encryption_key: Option<String>,
     #[arg(short, long, default_value = "false")]
     debug_mode: bool,
+    /// Time each startup phase, print the results and exit without starting the UI
+    #[arg(long, default_value = "false")]
+    profile_startup: bool,
+    /// Start with the default config and built in themes, without loading a save or writing the config.
+    /// Nothing is saved automatically until a save is loaded by hand
+    #[arg(long, default_value = "false")]
+    safe_mode: bool,
+    /// Include board notes in the markdown mirror
+    #[arg(long, default_value = "false")]
+    with_notes: bool,
+    #[command(subcommand)]
+    command: Option<CliCommand>,
+}
+
+#[derive(Subcommand, Debug)]
+enum CliCommand {
+    /// Import the open issues of a GitHub repository as cards into the latest save and exit
+    ImportGithub {
+        /// The repository as owner/name
+        #[arg(long)]
+        repo: String,
+        /// Environment variable holding the token, public repositories can be read without one
+        #[arg(long, default_value = GITHUB_DEFAULT_TOKEN_ENV)]
+        token_env: String,
+        /// Board new cards go to, made when no board has this name
+        #[arg(long, default_value = GITHUB_DEFAULT_BOARD_NAME)]
+        board: String,
+    },
+    /// Export the cards of the latest save for another tool and exit
+    Export {
+        #[arg(long, value_enum)]
+        format: InteropFormat,
+        /// File to write to, stdout when not given
+        #[arg(long)]
+        output: Option<PathBuf>,
+        /// Only cards with this tag, can be given more than once to allow several tags
+        #[arg(long)]
+        filter_tag: Vec<String>,
+        /// Only cards with this priority, can be given more than once
+        #[arg(long, value_enum)]
+        filter_priority: Vec<PriorityArg>,
+        /// Only the cards of the board with this name
+        #[arg(long)]
+        board: Option<String>,
+    },
+    /// Import cards exported by another tool into the latest save and exit
+    Import {
+        #[arg(long, value_enum)]
+        format: InteropFormat,
+        /// File to read from, stdin when not given
+        #[arg(long)]
+        input: Option<PathBuf>,
+    },
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug)]
+enum InteropFormat {
+    /// JSON as read by `task import` and written by `task export`
+    Taskwarrior,
+}
+
+/// Card priorities as typed on the command line
+#[derive(ValueEnum, Clone, Copy, Debug)]
+enum PriorityArg {
+    Low,
+    Medium,
+    High,
+}
+
+impl From<PriorityArg> for CardPriority {
+    fn from(priority: PriorityArg) -> Self {
+        match priority {
+            PriorityArg::Low => CardPriority::Low,
+            PriorityArg::Medium => CardPriority::Medium,
+            PriorityArg::High => CardPriority::High,
+        }
+    }
 }
 
 #[tokio::main]
@@ -33,8 +119,9 @@ async fn main() -> Result<()> {
     let args = CliArgs::parse();
     let default_panic = std::panic::take_hook();
     std::panic::set_hook(Box::new(move |info| {
+        write_panic_report(&info.to_string());
         _ = terminal::disable_raw_mode();
-        let execute_result = execute!(stdout(), DisableMouseCapture);
+        let execute_result = execute!(stdout(), DisableMouseCapture, DisableBracketedPaste);
         if let Err(e) = execute_result {
             println!("Error while disabling mouse capture: {}", e);
         }
@@ -63,7 +150,7 @@ async fn main() -> Result<()> {
         logger::set_default_level(LevelFilter::Info);
     }
 
-    let (sync_io_tx, mut sync_io_rx) = tokio::sync::mpsc::channel::<IoEvent>(100);
+    let (sync_io_tx, mut sync_io_rx) = tokio::sync::mpsc::channel::<IoEvent>(IO_EVENT_QUEUE_SIZE);
 
     let main_app_instance = Arc::new(tokio::sync::Mutex::new(App::new(
         sync_io_tx.clone(),
@@ -76,6 +163,53 @@ async fn main() -> Result<()> {
         reset_app_main();
         return Ok(());
     }
+    if args.profile_startup {
+        profile_startup_main();
+        return Ok(());
+    }
+    match args.command {
+        Some(CliCommand::ImportGithub {
+            repo,
+            token_env,
+            board,
+        }) => {
+            import_github_main(repo, token_env, board).await?;
+            return Ok(());
+        }
+        Some(CliCommand::Export {
+            format: InteropFormat::Taskwarrior,
+            output,
+            filter_tag,
+            filter_priority,
+            board,
+        }) => {
+            let card_filter = CardFilter {
+                tags: filter_tag.iter().map(|tag| tag.to_lowercase()).collect(),
+                priorities: if filter_priority.is_empty() {
+                    None
+                } else {
+                    Some(
+                        filter_priority
+                            .into_iter()
+                            .map(CardPriority::from)
+                            .collect(),
+                    )
+                },
+                board_name: board,
+                ..Default::default()
+            };
+            export_taskwarrior_main(output, card_filter);
+            return Ok(());
+        }
+        Some(CliCommand::Import {
+            format: InteropFormat::Taskwarrior,
+            input,
+        }) => {
+            import_taskwarrior_main(input);
+            return Ok(());
+        }
+        None => {}
+    }
     if args.generate_new_encryption_key {
         if args.email_id.is_none() || args.password.is_none() {
             println!();
@@ -97,11 +231,25 @@ async fn main() -> Result<()> {
         let mut app = main_app_instance.lock().await;
         app.state.encryption_key_from_arguments = Some(encryption_key);
     }
+    if args.with_notes {
+        main_app_instance
+            .lock()
+            .await
+            .state
+            .include_notes_in_exports = true;
+    }
+    if args.safe_mode {
+        main_app_instance.lock().await.enter_safe_mode();
+    } else {
+        print_safe_mode_hint_after_crash();
+    }
 
     tokio::spawn(async move {
         let mut handler = IoAsyncHandler::new(main_app_instance);
         while let Some(io_event) = sync_io_rx.recv().await {
-            handler.handle_io_event(io_event).await;
+            for io_event in take_io_event_burst(io_event, &mut sync_io_rx) {
+                handler.handle_io_event(io_event).await;
+            }
         }
     });
 
@@ -109,7 +257,8 @@ async fn main() -> Result<()> {
         let mut widget_manager =
             rust_kanban::ui::widgets::WidgetManager::new(app_widget_manager_instance);
         loop {
-            widget_manager.update().await;
+            let tick_rate = widget_manager.update().await;
+            tokio::time::sleep(tick_rate).await;
         }
     });
 
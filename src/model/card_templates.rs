@@ -0,0 +1,96 @@
+//! Cards kept as a starting point for new ones, stored as a JSON list next to the save files so
+//! they carry over between sessions. A template keeps what makes up the card's structure and
+//! drops what belongs to the card it was made from
+//!
+//! ```
+//! use rust_kanban::model::{
+//!     card_templates::{card_template_from, load_card_templates, save_card_template},
+//!     kanban::{Card, CardPriority, ChecklistItem},
+//! };
+//!
+//! let mut card = Card::default();
+//! card.name = "Bug report".to_string();
+//! card.priority = CardPriority::High;
+//! card.tags = vec!["bug".to_string()];
+//! card.due_date = "2024/06/01".to_string();
+//! card.comments = vec!["Seen on staging".to_string()];
+//! card.checklist = vec![ChecklistItem {
+//!     text: "Reproduce".to_string(),
+//!     completed: true,
+//! }];
+//! let template = card_template_from(&card);
+//! assert_eq!(template.id, (0, 0));
+//! assert_eq!(template.due_date, "Not Set");
+//! assert!(template.comments.is_empty());
+//! assert!(!template.checklist[0].completed);
+//! assert_eq!(template.tags, card.tags);
+//!
+//! let path = std::env::temp_dir().join("rust_kanban_card_templates_example.json");
+//! # std::fs::remove_file(&path).ok();
+//! assert!(load_card_templates(&path).is_empty());
+//! assert_eq!(save_card_template(&path, template.clone()), Ok(false));
+//! // Saving a template under a name already in use replaces it
+//! let mut low_priority = template.clone();
+//! low_priority.name = "bug report".to_string();
+//! low_priority.priority = CardPriority::Low;
+//! assert_eq!(save_card_template(&path, low_priority), Ok(true));
+//! let templates = load_card_templates(&path);
+//! assert_eq!(templates.len(), 1);
+//! assert_eq!(templates[0].priority, CardPriority::Low);
+//! # std::fs::remove_file(&path).ok();
+//! ```
+
+use crate::{
+    constants::FIELD_NOT_SET,
+    model::{
+        kanban::{Card, CardStatus},
+        save_file::write_file_atomically,
+    },
+};
+use std::{fs, path::Path};
+
+/// The card without its id, dates, comments, logged time or status, the checklist unticked
+pub fn card_template_from(card: &Card) -> Card {
+    let mut template = card.clone();
+    template.id = (0, 0);
+    template.due_date = FIELD_NOT_SET.to_string();
+    template.start_date = None;
+    template.date_completed = FIELD_NOT_SET.to_string();
+    template.comments.clear();
+    template.actual_minutes = None;
+    template.card_status = CardStatus::Active;
+    template.custom_status = None;
+    template.status_before_archive = None;
+    template.import_hash = None;
+    template.waiting_on = None;
+    for checklist_item in &mut template.checklist {
+        checklist_item.completed = false;
+    }
+    template
+}
+
+/// Reads the templates, a missing or corrupt file gives an empty list
+pub fn load_card_templates(path: &Path) -> Vec<Card> {
+    fs::read_to_string(path)
+        .ok()
+        .and_then(|contents| serde_json::from_str(&contents).ok())
+        .unwrap_or_default()
+}
+
+/// Adds the template to the list at path, returns true when it replaced a template with the same
+/// name (ignoring case)
+pub fn save_card_template(path: &Path, template: Card) -> Result<bool, String> {
+    let mut templates = load_card_templates(path);
+    let existing_index = templates
+        .iter()
+        .position(|existing| existing.name.eq_ignore_ascii_case(&template.name));
+    let replaced = existing_index.is_some();
+    match existing_index {
+        Some(index) => templates[index] = template,
+        None => templates.push(template),
+    }
+    let contents = serde_json::to_vec_pretty(&templates)
+        .map_err(|e| format!("Could not serialize card templates: {}", e))?;
+    write_file_atomically(path, &contents)?;
+    Ok(replaced)
+}
@@ -0,0 +1,411 @@
+//! Bookkeeping for uploading only the boards that changed since the last cloud sync. The hash of
+//! every board at the last acknowledged upload is kept in the save file metadata, a sync compares
+//! against it and uploads the changed and removed boards as a delta on top of that cloud save
+
+use crate::{
+    constants::MAX_CLOUD_SYNC_DELTA_CHAIN,
+    model::{ids::format_id, kanban::Board},
+};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashSet};
+
+/// What the cloud holds after the last acknowledged sync
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
+pub struct CloudSyncMetadata {
+    /// The hashes only describe this user's cloud saves
+    pub user_id: String,
+    /// The cloud save the hashes describe, deltas are uploaded on top of it
+    pub save_id: usize,
+    /// Deltas between `save_id` and the last full upload, loading a save walks all of them
+    #[serde(default)]
+    pub delta_depth: usize,
+    /// Content hash of every board, by the board id as written by `format_id`
+    pub board_hashes: BTreeMap<String, u64>,
+}
+
+/// The encrypted payload of a partial upload, applied with [`apply_delta`] to the boards of the
+/// base cloud save
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+pub struct BoardsDelta {
+    pub changed_boards: Vec<Board>,
+    pub removed_board_ids: Vec<(u64, u64)>,
+    /// Every board id in order, a move between boards changes nothing but the order
+    pub board_order: Vec<(u64, u64)>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum SyncPlan {
+    /// The cloud already holds exactly these boards
+    UpToDate,
+    Full,
+    Partial {
+        base_save_id: usize,
+        delta: BoardsDelta,
+    },
+}
+
+/// A row the server returns for an accepted upload
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct CloudSyncAck {
+    pub save_id: usize,
+}
+
+pub fn board_hashes(boards: &[Board]) -> BTreeMap<String, u64> {
+    boards
+        .iter()
+        .map(|board| (format_id(board.id), board.content_hash()))
+        .collect()
+}
+
+/// Decides what a sync uploads. Without usable bookkeeping, a base save that is gone from the
+/// cloud or a delta chain at its cap everything is uploaded
+pub fn plan_sync(
+    boards: &[Board],
+    metadata: Option<&CloudSyncMetadata>,
+    user_id: &str,
+    cloud_save_ids: &[usize],
+) -> SyncPlan {
+    let metadata = match metadata {
+        Some(metadata)
+            if metadata.user_id == user_id && cloud_save_ids.contains(&metadata.save_id) =>
+        {
+            metadata
+        }
+        _ => return SyncPlan::Full,
+    };
+    let current_hashes = board_hashes(boards);
+    if current_hashes == metadata.board_hashes {
+        return SyncPlan::UpToDate;
+    }
+    if metadata.delta_depth >= MAX_CLOUD_SYNC_DELTA_CHAIN {
+        return SyncPlan::Full;
+    }
+    let changed_boards = boards
+        .iter()
+        .filter(|board| {
+            metadata.board_hashes.get(&format_id(board.id)) != Some(&board.content_hash())
+        })
+        .cloned()
+        .collect::<Vec<Board>>();
+    let current_ids = boards
+        .iter()
+        .map(|board| format_id(board.id))
+        .collect::<HashSet<String>>();
+    let removed_board_ids = metadata
+        .board_hashes
+        .keys()
+        .filter(|board_id| !current_ids.contains(*board_id))
+        .filter_map(|board_id| parse_board_id(board_id))
+        .collect();
+    SyncPlan::Partial {
+        base_save_id: metadata.save_id,
+        delta: BoardsDelta {
+            changed_boards,
+            removed_board_ids,
+            board_order: boards.iter().map(|board| board.id).collect(),
+        },
+    }
+}
+
+/// The boards of the base save with the delta on top, in the order the delta was taken in
+pub fn apply_delta(base_boards: Vec<Board>, delta: &BoardsDelta) -> Vec<Board> {
+    let mut boards = base_boards
+        .into_iter()
+        .filter(|board| !delta.removed_board_ids.contains(&board.id))
+        .filter(|board| {
+            !delta
+                .changed_boards
+                .iter()
+                .any(|changed| changed.id == board.id)
+        })
+        .chain(delta.changed_boards.iter().cloned())
+        .collect::<Vec<Board>>();
+    boards.sort_by_key(|board| {
+        delta
+            .board_order
+            .iter()
+            .position(|board_id| *board_id == board.id)
+            .unwrap_or(usize::MAX)
+    });
+    boards
+}
+
+/// The bookkeeping after the server acknowledged `plan`, with `boards` being what was uploaded
+pub fn merge_sync_ack(
+    previous: Option<&CloudSyncMetadata>,
+    plan: &SyncPlan,
+    boards: &[Board],
+    ack: &CloudSyncAck,
+    user_id: &str,
+) -> CloudSyncMetadata {
+    match (plan, previous) {
+        (SyncPlan::Partial { delta, .. }, Some(previous)) => {
+            let mut board_hashes = previous.board_hashes.clone();
+            for board_id in &delta.removed_board_ids {
+                board_hashes.remove(&format_id(*board_id));
+            }
+            for board in &delta.changed_boards {
+                board_hashes.insert(format_id(board.id), board.content_hash());
+            }
+            CloudSyncMetadata {
+                user_id: user_id.to_string(),
+                save_id: ack.save_id,
+                delta_depth: previous.delta_depth + 1,
+                board_hashes,
+            }
+        }
+        _ => CloudSyncMetadata {
+            user_id: user_id.to_string(),
+            save_id: ack.save_id,
+            delta_depth: 0,
+            board_hashes: board_hashes(boards),
+        },
+    }
+}
+
+/// The save ids to decrypt to load `save_id`, the full upload first and then every delta on top
+/// of it. `saves` holds the save id and base save id of every cloud save
+pub fn resolve_cloud_save_chain(
+    saves: &[(usize, Option<usize>)],
+    save_id: usize,
+) -> Result<Vec<usize>, String> {
+    let mut chain = vec![save_id];
+    let mut next_save_id = save_id;
+    loop {
+        let base_save_id = saves
+            .iter()
+            .find(|(id, _)| *id == next_save_id)
+            .ok_or_else(|| format!("cloud save {} was deleted", next_save_id))?
+            .1;
+        match base_save_id {
+            None => break,
+            Some(base_save_id) if chain.contains(&base_save_id) => {
+                return Err(format!("cloud save {} is based on itself", save_id));
+            }
+            Some(base_save_id) => {
+                chain.push(base_save_id);
+                next_save_id = base_save_id;
+            }
+        }
+    }
+    chain.reverse();
+    Ok(chain)
+}
+
+/// The saves that need `save_id` to load, the partial saves on top of it and the ones on top of
+/// those. `saves` holds the save id and base save id of every cloud save
+pub fn dependent_cloud_saves(saves: &[(usize, Option<usize>)], save_id: usize) -> Vec<usize> {
+    saves
+        .iter()
+        .map(|(id, _)| *id)
+        .filter(|id| {
+            *id != save_id
+                && resolve_cloud_save_chain(saves, *id).is_ok_and(|chain| chain.contains(&save_id))
+        })
+        .collect()
+}
+
+fn parse_board_id(board_id: &str) -> Option<(u64, u64)> {
+    let (first, second) = board_id.split_once('-')?;
+    Some((first.parse().ok()?, second.parse().ok()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::kanban::Card;
+
+    const USER_ID: &str = "user";
+
+    fn sample_boards() -> Vec<Board> {
+        ["Todo", "Doing", "Done"]
+            .iter()
+            .map(|name| {
+                let mut board = Board::new(name, "");
+                board.cards.add_card(Card::default());
+                board
+            })
+            .collect()
+    }
+
+    fn synced(boards: &[Board], save_id: usize) -> CloudSyncMetadata {
+        merge_sync_ack(
+            None,
+            &SyncPlan::Full,
+            boards,
+            &CloudSyncAck { save_id },
+            USER_ID,
+        )
+    }
+
+    #[test]
+    fn content_hash_is_stable_and_follows_the_content() {
+        let boards = sample_boards();
+        let board = &boards[0];
+        assert_eq!(board.content_hash(), board.clone().content_hash());
+        let round_tripped: Board =
+            serde_json::from_str(&serde_json::to_string(board).unwrap()).unwrap();
+        assert_eq!(board.content_hash(), round_tripped.content_hash());
+        let mut edited = board.clone();
+        edited.description = "Edited".to_string();
+        assert_ne!(board.content_hash(), edited.content_hash());
+    }
+
+    #[test]
+    fn stable_hash_matches_the_fnv_1a_reference_values() {
+        use crate::model::ids::stable_hash;
+        assert_eq!(stable_hash(b""), 0xcbf29ce484222325);
+        assert_eq!(stable_hash(b"a"), 0xaf63dc4c8601ec8c);
+        assert_eq!(stable_hash(b"foobar"), 0x85944171f73967e8);
+    }
+
+    #[test]
+    fn without_bookkeeping_everything_is_uploaded() {
+        let boards = sample_boards();
+        assert_eq!(plan_sync(&boards, None, USER_ID, &[0]), SyncPlan::Full);
+    }
+
+    #[test]
+    fn bookkeeping_of_another_user_or_a_deleted_save_is_not_used() {
+        let boards = sample_boards();
+        let metadata = synced(&boards, 3);
+        assert_eq!(
+            plan_sync(&boards, Some(&metadata), "someone else", &[3]),
+            SyncPlan::Full
+        );
+        assert_eq!(
+            plan_sync(&boards, Some(&metadata), USER_ID, &[1, 2]),
+            SyncPlan::Full
+        );
+    }
+
+    #[test]
+    fn unchanged_boards_are_up_to_date() {
+        let boards = sample_boards();
+        let metadata = synced(&boards, 0);
+        assert_eq!(
+            plan_sync(&boards, Some(&metadata), USER_ID, &[0]),
+            SyncPlan::UpToDate
+        );
+    }
+
+    #[test]
+    fn only_changed_and_removed_boards_are_uploaded() {
+        let mut boards = sample_boards();
+        let metadata = synced(&boards, 0);
+        boards[1].name = "In Progress".to_string();
+        let removed_board = boards.remove(2);
+        let new_board = Board::new("Later", "");
+        boards.insert(0, new_board.clone());
+        match plan_sync(&boards, Some(&metadata), USER_ID, &[0]) {
+            SyncPlan::Partial {
+                base_save_id,
+                delta,
+            } => {
+                assert_eq!(base_save_id, 0);
+                let changed_ids = delta
+                    .changed_boards
+                    .iter()
+                    .map(|board| board.id)
+                    .collect::<Vec<_>>();
+                assert_eq!(changed_ids, vec![new_board.id, boards[2].id]);
+                assert_eq!(delta.removed_board_ids, vec![removed_board.id]);
+                assert_eq!(
+                    delta.board_order,
+                    boards.iter().map(|board| board.id).collect::<Vec<_>>()
+                );
+            }
+            plan => panic!("expected a partial sync, got {:?}", plan),
+        }
+    }
+
+    #[test]
+    fn a_delta_on_top_of_the_base_gives_the_local_boards() {
+        let base_boards = sample_boards();
+        let metadata = synced(&base_boards, 0);
+        let mut boards = base_boards.clone();
+        boards[0].cards.add_card(Card::default());
+        boards.swap(1, 2);
+        boards.remove(1);
+        boards.push(Board::new("New", ""));
+        let SyncPlan::Partial { delta, .. } = plan_sync(&boards, Some(&metadata), USER_ID, &[0])
+        else {
+            panic!("expected a partial sync");
+        };
+        assert_eq!(apply_delta(base_boards, &delta), boards);
+    }
+
+    #[test]
+    fn a_long_delta_chain_falls_back_to_a_full_upload() {
+        let mut boards = sample_boards();
+        let mut metadata = synced(&boards, 0);
+        metadata.delta_depth = MAX_CLOUD_SYNC_DELTA_CHAIN;
+        boards[0].name = "Renamed".to_string();
+        assert_eq!(
+            plan_sync(&boards, Some(&metadata), USER_ID, &[0]),
+            SyncPlan::Full
+        );
+    }
+
+    #[test]
+    fn acks_are_merged_into_the_bookkeeping() {
+        let mut boards = sample_boards();
+        let metadata = synced(&boards, 0);
+        boards[0].name = "Renamed".to_string();
+        boards.pop();
+        let plan = plan_sync(&boards, Some(&metadata), USER_ID, &[0]);
+        let merged = merge_sync_ack(
+            Some(&metadata),
+            &plan,
+            &boards,
+            &CloudSyncAck { save_id: 1 },
+            USER_ID,
+        );
+        assert_eq!(merged.save_id, 1);
+        assert_eq!(merged.delta_depth, 1);
+        assert_eq!(merged.board_hashes, board_hashes(&boards));
+        assert_eq!(
+            plan_sync(&boards, Some(&merged), USER_ID, &[0, 1]),
+            SyncPlan::UpToDate
+        );
+
+        let full = merge_sync_ack(
+            Some(&merged),
+            &SyncPlan::Full,
+            &boards,
+            &CloudSyncAck { save_id: 2 },
+            USER_ID,
+        );
+        assert_eq!(full.delta_depth, 0);
+        assert_eq!(full.board_hashes, board_hashes(&boards));
+    }
+
+    #[test]
+    fn cloud_save_chains_resolve_from_the_full_upload() {
+        let saves = [(0, None), (1, Some(0)), (2, Some(1)), (3, None)];
+        assert_eq!(resolve_cloud_save_chain(&saves, 2), Ok(vec![0, 1, 2]));
+        assert_eq!(resolve_cloud_save_chain(&saves, 3), Ok(vec![3]));
+        assert!(resolve_cloud_save_chain(&[(1, Some(0))], 1).is_err());
+        assert!(resolve_cloud_save_chain(&[(0, Some(1)), (1, Some(0))], 0).is_err());
+    }
+
+    #[test]
+    fn partial_saves_on_top_of_a_save_depend_on_it() {
+        let saves = [
+            (0, None),
+            (1, Some(0)),
+            (2, Some(1)),
+            (3, None),
+            (4, Some(3)),
+        ];
+        assert_eq!(dependent_cloud_saves(&saves, 0), vec![1, 2]);
+        assert_eq!(dependent_cloud_saves(&saves, 1), vec![2]);
+        assert!(dependent_cloud_saves(&saves, 2).is_empty());
+        assert_eq!(dependent_cloud_saves(&saves, 3), vec![4]);
+
+        // Deleting the newest partial save first leaves every other chain loadable
+        let remaining = [(0, None), (1, Some(0)), (3, None), (4, Some(3))];
+        assert!(dependent_cloud_saves(&remaining, 1).is_empty());
+        assert_eq!(resolve_cloud_save_chain(&remaining, 1), Ok(vec![0, 1]));
+    }
+}
@@ -0,0 +1,145 @@
+use crate::model::{
+    date_time_format::{parse_date_time, DateTimeFormat},
+    ids::format_id,
+    kanban::Board,
+};
+
+const CSV_HEADER: [&str; 13] = [
+    "board_name",
+    "card_name",
+    "description_first_line",
+    "status",
+    "priority",
+    "due_date",
+    "tags",
+    "comments_count",
+    "description",
+    "date_created",
+    "date_modified",
+    "board_id",
+    "card_id",
+];
+
+/// Quotes a field when it holds a comma, quote or line break, quotes inside are doubled
+pub fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// One row per card of every board below a header row, returned with the number of card rows.
+/// Line breaks in the full description are written as a literal `\n` so every card stays on one
+/// line, dates are shown in `date_time_format`. Ids are written the way
+/// [`format_id`] shows them so other tools can refer back to a board or card
+pub fn boards_to_csv(boards: &[Board], date_time_format: DateTimeFormat) -> (String, usize) {
+    let mut csv = CSV_HEADER.join(",");
+    csv.push('\n');
+    let mut row_count = 0;
+    for board in boards {
+        for card in board.cards.get_all_cards() {
+            let description_first_line = card
+                .description
+                .trim()
+                .lines()
+                .next()
+                .unwrap_or_default()
+                .to_string();
+            let description = card
+                .description
+                .trim()
+                .replace("\r\n", "\n")
+                .replace('\n', "\\n");
+            let status = card
+                .custom_status
+                .clone()
+                .unwrap_or_else(|| card.card_status.to_string());
+            let row = [
+                board.name.clone(),
+                card.name.clone(),
+                description_first_line,
+                status,
+                card.priority.to_string(),
+                format_date(&card.due_date, date_time_format),
+                card.tags.join(";"),
+                card.comments.len().to_string(),
+                description,
+                format_date(&card.date_created, date_time_format),
+                format_date(&card.date_modified, date_time_format),
+                format_id(board.id),
+                format_id(card.id),
+            ];
+            let row = row
+                .iter()
+                .map(|field| csv_field(field))
+                .collect::<Vec<String>>();
+            csv.push_str(&row.join(","));
+            csv.push('\n');
+            row_count += 1;
+        }
+    }
+    (csv, row_count)
+}
+
+/// Dates that can not be read, like a due date that was never set, are written as they are
+fn format_date(date: &str, date_time_format: DateTimeFormat) -> String {
+    parse_date_time(date)
+        .map(|date_time| {
+            date_time
+                .format(date_time_format.to_parser_string())
+                .to_string()
+        })
+        .unwrap_or_else(|| date.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::kanban::{Card, CardPriority};
+
+    #[test]
+    fn fields_are_quoted_only_when_they_need_it() {
+        assert_eq!(csv_field("Write docs"), "Write docs");
+        assert_eq!(csv_field("Docs, tests"), "\"Docs, tests\"");
+        assert_eq!(csv_field("The \"fast\" path"), "\"The \"\"fast\"\" path\"");
+        assert_eq!(csv_field("two\nlines"), "\"two\nlines\"");
+        assert_eq!(csv_field(""), "");
+    }
+
+    #[test]
+    fn each_card_is_one_row_below_the_header() {
+        let mut board = Board::new("Sprint, week 1", "");
+        board.id = (1, 2);
+        board.cards.add_card(Card {
+            id: (3, 4),
+            name: "Say \"hi\"".to_string(),
+            description: "first\nsecond".to_string(),
+            priority: CardPriority::High,
+            due_date: "2024/06/01".to_string(),
+            tags: vec!["docs".to_string(), "team".to_string()],
+            date_created: "01/05/2024-09:30:00".to_string(),
+            date_modified: "02/05/2024-10:00:00".to_string(),
+            ..Default::default()
+        });
+
+        let (csv, row_count) = boards_to_csv(&[board], DateTimeFormat::YearMonthDay);
+        assert_eq!(row_count, 1);
+        let mut lines = csv.lines();
+        assert_eq!(
+            lines.next(),
+            Some(
+                "board_name,card_name,description_first_line,status,priority,due_date,tags,\
+                 comments_count,description,date_created,date_modified,board_id,card_id"
+            )
+        );
+        assert_eq!(
+            lines.next(),
+            Some(
+                "\"Sprint, week 1\",\"Say \"\"hi\"\"\",first,Active,High,2024/06/01,docs;team,0,\
+                 first\\nsecond,2024/05/01,2024/05/02,1-2,3-4"
+            )
+        );
+        assert_eq!(lines.next(), None);
+    }
+}
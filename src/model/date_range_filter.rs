@@ -0,0 +1,153 @@
+//! Narrowing cards down to the ones due in a stretch of time, relative to now or between two
+//! days the user typed in
+//!
+//! ```
+//! use chrono::NaiveDate;
+//! use rust_kanban::model::{
+//!     date_range_filter::DateRangeFilter, date_time_format::DateTimeFormat,
+//! };
+//!
+//! let format = DateTimeFormat::DayMonthYear;
+//! // A Wednesday
+//! let now = NaiveDate::from_ymd_opt(2024, 5, 15)
+//!     .unwrap()
+//!     .and_hms_opt(12, 0, 0)
+//!     .unwrap();
+//! let due = |due_date: &str| DateRangeFilter::parse_due_date(due_date, format);
+//!
+//! assert!(DateRangeFilter::Overdue.contains(due("14/05/2024").unwrap(), now));
+//! // A due date without a time lasts until the end of that day
+//! assert!(!DateRangeFilter::Overdue.contains(due("15/05/2024").unwrap(), now));
+//! assert!(DateRangeFilter::DueToday.contains(due("15/05/2024").unwrap(), now));
+//! assert!(DateRangeFilter::DueThisWeek.contains(due("19/05/2024").unwrap(), now));
+//! assert!(!DateRangeFilter::DueThisWeek.contains(due("20/05/2024").unwrap(), now));
+//! assert!(DateRangeFilter::DueThisMonth.contains(due("31/05/2024").unwrap(), now));
+//! assert_eq!(due("Not Set"), None);
+//! assert_eq!(due("2024-05-15"), None);
+//!
+//! let custom = DateRangeFilter::parse_custom_range("01/05/2024 to 10/05/2024", format).unwrap();
+//! assert!(custom.contains(due("10/05/2024").unwrap(), now));
+//! assert!(!custom.contains(due("11/05/2024").unwrap(), now));
+//! assert!(DateRangeFilter::parse_custom_range("10/05/2024 to 01/05/2024", format).is_err());
+//! assert!(DateRangeFilter::parse_custom_range("01/05/2024", format).is_err());
+//! ```
+
+use crate::{constants::FIELD_NOT_SET, model::date_time_format::DateTimeFormat};
+use chrono::{Datelike, NaiveDate, NaiveDateTime};
+
+/// Written between the two days of a custom range
+pub const CUSTOM_RANGE_SEPARATOR: &str = " to ";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DateRangeFilter {
+    Overdue,
+    DueToday,
+    /// Monday to Sunday of the current week
+    DueThisWeek,
+    DueThisMonth,
+    /// From the first to the last day, both included
+    Custom(NaiveDate, NaiveDate),
+}
+
+impl DateRangeFilter {
+    /// The ranges that need no input, in the order they are offered
+    pub fn presets() -> [DateRangeFilter; 4] {
+        [
+            DateRangeFilter::Overdue,
+            DateRangeFilter::DueToday,
+            DateRangeFilter::DueThisWeek,
+            DateRangeFilter::DueThisMonth,
+        ]
+    }
+
+    /// Whether a card due at `due` is in the range, with `now` as the current local time
+    pub fn contains(&self, due: NaiveDateTime, now: NaiveDateTime) -> bool {
+        let (due_date, today) = (due.date(), now.date());
+        match self {
+            DateRangeFilter::Overdue => due < now,
+            DateRangeFilter::DueToday => due_date == today,
+            DateRangeFilter::DueThisWeek => due_date.iso_week() == today.iso_week(),
+            DateRangeFilter::DueThisMonth => {
+                due_date.year() == today.year() && due_date.month() == today.month()
+            }
+            DateRangeFilter::Custom(from, to) => *from <= due_date && due_date <= *to,
+        }
+    }
+
+    /// Reads a card due date written in `date_time_format`, with or without its time. None when
+    /// it is not set or written some other way
+    pub fn parse_due_date(
+        due_date: &str,
+        date_time_format: DateTimeFormat,
+    ) -> Option<NaiveDateTime> {
+        let due_date = due_date.trim();
+        if due_date == FIELD_NOT_SET || due_date.is_empty() {
+            return None;
+        }
+        let with_time = DateTimeFormat::add_time_to_date_format(date_time_format);
+        let without_time = DateTimeFormat::remove_time_from_date_format(date_time_format);
+        NaiveDateTime::parse_from_str(due_date, with_time.to_parser_string())
+            .ok()
+            .or_else(|| {
+                // Due any time that day, so it only counts as overdue once the day is over
+                NaiveDate::parse_from_str(due_date, without_time.to_parser_string())
+                    .ok()
+                    .and_then(|date| date.and_hms_opt(23, 59, 59))
+            })
+    }
+
+    /// Reads a custom range typed as two days in `date_time_format` with
+    /// [`CUSTOM_RANGE_SEPARATOR`] between them
+    pub fn parse_custom_range(
+        input: &str,
+        date_time_format: DateTimeFormat,
+    ) -> Result<DateRangeFilter, String> {
+        let date_format = DateTimeFormat::remove_time_from_date_format(date_time_format);
+        let Some((from, to)) = input.trim().split_once(CUSTOM_RANGE_SEPARATOR) else {
+            return Err(format!(
+                "Expected two dates separated by \"{}\"",
+                CUSTOM_RANGE_SEPARATOR.trim()
+            ));
+        };
+        let parse_date = |date: &str| {
+            NaiveDate::parse_from_str(date.trim(), date_format.to_parser_string()).map_err(|_| {
+                format!(
+                    "\"{}\" is not a date, expected {}",
+                    date.trim(),
+                    date_format.to_human_readable_string()
+                )
+            })
+        };
+        let (from, to) = (parse_date(from)?, parse_date(to)?);
+        if from > to {
+            return Err("The range ends before it starts".to_string());
+        }
+        Ok(DateRangeFilter::Custom(from, to))
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            DateRangeFilter::Overdue => "Overdue",
+            DateRangeFilter::DueToday => "Due today",
+            DateRangeFilter::DueThisWeek => "Due this week",
+            DateRangeFilter::DueThisMonth => "Due this month",
+            DateRangeFilter::Custom(..) => "Custom range",
+        }
+    }
+
+    /// Shown in the filter header, custom days are written in `date_time_format`
+    pub fn describe(&self, date_time_format: DateTimeFormat) -> String {
+        match self {
+            DateRangeFilter::Custom(from, to) => {
+                let date_format = DateTimeFormat::remove_time_from_date_format(date_time_format);
+                format!(
+                    "due {}{}{}",
+                    from.format(date_format.to_parser_string()),
+                    CUSTOM_RANGE_SEPARATOR,
+                    to.format(date_format.to_parser_string())
+                )
+            }
+            preset => preset.name().to_lowercase(),
+        }
+    }
+}
@@ -0,0 +1,221 @@
+use crate::constants::FIELD_NOT_SET;
+use chrono::{Datelike, NaiveDate, NaiveDateTime, NaiveTime};
+use serde::{Deserialize, Serialize};
+use std::{
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+};
+use strum::EnumString;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, EnumString)]
+pub enum DateTimeFormat {
+    DayMonthYear,
+    #[default]
+    DayMonthYearTime,
+    MonthDayYear,
+    MonthDayYearTime,
+    YearMonthDay,
+    YearMonthDayTime,
+}
+
+impl DateTimeFormat {
+    pub fn to_human_readable_string(&self) -> &str {
+        match self {
+            DateTimeFormat::DayMonthYear => "DD/MM/YYYY",
+            DateTimeFormat::DayMonthYearTime => "DD/MM/YYYY-HH:MM:SS",
+            DateTimeFormat::MonthDayYear => "MM/DD/YYYY",
+            DateTimeFormat::MonthDayYearTime => "MM/DD/YYYY-HH:MM:SS",
+            DateTimeFormat::YearMonthDay => "YYYY/MM/DD",
+            DateTimeFormat::YearMonthDayTime => "YYYY/MM/DD-HH:MM:SS",
+        }
+    }
+    pub fn to_parser_string(&self) -> &str {
+        match self {
+            DateTimeFormat::DayMonthYear => "%d/%m/%Y",
+            DateTimeFormat::DayMonthYearTime => "%d/%m/%Y-%H:%M:%S",
+            DateTimeFormat::MonthDayYear => "%m/%d/%Y",
+            DateTimeFormat::MonthDayYearTime => "%m/%d/%Y-%H:%M:%S",
+            DateTimeFormat::YearMonthDay => "%Y/%m/%d",
+            DateTimeFormat::YearMonthDayTime => "%Y/%m/%d-%H:%M:%S",
+        }
+    }
+    pub fn from_json_string(json_string: &str) -> Option<DateTimeFormat> {
+        match DateTimeFormat::from_str(json_string) {
+            Ok(date_time_format) => Some(date_time_format),
+            Err(_) => None,
+        }
+    }
+    pub fn from_human_readable_string(human_readable_string: &str) -> Option<DateTimeFormat> {
+        match human_readable_string {
+            "DD/MM/YYYY" => Some(DateTimeFormat::DayMonthYear),
+            "DD/MM/YYYY-HH:MM:SS" => Some(DateTimeFormat::DayMonthYearTime),
+            "MM/DD/YYYY" => Some(DateTimeFormat::MonthDayYear),
+            "MM/DD/YYYY-HH:MM:SS" => Some(DateTimeFormat::MonthDayYearTime),
+            "YYYY/MM/DD" => Some(DateTimeFormat::YearMonthDay),
+            "YYYY/MM/DD-HH:MM:SS" => Some(DateTimeFormat::YearMonthDayTime),
+            _ => None,
+        }
+    }
+    pub fn get_all_date_formats() -> Vec<DateTimeFormat> {
+        vec![
+            DateTimeFormat::DayMonthYear,
+            DateTimeFormat::DayMonthYearTime,
+            DateTimeFormat::MonthDayYear,
+            DateTimeFormat::MonthDayYearTime,
+            DateTimeFormat::YearMonthDay,
+            DateTimeFormat::YearMonthDayTime,
+        ]
+    }
+    pub fn all_formats_with_time() -> Vec<DateTimeFormat> {
+        vec![
+            DateTimeFormat::DayMonthYearTime,
+            DateTimeFormat::MonthDayYearTime,
+            DateTimeFormat::YearMonthDayTime,
+        ]
+    }
+    pub fn all_formats_without_time() -> Vec<DateTimeFormat> {
+        vec![
+            DateTimeFormat::DayMonthYear,
+            DateTimeFormat::MonthDayYear,
+            DateTimeFormat::YearMonthDay,
+        ]
+    }
+    pub fn add_time_to_date_format(date_format: DateTimeFormat) -> DateTimeFormat {
+        match date_format {
+            DateTimeFormat::DayMonthYear => DateTimeFormat::DayMonthYearTime,
+            DateTimeFormat::MonthDayYear => DateTimeFormat::MonthDayYearTime,
+            DateTimeFormat::YearMonthDay => DateTimeFormat::YearMonthDayTime,
+            _ => date_format,
+        }
+    }
+    pub fn remove_time_from_date_format(date_format: DateTimeFormat) -> DateTimeFormat {
+        match date_format {
+            DateTimeFormat::DayMonthYearTime => DateTimeFormat::DayMonthYear,
+            DateTimeFormat::MonthDayYearTime => DateTimeFormat::MonthDayYear,
+            DateTimeFormat::YearMonthDayTime => DateTimeFormat::YearMonthDay,
+            _ => date_format,
+        }
+    }
+}
+
+impl Display for DateTimeFormat {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}", self.to_human_readable_string())
+    }
+}
+
+pub fn date_format_finder(date_string: &str) -> Result<DateTimeFormat, String> {
+    let all_formats_with_time = DateTimeFormat::all_formats_with_time();
+    for date_format in DateTimeFormat::get_all_date_formats() {
+        if all_formats_with_time.contains(&date_format) {
+            match NaiveDateTime::parse_from_str(date_string, date_format.to_parser_string()) {
+                Ok(_) => return Ok(date_format),
+                Err(_) => {
+                    continue;
+                }
+            }
+        } else {
+            match NaiveDate::parse_from_str(date_string, date_format.to_parser_string()) {
+                Ok(_) => return Ok(date_format),
+                Err(_) => {
+                    continue;
+                }
+            }
+        }
+    }
+    Err("Invalid date format".to_string())
+}
+
+/// Parses a date in any supported format, dates without a time are taken at midnight
+pub fn parse_date_time(date_string: &str) -> Option<NaiveDateTime> {
+    let date_string = date_string.trim();
+    let date_format = date_format_finder(date_string).ok()?;
+    if DateTimeFormat::all_formats_with_time().contains(&date_format) {
+        NaiveDateTime::parse_from_str(date_string, date_format.to_parser_string()).ok()
+    } else {
+        NaiveDate::parse_from_str(date_string, date_format.to_parser_string())
+            .ok()
+            .and_then(|date| date.and_hms_opt(0, 0, 0))
+    }
+}
+
+pub fn date_format_converter(
+    date_string: &str,
+    date_format: DateTimeFormat,
+) -> Result<String, String> {
+    if date_string == FIELD_NOT_SET || date_string.is_empty() {
+        return Ok(date_string.to_string());
+    }
+    let given_date_format = date_format_finder(date_string)?;
+    if given_date_format == date_format {
+        return Ok(date_string.to_string());
+    }
+    let all_formats_with_time = DateTimeFormat::all_formats_with_time();
+    let all_formats_without_time = DateTimeFormat::all_formats_without_time();
+    if all_formats_with_time.contains(&given_date_format)
+        && all_formats_without_time.contains(&date_format)
+    {
+        let naive_date_time =
+            NaiveDateTime::parse_from_str(date_string, given_date_format.to_parser_string());
+        if let Ok(naive_date_time) = naive_date_time {
+            let naive_date = NaiveDate::from_ymd_opt(
+                naive_date_time.year(),
+                naive_date_time.month(),
+                naive_date_time.day(),
+            );
+            if let Some(naive_date) = naive_date {
+                return Ok(naive_date
+                    .format(date_format.to_parser_string())
+                    .to_string());
+            } else {
+                Err("Invalid date format".to_string())
+            }
+        } else {
+            Err("Invalid date format".to_string())
+        }
+    } else if all_formats_without_time.contains(&given_date_format)
+        && all_formats_with_time.contains(&date_format)
+    {
+        let naive_date =
+            NaiveDate::parse_from_str(date_string, given_date_format.to_parser_string());
+        if let Ok(naive_date) = naive_date {
+            let default_time = NaiveTime::from_hms_opt(0, 0, 0);
+            if let Some(default_time) = default_time {
+                let naive_date_time = NaiveDateTime::new(naive_date, default_time);
+                return Ok(naive_date_time
+                    .format(date_format.to_parser_string())
+                    .to_string());
+            } else {
+                Err("Invalid date format".to_string())
+            }
+        } else {
+            Err("Invalid date format".to_string())
+        }
+    } else if all_formats_with_time.contains(&given_date_format)
+        && all_formats_with_time.contains(&date_format)
+    {
+        let naive_date_time =
+            NaiveDateTime::parse_from_str(date_string, given_date_format.to_parser_string());
+        if let Ok(naive_date_time) = naive_date_time {
+            return Ok(naive_date_time
+                .format(date_format.to_parser_string())
+                .to_string());
+        } else {
+            Err("Invalid date format".to_string())
+        }
+    } else if all_formats_without_time.contains(&given_date_format)
+        && all_formats_without_time.contains(&date_format)
+    {
+        let naive_date =
+            NaiveDate::parse_from_str(date_string, given_date_format.to_parser_string());
+        if let Ok(naive_date) = naive_date {
+            return Ok(naive_date
+                .format(date_format.to_parser_string())
+                .to_string());
+        } else {
+            Err("Invalid date format".to_string())
+        }
+    } else {
+        Err("Invalid date format".to_string())
+    }
+}
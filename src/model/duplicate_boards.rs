@@ -0,0 +1,249 @@
+//! Finding boards that exist twice, usually after a save was restored on top of the boards it
+//! was made from, and folding one copy into the other
+
+use crate::model::{
+    date_time_format::parse_date_time,
+    kanban::{Board, Card, Cards},
+};
+use chrono::NaiveDateTime;
+use std::{
+    collections::{hash_map::DefaultHasher, HashSet},
+    hash::{Hash, Hasher},
+};
+
+/// Share of the smaller board's card names the other board must also have for two boards with
+/// the same name to count as copies
+pub const MIN_CARD_NAME_OVERLAP: f64 = 0.5;
+/// Put on both versions of a card whose copies were edited differently, so Filter by Tag finds
+/// the ones that need a look
+pub const DIVERGED_CARD_TAG: &str = "diverged";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DuplicateBoardPair {
+    /// The copy modified last, a merge keeps this board and removes the other
+    pub kept_board_id: (u64, u64),
+    pub copy_board_id: (u64, u64),
+    pub shared_card_names: usize,
+}
+
+#[derive(Debug, Clone)]
+pub struct BoardMerge {
+    /// The kept board with the cards of the copy folded in
+    pub merged_board: Board,
+    /// Cards of the copy that were added to the kept board, diverged ones already flagged
+    pub added_cards: Vec<Card>,
+    /// Cards of the kept board that got flagged, before and after
+    pub flagged_cards: Vec<(Card, Card)>,
+    /// Cards of the copy left out because the kept board has them with the same description
+    pub dropped_cards: usize,
+}
+
+/// When any card on the board was last touched, None for empty boards or unreadable dates
+pub fn board_last_modified(board: &Board) -> Option<NaiveDateTime> {
+    board
+        .cards
+        .get_all_cards()
+        .iter()
+        .filter_map(|card| parse_date_time(&card.date_modified))
+        .max()
+}
+
+fn card_content_hash(card: &Card) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    card.name.trim().hash(&mut hasher);
+    card.description.trim().hash(&mut hasher);
+    hasher.finish()
+}
+
+fn card_names(board: &Board) -> HashSet<&str> {
+    board
+        .cards
+        .get_all_cards()
+        .iter()
+        .map(|card| card.name.trim())
+        .collect()
+}
+
+/// Pairs up boards with identical names whose card names mostly overlap, see
+/// [`MIN_CARD_NAME_OVERLAP`]. A board copied three times shows up in a pair with each of the
+/// others
+pub fn find_duplicate_boards(boards: &[Board]) -> Vec<DuplicateBoardPair> {
+    let mut pairs = Vec::new();
+    for (index, board) in boards.iter().enumerate() {
+        for other in boards.iter().skip(index + 1) {
+            if board.name.trim() != other.name.trim() {
+                continue;
+            }
+            let board_card_names = card_names(board);
+            let other_card_names = card_names(other);
+            let shared_card_names = board_card_names.intersection(&other_card_names).count();
+            let smaller_board_size = board_card_names.len().min(other_card_names.len());
+            // A copy with no cards has nothing that could be lost
+            if smaller_board_size > 0
+                && (shared_card_names as f64) / (smaller_board_size as f64) < MIN_CARD_NAME_OVERLAP
+            {
+                continue;
+            }
+            let other_is_newer = match (board_last_modified(board), board_last_modified(other)) {
+                (Some(board_modified), Some(other_modified))
+                    if board_modified != other_modified =>
+                {
+                    other_modified > board_modified
+                }
+                _ => other.cards.len() > board.cards.len(),
+            };
+            let (kept, copy) = if other_is_newer {
+                (other, board)
+            } else {
+                (board, other)
+            };
+            pairs.push(DuplicateBoardPair {
+                kept_board_id: kept.id,
+                copy_board_id: copy.id,
+                shared_card_names,
+            });
+        }
+    }
+    pairs
+}
+
+fn flag_diverged(card: &Card) -> Card {
+    let mut card = card.clone();
+    if !card.tags.iter().any(|tag| tag == DIVERGED_CARD_TAG) {
+        card.tags.push(DIVERGED_CARD_TAG.to_string());
+    }
+    card
+}
+
+/// Adds the cards of the copy to the kept board. Cards with the same name and description as
+/// one on the kept board are dropped, cards with the same name but edited differently are kept
+/// twice and both versions are tagged with [`DIVERGED_CARD_TAG`]
+pub fn merge_boards(kept: &Board, copy: &Board) -> BoardMerge {
+    let kept_hashes = kept
+        .cards
+        .get_all_cards()
+        .iter()
+        .map(card_content_hash)
+        .collect::<HashSet<u64>>();
+    let kept_names = card_names(kept);
+
+    let mut added_cards = Vec::new();
+    let mut diverged_names = HashSet::new();
+    let mut dropped_cards = 0;
+    for card in copy.cards.get_all_cards() {
+        if kept_hashes.contains(&card_content_hash(card)) {
+            dropped_cards += 1;
+        } else if kept_names.contains(card.name.trim()) {
+            diverged_names.insert(card.name.trim());
+            added_cards.push(flag_diverged(card));
+        } else {
+            added_cards.push(card.clone());
+        }
+    }
+
+    let mut flagged_cards = Vec::new();
+    let mut merged_cards = Vec::new();
+    for card in kept.cards.get_all_cards() {
+        if diverged_names.contains(card.name.trim()) {
+            let flagged_card = flag_diverged(card);
+            if flagged_card.tags != card.tags {
+                flagged_cards.push((card.clone(), flagged_card.clone()));
+            }
+            merged_cards.push(flagged_card);
+        } else {
+            merged_cards.push(card.clone());
+        }
+    }
+    merged_cards.extend(added_cards.iter().cloned());
+
+    let mut merged_board = kept.clone();
+    merged_board.cards = Cards::from(merged_cards);
+    BoardMerge {
+        merged_board,
+        added_cards,
+        flagged_cards,
+        dropped_cards,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{date_time_format::DateTimeFormat, kanban::CardPriority};
+
+    fn card(name: &str, description: &str) -> Card {
+        Card::new(
+            name,
+            description,
+            "",
+            CardPriority::Low,
+            vec![],
+            vec![],
+            DateTimeFormat::default(),
+        )
+    }
+
+    fn board(name: &str, cards: &[(&str, &str)]) -> Board {
+        let mut board = Board::new(name, "");
+        for (card_name, description) in cards {
+            board.cards.add_card(card(card_name, description));
+        }
+        board
+    }
+
+    fn sprint_boards() -> (Board, Board) {
+        let kept = board(
+            "Sprint",
+            &[
+                ("Login page", "Add the form"),
+                ("Release notes", "Draft them"),
+            ],
+        );
+        let copy = board(
+            "Sprint",
+            &[
+                ("Login page", "Add the form"),
+                ("Release notes", "Draft and publish them"),
+                ("Fix CI", ""),
+            ],
+        );
+        (kept, copy)
+    }
+
+    #[test]
+    fn boards_with_the_same_name_and_mostly_the_same_cards_are_paired() {
+        let (kept, copy) = sprint_boards();
+        let pairs = find_duplicate_boards(&[kept, copy]);
+        assert_eq!(pairs.len(), 1);
+        assert_eq!(pairs[0].shared_card_names, 2);
+    }
+
+    #[test]
+    fn boards_with_other_names_or_other_cards_are_not_paired() {
+        let (kept, _) = sprint_boards();
+        let renamed = board("Backlog", &[("Login page", ""), ("Release notes", "")]);
+        let unrelated = board("Sprint", &[("Fix CI", ""), ("Update docs", "")]);
+        assert!(find_duplicate_boards(&[kept.clone(), renamed]).is_empty());
+        assert!(find_duplicate_boards(&[kept, unrelated]).is_empty());
+    }
+
+    #[test]
+    fn merging_drops_identical_cards_and_flags_diverged_ones() {
+        let (kept, copy) = sprint_boards();
+        let merge = merge_boards(&kept, &copy);
+        // The identical "Login page" is dropped, the edited "Release notes" is kept twice
+        assert_eq!(merge.dropped_cards, 1);
+        assert_eq!(merge.added_cards.len(), 2);
+        assert_eq!(merge.merged_board.cards.len(), 4);
+        assert_eq!(merge.flagged_cards.len(), 1);
+        let flagged_names = merge
+            .merged_board
+            .cards
+            .get_all_cards()
+            .iter()
+            .filter(|card| card.tags.iter().any(|tag| tag == DIVERGED_CARD_TAG))
+            .map(|card| card.name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(flagged_names, vec!["Release notes", "Release notes"]);
+    }
+}
@@ -0,0 +1,313 @@
+//! Turning the open issues of a GitHub repository into cards, and folding a later fetch into the
+//! cards an earlier import made without overwriting what was edited locally since
+//!
+//! ```
+//! use rust_kanban::model::{
+//!     date_time_format::DateTimeFormat,
+//!     github_issues::{import_github_issues, parse_github_issues_page},
+//!     kanban::Boards,
+//! };
+//!
+//! let page = r#"[
+//!     {"number": 1, "title": "Crash on start", "body": "Panics", "html_url": "https://github.com/o/r/issues/1",
+//!      "labels": [{"name": "bug"}], "milestone": null},
+//!     {"number": 2, "title": "Bump deps", "html_url": "https://github.com/o/r/pull/2",
+//!      "pull_request": {}}
+//! ]"#;
+//! let (issues, failures) = parse_github_issues_page(page)?;
+//! assert_eq!(issues.len(), 1);
+//! assert!(failures.is_empty());
+//!
+//! let mut boards = Boards::default();
+//! let import = import_github_issues(&mut boards, &issues, "Backlog", DateTimeFormat::default());
+//! assert_eq!(import.added_cards.len(), 1);
+//! assert!(import.created_board.is_some());
+//!
+//! // Running it again finds the card by its link and leaves it be
+//! let import = import_github_issues(&mut boards, &issues, "Backlog", DateTimeFormat::default());
+//! assert!(import.added_cards.is_empty());
+//! assert_eq!(import.unchanged_cards, 1);
+//! # Ok::<(), String>(())
+//! ```
+
+use crate::model::{
+    date_time_format::DateTimeFormat,
+    ids::generate_id,
+    kanban::{Board, Boards, Card, CardPriority, ImportHash},
+};
+use chrono::DateTime;
+use serde::Deserialize;
+use serde_json::Value;
+
+/// The parts of an issue from GitHub's REST api that have somewhere to go on a card
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct GithubIssue {
+    pub number: u64,
+    pub title: String,
+    #[serde(default)]
+    pub body: Option<String>,
+    pub html_url: String,
+    #[serde(default)]
+    pub labels: Vec<GithubLabel>,
+    #[serde(default)]
+    pub milestone: Option<GithubMilestone>,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct GithubLabel {
+    pub name: String,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct GithubMilestone {
+    #[serde(default)]
+    pub due_on: Option<String>,
+}
+
+/// An issue that could not be read or imported, shown in the summary after the import
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GithubImportFailure {
+    /// "#12" when the issue number could be read, the position in the page otherwise
+    pub issue: String,
+    pub reason: String,
+}
+
+/// What an import changed, the boards passed in already have these changes
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct GithubImport {
+    /// Set when no board had the target name yet, the board holds none of the added cards
+    pub created_board: Option<Board>,
+    pub target_board_id: (u64, u64),
+    pub added_cards: Vec<Card>,
+    /// The card before and after the update with the id of the board it is on
+    pub updated_cards: Vec<(Card, Card, (u64, u64))>,
+    pub unchanged_cards: usize,
+    /// Fields that changed on GitHub but were left alone because they were also edited locally
+    pub kept_local_edits: usize,
+}
+
+impl GithubImport {
+    /// One line for a toast or the terminal
+    pub fn summary(&self) -> String {
+        let mut summary = format!(
+            "{} added, {} updated, {} unchanged",
+            self.added_cards.len(),
+            self.updated_cards.len(),
+            self.unchanged_cards
+        );
+        if self.kept_local_edits > 0 {
+            summary.push_str(&format!(
+                ", {} local edit{} kept",
+                self.kept_local_edits,
+                if self.kept_local_edits == 1 { "" } else { "s" }
+            ));
+        }
+        summary
+    }
+}
+
+/// Checks that a repository is written as "owner/name"
+pub fn parse_github_repo(repo: &str) -> Result<(String, String), String> {
+    let is_valid_part = |part: &str| {
+        !part.is_empty()
+            && part
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_' || c == '.')
+    };
+    match repo.trim().split_once('/') {
+        Some((owner, name)) if is_valid_part(owner) && is_valid_part(name) => {
+            Ok((owner.to_string(), name.to_string()))
+        }
+        _ => Err(format!(
+            "'{}' is not a GitHub repository, expected owner/name",
+            repo.trim()
+        )),
+    }
+}
+
+/// Reads one page of the issues endpoint. Pull requests are listed there too and are skipped,
+/// an issue that does not parse is reported as a failure instead of failing the whole page
+pub fn parse_github_issues_page(
+    json: &str,
+) -> Result<(Vec<GithubIssue>, Vec<GithubImportFailure>), String> {
+    let items: Vec<Value> = serde_json::from_str(json)
+        .map_err(|e| format!("GitHub returned an unexpected response: {}", e))?;
+    let mut issues = Vec::new();
+    let mut failures = Vec::new();
+    for (index, item) in items.into_iter().enumerate() {
+        if item.get("pull_request").is_some() {
+            continue;
+        }
+        let issue_name = match item["number"].as_u64() {
+            Some(number) => format!("#{}", number),
+            None => format!("item {} of the page", index + 1),
+        };
+        match serde_json::from_value::<GithubIssue>(item) {
+            Ok(issue) => issues.push(issue),
+            Err(e) => failures.push(GithubImportFailure {
+                issue: issue_name,
+                reason: e.to_string(),
+            }),
+        }
+    }
+    Ok((issues, failures))
+}
+
+// Milestones are due at a time in UTC, they are shown in local time like the rest of the app
+fn convert_due_date(due_on: &str, date_time_format: DateTimeFormat) -> Option<String> {
+    let due_on = DateTime::parse_from_rfc3339(due_on).ok()?;
+    let date_time_format = DateTimeFormat::add_time_to_date_format(date_time_format);
+    Some(
+        due_on
+            .with_timezone(&chrono::Local)
+            .naive_local()
+            .format(date_time_format.to_parser_string())
+            .to_string(),
+    )
+}
+
+/// The card an issue turns into, the issue url is its only link
+pub fn issue_to_card(issue: &GithubIssue, date_time_format: DateTimeFormat) -> Card {
+    let mut tags = Vec::new();
+    for label in &issue.labels {
+        let tag = label.name.trim().to_string();
+        if !tag.is_empty() && !tags.contains(&tag) {
+            tags.push(tag);
+        }
+    }
+    let due_date = issue
+        .milestone
+        .as_ref()
+        .and_then(|milestone| milestone.due_on.as_deref())
+        .and_then(|due_on| convert_due_date(due_on, date_time_format))
+        .unwrap_or_default();
+    let mut card = Card::new(
+        issue.title.trim(),
+        issue.body.as_deref().unwrap_or_default().trim(),
+        &due_date,
+        CardPriority::Low,
+        tags,
+        Vec::new(),
+        date_time_format,
+    );
+    card.links = vec![issue.html_url.clone()];
+    card.import_hash = Some(ImportHash::of(&card));
+    card
+}
+
+// Copies the fields of the fresh card that were not edited locally, returns how many fields had
+// to be kept because they were
+fn update_imported_card(card: &mut Card, issue_card: &Card) -> usize {
+    let issue_hash = ImportHash::of(issue_card);
+    let Some(last_import_hash) = card.import_hash else {
+        // Linked by hand, everything on it counts as a local edit
+        return usize::from(
+            card.name != issue_card.name
+                || card.description != issue_card.description
+                || card.due_date != issue_card.due_date
+                || card.tags != issue_card.tags,
+        );
+    };
+    let current_hash = ImportHash::of(card);
+    let mut kept_local_edits = 0;
+    if issue_hash.name != last_import_hash.name {
+        if current_hash.name == last_import_hash.name {
+            card.name.clone_from(&issue_card.name);
+        } else {
+            kept_local_edits += 1;
+        }
+    }
+    if issue_hash.description != last_import_hash.description {
+        if current_hash.description == last_import_hash.description {
+            card.description.clone_from(&issue_card.description);
+        } else {
+            kept_local_edits += 1;
+        }
+    }
+    if issue_hash.due_date != last_import_hash.due_date {
+        if current_hash.due_date == last_import_hash.due_date {
+            card.due_date.clone_from(&issue_card.due_date);
+        } else {
+            kept_local_edits += 1;
+        }
+    }
+    if issue_hash.tags != last_import_hash.tags {
+        if current_hash.tags == last_import_hash.tags {
+            card.tags.clone_from(&issue_card.tags);
+        } else {
+            kept_local_edits += 1;
+        }
+    }
+    card.import_hash = Some(issue_hash);
+    kept_local_edits
+}
+
+/// Adds a card for every issue no card links to yet, to the board named `board_name` which is
+/// made when missing. Cards that already link to an issue are updated wherever they are, only in
+/// the fields nobody edited locally since the last import
+pub fn import_github_issues(
+    boards: &mut Boards,
+    issues: &[GithubIssue],
+    board_name: &str,
+    date_time_format: DateTimeFormat,
+) -> GithubImport {
+    let mut import = GithubImport::default();
+    let modified_at = chrono::Local::now()
+        .format(date_time_format.to_parser_string())
+        .to_string();
+
+    let mut new_cards = Vec::new();
+    for issue in issues {
+        let issue_card = issue_to_card(issue, date_time_format);
+        let linked_card = boards.get_mut_boards().iter_mut().find_map(|board| {
+            let board_id = board.id;
+            board
+                .cards
+                .get_mut_all_cards()
+                .iter_mut()
+                .find(|card| card.links.contains(&issue.html_url))
+                .map(|card| (card, board_id))
+        });
+        match linked_card {
+            Some((card, board_id)) => {
+                let old_card = card.clone();
+                import.kept_local_edits += update_imported_card(card, &issue_card);
+                if *card == old_card {
+                    import.unchanged_cards += 1;
+                } else {
+                    card.date_modified.clone_from(&modified_at);
+                    import
+                        .updated_cards
+                        .push((old_card, card.clone(), board_id));
+                }
+            }
+            None => new_cards.push(issue_card),
+        }
+    }
+
+    let target_board_id = match boards
+        .get_boards()
+        .iter()
+        .find(|board| board.name == board_name)
+    {
+        Some(board) => board.id,
+        None => {
+            let mut board = Board::new(board_name, "Imported from GitHub issues");
+            board.id = generate_id(|id| boards.contains_id(id));
+            import.created_board = Some(board.clone());
+            let board_id = board.id;
+            boards.add_board(board);
+            board_id
+        }
+    };
+    import.target_board_id = target_board_id;
+    for mut card in new_cards {
+        card.id = generate_id(|id| boards.contains_id(id));
+        card.import_hash = Some(ImportHash::of(&card));
+        if let Some(board) = boards.get_mut_board_with_id(target_board_id) {
+            board.cards.add_card(card.clone());
+            import.added_cards.push(card);
+        }
+    }
+    import
+}
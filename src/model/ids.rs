@@ -0,0 +1,30 @@
+use uuid::Uuid;
+
+/// The only place board and card ids are made. They are random so a clash is practically
+/// impossible, `is_taken` still gets a say so a save never holds the same id twice
+pub fn generate_id(is_taken: impl Fn((u64, u64)) -> bool) -> (u64, u64) {
+    loop {
+        let id = Uuid::new_v4().as_u64_pair();
+        if !is_taken(id) {
+            return id;
+        }
+    }
+}
+
+/// FNV-1a, unlike the std hasher it gives the same result in every build and on every
+/// platform, so hashes can be compared with ones taken earlier
+pub fn stable_hash(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ *byte as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+/// How ids are written for people and other tools, the same form the markdown mirror uses
+pub fn format_id(id: (u64, u64)) -> String {
+    format!("{}-{}", id.0, id.1)
+}
+
+/// The last six hex digits of the id, enough to tell cards apart on a card face
+pub fn format_short_id(id: (u64, u64)) -> String {
+    format!("#{:06x}", id.0 & 0xff_ffff)
+}
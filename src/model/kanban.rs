@@ -0,0 +1,1560 @@
+use crate::{
+    constants::{CONFLICT_MARKERS, FIELD_NA, FIELD_NOT_SET},
+    model::{
+        date_time_format::{parse_date_time, DateTimeFormat},
+        ids::{generate_id, stable_hash},
+        text_color::TextColorOptions,
+    },
+};
+use chrono::NaiveDate;
+use log::warn;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::{collections::HashMap, fmt};
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Board {
+    /// Color for the border and title of the board in the kanban view, None uses the theme
+    #[serde(default)]
+    pub accent_color: Option<TextColorOptions>,
+    /// Hidden from the kanban view unless archived boards are shown, the cards are kept as is
+    #[serde(default)]
+    pub archived: bool,
+    /// Keeps the cards sorted by priority after every change, moving cards by hand is refused
+    #[serde(default)]
+    pub auto_sort_by_priority: bool,
+    pub cards: Cards,
+    pub description: String,
+    /// Made once when the board is created and never changed after, moves, edits, undo and redo
+    /// all keep it so other tools can refer to the board
+    pub id: (u64, u64),
+    pub name: String,
+    /// Free form notes kept out of the board previews and the markdown mirror
+    #[serde(default)]
+    pub notes: String,
+    /// The statuses cards on this board move through, empty for the built in Active, Complete and
+    /// Stale, see [`CustomStatus`]
+    #[serde(default)]
+    pub statuses: Vec<CustomStatus>,
+}
+
+impl Board {
+    pub fn new(name: &str, description: &str) -> Self {
+        Self {
+            accent_color: None,
+            id: generate_id(|_| false),
+            name: name.to_owned(),
+            description: description.to_owned(),
+            cards: Cards::default(),
+            notes: String::new(),
+            archived: false,
+            auto_sort_by_priority: false,
+            statuses: Vec::new(),
+        }
+    }
+
+    /// The statuses cards on this board can be set to, the built in ones unless the board has
+    /// its own
+    pub fn get_status_selections(&self) -> Vec<StatusSelection> {
+        if self.statuses.is_empty() {
+            CardStatus::all()
+                .into_iter()
+                .map(|card_status| StatusSelection {
+                    card_status,
+                    custom_status: None,
+                })
+                .collect()
+        } else {
+            self.statuses
+                .iter()
+                .map(|status| StatusSelection {
+                    card_status: status.card_status(),
+                    custom_status: Some(status.name.clone()),
+                })
+                .collect()
+        }
+    }
+
+    /// What asking for a built in status means on this board. With custom statuses that is the
+    /// one with the same name, else the first that counts as complete for Complete or the first
+    /// that does not for Active, None when nothing matches
+    pub fn find_status_selection(&self, card_status: &CardStatus) -> Option<StatusSelection> {
+        let selections = self.get_status_selections();
+        let status_name = card_status.to_string();
+        selections
+            .iter()
+            .find(|selection| selection.to_string().eq_ignore_ascii_case(&status_name))
+            .or_else(|| match card_status {
+                CardStatus::Complete => selections
+                    .iter()
+                    .find(|selection| selection.card_status == CardStatus::Complete),
+                CardStatus::Active => selections
+                    .iter()
+                    .find(|selection| selection.card_status == CardStatus::Active),
+                CardStatus::Stale | CardStatus::Archived => None,
+            })
+            .cloned()
+    }
+
+    /// Points every card at a status the board has. Cards with a custom status the board no longer
+    /// has fall back to the first one, cards without one get the status matching their built in
+    /// one and on boards with the built in statuses custom ones are dropped
+    pub fn fix_card_statuses(&mut self) {
+        if self.statuses.is_empty() {
+            for card in self.cards.get_mut_all_cards() {
+                card.custom_status = None;
+            }
+            return;
+        }
+        let selections = self.get_status_selections();
+        let mut cards = std::mem::take(&mut self.cards);
+        for card in cards.get_mut_all_cards() {
+            let current_status = if card.card_status == CardStatus::Archived {
+                card.status_before_archive
+                    .clone()
+                    .unwrap_or(CardStatus::Active)
+            } else {
+                card.card_status.clone()
+            };
+            let selection = match &card.custom_status {
+                Some(custom_status) => selections
+                    .iter()
+                    .find(|selection| selection.custom_status.as_ref() == Some(custom_status))
+                    .cloned()
+                    .unwrap_or_else(|| {
+                        warn!(
+                            "Card \"{}\" on board \"{}\" has the status \"{}\" that the board does not have, moving it to \"{}\"",
+                            card.name, self.name, custom_status, selections[0]
+                        );
+                        selections[0].clone()
+                    }),
+                None => self
+                    .find_status_selection(&current_status)
+                    .unwrap_or_else(|| selections[0].clone()),
+            };
+            card.custom_status = selection.custom_status;
+            if card.card_status == CardStatus::Archived {
+                card.status_before_archive = Some(selection.card_status);
+            } else {
+                card.card_status = selection.card_status;
+            }
+        }
+        self.cards = cards;
+    }
+
+    /// A copy to start a new project from, with " (copy)" after the name and new ids for the
+    /// board and every card. The copy is never archived, the cards, statuses and notes are kept
+    /// as they are
+    pub fn duplicate(&self, is_id_taken: impl Fn((u64, u64)) -> bool) -> Self {
+        let mut duplicate = self.with_new_ids(is_id_taken);
+        duplicate.name = format!("{} (copy)", self.name);
+        duplicate.archived = false;
+        duplicate
+    }
+
+    /// The same board with new ids for the board and every card, none of them taken or shared
+    ///
+    /// ```
+    /// use rust_kanban::model::kanban::{Board, Card};
+    ///
+    /// let mut board = Board::new("Imported", "");
+    /// board.cards.add_card(Card::default());
+    /// board.cards.add_card(Card::default());
+    /// let copy = board.with_new_ids(|id| id == board.id);
+    /// assert_ne!(copy.id, board.id);
+    /// assert_eq!(copy.name, board.name);
+    /// let card_ids = copy.cards.get_all_card_ids();
+    /// assert_ne!(card_ids[0], card_ids[1]);
+    /// assert!(!card_ids.contains(&copy.id));
+    /// ```
+    pub fn with_new_ids(&self, is_id_taken: impl Fn((u64, u64)) -> bool) -> Self {
+        let mut new_ids: Vec<(u64, u64)> = vec![];
+        let mut next_id = || {
+            let id = generate_id(|id| is_id_taken(id) || new_ids.contains(&id));
+            new_ids.push(id);
+            id
+        };
+        let mut board = self.clone();
+        board.id = next_id();
+        for card in board.cards.get_mut_all_cards() {
+            card.id = next_id();
+        }
+        board
+    }
+
+    /// Stable sort of the cards, cards that compare equal keep their current order. Statuses
+    /// follow the order the board lists them in and cards without a due date go last
+    ///
+    /// ```
+    /// use rust_kanban::model::kanban::{Board, Card, CardPriority, CardSortOrder};
+    ///
+    /// let mut board = Board::new("Sprint", "");
+    /// for (name, priority, due_date) in [
+    ///     ("write docs", CardPriority::Low, "Not Set"),
+    ///     ("Fix crash", CardPriority::High, "2024/05/10"),
+    ///     ("Add tests", CardPriority::Medium, "2024/03/01"),
+    /// ] {
+    ///     let mut card = Card::default();
+    ///     card.name = name.to_string();
+    ///     card.priority = priority;
+    ///     card.due_date = due_date.to_string();
+    ///     board.cards.add_card(card);
+    /// }
+    /// let card_names = |board: &Board| {
+    ///     board.cards.get_all_cards().iter().map(|c| c.name.clone()).collect::<Vec<String>>()
+    /// };
+    ///
+    /// board.sort_cards(CardSortOrder::Priority);
+    /// assert_eq!(card_names(&board), ["Fix crash", "Add tests", "write docs"]);
+    /// board.sort_cards(CardSortOrder::DueDate);
+    /// assert_eq!(card_names(&board), ["Add tests", "Fix crash", "write docs"]);
+    /// board.sort_cards(CardSortOrder::Alphabetical);
+    /// assert_eq!(card_names(&board), ["Add tests", "Fix crash", "write docs"]);
+    /// ```
+    pub fn sort_cards(&mut self, order: CardSortOrder) {
+        let status_names = self
+            .get_status_selections()
+            .iter()
+            .map(|selection| selection.to_string())
+            .collect::<Vec<String>>();
+        let cards = self.cards.get_mut_all_cards();
+        match order {
+            CardSortOrder::Priority => cards.sort_by_key(|card| match card.priority {
+                CardPriority::High => 0,
+                CardPriority::Medium => 1,
+                CardPriority::Low => 2,
+            }),
+            CardSortOrder::Status => cards.sort_by_key(|card| {
+                let status_name = card.get_status_name();
+                status_names
+                    .iter()
+                    .position(|name| *name == status_name)
+                    .unwrap_or(status_names.len())
+            }),
+            CardSortOrder::DueDate => {
+                cards.sort_by_key(|card| {
+                    let due_date = parse_date_time(&card.due_date);
+                    (due_date.is_none(), due_date)
+                });
+            }
+            CardSortOrder::Alphabetical => cards.sort_by_key(|card| card.name.to_lowercase()),
+        }
+    }
+
+    /// Sorts the cards by priority when the board keeps them sorted, the sort is stable so cards of
+    /// the same priority keep their order
+    pub fn apply_auto_sort(&mut self) {
+        if self.auto_sort_by_priority {
+            self.sort_cards(CardSortOrder::Priority);
+        }
+    }
+
+    /// Where the card ends up once it has the given priority, None unless the board keeps its
+    /// cards sorted by priority and holds the card
+    pub fn get_auto_sorted_index(
+        &self,
+        card_id: (u64, u64),
+        priority: &CardPriority,
+    ) -> Option<usize> {
+        if !self.auto_sort_by_priority {
+            return None;
+        }
+        let mut board = self.clone();
+        board.cards.get_mut_card_with_id(card_id)?.priority = priority.clone();
+        board.apply_auto_sort();
+        board.cards.get_card_index(card_id)
+    }
+
+    /// Archived cards are never displayed, completed ones only when they are not hidden
+    pub fn get_displayable_cards(&self, hide_completed: bool) -> Cards {
+        self.cards
+            .get_all_cards()
+            .iter()
+            .filter(|card| card.card_status.is_displayable(hide_completed))
+            .cloned()
+            .collect()
+    }
+
+    /// Hash of the serialized board, fields serialize in declaration order so equal boards
+    /// always hash the same
+    pub fn content_hash(&self) -> u64 {
+        stable_hash(&serde_json::to_vec(self).unwrap_or_default())
+    }
+
+    /// Sum of the card estimates, None when no card on the board has one
+    pub fn get_total_estimate(&self) -> Option<f32> {
+        self.cards
+            .get_all_cards()
+            .iter()
+            .filter_map(|card| card.estimate)
+            .reduce(|total, estimate| total + estimate)
+    }
+
+    /// Estimate sums per status in the order the board lists its statuses, statuses none of
+    /// whose cards have an estimate are left out
+    pub fn get_estimate_by_status(&self) -> Vec<(String, f32)> {
+        let mut estimates = self
+            .get_status_selections()
+            .iter()
+            .map(|status_selection| (status_selection.to_string(), None))
+            .collect::<Vec<(String, Option<f32>)>>();
+        for card in self.cards.get_all_cards() {
+            let Some(estimate) = card.estimate else {
+                continue;
+            };
+            let status_name = card.get_status_name();
+            let status_index = match estimates.iter().position(|(name, _)| *name == status_name) {
+                Some(status_index) => status_index,
+                None => {
+                    estimates.push((status_name, None));
+                    estimates.len() - 1
+                }
+            };
+            let status_estimate = &mut estimates[status_index].1;
+            *status_estimate = Some(status_estimate.unwrap_or_default() + estimate);
+        }
+        estimates
+            .into_iter()
+            .filter_map(|(status_name, estimate)| estimate.map(|estimate| (status_name, estimate)))
+            .collect()
+    }
+
+    /// Estimated and actual minutes summed over the cards that have them
+    pub fn get_total_minutes(&self) -> (u32, u32) {
+        self.cards
+            .get_all_cards()
+            .iter()
+            .fold((0, 0), |(estimated, actual), card| {
+                (
+                    estimated.saturating_add(card.estimated_minutes.unwrap_or_default()),
+                    actual.saturating_add(card.actual_minutes.unwrap_or_default()),
+                )
+            })
+    }
+
+    pub fn get_completed_cards_count(&self) -> usize {
+        self.cards
+            .get_all_cards()
+            .iter()
+            .filter(|card| card.card_status == CardStatus::Complete)
+            .count()
+    }
+
+    /// Reads a board from a save, fields added after the first release are optional
+    ///
+    /// ```
+    /// use rust_kanban::model::{kanban::Board, text_color::TextColorOptions};
+    /// use serde_json::json;
+    ///
+    /// let old_save = json!({ "name": "Inbox", "description": "", "cards": [] });
+    /// let mut board = Board::from_json(&old_save).unwrap();
+    /// assert_eq!(board.accent_color, None);
+    ///
+    /// board.accent_color = Some(TextColorOptions::Cyan);
+    /// let saved = serde_json::to_value(&board).unwrap();
+    /// assert_eq!(
+    ///     Board::from_json(&saved).unwrap().accent_color,
+    ///     Some(TextColorOptions::Cyan)
+    /// );
+    /// ```
+    pub fn from_json(value: &Value) -> Result<Self, String> {
+        let id = match value["id"].as_array() {
+            Some(id) => {
+                let mut temp_id = (0, 0);
+                let id_1 = match id[0].as_u64() {
+                    Some(id_1) => id_1,
+                    None => {
+                        temp_id = generate_id(|_| false);
+                        temp_id.0
+                    }
+                };
+                let id_2 = match id[1].as_u64() {
+                    Some(id_2) => id_2,
+                    None => {
+                        if temp_id == (0, 0) {
+                            temp_id = generate_id(|_| false);
+                        }
+                        temp_id.1
+                    }
+                };
+                (id_1, id_2)
+            }
+            None => {
+                let temp_id = generate_id(|_| false);
+                (temp_id.0, temp_id.1)
+            }
+        };
+        let name = match value["name"].as_str() {
+            Some(name) => name,
+            None => return Err("board name is invalid for board".to_string()),
+        };
+        let description = match value["description"].as_str() {
+            Some(description) => description,
+            None => return Err("board description is invalid for board".to_string()),
+        };
+        // Mainly for backwards compatibility, recent versions use value["cards"]["cards"] due to Cards being a struct
+        let cards = match value["cards"].as_array() {
+            Some(cards) => cards
+                .iter()
+                .map(Card::from_json)
+                .collect::<Result<Cards, String>>()?,
+            None => match value["cards"]["cards"].as_array() {
+                Some(cards) => cards
+                    .iter()
+                    .map(Card::from_json)
+                    .collect::<Result<Cards, String>>()?,
+                None => return Err("board cards is invalid for board".to_string()),
+            },
+        };
+
+        let notes = value["notes"].as_str().unwrap_or_default();
+        let archived = value["archived"].as_bool().unwrap_or_default();
+        let auto_sort_by_priority = value["auto_sort_by_priority"].as_bool().unwrap_or_default();
+        let statuses = match value.get("statuses") {
+            Some(statuses) => serde_json::from_value(statuses.clone())
+                .map_err(|_| "board statuses is invalid for board".to_string())?,
+            None => Vec::new(),
+        };
+        let accent_color = match value.get("accent_color") {
+            Some(accent_color) => serde_json::from_value(accent_color.clone())
+                .map_err(|_| "board accent_color is invalid for board".to_string())?,
+            None => None,
+        };
+
+        let mut board = Self {
+            accent_color,
+            id,
+            name: name.to_string(),
+            description: description.to_string(),
+            cards,
+            notes: notes.to_string(),
+            archived,
+            auto_sort_by_priority,
+            statuses,
+        };
+        board.fix_card_statuses();
+        Ok(board)
+    }
+}
+
+impl Default for Board {
+    fn default() -> Self {
+        Self {
+            accent_color: None,
+            archived: false,
+            auto_sort_by_priority: false,
+            cards: Cards::default(),
+            description: String::from("Default Board Description"),
+            id: generate_id(|_| false),
+            name: String::from("Default Board"),
+            notes: String::new(),
+            statuses: Vec::new(),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum BoardSwapError {
+    IndexOutOfBounds,
+}
+
+impl fmt::Display for BoardSwapError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BoardSwapError::IndexOutOfBounds => write!(f, "Index out of bounds"),
+        }
+    }
+}
+
+impl std::error::Error for BoardSwapError {}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct Boards {
+    boards: Vec<Board>,
+}
+
+impl Boards {
+    pub fn add_board(&mut self, board: Board) {
+        self.boards.push(board);
+    }
+    /// Archived cards with the id of their board, grouped by board in board order
+    pub fn get_archived_cards(&self) -> Vec<((u64, u64), &Card)> {
+        self.boards
+            .iter()
+            .flat_map(|board| {
+                board
+                    .cards
+                    .get_all_cards()
+                    .iter()
+                    .filter(|card| card.card_status == CardStatus::Archived)
+                    .map(move |card| (board.id, card))
+            })
+            .collect()
+    }
+    pub fn get_board_hashes(&self) -> HashMap<(u64, u64), u64> {
+        self.boards
+            .iter()
+            .map(|board| (board.id, board.content_hash()))
+            .collect()
+    }
+    /// Whether any board or card in the save already uses `id`
+    pub fn contains_id(&self, id: (u64, u64)) -> bool {
+        self.boards.iter().any(|board| {
+            board.id == id || board.cards.get_all_cards().iter().any(|card| card.id == id)
+        })
+    }
+    /// An id for a new board or card that nothing in the save uses yet
+    pub fn generate_unused_id(&self) -> (u64, u64) {
+        generate_id(|id| self.contains_id(id))
+    }
+    pub fn get_board_with_id(&self, board_id: (u64, u64)) -> Option<&Board> {
+        self.boards.iter().find(|b| b.id == board_id)
+    }
+    pub fn get_mut_board_with_id(&mut self, board_id: (u64, u64)) -> Option<&mut Board> {
+        self.boards.iter_mut().find(|b| b.id == board_id)
+    }
+    pub fn get_board_with_index(&self, index: usize) -> Option<&Board> {
+        self.boards.get(index)
+    }
+    pub fn get_mut_board_with_index(&mut self, index: usize) -> Option<&mut Board> {
+        self.boards.get_mut(index)
+    }
+    pub fn get_boards(&self) -> &Vec<Board> {
+        &self.boards
+    }
+    pub fn get_mut_boards(&mut self) -> &mut Vec<Board> {
+        &mut self.boards
+    }
+    pub fn set_boards(&mut self, boards: Boards) {
+        self.boards = boards.boards;
+    }
+    pub fn is_empty(&self) -> bool {
+        self.boards.is_empty()
+    }
+    pub fn get_first_board_id(&self) -> Option<(u64, u64)> {
+        self.boards.first().map(|b| b.id)
+    }
+    pub fn get_all_board_ids(&self) -> Vec<(u64, u64)> {
+        self.boards.iter().map(|b| b.id).collect()
+    }
+    pub fn get_board_index(&self, board_id: (u64, u64)) -> Option<usize> {
+        self.boards.iter().position(|b| b.id == board_id)
+    }
+    pub fn len(&self) -> usize {
+        self.boards.len()
+    }
+    pub fn remove_board_with_id(&mut self, board_id: (u64, u64)) {
+        self.boards.retain(|b| b.id != board_id);
+    }
+    pub fn reset(&mut self) {
+        self.boards.clear();
+    }
+    pub fn find_board_with_card_id(&self, card_id: (u64, u64)) -> Option<(usize, &Board)> {
+        self.boards
+            .iter()
+            .enumerate()
+            .find(|(_, b)| b.cards.get_card_with_id(card_id).is_some())
+    }
+    /// Case insensitive lookup of a card by its trimmed name, returns (board_id, card_id)
+    pub fn find_card_with_name(&self, card_name: &str) -> Option<((u64, u64), (u64, u64))> {
+        let card_name = card_name.trim().to_lowercase();
+        self.boards.iter().find_map(|board| {
+            board
+                .cards
+                .get_all_cards()
+                .iter()
+                .find(|card| card.name.trim().to_lowercase() == card_name)
+                .map(|card| (board.id, card.id))
+        })
+    }
+    /// (board_id, card_id) of every card whose description still has merge conflict markers
+    pub fn get_cards_with_conflict_markers(&self) -> Vec<((u64, u64), (u64, u64))> {
+        self.boards
+            .iter()
+            .flat_map(|board| {
+                board
+                    .cards
+                    .get_all_cards()
+                    .iter()
+                    .filter(|card| card.has_conflict_markers())
+                    .map(move |card| (board.id, card.id))
+            })
+            .collect()
+    }
+    pub fn swap(&mut self, index_1: usize, index_2: usize) -> Result<(), BoardSwapError> {
+        if index_1 >= self.boards.len() || index_2 >= self.boards.len() {
+            return Err(BoardSwapError::IndexOutOfBounds);
+        }
+        self.boards.swap(index_1, index_2);
+        Ok(())
+    }
+}
+
+impl From<Vec<Board>> for Boards {
+    fn from(boards: Vec<Board>) -> Self {
+        Self { boards }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum CardStatus {
+    Active,
+    Complete,
+    Stale,
+    /// Kept in the save file but off the boards, see [`Card::archive`]
+    Archived,
+}
+
+impl fmt::Display for CardStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CardStatus::Active => write!(f, "Active"),
+            CardStatus::Complete => write!(f, "Complete"),
+            CardStatus::Stale => write!(f, "Stale"),
+            CardStatus::Archived => write!(f, "Archived"),
+        }
+    }
+}
+
+impl CardStatus {
+    /// The statuses a card can be set to, archiving has its own action
+    pub fn all() -> Vec<CardStatus> {
+        vec![CardStatus::Active, CardStatus::Complete, CardStatus::Stale]
+    }
+
+    /// Only completed cards carry a completion date, every other status clears it
+    pub fn get_date_completed(&self, date_time_format: DateTimeFormat) -> String {
+        if *self == CardStatus::Complete {
+            chrono::Local::now()
+                .format(date_time_format.to_parser_string())
+                .to_string()
+        } else {
+            FIELD_NOT_SET.to_string()
+        }
+    }
+
+    pub fn is_displayable(&self, hide_completed: bool) -> bool {
+        *self != CardStatus::Archived && !(hide_completed && *self == CardStatus::Complete)
+    }
+}
+
+/// A status a board defines in place of Active, Complete and Stale. Cards keep a built in status
+/// next to the custom one, Complete when the custom one counts as complete and Active otherwise,
+/// so completion dates and progress work as before and versions without custom statuses can still
+/// read the save
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct CustomStatus {
+    pub name: String,
+    #[serde(default)]
+    pub counts_as_complete: bool,
+}
+
+impl CustomStatus {
+    /// The built in status stored next to this one
+    pub fn card_status(&self) -> CardStatus {
+        if self.counts_as_complete {
+            CardStatus::Complete
+        } else {
+            CardStatus::Active
+        }
+    }
+}
+
+/// A status a card can be set to, custom_status is only set on boards with their own statuses
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StatusSelection {
+    pub card_status: CardStatus,
+    pub custom_status: Option<String>,
+}
+
+impl StatusSelection {
+    pub fn of(card: &Card) -> Self {
+        Self {
+            card_status: card.card_status.clone(),
+            custom_status: card.custom_status.clone(),
+        }
+    }
+
+    /// Sets the card to this status, the completion date only changes with the built in status
+    pub fn apply(&self, card: &mut Card, date_time_format: DateTimeFormat) {
+        if card.card_status != self.card_status {
+            card.date_completed = self.card_status.get_date_completed(date_time_format);
+            card.card_status = self.card_status.clone();
+        }
+        card.custom_status.clone_from(&self.custom_status);
+    }
+}
+
+impl fmt::Display for StatusSelection {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.custom_status {
+            Some(custom_status) if self.card_status != CardStatus::Archived => {
+                write!(f, "{}", custom_status)
+            }
+            _ => write!(f, "{}", self.card_status),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum CardPriority {
+    High,
+    Low,
+    Medium,
+}
+
+impl fmt::Display for CardPriority {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CardPriority::High => write!(f, "High"),
+            CardPriority::Low => write!(f, "Low"),
+            CardPriority::Medium => write!(f, "Medium"),
+        }
+    }
+}
+
+impl CardPriority {
+    pub fn all() -> Vec<CardPriority> {
+        vec![CardPriority::Low, CardPriority::Medium, CardPriority::High]
+    }
+}
+
+/// The orders the cards of a board can be sorted in, see [`Board::sort_cards`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CardSortOrder {
+    Priority,
+    Status,
+    DueDate,
+    Alphabetical,
+}
+
+impl fmt::Display for CardSortOrder {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CardSortOrder::Priority => write!(f, "Priority (High to Low)"),
+            CardSortOrder::Status => write!(f, "Status"),
+            CardSortOrder::DueDate => write!(f, "Due Date (Soonest First)"),
+            CardSortOrder::Alphabetical => write!(f, "Alphabetical"),
+        }
+    }
+}
+
+impl CardSortOrder {
+    pub fn all() -> Vec<CardSortOrder> {
+        vec![
+            CardSortOrder::Priority,
+            CardSortOrder::Status,
+            CardSortOrder::DueDate,
+            CardSortOrder::Alphabetical,
+        ]
+    }
+}
+
+/// A sub step of a card, ticked off on its own without changing the card status
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ChecklistItem {
+    pub text: String,
+    pub completed: bool,
+}
+
+impl ChecklistItem {
+    pub fn new(text: &str) -> Self {
+        Self {
+            text: text.to_string(),
+            completed: false,
+        }
+    }
+}
+
+/// Hashes of the fields an importer last wrote to a card, a field whose current value still
+/// hashes the same was not edited locally and can be overwritten by the next import
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ImportHash {
+    pub description: u64,
+    pub due_date: u64,
+    pub name: u64,
+    pub tags: u64,
+}
+
+impl ImportHash {
+    pub fn of(card: &Card) -> Self {
+        Self {
+            description: stable_hash(card.description.as_bytes()),
+            due_date: stable_hash(card.due_date.as_bytes()),
+            name: stable_hash(card.name.as_bytes()),
+            tags: stable_hash(card.tags.join("\n").as_bytes()),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Card {
+    /// Minutes spent on the card, None until time is logged
+    #[serde(default)]
+    pub actual_minutes: Option<u32>,
+    pub card_status: CardStatus,
+    #[serde(default)]
+    pub checklist: Vec<ChecklistItem>,
+    pub comments: Vec<String>,
+    /// The board's own status the card is in, None on boards with the built in statuses
+    #[serde(default)]
+    pub custom_status: Option<String>,
+    pub date_completed: String,
+    pub date_created: String,
+    pub date_modified: String,
+    pub description: String,
+    pub due_date: String,
+    /// Points or whatever unit the board plans in, None when the card was not estimated
+    #[serde(default)]
+    pub estimate: Option<f32>,
+    /// Minutes the card is expected to take, None when no time was planned
+    #[serde(default)]
+    pub estimated_minutes: Option<u32>,
+    #[serde(default)]
+    pub highlight_color: Option<TextColorOptions>,
+    /// Made once when the card is created and never changed after, see [`Board::id`]
+    pub id: (u64, u64),
+    /// Set on cards made by an import that can run again, see [`ImportHash`]
+    #[serde(default)]
+    pub import_hash: Option<ImportHash>,
+    /// Urls the card refers to, an imported card keeps the url of what it was imported from
+    #[serde(default)]
+    pub links: Vec<String>,
+    pub name: String,
+    pub priority: CardPriority,
+    /// When work on the card begins, None for cards that only have a due date
+    #[serde(default)]
+    pub start_date: Option<String>,
+    /// What restoring an archived card sets it back to, None for cards that are not archived
+    #[serde(default)]
+    pub status_before_archive: Option<CardStatus>,
+    pub tags: Vec<String>,
+    /// Who or what the card is blocked on, None when it is not waiting on anything
+    #[serde(default)]
+    pub waiting_on: Option<String>,
+}
+
+impl Card {
+    pub fn new(
+        name: &str,
+        description: &str,
+        due_date: &str,
+        priority: CardPriority,
+        tags: Vec<String>,
+        comments: Vec<String>,
+        date_time_format: DateTimeFormat,
+    ) -> Self {
+        let name = if name.is_empty() { FIELD_NOT_SET } else { name };
+        let description = if description.is_empty() {
+            FIELD_NOT_SET
+        } else {
+            description
+        };
+        let due_date = if due_date.is_empty() {
+            FIELD_NOT_SET
+        } else {
+            due_date
+        };
+        let priority = if priority.to_string().is_empty() {
+            CardPriority::Low
+        } else {
+            priority
+        };
+        let tags = if tags.is_empty() { Vec::new() } else { tags };
+        let comments = if comments.is_empty() {
+            Vec::new()
+        } else {
+            comments
+        };
+
+        let corrected_date_time_format = DateTimeFormat::add_time_to_date_format(date_time_format);
+
+        Self {
+            id: generate_id(|_| false),
+            name: name.to_string(),
+            description: description.to_string(),
+            date_created: chrono::Local::now()
+                .format(corrected_date_time_format.to_parser_string())
+                .to_string(),
+            date_modified: chrono::Local::now()
+                .format(corrected_date_time_format.to_parser_string())
+                .to_string(),
+            due_date: due_date.to_string(),
+            date_completed: FIELD_NA.to_string(),
+            priority,
+            card_status: CardStatus::Active,
+            checklist: Vec::new(),
+            custom_status: None,
+            tags,
+            comments,
+            actual_minutes: None,
+            estimate: None,
+            estimated_minutes: None,
+            highlight_color: None,
+            import_hash: None,
+            links: Vec::new(),
+            start_date: None,
+            status_before_archive: None,
+            waiting_on: None,
+        }
+    }
+
+    pub fn from_json(value: &Value) -> Result<Self, String> {
+        let id = match value["id"].as_array() {
+            Some(id) => {
+                let mut temp_id = (0, 0);
+                let id_1 = match id[0].as_u64() {
+                    Some(id_1) => id_1,
+                    None => {
+                        temp_id = generate_id(|_| false);
+                        temp_id.0
+                    }
+                };
+                let id_2 = match id[1].as_u64() {
+                    Some(id_2) => id_2,
+                    None => {
+                        if temp_id == (0, 0) {
+                            temp_id = generate_id(|_| false);
+                        }
+                        temp_id.1
+                    }
+                };
+                (id_1, id_2)
+            }
+            None => {
+                let temp_id = generate_id(|_| false);
+                (temp_id.0, temp_id.1)
+            }
+        };
+        let name = match value["name"].as_str() {
+            Some(name) => name,
+            None => return Err("card name is invalid for card".to_string()),
+        };
+        let description = match value["description"].as_str() {
+            Some(description) => description,
+            None => return Err("card description is invalid for card".to_string()),
+        };
+        let date_created = match value["date_created"].as_str() {
+            Some(date_created) => date_created,
+            None => return Err("card date_created is invalid for card".to_string()),
+        };
+        let date_modified = match value["date_modified"].as_str() {
+            Some(date_modified) => date_modified,
+            None => return Err("card date_modified is invalid for card".to_string()),
+        };
+        let due_date = match value["due_date"].as_str() {
+            Some(due_date) => due_date,
+            None => return Err("card due_date is invalid for card".to_string()),
+        };
+        let date_completed = match value["date_completed"].as_str() {
+            Some(date_completed) => date_completed,
+            None => return Err("card date_completed is invalid for card".to_string()),
+        };
+        let priority = match value["priority"].as_str() {
+            Some(priority) => match priority {
+                "Low" => CardPriority::Low,
+                "Medium" => CardPriority::Medium,
+                "High" => CardPriority::High,
+                _ => return Err("card priority is invalid for card".to_string()),
+            },
+            None => return Err("card priority is invalid for card".to_string()),
+        };
+        let card_status = match value["card_status"].as_str() {
+            Some(card_status) => match card_status {
+                "Active" => CardStatus::Active,
+                "Complete" => CardStatus::Complete,
+                "Stale" => CardStatus::Stale,
+                "Archived" => CardStatus::Archived,
+                _ => return Err("card card_status is invalid for card".to_string()),
+            },
+            None => return Err("card card_status is invalid for card".to_string()),
+        };
+        let tags = match value["tags"].as_array() {
+            Some(tags) => tags
+                .iter()
+                .map(|t| t.as_str().unwrap().to_string())
+                .collect(),
+            None => return Err("card tags is invalid for card".to_string()),
+        };
+        let comments = match value["comments"].as_array() {
+            Some(comments) => comments
+                .iter()
+                .map(|c| c.as_str().unwrap().to_string())
+                .collect(),
+            None => return Err("card comments is invalid for card".to_string()),
+        };
+        let checklist = match value.get("checklist") {
+            Some(checklist) => serde_json::from_value(checklist.clone())
+                .map_err(|_| "card checklist is invalid for card".to_string())?,
+            None => Vec::new(),
+        };
+        let start_date = value["start_date"]
+            .as_str()
+            .map(|start_date| start_date.to_string());
+        let estimate = match value.get("estimate") {
+            Some(estimate) => serde_json::from_value(estimate.clone())
+                .map_err(|_| "card estimate is invalid for card".to_string())?,
+            None => None,
+        };
+        let estimated_minutes = match value.get("estimated_minutes") {
+            Some(estimated_minutes) => serde_json::from_value(estimated_minutes.clone())
+                .map_err(|_| "card estimated_minutes is invalid for card".to_string())?,
+            None => None,
+        };
+        let actual_minutes = match value.get("actual_minutes") {
+            Some(actual_minutes) => serde_json::from_value(actual_minutes.clone())
+                .map_err(|_| "card actual_minutes is invalid for card".to_string())?,
+            None => None,
+        };
+        let highlight_color = match value.get("highlight_color") {
+            Some(highlight_color) => serde_json::from_value(highlight_color.clone())
+                .map_err(|_| "card highlight_color is invalid for card".to_string())?,
+            None => None,
+        };
+        let status_before_archive = match value.get("status_before_archive") {
+            Some(status_before_archive) => serde_json::from_value(status_before_archive.clone())
+                .map_err(|_| "card status_before_archive is invalid for card".to_string())?,
+            None => None,
+        };
+        let import_hash = match value.get("import_hash") {
+            Some(import_hash) => serde_json::from_value(import_hash.clone())
+                .map_err(|_| "card import_hash is invalid for card".to_string())?,
+            None => None,
+        };
+        let links = match value.get("links") {
+            Some(links) => serde_json::from_value(links.clone())
+                .map_err(|_| "card links is invalid for card".to_string())?,
+            None => Vec::new(),
+        };
+        let custom_status = value["custom_status"]
+            .as_str()
+            .map(|custom_status| custom_status.to_string());
+        let waiting_on = value["waiting_on"]
+            .as_str()
+            .map(|waiting_on| waiting_on.to_string());
+
+        Ok(Self {
+            id,
+            name: name.to_string(),
+            description: description.to_string(),
+            date_created: date_created.to_string(),
+            date_modified: date_modified.to_string(),
+            due_date: due_date.to_string(),
+            date_completed: date_completed.to_string(),
+            priority,
+            card_status,
+            checklist,
+            custom_status,
+            tags,
+            comments,
+            actual_minutes,
+            estimate,
+            estimated_minutes,
+            highlight_color,
+            import_hash,
+            links,
+            start_date,
+            status_before_archive,
+            waiting_on,
+        })
+    }
+}
+
+/// Reads an estimate typed by the user, both "2.5" and "2,5" are accepted and an empty value
+/// clears the estimate
+pub fn parse_estimate(value: &str) -> Result<Option<f32>, String> {
+    let value = value.trim();
+    if value.is_empty() || value == FIELD_NOT_SET {
+        return Ok(None);
+    }
+    let estimate = value
+        .replace(',', ".")
+        .parse::<f32>()
+        .ok()
+        .filter(|estimate| estimate.is_finite())
+        .ok_or_else(|| format!("Invalid estimate: {}", value))?;
+    if estimate < 0.0 {
+        return Err("Estimate must not be negative".to_string());
+    }
+    Ok(Some(estimate))
+}
+
+/// Takes `~5` or `=5` estimate tokens out of a quick-add card name, the last one wins. Words
+/// that only start with the marker, like "=>" or "~approx", stay part of the name
+///
+/// ```
+/// use rust_kanban::app::kanban::take_estimate_token;
+///
+/// assert_eq!(
+///     take_estimate_token("Write docs ~2,5"),
+///     Ok(("Write docs".to_string(), Some(2.5)))
+/// );
+/// assert_eq!(
+///     take_estimate_token("a => b"),
+///     Ok(("a => b".to_string(), None))
+/// );
+/// assert!(take_estimate_token("Fix bug =-3").is_err());
+/// ```
+pub fn take_estimate_token(name: &str) -> Result<(String, Option<f32>), String> {
+    let mut estimate = None;
+    let mut found_token = false;
+    let mut name_words = Vec::new();
+    for word in name.split_whitespace() {
+        let token_value = word
+            .strip_prefix('~')
+            .or_else(|| word.strip_prefix('='))
+            .filter(|value| {
+                value
+                    .replace(',', ".")
+                    .parse::<f32>()
+                    .is_ok_and(|value| value.is_finite())
+            });
+        match token_value {
+            Some(value) => {
+                estimate = parse_estimate(value)?;
+                found_token = true;
+            }
+            None => name_words.push(word),
+        }
+    }
+    if !found_token {
+        return Ok((name.to_string(), None));
+    }
+    Ok((name_words.join(" "), estimate))
+}
+
+/// Reads a number of minutes typed by the user, an empty value clears the field
+pub fn parse_minutes(value: &str) -> Result<Option<u32>, String> {
+    let value = value.trim();
+    if value.is_empty() || value == FIELD_NOT_SET {
+        return Ok(None);
+    }
+    value
+        .parse::<u32>()
+        .map(Some)
+        .map_err(|_| format!("Invalid number of minutes: {}", value))
+}
+
+/// Reads who a card is waiting on as typed by the user, an empty value clears the field
+pub fn parse_waiting_on(value: &str) -> Option<String> {
+    let value = value.trim();
+    if value.is_empty() || value == FIELD_NOT_SET {
+        None
+    } else {
+        Some(value.to_string())
+    }
+}
+
+/// Rounded to two decimals so sums like 0.1 + 0.2 do not show float noise
+pub fn format_estimate(estimate: f32) -> String {
+    let rounded = format!("{:.2}", estimate);
+    rounded
+        .trim_end_matches('0')
+        .trim_end_matches('.')
+        .to_string()
+}
+
+impl Card {
+    /// The custom status on boards that have their own statuses, the built in one otherwise
+    pub fn get_status_name(&self) -> String {
+        StatusSelection::of(self).to_string()
+    }
+    /// False when both dates are set and the start date is after the due date
+    pub fn has_valid_date_range(&self) -> bool {
+        let start_date = self.start_date.as_deref().and_then(parse_date_time);
+        let due_date = parse_date_time(&self.due_date);
+        match (start_date, due_date) {
+            (Some(start_date), Some(due_date)) => start_date <= due_date,
+            _ => true,
+        }
+    }
+    /// True when a description line starts with a conflict marker, the "=======" separator has to
+    /// be the whole line so markdown heading underlines are not mistaken for it
+    pub fn has_conflict_markers(&self) -> bool {
+        self.description.lines().any(|line| {
+            let line = line.trim_end();
+            line == CONFLICT_MARKERS[1]
+                || line.starts_with(CONFLICT_MARKERS[0])
+                || line.starts_with(CONFLICT_MARKERS[2])
+        })
+    }
+    /// (completed, total) checklist items, None when the card has no checklist
+    pub fn checklist_progress(&self) -> Option<(usize, usize)> {
+        if self.checklist.is_empty() {
+            return None;
+        }
+        let completed = self
+            .checklist
+            .iter()
+            .filter(|checklist_item| checklist_item.completed)
+            .count();
+        Some((completed, self.checklist.len()))
+    }
+
+    /// A copy to start the next piece of templated work from. It gets a new id, " (copy)" after the
+    /// name, no due or completion date, an unticked checklist and no time logged, everything else
+    /// including the status is kept
+    ///
+    /// ```
+    /// use rust_kanban::model::{
+    ///     date_time_format::DateTimeFormat,
+    ///     kanban::{Card, CardPriority, CardStatus},
+    /// };
+    ///
+    /// let mut card = Card::default();
+    /// card.name = "Weekly report".to_string();
+    /// card.due_date = "2024/06/01".to_string();
+    /// card.priority = CardPriority::High;
+    /// card.card_status = CardStatus::Stale;
+    /// card.tags = vec!["reports".to_string()];
+    /// let duplicate = card.duplicate(|id| id == card.id, DateTimeFormat::default());
+    /// assert_ne!(duplicate.id, card.id);
+    /// assert_eq!(duplicate.name, "Weekly report (copy)");
+    /// assert_eq!(duplicate.due_date, "Not Set");
+    /// assert_eq!(duplicate.priority, CardPriority::High);
+    /// assert_eq!(duplicate.card_status, CardStatus::Stale);
+    /// assert_eq!(duplicate.tags, card.tags);
+    /// ```
+    pub fn duplicate(
+        &self,
+        is_id_taken: impl Fn((u64, u64)) -> bool,
+        date_time_format: DateTimeFormat,
+    ) -> Self {
+        let now = chrono::Local::now()
+            .format(date_time_format.to_parser_string())
+            .to_string();
+        let mut duplicate = self.clone();
+        duplicate.id = generate_id(is_id_taken);
+        duplicate.name = format!("{} (copy)", self.name);
+        duplicate.due_date = FIELD_NOT_SET.to_string();
+        duplicate.date_completed = FIELD_NOT_SET.to_string();
+        duplicate.date_created.clone_from(&now);
+        duplicate.date_modified = now;
+        duplicate.actual_minutes = None;
+        duplicate.import_hash = None;
+        for checklist_item in duplicate.checklist.iter_mut() {
+            checklist_item.completed = false;
+        }
+        duplicate
+    }
+
+    /// Whole days from when the card was completed until `today`, falling back to when it was
+    /// last modified for cards without a usable completion date
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use rust_kanban::model::kanban::Card;
+    ///
+    /// let today = NaiveDate::from_ymd_opt(2024, 6, 30).unwrap();
+    /// let mut card = Card::default();
+    /// card.date_completed = "2024/06/01".to_string();
+    /// assert_eq!(card.days_since_completed(today), Some(29));
+    /// card.date_completed = "Not Set".to_string();
+    /// card.date_modified = "2024/06/20".to_string();
+    /// assert_eq!(card.days_since_completed(today), Some(10));
+    /// ```
+    pub fn days_since_completed(&self, today: NaiveDate) -> Option<i64> {
+        parse_date_time(&self.date_completed)
+            .or_else(|| parse_date_time(&self.date_modified))
+            .map(|date_time| today.signed_duration_since(date_time.date()).num_days())
+    }
+
+    /// Takes the card off the board, the status it had is kept for [`Card::restore_from_archive`]
+    pub fn archive(&mut self) {
+        if self.card_status == CardStatus::Archived {
+            return;
+        }
+        self.status_before_archive = Some(self.card_status.clone());
+        self.card_status = CardStatus::Archived;
+    }
+
+    /// Puts an archived card back with the status it had, Active when that is unknown
+    pub fn restore_from_archive(&mut self) {
+        if self.card_status != CardStatus::Archived {
+            return;
+        }
+        self.card_status = self
+            .status_before_archive
+            .take()
+            .unwrap_or(CardStatus::Active);
+    }
+}
+
+impl Default for Card {
+    fn default() -> Self {
+        Self {
+            actual_minutes: None,
+            card_status: CardStatus::Active,
+            checklist: Vec::new(),
+            comments: Vec::new(),
+            custom_status: None,
+            date_completed: FIELD_NOT_SET.to_string(),
+            date_created: chrono::Local::now()
+                .format(DateTimeFormat::default().to_parser_string())
+                .to_string(),
+            date_modified: chrono::Local::now()
+                .format(DateTimeFormat::default().to_parser_string())
+                .to_string(),
+            description: String::from("Default Card Description"),
+            due_date: FIELD_NOT_SET.to_string(),
+            estimate: None,
+            estimated_minutes: None,
+            highlight_color: None,
+            id: generate_id(|_| false),
+            import_hash: None,
+            links: Vec::new(),
+            name: String::from("Default Card"),
+            priority: CardPriority::Low,
+            start_date: None,
+            status_before_archive: None,
+            tags: Vec::new(),
+            waiting_on: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct Cards {
+    cards: Vec<Card>,
+}
+
+impl Cards {
+    pub fn add_card(&mut self, card: Card) {
+        self.cards.push(card);
+    }
+    pub fn add_card_at_index(&mut self, index: usize, card: Card) {
+        self.cards.insert(index, card);
+    }
+    pub fn get_card_with_id(&self, card_id: (u64, u64)) -> Option<&Card> {
+        self.cards.iter().find(|c| c.id == card_id)
+    }
+    pub fn get_mut_card_with_id(&mut self, card_id: (u64, u64)) -> Option<&mut Card> {
+        self.cards.iter_mut().find(|c| c.id == card_id)
+    }
+    pub fn get_card_with_index(&self, index: usize) -> Option<&Card> {
+        self.cards.get(index)
+    }
+    pub fn get_mut_card_with_index(&mut self, index: usize) -> Option<&mut Card> {
+        self.cards.get_mut(index)
+    }
+    pub fn get_all_cards(&self) -> &Vec<Card> {
+        &self.cards
+    }
+    pub fn get_all_card_ids(&self) -> Vec<(u64, u64)> {
+        self.cards.iter().map(|c| c.id).collect()
+    }
+    pub fn get_cards_with_range(&self, start: usize, end: usize) -> Cards {
+        self.cards[start..end].to_vec().into()
+    }
+    pub fn get_mut_all_cards(&mut self) -> &mut Vec<Card> {
+        &mut self.cards
+    }
+    pub fn set_cards(&mut self, cards: Cards) {
+        self.cards = cards.cards;
+    }
+    pub fn is_empty(&self) -> bool {
+        self.cards.is_empty()
+    }
+    pub fn get_first_card_id(&self) -> Option<(u64, u64)> {
+        self.cards.first().map(|c| c.id)
+    }
+    pub fn get_card_index(&self, card_id: (u64, u64)) -> Option<usize> {
+        self.cards.iter().position(|c| c.id == card_id)
+    }
+    pub fn len(&self) -> usize {
+        self.cards.len()
+    }
+    pub fn remove_card_with_id(&mut self, card_id: (u64, u64)) -> Option<Card> {
+        let index = self.cards.iter().position(|c| c.id == card_id)?;
+        Some(self.cards.remove(index))
+    }
+    pub fn reset(&mut self) {
+        self.cards.clear();
+    }
+    pub fn swap(&mut self, index_1: usize, index_2: usize) {
+        self.cards.swap(index_1, index_2);
+    }
+    /// Puts the cards in the order of the given ids, cards not in the list keep their relative
+    /// order after the listed ones
+    pub fn reorder(&mut self, card_ids: &[(u64, u64)]) {
+        self.cards.sort_by_key(|card| {
+            card_ids
+                .iter()
+                .position(|card_id| *card_id == card.id)
+                .unwrap_or(card_ids.len())
+        });
+    }
+}
+
+impl From<Vec<Card>> for Cards {
+    fn from(cards: Vec<Card>) -> Self {
+        Self { cards }
+    }
+}
+
+impl FromIterator<Card> for Cards {
+    fn from_iter<I: IntoIterator<Item = Card>>(iter: I) -> Self {
+        Self {
+            cards: iter.into_iter().collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn estimated_card(card_status: CardStatus, estimate: Option<f32>) -> Card {
+        Card {
+            card_status,
+            estimate,
+            ..Card::default()
+        }
+    }
+
+    #[test]
+    fn estimate_tokens_are_taken_out_of_the_name() {
+        assert_eq!(
+            take_estimate_token("Plan sprint ~5"),
+            Ok(("Plan sprint".to_string(), Some(5.0)))
+        );
+        assert_eq!(
+            take_estimate_token("=1.5 Plan sprint"),
+            Ok(("Plan sprint".to_string(), Some(1.5)))
+        );
+        assert_eq!(
+            take_estimate_token("Plan ~1 sprint =2,5"),
+            Ok(("Plan sprint".to_string(), Some(2.5)))
+        );
+        assert_eq!(
+            take_estimate_token("~approx the = sign ~inf"),
+            Ok(("~approx the = sign ~inf".to_string(), None))
+        );
+    }
+
+    #[test]
+    fn negative_estimate_tokens_are_rejected() {
+        assert_eq!(
+            take_estimate_token("Plan sprint ~-1"),
+            Err("Estimate must not be negative".to_string())
+        );
+        assert!(take_estimate_token("Plan sprint =-0,5").is_err());
+    }
+
+    #[test]
+    fn estimates_are_summed_per_status_in_board_order() {
+        let mut board = Board::new("Todo", "");
+        for card in [
+            estimated_card(CardStatus::Complete, Some(3.0)),
+            estimated_card(CardStatus::Active, Some(1.5)),
+            estimated_card(CardStatus::Active, Some(2.0)),
+            estimated_card(CardStatus::Stale, None),
+            estimated_card(CardStatus::Archived, Some(4.0)),
+        ] {
+            board.cards.add_card(card);
+        }
+        assert_eq!(
+            board.get_estimate_by_status(),
+            vec![
+                ("Active".to_string(), 3.5),
+                ("Complete".to_string(), 3.0),
+                ("Archived".to_string(), 4.0),
+            ]
+        );
+        assert_eq!(board.get_total_estimate(), Some(10.5));
+    }
+
+    #[test]
+    fn estimates_follow_the_custom_statuses_of_a_board() {
+        let mut board = Board::new("Todo", "");
+        board.statuses = vec![
+            CustomStatus {
+                name: "Doing".to_string(),
+                counts_as_complete: false,
+            },
+            CustomStatus {
+                name: "Shipped".to_string(),
+                counts_as_complete: true,
+            },
+        ];
+        let mut shipped = estimated_card(CardStatus::Complete, Some(2.0));
+        shipped.custom_status = Some("Shipped".to_string());
+        board.cards.add_card(shipped);
+        assert_eq!(
+            board.get_estimate_by_status(),
+            vec![("Shipped".to_string(), 2.0)]
+        );
+        assert!(Board::new("Empty", "").get_estimate_by_status().is_empty());
+    }
+
+    #[test]
+    fn a_duplicated_completed_card_does_not_keep_the_completion_date() {
+        let card = Card {
+            name: "Weekly report".to_string(),
+            card_status: CardStatus::Complete,
+            date_completed: "2024/06/01-09:30:00".to_string(),
+            due_date: "2024/06/01".to_string(),
+            ..Card::default()
+        };
+        let duplicate = card.duplicate(|id| id == card.id, DateTimeFormat::default());
+        assert_eq!(duplicate.card_status, CardStatus::Complete);
+        assert_eq!(duplicate.date_completed, FIELD_NOT_SET);
+        assert_eq!(duplicate.due_date, FIELD_NOT_SET);
+    }
+
+    fn status_test_board_json() -> Value {
+        let card = serde_json::json!({
+            "id": [1, 1], "name": "Write docs", "description": "", "date_created": "",
+            "date_modified": "", "due_date": "", "date_completed": "", "priority": "Low",
+            "card_status": "Complete", "tags": [], "comments": [],
+        });
+        serde_json::json!({ "name": "Docs", "description": "", "cards": [card] })
+    }
+
+    fn status_names(board: &Board) -> Vec<String> {
+        board
+            .get_status_selections()
+            .iter()
+            .map(|status| status.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn a_board_without_statuses_uses_the_built_in_ones() {
+        let board = Board::from_json(&status_test_board_json()).unwrap();
+        assert_eq!(status_names(&board), ["Active", "Complete", "Stale"]);
+        assert_eq!(board.cards.get_all_cards()[0].get_status_name(), "Complete");
+    }
+
+    #[test]
+    fn custom_statuses_replace_the_built_in_ones_and_cards_move_to_a_matching_one() {
+        let mut board_json = status_test_board_json();
+        board_json["statuses"] = serde_json::json!([
+            { "name": "Todo" },
+            { "name": "Review" },
+            { "name": "Done", "counts_as_complete": true },
+        ]);
+        let board = Board::from_json(&board_json).unwrap();
+        assert_eq!(status_names(&board), ["Todo", "Review", "Done"]);
+        // The card was complete, so it gets the first status that counts as complete
+        assert_eq!(board.cards.get_all_cards()[0].get_status_name(), "Done");
+
+        // A status that was removed falls back to the first one
+        board_json["cards"][0]["custom_status"] = serde_json::json!("Shipped");
+        let board = Board::from_json(&board_json).unwrap();
+        let card = &board.cards.get_all_cards()[0];
+        assert_eq!(card.get_status_name(), "Todo");
+        assert_eq!(card.card_status, CardStatus::Active);
+    }
+
+    #[test]
+    fn removing_the_statuses_keeps_the_built_in_status_of_the_card() {
+        let mut board_json = status_test_board_json();
+        board_json["statuses"] = serde_json::json!([]);
+        board_json["cards"][0]["custom_status"] = serde_json::json!("Done");
+        let board = Board::from_json(&board_json).unwrap();
+        let card = &board.cards.get_all_cards()[0];
+        assert_eq!(card.custom_status, None);
+        assert_eq!(card.get_status_name(), "Complete");
+    }
+}
@@ -0,0 +1,52 @@
+use crate::{
+    constants::FIELD_NOT_SET,
+    model::{ids::format_id, kanban::Board},
+};
+
+/// Renders the boards as one markdown document, a `##` section per board and a `###` section
+/// per card. Unlike the markdown mirror this is only meant to be read, nothing parses it back.
+/// `filter_note` goes under the title when only some cards were exported
+pub fn boards_to_markdown(boards: &[Board], with_notes: bool, filter_note: Option<&str>) -> String {
+    let mut markdown = String::from("# Kanban Export\n");
+    if let Some(filter_note) = filter_note {
+        markdown.push_str(&format!("\n_{}_\n", filter_note));
+    }
+    for board in boards {
+        markdown.push_str(&format!("\n## {}\n", board.name));
+        markdown.push_str(&format!("\n- Id: {}\n", format_id(board.id)));
+        if !board.description.trim().is_empty() {
+            markdown.push_str(&format!("\n{}\n", board.description.trim()));
+        }
+        if with_notes && !board.notes.trim().is_empty() {
+            markdown.push_str(&format!("\n**Notes**\n\n{}\n", board.notes.trim()));
+        }
+        for card in board.cards.get_all_cards() {
+            markdown.push_str(&format!("\n### {}\n", card.name));
+            if !card.description.trim().is_empty() {
+                markdown.push_str(&format!("\n{}\n", card.description.trim()));
+            }
+            let tags = if card.tags.is_empty() {
+                FIELD_NOT_SET.to_string()
+            } else {
+                card.tags.join(", ")
+            };
+            let due_date = if card.due_date.trim().is_empty() {
+                FIELD_NOT_SET
+            } else {
+                card.due_date.trim()
+            };
+            markdown.push_str(&format!(
+                "\n- Id: {}\n- Tags: {}\n- Status: {}\n- Priority: {}\n- Due date: {}\n",
+                format_id(card.id),
+                tags,
+                card.card_status,
+                card.priority,
+                due_date
+            ));
+            if let Some(waiting_on) = &card.waiting_on {
+                markdown.push_str(&format!("- Waiting on: {}\n", waiting_on));
+            }
+        }
+    }
+    markdown
+}
@@ -0,0 +1,1235 @@
+use crate::{
+    constants::{
+        FIELD_NOT_SET, MIRROR_BOARD_MARKER, MIRROR_CARD_MARKER, MIRROR_FILE_EXTENSION,
+        MIRROR_NOTES_END_MARKER, MIRROR_NOTES_MARKER, MIRROR_SNAPSHOT_FILE_NAME,
+    },
+    model::{
+        date_time_format::DateTimeFormat,
+        ids::{format_id, generate_id},
+        kanban::{
+            format_estimate, parse_estimate, parse_waiting_on, Board, Boards, Card, CardPriority,
+            CardStatus,
+        },
+        save_file::{make_file_system_safe_name, write_file_atomically},
+    },
+};
+use log::{debug, info, warn};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{HashMap, HashSet},
+    fmt, fs,
+    path::Path,
+};
+
+/// The parts of a card that are written to and read back from the markdown mirror
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct MirrorCard {
+    pub id: Option<(u64, u64)>,
+    pub name: String,
+    pub description: String,
+    pub card_status: CardStatus,
+    pub priority: CardPriority,
+    pub due_date: String,
+    #[serde(default)]
+    pub estimate: Option<f32>,
+    #[serde(default)]
+    pub start_date: Option<String>,
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub waiting_on: Option<String>,
+}
+
+impl MirrorCard {
+    pub fn from_card(card: &Card) -> Self {
+        Self {
+            id: Some(card.id),
+            name: card.name.clone(),
+            description: card.description.clone(),
+            card_status: card.card_status.clone(),
+            priority: card.priority.clone(),
+            due_date: card.due_date.clone(),
+            estimate: card.estimate,
+            start_date: card.start_date.clone(),
+            tags: card.tags.clone(),
+            waiting_on: card.waiting_on.clone(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct MirrorBoard {
+    pub id: Option<(u64, u64)>,
+    pub name: String,
+    pub description: String,
+    /// Only written with --with-notes, edits made to them in the mirror are not synced back
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notes: Option<String>,
+    pub cards: Vec<MirrorCard>,
+}
+
+impl MirrorBoard {
+    /// Passed through the markdown once so whitespace and tag splitting match what is read back
+    /// from the files, otherwise untouched cards would show up as edited
+    pub fn from_board(board: &Board, with_notes: bool) -> Self {
+        let mirror_board = Self {
+            id: Some(board.id),
+            name: board.name.clone(),
+            description: board.description.clone(),
+            notes: (with_notes && !board.notes.is_empty()).then(|| board.notes.clone()),
+            cards: board
+                .cards
+                .get_all_cards()
+                .iter()
+                .map(MirrorCard::from_card)
+                .collect(),
+        };
+        parse_board_markdown(&board_to_markdown(&mirror_board)).unwrap_or(mirror_board)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ConflictResolution {
+    #[default]
+    KeepMine,
+    KeepTheirs,
+}
+
+impl ConflictResolution {
+    pub fn toggle(&mut self) {
+        *self = match self {
+            ConflictResolution::KeepMine => ConflictResolution::KeepTheirs,
+            ConflictResolution::KeepTheirs => ConflictResolution::KeepMine,
+        };
+    }
+}
+
+impl fmt::Display for ConflictResolution {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ConflictResolution::KeepMine => write!(f, "Keep mine"),
+            ConflictResolution::KeepTheirs => write!(f, "Keep theirs"),
+        }
+    }
+}
+
+/// A card changed on both sides since the last sync, None means that side removed it
+#[derive(Debug, Clone, PartialEq)]
+pub struct MirrorConflict {
+    pub board_id: (u64, u64),
+    pub card_id: (u64, u64),
+    pub mine: Option<MirrorCard>,
+    pub theirs: Option<MirrorCard>,
+    pub resolution: ConflictResolution,
+}
+
+impl MirrorConflict {
+    pub fn card_name(&self) -> &str {
+        self.mine
+            .as_ref()
+            .or(self.theirs.as_ref())
+            .map(|card| card.name.as_str())
+            .unwrap_or(FIELD_NOT_SET)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum MirrorChange {
+    AddCard {
+        board_id: (u64, u64),
+        card: MirrorCard,
+    },
+    EditCard {
+        board_id: (u64, u64),
+        card: MirrorCard,
+    },
+    /// Moved to another board file in the mirror, the card keeps its id
+    MoveCard {
+        from_board_id: (u64, u64),
+        board_id: (u64, u64),
+        card: MirrorCard,
+    },
+    RemoveCard {
+        board_id: (u64, u64),
+        card_id: (u64, u64),
+    },
+    Conflict(Box<MirrorConflict>),
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct MirrorDiff {
+    pub changes: Vec<MirrorChange>,
+    /// Names of mirror boards that do not match a loaded board, these are left alone
+    pub skipped_boards: Vec<String>,
+}
+
+impl MirrorDiff {
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty() && self.skipped_boards.is_empty()
+    }
+
+    pub fn added_count(&self) -> usize {
+        self.changes
+            .iter()
+            .filter(|change| matches!(change, MirrorChange::AddCard { .. }))
+            .count()
+    }
+
+    pub fn edited_count(&self) -> usize {
+        self.changes
+            .iter()
+            .filter(|change| matches!(change, MirrorChange::EditCard { .. }))
+            .count()
+    }
+
+    pub fn moved_count(&self) -> usize {
+        self.changes
+            .iter()
+            .filter(|change| matches!(change, MirrorChange::MoveCard { .. }))
+            .count()
+    }
+
+    pub fn removed_count(&self) -> usize {
+        self.changes
+            .iter()
+            .filter(|change| matches!(change, MirrorChange::RemoveCard { .. }))
+            .count()
+    }
+
+    pub fn conflicts(&self) -> Vec<&MirrorConflict> {
+        self.changes
+            .iter()
+            .filter_map(|change| match change {
+                MirrorChange::Conflict(conflict) => Some(conflict.as_ref()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    pub fn get_mut_conflict(&mut self, conflict_index: usize) -> Option<&mut MirrorConflict> {
+        self.changes
+            .iter_mut()
+            .filter_map(|change| match change {
+                MirrorChange::Conflict(conflict) => Some(conflict.as_mut()),
+                _ => None,
+            })
+            .nth(conflict_index)
+    }
+}
+
+fn format_id_marker(marker: &str, id: (u64, u64)) -> String {
+    format!("<!-- {} {} -->", marker, format_id(id))
+}
+
+fn parse_id_marker(marker: &str, line: &str) -> Option<(u64, u64)> {
+    let id = line
+        .trim()
+        .strip_prefix("<!--")?
+        .strip_suffix("-->")?
+        .trim()
+        .strip_prefix(marker)?
+        .trim();
+    let (id_1, id_2) = id.split_once('-')?;
+    Some((id_1.trim().parse().ok()?, id_2.trim().parse().ok()?))
+}
+
+// Lines that would be read back as structure are prefixed with a backslash
+fn escape_line(line: &str) -> String {
+    if line.starts_with('#') || line.starts_with('\\') || line.starts_with("<!--") {
+        format!("\\{}", line)
+    } else {
+        line.to_string()
+    }
+}
+
+fn unescape_line(line: &str) -> &str {
+    line.strip_prefix('\\').unwrap_or(line)
+}
+
+fn push_text_block(markdown: &mut String, text: &str) {
+    if text.is_empty() || text == FIELD_NOT_SET {
+        return;
+    }
+    markdown.push('\n');
+    for line in text.lines() {
+        markdown.push_str(&escape_line(line));
+        markdown.push('\n');
+    }
+}
+
+/// Renders a board as markdown, every card carries its id in an html comment so edits can be
+/// matched back to the card they came from
+pub fn board_to_markdown(board: &MirrorBoard) -> String {
+    let mut markdown = format!("# {}\n", board.name);
+    if let Some(board_id) = board.id {
+        markdown.push_str(&format_id_marker(MIRROR_BOARD_MARKER, board_id));
+        markdown.push('\n');
+    }
+    push_text_block(&mut markdown, &board.description);
+    if let Some(notes) = &board.notes {
+        markdown.push('\n');
+        markdown.push_str(MIRROR_NOTES_MARKER);
+        markdown.push('\n');
+        push_text_block(&mut markdown, notes);
+        markdown.push('\n');
+        markdown.push_str(MIRROR_NOTES_END_MARKER);
+        markdown.push('\n');
+    }
+    for card in &board.cards {
+        markdown.push_str(&format!("\n## {}\n", card.name));
+        if let Some(card_id) = card.id {
+            markdown.push_str(&format_id_marker(MIRROR_CARD_MARKER, card_id));
+            markdown.push('\n');
+        }
+        markdown.push_str(&format!("- Status: {}\n", card.card_status));
+        markdown.push_str(&format!("- Priority: {}\n", card.priority));
+        if let Some(start_date) = &card.start_date {
+            markdown.push_str(&format!("- Start: {}\n", start_date));
+        }
+        markdown.push_str(&format!("- Due: {}\n", card.due_date));
+        if let Some(estimate) = card.estimate {
+            markdown.push_str(&format!("- Estimate: {}\n", format_estimate(estimate)));
+        }
+        markdown.push_str(&format!("- Tags: {}\n", card.tags.join(", ")));
+        if let Some(waiting_on) = &card.waiting_on {
+            markdown.push_str(&format!("- Waiting on: {}\n", waiting_on));
+        }
+        push_text_block(&mut markdown, &card.description);
+    }
+    markdown
+}
+
+fn collect_text_block(lines: &[&str]) -> String {
+    let start = lines.iter().position(|line| !line.trim().is_empty());
+    let end = lines.iter().rposition(|line| !line.trim().is_empty());
+    match (start, end) {
+        (Some(start), Some(end)) => lines[start..=end]
+            .iter()
+            .map(|line| unescape_line(line))
+            .collect::<Vec<&str>>()
+            .join("\n"),
+        _ => FIELD_NOT_SET.to_string(),
+    }
+}
+
+fn parse_card_status(value: &str) -> Option<CardStatus> {
+    match value.trim().to_lowercase().as_str() {
+        "active" => Some(CardStatus::Active),
+        "complete" | "completed" => Some(CardStatus::Complete),
+        "stale" => Some(CardStatus::Stale),
+        "archived" => Some(CardStatus::Archived),
+        _ => None,
+    }
+}
+
+fn parse_card_priority(value: &str) -> Option<CardPriority> {
+    match value.trim().to_lowercase().as_str() {
+        "high" => Some(CardPriority::High),
+        "medium" => Some(CardPriority::Medium),
+        "low" => Some(CardPriority::Low),
+        _ => None,
+    }
+}
+
+fn parse_card(name: &str, lines: &[&str]) -> Result<MirrorCard, String> {
+    let name = if name.trim().is_empty() {
+        FIELD_NOT_SET.to_string()
+    } else {
+        name.trim().to_string()
+    };
+    let mut card = MirrorCard {
+        id: None,
+        name,
+        description: FIELD_NOT_SET.to_string(),
+        card_status: CardStatus::Active,
+        priority: CardPriority::Low,
+        due_date: FIELD_NOT_SET.to_string(),
+        estimate: None,
+        start_date: None,
+        tags: Vec::new(),
+        waiting_on: None,
+    };
+    let mut body_start = 0;
+    if let Some(card_id) = lines
+        .first()
+        .and_then(|line| parse_id_marker(MIRROR_CARD_MARKER, line))
+    {
+        card.id = Some(card_id);
+        body_start = 1;
+    }
+    // Metadata only counts directly under the heading, anything after it is the description
+    for line in &lines[body_start..] {
+        let Some((key, value)) = line
+            .strip_prefix("- ")
+            .and_then(|field| field.split_once(':'))
+        else {
+            break;
+        };
+        match key.trim().to_lowercase().as_str() {
+            "status" => {
+                card.card_status = parse_card_status(value).ok_or_else(|| {
+                    format!("Unknown status '{}' for card '{}'", value.trim(), card.name)
+                })?;
+            }
+            "priority" => {
+                card.priority = parse_card_priority(value).ok_or_else(|| {
+                    format!(
+                        "Unknown priority '{}' for card '{}'",
+                        value.trim(),
+                        card.name
+                    )
+                })?;
+            }
+            "start" => {
+                let start_date = value.trim();
+                card.start_date = if start_date.is_empty() || start_date == FIELD_NOT_SET {
+                    None
+                } else {
+                    Some(start_date.to_string())
+                };
+            }
+            "due" => {
+                let due_date = value.trim();
+                card.due_date = if due_date.is_empty() {
+                    FIELD_NOT_SET.to_string()
+                } else {
+                    due_date.to_string()
+                };
+            }
+            "estimate" => {
+                card.estimate = parse_estimate(value)
+                    .map_err(|error| format!("{} for card '{}'", error, card.name))?;
+            }
+            "tags" => {
+                card.tags = value
+                    .split(',')
+                    .map(|tag| tag.trim().to_string())
+                    .filter(|tag| !tag.is_empty())
+                    .collect();
+            }
+            "waiting on" => {
+                card.waiting_on = parse_waiting_on(value);
+            }
+            _ => break,
+        }
+        body_start += 1;
+    }
+    card.description = collect_text_block(&lines[body_start..]);
+    Ok(card)
+}
+
+/// Parses markdown written by board_to_markdown, cards added by hand have no id
+pub fn parse_board_markdown(markdown: &str) -> Result<MirrorBoard, String> {
+    let lines = markdown.lines().collect::<Vec<&str>>();
+    let title_index = lines
+        .iter()
+        .position(|line| !line.trim().is_empty())
+        .ok_or_else(|| "File is empty".to_string())?;
+    let name = lines[title_index]
+        .strip_prefix("# ")
+        .ok_or_else(|| "First line must be the board title starting with '# '".to_string())?
+        .trim()
+        .to_string();
+    let mut section_start = title_index + 1;
+    let id = lines
+        .get(section_start)
+        .and_then(|line| parse_id_marker(MIRROR_BOARD_MARKER, line));
+    if id.is_some() {
+        section_start += 1;
+    }
+    let card_heading_indexes = lines
+        .iter()
+        .enumerate()
+        .skip(section_start)
+        .filter(|(_, line)| line.starts_with("## "))
+        .map(|(index, _)| index)
+        .collect::<Vec<usize>>();
+    let section_end = card_heading_indexes.first().copied().unwrap_or(lines.len());
+    let notes_start = lines[section_start..section_end]
+        .iter()
+        .position(|line| line.trim() == MIRROR_NOTES_MARKER)
+        .map(|position| section_start + position);
+    let (description_end, notes) = match notes_start {
+        Some(notes_start) => {
+            let notes_end = lines[notes_start..section_end]
+                .iter()
+                .position(|line| line.trim() == MIRROR_NOTES_END_MARKER)
+                .map(|position| notes_start + position)
+                .ok_or_else(|| "Board notes are missing their end marker".to_string())?;
+            let notes = collect_text_block(&lines[notes_start + 1..notes_end]);
+            (notes_start, (notes != FIELD_NOT_SET).then_some(notes))
+        }
+        None => (section_end, None),
+    };
+    let description = collect_text_block(&lines[section_start..description_end]);
+
+    let mut cards = Vec::new();
+    for (position, heading_index) in card_heading_indexes.iter().enumerate() {
+        let card_end = card_heading_indexes
+            .get(position + 1)
+            .copied()
+            .unwrap_or(lines.len());
+        let card_name = &lines[*heading_index][3..];
+        cards.push(parse_card(card_name, &lines[heading_index + 1..card_end])?);
+    }
+    Ok(MirrorBoard {
+        id,
+        name,
+        description,
+        notes,
+        cards,
+    })
+}
+
+fn cards_by_id(cards: &[MirrorCard]) -> HashMap<(u64, u64), &MirrorCard> {
+    cards
+        .iter()
+        .filter_map(|card| card.id.map(|card_id| (card_id, card)))
+        .collect()
+}
+
+fn new_card_id(taken_ids: &mut HashSet<(u64, u64)>) -> (u64, u64) {
+    let card_id = generate_id(|id| taken_ids.contains(&id));
+    taken_ids.insert(card_id);
+    card_id
+}
+
+/// The board each card id is on, the first one for cards pasted into several boards
+fn card_board_ids(boards: &[MirrorBoard]) -> HashMap<(u64, u64), (u64, u64)> {
+    let mut card_board_ids = HashMap::new();
+    for board in boards {
+        let Some(board_id) = board.id else {
+            continue;
+        };
+        for card_id in board.cards.iter().filter_map(|card| card.id) {
+            card_board_ids.entry(card_id).or_insert(board_id);
+        }
+    }
+    card_board_ids
+}
+
+/// Three way diff between the last synced snapshot, the loaded boards and the mirror files.
+/// Only changes made in the mirror become changes, a card edited on both sides since the
+/// last sync becomes a conflict. A card moved to another board file keeps its id and any edit
+/// made to it here since the last sync
+pub fn diff_mirror(
+    base: &[MirrorBoard],
+    mine: &[MirrorBoard],
+    theirs: &[MirrorBoard],
+) -> MirrorDiff {
+    let mut diff = MirrorDiff::default();
+    // New cards get their id here so applying the same diff again does not add them twice
+    let mut taken_ids = [base, mine, theirs]
+        .iter()
+        .flat_map(|boards| boards.iter())
+        .flat_map(|board| {
+            board
+                .id
+                .into_iter()
+                .chain(board.cards.iter().filter_map(|card| card.id))
+        })
+        .collect::<HashSet<(u64, u64)>>();
+    let my_card_board_ids = card_board_ids(mine);
+    let base_cards = base
+        .iter()
+        .flat_map(|board| board.cards.iter())
+        .filter_map(|card| card.id.map(|card_id| (card_id, card)))
+        .collect::<HashMap<(u64, u64), &MirrorCard>>();
+    let their_card_ids_by_board = theirs
+        .iter()
+        .filter_map(|board| {
+            board.id.map(|board_id| {
+                (
+                    board_id,
+                    board
+                        .cards
+                        .iter()
+                        .filter_map(|card| card.id)
+                        .collect::<HashSet<(u64, u64)>>(),
+                )
+            })
+        })
+        .collect::<HashMap<(u64, u64), HashSet<(u64, u64)>>>();
+    let is_in_their_board = |board_id: (u64, u64), card_id: (u64, u64)| {
+        their_card_ids_by_board
+            .get(&board_id)
+            .is_some_and(|card_ids| card_ids.contains(&card_id))
+    };
+    let my_card = |board_id: (u64, u64), card_id: (u64, u64)| {
+        mine.iter()
+            .find(|board| board.id == Some(board_id))
+            .and_then(|board| board.cards.iter().find(|card| card.id == Some(card_id)))
+    };
+    for their_board in theirs {
+        let Some(my_board) = their_board
+            .id
+            .and_then(|board_id| mine.iter().find(|board| board.id == Some(board_id)))
+        else {
+            diff.skipped_boards.push(their_board.name.clone());
+            continue;
+        };
+        let board_id = my_board.id.unwrap_or_default();
+        let base_board_cards = base
+            .iter()
+            .find(|board| board.id == my_board.id)
+            .map(|board| cards_by_id(&board.cards))
+            .unwrap_or_default();
+        let my_cards = cards_by_id(&my_board.cards);
+        let mut seen_card_ids = HashSet::new();
+
+        for their_card in &their_board.cards {
+            let card_id = match their_card.id {
+                Some(card_id) if seen_card_ids.insert(card_id) => card_id,
+                // Cards without an id, or pasted copies of another card, are new
+                _ => {
+                    diff.changes.push(MirrorChange::AddCard {
+                        board_id,
+                        card: MirrorCard {
+                            id: Some(new_card_id(&mut taken_ids)),
+                            ..their_card.clone()
+                        },
+                    });
+                    continue;
+                }
+            };
+            let conflict = || {
+                MirrorChange::Conflict(Box::new(MirrorConflict {
+                    board_id,
+                    card_id,
+                    mine: my_cards.get(&card_id).map(|card| (*card).clone()),
+                    theirs: Some(their_card.clone()),
+                    resolution: ConflictResolution::default(),
+                }))
+            };
+            match (base_board_cards.get(&card_id), my_cards.get(&card_id)) {
+                (Some(base_card), Some(my_card)) => {
+                    if their_card == *base_card || their_card == *my_card {
+                        continue;
+                    }
+                    if my_card == base_card {
+                        diff.changes.push(MirrorChange::EditCard {
+                            board_id,
+                            card: their_card.clone(),
+                        });
+                    } else {
+                        diff.changes.push(conflict());
+                    }
+                }
+                (Some(base_card), None) => {
+                    // Removed here since the last sync, only a conflict if the mirror edited it
+                    if their_card != *base_card {
+                        diff.changes.push(conflict());
+                    }
+                }
+                (None, Some(my_card)) => {
+                    if their_card != *my_card {
+                        diff.changes.push(conflict());
+                    }
+                }
+                (None, None) => {
+                    let from_board_id = my_card_board_ids
+                        .get(&card_id)
+                        .copied()
+                        .filter(|from_board_id| !is_in_their_board(*from_board_id, card_id));
+                    if let Some(from_board_id) = from_board_id {
+                        let my_moved_card = my_card(from_board_id, card_id);
+                        let edited_here = my_moved_card.is_some_and(|my_moved_card| {
+                            base_cards.get(&card_id) != Some(&my_moved_card)
+                        });
+                        let card = match my_moved_card {
+                            Some(my_moved_card) if edited_here => my_moved_card.clone(),
+                            _ => their_card.clone(),
+                        };
+                        diff.changes.push(MirrorChange::MoveCard {
+                            from_board_id,
+                            board_id,
+                            card,
+                        });
+                        continue;
+                    }
+                    diff.changes.push(MirrorChange::AddCard {
+                        board_id,
+                        card: MirrorCard {
+                            id: Some(new_card_id(&mut taken_ids)),
+                            ..their_card.clone()
+                        },
+                    });
+                }
+            }
+        }
+
+        for (card_id, base_card) in &base_board_cards {
+            // Moved to another board file, the board it went to picks it up
+            if seen_card_ids.contains(card_id)
+                || their_card_ids_by_board
+                    .iter()
+                    .any(|(other_board_id, card_ids)| {
+                        *other_board_id != board_id && card_ids.contains(card_id)
+                    })
+            {
+                continue;
+            }
+            match my_cards.get(card_id) {
+                Some(my_card) if my_card == base_card => {
+                    diff.changes.push(MirrorChange::RemoveCard {
+                        board_id,
+                        card_id: *card_id,
+                    });
+                }
+                Some(my_card) => {
+                    diff.changes
+                        .push(MirrorChange::Conflict(Box::new(MirrorConflict {
+                            board_id,
+                            card_id: *card_id,
+                            mine: Some((*my_card).clone()),
+                            theirs: None,
+                            resolution: ConflictResolution::default(),
+                        })));
+                }
+                None => {}
+            }
+        }
+    }
+    diff
+}
+
+/// Leaves the card alone when it already matches, so applying a diff twice changes nothing
+fn update_card_from_mirror(card: &mut Card, mirror_card: &MirrorCard, timestamp: &str) {
+    let mirror_card = &MirrorCard {
+        id: Some(card.id),
+        ..mirror_card.clone()
+    };
+    if MirrorCard::from_card(card) == *mirror_card {
+        return;
+    }
+    if card.card_status != mirror_card.card_status {
+        card.date_completed = if mirror_card.card_status == CardStatus::Complete {
+            timestamp.to_string()
+        } else {
+            FIELD_NOT_SET.to_string()
+        };
+        // The mirror only knows the built in statuses, the board picks the matching custom one
+        card.custom_status = None;
+    }
+    card.name.clone_from(&mirror_card.name);
+    card.description.clone_from(&mirror_card.description);
+    card.card_status = mirror_card.card_status.clone();
+    card.priority = mirror_card.priority.clone();
+    card.due_date.clone_from(&mirror_card.due_date);
+    card.start_date.clone_from(&mirror_card.start_date);
+    card.estimate = mirror_card.estimate;
+    card.tags.clone_from(&mirror_card.tags);
+    card.waiting_on.clone_from(&mirror_card.waiting_on);
+    card.date_modified = timestamp.to_string();
+}
+
+fn add_card_from_mirror(
+    board: &mut Board,
+    card_id: (u64, u64),
+    mirror_card: &MirrorCard,
+    date_time_format: DateTimeFormat,
+    timestamp: &str,
+) {
+    let mut card = Card::new(
+        &mirror_card.name,
+        &mirror_card.description,
+        &mirror_card.due_date,
+        mirror_card.priority.clone(),
+        mirror_card.tags.clone(),
+        vec![],
+        date_time_format,
+    );
+    card.id = card_id;
+    update_card_from_mirror(&mut card, mirror_card, timestamp);
+    board.cards.add_card(card);
+}
+
+/// Applies the diff to the boards, conflicts follow their chosen resolution
+pub fn apply_mirror_diff(boards: &mut Boards, diff: &MirrorDiff, date_time_format: DateTimeFormat) {
+    let timestamp = chrono::Local::now()
+        .format(date_time_format.to_parser_string())
+        .to_string();
+    for change in &diff.changes {
+        match change {
+            MirrorChange::AddCard { board_id, card } => {
+                let card_id = match card.id {
+                    // Added by an earlier apply of the same diff
+                    Some(card_id)
+                        if boards.get_board_with_id(*board_id).is_some_and(|board| {
+                            board.cards.get_card_with_id(card_id).is_some()
+                        }) =>
+                    {
+                        continue;
+                    }
+                    Some(card_id) if !boards.contains_id(card_id) => card_id,
+                    _ => boards.generate_unused_id(),
+                };
+                if let Some(board) = boards.get_mut_board_with_id(*board_id) {
+                    add_card_from_mirror(board, card_id, card, date_time_format, &timestamp);
+                }
+            }
+            MirrorChange::EditCard { board_id, card } => {
+                if let Some(existing_card) = card.id.and_then(|card_id| {
+                    boards
+                        .get_mut_board_with_id(*board_id)
+                        .and_then(|board| board.cards.get_mut_card_with_id(card_id))
+                }) {
+                    update_card_from_mirror(existing_card, card, &timestamp);
+                }
+            }
+            MirrorChange::MoveCard {
+                from_board_id,
+                board_id,
+                card,
+            } => {
+                let Some(card_id) = card.id else {
+                    continue;
+                };
+                if boards.get_board_with_id(*board_id).is_none() {
+                    continue;
+                }
+                let Some(mut moved_card) = boards
+                    .get_mut_board_with_id(*from_board_id)
+                    .and_then(|board| board.cards.remove_card_with_id(card_id))
+                else {
+                    continue;
+                };
+                update_card_from_mirror(&mut moved_card, card, &timestamp);
+                if let Some(board) = boards.get_mut_board_with_id(*board_id) {
+                    board.cards.add_card(moved_card);
+                }
+            }
+            MirrorChange::RemoveCard { board_id, card_id } => {
+                if let Some(board) = boards.get_mut_board_with_id(*board_id) {
+                    board.cards.remove_card_with_id(*card_id);
+                }
+            }
+            MirrorChange::Conflict(conflict) => {
+                if conflict.resolution == ConflictResolution::KeepMine {
+                    continue;
+                }
+                let Some(board) = boards.get_mut_board_with_id(conflict.board_id) else {
+                    continue;
+                };
+                match (
+                    &conflict.theirs,
+                    board.cards.get_mut_card_with_id(conflict.card_id),
+                ) {
+                    (Some(their_card), Some(existing_card)) => {
+                        update_card_from_mirror(existing_card, their_card, &timestamp);
+                    }
+                    // Deleted here but edited in the mirror, it comes back under its old id
+                    (Some(their_card), None) => {
+                        add_card_from_mirror(
+                            board,
+                            conflict.card_id,
+                            their_card,
+                            date_time_format,
+                            &timestamp,
+                        );
+                    }
+                    (None, Some(_)) => {
+                        board.cards.remove_card_with_id(conflict.card_id);
+                    }
+                    (None, None) => {}
+                }
+            }
+        }
+    }
+}
+
+fn is_mirror_file(path: &Path) -> bool {
+    path.is_file()
+        && path
+            .extension()
+            .is_some_and(|extension| extension == MIRROR_FILE_EXTENSION)
+}
+
+/// Reads every markdown file in the mirror directory
+pub fn read_mirror(mirror_directory: &Path) -> Result<Vec<MirrorBoard>, String> {
+    let entries = fs::read_dir(mirror_directory).map_err(|e| {
+        debug!("Error reading {}: {}", mirror_directory.display(), e);
+        format!(
+            "Could not read mirror directory {}",
+            mirror_directory.display()
+        )
+    })?;
+    let mut mirror_files = entries
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| is_mirror_file(path))
+        .collect::<Vec<_>>();
+    mirror_files.sort();
+    let mut boards = Vec::new();
+    for mirror_file in mirror_files {
+        let markdown = fs::read_to_string(&mirror_file)
+            .map_err(|e| format!("Could not read {}: {}", mirror_file.display(), e))?;
+        let board = parse_board_markdown(&markdown)
+            .map_err(|e| format!("{}: {}", mirror_file.display(), e))?;
+        boards.push(board);
+    }
+    Ok(boards)
+}
+
+/// The boards as they were when the mirror was last written, None if it never was
+pub fn read_mirror_snapshot(mirror_directory: &Path) -> Result<Option<Vec<MirrorBoard>>, String> {
+    let snapshot_path = mirror_directory.join(MIRROR_SNAPSHOT_FILE_NAME);
+    if !snapshot_path.exists() {
+        return Ok(None);
+    }
+    let snapshot = fs::read_to_string(&snapshot_path)
+        .map_err(|e| format!("Could not read {}: {}", snapshot_path.display(), e))?;
+    serde_json::from_str(&snapshot)
+        .map(Some)
+        .map_err(|e| format!("Invalid mirror snapshot {}: {}", snapshot_path.display(), e))
+}
+
+/// Regenerates the markdown files and the snapshot used as the base of the next sync
+pub fn write_mirror(
+    boards: &[Board],
+    mirror_directory: &Path,
+    with_notes: bool,
+) -> Result<(), String> {
+    fs::create_dir_all(mirror_directory).map_err(|e| {
+        format!(
+            "Could not create mirror directory {}: {}",
+            mirror_directory.display(),
+            e
+        )
+    })?;
+    // Only remove files that were written by the mirror, the directory may hold other notes
+    for existing_board in fs::read_dir(mirror_directory)
+        .map_err(|e| format!("Could not read mirror directory: {}", e))?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| is_mirror_file(path))
+    {
+        let written_by_mirror = fs::read_to_string(&existing_board)
+            .ok()
+            .and_then(|markdown| parse_board_markdown(&markdown).ok())
+            .is_some_and(|board| board.id.is_some());
+        if written_by_mirror {
+            fs::remove_file(&existing_board)
+                .map_err(|e| format!("Could not remove {}: {}", existing_board.display(), e))?;
+        }
+    }
+
+    let mirror_boards = boards
+        .iter()
+        .map(|board| MirrorBoard::from_board(board, with_notes))
+        .collect::<Vec<MirrorBoard>>();
+    let mut used_file_names = HashSet::new();
+    for board in &mirror_boards {
+        let safe_name = make_file_system_safe_name(&board.name);
+        let safe_name = if safe_name.is_empty() {
+            "board".to_string()
+        } else {
+            safe_name
+        };
+        let mut file_name = format!("{}.{}", safe_name, MIRROR_FILE_EXTENSION);
+        let mut duplicate_count = 1;
+        while !used_file_names.insert(file_name.clone()) {
+            duplicate_count += 1;
+            file_name = format!(
+                "{}_{}.{}",
+                safe_name, duplicate_count, MIRROR_FILE_EXTENSION
+            );
+        }
+        write_file_atomically(
+            &mirror_directory.join(&file_name),
+            board_to_markdown(board).as_bytes(),
+        )?;
+    }
+    let snapshot = serde_json::to_string_pretty(&mirror_boards)
+        .map_err(|e| format!("Could not serialize mirror snapshot: {}", e))?;
+    write_file_atomically(
+        &mirror_directory.join(MIRROR_SNAPSHOT_FILE_NAME),
+        snapshot.as_bytes(),
+    )?;
+    info!("Markdown mirror written to {}", mirror_directory.display());
+    Ok(())
+}
+
+/// Diff of the mirror against the loaded boards, None if the mirror was never written
+pub fn get_mirror_diff(
+    boards: &Boards,
+    mirror_directory: &Path,
+) -> Result<Option<MirrorDiff>, String> {
+    let Some(snapshot) = read_mirror_snapshot(mirror_directory)? else {
+        return Ok(None);
+    };
+    let mirror_boards = read_mirror(mirror_directory)?;
+    let loaded_boards = boards
+        .get_boards()
+        .iter()
+        .map(|board| MirrorBoard::from_board(board, false))
+        .collect::<Vec<MirrorBoard>>();
+    Ok(Some(diff_mirror(&snapshot, &loaded_boards, &mirror_boards)))
+}
+
+/// Rewrites the mirror after a save unless it holds edits that were not synced yet,
+/// those would be lost otherwise
+pub fn update_mirror_after_save(
+    boards: &[Board],
+    mirror_directory: &Path,
+    with_notes: bool,
+) -> Result<(), String> {
+    if let Some(snapshot) = read_mirror_snapshot(mirror_directory)? {
+        let mirror_boards = read_mirror(mirror_directory)?;
+        if !diff_mirror(&snapshot, &snapshot, &mirror_boards)
+            .changes
+            .is_empty()
+        {
+            warn!("Markdown mirror has edits that were not synced yet, not overwriting it");
+            return Err("Mirror has unsynced edits, run 'Sync from Mirror' first".to_string());
+        }
+    }
+    write_mirror(boards, mirror_directory, with_notes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_boards() -> Boards {
+        let mut boards = Boards::default();
+        for (board_name, card_names) in [("Todo", ["Write", "Test"]), ("Done", ["Plan", "Design"])]
+        {
+            let mut board = Board::new(board_name, "");
+            for card_name in card_names {
+                board.cards.add_card(Card {
+                    name: card_name.to_string(),
+                    description: format!("{} it", card_name),
+                    date_modified: "2024/01/01-00:00:00".to_string(),
+                    ..Card::default()
+                });
+            }
+            boards.add_board(board);
+        }
+        boards
+    }
+
+    fn mirror_of(boards: &Boards) -> Vec<MirrorBoard> {
+        boards
+            .get_boards()
+            .iter()
+            .map(|board| MirrorBoard::from_board(board, false))
+            .collect()
+    }
+
+    fn card_id(boards: &Boards, board_index: usize, card_index: usize) -> (u64, u64) {
+        boards.get_boards()[board_index].cards.get_all_cards()[card_index].id
+    }
+
+    fn card_names(boards: &Boards, board_index: usize) -> Vec<String> {
+        boards.get_boards()[board_index]
+            .cards
+            .get_all_cards()
+            .iter()
+            .map(|card| card.name.clone())
+            .collect()
+    }
+
+    #[test]
+    fn the_markdown_round_trip_keeps_ids() {
+        let boards = sample_boards();
+        for mirror_board in mirror_of(&boards) {
+            assert_eq!(
+                parse_board_markdown(&board_to_markdown(&mirror_board)),
+                Ok(mirror_board)
+            );
+        }
+    }
+
+    #[test]
+    fn an_untouched_or_reordered_mirror_has_no_changes() {
+        let boards = sample_boards();
+        let base = mirror_of(&boards);
+        assert!(diff_mirror(&base, &base, &base).is_empty());
+        let mut theirs = base.clone();
+        theirs[0].cards.reverse();
+        assert!(diff_mirror(&base, &base, &theirs).is_empty());
+    }
+
+    #[test]
+    fn cards_added_in_the_mirror_are_added() {
+        let mut boards = sample_boards();
+        let base = mirror_of(&boards);
+        let mut theirs = base.clone();
+        let added_card = MirrorCard {
+            id: None,
+            name: "Ship".to_string(),
+            ..theirs[0].cards[0].clone()
+        };
+        theirs[0].cards.push(added_card);
+        let diff = diff_mirror(&base, &base, &theirs);
+        assert_eq!(diff.added_count(), 1);
+        assert_eq!(diff.changes.len(), 1);
+        apply_mirror_diff(&mut boards, &diff, DateTimeFormat::default());
+        assert_eq!(card_names(&boards, 0), vec!["Write", "Test", "Ship"]);
+    }
+
+    #[test]
+    fn cards_removed_in_the_mirror_are_removed() {
+        let mut boards = sample_boards();
+        let base = mirror_of(&boards);
+        let mut theirs = base.clone();
+        theirs[1].cards.remove(0);
+        let diff = diff_mirror(&base, &base, &theirs);
+        assert_eq!(
+            diff.changes,
+            vec![MirrorChange::RemoveCard {
+                board_id: boards.get_boards()[1].id,
+                card_id: card_id(&boards, 1, 0),
+            }]
+        );
+        apply_mirror_diff(&mut boards, &diff, DateTimeFormat::default());
+        assert_eq!(card_names(&boards, 1), vec!["Design"]);
+    }
+
+    #[test]
+    fn cards_edited_in_the_mirror_are_edited() {
+        let mut boards = sample_boards();
+        let base = mirror_of(&boards);
+        let mut theirs = base.clone();
+        theirs[0].cards[1].description = "Test it twice".to_string();
+        theirs[0].cards[1].card_status = CardStatus::Complete;
+        let diff = diff_mirror(&base, &base, &theirs);
+        assert_eq!(diff.edited_count(), 1);
+        apply_mirror_diff(&mut boards, &diff, DateTimeFormat::default());
+        let card = &boards.get_boards()[0].cards.get_all_cards()[1];
+        assert_eq!(card.description, "Test it twice");
+        assert_eq!(card.card_status, CardStatus::Complete);
+        assert_ne!(card.date_completed, FIELD_NOT_SET);
+    }
+
+    #[test]
+    fn cards_moved_to_another_board_keep_their_id() {
+        let mut boards = sample_boards();
+        let moved_card_id = card_id(&boards, 0, 1);
+        let base = mirror_of(&boards);
+        let mut theirs = base.clone();
+        let mut moved_card = theirs[0].cards.remove(1);
+        moved_card.name = "Test more".to_string();
+        theirs[1].cards.push(moved_card);
+        let diff = diff_mirror(&base, &base, &theirs);
+        assert_eq!(diff.moved_count(), 1);
+        assert_eq!(diff.changes.len(), 1);
+        apply_mirror_diff(&mut boards, &diff, DateTimeFormat::default());
+        assert_eq!(card_names(&boards, 0), vec!["Write"]);
+        assert_eq!(card_names(&boards, 1), vec!["Plan", "Design", "Test more"]);
+        assert_eq!(card_id(&boards, 1, 2), moved_card_id);
+    }
+
+    #[test]
+    fn a_card_moved_in_the_mirror_keeps_edits_made_here() {
+        let mut boards = sample_boards();
+        let base = mirror_of(&boards);
+        let mut theirs = base.clone();
+        let moved_card = theirs[0].cards.remove(0);
+        theirs[1].cards.push(moved_card);
+        boards.get_mut_boards()[0].cards.get_mut_all_cards()[0].name = "Write more".to_string();
+        let diff = diff_mirror(&base, &mirror_of(&boards), &theirs);
+        assert_eq!(diff.moved_count(), 1);
+        apply_mirror_diff(&mut boards, &diff, DateTimeFormat::default());
+        assert_eq!(card_names(&boards, 1), vec!["Plan", "Design", "Write more"]);
+    }
+
+    #[test]
+    fn cards_edited_on_both_sides_are_conflicts() {
+        let mut boards = sample_boards();
+        let base = mirror_of(&boards);
+        let mut theirs = base.clone();
+        theirs[0].cards[0].name = "Theirs".to_string();
+        // Edited here and removed in the mirror
+        theirs[1].cards.remove(1);
+        boards.get_mut_boards()[0].cards.get_mut_all_cards()[0].name = "Mine".to_string();
+        boards.get_mut_boards()[1].cards.get_mut_all_cards()[1].name = "Mine too".to_string();
+        let mine = mirror_of(&boards);
+
+        let mut diff = diff_mirror(&base, &mine, &theirs);
+        assert_eq!(diff.conflicts().len(), 2);
+        assert_eq!(diff.changes.len(), 2);
+
+        let mut kept_mine = boards.clone();
+        apply_mirror_diff(&mut kept_mine, &diff, DateTimeFormat::default());
+        assert_eq!(kept_mine, boards);
+
+        for conflict_index in 0..2 {
+            diff.get_mut_conflict(conflict_index)
+                .unwrap()
+                .resolution
+                .toggle();
+        }
+        apply_mirror_diff(&mut boards, &diff, DateTimeFormat::default());
+        assert_eq!(card_names(&boards, 0), vec!["Theirs", "Test"]);
+        assert_eq!(card_names(&boards, 1), vec!["Plan"]);
+    }
+
+    #[test]
+    fn a_card_removed_here_but_edited_in_the_mirror_is_a_conflict() {
+        let mut boards = sample_boards();
+        let removed_card_id = card_id(&boards, 0, 0);
+        let base = mirror_of(&boards);
+        let mut theirs = base.clone();
+        theirs[0].cards[0].name = "Still needed".to_string();
+        boards.get_mut_boards()[0]
+            .cards
+            .remove_card_with_id(removed_card_id);
+        let mut diff = diff_mirror(&base, &mirror_of(&boards), &theirs);
+        assert_eq!(diff.conflicts().len(), 1);
+        diff.get_mut_conflict(0).unwrap().resolution = ConflictResolution::KeepTheirs;
+        apply_mirror_diff(&mut boards, &diff, DateTimeFormat::default());
+        assert_eq!(card_names(&boards, 0), vec!["Test", "Still needed"]);
+        assert_eq!(card_id(&boards, 0, 1), removed_card_id);
+    }
+
+    #[test]
+    fn unknown_mirror_boards_are_skipped() {
+        let boards = sample_boards();
+        let base = mirror_of(&boards);
+        let mut theirs = base.clone();
+        theirs.push(MirrorBoard {
+            id: None,
+            name: "Elsewhere".to_string(),
+            description: String::new(),
+            notes: None,
+            cards: vec![],
+        });
+        let diff = diff_mirror(&base, &base, &theirs);
+        assert!(diff.changes.is_empty());
+        assert_eq!(diff.skipped_boards, vec!["Elsewhere".to_string()]);
+    }
+
+    #[test]
+    fn applying_a_diff_twice_changes_nothing_more() {
+        let mut boards = sample_boards();
+        let base = mirror_of(&boards);
+        let mut theirs = base.clone();
+        theirs[0].cards[0].name = "Edited".to_string();
+        let added_card = MirrorCard {
+            id: None,
+            name: "Added".to_string(),
+            ..theirs[0].cards[1].clone()
+        };
+        theirs[0].cards.push(added_card);
+        let moved_card = theirs[0].cards.remove(1);
+        theirs[1].cards.push(moved_card);
+        theirs[1].cards.remove(0);
+        let diff = diff_mirror(&base, &base, &theirs);
+        assert_eq!(
+            (
+                diff.added_count(),
+                diff.edited_count(),
+                diff.moved_count(),
+                diff.removed_count()
+            ),
+            (1, 1, 1, 1)
+        );
+        apply_mirror_diff(&mut boards, &diff, DateTimeFormat::default());
+        let applied_once = boards.clone();
+        apply_mirror_diff(&mut boards, &diff, DateTimeFormat::default());
+        assert_eq!(boards, applied_once);
+        assert!(diff_mirror(&base, &mirror_of(&boards), &theirs)
+            .changes
+            .iter()
+            .all(|change| matches!(change, MirrorChange::AddCard { .. })));
+    }
+}
@@ -0,0 +1,47 @@
+//! The kanban data model and the save file format. Nothing in here depends on the terminal UI,
+//! so it is also built with `default-features = false` for tools that only work with saves
+//!
+//! ```
+//! use rust_kanban::model::{
+//!     date_time_format::DateTimeFormat,
+//!     kanban::{Board, Boards, Card, CardPriority},
+//!     save_file::{load_save, save},
+//! };
+//!
+//! let mut board = Board::new("Inbox", "Added by a script");
+//! board.cards.add_card(Card::new(
+//!     "Triage",
+//!     "Look through new issues",
+//!     "",
+//!     CardPriority::Medium,
+//!     vec![],
+//!     vec![],
+//!     DateTimeFormat::default(),
+//! ));
+//! let mut boards = Boards::default();
+//! boards.add_board(board);
+//!
+//! let save_path = std::env::temp_dir().join("rust_kanban_model_example.json");
+//! save(boards.get_boards(), &save_path, DateTimeFormat::default())?;
+//! let loaded_boards = load_save(&save_path)?;
+//! assert_eq!(loaded_boards.len(), 1);
+//! # std::fs::remove_file(&save_path).ok();
+//! # Ok::<(), String>(())
+//! ```
+
+pub mod card_templates;
+pub mod cloud_sync;
+pub mod csv_export;
+pub mod date_range_filter;
+pub mod date_time_format;
+pub mod duplicate_boards;
+pub mod github_issues;
+pub mod ids;
+pub mod kanban;
+pub mod markdown_export;
+pub mod markdown_mirror;
+pub mod plain_text_export;
+pub mod save_file;
+pub mod stats_history;
+pub mod text_color;
+pub mod trello;
@@ -0,0 +1,73 @@
+use crate::{
+    constants::FIELD_NOT_SET,
+    model::kanban::{Board, Card, CardStatus},
+};
+
+/// Renders a card as a plain text block for pasting outside the app: the name, its due date
+/// and tags, then the description and comments when there are any
+///
+/// ```
+/// use rust_kanban::model::{kanban::Card, plain_text_export::card_to_plain_text};
+///
+/// let mut card = Card::default();
+/// card.name = "Fix crash".to_string();
+/// card.description = "Happens on resize".to_string();
+/// card.due_date = "2024/05/10".to_string();
+/// card.tags = vec!["bug".to_string(), "ui".to_string()];
+/// card.comments = vec!["Seen on macOS\nand Linux".to_string()];
+/// assert_eq!(
+///     card_to_plain_text(&card),
+///     "Fix crash\nDue date: 2024/05/10\nTags: bug, ui\n\nHappens on resize\n\nComments:\n- Seen on macOS\n  and Linux\n"
+/// );
+/// ```
+pub fn card_to_plain_text(card: &Card) -> String {
+    let due_date = if card.due_date.trim().is_empty() {
+        FIELD_NOT_SET
+    } else {
+        card.due_date.trim()
+    };
+    let tags = if card.tags.is_empty() {
+        FIELD_NOT_SET.to_string()
+    } else {
+        card.tags.join(", ")
+    };
+    let mut text = format!("{}\nDue date: {}\nTags: {}\n", card.name, due_date, tags);
+    if !card.description.trim().is_empty() {
+        text.push_str(&format!("\n{}\n", card.description.trim()));
+    }
+    if !card.comments.is_empty() {
+        text.push_str("\nComments:\n");
+        for comment in &card.comments {
+            // Later lines of a comment are indented to stay under their bullet
+            text.push_str(&format!("- {}\n", comment.trim().replace('\n', "\n  ")));
+        }
+    }
+    text
+}
+
+/// Renders a board as its name and description followed by a block per card, archived cards
+/// are left out like they are on the board. `filter_note` goes under the name when only some
+/// cards are included
+pub fn board_to_plain_text(board: &Board, filter_note: Option<&str>) -> String {
+    let mut text = format!(
+        "{}\n{}\n",
+        board.name,
+        "=".repeat(board.name.chars().count())
+    );
+    if let Some(filter_note) = filter_note {
+        text.push_str(&format!("({})\n", filter_note));
+    }
+    if !board.description.trim().is_empty() {
+        text.push_str(&format!("\n{}\n", board.description.trim()));
+    }
+    for card in board
+        .cards
+        .get_all_cards()
+        .iter()
+        .filter(|card| card.card_status != CardStatus::Archived)
+    {
+        text.push('\n');
+        text.push_str(&card_to_plain_text(card));
+    }
+    text
+}
@@ -0,0 +1,419 @@
+//! The JSON save file format shared by the app and anything else that reads or writes saves
+
+use crate::{
+    constants::ATOMIC_WRITE_TEMP_FILE_EXTENSION,
+    model::{
+        cloud_sync::CloudSyncMetadata,
+        date_time_format::DateTimeFormat,
+        kanban::{Board, Boards},
+    },
+};
+use log::debug;
+use serde::{Deserialize, Serialize};
+use std::{
+    fs,
+    io::Write,
+    path::{Path, PathBuf},
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+/// Tells apart the temp files of writes from the same process
+static ATOMIC_WRITE_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ExportStruct {
+    pub boards: Vec<Board>,
+    pub export_date: String,
+    pub kanban_version: String,
+    /// What the last acknowledged cloud sync uploaded, carried over to every newer save
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cloud_sync: Option<CloudSyncMetadata>,
+}
+
+/// Reads the boards out of a save file written by [`save`] or by the app
+pub fn load_save(file_path: &Path) -> Result<Boards, String> {
+    let file = fs::File::open(file_path);
+    if file.is_err() {
+        debug!("Error opening save file: {}", file.err().unwrap());
+        return Err("Error opening save file".to_string());
+    }
+    let file = file.unwrap();
+    let serde_object = serde_json::from_reader(file);
+    if serde_object.is_err() {
+        debug!("Error parsing save file: {}", serde_object.err().unwrap());
+        return Err("Error parsing save file".to_string());
+    }
+    let serde_object: serde_json::Value = serde_object.unwrap();
+    let boards = serde_object.get("boards");
+    if boards.is_none() {
+        debug!("Error parsing save file, no boards found");
+        return Err("Error parsing save file".to_string());
+    }
+    let boards = boards.unwrap();
+    let boards = boards.as_array();
+    if boards.is_none() {
+        debug!("Error parsing save file, boards is not an array");
+        return Err("Error parsing save file".to_string());
+    }
+    let boards = boards.unwrap();
+    let mut parsed_boards = Vec::new();
+    for board in boards {
+        let parsed_board = Board::from_json(board)?;
+        parsed_boards.push(parsed_board);
+    }
+    Ok(Boards::from(parsed_boards))
+}
+
+/// Reads the boards out of save file JSON that did not come from this app's save directory.
+/// Unlike [`load_save`] the error names the board and field that could not be read, so the
+/// file can be fixed by hand
+pub fn parse_save_boards(json: &str) -> Result<Vec<Board>, String> {
+    let serde_object: serde_json::Value =
+        serde_json::from_str(json).map_err(|e| format!("not valid JSON: {}", e))?;
+    let boards = serde_object["boards"]
+        .as_array()
+        .ok_or_else(|| "boards is missing or is not a list".to_string())?;
+    boards
+        .iter()
+        .enumerate()
+        .map(|(index, board)| {
+            Board::from_json(board).map_err(|e| format!("board {}: {}", index + 1, e))
+        })
+        .collect()
+}
+
+/// Writes the boards as a save file, `date_time_format` is only used for the export date
+/// recorded in the file
+pub fn save(
+    boards: &[Board],
+    file_path: &Path,
+    date_time_format: DateTimeFormat,
+) -> Result<(), String> {
+    save_with_cloud_sync(boards, file_path, date_time_format, None)
+}
+
+/// [`save`] with the cloud sync bookkeeping in the file metadata
+pub fn save_with_cloud_sync(
+    boards: &[Board],
+    file_path: &Path,
+    date_time_format: DateTimeFormat,
+    cloud_sync: Option<&CloudSyncMetadata>,
+) -> Result<(), String> {
+    let export_struct = ExportStruct {
+        boards: boards.to_vec(),
+        export_date: format!(
+            "{} ({})",
+            chrono::Local::now().format(date_time_format.to_parser_string()),
+            date_time_format.to_human_readable_string()
+        ),
+        kanban_version: env!("CARGO_PKG_VERSION").to_string(),
+        cloud_sync: cloud_sync.cloned(),
+    };
+    let export_json = serde_json::to_string_pretty(&export_struct)
+        .map_err(|e| format!("Could not serialize boards: {}", e))?;
+    write_file_atomically(file_path, export_json.as_bytes())
+}
+
+/// The cloud sync bookkeeping of a save file, None for saves written before the last sync or
+/// that can not be read
+pub fn load_cloud_sync_metadata(file_path: &Path) -> Option<CloudSyncMetadata> {
+    let json = fs::read_to_string(file_path).ok()?;
+    let mut serde_object: serde_json::Value = serde_json::from_str(&json).ok()?;
+    serde_json::from_value(serde_object.get_mut("cloud_sync")?.take()).ok()
+}
+
+/// Replaces the cloud sync bookkeeping of an existing save file and leaves the rest as it is
+pub fn store_cloud_sync_metadata(
+    file_path: &Path,
+    cloud_sync: &CloudSyncMetadata,
+) -> Result<(), String> {
+    let json = fs::read_to_string(file_path)
+        .map_err(|e| format!("Could not read {}: {}", file_path.display(), e))?;
+    let mut export_struct: ExportStruct = serde_json::from_str(&json)
+        .map_err(|e| format!("Could not parse {}: {}", file_path.display(), e))?;
+    export_struct.cloud_sync = Some(cloud_sync.clone());
+    let export_json = serde_json::to_string_pretty(&export_struct)
+        .map_err(|e| format!("Could not serialize boards: {}", e))?;
+    write_file_atomically(file_path, export_json.as_bytes())
+}
+
+fn atomic_write_temp_prefix(path: &Path) -> String {
+    let file_name = path
+        .file_name()
+        .map(|file_name| file_name.to_string_lossy().to_string())
+        .unwrap_or_default();
+    format!(".{}.", file_name)
+}
+
+/// A hidden file next to `path` for one write to go to before it replaces `path`, the leading
+/// dot keeps it out of the save file and theme listings. Every call gives a new name, so two
+/// writes to the same file, like an autosave and the save on exit, never share a temp file
+pub fn get_atomic_write_temp_path(path: &Path) -> PathBuf {
+    path.with_file_name(format!(
+        "{}{}.{}.{}",
+        atomic_write_temp_prefix(path),
+        std::process::id(),
+        ATOMIC_WRITE_COUNTER.fetch_add(1, Ordering::Relaxed),
+        ATOMIC_WRITE_TEMP_FILE_EXTENSION
+    ))
+}
+
+/// Temp files writes to `path` left behind when they were cut off before the rename, the most
+/// recently written first
+pub fn get_leftover_temp_paths(path: &Path) -> Vec<PathBuf> {
+    let prefix = atomic_write_temp_prefix(path);
+    let suffix = format!(".{}", ATOMIC_WRITE_TEMP_FILE_EXTENSION);
+    let Some(read_dir) = path.parent().and_then(|parent| fs::read_dir(parent).ok()) else {
+        return vec![];
+    };
+    let mut leftover_temp_paths = read_dir
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| {
+            let file_name = entry.file_name().to_string_lossy().to_string();
+            file_name.starts_with(&prefix) && file_name.ends_with(&suffix)
+        })
+        .map(|entry| {
+            let modified = entry
+                .metadata()
+                .and_then(|metadata| metadata.modified())
+                .ok();
+            (modified, entry.path())
+        })
+        .collect::<Vec<_>>();
+    leftover_temp_paths.sort_by(|a, b| b.cmp(a));
+    leftover_temp_paths
+        .into_iter()
+        .map(|(_, temp_path)| temp_path)
+        .collect()
+}
+
+/// Writes to a temp file in the same directory and renames it over `path`, so a crash or power
+/// cut leaves either the old or the new file and never a half written one. The rename replaces
+/// an existing file on Windows as well. The temp file is removed again when the write fails
+pub fn write_file_atomically(path: &Path, contents: &[u8]) -> Result<(), String> {
+    let temp_path = get_atomic_write_temp_path(path);
+    let mut temp_file = fs::File::create(&temp_path)
+        .map_err(|e| format!("Could not create {}: {}", temp_path.display(), e))?;
+    let written = temp_file
+        .write_all(contents)
+        .and_then(|_| temp_file.sync_all())
+        .map_err(|e| format!("Could not write {}: {}", temp_path.display(), e));
+    drop(temp_file);
+    if let Err(e) = written.and_then(|_| {
+        fs::rename(&temp_path, path)
+            .map_err(|e| format!("Could not replace {}: {}", path.display(), e))
+    }) {
+        if let Err(remove_error) = fs::remove_file(&temp_path) {
+            debug!("Could not remove {}: {}", temp_path.display(), remove_error);
+        }
+        return Err(e);
+    }
+    // The rename itself only survives a power cut once the directory entry is on disk
+    #[cfg(unix)]
+    if let Some(parent_dir) = path.parent() {
+        // A bare file name has an empty parent, which is the current directory
+        let parent_dir = if parent_dir.as_os_str().is_empty() {
+            Path::new(".")
+        } else {
+            parent_dir
+        };
+        fs::File::open(parent_dir)
+            .and_then(|dir| dir.sync_all())
+            .map_err(|e| format!("Could not sync {}: {}", parent_dir.display(), e))?;
+    }
+    Ok(())
+}
+
+pub fn make_file_system_safe_name(name: &str) -> String {
+    let mut safe_name = name.to_string();
+    let unsafe_chars = vec!["/", "\\", ":", "*", "?", "\"", "<", ">", "|", " "];
+    for unsafe_char in unsafe_chars {
+        safe_name = safe_name.replace(unsafe_char, "");
+    }
+    safe_name
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{
+        cloud_sync::board_hashes,
+        kanban::{Card, CardPriority, CardStatus, ChecklistItem},
+    };
+
+    fn temp_save_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("rust_kanban_{}_{}.json", name, std::process::id()))
+    }
+
+    fn sample_boards() -> Vec<Board> {
+        let mut todo = Board::new("Todo", "Things to do");
+        let mut card = Card::new(
+            "Write docs",
+            "For the save format",
+            "2024/05/01-12:00:00",
+            CardPriority::High,
+            vec!["docs".to_string(), "chore".to_string()],
+            vec!["Started".to_string()],
+            DateTimeFormat::default(),
+        );
+        card.checklist.push(ChecklistItem::new("Outline"));
+        todo.cards.add_card(card);
+        todo.cards.add_card(Card::default());
+        let mut done = Board::new("Done", "");
+        done.cards.add_card(Card {
+            card_status: CardStatus::Complete,
+            date_completed: "2024/04/01-09:30:00".to_string(),
+            ..Default::default()
+        });
+        vec![todo, done]
+    }
+
+    /// The lines of a save file without the export date, which is stamped on every save
+    fn save_lines(path: &Path) -> Vec<String> {
+        fs::read_to_string(path)
+            .unwrap()
+            .lines()
+            .filter(|line| !line.trim_start().starts_with("\"export_date\""))
+            .map(String::from)
+            .collect()
+    }
+
+    #[test]
+    fn saving_a_loaded_save_gives_the_same_bytes() {
+        let first_path = temp_save_path("round_trip_first");
+        let second_path = temp_save_path("round_trip_second");
+        save(&sample_boards(), &first_path, DateTimeFormat::default()).unwrap();
+        let loaded_boards = load_save(&first_path).unwrap();
+        save(
+            loaded_boards.get_boards(),
+            &second_path,
+            DateTimeFormat::default(),
+        )
+        .unwrap();
+        assert_eq!(save_lines(&first_path), save_lines(&second_path));
+        fs::remove_file(&first_path).ok();
+        fs::remove_file(&second_path).ok();
+    }
+
+    #[test]
+    fn editing_one_card_only_changes_its_lines() {
+        let first_path = temp_save_path("one_card_first");
+        let second_path = temp_save_path("one_card_second");
+        save(&sample_boards(), &first_path, DateTimeFormat::default()).unwrap();
+        let mut boards = load_save(&first_path).unwrap().get_boards().clone();
+        let card = &mut boards[0].cards.get_mut_all_cards()[1];
+        card.name = "Renamed card".to_string();
+        card.date_modified = "2024/06/01-08:00:00".to_string();
+        save(&boards, &second_path, DateTimeFormat::default()).unwrap();
+
+        let first_lines = save_lines(&first_path);
+        let second_lines = save_lines(&second_path);
+        assert_eq!(first_lines.len(), second_lines.len());
+        let changed_lines = first_lines
+            .iter()
+            .zip(second_lines.iter())
+            .filter(|(first, second)| first != second)
+            .map(|(_, second)| second.trim())
+            .collect::<Vec<&str>>();
+        assert_eq!(
+            changed_lines,
+            vec![
+                "\"date_modified\": \"2024/06/01-08:00:00\",",
+                "\"name\": \"Renamed card\",",
+            ]
+        );
+        fs::remove_file(&first_path).ok();
+        fs::remove_file(&second_path).ok();
+    }
+
+    #[test]
+    fn cloud_sync_metadata_is_kept_in_the_save_file() {
+        let path = temp_save_path("cloud_sync_metadata");
+        let boards = vec![Board::new("Todo", "")];
+        save(&boards, &path, DateTimeFormat::default()).unwrap();
+        assert_eq!(load_cloud_sync_metadata(&path), None);
+
+        let metadata = CloudSyncMetadata {
+            user_id: "user".to_string(),
+            save_id: 4,
+            delta_depth: 1,
+            board_hashes: board_hashes(&boards),
+        };
+        store_cloud_sync_metadata(&path, &metadata).unwrap();
+        assert_eq!(load_cloud_sync_metadata(&path), Some(metadata.clone()));
+        assert_eq!(load_save(&path).unwrap().get_boards(), &boards);
+
+        save_with_cloud_sync(&boards, &path, DateTimeFormat::default(), Some(&metadata)).unwrap();
+        assert_eq!(load_cloud_sync_metadata(&path), Some(metadata));
+        fs::remove_file(&path).ok();
+    }
+
+    #[test]
+    fn every_atomic_write_gets_its_own_temp_file() {
+        let path = temp_save_path("unique_temp");
+        let first_temp_path = get_atomic_write_temp_path(&path);
+        let second_temp_path = get_atomic_write_temp_path(&path);
+        assert_ne!(first_temp_path, second_temp_path);
+        assert_eq!(first_temp_path.parent(), path.parent());
+        for temp_path in [first_temp_path, second_temp_path] {
+            let file_name = temp_path.file_name().unwrap().to_string_lossy().to_string();
+            assert!(file_name.starts_with(&atomic_write_temp_prefix(&path)));
+            assert!(file_name.ends_with(ATOMIC_WRITE_TEMP_FILE_EXTENSION));
+        }
+    }
+
+    #[test]
+    fn concurrent_writes_leave_a_whole_file_and_no_temp_files() {
+        let path = temp_save_path("concurrent_writes");
+        let contents = (0..8)
+            .map(|writer| format!("writer {} ", writer).repeat(4096))
+            .collect::<Vec<String>>();
+        std::thread::scope(|scope| {
+            for writer_contents in &contents {
+                let path = &path;
+                scope.spawn(move || write_file_atomically(path, writer_contents.as_bytes()));
+            }
+        });
+        let written = fs::read_to_string(&path).unwrap();
+        assert!(contents.contains(&written));
+        assert!(get_leftover_temp_paths(&path).is_empty());
+        fs::remove_file(&path).ok();
+    }
+
+    #[test]
+    fn a_failed_write_removes_its_temp_file() {
+        let path = temp_save_path("failed_write");
+        fs::create_dir_all(path.join("in_the_way")).unwrap();
+        assert!(write_file_atomically(&path, b"{}").is_err());
+        assert!(get_leftover_temp_paths(&path).is_empty());
+        fs::remove_dir_all(&path).ok();
+    }
+
+    #[test]
+    fn an_interrupted_write_leaves_the_original_untouched() {
+        let path = temp_save_path("interrupted_write");
+        let boards = sample_boards();
+        save(&boards, &path, DateTimeFormat::default()).unwrap();
+        let original = fs::read(&path).unwrap();
+        let temp_path = get_atomic_write_temp_path(&path);
+        fs::write(&temp_path, &original[..original.len() / 2]).unwrap();
+
+        assert_eq!(fs::read(&path).unwrap(), original);
+        assert_eq!(load_save(&path).unwrap().get_boards(), &boards);
+        assert_eq!(get_leftover_temp_paths(&path), vec![temp_path.clone()]);
+        assert!(load_save(&temp_path).is_err());
+        fs::remove_file(&temp_path).ok();
+        fs::remove_file(&path).ok();
+    }
+
+    #[test]
+    fn a_bare_file_name_is_written_to_the_current_directory() {
+        let bare_path = PathBuf::from(format!("rust_kanban_bare_{}.json", std::process::id()));
+        assert_eq!(bare_path.parent(), Some(Path::new("")));
+        let written = write_file_atomically(&bare_path, b"[]");
+        let contents = fs::read(&bare_path);
+        fs::remove_file(&bare_path).ok();
+        assert_eq!(written, Ok(()));
+        assert_eq!(contents.unwrap(), b"[]");
+    }
+}
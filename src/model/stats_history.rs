@@ -0,0 +1,265 @@
+//! A small record of the card counts taken on every save that changed something, kept next to the
+//! save files so the Stats view can show whether the backlog is shrinking
+//!
+//! ```
+//! use rust_kanban::model::stats_history::render_sparkline;
+//!
+//! // Scaled between the lowest and highest value of the series
+//! assert_eq!(render_sparkline(&[0, 4, 8], 10), "▁▅█");
+//! // Only the most recent values are kept when the series is wider than the sparkline
+//! assert_eq!(render_sparkline(&[8, 0, 4, 8], 3), "▁▅█");
+//! // A single point or a flat series has nothing to scale against
+//! assert_eq!(render_sparkline(&[3], 10), "▄");
+//! assert_eq!(render_sparkline(&[3, 3], 10), "▄▄");
+//! assert_eq!(render_sparkline(&[], 10), "");
+//! assert_eq!(render_sparkline(&[1, 2], 0), "");
+//! ```
+
+use crate::{
+    constants::{MAX_STATS_HISTORY_ENTRIES, SPARKLINE_SYMBOLS},
+    model::{
+        date_time_format::parse_date_time,
+        kanban::{Board, CardStatus},
+        save_file::write_file_atomically,
+    },
+};
+use serde::{Deserialize, Serialize};
+use std::{fmt, fs, path::Path};
+
+/// The card counts at the time of one save
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
+pub struct StatsRecord {
+    pub date: String,
+    pub total_cards: usize,
+    pub active_cards: usize,
+    pub completed_cards: usize,
+    pub stale_cards: usize,
+    pub archived_cards: usize,
+    /// Cards that are not complete or archived and whose due date has passed
+    pub overdue_cards: usize,
+}
+
+impl StatsRecord {
+    pub fn from_boards(boards: &[Board], date: &str) -> Self {
+        let now = chrono::Local::now().naive_local();
+        let mut record = StatsRecord {
+            date: date.to_string(),
+            ..Default::default()
+        };
+        for card in boards
+            .iter()
+            .flat_map(|board| board.cards.get_all_cards().iter())
+        {
+            record.total_cards += 1;
+            match card.card_status {
+                CardStatus::Active => record.active_cards += 1,
+                CardStatus::Complete => record.completed_cards += 1,
+                CardStatus::Stale => record.stale_cards += 1,
+                CardStatus::Archived => record.archived_cards += 1,
+            }
+            let is_open = matches!(card.card_status, CardStatus::Active | CardStatus::Stale);
+            if is_open
+                && parse_date_time(&card.due_date)
+                    .is_some_and(|due_date| due_date.date() < now.date())
+            {
+                record.overdue_cards += 1;
+            }
+        }
+        record
+    }
+
+    fn has_same_counts(&self, other: &StatsRecord) -> bool {
+        StatsRecord {
+            date: String::new(),
+            ..self.clone()
+        } == StatsRecord {
+            date: String::new(),
+            ..other.clone()
+        }
+    }
+}
+
+/// What the Stats view plots, Left and Right cycle through these
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StatsMetric {
+    #[default]
+    OpenCards,
+    TotalCards,
+    CompletedCards,
+    OverdueCards,
+}
+
+impl StatsMetric {
+    const ALL: [StatsMetric; 4] = [
+        StatsMetric::OpenCards,
+        StatsMetric::TotalCards,
+        StatsMetric::CompletedCards,
+        StatsMetric::OverdueCards,
+    ];
+
+    pub fn value(&self, record: &StatsRecord) -> usize {
+        match self {
+            StatsMetric::OpenCards => record.active_cards + record.stale_cards,
+            StatsMetric::TotalCards => record.total_cards,
+            StatsMetric::CompletedCards => record.completed_cards,
+            StatsMetric::OverdueCards => record.overdue_cards,
+        }
+    }
+
+    pub fn next(&self) -> Self {
+        let index = Self::ALL
+            .iter()
+            .position(|metric| metric == self)
+            .unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+
+    pub fn prev(&self) -> Self {
+        let index = Self::ALL
+            .iter()
+            .position(|metric| metric == self)
+            .unwrap_or(0);
+        Self::ALL[(index + Self::ALL.len() - 1) % Self::ALL.len()]
+    }
+}
+
+impl fmt::Display for StatsMetric {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            StatsMetric::OpenCards => write!(f, "Open cards"),
+            StatsMetric::TotalCards => write!(f, "Total cards"),
+            StatsMetric::CompletedCards => write!(f, "Completed cards"),
+            StatsMetric::OverdueCards => write!(f, "Overdue cards"),
+        }
+    }
+}
+
+/// Reads the history, a missing or corrupt file gives an empty history that the next save
+/// starts over
+pub fn load_stats_history(path: &Path) -> Vec<StatsRecord> {
+    fs::read_to_string(path)
+        .ok()
+        .and_then(|contents| serde_json::from_str(&contents).ok())
+        .unwrap_or_default()
+}
+
+/// Adds a record to the history at path, dropping the oldest ones past MAX_STATS_HISTORY_ENTRIES.
+/// A record with the same counts as the latest one is skipped, saves that only edit card text
+/// would otherwise flatten the sparkline
+pub fn append_stats_record(path: &Path, record: StatsRecord) -> Result<(), String> {
+    let mut history = load_stats_history(path);
+    if history
+        .last()
+        .is_some_and(|last| last.has_same_counts(&record))
+    {
+        return Ok(());
+    }
+    history.push(record);
+    let overflow = history.len().saturating_sub(MAX_STATS_HISTORY_ENTRIES);
+    history.drain(..overflow);
+    let contents = serde_json::to_vec(&history)
+        .map_err(|e| format!("Could not serialize stats history: {}", e))?;
+    write_file_atomically(path, &contents)
+}
+
+/// One block character per value, scaled between the lowest and highest value shown. When there
+/// are more values than width only the most recent ones are drawn
+pub fn render_sparkline(series: &[usize], width: usize) -> String {
+    let series = &series[series.len().saturating_sub(width)..];
+    let (Some(min), Some(max)) = (series.iter().min(), series.iter().max()) else {
+        return String::new();
+    };
+    let levels = SPARKLINE_SYMBOLS.len() - 1;
+    series
+        .iter()
+        .map(|value| {
+            let level = if max == min {
+                levels / 2
+            } else {
+                ((value - min) * levels + (max - min) / 2) / (max - min)
+            };
+            SPARKLINE_SYMBOLS[level]
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn temp_history_path(name: &str) -> PathBuf {
+        let path =
+            std::env::temp_dir().join(format!("rust_kanban_{}_{}.json", name, std::process::id()));
+        fs::remove_file(&path).ok();
+        path
+    }
+
+    fn record(date: &str, total_cards: usize) -> StatsRecord {
+        StatsRecord {
+            date: date.to_string(),
+            total_cards,
+            active_cards: total_cards,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn sparkline_scales_between_the_lowest_and_highest_value() {
+        assert_eq!(render_sparkline(&[10, 17, 24], 10), "▁▅█");
+        assert_eq!(render_sparkline(&[0, 1, 2, 3, 4, 5, 6, 7], 8), "▁▂▃▄▅▆▇█");
+        assert_eq!(render_sparkline(&[24, 0, 10, 17, 24], 3), "▁▅█");
+    }
+
+    #[test]
+    fn sparkline_of_a_single_point_or_nothing() {
+        assert_eq!(render_sparkline(&[0], 10), "▄");
+        assert_eq!(render_sparkline(&[42], 1), "▄");
+        assert_eq!(render_sparkline(&[], 10), "");
+        assert_eq!(render_sparkline(&[5], 0), "");
+    }
+
+    #[test]
+    fn history_keeps_only_the_newest_records() {
+        let path = temp_history_path("stats_history_cap");
+        for total_cards in 0..MAX_STATS_HISTORY_ENTRIES + 5 {
+            append_stats_record(&path, record(&total_cards.to_string(), total_cards)).unwrap();
+        }
+        let history = load_stats_history(&path);
+        assert_eq!(history.len(), MAX_STATS_HISTORY_ENTRIES);
+        assert_eq!(history[0].date, "5");
+        assert_eq!(
+            history.last().unwrap().total_cards,
+            MAX_STATS_HISTORY_ENTRIES + 4
+        );
+        fs::remove_file(&path).ok();
+    }
+
+    #[test]
+    fn a_corrupt_history_is_rebuilt_by_the_next_save() {
+        let path = temp_history_path("stats_history_corrupt");
+        fs::write(&path, "[{\"date\": \"2024/05/01\", \"total_ca").unwrap();
+        assert!(load_stats_history(&path).is_empty());
+
+        append_stats_record(&path, record("2024/05/02", 3)).unwrap();
+        assert_eq!(load_stats_history(&path), vec![record("2024/05/02", 3)]);
+        fs::remove_file(&path).ok();
+    }
+
+    #[test]
+    fn a_record_with_unchanged_counts_is_skipped() {
+        let path = temp_history_path("stats_history_unchanged");
+        append_stats_record(&path, record("2024/05/01", 3)).unwrap();
+        append_stats_record(&path, record("2024/05/02", 3)).unwrap();
+        assert_eq!(load_stats_history(&path), vec![record("2024/05/01", 3)]);
+
+        append_stats_record(&path, record("2024/05/03", 4)).unwrap();
+        append_stats_record(&path, record("2024/05/04", 3)).unwrap();
+        let dates = load_stats_history(&path)
+            .into_iter()
+            .map(|record| record.date)
+            .collect::<Vec<String>>();
+        assert_eq!(dates, ["2024/05/01", "2024/05/03", "2024/05/04"]);
+        fs::remove_file(&path).ok();
+    }
+}
@@ -0,0 +1,66 @@
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumIter, IntoEnumIterator};
+
+#[derive(Debug, Clone, Serialize, Deserialize, EnumIter, Display, Copy, PartialEq, Eq)]
+pub enum TextColorOptions {
+    Black,
+    Blue,
+    Cyan,
+    DarkGray,
+    Gray,
+    Green,
+    LightBlue,
+    LightCyan,
+    LightGreen,
+    LightMagenta,
+    LightRed,
+    LightYellow,
+    Magenta,
+    None,
+    #[strum(to_string = "HEX #{0:02x}{1:02x}{2:02x}")]
+    HEX(u8, u8, u8),
+    Red,
+    White,
+    Yellow,
+}
+
+impl TextColorOptions {
+    /// Options offered for card highlights, None clears the highlight
+    pub fn card_highlight_options() -> Vec<Option<TextColorOptions>> {
+        let mut options = vec![None];
+        options.extend(
+            TextColorOptions::iter()
+                .filter(|color| {
+                    !matches!(
+                        color,
+                        TextColorOptions::None | TextColorOptions::HEX(_, _, _)
+                    )
+                })
+                .map(Some),
+        );
+        options
+    }
+
+    pub fn to_rgb(&self) -> (u8, u8, u8) {
+        match self {
+            TextColorOptions::Black => (0, 0, 0),
+            TextColorOptions::Blue => (0, 0, 128),
+            TextColorOptions::Cyan => (0, 128, 128),
+            TextColorOptions::DarkGray => (128, 128, 128),
+            TextColorOptions::Gray => (192, 192, 192),
+            TextColorOptions::Green => (0, 128, 0),
+            TextColorOptions::LightBlue => (0, 0, 255),
+            TextColorOptions::LightCyan => (0, 255, 255),
+            TextColorOptions::LightGreen => (255, 255, 0),
+            TextColorOptions::LightMagenta => (255, 0, 255),
+            TextColorOptions::LightRed => (255, 0, 0),
+            TextColorOptions::LightYellow => (0, 255, 0),
+            TextColorOptions::Magenta => (128, 0, 128),
+            TextColorOptions::None => (0, 0, 0),
+            TextColorOptions::Red => (128, 0, 0),
+            TextColorOptions::HEX(r, g, b) => (*r, *g, *b),
+            TextColorOptions::White => (255, 255, 255),
+            TextColorOptions::Yellow => (128, 128, 0),
+        }
+    }
+}
@@ -0,0 +1,186 @@
+use crate::model::{
+    date_time_format::DateTimeFormat,
+    kanban::{Board, Card, CardPriority, CardStatus},
+};
+use chrono::DateTime;
+use serde::Deserialize;
+use std::collections::HashMap;
+
+/// The parts of Trello's board export (Menu > Print and export > Export as JSON) that have
+/// somewhere to go, everything else in the file is ignored
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct TrelloExport {
+    name: String,
+    #[serde(default)]
+    lists: Vec<TrelloList>,
+    #[serde(default)]
+    cards: Vec<TrelloCard>,
+    #[serde(default)]
+    actions: Vec<TrelloAction>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct TrelloList {
+    id: String,
+    name: String,
+    #[serde(default)]
+    closed: bool,
+    #[serde(default)]
+    pos: f64,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct TrelloCard {
+    id: String,
+    name: String,
+    #[serde(default)]
+    desc: String,
+    id_list: String,
+    #[serde(default)]
+    closed: bool,
+    #[serde(default)]
+    pos: f64,
+    #[serde(default)]
+    due: Option<String>,
+    #[serde(default)]
+    due_complete: bool,
+    #[serde(default)]
+    labels: Vec<TrelloLabel>,
+}
+
+#[derive(Deserialize, Debug)]
+struct TrelloLabel {
+    #[serde(default)]
+    name: String,
+    #[serde(default)]
+    color: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+struct TrelloAction {
+    #[serde(rename = "type")]
+    action_type: String,
+    #[serde(default)]
+    data: TrelloActionData,
+}
+
+#[derive(Deserialize, Debug, Default)]
+struct TrelloActionData {
+    #[serde(default)]
+    card: Option<TrelloActionCard>,
+    #[serde(default)]
+    text: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+struct TrelloActionCard {
+    id: String,
+}
+
+// Unnamed labels are only a color in Trello, so the color stands in as the tag
+fn label_to_tag(label: &TrelloLabel) -> Option<String> {
+    let tag = if label.name.trim().is_empty() {
+        label.color.clone()?
+    } else {
+        label.name.trim().to_string()
+    };
+    (!tag.is_empty()).then_some(tag)
+}
+
+// Trello keeps due dates in UTC, they are shown in local time like the rest of the app
+fn convert_due_date(due: &str, date_time_format: DateTimeFormat) -> Option<String> {
+    let due = DateTime::parse_from_rfc3339(due).ok()?;
+    let date_time_format = DateTimeFormat::add_time_to_date_format(date_time_format);
+    Some(
+        due.with_timezone(&chrono::Local)
+            .naive_local()
+            .format(date_time_format.to_parser_string())
+            .to_string(),
+    )
+}
+
+/// Turns a Trello board export into boards, one per open list in Trello's order. Labels become
+/// tags, comments are kept oldest first and archived lists and cards are left out
+pub fn parse_trello_export(
+    json: &str,
+    date_time_format: DateTimeFormat,
+) -> Result<Vec<Board>, String> {
+    let export: TrelloExport = serde_json::from_str(json)
+        .map_err(|e| format!("Not a valid Trello board export: {}", e))?;
+
+    let mut comments: HashMap<&str, Vec<String>> = HashMap::new();
+    // Actions are listed newest first
+    for action in export.actions.iter().rev() {
+        if action.action_type != "commentCard" {
+            continue;
+        }
+        if let (Some(card), Some(text)) = (&action.data.card, &action.data.text) {
+            comments
+                .entry(card.id.as_str())
+                .or_default()
+                .push(text.clone());
+        }
+    }
+
+    let mut lists = export
+        .lists
+        .iter()
+        .filter(|list| !list.closed)
+        .collect::<Vec<&TrelloList>>();
+    if lists.is_empty() {
+        return Err(format!(
+            "The Trello board '{}' has no open lists to import",
+            export.name
+        ));
+    }
+    lists.sort_by(|a, b| a.pos.total_cmp(&b.pos));
+    let mut cards = export
+        .cards
+        .iter()
+        .filter(|card| !card.closed)
+        .collect::<Vec<&TrelloCard>>();
+    cards.sort_by(|a, b| a.pos.total_cmp(&b.pos));
+
+    let mut boards = Vec::new();
+    for list in lists {
+        let mut board = Board::new(
+            list.name.trim(),
+            &format!("Imported from the Trello board '{}'", export.name),
+        );
+        for trello_card in cards.iter().filter(|card| card.id_list == list.id) {
+            let mut tags = Vec::new();
+            for tag in trello_card.labels.iter().filter_map(label_to_tag) {
+                if !tags.contains(&tag) {
+                    tags.push(tag);
+                }
+            }
+            let due_date = trello_card
+                .due
+                .as_deref()
+                .and_then(|due| convert_due_date(due, date_time_format))
+                .unwrap_or_default();
+            let mut card = Card::new(
+                trello_card.name.trim(),
+                trello_card.desc.trim(),
+                &due_date,
+                CardPriority::Low,
+                tags,
+                comments
+                    .get(trello_card.id.as_str())
+                    .cloned()
+                    .unwrap_or_default(),
+                date_time_format,
+            );
+            if trello_card.due_complete {
+                card.card_status = CardStatus::Complete;
+                card.date_completed.clone_from(&card.date_modified);
+            }
+            board.cards.add_card(card);
+        }
+        boards.push(board);
+    }
+    Ok(boards)
+}
@@ -0,0 +1,206 @@
+//! How many colors the terminal can show, and bringing RGB colors down to what it can when it
+//! cannot show them as they are
+//!
+//! ```
+//! use ratatui::style::Color;
+//! use rust_kanban::ui::color_depth::{
+//!     degrade_color, detect_color_depth, quantize_to_ansi16, quantize_to_ansi256, ColorDepth,
+//! };
+//!
+//! assert_eq!(
+//!     detect_color_depth(Some("truecolor"), Some("xterm-256color")),
+//!     ColorDepth::TrueColor
+//! );
+//! assert_eq!(detect_color_depth(None, Some("tmux-256color")), ColorDepth::Ansi256);
+//! assert_eq!(detect_color_depth(None, Some("linux")), ColorDepth::Ansi16);
+//! assert_eq!(detect_color_depth(None, Some("xterm-direct")), ColorDepth::TrueColor);
+//! // No TERM at all is a Windows console, which takes RGB colors
+//! assert_eq!(detect_color_depth(None, None), ColorDepth::TrueColor);
+//!
+//! // Primary colors
+//! assert_eq!(quantize_to_ansi256(255, 0, 0), 196);
+//! assert_eq!(quantize_to_ansi256(0, 255, 0), 46);
+//! assert_eq!(quantize_to_ansi256(0, 0, 255), 21);
+//! assert_eq!(quantize_to_ansi16(255, 0, 0), Color::LightRed);
+//! assert_eq!(quantize_to_ansi16(0, 0, 128), Color::Blue);
+//!
+//! // Grays use the gray ramp unless a cube color is as close
+//! assert_eq!(quantize_to_ansi256(128, 128, 128), 244);
+//! assert_eq!(quantize_to_ansi256(0, 0, 0), 16);
+//! assert_eq!(quantize_to_ansi256(255, 255, 255), 231);
+//! assert_eq!(quantize_to_ansi16(128, 128, 128), Color::DarkGray);
+//! assert_eq!(quantize_to_ansi16(200, 200, 200), Color::Gray);
+//!
+//! // Exact cube hits come back unchanged
+//! assert_eq!(quantize_to_ansi256(95, 135, 175), 16 + 36 + 2 * 6 + 3);
+//! assert_eq!(quantize_to_ansi256(215, 0, 95), 16 + 4 * 36 + 1);
+//!
+//! assert_eq!(
+//!     degrade_color(Color::Rgb(255, 0, 0), ColorDepth::Ansi256),
+//!     Color::Indexed(196)
+//! );
+//! assert_eq!(
+//!     degrade_color(Color::Rgb(1, 2, 3), ColorDepth::TrueColor),
+//!     Color::Rgb(1, 2, 3)
+//! );
+//! assert_eq!(degrade_color(Color::Yellow, ColorDepth::Ansi16), Color::Yellow);
+//! ```
+
+use ratatui::{buffer::Buffer, style::Color};
+use serde::{Deserialize, Serialize};
+use strum::EnumString;
+
+/// The levels each channel of the 6x6x6 cube in the 256 color palette can take
+const ANSI256_CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+/// The basic 16 colors with the RGB values xterm uses for them
+const ANSI16_COLORS: [(Color, (u8, u8, u8)); 16] = [
+    (Color::Black, (0, 0, 0)),
+    (Color::Red, (128, 0, 0)),
+    (Color::Green, (0, 128, 0)),
+    (Color::Yellow, (128, 128, 0)),
+    (Color::Blue, (0, 0, 128)),
+    (Color::Magenta, (128, 0, 128)),
+    (Color::Cyan, (0, 128, 128)),
+    (Color::Gray, (192, 192, 192)),
+    (Color::DarkGray, (128, 128, 128)),
+    (Color::LightRed, (255, 0, 0)),
+    (Color::LightGreen, (0, 255, 0)),
+    (Color::LightYellow, (255, 255, 0)),
+    (Color::LightBlue, (0, 0, 255)),
+    (Color::LightMagenta, (255, 0, 255)),
+    (Color::LightCyan, (0, 255, 255)),
+    (Color::White, (255, 255, 255)),
+];
+
+/// Auto uses what was detected at startup, the others override it
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq, EnumString)]
+pub enum ColorDepth {
+    #[default]
+    Auto,
+    TrueColor,
+    Ansi256,
+    Ansi16,
+}
+
+impl ColorDepth {
+    pub fn next(&self) -> ColorDepth {
+        match self {
+            ColorDepth::Auto => ColorDepth::TrueColor,
+            ColorDepth::TrueColor => ColorDepth::Ansi256,
+            ColorDepth::Ansi256 => ColorDepth::Ansi16,
+            ColorDepth::Ansi16 => ColorDepth::Auto,
+        }
+    }
+
+    /// The depth to draw with, the detected one when this is Auto
+    pub fn resolve(&self, detected: ColorDepth) -> ColorDepth {
+        match self {
+            ColorDepth::Auto => detected,
+            depth => *depth,
+        }
+    }
+}
+
+impl std::fmt::Display for ColorDepth {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            ColorDepth::Auto => write!(f, "Auto"),
+            ColorDepth::TrueColor => write!(f, "TrueColor"),
+            ColorDepth::Ansi256 => write!(f, "Ansi256"),
+            ColorDepth::Ansi16 => write!(f, "Ansi16"),
+        }
+    }
+}
+
+/// Guesses the color depth from the COLORTERM and TERM environment variables, never Auto
+pub fn detect_color_depth(colorterm: Option<&str>, term: Option<&str>) -> ColorDepth {
+    if colorterm.is_some_and(|colorterm| {
+        colorterm.eq_ignore_ascii_case("truecolor") || colorterm.eq_ignore_ascii_case("24bit")
+    }) {
+        return ColorDepth::TrueColor;
+    }
+    match term {
+        None => ColorDepth::TrueColor,
+        Some(term) if term.contains("direct") || term.contains("truecolor") => {
+            ColorDepth::TrueColor
+        }
+        Some(term) if term.contains("256") => ColorDepth::Ansi256,
+        Some(_) => ColorDepth::Ansi16,
+    }
+}
+
+/// Reads the environment of the running terminal, see [`detect_color_depth`]
+pub fn detect_terminal_color_depth() -> ColorDepth {
+    let colorterm = std::env::var("COLORTERM").ok();
+    let term = std::env::var("TERM").ok();
+    detect_color_depth(colorterm.as_deref(), term.as_deref())
+}
+
+/// The closest entry of the 256 color palette, from the color cube or the gray ramp. The 16
+/// basic colors are left out as terminals are free to change them
+pub fn quantize_to_ansi256(r: u8, g: u8, b: u8) -> u8 {
+    let nearest_level = |channel: u8| {
+        ANSI256_CUBE_LEVELS
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, level)| (i32::from(**level) - i32::from(channel)).abs())
+            .map(|(index, _)| index as u8)
+            .unwrap_or(0)
+    };
+    let (r_level, g_level, b_level) = (nearest_level(r), nearest_level(g), nearest_level(b));
+    let cube_index = 16 + 36 * r_level + 6 * g_level + b_level;
+    let cube_distance = distance(
+        (r, g, b),
+        (
+            ANSI256_CUBE_LEVELS[r_level as usize],
+            ANSI256_CUBE_LEVELS[g_level as usize],
+            ANSI256_CUBE_LEVELS[b_level as usize],
+        ),
+    );
+    // The ramp runs from 8 to 238 in steps of 10
+    let average = (u32::from(r) + u32::from(g) + u32::from(b)) / 3;
+    let gray_step = (average.saturating_sub(3) / 10).min(23) as u8;
+    let gray_value = 8 + 10 * gray_step;
+    let gray_distance = distance((r, g, b), (gray_value, gray_value, gray_value));
+    if gray_distance < cube_distance {
+        232 + gray_step
+    } else {
+        cube_index
+    }
+}
+
+/// The closest of the basic 16 colors
+pub fn quantize_to_ansi16(r: u8, g: u8, b: u8) -> Color {
+    ANSI16_COLORS
+        .iter()
+        .min_by_key(|(_, rgb)| distance((r, g, b), *rgb))
+        .map(|(color, _)| *color)
+        .unwrap_or(Color::Reset)
+}
+
+/// Brings an RGB color down to the depth, every other color is left as it is
+pub fn degrade_color(color: Color, depth: ColorDepth) -> Color {
+    match (color, depth) {
+        (Color::Rgb(r, g, b), ColorDepth::Ansi256) => Color::Indexed(quantize_to_ansi256(r, g, b)),
+        (Color::Rgb(r, g, b), ColorDepth::Ansi16) => quantize_to_ansi16(r, g, b),
+        _ => color,
+    }
+}
+
+/// Degrades every RGB color in the drawn frame, run last so colors from themes, cards and
+/// previews are all caught
+pub fn degrade_buffer_colors(buffer: &mut Buffer, depth: ColorDepth) {
+    if matches!(depth, ColorDepth::Auto | ColorDepth::TrueColor) {
+        return;
+    }
+    for cell in buffer.content.iter_mut() {
+        cell.fg = degrade_color(cell.fg, depth);
+        cell.bg = degrade_color(cell.bg, depth);
+    }
+}
+
+fn distance(from: (u8, u8, u8), to: (u8, u8, u8)) -> u32 {
+    let channel_distance = |a: u8, b: u8| (i32::from(a) - i32::from(b)).pow(2) as u32;
+    channel_distance(from.0, to.0) + channel_distance(from.1, to.1) + channel_distance(from.2, to.2)
+}
@@ -1,4 +1,4 @@
-use crate::ui::theme::Theme;
+use crate::{constants::HIGH_CONTRAST_THEME_NAME, ui::theme::Theme};
 use ratatui::style::{Color, Modifier, Style};
 
 pub fn default_theme() -> Theme {
@@ -478,3 +478,38 @@ pub fn dracula_theme() -> Theme {
             .bg(Color::Rgb(68, 71, 90)),
     }
 }
+/// Only black and white, emphasis comes from bold and reversed text instead of color or dimming
+pub fn high_contrast_theme() -> Theme {
+    let normal = Style::default().fg(Color::White).bg(Color::Black);
+    let emphasis = normal.add_modifier(Modifier::BOLD);
+    let inverted = Style::default()
+        .fg(Color::Black)
+        .bg(Color::White)
+        .add_modifier(Modifier::BOLD);
+    Theme {
+        card_due_default_style: normal,
+        card_due_overdue_style: inverted,
+        card_due_warning_style: emphasis.add_modifier(Modifier::UNDERLINED),
+        card_priority_high_style: inverted,
+        card_priority_low_style: normal,
+        card_priority_medium_style: emphasis,
+        card_status_active_style: emphasis,
+        card_status_completed_style: normal.add_modifier(Modifier::CROSSED_OUT),
+        card_status_stale_style: normal.add_modifier(Modifier::ITALIC),
+        error_text_style: inverted,
+        general_style: normal,
+        help_key_style: emphasis,
+        help_text_style: normal,
+        inactive_text_style: normal,
+        keyboard_focus_style: inverted,
+        list_select_style: inverted,
+        log_debug_style: normal,
+        log_error_style: inverted,
+        log_info_style: normal,
+        log_trace_style: normal,
+        log_warn_style: emphasis,
+        mouse_focus_style: inverted,
+        name: HIGH_CONTRAST_THEME_NAME.to_string(),
+        progress_bar_style: normal,
+    }
+}
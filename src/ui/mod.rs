@@ -1,6 +1,12 @@
 use crate::{
     app::{state::Focus, App},
-    ui::rendering::view::{BodyHelp, TitleBody, Zen},
+    constants::{
+        MIN_DATE_PICKER_WIDTH, TAG_SELECTOR_HEIGHT, TAG_SELECTOR_WIDTH, TIME_PICKER_WIDTH,
+    },
+    ui::rendering::{
+        common::render_popup_too_small,
+        view::{BodyHelp, TitleBody, Zen},
+    },
 };
 use ratatui::{
     style::{Color, Modifier},
@@ -9,20 +15,32 @@ use ratatui::{
 use rendering::{
     popup::{
         widgets::{CommandPalette, DateTimePicker, TagPicker},
+        BoardAccentColorSelector, BoardStats, CalendarDayCards, CardHighlightColorSelector,
         CardPrioritySelector, CardStatusSelector, ChangeDateFormat, ChangeTheme, ChangeView,
-        ConfirmDiscardCardChanges, CustomHexColorPrompt, EditGeneralConfig, EditSpecificKeybinding,
-        EditThemeStyle, FilterByTag, SaveThemePrompt, SelectDefaultView, ViewCard,
+        ConfirmClearWaitingOn, ConfirmDiscardCardChanges, ConfirmDuplicateCardName,
+        ConfirmKeepDraft, ConfirmQuit, ConfirmRestoreThemesFromBackup, ConflictMarkerWarning,
+        CustomDateRangePrompt, CustomHexColorPrompt, DuplicateBoards, EditGeneralConfig,
+        EditSpecificKeybinding, EditThemeStyle, FilterByDateRange, FilterByPriority,
+        FilterByStatus, FilterByTag, FindReplace, GithubImportSummary, ImportBoardJsonPrompt,
+        ImportGithubPrompt, ImportJsonPrompt, ImportOptions, ImportTrelloPrompt, MirrorSyncSummary,
+        MoveCardToBoard, Notifications, RecoverSaveFile, SaveThemePrompt, SearchCards,
+        SelectCardTemplate, SelectDefaultView, SetLogLevel, SortCardsBy, ViewCard, WaitingOnList,
+        WhatsNew,
     },
     view::{
-        BodyHelpLog, BodyLog, ConfigMenu, CreateTheme, EditKeybindings, HelpMenu, LoadASave,
-        LoadCloudSave, LogView, Login, MainMenuView, NewBoardForm, NewCardForm, ResetPassword,
-        Signup, TitleBodyHelp, TitleBodyHelpLog, TitleBodyLog,
+        ArchivedCards, BodyHelpLog, BodyLog, Calendar, ConfigMenu, CreateTheme, EditBoardNotes,
+        EditKeybindings, HelpMenu, LoadASave, LoadCloudSave, LogView, Login, MainMenuView,
+        NewBoardForm, NewCardForm, ResetPassword, Signup, Stats, Swimlane, TitleBodyHelp,
+        TitleBodyHelpLog, TitleBodyLog,
     },
 };
 use serde::{Deserialize, Serialize};
 use std::fmt::{self, Formatter};
 use strum::{Display, EnumIter, EnumString};
 
+pub use crate::model::text_color::TextColorOptions;
+
+pub mod color_depth;
 pub mod inbuilt_themes;
 pub mod rendering;
 pub mod text_box;
@@ -31,29 +49,6 @@ pub mod ui_helper;
 pub mod ui_main;
 pub mod widgets;
 
-#[derive(Debug, Clone, Serialize, Deserialize, EnumIter, Display, Copy)]
-pub enum TextColorOptions {
-    Black,
-    Blue,
-    Cyan,
-    DarkGray,
-    Gray,
-    Green,
-    LightBlue,
-    LightCyan,
-    LightGreen,
-    LightMagenta,
-    LightRed,
-    LightYellow,
-    Magenta,
-    None,
-    #[strum(to_string = "HEX #{0:02x}{1:02x}{2:02x}")]
-    HEX(u8, u8, u8),
-    Red,
-    White,
-    Yellow,
-}
-
 impl From<Color> for TextColorOptions {
     fn from(color: Color) -> Self {
         match color {
@@ -105,31 +100,6 @@ impl From<TextColorOptions> for Color {
     }
 }
 
-impl TextColorOptions {
-    pub fn to_rgb(&self) -> (u8, u8, u8) {
-        match self {
-            TextColorOptions::Black => (0, 0, 0),
-            TextColorOptions::Blue => (0, 0, 128),
-            TextColorOptions::Cyan => (0, 128, 128),
-            TextColorOptions::DarkGray => (128, 128, 128),
-            TextColorOptions::Gray => (192, 192, 192),
-            TextColorOptions::Green => (0, 128, 0),
-            TextColorOptions::LightBlue => (0, 0, 255),
-            TextColorOptions::LightCyan => (0, 255, 255),
-            TextColorOptions::LightGreen => (255, 255, 0),
-            TextColorOptions::LightMagenta => (255, 0, 255),
-            TextColorOptions::LightRed => (255, 0, 0),
-            TextColorOptions::LightYellow => (0, 255, 0),
-            TextColorOptions::Magenta => (128, 0, 128),
-            TextColorOptions::None => (0, 0, 0),
-            TextColorOptions::Red => (128, 0, 0),
-            TextColorOptions::HEX(r, g, b) => (*r, *g, *b),
-            TextColorOptions::White => (255, 255, 255),
-            TextColorOptions::Yellow => (128, 128, 0),
-        }
-    }
-}
-
 #[derive(Debug, Clone, Serialize, Deserialize, Display, EnumIter)]
 pub enum TextModifierOptions {
     Bold,
@@ -167,11 +137,15 @@ pub trait Renderable {
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Copy, Default, EnumString)]
 pub enum View {
+    ArchivedCards,
     BodyHelp,
     BodyHelpLog,
     BodyLog,
+    Calendar,
     ConfigMenu,
     CreateTheme,
+    EditBoard,
+    EditBoardNotes,
     EditKeybindings,
     HelpMenu,
     LoadCloudSave,
@@ -183,6 +157,8 @@ pub enum View {
     NewCard,
     ResetPassword,
     SignUp,
+    Stats,
+    Swimlane,
     TitleBody,
     TitleBodyHelp,
     TitleBodyHelpLog,
@@ -194,11 +170,15 @@ pub enum View {
 impl View {
     pub fn from_string(s: &str) -> Option<View> {
         match s {
+            "Archived Cards" => Some(View::ArchivedCards),
             "Body and Help" => Some(View::BodyHelp),
             "Body, Help and Log" => Some(View::BodyHelpLog),
             "Body and Log" => Some(View::BodyLog),
+            "Calendar" => Some(View::Calendar),
             "Config" => Some(View::ConfigMenu),
             "Create Theme" => Some(View::CreateTheme),
+            "Edit Board" => Some(View::EditBoard),
+            "Edit Board Notes" => Some(View::EditBoardNotes),
             "Edit Keybindings" => Some(View::EditKeybindings),
             "Help Menu" => Some(View::HelpMenu),
             "Load a Save (Cloud)" => Some(View::LoadCloudSave),
@@ -210,6 +190,8 @@ impl View {
             "New Card" => Some(View::NewCard),
             "Reset Password" => Some(View::ResetPassword),
             "Sign Up" => Some(View::SignUp),
+            "Stats" => Some(View::Stats),
+            "Swimlane" => Some(View::Swimlane),
             "Title and Body" => Some(View::TitleBody),
             "Title, Body and Help" => Some(View::TitleBodyHelp),
             "Title, Body, Help and Log" => Some(View::TitleBodyHelpLog),
@@ -239,6 +221,7 @@ impl View {
 
     pub fn get_available_targets(&self) -> Vec<Focus> {
         match self {
+            View::ArchivedCards | View::Calendar | View::Stats => vec![Focus::Body],
             View::BodyHelp => vec![Focus::Body, Focus::Help],
             View::BodyHelpLog => vec![Focus::Body, Focus::Help, Focus::Log],
             View::BodyLog => vec![Focus::Body, Focus::Log],
@@ -257,15 +240,17 @@ impl View {
             ],
             View::LogsOnly => vec![Focus::Log],
             View::MainMenu => vec![Focus::MainMenu, Focus::Help, Focus::Log],
-            View::NewBoard => vec![
+            View::NewBoard | View::EditBoard => vec![
                 Focus::NewBoardName,
                 Focus::NewBoardDescription,
                 Focus::SubmitButton,
             ],
+            View::EditBoardNotes => vec![Focus::BoardNotes, Focus::SubmitButton],
             View::NewCard => vec![
                 Focus::CardName,
                 Focus::CardDescription,
                 Focus::CardDueDate,
+                Focus::UseTemplate,
                 Focus::SubmitButton,
             ],
             View::ResetPassword => vec![
@@ -290,13 +275,14 @@ impl View {
             View::TitleBodyHelp => vec![Focus::Title, Focus::Body, Focus::Help],
             View::TitleBodyHelpLog => vec![Focus::Title, Focus::Body, Focus::Help, Focus::Log],
             View::TitleBodyLog => vec![Focus::Title, Focus::Body, Focus::Log],
-            View::Zen => vec![Focus::Body],
+            View::Swimlane | View::Zen => vec![Focus::Body],
         }
     }
 
     pub fn all_views_as_string() -> Vec<String> {
         View::views_with_kanban_board()
             .iter()
+            .chain([View::ArchivedCards, View::Calendar, View::Stats].iter())
             .map(|x| x.to_string())
             .collect()
     }
@@ -311,6 +297,7 @@ impl View {
             View::TitleBodyLog,
             View::BodyHelpLog,
             View::TitleBodyHelpLog,
+            View::Swimlane,
         ]
     }
 
@@ -339,6 +326,8 @@ impl View {
             View::BodyHelp => {
                 BodyHelp::render(rect, app, is_active);
             }
+            View::ArchivedCards => ArchivedCards::render(rect, app, is_active),
+            View::Calendar => Calendar::render(rect, app, is_active),
             View::BodyLog => {
                 BodyLog::render(rect, app, is_active);
             }
@@ -369,9 +358,10 @@ impl View {
             View::LogsOnly => {
                 LogView::render(rect, app, is_active);
             }
-            View::NewBoard => {
+            View::NewBoard | View::EditBoard => {
                 NewBoardForm::render(rect, app, is_active);
             }
+            View::EditBoardNotes => EditBoardNotes::render(rect, app, is_active),
             View::NewCard => NewCardForm::render(rect, app, is_active),
             View::LoadLocalSave => {
                 LoadASave::render(rect, app, is_active);
@@ -379,6 +369,8 @@ impl View {
             View::CreateTheme => CreateTheme::render(rect, app, is_active),
             View::Login => Login::render(rect, app, is_active),
             View::SignUp => Signup::render(rect, app, is_active),
+            View::Stats => Stats::render(rect, app, is_active),
+            View::Swimlane => Swimlane::render(rect, app, is_active),
             View::ResetPassword => ResetPassword::render(rect, app, is_active),
             View::LoadCloudSave => LoadCloudSave::render(rect, app, is_active),
         }
@@ -388,11 +380,15 @@ impl View {
 impl fmt::Display for View {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
+            View::ArchivedCards => write!(f, "Archived Cards"),
             View::BodyHelp => write!(f, "Body and Help"),
             View::BodyHelpLog => write!(f, "Body, Help and Log"),
             View::BodyLog => write!(f, "Body and Log"),
+            View::Calendar => write!(f, "Calendar"),
             View::ConfigMenu => write!(f, "Config"),
             View::CreateTheme => write!(f, "Create Theme"),
+            View::EditBoard => write!(f, "Edit Board"),
+            View::EditBoardNotes => write!(f, "Edit Board Notes"),
             View::EditKeybindings => write!(f, "Edit Keybindings"),
             View::HelpMenu => write!(f, "Help Menu"),
             View::LoadCloudSave => write!(f, "Load a Save (Cloud)"),
@@ -404,6 +400,8 @@ impl fmt::Display for View {
             View::NewCard => write!(f, "New Card"),
             View::ResetPassword => write!(f, "Reset Password"),
             View::SignUp => write!(f, "Sign Up"),
+            View::Stats => write!(f, "Stats"),
+            View::Swimlane => write!(f, "Swimlane"),
             View::TitleBody => write!(f, "Title and Body"),
             View::TitleBodyHelp => write!(f, "Title, Body and Help"),
             View::TitleBodyHelpLog => write!(f, "Title, Body, Help and Log"),
@@ -413,7 +411,7 @@ impl fmt::Display for View {
     }
 }
 
-#[derive(Clone, PartialEq, Debug, Copy)]
+#[derive(Clone, PartialEq, Debug, Copy, EnumIter)]
 pub enum PopUp {
     ViewCard,
     CommandPalette,
@@ -428,11 +426,43 @@ pub enum PopUp {
     SaveThemePrompt,
     CustomHexColorPromptFG,
     CustomHexColorPromptBG,
+    ImportTrelloPrompt,
+    ImportJsonPrompt,
+    ImportBoardJsonPrompt,
+    ImportGithubPrompt,
+    ImportOptions,
+    SearchCards,
+    ConfirmClearWaitingOn,
     ConfirmDiscardCardChanges,
+    ConfirmDuplicateCardName,
+    ConfirmKeepDraft,
+    ConfirmQuit,
+    ConfirmRestoreThemesFromBackup,
     CardPrioritySelector,
+    CardHighlightColorSelector,
+    BoardAccentColorSelector,
     FilterByTag,
+    FilterByDateRange,
+    CustomDateRangePrompt,
+    FilterByPriority,
+    FilterByStatus,
     DateTimePicker,
     TagPicker,
+    MirrorSyncSummary,
+    Notifications,
+    WhatsNew,
+    GithubImportSummary,
+    ConflictMarkerWarning,
+    DuplicateBoards,
+    RecoverSaveFile,
+    BoardStats,
+    SortCardsBy,
+    MoveCardToBoard,
+    CalendarDayCards,
+    WaitingOnList,
+    SelectCardTemplate,
+    FindReplace,
+    SetLogLevel,
 }
 
 impl fmt::Display for PopUp {
@@ -451,11 +481,45 @@ impl fmt::Display for PopUp {
             PopUp::SaveThemePrompt => write!(f, "Save Theme Prompt"),
             PopUp::CustomHexColorPromptFG => write!(f, "Custom Hex Color Prompt FG"),
             PopUp::CustomHexColorPromptBG => write!(f, "Custom Hex Color Prompt BG"),
+            PopUp::ImportTrelloPrompt => write!(f, "Import Trello Prompt"),
+            PopUp::ImportJsonPrompt => write!(f, "Import JSON Prompt"),
+            PopUp::ImportBoardJsonPrompt => write!(f, "Import Board JSON Prompt"),
+            PopUp::ImportGithubPrompt => write!(f, "Import GitHub Prompt"),
+            PopUp::ImportOptions => write!(f, "Import Options"),
+            PopUp::SearchCards => write!(f, "Search Cards"),
             PopUp::ConfirmDiscardCardChanges => write!(f, "Confirm Discard Card Changes"),
+            PopUp::ConfirmDuplicateCardName => write!(f, "Confirm Duplicate Card Name"),
+            PopUp::ConfirmKeepDraft => write!(f, "Confirm Keep Draft"),
+            PopUp::ConfirmClearWaitingOn => write!(f, "Confirm Clear Waiting On"),
+            PopUp::ConfirmQuit => write!(f, "Confirm Quit"),
+            PopUp::ConfirmRestoreThemesFromBackup => {
+                write!(f, "Confirm Restore Themes From Backup")
+            }
             PopUp::CardPrioritySelector => write!(f, "Change Card Priority"),
+            PopUp::CardHighlightColorSelector => write!(f, "Change Card Highlight Color"),
+            PopUp::BoardAccentColorSelector => write!(f, "Change Board Accent Color"),
             PopUp::FilterByTag => write!(f, "Filter By Tag"),
+            PopUp::FilterByDateRange => write!(f, "Filter By Date Range"),
+            PopUp::CustomDateRangePrompt => write!(f, "Custom Date Range Prompt"),
+            PopUp::FilterByPriority => write!(f, "Filter By Priority"),
+            PopUp::FilterByStatus => write!(f, "Filter By Status"),
             PopUp::DateTimePicker => write!(f, "Date Time Picker"),
             PopUp::TagPicker => write!(f, "Tag Picker"),
+            PopUp::MirrorSyncSummary => write!(f, "Mirror Sync Summary"),
+            PopUp::Notifications => write!(f, "Notifications"),
+            PopUp::WhatsNew => write!(f, "What's New"),
+            PopUp::GithubImportSummary => write!(f, "GitHub Import Summary"),
+            PopUp::ConflictMarkerWarning => write!(f, "Conflict Marker Warning"),
+            PopUp::DuplicateBoards => write!(f, "Duplicate Boards"),
+            PopUp::RecoverSaveFile => write!(f, "Recover Save File"),
+            PopUp::BoardStats => write!(f, "Board Stats"),
+            PopUp::SortCardsBy => write!(f, "Sort Cards By"),
+            PopUp::MoveCardToBoard => write!(f, "Move Card to Board"),
+            PopUp::CalendarDayCards => write!(f, "Cards Due"),
+            PopUp::WaitingOnList => write!(f, "Waiting On"),
+            PopUp::SelectCardTemplate => write!(f, "Select Card Template"),
+            PopUp::FindReplace => write!(f, "Find and Replace"),
+            PopUp::SetLogLevel => write!(f, "Set Log Level"),
         }
     }
 }
@@ -466,12 +530,20 @@ impl PopUp {
             PopUp::ViewCard => vec![
                 Focus::CardName,
                 Focus::CardDescription,
+                Focus::CardChecklist,
+                Focus::CardStartDate,
                 Focus::CardDueDate,
                 Focus::CardPriority,
                 Focus::CardStatus,
+                Focus::CardColor,
+                Focus::CardEstimate,
+                Focus::CardEstimatedTime,
+                Focus::CardActualTime,
+                Focus::CardWaitingOn,
                 Focus::CardTags,
                 Focus::CardComments,
                 Focus::SubmitButton,
+                Focus::SaveCardAsTemplate,
             ],
             PopUp::CommandPalette => vec![
                 Focus::CommandPaletteCommand,
@@ -494,9 +566,31 @@ impl PopUp {
             PopUp::SaveThemePrompt => vec![Focus::SubmitButton, Focus::ExtraFocus],
             PopUp::CustomHexColorPromptFG => vec![Focus::TextInput, Focus::SubmitButton],
             PopUp::CustomHexColorPromptBG => vec![Focus::TextInput, Focus::SubmitButton],
-            PopUp::ConfirmDiscardCardChanges => vec![Focus::SubmitButton, Focus::ExtraFocus],
+            PopUp::ImportTrelloPrompt
+            | PopUp::ImportJsonPrompt
+            | PopUp::ImportBoardJsonPrompt
+            | PopUp::ImportGithubPrompt => {
+                vec![Focus::TextInput, Focus::SubmitButton]
+            }
+            PopUp::ImportOptions => vec![Focus::SubmitButton, Focus::ExtraFocus],
+            PopUp::SearchCards => vec![Focus::TextInput],
+            PopUp::ConfirmDiscardCardChanges
+            | PopUp::ConfirmKeepDraft
+            | PopUp::ConfirmClearWaitingOn => {
+                vec![Focus::SubmitButton, Focus::ExtraFocus]
+            }
+            PopUp::ConfirmRestoreThemesFromBackup => vec![Focus::SubmitButton, Focus::ExtraFocus],
+            PopUp::ConfirmDuplicateCardName | PopUp::ConfirmQuit => {
+                vec![Focus::SubmitButton, Focus::ExtraFocus, Focus::CancelButton]
+            }
             PopUp::CardPrioritySelector => vec![],
+            PopUp::CardHighlightColorSelector => vec![],
+            PopUp::BoardAccentColorSelector => vec![],
             PopUp::FilterByTag => vec![Focus::FilterByTagPopup, Focus::SubmitButton],
+            PopUp::FilterByDateRange => vec![],
+            PopUp::CustomDateRangePrompt => vec![Focus::TextInput, Focus::SubmitButton],
+            PopUp::FilterByPriority => vec![],
+            PopUp::FilterByStatus => vec![],
             PopUp::DateTimePicker => vec![
                 Focus::DTPCalender,
                 Focus::DTPMonth,
@@ -507,6 +601,86 @@ impl PopUp {
                 Focus::DTPSecond,
             ],
             PopUp::TagPicker => vec![Focus::CardTags],
+            PopUp::MirrorSyncSummary => vec![
+                Focus::MirrorSyncConflicts,
+                Focus::SubmitButton,
+                Focus::ExtraFocus,
+            ],
+            PopUp::Notifications => vec![Focus::NotificationsList, Focus::SubmitButton],
+            PopUp::WhatsNew => vec![],
+            PopUp::GithubImportSummary => vec![],
+            PopUp::ConflictMarkerWarning => vec![Focus::ConflictMarkerCards],
+            PopUp::DuplicateBoards => {
+                vec![Focus::SubmitButton, Focus::ExtraFocus, Focus::CancelButton]
+            }
+            PopUp::RecoverSaveFile => vec![Focus::SubmitButton, Focus::ExtraFocus],
+            PopUp::BoardStats => vec![Focus::NoFocus],
+            PopUp::SortCardsBy => vec![],
+            PopUp::MoveCardToBoard => vec![],
+            PopUp::CalendarDayCards | PopUp::WaitingOnList | PopUp::SelectCardTemplate => vec![],
+            PopUp::SetLogLevel => vec![],
+            PopUp::FindReplace => vec![
+                Focus::FindReplaceQuery,
+                Focus::FindReplaceReplacement,
+                Focus::SubmitButton,
+                Focus::CancelButton,
+            ],
+        }
+    }
+
+    /// Smallest terminal area the popup layout fits in, below it a short notice is rendered
+    /// instead of a clipped or overflowing layout
+    pub fn minimum_size(&self) -> (u16, u16) {
+        match self {
+            PopUp::ViewCard => (60, 25),
+            PopUp::CommandPalette => (50, 20),
+            PopUp::EditSpecificKeyBinding => (40, 15),
+            PopUp::ChangeView => (40, 10),
+            PopUp::CardStatusSelector => (30, 12),
+            PopUp::EditGeneralConfig => (40, 15),
+            PopUp::SelectDefaultView => (40, 15),
+            PopUp::ChangeDateFormatPopup => (40, 15),
+            PopUp::ChangeTheme => (40, 15),
+            PopUp::EditThemeStyle => (60, 20),
+            PopUp::SaveThemePrompt => (40, 10),
+            PopUp::CustomHexColorPromptFG | PopUp::CustomHexColorPromptBG => (72, 12),
+            PopUp::ImportTrelloPrompt
+            | PopUp::ImportJsonPrompt
+            | PopUp::ImportBoardJsonPrompt
+            | PopUp::ImportGithubPrompt => (72, 12),
+            PopUp::ImportOptions => (60, 11),
+            PopUp::SearchCards => (60, 9),
+            PopUp::ConfirmDiscardCardChanges => (30, 7),
+            PopUp::ConfirmDuplicateCardName => (60, 10),
+            PopUp::ConfirmKeepDraft => (40, 8),
+            PopUp::ConfirmClearWaitingOn => (40, 8),
+            PopUp::ConfirmQuit => (60, 9),
+            PopUp::ConfirmRestoreThemesFromBackup => (50, 10),
+            PopUp::CardPrioritySelector => (30, 12),
+            PopUp::CardHighlightColorSelector => (30, 20),
+            PopUp::BoardAccentColorSelector => (30, 20),
+            PopUp::FilterByTag => (40, 15),
+            PopUp::FilterByDateRange => (40, 9),
+            PopUp::CustomDateRangePrompt => (72, 13),
+            PopUp::FilterByPriority => (40, 7),
+            PopUp::FilterByStatus => (40, 7),
+            PopUp::DateTimePicker => (MIN_DATE_PICKER_WIDTH + TIME_PICKER_WIDTH, 12),
+            PopUp::TagPicker => (TAG_SELECTOR_WIDTH, TAG_SELECTOR_HEIGHT),
+            PopUp::MirrorSyncSummary => (80, 22),
+            PopUp::Notifications => (60, 15),
+            PopUp::WhatsNew => (50, 12),
+            PopUp::GithubImportSummary => (50, 12),
+            PopUp::ConflictMarkerWarning => (50, 12),
+            PopUp::DuplicateBoards => (76, 20),
+            PopUp::RecoverSaveFile => (50, 10),
+            PopUp::BoardStats => (50, 13),
+            PopUp::SortCardsBy => (30, 6),
+            PopUp::MoveCardToBoard => (30, 6),
+            PopUp::CalendarDayCards => (40, 6),
+            PopUp::WaitingOnList => (40, 6),
+            PopUp::SelectCardTemplate => (40, 6),
+            PopUp::FindReplace => (60, 15),
+            PopUp::SetLogLevel => (30, 7),
         }
     }
 
@@ -536,6 +710,11 @@ impl PopUp {
                 app.state.set_focus(self.get_available_targets()[0]);
             }
         }
+        let (min_width, min_height) = self.minimum_size();
+        if rect.area().width < min_width || rect.area().height < min_height {
+            render_popup_too_small(rect, app, self, is_active);
+            return;
+        }
         match self {
             PopUp::ViewCard => {
                 ViewCard::render(rect, app, is_active);
@@ -570,15 +749,66 @@ impl PopUp {
             PopUp::CustomHexColorPromptFG | PopUp::CustomHexColorPromptBG => {
                 CustomHexColorPrompt::render(rect, app, is_active);
             }
+            PopUp::ImportTrelloPrompt => {
+                ImportTrelloPrompt::render(rect, app, is_active);
+            }
+            PopUp::ImportJsonPrompt => {
+                ImportJsonPrompt::render(rect, app, is_active);
+            }
+            PopUp::ImportBoardJsonPrompt => {
+                ImportBoardJsonPrompt::render(rect, app, is_active);
+            }
+            PopUp::ImportGithubPrompt => {
+                ImportGithubPrompt::render(rect, app, is_active);
+            }
+            PopUp::ImportOptions => {
+                ImportOptions::render(rect, app, is_active);
+            }
+            PopUp::SearchCards => {
+                SearchCards::render(rect, app, is_active);
+            }
             PopUp::ConfirmDiscardCardChanges => {
                 ConfirmDiscardCardChanges::render(rect, app, is_active);
             }
+            PopUp::ConfirmKeepDraft => {
+                ConfirmKeepDraft::render(rect, app, is_active);
+            }
+            PopUp::ConfirmClearWaitingOn => {
+                ConfirmClearWaitingOn::render(rect, app, is_active);
+            }
+            PopUp::ConfirmRestoreThemesFromBackup => {
+                ConfirmRestoreThemesFromBackup::render(rect, app, is_active);
+            }
+            PopUp::ConfirmDuplicateCardName => {
+                ConfirmDuplicateCardName::render(rect, app, is_active);
+            }
+            PopUp::ConfirmQuit => {
+                ConfirmQuit::render(rect, app, is_active);
+            }
             PopUp::CardPrioritySelector => {
                 CardPrioritySelector::render(rect, app, is_active);
             }
+            PopUp::CardHighlightColorSelector => {
+                CardHighlightColorSelector::render(rect, app, is_active);
+            }
+            PopUp::BoardAccentColorSelector => {
+                BoardAccentColorSelector::render(rect, app, is_active);
+            }
             PopUp::FilterByTag => {
                 FilterByTag::render(rect, app, is_active);
             }
+            PopUp::FilterByDateRange => {
+                FilterByDateRange::render(rect, app, is_active);
+            }
+            PopUp::CustomDateRangePrompt => {
+                CustomDateRangePrompt::render(rect, app, is_active);
+            }
+            PopUp::FilterByPriority => {
+                FilterByPriority::render(rect, app, is_active);
+            }
+            PopUp::FilterByStatus => {
+                FilterByStatus::render(rect, app, is_active);
+            }
             PopUp::ChangeDateFormatPopup => {
                 ChangeDateFormat::render(rect, app, is_active);
             }
@@ -588,6 +818,51 @@ impl PopUp {
             PopUp::TagPicker => {
                 TagPicker::render(rect, app, is_active);
             }
+            PopUp::MirrorSyncSummary => {
+                MirrorSyncSummary::render(rect, app, is_active);
+            }
+            PopUp::Notifications => {
+                Notifications::render(rect, app, is_active);
+            }
+            PopUp::WhatsNew => {
+                WhatsNew::render(rect, app, is_active);
+            }
+            PopUp::GithubImportSummary => {
+                GithubImportSummary::render(rect, app, is_active);
+            }
+            PopUp::ConflictMarkerWarning => {
+                ConflictMarkerWarning::render(rect, app, is_active);
+            }
+            PopUp::DuplicateBoards => {
+                DuplicateBoards::render(rect, app, is_active);
+            }
+            PopUp::BoardStats => {
+                BoardStats::render(rect, app, is_active);
+            }
+            PopUp::SortCardsBy => {
+                SortCardsBy::render(rect, app, is_active);
+            }
+            PopUp::MoveCardToBoard => {
+                MoveCardToBoard::render(rect, app, is_active);
+            }
+            PopUp::CalendarDayCards => {
+                CalendarDayCards::render(rect, app, is_active);
+            }
+            PopUp::WaitingOnList => {
+                WaitingOnList::render(rect, app, is_active);
+            }
+            PopUp::SelectCardTemplate => {
+                SelectCardTemplate::render(rect, app, is_active);
+            }
+            PopUp::FindReplace => {
+                FindReplace::render(rect, app, is_active);
+            }
+            PopUp::SetLogLevel => {
+                SetLogLevel::render(rect, app, is_active);
+            }
+            PopUp::RecoverSaveFile => {
+                RecoverSaveFile::render(rect, app, is_active);
+            }
         }
     }
 }
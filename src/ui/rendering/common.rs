@@ -1,26 +1,29 @@
 use crate::{
     app::{
-        app_helper::reset_card_drag_mode,
-        kanban::{Boards, Card, CardPriority, CardStatus},
+        app_helper::{refresh_visible_cards_for_all_boards, reset_card_drag_mode},
+        card_aging::{age_tier, AgeTier},
+        kanban::{format_estimate, Boards, Card, CardPriority, CardStatus},
         state::{Focus, KeyBindingEnum},
-        App, DateTimeFormat,
+        App, CardFaceToken, DateTimeFormat,
     },
     constants::{
-        APP_TITLE, DEFAULT_BOARD_TITLE_LENGTH, DEFAULT_CARD_TITLE_LENGTH, FIELD_NOT_SET,
-        HIDDEN_PASSWORD_SYMBOL, LIST_SELECTED_SYMBOL, MOUSE_OUT_OF_BOUNDS_COORDINATES,
-        PATTERN_CHANGE_INTERVAL, SCROLLBAR_BEGIN_SYMBOL, SCROLLBAR_END_SYMBOL,
-        SCROLLBAR_TRACK_SYMBOL,
+        APP_TITLE, CONFLICT_MARKER_BADGE, DEFAULT_BOARD_TITLE_LENGTH, DEFAULT_CARD_TITLE_LENGTH,
+        FIELD_NOT_SET, HIDDEN_PASSWORD_SYMBOL, LIST_SELECTED_SYMBOL,
+        MOUSE_OUT_OF_BOUNDS_COORDINATES, PATTERN_CHANGE_INTERVAL, SCROLLBAR_BEGIN_SYMBOL,
+        SCROLLBAR_END_SYMBOL, SCROLLBAR_TRACK_SYMBOL,
     },
+    i18n::{tr, MessageId},
     io::logger::{get_logs, get_selected_index, RUST_KANBAN_LOGGER},
     ui::{
         rendering::utils::{
             centered_rect_with_length, check_for_card_drag_and_get_style,
             check_if_active_and_get_style, check_if_mouse_is_in_area,
-            get_mouse_focusable_field_style,
+            get_mouse_focusable_field_style, get_preview_lines,
         },
         theme::Theme,
+        PopUp,
     },
-    util::{date_format_converter, date_format_finder},
+    util::{date_format_converter, date_format_finder, format_short_id, parse_date_time},
 };
 use chrono::{Local, NaiveDate, NaiveDateTime};
 use log::Level;
@@ -30,12 +33,12 @@ use ratatui::{
     text::{Line, Span},
     widgets::{
         Block, BorderType, Borders, Cell, Gauge, Paragraph, Row, Scrollbar, ScrollbarOrientation,
-        ScrollbarState, Table,
+        ScrollbarState, Table, Wrap,
     },
     Frame,
 };
 use std::{
-    cmp::Ordering,
+    cmp::{min, Ordering},
     time::{SystemTime, UNIX_EPOCH},
 };
 
@@ -85,6 +88,8 @@ pub fn render_body(
         app.current_theme.help_key_style,
     );
     let current_board_id = &app.state.current_board_id.unwrap_or((0, 0));
+    // Taken once so every card on the board ages against the same day
+    let today = Local::now().date_naive();
 
     let new_board_key = app
         .get_first_keybinding(KeyBindingEnum::NewBoard)
@@ -104,7 +109,7 @@ pub fn render_body(
                 .alignment(Alignment::Center)
                 .block(
                     Block::default()
-                        .title("Boards")
+                        .title(tr(MessageId::TitleBoards))
                         .borders(Borders::ALL)
                         .border_type(BorderType::Rounded),
                 )
@@ -124,7 +129,7 @@ pub fn render_body(
         .alignment(Alignment::Center)
         .block(
             Block::default()
-                .title("Boards")
+                .title(tr(MessageId::TitleBoards))
                 .borders(Borders::ALL)
                 .border_type(BorderType::Rounded),
         )
@@ -133,7 +138,12 @@ pub fn render_body(
         return;
     }
 
-    let filter_chunks = if app.filtered_boards.is_empty() {
+    let filter_criteria = if preview_mode {
+        vec![]
+    } else {
+        app.get_active_filter_criteria()
+    };
+    let filter_chunks = if filter_criteria.is_empty() {
         Layout::default()
             .direction(Direction::Vertical)
             .constraints([Constraint::Percentage(0), Constraint::Fill(1)].as_ref())
@@ -157,9 +167,12 @@ pub fn render_body(
             .split(filter_chunks[1])
     };
 
-    if !app.filtered_boards.is_empty() {
-        let filtered_text = "This is a filtered view, Clear filter to see all boards and cards";
-        let filtered_paragraph = Paragraph::new(filtered_text.to_string())
+    if !filter_criteria.is_empty() {
+        let filtered_text = format!(
+            "Filter active: {}, Clear filter to see all boards and cards",
+            filter_criteria.join(" and ")
+        );
+        let filtered_paragraph = Paragraph::new(filtered_text)
             .alignment(Alignment::Center)
             .block(Block::default())
             .style(error_text_style);
@@ -180,6 +193,16 @@ pub fn render_body(
         .direction(Direction::Horizontal)
         .constraints(AsRef::<[Constraint]>::as_ref(&constraints))
         .split(chunks[0]);
+    if !preview_mode && !board_chunks.is_empty() {
+        // Board borders take two rows, single line densities fill whatever is left
+        let card_rows_available = board_chunks[0].height.saturating_sub(2);
+        if app.state.card_rows_available != Some(card_rows_available) {
+            app.state.card_rows_available = Some(card_rows_available);
+            if app.config.card_density.is_single_line() {
+                refresh_visible_cards_for_all_boards(app);
+            }
+        }
+    }
     let visible_boards_and_cards = if preview_mode {
         app.state.preview_visible_boards_and_cards.clone()
     } else {
@@ -202,30 +225,78 @@ pub fn render_body(
         } else {
             board_title
         };
-        let board_title = format!("{} ({})", board_title, board.cards.len());
+        let hidden_completed_cards_count = if !preview_mode
+            && app
+                .state
+                .boards_with_hidden_completed_cards
+                .contains(board_id)
+        {
+            board.get_completed_cards_count()
+        } else {
+            0
+        };
+        let displayable_cards = if preview_mode {
+            board.get_displayable_cards(false)
+        } else {
+            app.get_displayable_cards(board)
+        };
+        let board_title = if hidden_completed_cards_count > 0 {
+            format!(
+                "{} ({}) (+{} done hidden)",
+                board_title,
+                displayable_cards.len(),
+                hidden_completed_cards_count
+            )
+        } else {
+            format!("{} ({})", board_title, displayable_cards.len())
+        };
+        let board_title = if let Some(total_estimate) = board.get_total_estimate() {
+            format!("{} Σ {}", board_title, format_estimate(total_estimate))
+        } else {
+            board_title
+        };
+        let board_title = if board.archived {
+            format!("{} [archived]", board_title)
+        } else {
+            board_title
+        };
         let board_title = if board_id == current_board_id {
             format!("{} {}", ">>", board_title)
         } else {
             board_title
         };
 
+        let card_constraint = if app.config.card_density.is_single_line() {
+            Constraint::Length(1)
+        } else {
+            Constraint::Fill(1)
+        };
         let mut card_constraints = vec![];
-        if board_cards.len() > app.config.no_of_cards_to_show.into() {
-            for _i in 0..app.config.no_of_cards_to_show {
-                card_constraints.push(Constraint::Fill(1));
+        if board_cards.len() > app.get_no_of_cards_to_show().into() {
+            for _i in 0..app.get_no_of_cards_to_show() {
+                card_constraints.push(card_constraint);
             }
         } else if board_cards.is_empty() {
             card_constraints.push(Constraint::Fill(1));
         } else {
             for _i in 0..board_cards.len() {
-                card_constraints.push(Constraint::Fill(1));
+                card_constraints.push(card_constraint);
             }
         }
+        if app.config.card_density.is_single_line() && !board_cards.is_empty() {
+            // Keeps the single line cards packed at the top of the board
+            card_constraints.push(Constraint::Fill(1));
+        }
 
         if board_index >= board_chunks.len() {
             continue;
         }
 
+        // Inactive boards stay in the inactive style so popups on top keep standing out
+        let accent_style = board
+            .accent_color
+            .filter(|_| is_active)
+            .map(|accent_color| app.current_theme.general_style.fg(accent_color.into()));
         let board_style = check_for_card_drag_and_get_style(
             app.state.card_drag_mode,
             is_active,
@@ -247,6 +318,8 @@ pub fn render_body(
             }
             app.state.hovered_board = Some(*board_id);
             app.current_theme.mouse_focus_style
+        } else if app.state.is_created_item_flashing(*board_id, None) {
+            app.current_theme.list_select_style
         } else if (app.state.current_board_id.unwrap_or((0, 0)) == *board_id)
             && app.state.current_card_id.is_none()
             && matches!(app.state.focus, Focus::Body)
@@ -254,12 +327,14 @@ pub fn render_body(
             app.current_theme.keyboard_focus_style
         } else if app.state.card_drag_mode {
             app.current_theme.inactive_text_style
+        } else if let Some(accent_style) = accent_style {
+            accent_style
         } else {
             app.current_theme.general_style
         };
 
         let board_block = Block::default()
-            .title(&*board_title)
+            .title(Line::from(board_title).style(accent_style.unwrap_or_default()))
             .borders(Borders::ALL)
             .style(board_style)
             .border_style(board_border_style)
@@ -309,8 +384,7 @@ pub fn render_body(
             continue;
         }
         if !app.config.disable_scroll_bar && !board_cards.is_empty() && board_cards.len() > 1 {
-            let current_card_index = board
-                .cards
+            let current_card_index = displayable_cards
                 .get_card_index(app.state.current_card_id.unwrap_or((0, 0)))
                 .unwrap_or(0);
             let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalLeft)
@@ -319,7 +393,7 @@ pub fn render_body(
                 .end_symbol(SCROLLBAR_END_SYMBOL)
                 .track_symbol(SCROLLBAR_TRACK_SYMBOL)
                 .track_style(app.current_theme.inactive_text_style);
-            let mut scrollbar_state = ScrollbarState::new(board.cards.len())
+            let mut scrollbar_state = ScrollbarState::new(displayable_cards.len())
                 .position(current_card_index)
                 .viewport_content_length((card_chunks[0].height) as usize);
             let scrollbar_area = card_area_chunks[0].inner(Margin {
@@ -328,6 +402,14 @@ pub fn render_body(
             });
             rect.render_stateful_widget(scrollbar, scrollbar_area, &mut scrollbar_state);
         };
+        let (card_range_selection, marked_card_ids) = if *board_id == *current_board_id {
+            (
+                app.get_card_range_selection().unwrap_or_default(),
+                app.get_marked_card_ids(),
+            )
+        } else {
+            (vec![], vec![])
+        };
         for (card_index, card_id) in board_cards.iter().enumerate() {
             if app.state.hovered_card.is_some()
                 && app.state.card_drag_mode
@@ -361,6 +443,8 @@ pub fn render_body(
                     ));
                 }
                 app.current_theme.mouse_focus_style
+            } else if app.state.is_created_item_flashing(*board_id, Some(card.id)) {
+                app.current_theme.list_select_style
             } else if app.state.current_card_id.unwrap_or((0, 0)) == card.id
                 && matches!(app.state.focus, Focus::Body)
                 && *board_id == *current_board_id
@@ -368,14 +452,26 @@ pub fn render_body(
                 app.current_theme.keyboard_focus_style
             } else if app.state.card_drag_mode {
                 app.current_theme.inactive_text_style
+            } else if marked_card_ids.contains(card_id) {
+                app.current_theme.help_key_style
+            } else if card_range_selection.contains(card_id) {
+                app.current_theme.list_select_style
+            } else if let Some(highlight_color) = card.highlight_color {
+                app.current_theme.general_style.fg(highlight_color.into())
             } else {
                 app.current_theme.general_style
             };
+            let card_age = if app.config.card_aging_days == 0 {
+                None
+            } else {
+                app.state.card_age_cache.age_in_days(card, today)
+            };
             render_a_single_card(
                 app,
                 card_chunks[card_index],
                 card_style,
                 card,
+                card_age,
                 rect,
                 is_active,
             );
@@ -480,12 +576,121 @@ pub fn render_card_being_dragged(
             render_area,
             app.current_theme.error_text_style,
             &card,
+            None,
             rect,
             is_active,
         )
     }
 }
 
+/// Rendered in place of a popup whose layout does not fit in the terminal
+pub fn render_popup_too_small(rect: &mut Frame, app: &mut App, popup: PopUp, is_active: bool) {
+    let style = check_if_active_and_get_style(
+        is_active,
+        app.current_theme.inactive_text_style,
+        app.current_theme.error_text_style,
+    );
+    let message = format!(
+        "Terminal too small for the {}, resize or press Esc",
+        popup.to_string().to_lowercase()
+    );
+    let width = (message.len() as u16 + 4).min(rect.area().width);
+    let message_rows = (message.len() as u16).div_ceil(width.saturating_sub(4).max(1));
+    let popup_area = centered_rect_with_length(width, message_rows + 2, rect.area());
+    let message_paragraph = Paragraph::new(message)
+        .style(style)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .style(style),
+        )
+        .alignment(Alignment::Center)
+        .wrap(Wrap { trim: true });
+    render_blank_styled_canvas(rect, &app.current_theme, popup_area, is_active);
+    rect.render_widget(message_paragraph, popup_area);
+}
+
+/// A message above Yes and No buttons for the confirm popups, Yes has the submit button focus and
+/// No the extra focus
+pub fn render_yes_no_popup(
+    rect: &mut Frame,
+    app: &mut App,
+    is_active: bool,
+    title: String,
+    message: String,
+) {
+    let popup_area = centered_rect_with_length(50, 10, rect.area());
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Fill(1), Constraint::Length(3)].as_ref())
+        .margin(2)
+        .split(popup_area);
+    let button_chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Fill(1), Constraint::Fill(1)].as_ref())
+        .split(chunks[1]);
+
+    let general_style = check_if_active_and_get_style(
+        is_active,
+        app.current_theme.inactive_text_style,
+        app.current_theme.general_style,
+    );
+
+    let message_paragraph = Paragraph::new(message)
+        .style(general_style)
+        .alignment(Alignment::Center)
+        .wrap(Wrap { trim: true });
+
+    let yes_button_style = get_mouse_focusable_field_style(
+        app,
+        Focus::SubmitButton,
+        &button_chunks[0],
+        is_active,
+        false,
+    );
+    let no_button_style = get_mouse_focusable_field_style(
+        app,
+        Focus::ExtraFocus,
+        &button_chunks[1],
+        is_active,
+        false,
+    );
+    let yes_button = Paragraph::new(tr(MessageId::ButtonYes))
+        .style(yes_button_style)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(yes_button_style)
+                .border_type(BorderType::Rounded),
+        )
+        .alignment(Alignment::Center);
+    let no_button = Paragraph::new(tr(MessageId::ButtonNo))
+        .style(no_button_style)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(no_button_style)
+                .border_type(BorderType::Rounded),
+        )
+        .alignment(Alignment::Center);
+    let border_block = Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(general_style);
+
+    render_blank_styled_canvas(rect, &app.current_theme, popup_area, is_active);
+    rect.render_widget(message_paragraph, chunks[0]);
+    rect.render_widget(yes_button, button_chunks[0]);
+    rect.render_widget(no_button, button_chunks[1]);
+    rect.render_widget(border_block, popup_area);
+    if app.config.enable_mouse_support {
+        render_close_button(rect, app, is_active)
+    }
+}
+
 pub fn render_close_button(rect: &mut Frame, app: &mut App, is_active: bool) {
     let close_btn_area = Rect::new(rect.area().width - 3, 0, 3, 3);
     // Exception to not using get_button_style as we have to manage other state
@@ -641,7 +846,7 @@ pub fn render_logs(
     )
     .block(
         Block::default()
-            .title("Logs")
+            .title(tr(MessageId::TitleLogs))
             .style(log_box_style)
             .border_style(log_box_border_style)
             .borders(Borders::ALL)
@@ -662,16 +867,31 @@ fn render_a_single_card(
     render_area: Rect,
     card_style: Style,
     card: &Card,
+    card_age: Option<i64>,
     frame_to_render_on: &mut Frame,
     is_active: bool,
 ) {
-    let inner_card_chunks = Layout::default()
-        .direction(Direction::Vertical)
-        .constraints([Constraint::Fill(1), Constraint::Length(3)].as_ref())
-        .margin(1)
-        .split(render_area);
+    if render_area.height == 0 || render_area.width == 0 {
+        return;
+    }
+    if app.config.card_density.is_single_line() {
+        render_a_single_line_card(
+            app,
+            render_area,
+            card_style,
+            card,
+            card_age,
+            frame_to_render_on,
+            is_active,
+        );
+        return;
+    }
+    let (aged_title_style, age_suffix) = get_card_age_cue(app, card, card_age, card_style);
+    let card_face_layout = app.config.card_face_layout.clone();
 
-    let card_title = if card.name.len() > DEFAULT_CARD_TITLE_LENGTH.into() {
+    let card_title = if !card_face_layout.contains(&CardFaceToken::Name) {
+        String::new()
+    } else if card.name.len() > DEFAULT_CARD_TITLE_LENGTH.into() {
         format!("{}...", &card.name[0..DEFAULT_CARD_TITLE_LENGTH as usize])
     } else {
         card.name.clone()
@@ -681,13 +901,317 @@ fn render_a_single_card(
     } else {
         card_title
     };
+    let card_title = match &age_suffix {
+        Some(age_suffix) => format!("{} {}", card_title, age_suffix),
+        None => card_title,
+    };
+
+    // accounting for border
+    let available_width = render_area.width.saturating_sub(2) as usize;
+    let card_face_segments =
+        get_card_face_segments(app, card, &card_face_layout, available_width, is_active);
+    let mut constraints = card_face_segments
+        .iter()
+        .map(|segment| match segment {
+            CardFaceSegment::Description => Constraint::Fill(1),
+            CardFaceSegment::Lines(lines) => Constraint::Length(lines.len() as u16),
+        })
+        .collect::<Vec<Constraint>>();
+    if !card_face_layout.contains(&CardFaceToken::Description) {
+        constraints.push(Constraint::Fill(1));
+    }
+    let inner_card_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(constraints)
+        .margin(1)
+        .split(render_area);
 
-    let card_description = if card.description == FIELD_NOT_SET {
-        format!("Description: {}", FIELD_NOT_SET)
+    let card_block = Block::default()
+        .title(&*card_title)
+        .borders(Borders::ALL)
+        .border_style(card_style)
+        .border_type(BorderType::Rounded);
+    let card_block = if let Some(aged_title_style) = aged_title_style {
+        card_block.title_style(aged_title_style)
+    } else if card.highlight_color.is_some() {
+        card_block.title_style(card_style)
+    } else {
+        card_block
+    };
+    let card_block = if has_conflict_markers(app, card) {
+        card_block.title(
+            Line::from(Span::styled(
+                CONFLICT_MARKER_BADGE,
+                check_if_active_and_get_style(
+                    is_active,
+                    app.current_theme.inactive_text_style,
+                    app.current_theme.log_warn_style,
+                ),
+            ))
+            .right_aligned(),
+        )
+    } else {
+        card_block
+    };
+    let card_block =
+        if let Some(checklist_progress) = get_checklist_progress_span(app, card, is_active) {
+            card_block.title(Line::from(checklist_progress).right_aligned())
+        } else {
+            card_block
+        };
+    let card_block = if let Some(waiting_on) = get_waiting_on_span(app, card, is_active) {
+        card_block.title(Line::from(waiting_on).right_aligned())
     } else {
-        card.description.clone()
+        card_block
     };
+    frame_to_render_on.render_widget(card_block, render_area);
+
+    for (segment, segment_area) in card_face_segments.into_iter().zip(inner_card_chunks.iter()) {
+        match segment {
+            CardFaceSegment::Description => {
+                // Only lay out the description rows that fit on the card face
+                let preview_line_count =
+                    min(app.config.card_preview_lines, segment_area.height) as usize;
+                let card_description = if preview_line_count == 0 {
+                    vec![]
+                } else if card.description == FIELD_NOT_SET {
+                    get_preview_lines(
+                        &format!("Description: {}", FIELD_NOT_SET),
+                        segment_area.width as usize,
+                        preview_line_count,
+                    )
+                } else {
+                    get_preview_lines(
+                        &card.description,
+                        segment_area.width as usize,
+                        preview_line_count,
+                    )
+                };
+                let card_paragraph = Paragraph::new(
+                    card_description
+                        .into_iter()
+                        .map(Line::from)
+                        .collect::<Vec<Line>>(),
+                )
+                .alignment(Alignment::Left)
+                .block(Block::default());
+                frame_to_render_on.render_widget(card_paragraph, *segment_area);
+            }
+            CardFaceSegment::Lines(lines) => {
+                let card_extra_info = Paragraph::new(lines)
+                    .alignment(Alignment::Left)
+                    .block(Block::default())
+                    .wrap(ratatui::widgets::Wrap { trim: false });
+                frame_to_render_on.render_widget(card_extra_info, *segment_area);
+            }
+        }
+    }
+}
+
+/// A run of the card face, the description takes whatever height the other runs leave
+enum CardFaceSegment {
+    Description,
+    Lines(Vec<Line<'static>>),
+}
 
+/// Groups the layout tokens of a comfortable card into segments. Due and tags get a row each,
+/// neighbouring short tokens share a row separated by " | " and switch to their short labels
+/// when the row does not fit. A row directly below the description is spaced off by a blank line
+fn get_card_face_segments(
+    app: &App,
+    card: &Card,
+    card_face_layout: &[CardFaceToken],
+    available_width: usize,
+    is_active: bool,
+) -> Vec<CardFaceSegment> {
+    let general_style = check_if_active_and_get_style(
+        is_active,
+        app.current_theme.inactive_text_style,
+        app.current_theme.general_style,
+    );
+    let mut segments = vec![];
+    let mut lines: Vec<Line<'static>> = vec![];
+    let mut inline_tokens = vec![];
+    let flush_inline_tokens = |inline_tokens: &mut Vec<CardFaceInlineToken>,
+                               lines: &mut Vec<Line<'static>>| {
+        if inline_tokens.is_empty() {
+            return;
+        }
+        let required_space = inline_tokens
+            .iter()
+            .map(|token| token.label.chars().count())
+            .sum::<usize>()
+            + 3 * (inline_tokens.len() - 1); // 3 is for the " | " separator
+        let abbreviate = required_space > available_width;
+        let mut spans = vec![];
+        for (index, token) in inline_tokens.drain(..).enumerate() {
+            if index > 0 {
+                spans.push(Span::styled(" | ", general_style));
+            }
+            let label = if abbreviate {
+                token.short_label
+            } else {
+                token.label
+            };
+            spans.push(Span::styled(label, token.style));
+        }
+        lines.push(Line::from(spans));
+    };
+    for token in card_face_layout {
+        match token {
+            CardFaceToken::Name => {}
+            CardFaceToken::Description => {
+                flush_inline_tokens(&mut inline_tokens, &mut lines);
+                if !lines.is_empty() {
+                    segments.push(CardFaceSegment::Lines(std::mem::take(&mut lines)));
+                }
+                segments.push(CardFaceSegment::Description);
+            }
+            CardFaceToken::Due => {
+                flush_inline_tokens(&mut inline_tokens, &mut lines);
+                lines.push(get_card_due_line(app, card, is_active));
+            }
+            CardFaceToken::Tags => {
+                flush_inline_tokens(&mut inline_tokens, &mut lines);
+                if !card.tags.is_empty() {
+                    lines.push(Line::from(Span::styled(
+                        format!("Tags: {}", card.tags.join(", ")),
+                        general_style,
+                    )));
+                }
+            }
+            CardFaceToken::DueRelative
+            | CardFaceToken::Priority
+            | CardFaceToken::Status
+            | CardFaceToken::Id
+            | CardFaceToken::Estimate => {
+                if let Some(inline_token) = get_card_face_inline_token(app, card, *token, is_active)
+                {
+                    inline_tokens.push(inline_token);
+                }
+            }
+        }
+    }
+    flush_inline_tokens(&mut inline_tokens, &mut lines);
+    if !lines.is_empty() {
+        segments.push(CardFaceSegment::Lines(lines));
+    }
+    let mut follows_description = false;
+    for segment in segments.iter_mut() {
+        match segment {
+            CardFaceSegment::Description => follows_description = true,
+            CardFaceSegment::Lines(lines) => {
+                if follows_description {
+                    lines.insert(0, Line::from(""));
+                }
+                follows_description = false;
+            }
+        }
+    }
+    segments
+}
+
+/// A card face token that shares its row with its neighbours
+struct CardFaceInlineToken {
+    label: String,
+    short_label: String,
+    style: Style,
+}
+
+/// None when the card has nothing to show for the token, so it is skipped
+fn get_card_face_inline_token(
+    app: &App,
+    card: &Card,
+    token: CardFaceToken,
+    is_active: bool,
+) -> Option<CardFaceInlineToken> {
+    let general_style = check_if_active_and_get_style(
+        is_active,
+        app.current_theme.inactive_text_style,
+        app.current_theme.general_style,
+    );
+    match token {
+        CardFaceToken::Priority => Some(CardFaceInlineToken {
+            label: format!("Priority: {}", card.priority),
+            short_label: format!("P: {}", card.priority),
+            style: get_card_priority_style(app, card, is_active),
+        }),
+        CardFaceToken::Status => Some(CardFaceInlineToken {
+            label: format!("Status: {}", card.get_status_name()),
+            short_label: format!("S: {}", card.get_status_name()),
+            style: get_card_status_style(app, card, is_active),
+        }),
+        CardFaceToken::DueRelative => {
+            let days_left = get_card_days_left(card, app.config.date_time_format)?;
+            let label = match days_left.cmp(&0) {
+                Ordering::Less => format!("Overdue by {}d", -days_left),
+                Ordering::Equal => "Due today".to_string(),
+                Ordering::Greater => format!("Due in {}d", days_left),
+            };
+            Some(CardFaceInlineToken {
+                label,
+                short_label: format!("{}d", days_left),
+                style: get_card_due_style(app, days_left, is_active),
+            })
+        }
+        CardFaceToken::Id => Some(CardFaceInlineToken {
+            label: format!("Id: {}", format_short_id(card.id)),
+            short_label: format_short_id(card.id),
+            style: general_style,
+        }),
+        CardFaceToken::Estimate => card.estimate.map(|estimate| CardFaceInlineToken {
+            label: format!("Estimate: {}", format_estimate(estimate)),
+            short_label: format!("E: {}", format_estimate(estimate)),
+            style: general_style,
+        }),
+        CardFaceToken::Name
+        | CardFaceToken::Description
+        | CardFaceToken::Due
+        | CardFaceToken::Tags => None,
+    }
+}
+
+fn get_card_priority_style(app: &App, card: &Card, is_active: bool) -> Style {
+    if !is_active {
+        app.current_theme.inactive_text_style
+    } else {
+        match card.priority {
+            CardPriority::High => app.current_theme.card_priority_high_style,
+            CardPriority::Medium => app.current_theme.card_priority_medium_style,
+            CardPriority::Low => app.current_theme.card_priority_low_style,
+        }
+    }
+}
+
+fn get_card_status_style(app: &App, card: &Card, is_active: bool) -> Style {
+    if !is_active {
+        app.current_theme.inactive_text_style
+    } else {
+        match card.card_status {
+            CardStatus::Active => app.current_theme.card_status_active_style,
+            CardStatus::Complete => app.current_theme.card_status_completed_style,
+            CardStatus::Stale | CardStatus::Archived => app.current_theme.card_status_stale_style,
+        }
+    }
+}
+
+/// Overdue, due within the warning delta or the default due style
+fn get_card_due_style(app: &App, days_left: i64, is_active: bool) -> Style {
+    check_if_active_and_get_style(
+        is_active,
+        app.current_theme.inactive_text_style,
+        if days_left < 0 {
+            app.current_theme.card_due_overdue_style
+        } else if days_left <= app.config.warning_delta as i64 {
+            app.current_theme.card_due_warning_style
+        } else {
+            app.current_theme.card_due_default_style
+        },
+    )
+}
+
+/// The "Due: ..." row of a comfortable card, the date range when the card also has a start date
+fn get_card_due_line(app: &App, card: &Card, is_active: bool) -> Line<'static> {
     let card_due_default_style = check_if_active_and_get_style(
         is_active,
         app.current_theme.inactive_text_style,
@@ -703,23 +1227,16 @@ fn render_a_single_card(
         app.current_theme.inactive_text_style,
         app.current_theme.card_due_overdue_style,
     );
-    let general_style = check_if_active_and_get_style(
-        is_active,
-        app.current_theme.inactive_text_style,
-        app.current_theme.general_style,
-    );
-
-    let mut card_extra_info = vec![Line::from("")];
     if card.due_date == FIELD_NOT_SET {
-        card_extra_info.push(Line::from(Span::styled(
+        Line::from(Span::styled(
             format!("Due: {}", FIELD_NOT_SET),
             card_due_default_style,
-        )))
+        ))
     } else {
         let card_due_date = card.due_date.clone();
         let parsed_due_date =
             date_format_converter(card_due_date.trim(), app.config.date_time_format);
-        let card_due_date_styled = if let Ok(parsed_due_date) = parsed_due_date {
+        if let Ok(parsed_due_date) = parsed_due_date {
             if parsed_due_date == FIELD_NOT_SET || parsed_due_date.is_empty() {
                 Line::from(Span::styled(
                     format!("Due: {}", parsed_due_date),
@@ -763,22 +1280,19 @@ fn render_a_single_card(
                         (days_left, parsed_due_date)
                     }
                 };
+                let due_label = card_date_range_label(card)
+                    .unwrap_or_else(|| format!("Due: {}", parsed_due_date));
                 if days_left >= 0 {
                     match days_left.cmp(&(app.config.warning_delta as i64)) {
-                        Ordering::Less | Ordering::Equal => Line::from(Span::styled(
-                            format!("Due: {}", parsed_due_date),
-                            card_due_warning_style,
-                        )),
-                        Ordering::Greater => Line::from(Span::styled(
-                            format!("Due: {}", parsed_due_date),
-                            card_due_default_style,
-                        )),
+                        Ordering::Less | Ordering::Equal => {
+                            Line::from(Span::styled(due_label.clone(), card_due_warning_style))
+                        }
+                        Ordering::Greater => {
+                            Line::from(Span::styled(due_label.clone(), card_due_default_style))
+                        }
                     }
                 } else {
-                    Line::from(Span::styled(
-                        format!("Due: {}", parsed_due_date),
-                        card_due_overdue_style,
-                    ))
+                    Line::from(Span::styled(due_label, card_due_overdue_style))
                 }
             }
         } else {
@@ -786,71 +1300,274 @@ fn render_a_single_card(
                 format!("Due: {}", card_due_date),
                 card_due_default_style,
             ))
-        };
-        card_extra_info.extend(vec![card_due_date_styled]);
+        }
     }
+}
 
-    let mut card_status = format!("Status: {}", card.card_status.clone());
-    let mut card_priority = format!("Priority: {}", card.priority.clone());
-    let required_space = card_status.len() + 3 + card_priority.len(); // 3 is for the " | " separator
+/// Compact ("▸ [H] Card name · 2d") and Minimal (name only) cards, drawn on one row without
+/// a border. The priority is a badge before the name, the other layout tokens follow it
+fn render_a_single_line_card(
+    app: &App,
+    render_area: Rect,
+    card_style: Style,
+    card: &Card,
+    card_age: Option<i64>,
+    frame_to_render_on: &mut Frame,
+    is_active: bool,
+) {
+    let general_style = check_if_active_and_get_style(
+        is_active,
+        app.current_theme.inactive_text_style,
+        app.current_theme.general_style,
+    );
+    let priority_style = get_card_priority_style(app, card, is_active);
+    let days_left = get_card_days_left(card, app.config.date_time_format);
+    let is_due_soon =
+        days_left.is_some_and(|days_left| days_left <= app.config.warning_delta as i64);
+    let due_style = check_if_active_and_get_style(
+        is_active,
+        app.current_theme.inactive_text_style,
+        match days_left {
+            Some(days_left) if days_left < 0 => app.current_theme.card_due_overdue_style,
+            Some(_) if is_due_soon => app.current_theme.card_due_warning_style,
+            _ => app.current_theme.card_due_default_style,
+        },
+    );
+    let is_current_card = app.state.current_card_id == Some(card.id);
 
-    // if required space is not available abbreviate the card status and priority
-    if required_space > (render_area.width - 2) as usize {
-        // accounting for border
-        card_status = format!("S: {}", card.card_status.clone());
-        card_priority = format!("P: {}", card.priority.clone());
-    }
-    let spacer_span = Span::styled(" | ", general_style);
-    let card_status = if !is_active {
-        Span::styled(card_status, app.current_theme.inactive_text_style)
-    } else {
-        match card.card_status {
-            CardStatus::Active => {
-                Span::styled(card_status, app.current_theme.card_status_active_style)
+    let marker = if is_current_card { "▸ " } else { "• " };
+    let mut prefix_spans = vec![Span::styled(marker, priority_style)];
+    let mut suffix_spans = vec![];
+    // The description has no one row form, the density decides how many of the others are drawn
+    let card_face_tokens = app
+        .config
+        .card_face_layout
+        .iter()
+        .filter(|token| !matches!(token, CardFaceToken::Name | CardFaceToken::Description))
+        .take(
+            app.config
+                .card_density
+                .card_face_token_limit()
+                .unwrap_or(usize::MAX),
+        )
+        .copied()
+        .collect::<Vec<CardFaceToken>>();
+    for token in card_face_tokens.iter() {
+        let suffix = match token {
+            CardFaceToken::Priority => {
+                let priority_initial = card.priority.to_string().chars().next().unwrap_or(' ');
+                prefix_spans.push(Span::styled(
+                    format!("[{}] ", priority_initial),
+                    priority_style,
+                ));
+                None
             }
-            CardStatus::Complete => {
-                Span::styled(card_status, app.current_theme.card_status_completed_style)
+            CardFaceToken::Due | CardFaceToken::DueRelative => {
+                days_left.map(|days_left| Span::styled(format!("{}d", days_left), due_style))
             }
-            CardStatus::Stale => {
-                Span::styled(card_status, app.current_theme.card_status_stale_style)
+            CardFaceToken::Status => Some(Span::styled(
+                card.get_status_name(),
+                get_card_status_style(app, card, is_active),
+            )),
+            CardFaceToken::Tags => {
+                (!card.tags.is_empty()).then(|| Span::styled(card.tags.join(", "), general_style))
             }
+            CardFaceToken::Id => Some(Span::styled(format_short_id(card.id), general_style)),
+            CardFaceToken::Estimate => card
+                .estimate
+                .map(|estimate| Span::styled(format_estimate(estimate), general_style)),
+            CardFaceToken::Name | CardFaceToken::Description => None,
+        };
+        if let Some(suffix) = suffix {
+            suffix_spans.push(Span::styled(" · ", general_style));
+            suffix_spans.push(suffix);
         }
+    }
+    if let Some(checklist_progress) = get_checklist_progress_span(app, card, is_active) {
+        suffix_spans.push(Span::styled(" · ", general_style));
+        suffix_spans.push(checklist_progress);
+    }
+    if let Some(waiting_on) = get_waiting_on_span(app, card, is_active) {
+        suffix_spans.push(Span::styled(" · ", general_style));
+        suffix_spans.push(waiting_on);
+    }
+    let is_due_shown = card_face_tokens
+        .iter()
+        .any(|token| matches!(token, CardFaceToken::Due | CardFaceToken::DueRelative));
+    let is_due_in_layout = app
+        .config
+        .card_face_layout
+        .iter()
+        .any(|token| matches!(token, CardFaceToken::Due | CardFaceToken::DueRelative));
+    let name_style = if is_due_in_layout && !is_due_shown && is_due_soon && !is_current_card {
+        // The due date did not make the cut, the name carries its warning instead
+        card_style.patch(due_style)
+    } else {
+        card_style
     };
-    let card_priority = if !is_active {
-        Span::styled(card_priority, app.current_theme.inactive_text_style)
+    let (aged_name_style, age_suffix) = get_card_age_cue(app, card, card_age, name_style);
+    let name_style = aged_name_style.unwrap_or(name_style);
+    if let Some(age_suffix) = age_suffix {
+        suffix_spans.push(Span::styled(format!(" {}", age_suffix), general_style));
+    }
+
+    if has_conflict_markers(app, card) {
+        suffix_spans.push(Span::styled(
+            format!(" {}", CONFLICT_MARKER_BADGE),
+            check_if_active_and_get_style(
+                is_active,
+                app.current_theme.inactive_text_style,
+                app.current_theme.log_warn_style,
+            ),
+        ));
+    }
+
+    let reserved_width = prefix_spans
+        .iter()
+        .chain(suffix_spans.iter())
+        .fold(0, |acc, span| acc + span.content.chars().count());
+    let available_width = (render_area.width as usize).saturating_sub(reserved_width);
+    let card_name = if card.name.chars().count() > available_width {
+        let mut truncated_name = card
+            .name
+            .chars()
+            .take(available_width.saturating_sub(1))
+            .collect::<String>();
+        truncated_name.push('…');
+        truncated_name
     } else {
-        match card.priority {
-            CardPriority::High => {
-                Span::styled(card_priority, app.current_theme.card_priority_high_style)
-            }
-            CardPriority::Medium => {
-                Span::styled(card_priority, app.current_theme.card_priority_medium_style)
-            }
-            CardPriority::Low => {
-                Span::styled(card_priority, app.current_theme.card_priority_low_style)
-            }
-        }
+        card.name.clone()
     };
-    let status_line = Line::from(vec![card_priority, spacer_span, card_status]);
-    card_extra_info.extend(vec![status_line]);
 
-    let card_block = Block::default()
-        .title(&*card_title)
-        .borders(Borders::ALL)
-        .border_style(card_style)
-        .border_type(BorderType::Rounded);
-    let card_paragraph = Paragraph::new(card_description)
-        .alignment(Alignment::Left)
-        .block(Block::default())
-        .wrap(ratatui::widgets::Wrap { trim: false });
-    let card_extra_info = Paragraph::new(card_extra_info)
+    let mut card_spans = prefix_spans;
+    card_spans.push(Span::styled(card_name, name_style));
+    card_spans.extend(suffix_spans);
+    let card_paragraph = Paragraph::new(Line::from(card_spans))
         .alignment(Alignment::Left)
-        .block(Block::default())
-        .wrap(ratatui::widgets::Wrap { trim: false });
+        .block(Block::default());
+    frame_to_render_on.render_widget(card_paragraph, render_area);
+}
 
-    frame_to_render_on.render_widget(card_block, render_area);
-    frame_to_render_on.render_widget(card_paragraph, inner_card_chunks[0]);
-    frame_to_render_on.render_widget(card_extra_info, inner_card_chunks[1]);
+/// The "3/5" checklist count for the card face, None when the card has no checklist or the
+/// count is turned off in the config
+fn get_checklist_progress_span(app: &App, card: &Card, is_active: bool) -> Option<Span<'static>> {
+    if !app.config.show_checklist_progress {
+        return None;
+    }
+    let (completed, total) = card.checklist_progress()?;
+    let progress_style = check_if_active_and_get_style(
+        is_active,
+        app.current_theme.inactive_text_style,
+        if completed == total {
+            app.current_theme.card_status_completed_style
+        } else {
+            app.current_theme.general_style
+        },
+    );
+    Some(Span::styled(
+        format!("{}/{}", completed, total),
+        progress_style,
+    ))
+}
+
+/// The "⏳ Alice" badge for a card that is waiting on someone
+fn get_waiting_on_span(app: &App, card: &Card, is_active: bool) -> Option<Span<'static>> {
+    let waiting_on = card.waiting_on.as_ref()?;
+    Some(Span::styled(
+        format!("⏳ {}", waiting_on),
+        check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.help_text_style,
+        ),
+    ))
+}
+
+/// Whether the card was flagged by the last conflict marker scan
+fn has_conflict_markers(app: &App, card: &Card) -> bool {
+    app.state
+        .cards_with_conflict_markers
+        .iter()
+        .any(|(_, card_id)| *card_id == card.id)
+}
+
+/// The faded title style and "· 34d" suffix for a card past the aging threshold, either is
+/// None when the configured cue leaves it out. The current card is never faded so the
+/// selection stays readable
+fn get_card_age_cue(
+    app: &App,
+    card: &Card,
+    card_age: Option<i64>,
+    title_style: Style,
+) -> (Option<Style>, Option<String>) {
+    let Some(age_days) = card_age else {
+        return (None, None);
+    };
+    let tier = age_tier(age_days, app.config.card_aging_days);
+    if tier == AgeTier::Fresh {
+        return (None, None);
+    }
+    let cue = app.config.card_aging_cue;
+    let is_current_card = app.state.current_card_id == Some(card.id);
+    let aged_title_style = if !cue.dims() || is_current_card {
+        None
+    } else if tier == AgeTier::Old {
+        Some(
+            title_style
+                .patch(app.current_theme.inactive_text_style)
+                .add_modifier(Modifier::DIM),
+        )
+    } else {
+        Some(title_style.add_modifier(Modifier::DIM))
+    };
+    let age_suffix = cue.shows_age_suffix().then(|| format!("· {}d", age_days));
+    (aged_title_style, age_suffix)
+}
+
+/// A short "May 3 → May 9" label for cards with both a start and a due date
+fn card_date_range_label(card: &Card) -> Option<String> {
+    let start_date = parse_date_time(card.start_date.as_deref()?)?;
+    let due_date = parse_date_time(&card.due_date)?;
+    Some(format!(
+        "{} → {}",
+        start_date.format("%b %-d"),
+        due_date.format("%b %-d")
+    ))
+}
+
+/// Whole days until the card is due, None when it has no valid due date
+pub fn get_card_days_left(card: &Card, date_time_format: DateTimeFormat) -> Option<i64> {
+    if card.due_date == FIELD_NOT_SET {
+        return None;
+    }
+    let parsed_due_date = date_format_converter(card.due_date.trim(), date_time_format).ok()?;
+    if parsed_due_date == FIELD_NOT_SET || parsed_due_date.is_empty() {
+        return None;
+    }
+    match date_format_finder(&parsed_due_date).ok()? {
+        DateTimeFormat::DayMonthYear
+        | DateTimeFormat::MonthDayYear
+        | DateTimeFormat::YearMonthDay => {
+            NaiveDate::parse_from_str(&parsed_due_date, date_time_format.to_parser_string())
+                .ok()
+                .map(|due_date| {
+                    due_date
+                        .signed_duration_since(Local::now().date_naive())
+                        .num_days()
+                })
+        }
+        DateTimeFormat::DayMonthYearTime
+        | DateTimeFormat::MonthDayYearTime
+        | DateTimeFormat::YearMonthDayTime => {
+            NaiveDateTime::parse_from_str(&parsed_due_date, date_time_format.to_parser_string())
+                .ok()
+                .map(|due_date| {
+                    due_date
+                        .signed_duration_since(Local::now().naive_local())
+                        .num_days()
+                })
+        }
+    }
 }
 
 pub fn draw_title<'a>(app: &mut App, render_area: Rect, is_active: bool) -> Paragraph<'a> {
@@ -861,15 +1578,36 @@ pub fn draw_title<'a>(app: &mut App, render_area: Rect, is_active: bool) -> Para
     );
     let border_style =
         get_mouse_focusable_field_style(app, Focus::Title, &render_area, is_active, false);
-    Paragraph::new(APP_TITLE)
-        .alignment(Alignment::Center)
-        .block(
-            Block::default()
-                .style(title_style)
-                .borders(Borders::ALL)
-                .border_style(border_style)
-                .border_type(BorderType::Rounded),
-        )
+    let unread_count = app.state.notifications.unread_count();
+    let mut title = if unread_count > 0 {
+        format!("{} [{} unread]", APP_TITLE, unread_count)
+    } else {
+        APP_TITLE.to_string()
+    };
+    if app.has_unsaved_changes() {
+        title.push_str(" (unsaved)");
+    }
+    if app.state.safe_mode {
+        title.push_str(" | SAFE MODE");
+    }
+    // Derived from the wall clock on every draw, the text only changes once a second
+    if let Some(focus_timer) = &app.state.focus_timer {
+        title.push_str(&format!(
+            " | {} {}",
+            focus_timer.remaining_as_string(Local::now()),
+            focus_timer.card_name
+        ));
+        if focus_timer.is_paused() {
+            title.push_str(" (paused)");
+        }
+    }
+    Paragraph::new(title).alignment(Alignment::Center).block(
+        Block::default()
+            .style(title_style)
+            .borders(Borders::ALL)
+            .border_style(border_style)
+            .border_type(BorderType::Rounded),
+    )
 }
 
 pub fn draw_help<'a>(
@@ -937,7 +1675,7 @@ pub fn draw_help<'a>(
     .style(border_style);
 
     let border_block = Block::default()
-        .title("Help")
+        .title(tr(MessageId::TitleHelp))
         .borders(Borders::ALL)
         .style(help_text_style)
         .border_style(border_style)
@@ -1079,3 +1817,53 @@ pub fn render_blank_styled_canvas_with_margin(
         .block(Block::default());
     rect.render_widget(styled_text, new_render_area);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::CardAgingCue;
+
+    #[test]
+    fn the_age_cue_follows_the_configured_cue() {
+        let (mut app, _io_rx) = App::for_tests();
+        app.config.card_aging_days = 7;
+        let card = Card::default();
+        let title_style = app.current_theme.general_style;
+
+        assert_eq!(
+            get_card_age_cue(&app, &card, Some(3), title_style),
+            (None, None)
+        );
+        assert_eq!(
+            get_card_age_cue(&app, &card, None, title_style),
+            (None, None)
+        );
+        for (cue, dims, shows_suffix) in [
+            (CardAgingCue::Dim, true, false),
+            (CardAgingCue::AgeSuffix, false, true),
+            (CardAgingCue::Both, true, true),
+        ] {
+            app.config.card_aging_cue = cue;
+            let (aged_title_style, age_suffix) =
+                get_card_age_cue(&app, &card, Some(10), title_style);
+            assert_eq!(aged_title_style.is_some(), dims, "{}", cue);
+            assert_eq!(age_suffix, shows_suffix.then(|| "· 10d".to_string()));
+        }
+    }
+
+    #[test]
+    fn the_current_card_is_never_faded() {
+        let (mut app, _io_rx) = App::for_tests();
+        app.config.card_aging_days = 7;
+        app.config.card_aging_cue = CardAgingCue::Both;
+        let card = Card::default();
+        let title_style = app.current_theme.general_style;
+        let (aged_title_style, _) = get_card_age_cue(&app, &card, Some(30), title_style);
+        assert!(aged_title_style.is_some_and(|style| style.add_modifier.contains(Modifier::DIM)));
+
+        app.state.current_card_id = Some(card.id);
+        let (aged_title_style, age_suffix) = get_card_age_cue(&app, &card, Some(30), title_style);
+        assert_eq!(aged_title_style, None);
+        assert_eq!(age_suffix.as_deref(), Some("· 30d"));
+    }
+}
@@ -0,0 +1,84 @@
+use crate::{
+    app::{state::Focus, App},
+    constants::LIST_SELECTED_SYMBOL,
+    ui::{
+        rendering::{
+            common::{render_blank_styled_canvas, render_close_button},
+            popup::BoardAccentColorSelector,
+            utils::{
+                calculate_mouse_list_select_index, centered_rect_with_percentage,
+                check_if_active_and_get_style, check_if_mouse_is_in_area,
+            },
+        },
+        Renderable, TextColorOptions,
+    },
+};
+use ratatui::{
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, List, ListItem},
+    Frame,
+};
+
+impl Renderable for BoardAccentColorSelector {
+    fn render(rect: &mut Frame, app: &mut App, is_active: bool) {
+        let general_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.general_style,
+        );
+        let list_select_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.list_select_style,
+        );
+        let board_name = app
+            .state
+            .current_board_id
+            .and_then(|board_id| app.boards.get_board_with_id(board_id))
+            .map(|board| board.name.clone())
+            .unwrap_or_default();
+        let all_colors = TextColorOptions::card_highlight_options()
+            .iter()
+            .map(|color| match color {
+                Some(color) => ListItem::new(vec![Line::from(Span::styled(
+                    color.to_string(),
+                    general_style.fg((*color).into()),
+                ))]),
+                None => ListItem::new(vec![Line::from("None (reset accent)")]),
+            })
+            .collect::<Vec<ListItem>>();
+        let percent_height =
+            (((all_colors.len() + 3) as f32 / rect.area().height as f32) * 100.0) as u16;
+        let popup_area = centered_rect_with_percentage(50, percent_height, rect.area());
+        if check_if_mouse_is_in_area(&app.state.current_mouse_coordinates, &popup_area) {
+            app.state.mouse_focus = Some(Focus::ChangeBoardAccentColorPopup);
+            app.state.set_focus(Focus::ChangeBoardAccentColorPopup);
+            calculate_mouse_list_select_index(
+                app.state.current_mouse_coordinates.1,
+                &all_colors,
+                popup_area,
+                &mut app.state.app_list_states.board_accent_color_selector,
+            );
+        }
+        let colors = List::new(all_colors)
+            .block(
+                Block::default()
+                    .title(format!("Changing Accent Color of \"{}\"", board_name))
+                    .style(general_style)
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded),
+            )
+            .highlight_style(list_select_style)
+            .highlight_symbol(LIST_SELECTED_SYMBOL);
+
+        render_blank_styled_canvas(rect, &app.current_theme, popup_area, is_active);
+        rect.render_stateful_widget(
+            colors,
+            popup_area,
+            &mut app.state.app_list_states.board_accent_color_selector,
+        );
+        if app.config.enable_mouse_support {
+            render_close_button(rect, app, is_active);
+        }
+    }
+}
@@ -0,0 +1,165 @@
+use crate::{
+    app::{kanban::format_estimate, App},
+    model::stats_history::StatsRecord,
+    ui::{
+        rendering::{
+            common::{render_blank_styled_canvas, render_close_button},
+            popup::BoardStats,
+            utils::{centered_rect_with_length, check_if_active_and_get_style},
+        },
+        Renderable,
+    },
+};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Gauge, Paragraph},
+    Frame,
+};
+
+impl Renderable for BoardStats {
+    fn render(rect: &mut Frame, app: &mut App, is_active: bool) {
+        let general_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.general_style,
+        );
+        let label_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.help_key_style,
+        );
+        let overdue_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.card_due_overdue_style,
+        );
+        let progress_bar_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.progress_bar_style,
+        );
+
+        // Worked out on every frame so the numbers follow edits made while the popup is open
+        let board = app
+            .state
+            .current_board_id
+            .and_then(|board_id| app.boards.get_board_with_id(board_id));
+        let (
+            board_name,
+            stats,
+            total_estimate,
+            status_estimates,
+            (estimated_minutes, actual_minutes),
+        ) = match board {
+            Some(board) => (
+                board.name.clone(),
+                StatsRecord::from_boards(std::slice::from_ref(board), ""),
+                board.get_total_estimate(),
+                board.get_estimate_by_status(),
+                board.get_total_minutes(),
+            ),
+            None => (String::new(), StatsRecord::default(), None, vec![], (0, 0)),
+        };
+        let card_count = stats.total_cards - stats.archived_cards;
+        let completion_percent = (stats.completed_cards * 100)
+            .checked_div(card_count)
+            .unwrap_or_default() as u16;
+
+        let stat_line = |label: &str, value: String, value_style| {
+            Line::from(vec![
+                Span::styled(format!("{}: ", label), label_style),
+                Span::styled(value, value_style),
+            ])
+        };
+        let mut stats_lines = vec![
+            stat_line("Total cards", card_count.to_string(), general_style),
+            stat_line(
+                "Completed",
+                stats.completed_cards.to_string(),
+                general_style,
+            ),
+            stat_line("Active", stats.active_cards.to_string(), general_style),
+            stat_line("Stale", stats.stale_cards.to_string(), general_style),
+            stat_line(
+                "Overdue",
+                stats.overdue_cards.to_string(),
+                if stats.overdue_cards > 0 {
+                    overdue_style
+                } else {
+                    general_style
+                },
+            ),
+        ];
+        if stats.archived_cards > 0 {
+            stats_lines.push(stat_line(
+                "Archived",
+                stats.archived_cards.to_string(),
+                general_style,
+            ));
+        }
+        if let Some(total_estimate) = total_estimate {
+            stats_lines.push(stat_line(
+                "Estimate",
+                format_estimate(total_estimate),
+                general_style,
+            ));
+            for (status_name, estimate) in status_estimates {
+                stats_lines.push(stat_line(
+                    &format!("  {}", status_name),
+                    format_estimate(estimate),
+                    general_style,
+                ));
+            }
+        }
+        if estimated_minutes > 0 || actual_minutes > 0 {
+            stats_lines.push(stat_line(
+                "Time",
+                format!(
+                    "{} min estimated, {} min spent",
+                    estimated_minutes, actual_minutes
+                ),
+                general_style,
+            ));
+        }
+
+        // Tall enough for every line, the gauge and the help line inside the border
+        let popup_height = (stats_lines.len() as u16 + 4).max(13);
+        let popup_area = centered_rect_with_length(50, popup_height, rect.area());
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(
+                [
+                    Constraint::Fill(1),
+                    Constraint::Length(1),
+                    Constraint::Length(1),
+                ]
+                .as_ref(),
+            )
+            .margin(1)
+            .split(popup_area);
+
+        let stats_paragraph = Paragraph::new(stats_lines);
+        let completion_gauge = Gauge::default()
+            .gauge_style(progress_bar_style)
+            .percent(completion_percent)
+            .label(format!("{}% complete", completion_percent));
+        let help_paragraph = Paragraph::new("Esc to close")
+            .alignment(Alignment::Center)
+            .style(general_style);
+        let border_block = Block::default()
+            .title(format!("Stats for {}", board_name))
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(general_style);
+
+        render_blank_styled_canvas(rect, &app.current_theme, popup_area, is_active);
+        rect.render_widget(stats_paragraph, chunks[0]);
+        rect.render_widget(completion_gauge, chunks[1]);
+        rect.render_widget(help_paragraph, chunks[2]);
+        rect.render_widget(border_block, popup_area);
+        if app.config.enable_mouse_support {
+            render_close_button(rect, app, is_active)
+        }
+    }
+}
@@ -0,0 +1,85 @@
+use crate::{
+    app::{calendar::cards_due_on, state::Focus, App},
+    constants::LIST_SELECTED_SYMBOL,
+    ui::{
+        rendering::{
+            common::{render_blank_styled_canvas, render_close_button},
+            popup::CalendarDayCards,
+            utils::{
+                calculate_mouse_list_select_index, centered_rect_with_percentage,
+                check_if_active_and_get_style, check_if_mouse_is_in_area,
+            },
+        },
+        Renderable,
+    },
+};
+use ratatui::{
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, List, ListItem},
+    Frame,
+};
+
+impl Renderable for CalendarDayCards {
+    fn render(rect: &mut Frame, app: &mut App, is_active: bool) {
+        let general_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.general_style,
+        );
+        let help_text_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.help_text_style,
+        );
+        let list_select_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.list_select_style,
+        );
+        let due_cards = cards_due_on(&app.boards, app.state.calendar_date)
+            .into_iter()
+            .map(|due_card| {
+                ListItem::new(vec![Line::from(vec![
+                    Span::styled(due_card.card_name, general_style),
+                    Span::styled(format!(" ({})", due_card.board_name), help_text_style),
+                ])])
+            })
+            .collect::<Vec<ListItem>>();
+        let percent_height =
+            (((due_cards.len() + 3) as f32 / rect.area().height as f32) * 100.0) as u16;
+        let popup_area = centered_rect_with_percentage(50, percent_height, rect.area());
+        if check_if_mouse_is_in_area(&app.state.current_mouse_coordinates, &popup_area) {
+            app.state.mouse_focus = Some(Focus::CalendarDayCardsPopup);
+            app.state.set_focus(Focus::CalendarDayCardsPopup);
+            calculate_mouse_list_select_index(
+                app.state.current_mouse_coordinates.1,
+                &due_cards,
+                popup_area,
+                &mut app.state.app_list_states.calendar_day_cards,
+            );
+        }
+        let due_cards_list = List::new(due_cards)
+            .block(
+                Block::default()
+                    .title(format!(
+                        "Due on {}",
+                        app.state.calendar_date.format("%A %-d %B %Y")
+                    ))
+                    .style(general_style)
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded),
+            )
+            .highlight_style(list_select_style)
+            .highlight_symbol(LIST_SELECTED_SYMBOL);
+
+        render_blank_styled_canvas(rect, &app.current_theme, popup_area, is_active);
+        rect.render_stateful_widget(
+            due_cards_list,
+            popup_area,
+            &mut app.state.app_list_states.calendar_day_cards,
+        );
+        if app.config.enable_mouse_support {
+            render_close_button(rect, app, is_active);
+        }
+    }
+}
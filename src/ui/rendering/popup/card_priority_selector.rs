@@ -1,6 +1,7 @@
 use crate::{
-    app::{kanban::CardPriority, state::Focus, App},
+    app::{app_helper::get_card_priority_change_preview, kanban::CardPriority, state::Focus, App},
     constants::LIST_SELECTED_SYMBOL,
+    i18n::{tr, MessageId},
     ui::{
         rendering::{
             common::{render_blank_styled_canvas, render_close_button},
@@ -14,8 +15,9 @@ use crate::{
     },
 };
 use ratatui::{
+    layout::{Constraint, Direction, Layout},
     text::Line,
-    widgets::{Block, BorderType, Borders, List, ListItem},
+    widgets::{Block, BorderType, Borders, List, ListItem, Paragraph, Wrap},
     Frame,
 };
 
@@ -54,16 +56,52 @@ impl Renderable for CardPrioritySelector {
             .iter()
             .map(|p| ListItem::new(vec![Line::from(p.to_string())]))
             .collect::<Vec<ListItem>>();
-        let percent_height =
-            (((all_priorities.len() + 3) as f32 / rect.area().height as f32) * 100.0) as u16;
+        let selected_priority = CardPriority::all()
+            .get(
+                app.state
+                    .app_list_states
+                    .card_priority_selector
+                    .selected()
+                    .unwrap_or(0),
+            )
+            .cloned()
+            .unwrap_or(CardPriority::Low);
+        let (targeted_cards, _) = app.get_cards_targeted_by_selector();
+        let current_priorities = targeted_cards
+            .iter()
+            .map(|card| card.priority.clone())
+            .collect::<Vec<CardPriority>>();
+        let auto_sort_move = match (targeted_cards.as_slice(), app.state.current_board_id) {
+            ([card], Some(board_id)) => app.boards.get_board_with_id(board_id).and_then(|board| {
+                Some((
+                    board.cards.get_card_index(card.id)?,
+                    board.get_auto_sorted_index(card.id, &selected_priority)?,
+                ))
+            }),
+            _ => None,
+        };
+        let preview_lines = get_card_priority_change_preview(
+            &current_priorities,
+            &selected_priority,
+            auto_sort_move,
+        );
+        let list_height = all_priorities.len() as u16 + 2;
+        let preview_height = preview_lines.len() as u16 + 2;
+        let percent_height = ((((list_height + preview_height) as usize + 1) as f32
+            / rect.area().height as f32)
+            * 100.0) as u16;
         let popup_area = centered_rect_with_percentage(50, percent_height, rect.area());
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(list_height), Constraint::Fill(1)])
+            .split(popup_area);
         if check_if_mouse_is_in_area(&app.state.current_mouse_coordinates, &popup_area) {
             app.state.mouse_focus = Some(Focus::ChangeCardPriorityPopup);
             app.state.set_focus(Focus::ChangeCardPriorityPopup);
             calculate_mouse_list_select_index(
                 app.state.current_mouse_coordinates.1,
                 &all_priorities,
-                popup_area,
+                chunks[0],
                 &mut app.state.app_list_states.card_priority_selector,
             );
         }
@@ -81,12 +119,28 @@ impl Renderable for CardPrioritySelector {
             .highlight_style(list_select_style)
             .highlight_symbol(LIST_SELECTED_SYMBOL);
 
+        let preview = Paragraph::new(
+            preview_lines
+                .into_iter()
+                .map(Line::from)
+                .collect::<Vec<Line>>(),
+        )
+        .block(
+            Block::default()
+                .title(tr(MessageId::TitlePreview))
+                .style(general_style)
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded),
+        )
+        .wrap(Wrap { trim: true });
+
         render_blank_styled_canvas(rect, &app.current_theme, popup_area, is_active);
         rect.render_stateful_widget(
             priorities,
-            popup_area,
+            chunks[0],
             &mut app.state.app_list_states.card_priority_selector,
         );
+        rect.render_widget(preview, chunks[1]);
         if app.config.enable_mouse_support {
             render_close_button(rect, app, is_active);
         }
@@ -1,6 +1,7 @@
 use crate::{
-    app::{kanban::CardStatus, state::Focus, App},
+    app::{app_helper::get_card_status_change_preview, kanban::StatusSelection, state::Focus, App},
     constants::LIST_SELECTED_SYMBOL,
+    i18n::{tr, MessageId},
     ui::{
         rendering::{
             common::{render_blank_styled_canvas, render_close_button},
@@ -14,8 +15,9 @@ use crate::{
     },
 };
 use ratatui::{
+    layout::{Constraint, Direction, Layout},
     text::Line,
-    widgets::{Block, BorderType, Borders, List, ListItem},
+    widgets::{Block, BorderType, Borders, List, ListItem, Paragraph, Wrap},
     Frame,
 };
 
@@ -50,20 +52,49 @@ impl Renderable for CardStatusSelector {
                 }
             }
         }
-        let all_statuses = CardStatus::all()
+        let status_selections = app.get_current_board_status_selections();
+        let all_statuses = status_selections
             .iter()
             .map(|s| ListItem::new(vec![Line::from(s.to_string())]))
             .collect::<Vec<ListItem>>();
-        let percent_height =
-            (((all_statuses.len() + 3) as f32 / rect.area().height as f32) * 100.0) as u16;
+        let selected_status = status_selections
+            .get(
+                app.state
+                    .app_list_states
+                    .card_status_selector
+                    .selected()
+                    .unwrap_or(0),
+            )
+            .cloned()
+            .unwrap_or_else(|| status_selections[0].clone());
+        let (targeted_cards, completed_cards_hidden) = app.get_cards_targeted_by_selector();
+        let current_statuses = targeted_cards
+            .iter()
+            .map(StatusSelection::of)
+            .collect::<Vec<StatusSelection>>();
+        let preview_lines = get_card_status_change_preview(
+            &current_statuses,
+            &selected_status,
+            completed_cards_hidden,
+            app.config.date_time_format,
+        );
+        let list_height = all_statuses.len() as u16 + 2;
+        let preview_height = preview_lines.len() as u16 + 2;
+        let percent_height = ((((list_height + preview_height) as usize + 1) as f32
+            / rect.area().height as f32)
+            * 100.0) as u16;
         let popup_area = centered_rect_with_percentage(50, percent_height, rect.area());
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(list_height), Constraint::Fill(1)])
+            .split(popup_area);
         if check_if_mouse_is_in_area(&app.state.current_mouse_coordinates, &popup_area) {
             app.state.mouse_focus = Some(Focus::ChangeCardStatusPopup);
             app.state.set_focus(Focus::ChangeCardStatusPopup);
             calculate_mouse_list_select_index(
                 app.state.current_mouse_coordinates.1,
                 &all_statuses,
-                popup_area,
+                chunks[0],
                 &mut app.state.app_list_states.card_status_selector,
             );
         }
@@ -81,12 +112,28 @@ impl Renderable for CardStatusSelector {
             .highlight_style(list_select_style)
             .highlight_symbol(LIST_SELECTED_SYMBOL);
 
+        let preview = Paragraph::new(
+            preview_lines
+                .into_iter()
+                .map(Line::from)
+                .collect::<Vec<Line>>(),
+        )
+        .block(
+            Block::default()
+                .title(tr(MessageId::TitlePreview))
+                .style(general_style)
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded),
+        )
+        .wrap(Wrap { trim: true });
+
         render_blank_styled_canvas(rect, &app.current_theme, popup_area, is_active);
         rect.render_stateful_widget(
             statuses,
-            popup_area,
+            chunks[0],
             &mut app.state.app_list_states.card_status_selector,
         );
+        rect.render_widget(preview, chunks[1]);
         if app.config.enable_mouse_support {
             render_close_button(rect, app, is_active);
         }
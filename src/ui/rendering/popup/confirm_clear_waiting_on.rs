@@ -0,0 +1,110 @@
+use crate::{
+    app::{state::Focus, App},
+    i18n::{tr, MessageId},
+    ui::{
+        rendering::{
+            common::{render_blank_styled_canvas, render_close_button},
+            popup::ConfirmClearWaitingOn,
+            utils::{
+                centered_rect_with_length, check_if_active_and_get_style,
+                get_mouse_focusable_field_style, truncate_to_width,
+            },
+        },
+        Renderable,
+    },
+};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout},
+    widgets::{Block, BorderType, Borders, Paragraph},
+    Frame,
+};
+
+const POPUP_WIDTH: u16 = 40;
+const POPUP_HEIGHT: u16 = 8;
+
+impl Renderable for ConfirmClearWaitingOn {
+    fn render(rect: &mut Frame, app: &mut App, is_active: bool) {
+        let popup_area = centered_rect_with_length(POPUP_WIDTH, POPUP_HEIGHT, rect.area());
+        let main_chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(1), Constraint::Fill(1)].as_ref())
+            .margin(2)
+            .split(popup_area);
+        let chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Fill(1), Constraint::Fill(1)].as_ref())
+            .split(main_chunks[1]);
+
+        let general_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.general_style,
+        );
+        // The card being edited may not have been saved to its board yet
+        let card_waiting_on = app
+            .state
+            .waiting_on_to_clear
+            .and_then(|(board_id, card_id)| {
+                if let Some(card) = app
+                    .state
+                    .card_being_edited
+                    .as_ref()
+                    .filter(|(edited_board_id, card)| {
+                        *edited_board_id == board_id && card.id == card_id
+                    })
+                    .map(|(_, card)| card)
+                {
+                    return card.waiting_on.clone();
+                }
+                app.boards
+                    .get_board_with_id(board_id)?
+                    .cards
+                    .get_card_with_id(card_id)?
+                    .waiting_on
+                    .clone()
+            })
+            .unwrap_or_default();
+        let prompt = format!("Still waiting on {}?", card_waiting_on);
+
+        let clear_button_style =
+            get_mouse_focusable_field_style(app, Focus::SubmitButton, &chunks[0], is_active, false);
+        let keep_waiting_button_style =
+            get_mouse_focusable_field_style(app, Focus::ExtraFocus, &chunks[1], is_active, false);
+        let clear_button = Paragraph::new(tr(MessageId::ButtonClearWaitingOn))
+            .style(clear_button_style)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(clear_button_style)
+                    .border_type(BorderType::Rounded),
+            )
+            .alignment(Alignment::Center);
+        let keep_waiting_button = Paragraph::new(tr(MessageId::ButtonKeepWaitingOn))
+            .style(keep_waiting_button_style)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(keep_waiting_button_style)
+                    .border_type(BorderType::Rounded),
+            )
+            .alignment(Alignment::Center);
+        let border_block = Block::default()
+            .title(tr(MessageId::TitleClearWaitingOn))
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(general_style);
+
+        render_blank_styled_canvas(rect, &app.current_theme, popup_area, is_active);
+        rect.render_widget(
+            Paragraph::new(truncate_to_width(&prompt, main_chunks[0].width as usize))
+                .style(general_style),
+            main_chunks[0],
+        );
+        rect.render_widget(clear_button, chunks[0]);
+        rect.render_widget(keep_waiting_button, chunks[1]);
+        rect.render_widget(border_block, popup_area);
+        if app.config.enable_mouse_support {
+            render_close_button(rect, app, is_active)
+        }
+    }
+}
@@ -1,12 +1,13 @@
 use crate::{
-    app::{state::Focus, App},
+    app::{card_diff::card_diff, state::Focus, App},
+    i18n::{tr, MessageId},
     ui::{
         rendering::{
             common::{render_blank_styled_canvas, render_close_button},
             popup::ConfirmDiscardCardChanges,
             utils::{
                 centered_rect_with_length, check_if_active_and_get_style,
-                get_mouse_focusable_field_style,
+                get_mouse_focusable_field_style, get_preview_lines,
             },
         },
         Renderable,
@@ -14,19 +15,67 @@ use crate::{
 };
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout},
+    text::Line,
     widgets::{Block, BorderType, Borders, Paragraph},
     Frame,
 };
 
+const POPUP_WIDTH: u16 = 30;
+const PLAIN_POPUP_HEIGHT: u16 = 7;
+/// Rows one change may wrap onto before it is cut off with an ellipsis
+const MAX_ROWS_PER_CHANGE: usize = 2;
+
+/// The changes wrapped to the popup width, empty when there is nothing to show
+fn get_diff_lines(app: &App) -> Vec<String> {
+    let Some((board_id, edited_card)) = &app.state.card_being_edited else {
+        return vec![];
+    };
+    let Some(original_card) = app
+        .boards
+        .get_board_with_id(*board_id)
+        .and_then(|board| board.cards.get_card_with_id(edited_card.id))
+    else {
+        return vec![];
+    };
+    let inner_width = (POPUP_WIDTH - 4) as usize;
+    card_diff(original_card, edited_card)
+        .iter()
+        .flat_map(|diff_line| {
+            get_preview_lines(&diff_line.to_string(), inner_width, MAX_ROWS_PER_CHANGE)
+        })
+        .collect()
+}
+
 impl Renderable for ConfirmDiscardCardChanges {
     fn render(rect: &mut Frame, app: &mut App, is_active: bool) {
-        let popup_area = centered_rect_with_length(30, 7, rect.area());
+        let mut diff_lines = get_diff_lines(app);
+        // One extra row keeps the changes apart from the buttons
+        let diff_height = diff_lines.len() as u16 + 1;
+        if diff_lines.is_empty() || PLAIN_POPUP_HEIGHT + diff_height > rect.area().height {
+            diff_lines.clear();
+        }
+        let popup_height = if diff_lines.is_empty() {
+            PLAIN_POPUP_HEIGHT
+        } else {
+            PLAIN_POPUP_HEIGHT + diff_height
+        };
+        let popup_area = centered_rect_with_length(POPUP_WIDTH, popup_height, rect.area());
 
+        let main_chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(
+                [
+                    Constraint::Length(popup_height - PLAIN_POPUP_HEIGHT),
+                    Constraint::Fill(1),
+                ]
+                .as_ref(),
+            )
+            .margin(2)
+            .split(popup_area);
         let chunks = Layout::default()
             .direction(Direction::Horizontal)
             .constraints([Constraint::Fill(1), Constraint::Fill(1)].as_ref())
-            .margin(2)
-            .split(popup_area);
+            .split(main_chunks[1]);
 
         let general_style = check_if_active_and_get_style(
             is_active,
@@ -38,7 +87,7 @@ impl Renderable for ConfirmDiscardCardChanges {
             get_mouse_focusable_field_style(app, Focus::SubmitButton, &chunks[0], is_active, false);
         let dont_save_card_button_style =
             get_mouse_focusable_field_style(app, Focus::ExtraFocus, &chunks[1], is_active, false);
-        let save_theme_button = Paragraph::new("Yes")
+        let save_theme_button = Paragraph::new(tr(MessageId::ButtonYes))
             .style(save_card_button_style)
             .block(
                 Block::default()
@@ -47,7 +96,7 @@ impl Renderable for ConfirmDiscardCardChanges {
                     .border_type(BorderType::Rounded),
             )
             .alignment(Alignment::Center);
-        let dont_save_theme_button = Paragraph::new("No")
+        let dont_save_theme_button = Paragraph::new(tr(MessageId::ButtonNo))
             .style(dont_save_card_button_style)
             .block(
                 Block::default()
@@ -57,12 +106,22 @@ impl Renderable for ConfirmDiscardCardChanges {
             )
             .alignment(Alignment::Center);
         let border_block = Block::default()
-            .title("Save Changes to Card?")
+            .title(tr(MessageId::TitleSaveChangesToCard))
             .borders(Borders::ALL)
             .border_type(BorderType::Rounded)
             .border_style(general_style);
 
         render_blank_styled_canvas(rect, &app.current_theme, popup_area, is_active);
+        if !diff_lines.is_empty() {
+            let diff_paragraph = Paragraph::new(
+                diff_lines
+                    .into_iter()
+                    .map(Line::from)
+                    .collect::<Vec<Line>>(),
+            )
+            .style(general_style);
+            rect.render_widget(diff_paragraph, main_chunks[0]);
+        }
         rect.render_widget(save_theme_button, chunks[0]);
         rect.render_widget(dont_save_theme_button, chunks[1]);
         rect.render_widget(border_block, popup_area);
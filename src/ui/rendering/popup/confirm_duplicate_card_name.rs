@@ -0,0 +1,132 @@
+use crate::{
+    app::{state::Focus, App},
+    i18n::{tr, MessageId},
+    ui::{
+        rendering::{
+            common::{render_blank_styled_canvas, render_close_button},
+            popup::ConfirmDuplicateCardName,
+            utils::{
+                centered_rect_with_length, check_if_active_and_get_style,
+                get_mouse_focusable_field_style,
+            },
+        },
+        Renderable,
+    },
+};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout},
+    widgets::{Block, BorderType, Borders, Paragraph, Wrap},
+    Frame,
+};
+
+impl Renderable for ConfirmDuplicateCardName {
+    fn render(rect: &mut Frame, app: &mut App, is_active: bool) {
+        let popup_area = centered_rect_with_length(60, 10, rect.area());
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Fill(1), Constraint::Length(3)].as_ref())
+            .margin(2)
+            .split(popup_area);
+        let button_chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(
+                [
+                    Constraint::Fill(1),
+                    Constraint::Fill(1),
+                    Constraint::Fill(1),
+                ]
+                .as_ref(),
+            )
+            .split(chunks[1]);
+
+        let general_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.general_style,
+        );
+
+        let message = if let Some(duplicate) = app.state.duplicate_card_name_match {
+            let board = app.boards.get_board_with_id(duplicate.board_id);
+            let card_name = board
+                .and_then(|board| board.cards.get_card_with_id(duplicate.card_id))
+                .map(|card| card.name.clone())
+                .unwrap_or_default();
+            let board_name = board.map(|board| board.name.clone()).unwrap_or_default();
+            format!(
+                "A card named '{}' already exists on board '{}'",
+                card_name, board_name
+            )
+        } else {
+            "A card with the same name already exists".to_string()
+        };
+        let message_paragraph = Paragraph::new(message)
+            .style(general_style)
+            .alignment(Alignment::Center)
+            .wrap(Wrap { trim: true });
+
+        let create_anyway_button_style = get_mouse_focusable_field_style(
+            app,
+            Focus::SubmitButton,
+            &button_chunks[0],
+            is_active,
+            false,
+        );
+        let go_to_existing_button_style = get_mouse_focusable_field_style(
+            app,
+            Focus::ExtraFocus,
+            &button_chunks[1],
+            is_active,
+            false,
+        );
+        let cancel_button_style = get_mouse_focusable_field_style(
+            app,
+            Focus::CancelButton,
+            &button_chunks[2],
+            is_active,
+            false,
+        );
+        let create_anyway_button = Paragraph::new(tr(MessageId::ButtonCreateAnyway))
+            .style(create_anyway_button_style)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(create_anyway_button_style)
+                    .border_type(BorderType::Rounded),
+            )
+            .alignment(Alignment::Center);
+        let go_to_existing_button = Paragraph::new(tr(MessageId::ButtonGoToExisting))
+            .style(go_to_existing_button_style)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(go_to_existing_button_style)
+                    .border_type(BorderType::Rounded),
+            )
+            .alignment(Alignment::Center);
+        let cancel_button = Paragraph::new(tr(MessageId::ButtonCancel))
+            .style(cancel_button_style)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(cancel_button_style)
+                    .border_type(BorderType::Rounded),
+            )
+            .alignment(Alignment::Center);
+        let border_block = Block::default()
+            .title(tr(MessageId::TitleDuplicateCardName))
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(general_style);
+
+        render_blank_styled_canvas(rect, &app.current_theme, popup_area, is_active);
+        rect.render_widget(message_paragraph, chunks[0]);
+        rect.render_widget(create_anyway_button, button_chunks[0]);
+        rect.render_widget(go_to_existing_button, button_chunks[1]);
+        rect.render_widget(cancel_button, button_chunks[2]);
+        rect.render_widget(border_block, popup_area);
+        if app.config.enable_mouse_support {
+            render_close_button(rect, app, is_active)
+        }
+    }
+}
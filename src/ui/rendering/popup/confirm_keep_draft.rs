@@ -0,0 +1,106 @@
+use crate::{
+    app::{state::Focus, App},
+    i18n::{tr, MessageId},
+    ui::{
+        rendering::{
+            common::{render_blank_styled_canvas, render_close_button},
+            popup::ConfirmKeepDraft,
+            utils::{
+                centered_rect_with_length, check_if_active_and_get_style,
+                get_mouse_focusable_field_style, truncate_to_width,
+            },
+        },
+        Renderable, View,
+    },
+};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout},
+    widgets::{Block, BorderType, Borders, Paragraph},
+    Frame,
+};
+
+const POPUP_WIDTH: u16 = 40;
+const POPUP_HEIGHT: u16 = 8;
+
+impl Renderable for ConfirmKeepDraft {
+    fn render(rect: &mut Frame, app: &mut App, is_active: bool) {
+        let popup_area = centered_rect_with_length(POPUP_WIDTH, POPUP_HEIGHT, rect.area());
+        let main_chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(1), Constraint::Fill(1)].as_ref())
+            .margin(2)
+            .split(popup_area);
+        let chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Fill(1), Constraint::Fill(1)].as_ref())
+            .split(main_chunks[1]);
+
+        let general_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.general_style,
+        );
+        // Untitled drafts only have a description, which is named instead
+        let draft_preview = app
+            .state
+            .form_drafts
+            .pending()
+            .map(|draft| {
+                let kind = if draft.view == View::NewBoard {
+                    "board"
+                } else {
+                    "card"
+                };
+                if draft.name.trim().is_empty() {
+                    format!("Unnamed {} with a description", kind)
+                } else {
+                    format!("New {} '{}'", kind, draft.name.trim())
+                }
+            })
+            .unwrap_or_default();
+
+        let keep_button_style =
+            get_mouse_focusable_field_style(app, Focus::SubmitButton, &chunks[0], is_active, false);
+        let discard_button_style =
+            get_mouse_focusable_field_style(app, Focus::ExtraFocus, &chunks[1], is_active, false);
+        let keep_button = Paragraph::new(tr(MessageId::ButtonKeepDraft))
+            .style(keep_button_style)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(keep_button_style)
+                    .border_type(BorderType::Rounded),
+            )
+            .alignment(Alignment::Center);
+        let discard_button = Paragraph::new(tr(MessageId::ButtonDiscard))
+            .style(discard_button_style)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(discard_button_style)
+                    .border_type(BorderType::Rounded),
+            )
+            .alignment(Alignment::Center);
+        let border_block = Block::default()
+            .title(tr(MessageId::TitleKeepDraft))
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(general_style);
+
+        render_blank_styled_canvas(rect, &app.current_theme, popup_area, is_active);
+        rect.render_widget(
+            Paragraph::new(truncate_to_width(
+                &draft_preview,
+                main_chunks[0].width as usize,
+            ))
+            .style(general_style),
+            main_chunks[0],
+        );
+        rect.render_widget(keep_button, chunks[0]);
+        rect.render_widget(discard_button, chunks[1]);
+        rect.render_widget(border_block, popup_area);
+        if app.config.enable_mouse_support {
+            render_close_button(rect, app, is_active)
+        }
+    }
+}
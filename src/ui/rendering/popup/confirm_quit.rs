@@ -0,0 +1,118 @@
+use crate::{
+    app::{state::Focus, App},
+    i18n::{tr, MessageId},
+    ui::{
+        rendering::{
+            common::{render_blank_styled_canvas, render_close_button},
+            popup::ConfirmQuit,
+            utils::{
+                centered_rect_with_length, check_if_active_and_get_style,
+                get_mouse_focusable_field_style,
+            },
+        },
+        Renderable,
+    },
+};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout},
+    widgets::{Block, BorderType, Borders, Paragraph, Wrap},
+    Frame,
+};
+
+impl Renderable for ConfirmQuit {
+    fn render(rect: &mut Frame, app: &mut App, is_active: bool) {
+        let popup_area = centered_rect_with_length(60, 9, rect.area());
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Fill(1), Constraint::Length(3)].as_ref())
+            .margin(2)
+            .split(popup_area);
+        let button_chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(
+                [
+                    Constraint::Fill(1),
+                    Constraint::Fill(1),
+                    Constraint::Fill(1),
+                ]
+                .as_ref(),
+            )
+            .split(chunks[1]);
+
+        let general_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.general_style,
+        );
+
+        let message_paragraph = Paragraph::new("The boards have changes that are not saved yet")
+            .style(general_style)
+            .alignment(Alignment::Center)
+            .wrap(Wrap { trim: true });
+
+        let save_and_quit_button_style = get_mouse_focusable_field_style(
+            app,
+            Focus::SubmitButton,
+            &button_chunks[0],
+            is_active,
+            false,
+        );
+        let quit_without_saving_button_style = get_mouse_focusable_field_style(
+            app,
+            Focus::ExtraFocus,
+            &button_chunks[1],
+            is_active,
+            false,
+        );
+        let cancel_button_style = get_mouse_focusable_field_style(
+            app,
+            Focus::CancelButton,
+            &button_chunks[2],
+            is_active,
+            false,
+        );
+        let save_and_quit_button = Paragraph::new(tr(MessageId::ButtonSaveAndQuit))
+            .style(save_and_quit_button_style)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(save_and_quit_button_style)
+                    .border_type(BorderType::Rounded),
+            )
+            .alignment(Alignment::Center);
+        let quit_without_saving_button = Paragraph::new(tr(MessageId::ButtonQuitWithoutSaving))
+            .style(quit_without_saving_button_style)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(quit_without_saving_button_style)
+                    .border_type(BorderType::Rounded),
+            )
+            .alignment(Alignment::Center);
+        let cancel_button = Paragraph::new(tr(MessageId::ButtonCancel))
+            .style(cancel_button_style)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(cancel_button_style)
+                    .border_type(BorderType::Rounded),
+            )
+            .alignment(Alignment::Center);
+        let border_block = Block::default()
+            .title(tr(MessageId::TitleUnsavedChanges))
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(general_style);
+
+        render_blank_styled_canvas(rect, &app.current_theme, popup_area, is_active);
+        rect.render_widget(message_paragraph, chunks[0]);
+        rect.render_widget(save_and_quit_button, button_chunks[0]);
+        rect.render_widget(quit_without_saving_button, button_chunks[1]);
+        rect.render_widget(cancel_button, button_chunks[2]);
+        rect.render_widget(border_block, popup_area);
+        if app.config.enable_mouse_support {
+            render_close_button(rect, app, is_active)
+        }
+    }
+}
@@ -0,0 +1,26 @@
+use crate::{
+    app::App,
+    i18n::{tr, MessageId},
+    ui::{
+        rendering::{common::render_yes_no_popup, popup::ConfirmRestoreThemesFromBackup},
+        Renderable,
+    },
+};
+use ratatui::Frame;
+
+impl Renderable for ConfirmRestoreThemesFromBackup {
+    fn render(rect: &mut Frame, app: &mut App, is_active: bool) {
+        let summary = app.state.theme_backup_summary.unwrap_or_default();
+        let message = format!(
+            "Saved themes: {}, themes in backup: {}",
+            summary.current_themes, summary.backup_themes
+        );
+        render_yes_no_popup(
+            rect,
+            app,
+            is_active,
+            tr(MessageId::TitleRestoreThemesFromBackup),
+            message,
+        );
+    }
+}
@@ -0,0 +1,130 @@
+use crate::{
+    app::{state::Focus, App},
+    constants::{CONFLICT_MARKERS, LIST_SELECTED_SYMBOL},
+    i18n::{tr, MessageId},
+    ui::{
+        rendering::{
+            common::{render_blank_styled_canvas, render_close_button},
+            popup::ConflictMarkerWarning,
+            utils::{
+                calculate_mouse_list_select_index, centered_rect_with_length,
+                check_if_active_and_get_style, check_if_mouse_is_in_area,
+                get_mouse_focusable_field_style,
+            },
+        },
+        Renderable,
+    },
+};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout},
+    text::Line,
+    widgets::{Block, BorderType, Borders, List, ListItem, Paragraph, Wrap},
+    Frame,
+};
+
+impl Renderable for ConflictMarkerWarning {
+    fn render(rect: &mut Frame, app: &mut App, is_active: bool) {
+        let popup_area = centered_rect_with_length(70, 18, rect.area());
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(
+                [
+                    Constraint::Length(2),
+                    Constraint::Fill(1),
+                    Constraint::Length(1),
+                ]
+                .as_ref(),
+            )
+            .margin(2)
+            .split(popup_area);
+
+        let general_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.general_style,
+        );
+        let list_select_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.list_select_style,
+        );
+        let help_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.help_text_style,
+        );
+
+        let card_items = app
+            .state
+            .cards_with_conflict_markers
+            .iter()
+            .filter_map(|(board_id, card_id)| {
+                let board = app.boards.get_board_with_id(*board_id)?;
+                let card = board.cards.get_card_with_id(*card_id)?;
+                Some(ListItem::new(Line::from(format!(
+                    "{} / {}",
+                    board.name, card.name
+                ))))
+            })
+            .collect::<Vec<ListItem>>();
+
+        let summary_paragraph = Paragraph::new(format!(
+            "These card descriptions still contain {} markers from a merge",
+            CONFLICT_MARKERS.join(" ")
+        ))
+        .style(general_style)
+        .alignment(Alignment::Center)
+        .wrap(Wrap { trim: true });
+
+        if is_active && check_if_mouse_is_in_area(&app.state.current_mouse_coordinates, &chunks[1])
+        {
+            calculate_mouse_list_select_index(
+                app.state.current_mouse_coordinates.1,
+                &card_items,
+                chunks[1],
+                &mut app.state.app_list_states.conflict_marker_cards,
+            );
+        }
+        let list_border_style = get_mouse_focusable_field_style(
+            app,
+            Focus::ConflictMarkerCards,
+            &chunks[1],
+            is_active,
+            false,
+        );
+        let card_list = List::new(card_items)
+            .block(
+                Block::default()
+                    .title(tr(MessageId::TitleAffectedCards))
+                    .style(general_style)
+                    .border_style(list_border_style)
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded),
+            )
+            .highlight_style(list_select_style)
+            .highlight_symbol(LIST_SELECTED_SYMBOL);
+
+        let help_paragraph = Paragraph::new("Enter or click a card to jump to it, Esc to close")
+            .style(help_style)
+            .alignment(Alignment::Center);
+        let border_block = Block::default()
+            .title(tr(MessageId::TitleConflictMarkers))
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(general_style);
+
+        render_blank_styled_canvas(rect, &app.current_theme, popup_area, is_active);
+        rect.render_widget(summary_paragraph, chunks[0]);
+        rect.render_stateful_widget(
+            card_list,
+            chunks[1],
+            &mut app.state.app_list_states.conflict_marker_cards,
+        );
+        rect.render_widget(help_paragraph, chunks[2]);
+        rect.render_widget(border_block, popup_area);
+        if app.config.enable_mouse_support {
+            render_close_button(rect, app, is_active)
+        }
+    }
+}
@@ -0,0 +1,194 @@
+use crate::{
+    app::{
+        state::{AppStatus, Focus, KeyBindingEnum},
+        App, DateTimeFormat,
+    },
+    i18n::{tr, MessageId},
+    model::date_range_filter::{DateRangeFilter, CUSTOM_RANGE_SEPARATOR},
+    ui::{
+        rendering::{
+            common::{render_blank_styled_canvas, render_close_button},
+            popup::CustomDateRangePrompt,
+            utils::{
+                calculate_viewport_corrected_cursor_position, centered_rect_with_length,
+                check_if_active_and_get_style, get_mouse_focusable_field_style,
+            },
+        },
+        Renderable,
+    },
+};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Paragraph},
+    Frame,
+};
+
+impl Renderable for CustomDateRangePrompt {
+    fn render(rect: &mut Frame, app: &mut App, is_active: bool) {
+        let popup_area = centered_rect_with_length(72, 13, rect.area());
+        let date_format = DateTimeFormat::remove_time_from_date_format(app.config.date_time_format);
+        let prompt_text = format!(
+            "Enter the first and last due day as {}{}{}",
+            date_format.to_human_readable_string(),
+            CUSTOM_RANGE_SEPARATOR,
+            date_format.to_human_readable_string()
+        );
+
+        let chunks = if app.config.enable_mouse_support {
+            Layout::default()
+                .direction(Direction::Vertical)
+                .constraints(
+                    [
+                        Constraint::Length(1),
+                        Constraint::Length(3),
+                        Constraint::Length(1),
+                        Constraint::Length(3),
+                        Constraint::Length(3),
+                    ]
+                    .as_ref(),
+                )
+                .margin(1)
+                .split(popup_area)
+        } else {
+            Layout::default()
+                .direction(Direction::Vertical)
+                .constraints(
+                    [
+                        Constraint::Length(1),
+                        Constraint::Length(3),
+                        Constraint::Length(1),
+                        Constraint::Length(3),
+                    ]
+                    .as_ref(),
+                )
+                .margin(1)
+                .split(popup_area)
+        };
+
+        let general_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.general_style,
+        );
+        let help_key_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.help_key_style,
+        );
+        let help_text_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.help_text_style,
+        );
+        let error_text_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.error_text_style,
+        );
+        let text_input_style =
+            get_mouse_focusable_field_style(app, Focus::TextInput, &chunks[1], is_active, true);
+
+        let custom_date_range_input = app.state.text_buffers.custom_date_range.get_joined_lines();
+        // Checked on every render so the status follows the text as it is typed
+        let parsed_range = DateRangeFilter::parse_custom_range(
+            &custom_date_range_input,
+            app.config.date_time_format,
+        );
+        let validation_status = match &parsed_range {
+            _ if custom_date_range_input.trim().is_empty() => Paragraph::new(""),
+            Ok(date_range) => Paragraph::new(format!(
+                "✓ Cards {}",
+                date_range.describe(app.config.date_time_format)
+            ))
+            .style(general_style),
+            Err(err) => Paragraph::new(format!("✗ {}", err)).style(error_text_style),
+        }
+        .alignment(Alignment::Center);
+
+        let prompt_text = Paragraph::new(prompt_text)
+            .style(general_style)
+            .block(Block::default())
+            .alignment(Alignment::Center)
+            .wrap(ratatui::widgets::Wrap { trim: true });
+
+        let text_input = Paragraph::new(custom_date_range_input)
+            .style(general_style)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(text_input_style)
+                    .border_type(BorderType::Rounded),
+            );
+
+        let accept_key = app
+            .get_first_keybinding(KeyBindingEnum::Accept)
+            .unwrap_or("".to_string());
+
+        let help_spans = vec![
+            Span::styled("Press ", help_text_style),
+            Span::styled(accept_key, help_key_style),
+            Span::styled(" to apply the range.", help_text_style),
+        ];
+
+        let border_block = Block::default()
+            .title(tr(MessageId::TitleCustomDueDateRange))
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(general_style);
+
+        let help_text = Paragraph::new(Line::from(help_spans))
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(general_style)
+                    .border_type(BorderType::Rounded),
+            )
+            .alignment(Alignment::Center)
+            .wrap(ratatui::widgets::Wrap { trim: true });
+
+        if app.state.app_status == AppStatus::UserInput {
+            let (x_pos, y_pos) = calculate_viewport_corrected_cursor_position(
+                &app.state.text_buffers.custom_date_range,
+                &app.config.show_line_numbers,
+                &chunks[1],
+            );
+            rect.set_cursor_position((x_pos, y_pos));
+        }
+
+        render_blank_styled_canvas(rect, &app.current_theme, popup_area, is_active);
+
+        if app.config.enable_mouse_support {
+            let submit_button_style = get_mouse_focusable_field_style(
+                app,
+                Focus::SubmitButton,
+                &chunks[3],
+                is_active,
+                false,
+            );
+            let submit_button = Paragraph::new("Submit")
+                .style(if parsed_range.is_ok() {
+                    general_style
+                } else {
+                    app.current_theme.inactive_text_style
+                })
+                .block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .border_style(submit_button_style)
+                        .border_type(BorderType::Rounded),
+                )
+                .alignment(Alignment::Center);
+            rect.render_widget(submit_button, chunks[3]);
+            rect.render_widget(help_text, chunks[4]);
+            render_close_button(rect, app, is_active);
+        } else {
+            rect.render_widget(help_text, chunks[3]);
+        }
+
+        rect.render_widget(prompt_text, chunks[0]);
+        rect.render_widget(text_input, chunks[1]);
+        rect.render_widget(validation_status, chunks[2]);
+        rect.render_widget(border_block, popup_area);
+    }
+}
@@ -19,7 +19,7 @@ use crate::{
 use log::debug;
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout},
-    style::Color,
+    style::{Color, Style},
     text::{Line, Span},
     widgets::{Block, BorderType, Borders, Paragraph},
     Frame,
@@ -28,7 +28,7 @@ use ratatui::{
 impl Renderable for CustomHexColorPrompt {
     fn render(rect: &mut Frame, app: &mut App, is_active: bool) {
         let popup_area = centered_rect_with_length(72, 12, rect.area());
-        let prompt_text = "Enter a custom Hex color in the format: #RRGGBB (e.g. #FF0000)";
+        let prompt_text = "Enter a custom Hex color as #RRGGBB or #RGB (e.g. #FF0000)";
 
         let chunks = if app.config.enable_mouse_support {
             Layout::default()
@@ -76,19 +76,12 @@ impl Renderable for CustomHexColorPrompt {
             }
         };
 
-        let parsed_hex = parse_hex_to_rgb(&custom_hex_color_input);
+        let parsed_hex = parse_hex_to_rgb(custom_hex_color_input.trim());
 
-        let input_field_chunks = if parsed_hex.is_some() {
-            Layout::default()
-                .direction(Direction::Horizontal)
-                .constraints([Constraint::Percentage(100)].as_ref())
-                .split(chunks[1])
-        } else {
-            Layout::default()
-                .direction(Direction::Horizontal)
-                .constraints([Constraint::Fill(1), Constraint::Length(20)].as_ref())
-                .split(chunks[1])
-        };
+        let input_field_chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Fill(1), Constraint::Length(22)].as_ref())
+            .split(chunks[1]);
 
         let general_style = check_if_active_and_get_style(
             is_active,
@@ -205,7 +198,11 @@ impl Renderable for CustomHexColorPrompt {
                 false,
             );
             let submit_button = Paragraph::new("Submit")
-                .style(general_style)
+                .style(if parsed_hex.is_some() {
+                    general_style
+                } else {
+                    app.current_theme.inactive_text_style
+                })
                 .block(
                     Block::default()
                         .borders(Borders::ALL)
@@ -220,8 +217,20 @@ impl Renderable for CustomHexColorPrompt {
             rect.render_widget(help_text, chunks[2]);
         }
 
-        if parsed_hex.is_none() {
-            let invalid_text = Paragraph::new("Invalid Hex Color")
+        // Checked on every render so the status follows the text as it is typed
+        let validation_status = if let Some((r, g, b)) = parsed_hex {
+            Paragraph::new(Line::from(vec![
+                Span::styled("   ", Style::default().bg(Color::Rgb(r, g, b))),
+                Span::styled(format!(" ✓ #{:02x}{:02x}{:02x}", r, g, b), general_style),
+            ]))
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(general_style)
+                    .border_type(BorderType::Rounded),
+            )
+        } else {
+            Paragraph::new("✗ expected #RRGGBB")
                 .style(error_text_style)
                 .block(
                     Block::default()
@@ -229,9 +238,9 @@ impl Renderable for CustomHexColorPrompt {
                         .border_style(error_text_style)
                         .border_type(BorderType::Rounded),
                 )
-                .alignment(Alignment::Center);
-            rect.render_widget(invalid_text, input_field_chunks[1]);
         }
+        .alignment(Alignment::Center);
+        rect.render_widget(validation_status, input_field_chunks[1]);
 
         rect.render_widget(prompt_text, chunks[0]);
         rect.render_widget(text_input, input_field_chunks[0]);
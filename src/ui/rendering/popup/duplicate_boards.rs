@@ -0,0 +1,224 @@
+use crate::{
+    app::{state::Focus, App},
+    constants::LIST_SELECTED_SYMBOL,
+    i18n::{tr, MessageId},
+    model::{
+        date_time_format::DateTimeFormat,
+        duplicate_boards::{board_last_modified, DuplicateBoardPair},
+        kanban::Board,
+    },
+    ui::{
+        rendering::{
+            common::{render_blank_styled_canvas, render_close_button},
+            popup::DuplicateBoards,
+            utils::{
+                calculate_mouse_list_select_index, centered_rect_with_length,
+                check_if_active_and_get_style, check_if_mouse_is_in_area,
+                get_mouse_focusable_field_style,
+            },
+        },
+        Renderable,
+    },
+};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout},
+    text::Line,
+    widgets::{Block, BorderType, Borders, List, ListItem, Paragraph, Wrap},
+    Frame,
+};
+
+fn describe_board(label: &str, board: Option<&Board>, date_time_format: DateTimeFormat) -> String {
+    let Some(board) = board else {
+        return format!("{}: no longer exists", label);
+    };
+    let last_modified = board_last_modified(board)
+        .map(|last_modified| {
+            last_modified
+                .format(
+                    DateTimeFormat::add_time_to_date_format(date_time_format).to_parser_string(),
+                )
+                .to_string()
+        })
+        .unwrap_or_else(|| "never".to_string());
+    format!(
+        "{}: {} card{}, last modified {}",
+        label,
+        board.cards.len(),
+        if board.cards.len() == 1 { "" } else { "s" },
+        last_modified
+    )
+}
+
+impl Renderable for DuplicateBoards {
+    fn render(rect: &mut Frame, app: &mut App, is_active: bool) {
+        let popup_area = centered_rect_with_length(76, 20, rect.area());
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(
+                [
+                    Constraint::Length(2),
+                    Constraint::Fill(1),
+                    Constraint::Length(2),
+                    Constraint::Length(3),
+                    Constraint::Length(1),
+                ]
+                .as_ref(),
+            )
+            .margin(2)
+            .split(popup_area);
+        let button_chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(
+                [
+                    Constraint::Fill(1),
+                    Constraint::Fill(1),
+                    Constraint::Fill(1),
+                ]
+                .as_ref(),
+            )
+            .split(chunks[3]);
+
+        let general_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.general_style,
+        );
+        let list_select_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.list_select_style,
+        );
+        let help_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.help_text_style,
+        );
+
+        let pair_items = app
+            .state
+            .duplicate_board_pairs
+            .iter()
+            .map(|pair| {
+                let board_name = app
+                    .boards
+                    .get_board_with_id(pair.kept_board_id)
+                    .map(|board| board.name.clone())
+                    .unwrap_or_default();
+                ListItem::new(Line::from(format!(
+                    "{} ({} card name{} in common)",
+                    board_name,
+                    pair.shared_card_names,
+                    if pair.shared_card_names == 1 { "" } else { "s" }
+                )))
+            })
+            .collect::<Vec<ListItem>>();
+
+        let summary_paragraph = Paragraph::new(
+            "These boards share a name and most cards, the one modified last is kept",
+        )
+        .style(general_style)
+        .alignment(Alignment::Center)
+        .wrap(Wrap { trim: true });
+
+        if is_active && check_if_mouse_is_in_area(&app.state.current_mouse_coordinates, &chunks[1])
+        {
+            calculate_mouse_list_select_index(
+                app.state.current_mouse_coordinates.1,
+                &pair_items,
+                chunks[1],
+                &mut app.state.app_list_states.duplicate_board_pairs,
+            );
+        }
+        let pair_list = List::new(pair_items)
+            .block(
+                Block::default()
+                    .title(tr(MessageId::TitleDuplicates))
+                    .style(general_style)
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded),
+            )
+            .highlight_style(list_select_style)
+            .highlight_symbol(LIST_SELECTED_SYMBOL);
+
+        let selected_pair = app
+            .state
+            .app_list_states
+            .duplicate_board_pairs
+            .selected()
+            .and_then(|index| app.state.duplicate_board_pairs.get(index))
+            .cloned();
+        let details = if let Some(DuplicateBoardPair {
+            kept_board_id,
+            copy_board_id,
+            ..
+        }) = selected_pair
+        {
+            vec![
+                Line::from(describe_board(
+                    "Keep",
+                    app.boards.get_board_with_id(kept_board_id),
+                    app.config.date_time_format,
+                )),
+                Line::from(describe_board(
+                    "Copy",
+                    app.boards.get_board_with_id(copy_board_id),
+                    app.config.date_time_format,
+                )),
+            ]
+        } else {
+            vec![Line::from("Select a pair to see its boards")]
+        };
+        let details_paragraph = Paragraph::new(details).style(general_style);
+
+        let button_styles = [Focus::SubmitButton, Focus::ExtraFocus, Focus::CancelButton]
+            .iter()
+            .zip(button_chunks.iter())
+            .map(|(focus, chunk)| {
+                get_mouse_focusable_field_style(app, *focus, chunk, is_active, false)
+            })
+            .collect::<Vec<_>>();
+        let buttons = ["Merge", "Keep Both", "Delete Copy"]
+            .iter()
+            .zip(button_styles)
+            .map(|(label, style)| {
+                Paragraph::new(*label)
+                    .style(style)
+                    .block(
+                        Block::default()
+                            .borders(Borders::ALL)
+                            .border_style(style)
+                            .border_type(BorderType::Rounded),
+                    )
+                    .alignment(Alignment::Center)
+            })
+            .collect::<Vec<Paragraph>>();
+
+        let help_paragraph =
+            Paragraph::new("Up and Down pick a pair, Left and Right pick an action, Esc to close")
+                .style(help_style)
+                .alignment(Alignment::Center);
+        let border_block = Block::default()
+            .title(tr(MessageId::TitleDuplicateBoards))
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(general_style);
+
+        render_blank_styled_canvas(rect, &app.current_theme, popup_area, is_active);
+        rect.render_widget(summary_paragraph, chunks[0]);
+        rect.render_stateful_widget(
+            pair_list,
+            chunks[1],
+            &mut app.state.app_list_states.duplicate_board_pairs,
+        );
+        rect.render_widget(details_paragraph, chunks[2]);
+        for (button, chunk) in buttons.into_iter().zip(button_chunks.iter()) {
+            rect.render_widget(button, *chunk);
+        }
+        rect.render_widget(help_paragraph, chunks[4]);
+        rect.render_widget(border_block, popup_area);
+        if app.config.enable_mouse_support {
+            render_close_button(rect, app, is_active)
+        }
+    }
+}
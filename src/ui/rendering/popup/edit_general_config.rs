@@ -106,7 +106,10 @@ impl Renderable for EditGeneralConfig {
             "Theme Name"
         };
         if let Ok(config_enum) = ConfigEnum::from_str(config_item_name) {
-            app.state.path_check_state.path_check_mode = config_enum == ConfigEnum::SaveDirectory;
+            app.state.path_check_state.path_check_mode = matches!(
+                config_enum,
+                ConfigEnum::SaveDirectory | ConfigEnum::MirrorDirectory
+            );
         }
         let config_item_value = if app.state.app_table_states.config.selected().is_some() {
             list_items
@@ -164,84 +167,7 @@ impl Renderable for EditGeneralConfig {
             .wrap(ratatui::widgets::Wrap { trim: true });
         let current_user_input = app.state.text_buffers.general_config.get_joined_lines();
         let user_input = if app.state.path_check_state.path_check_mode {
-            if (current_user_input != app.state.path_check_state.path_last_checked)
-                || app.state.path_check_state.recheck_required
-            {
-                app.state.path_check_state.recheck_required = false;
-                app.state.path_check_state.potential_completion = None;
-                app.state
-                    .path_check_state
-                    .path_last_checked
-                    .clone_from(&current_user_input);
-                app.state.path_check_state.path_exists =
-                    std::path::Path::new(&current_user_input).is_dir();
-                if !app.state.path_check_state.path_exists {
-                    let mut split_input = current_user_input
-                        .split(std::path::MAIN_SEPARATOR)
-                        .collect::<Vec<&str>>();
-                    // remove any empty strings
-                    split_input.retain(|&x| !x.is_empty());
-                    if !split_input.is_empty() {
-                        let last_input = split_input.pop().unwrap();
-                        if split_input.is_empty() {
-                            let to_check = last_input;
-                            let dir = std::fs::read_dir(std::path::MAIN_SEPARATOR.to_string());
-                            if dir.is_ok() {
-                                let dir = dir.unwrap();
-                                // only retain the ones that are directories
-                                let dir = dir.flatten();
-                                for entry in dir {
-                                    let path = entry.path();
-                                    if path.to_str().unwrap().starts_with(
-                                        &(std::path::MAIN_SEPARATOR.to_string() + to_check),
-                                    ) && path.is_dir()
-                                    {
-                                        app.state.path_check_state.potential_completion = Some(
-                                            path.to_str()
-                                                .unwrap()
-                                                .to_string()
-                                                .strip_prefix(
-                                                    &(std::path::MAIN_SEPARATOR.to_string()
-                                                        + to_check),
-                                                )
-                                                .unwrap()
-                                                .to_string(),
-                                        );
-                                        break;
-                                    }
-                                }
-                            }
-                        } else {
-                            let to_check = std::path::MAIN_SEPARATOR.to_string()
-                                + &split_input.join(std::path::MAIN_SEPARATOR_STR);
-                            if std::path::Path::new(&to_check).is_dir() {
-                                let dir = std::fs::read_dir(&to_check);
-                                let dir = dir.unwrap();
-                                // only retain the ones that are directories
-                                let dir = dir.flatten();
-                                for entry in dir {
-                                    let path = entry.path();
-                                    if path
-                                        .to_str()
-                                        .unwrap()
-                                        .starts_with(current_user_input.as_str())
-                                        && path.is_dir()
-                                    {
-                                        app.state.path_check_state.potential_completion = Some(
-                                            path.to_str()
-                                                .unwrap()
-                                                .strip_prefix(current_user_input.as_str())
-                                                .unwrap()
-                                                .to_string(),
-                                        );
-                                        break;
-                                    }
-                                }
-                            }
-                        };
-                    }
-                }
-            }
+            app.state.path_check_state.check(&current_user_input, false);
             if !current_user_input.is_empty() {
                 if let Some(potential_completion) = &app.state.path_check_state.potential_completion
                 {
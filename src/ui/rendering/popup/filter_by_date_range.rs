@@ -0,0 +1,92 @@
+use crate::{
+    app::{state::Focus, App},
+    constants::LIST_SELECTED_SYMBOL,
+    i18n::{tr, MessageId},
+    model::date_range_filter::DateRangeFilter,
+    ui::{
+        rendering::{
+            common::{render_blank_styled_canvas, render_close_button},
+            popup::FilterByDateRange,
+            utils::{
+                calculate_mouse_list_select_index, centered_rect_with_percentage,
+                check_if_active_and_get_style, check_if_mouse_is_in_area,
+            },
+        },
+        Renderable,
+    },
+};
+use ratatui::{
+    text::Line,
+    widgets::{Block, BorderType, Borders, List, ListItem},
+    Frame,
+};
+
+impl Renderable for FilterByDateRange {
+    fn render(rect: &mut Frame, app: &mut App, is_active: bool) {
+        let general_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.general_style,
+        );
+        let list_select_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.list_select_style,
+        );
+        let active_date_filter = app.state.active_date_filter;
+        let marker = |is_active_filter: bool| if is_active_filter { "(x)" } else { "( )" };
+        let mut all_ranges = DateRangeFilter::presets()
+            .iter()
+            .map(|preset| {
+                ListItem::new(vec![Line::from(format!(
+                    "{} {}",
+                    marker(active_date_filter == Some(*preset)),
+                    preset.name()
+                ))])
+            })
+            .collect::<Vec<ListItem>>();
+        let custom_range_label = match active_date_filter {
+            Some(custom_range @ DateRangeFilter::Custom(..)) => format!(
+                "{} {}, {}",
+                marker(true),
+                custom_range.name(),
+                custom_range.describe(app.config.date_time_format)
+            ),
+            _ => "( ) Custom range...".to_string(),
+        };
+        all_ranges.push(ListItem::new(vec![Line::from(custom_range_label)]));
+        let percent_height =
+            (((all_ranges.len() + 3) as f32 / rect.area().height as f32) * 100.0) as u16;
+        let popup_area = centered_rect_with_percentage(50, percent_height, rect.area());
+        if check_if_mouse_is_in_area(&app.state.current_mouse_coordinates, &popup_area) {
+            app.state.mouse_focus = Some(Focus::FilterByDateRangePopup);
+            app.state.set_focus(Focus::FilterByDateRangePopup);
+            calculate_mouse_list_select_index(
+                app.state.current_mouse_coordinates.1,
+                &all_ranges,
+                popup_area,
+                &mut app.state.app_list_states.filter_by_date_range_list,
+            );
+        }
+        let ranges = List::new(all_ranges)
+            .block(
+                Block::default()
+                    .title(tr(MessageId::TitleFilterByDueDate))
+                    .style(general_style)
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded),
+            )
+            .highlight_style(list_select_style)
+            .highlight_symbol(LIST_SELECTED_SYMBOL);
+
+        render_blank_styled_canvas(rect, &app.current_theme, popup_area, is_active);
+        rect.render_stateful_widget(
+            ranges,
+            popup_area,
+            &mut app.state.app_list_states.filter_by_date_range_list,
+        );
+        if app.config.enable_mouse_support {
+            render_close_button(rect, app, is_active);
+        }
+    }
+}
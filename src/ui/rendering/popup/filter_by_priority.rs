@@ -0,0 +1,81 @@
+use crate::{
+    app::{kanban::CardPriority, state::Focus, App},
+    constants::LIST_SELECTED_SYMBOL,
+    i18n::{tr, MessageId},
+    ui::{
+        rendering::{
+            common::{render_blank_styled_canvas, render_close_button},
+            popup::FilterByPriority,
+            utils::{
+                calculate_mouse_list_select_index, centered_rect_with_percentage,
+                check_if_active_and_get_style, check_if_mouse_is_in_area,
+            },
+        },
+        Renderable,
+    },
+};
+use ratatui::{
+    text::Line,
+    widgets::{Block, BorderType, Borders, List, ListItem},
+    Frame,
+};
+
+impl Renderable for FilterByPriority {
+    fn render(rect: &mut Frame, app: &mut App, is_active: bool) {
+        let general_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.general_style,
+        );
+        let list_select_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.list_select_style,
+        );
+        let selected_priorities = app.state.active_priority_filter.clone().unwrap_or_default();
+        let all_priorities = CardPriority::all()
+            .iter()
+            .map(|priority| {
+                let marker = if selected_priorities.contains(priority) {
+                    "[x]"
+                } else {
+                    "[ ]"
+                };
+                ListItem::new(vec![Line::from(format!("{} {}", marker, priority))])
+            })
+            .collect::<Vec<ListItem>>();
+        let percent_height =
+            (((all_priorities.len() + 3) as f32 / rect.area().height as f32) * 100.0) as u16;
+        let popup_area = centered_rect_with_percentage(50, percent_height, rect.area());
+        if check_if_mouse_is_in_area(&app.state.current_mouse_coordinates, &popup_area) {
+            app.state.mouse_focus = Some(Focus::FilterByPriorityPopup);
+            app.state.set_focus(Focus::FilterByPriorityPopup);
+            calculate_mouse_list_select_index(
+                app.state.current_mouse_coordinates.1,
+                &all_priorities,
+                popup_area,
+                &mut app.state.app_list_states.filter_by_priority_list,
+            );
+        }
+        let priorities = List::new(all_priorities)
+            .block(
+                Block::default()
+                    .title(tr(MessageId::TitleFilterByPriority))
+                    .style(general_style)
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded),
+            )
+            .highlight_style(list_select_style)
+            .highlight_symbol(LIST_SELECTED_SYMBOL);
+
+        render_blank_styled_canvas(rect, &app.current_theme, popup_area, is_active);
+        rect.render_stateful_widget(
+            priorities,
+            popup_area,
+            &mut app.state.app_list_states.filter_by_priority_list,
+        );
+        if app.config.enable_mouse_support {
+            render_close_button(rect, app, is_active);
+        }
+    }
+}
@@ -0,0 +1,81 @@
+use crate::{
+    app::{kanban::CardStatus, state::Focus, App},
+    constants::LIST_SELECTED_SYMBOL,
+    i18n::{tr, MessageId},
+    ui::{
+        rendering::{
+            common::{render_blank_styled_canvas, render_close_button},
+            popup::FilterByStatus,
+            utils::{
+                calculate_mouse_list_select_index, centered_rect_with_percentage,
+                check_if_active_and_get_style, check_if_mouse_is_in_area,
+            },
+        },
+        Renderable,
+    },
+};
+use ratatui::{
+    text::Line,
+    widgets::{Block, BorderType, Borders, List, ListItem},
+    Frame,
+};
+
+impl Renderable for FilterByStatus {
+    fn render(rect: &mut Frame, app: &mut App, is_active: bool) {
+        let general_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.general_style,
+        );
+        let list_select_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.list_select_style,
+        );
+        let selected_statuses = app.state.active_status_filter.clone().unwrap_or_default();
+        let all_statuses = CardStatus::all()
+            .iter()
+            .map(|status| {
+                let marker = if selected_statuses.contains(status) {
+                    "[x]"
+                } else {
+                    "[ ]"
+                };
+                ListItem::new(vec![Line::from(format!("{} {}", marker, status))])
+            })
+            .collect::<Vec<ListItem>>();
+        let percent_height =
+            (((all_statuses.len() + 3) as f32 / rect.area().height as f32) * 100.0) as u16;
+        let popup_area = centered_rect_with_percentage(50, percent_height, rect.area());
+        if check_if_mouse_is_in_area(&app.state.current_mouse_coordinates, &popup_area) {
+            app.state.mouse_focus = Some(Focus::FilterByStatusPopup);
+            app.state.set_focus(Focus::FilterByStatusPopup);
+            calculate_mouse_list_select_index(
+                app.state.current_mouse_coordinates.1,
+                &all_statuses,
+                popup_area,
+                &mut app.state.app_list_states.filter_by_status_list,
+            );
+        }
+        let statuses = List::new(all_statuses)
+            .block(
+                Block::default()
+                    .title(tr(MessageId::TitleFilterByStatus))
+                    .style(general_style)
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded),
+            )
+            .highlight_style(list_select_style)
+            .highlight_symbol(LIST_SELECTED_SYMBOL);
+
+        render_blank_styled_canvas(rect, &app.current_theme, popup_area, is_active);
+        rect.render_stateful_widget(
+            statuses,
+            popup_area,
+            &mut app.state.app_list_states.filter_by_status_list,
+        );
+        if app.config.enable_mouse_support {
+            render_close_button(rect, app, is_active);
+        }
+    }
+}
@@ -6,6 +6,7 @@ use crate::{
     constants::{
         LIST_SELECTED_SYMBOL, SCROLLBAR_BEGIN_SYMBOL, SCROLLBAR_END_SYMBOL, SCROLLBAR_TRACK_SYMBOL,
     },
+    i18n::{tr, MessageId},
     ui::{
         rendering::{
             common::{render_blank_styled_canvas, render_close_button},
@@ -158,7 +159,7 @@ impl Renderable for FilterByTag {
                 .alignment(Alignment::Left)
                 .block(
                     Block::default()
-                        .title("Help")
+                        .title(tr(MessageId::TitleHelp))
                         .borders(Borders::ALL)
                         .style(general_style)
                         .border_type(BorderType::Rounded),
@@ -0,0 +1,229 @@
+use crate::{
+    app::{
+        state::{AppStatus, Focus, KeyBindingEnum},
+        App,
+    },
+    i18n::{tr, MessageId},
+    ui::{
+        rendering::{
+            common::{render_blank_styled_canvas, render_close_button},
+            popup::FindReplace,
+            utils::{
+                calculate_viewport_corrected_cursor_position, centered_rect_with_length,
+                check_if_active_and_get_style, get_mouse_focusable_field_style,
+            },
+        },
+        Renderable,
+    },
+};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Paragraph},
+    Frame,
+};
+
+impl Renderable for FindReplace {
+    fn render(rect: &mut Frame, app: &mut App, is_active: bool) {
+        let popup_area = centered_rect_with_length(60, 15, rect.area());
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(
+                [
+                    Constraint::Length(3),
+                    Constraint::Length(3),
+                    Constraint::Length(1),
+                    Constraint::Length(3),
+                    Constraint::Length(3),
+                ]
+                .as_ref(),
+            )
+            .margin(1)
+            .split(popup_area);
+        let button_chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Fill(1), Constraint::Fill(1)].as_ref())
+            .split(chunks[3]);
+
+        let general_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.general_style,
+        );
+        let help_key_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.help_key_style,
+        );
+        let help_text_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.help_text_style,
+        );
+        let error_text_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.error_text_style,
+        );
+        let query_style = get_mouse_focusable_field_style(
+            app,
+            Focus::FindReplaceQuery,
+            &chunks[0],
+            is_active,
+            true,
+        );
+        let replacement_style = get_mouse_focusable_field_style(
+            app,
+            Focus::FindReplaceReplacement,
+            &chunks[1],
+            is_active,
+            true,
+        );
+        let accept_button_style = get_mouse_focusable_field_style(
+            app,
+            Focus::SubmitButton,
+            &button_chunks[0],
+            is_active,
+            false,
+        );
+        let cancel_button_style = get_mouse_focusable_field_style(
+            app,
+            Focus::CancelButton,
+            &button_chunks[1],
+            is_active,
+            false,
+        );
+
+        let query = app.state.text_buffers.find_replace_query.get_joined_lines();
+        let replacement = app
+            .state
+            .text_buffers
+            .find_replace_replacement
+            .get_joined_lines();
+        let match_count = app
+            .state
+            .text_buffers
+            .card_description
+            .find_matches(&query)
+            .len();
+        let status_text = if query.is_empty() {
+            Paragraph::new("Type the text to find in the description").style(general_style)
+        } else if match_count == 0 {
+            Paragraph::new("No matches").style(error_text_style)
+        } else {
+            Paragraph::new(format!(
+                "{} match{} in the description",
+                match_count,
+                if match_count == 1 { "" } else { "es" }
+            ))
+            .style(general_style)
+        }
+        .alignment(Alignment::Center);
+
+        let text_input = |text: String, title: &'static str, border_style| {
+            let line = if text.is_empty() {
+                Line::from(Span::styled(
+                    "No input",
+                    app.current_theme.inactive_text_style,
+                ))
+            } else {
+                Line::from(Span::styled(text, general_style))
+            };
+            Paragraph::new(line).block(
+                Block::default()
+                    .title(title)
+                    .borders(Borders::ALL)
+                    .border_style(border_style)
+                    .border_type(BorderType::Rounded),
+            )
+        };
+        let query_input = text_input(query, "Find", query_style);
+        let replacement_input = text_input(replacement, "Replace with", replacement_style);
+
+        let accept_button = Paragraph::new("Replace All")
+            .style(accept_button_style)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(accept_button_style)
+                    .border_type(BorderType::Rounded),
+            )
+            .alignment(Alignment::Center);
+        let cancel_button = Paragraph::new("Cancel")
+            .style(cancel_button_style)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(cancel_button_style)
+                    .border_type(BorderType::Rounded),
+            )
+            .alignment(Alignment::Center);
+
+        let next_focus_key = app
+            .get_first_keybinding(KeyBindingEnum::NextFocus)
+            .unwrap_or("".to_string());
+        let accept_key = app
+            .get_first_keybinding(KeyBindingEnum::Accept)
+            .unwrap_or("".to_string());
+        let cancel_key = app
+            .get_first_keybinding(KeyBindingEnum::GoToPreviousViewOrCancel)
+            .unwrap_or("".to_string());
+
+        let help_text = Paragraph::new(Line::from(vec![
+            Span::styled("Press ", help_text_style),
+            Span::styled(next_focus_key, help_key_style),
+            Span::styled(" to switch fields, ", help_text_style),
+            Span::styled(accept_key, help_key_style),
+            Span::styled(" to replace all or ", help_text_style),
+            Span::styled(cancel_key, help_key_style),
+            Span::styled(" to cancel.", help_text_style),
+        ]))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(general_style)
+                .border_type(BorderType::Rounded),
+        )
+        .alignment(Alignment::Center)
+        .wrap(ratatui::widgets::Wrap { trim: true });
+
+        let border_block = Block::default()
+            .title(tr(MessageId::TitleFindAndReplace))
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(general_style);
+
+        if app.state.app_status == AppStatus::UserInput {
+            let focused_input = match app.state.focus {
+                Focus::FindReplaceQuery => {
+                    Some((&app.state.text_buffers.find_replace_query, &chunks[0]))
+                }
+                Focus::FindReplaceReplacement => {
+                    Some((&app.state.text_buffers.find_replace_replacement, &chunks[1]))
+                }
+                _ => None,
+            };
+            if let Some((text_box, area)) = focused_input {
+                let (x_pos, y_pos) = calculate_viewport_corrected_cursor_position(
+                    text_box,
+                    &app.config.show_line_numbers,
+                    area,
+                );
+                rect.set_cursor_position((x_pos, y_pos));
+            }
+        }
+
+        render_blank_styled_canvas(rect, &app.current_theme, popup_area, is_active);
+        rect.render_widget(query_input, chunks[0]);
+        rect.render_widget(replacement_input, chunks[1]);
+        rect.render_widget(status_text, chunks[2]);
+        rect.render_widget(accept_button, button_chunks[0]);
+        rect.render_widget(cancel_button, button_chunks[1]);
+        rect.render_widget(help_text, chunks[4]);
+        rect.render_widget(border_block, popup_area);
+        if app.config.enable_mouse_support {
+            render_close_button(rect, app, is_active);
+        }
+    }
+}
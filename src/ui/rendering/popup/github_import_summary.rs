@@ -0,0 +1,146 @@
+use crate::{
+    app::App,
+    constants::{SCROLLBAR_BEGIN_SYMBOL, SCROLLBAR_END_SYMBOL, SCROLLBAR_TRACK_SYMBOL},
+    i18n::{tr, MessageId},
+    ui::{
+        rendering::{
+            common::{render_blank_styled_canvas, render_close_button},
+            popup::GithubImportSummary,
+            utils::{centered_rect_with_length, check_if_active_and_get_style},
+        },
+        Renderable,
+    },
+};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Margin},
+    text::{Line, Span},
+    widgets::{
+        Block, BorderType, Borders, Paragraph, Scrollbar, ScrollbarOrientation, ScrollbarState,
+        Wrap,
+    },
+    Frame,
+};
+
+impl Renderable for GithubImportSummary {
+    fn render(rect: &mut Frame, app: &mut App, is_active: bool) {
+        let popup_area = centered_rect_with_length(80, 20, rect.area());
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(
+                [
+                    Constraint::Length(3),
+                    Constraint::Fill(1),
+                    Constraint::Length(1),
+                ]
+                .as_ref(),
+            )
+            .margin(1)
+            .split(popup_area);
+
+        let general_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.general_style,
+        );
+        let issue_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.help_key_style,
+        );
+        let error_text_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.error_text_style,
+        );
+        let scrollbar_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.progress_bar_style,
+        );
+
+        let (summary, failure_lines) = match &app.state.github_import_report {
+            Some(report) => {
+                let failure_lines = report
+                    .failures
+                    .iter()
+                    .map(|failure| {
+                        Line::from(vec![
+                            Span::styled(format!("{}: ", failure.issue), issue_style),
+                            Span::styled(failure.reason.clone(), general_style),
+                        ])
+                    })
+                    .collect::<Vec<Line>>();
+                (report.summary.clone(), failure_lines)
+            }
+            None => (String::new(), Vec::new()),
+        };
+        let failure_count = failure_lines.len();
+
+        // Reasons are short enough that wrapping rarely kicks in, the line count is a close
+        // enough bound for the scroll range
+        let max_scroll = (failure_count as u16).saturating_sub(chunks[1].height.saturating_sub(2));
+        app.state.github_import_report_scroll =
+            app.state.github_import_report_scroll.min(max_scroll);
+
+        let summary_paragraph = Paragraph::new(summary)
+            .style(general_style)
+            .alignment(Alignment::Center)
+            .wrap(Wrap { trim: true })
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+                    .border_style(general_style),
+            );
+        let failures_paragraph = Paragraph::new(failure_lines)
+            .block(
+                Block::default()
+                    .title(Span::styled(
+                        format!(
+                            "{} issue{} not imported",
+                            failure_count,
+                            if failure_count == 1 { "" } else { "s" }
+                        ),
+                        error_text_style,
+                    ))
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+                    .border_style(general_style),
+            )
+            .wrap(Wrap { trim: false })
+            .scroll((app.state.github_import_report_scroll, 0));
+        let help_paragraph = Paragraph::new("Up/Down to scroll, Esc to close")
+            .alignment(Alignment::Center)
+            .style(general_style);
+        let border_block = Block::default()
+            .title(tr(MessageId::TitleGithubImport))
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(general_style);
+
+        render_blank_styled_canvas(rect, &app.current_theme, popup_area, is_active);
+        rect.render_widget(summary_paragraph, chunks[0]);
+        rect.render_widget(failures_paragraph, chunks[1]);
+        if max_scroll > 0 {
+            let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight)
+                .begin_symbol(SCROLLBAR_BEGIN_SYMBOL)
+                .style(scrollbar_style)
+                .end_symbol(SCROLLBAR_END_SYMBOL)
+                .track_symbol(SCROLLBAR_TRACK_SYMBOL)
+                .track_style(app.current_theme.inactive_text_style);
+            let mut scrollbar_state = ScrollbarState::new(max_scroll as usize + 1)
+                .position(app.state.github_import_report_scroll as usize);
+            let scrollbar_area = chunks[1].inner(Margin {
+                vertical: 1,
+                horizontal: 0,
+            });
+            rect.render_stateful_widget(scrollbar, scrollbar_area, &mut scrollbar_state);
+        }
+        rect.render_widget(help_paragraph, chunks[2]);
+        rect.render_widget(border_block, popup_area);
+        if app.config.enable_mouse_support {
+            render_close_button(rect, app, is_active)
+        }
+    }
+}
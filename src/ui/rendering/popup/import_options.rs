@@ -0,0 +1,117 @@
+use crate::{
+    app::{state::Focus, App},
+    i18n::{tr, MessageId},
+    ui::{
+        rendering::{
+            common::{render_blank_styled_canvas, render_close_button},
+            popup::ImportOptions,
+            utils::{
+                centered_rect_with_length, check_if_active_and_get_style,
+                get_mouse_focusable_field_style,
+            },
+        },
+        Renderable,
+    },
+};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout},
+    widgets::{Block, BorderType, Borders, Paragraph, Wrap},
+    Frame,
+};
+
+impl Renderable for ImportOptions {
+    fn render(rect: &mut Frame, app: &mut App, is_active: bool) {
+        let popup_area = centered_rect_with_length(60, 11, rect.area());
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Fill(1), Constraint::Length(3)].as_ref())
+            .margin(2)
+            .split(popup_area);
+        let button_chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Fill(1), Constraint::Fill(1)].as_ref())
+            .split(chunks[1]);
+
+        let general_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.general_style,
+        );
+
+        let message = if let Some(pending_import) = &app.state.pending_json_import {
+            let board_count = pending_import.boards.len();
+            let card_count = pending_import
+                .boards
+                .iter()
+                .map(|board| board.cards.len())
+                .sum::<usize>();
+            let file_name = pending_import
+                .path
+                .file_name()
+                .map(|file_name| file_name.to_string_lossy().to_string())
+                .unwrap_or_default();
+            format!(
+                "{} has {} board{} with {} card{}. Add them next to the current boards or replace all boards with them?",
+                file_name,
+                board_count,
+                if board_count == 1 { "" } else { "s" },
+                card_count,
+                if card_count == 1 { "" } else { "s" }
+            )
+        } else {
+            String::new()
+        };
+        let message_paragraph = Paragraph::new(message)
+            .style(general_style)
+            .alignment(Alignment::Center)
+            .wrap(Wrap { trim: true });
+
+        let merge_button_style = get_mouse_focusable_field_style(
+            app,
+            Focus::SubmitButton,
+            &button_chunks[0],
+            is_active,
+            false,
+        );
+        let replace_button_style = get_mouse_focusable_field_style(
+            app,
+            Focus::ExtraFocus,
+            &button_chunks[1],
+            is_active,
+            false,
+        );
+        let merge_button = Paragraph::new("Merge")
+            .style(merge_button_style)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(merge_button_style)
+                    .border_type(BorderType::Rounded),
+            )
+            .alignment(Alignment::Center);
+        let replace_button = Paragraph::new("Replace All")
+            .style(replace_button_style)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(replace_button_style)
+                    .border_type(BorderType::Rounded),
+            )
+            .alignment(Alignment::Center);
+        let border_block = Block::default()
+            .title(tr(MessageId::TitleImportOptions))
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(general_style);
+
+        render_blank_styled_canvas(rect, &app.current_theme, popup_area, is_active);
+        rect.render_widget(message_paragraph, chunks[0]);
+        rect.render_widget(merge_button, button_chunks[0]);
+        rect.render_widget(replace_button, button_chunks[1]);
+        rect.render_widget(border_block, popup_area);
+        if app.config.enable_mouse_support {
+            render_close_button(rect, app, is_active)
+        }
+    }
+}
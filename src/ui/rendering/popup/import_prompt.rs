@@ -0,0 +1,254 @@
+use crate::{
+    app::{
+        state::{AppStatus, Focus, KeyBindingEnum},
+        App,
+    },
+    ui::{
+        rendering::{
+            common::{render_blank_styled_canvas, render_close_button},
+            popup::{
+                ImportBoardJsonPrompt, ImportGithubPrompt, ImportJsonPrompt, ImportTrelloPrompt,
+            },
+            utils::{
+                calculate_viewport_corrected_cursor_position, centered_rect_with_length,
+                check_if_active_and_get_style, get_mouse_focusable_field_style,
+            },
+        },
+        Renderable,
+    },
+};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Paragraph},
+    Frame,
+};
+
+impl Renderable for ImportTrelloPrompt {
+    fn render(rect: &mut Frame, app: &mut App, is_active: bool) {
+        render_import_prompt(
+            rect,
+            app,
+            is_active,
+            "Import from Trello",
+            "Enter the path of a Trello board export (.json)",
+            true,
+        );
+    }
+}
+
+impl Renderable for ImportJsonPrompt {
+    fn render(rect: &mut Frame, app: &mut App, is_active: bool) {
+        render_import_prompt(
+            rect,
+            app,
+            is_active,
+            "Import from JSON",
+            "Enter the path of a save file (.json)",
+            true,
+        );
+    }
+}
+
+impl Renderable for ImportBoardJsonPrompt {
+    fn render(rect: &mut Frame, app: &mut App, is_active: bool) {
+        render_import_prompt(
+            rect,
+            app,
+            is_active,
+            "Import Board from JSON",
+            "Enter the path of an exported board (.json)",
+            true,
+        );
+    }
+}
+
+impl Renderable for ImportGithubPrompt {
+    fn render(rect: &mut Frame, app: &mut App, is_active: bool) {
+        render_import_prompt(
+            rect,
+            app,
+            is_active,
+            "Import from GitHub Issues",
+            "Enter a repository as owner/name, optionally followed by the board for new cards",
+            false,
+        );
+    }
+}
+
+fn render_import_prompt(
+    rect: &mut Frame,
+    app: &mut App,
+    is_active: bool,
+    title: &str,
+    prompt_text: &str,
+    is_path: bool,
+) {
+    let popup_area = centered_rect_with_length(72, 12, rect.area());
+
+    let chunks = if app.config.enable_mouse_support {
+        Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(
+                [
+                    Constraint::Length(1),
+                    Constraint::Length(3),
+                    Constraint::Length(3),
+                    Constraint::Length(3),
+                ]
+                .as_ref(),
+            )
+            .margin(1)
+            .split(popup_area)
+    } else {
+        Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(
+                [
+                    Constraint::Length(1),
+                    Constraint::Length(3),
+                    Constraint::Length(3),
+                ]
+                .as_ref(),
+            )
+            .margin(1)
+            .split(popup_area)
+    };
+
+    let general_style = check_if_active_and_get_style(
+        is_active,
+        app.current_theme.inactive_text_style,
+        app.current_theme.general_style,
+    );
+    let help_key_style = check_if_active_and_get_style(
+        is_active,
+        app.current_theme.inactive_text_style,
+        app.current_theme.help_key_style,
+    );
+    let help_text_style = check_if_active_and_get_style(
+        is_active,
+        app.current_theme.inactive_text_style,
+        app.current_theme.help_text_style,
+    );
+    let error_text_style = check_if_active_and_get_style(
+        is_active,
+        app.current_theme.inactive_text_style,
+        app.current_theme.error_text_style,
+    );
+    let card_status_active_style = check_if_active_and_get_style(
+        is_active,
+        app.current_theme.inactive_text_style,
+        app.current_theme.card_status_active_style,
+    );
+    let text_input_style =
+        get_mouse_focusable_field_style(app, Focus::TextInput, &chunks[1], is_active, true);
+
+    let current_user_input = app.state.text_buffers.import_path.get_joined_lines();
+    if is_path {
+        app.state.path_check_state.check(&current_user_input, true);
+    }
+    let user_input = if current_user_input.is_empty() {
+        Line::from(Span::styled(
+            "No input",
+            app.current_theme.inactive_text_style,
+        ))
+    } else if !is_path {
+        Line::from(Span::styled(current_user_input, general_style))
+    } else if let Some(potential_completion) = &app.state.path_check_state.potential_completion {
+        Line::from(vec![
+            Span::styled(current_user_input, general_style),
+            Span::styled(
+                potential_completion.clone(),
+                app.current_theme.inactive_text_style,
+            ),
+        ])
+    } else if app.state.path_check_state.path_exists {
+        Line::from(Span::styled(current_user_input, card_status_active_style))
+    } else {
+        Line::from(Span::styled(current_user_input, error_text_style))
+    };
+
+    let prompt_text = Paragraph::new(prompt_text)
+        .style(general_style)
+        .block(Block::default())
+        .alignment(Alignment::Center)
+        .wrap(ratatui::widgets::Wrap { trim: true });
+
+    let text_input = Paragraph::new(user_input).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(text_input_style)
+            .border_type(BorderType::Rounded),
+    );
+
+    let accept_key = app
+        .get_first_keybinding(KeyBindingEnum::Accept)
+        .unwrap_or("".to_string());
+    let cancel_key = app
+        .get_first_keybinding(KeyBindingEnum::GoToPreviousViewOrCancel)
+        .unwrap_or("".to_string());
+
+    let mut help_spans = vec![Span::styled("Press ", help_text_style)];
+    if is_path {
+        help_spans.extend([
+            Span::styled("Tab", help_key_style),
+            Span::styled(" to autocomplete, ", help_text_style),
+        ]);
+    }
+    help_spans.extend([
+        Span::styled(accept_key, help_key_style),
+        Span::styled(" to import or ", help_text_style),
+        Span::styled(cancel_key, help_key_style),
+        Span::styled(" to cancel.", help_text_style),
+    ]);
+
+    let border_block = Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(general_style);
+
+    let help_text = Paragraph::new(Line::from(help_spans))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(general_style)
+                .border_type(BorderType::Rounded),
+        )
+        .alignment(Alignment::Center)
+        .wrap(ratatui::widgets::Wrap { trim: true });
+
+    if app.state.app_status == AppStatus::UserInput {
+        let (x_pos, y_pos) = calculate_viewport_corrected_cursor_position(
+            &app.state.text_buffers.import_path,
+            &app.config.show_line_numbers,
+            &chunks[1],
+        );
+        rect.set_cursor_position((x_pos, y_pos));
+    }
+
+    render_blank_styled_canvas(rect, &app.current_theme, popup_area, is_active);
+
+    if app.config.enable_mouse_support {
+        let submit_button_style =
+            get_mouse_focusable_field_style(app, Focus::SubmitButton, &chunks[2], is_active, false);
+        let submit_button = Paragraph::new("Import")
+            .style(general_style)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(submit_button_style)
+                    .border_type(BorderType::Rounded),
+            )
+            .alignment(Alignment::Center);
+        rect.render_widget(submit_button, chunks[2]);
+        rect.render_widget(help_text, chunks[3]);
+        render_close_button(rect, app, is_active);
+    } else {
+        rect.render_widget(help_text, chunks[2]);
+    }
+
+    rect.render_widget(prompt_text, chunks[0]);
+    rect.render_widget(text_input, chunks[1]);
+    rect.render_widget(border_block, popup_area);
+}
@@ -0,0 +1,192 @@
+use crate::{
+    app::{state::Focus, App},
+    constants::LIST_SELECTED_SYMBOL,
+    i18n::{tr, MessageId},
+    ui::{
+        rendering::{
+            common::{render_blank_styled_canvas, render_close_button},
+            popup::MirrorSyncSummary,
+            utils::{
+                calculate_mouse_list_select_index, centered_rect_with_length,
+                check_if_active_and_get_style, check_if_mouse_is_in_area,
+                get_mouse_focusable_field_style,
+            },
+        },
+        Renderable,
+    },
+};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout},
+    text::Line,
+    widgets::{Block, BorderType, Borders, List, ListItem, Paragraph, Wrap},
+    Frame,
+};
+
+impl Renderable for MirrorSyncSummary {
+    fn render(rect: &mut Frame, app: &mut App, is_active: bool) {
+        let popup_area = centered_rect_with_length(80, 22, rect.area());
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(
+                [
+                    Constraint::Length(3),
+                    Constraint::Fill(1),
+                    Constraint::Length(1),
+                    Constraint::Length(3),
+                ]
+                .as_ref(),
+            )
+            .margin(2)
+            .split(popup_area);
+        let button_chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Fill(1), Constraint::Fill(1)].as_ref())
+            .split(chunks[3]);
+
+        let general_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.general_style,
+        );
+        let list_select_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.list_select_style,
+        );
+        let help_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.help_text_style,
+        );
+
+        let (summary, conflict_items) = match &app.state.mirror_sync_diff {
+            Some(diff) => {
+                let conflicts = diff.conflicts();
+                let mut summary = format!(
+                    "Mirror changes: {} new, {} edited, {} moved, {} removed, {} conflicts",
+                    diff.added_count(),
+                    diff.edited_count(),
+                    diff.moved_count(),
+                    diff.removed_count(),
+                    conflicts.len()
+                );
+                if !diff.skipped_boards.is_empty() {
+                    summary.push_str(&format!(
+                        "\nSkipped unknown boards: {}",
+                        diff.skipped_boards.join(", ")
+                    ));
+                }
+                let conflict_items = conflicts
+                    .iter()
+                    .map(|conflict| {
+                        let detail = match (&conflict.mine, &conflict.theirs) {
+                            (None, _) => "removed here, edited in mirror",
+                            (_, None) => "edited here, removed in mirror",
+                            _ => "edited on both sides",
+                        };
+                        ListItem::new(Line::from(format!(
+                            "{} ({}): {}",
+                            conflict.card_name(),
+                            detail,
+                            conflict.resolution
+                        )))
+                    })
+                    .collect::<Vec<ListItem>>();
+                (summary, conflict_items)
+            }
+            None => (String::new(), Vec::new()),
+        };
+
+        let summary_paragraph = Paragraph::new(summary)
+            .style(general_style)
+            .alignment(Alignment::Center)
+            .wrap(Wrap { trim: true });
+
+        if is_active && check_if_mouse_is_in_area(&app.state.current_mouse_coordinates, &chunks[1])
+        {
+            calculate_mouse_list_select_index(
+                app.state.current_mouse_coordinates.1,
+                &conflict_items,
+                chunks[1],
+                &mut app.state.app_list_states.mirror_sync_conflicts,
+            );
+        }
+        let conflicts_border_style = get_mouse_focusable_field_style(
+            app,
+            Focus::MirrorSyncConflicts,
+            &chunks[1],
+            is_active,
+            false,
+        );
+        let conflict_list = List::new(conflict_items)
+            .block(
+                Block::default()
+                    .title(tr(MessageId::TitleConflicts))
+                    .style(general_style)
+                    .border_style(conflicts_border_style)
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded),
+            )
+            .highlight_style(list_select_style)
+            .highlight_symbol(LIST_SELECTED_SYMBOL);
+
+        let help_paragraph =
+            Paragraph::new("Left/Right or click a conflict to keep your version or the mirror's")
+                .style(help_style)
+                .alignment(Alignment::Center);
+
+        let apply_button_style = get_mouse_focusable_field_style(
+            app,
+            Focus::SubmitButton,
+            &button_chunks[0],
+            is_active,
+            false,
+        );
+        let cancel_button_style = get_mouse_focusable_field_style(
+            app,
+            Focus::ExtraFocus,
+            &button_chunks[1],
+            is_active,
+            false,
+        );
+        let apply_button = Paragraph::new(tr(MessageId::ButtonYes))
+            .style(apply_button_style)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(apply_button_style)
+                    .border_type(BorderType::Rounded),
+            )
+            .alignment(Alignment::Center);
+        let cancel_button = Paragraph::new(tr(MessageId::ButtonNo))
+            .style(cancel_button_style)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(cancel_button_style)
+                    .border_type(BorderType::Rounded),
+            )
+            .alignment(Alignment::Center);
+        let border_block = Block::default()
+            .title(tr(MessageId::TitleMirrorSync))
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(general_style);
+
+        render_blank_styled_canvas(rect, &app.current_theme, popup_area, is_active);
+        rect.render_widget(summary_paragraph, chunks[0]);
+        rect.render_stateful_widget(
+            conflict_list,
+            chunks[1],
+            &mut app.state.app_list_states.mirror_sync_conflicts,
+        );
+        rect.render_widget(help_paragraph, chunks[2]);
+        rect.render_widget(apply_button, button_chunks[0]);
+        rect.render_widget(cancel_button, button_chunks[1]);
+        rect.render_widget(border_block, popup_area);
+        if app.config.enable_mouse_support {
+            render_close_button(rect, app, is_active)
+        }
+    }
+}
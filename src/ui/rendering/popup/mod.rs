@@ -1,17 +1,46 @@
+pub mod board_accent_color_selector;
+pub mod board_stats;
+pub mod calendar_day_cards;
+pub mod card_highlight_color_selector;
 pub mod card_priority_selector;
 pub mod card_status_selector;
 pub mod change_date_format;
 pub mod change_theme;
 pub mod change_view;
+pub mod confirm_clear_waiting_on;
 pub mod confirm_discard_card_changes;
+pub mod confirm_duplicate_card_name;
+pub mod confirm_keep_draft;
+pub mod confirm_quit;
+pub mod confirm_restore_themes_from_backup;
+pub mod conflict_marker_warning;
+pub mod custom_date_range_prompt;
 pub mod custom_hex_color_prompt;
+pub mod duplicate_boards;
 pub mod edit_general_config;
 pub mod edit_specific_keybinding;
 pub mod edit_theme_style;
+pub mod filter_by_date_range;
+pub mod filter_by_priority;
+pub mod filter_by_status;
 pub mod filter_by_tag;
+pub mod find_replace;
+pub mod github_import_summary;
+pub mod import_options;
+pub mod import_prompt;
+pub mod mirror_sync_summary;
+pub mod move_card_to_board;
+pub mod notifications;
+pub mod recover_save_file;
 pub mod save_theme_prompt;
+pub mod search_cards;
+pub mod select_card_template;
 pub mod select_default_view;
+pub mod set_log_level;
+pub mod sort_cards_by;
 pub mod view_card;
+pub mod waiting_on_list;
+pub mod whats_new;
 pub mod widgets;
 
 pub struct ViewCard;
@@ -24,7 +53,39 @@ pub struct ChangeTheme;
 pub struct EditThemeStyle;
 pub struct SaveThemePrompt;
 pub struct CustomHexColorPrompt;
+pub struct CustomDateRangePrompt;
+pub struct ImportTrelloPrompt;
+pub struct ImportJsonPrompt;
+pub struct ImportBoardJsonPrompt;
+pub struct ImportGithubPrompt;
+pub struct ImportOptions;
+pub struct SearchCards;
 pub struct ConfirmDiscardCardChanges;
+pub struct ConfirmRestoreThemesFromBackup;
+pub struct ConfirmDuplicateCardName;
+pub struct ConfirmKeepDraft;
+pub struct ConfirmClearWaitingOn;
+pub struct ConfirmQuit;
 pub struct CardPrioritySelector;
+pub struct CardHighlightColorSelector;
+pub struct BoardAccentColorSelector;
 pub struct FilterByTag;
+pub struct FilterByDateRange;
+pub struct FilterByPriority;
+pub struct FilterByStatus;
 pub struct ChangeDateFormat;
+pub struct MirrorSyncSummary;
+pub struct Notifications;
+pub struct WhatsNew;
+pub struct GithubImportSummary;
+pub struct ConflictMarkerWarning;
+pub struct DuplicateBoards;
+pub struct RecoverSaveFile;
+pub struct BoardStats;
+pub struct SortCardsBy;
+pub struct MoveCardToBoard;
+pub struct CalendarDayCards;
+pub struct WaitingOnList;
+pub struct SelectCardTemplate;
+pub struct SetLogLevel;
+pub struct FindReplace;
@@ -0,0 +1,160 @@
+use crate::{
+    app::{state::Focus, App},
+    constants::LIST_SELECTED_SYMBOL,
+    i18n::{tr, MessageId},
+    io::IoOutcome,
+    ui::{
+        rendering::{
+            common::{render_blank_styled_canvas, render_close_button},
+            popup::Notifications,
+            utils::{
+                calculate_mouse_list_select_index, centered_rect_with_length,
+                check_if_active_and_get_style, check_if_mouse_is_in_area,
+                get_mouse_focusable_field_style,
+            },
+        },
+        Renderable,
+    },
+};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout},
+    style::Modifier,
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, List, ListItem, Paragraph},
+    Frame,
+};
+
+impl Renderable for Notifications {
+    fn render(rect: &mut Frame, app: &mut App, is_active: bool) {
+        let popup_area = centered_rect_with_length(90, 20, rect.area());
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Fill(1), Constraint::Length(3)].as_ref())
+            .margin(2)
+            .split(popup_area);
+
+        let general_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.general_style,
+        );
+        let list_select_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.list_select_style,
+        );
+        let succeeded_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.log_info_style,
+        );
+        let skipped_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.log_warn_style,
+        );
+        let failed_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.log_error_style,
+        );
+
+        let entries = app.state.notifications.entries();
+        let first_unread_index = entries.len() - app.state.notifications.unread_count();
+        // Newest first, the unread ones are the tail of the entries
+        let notification_items = entries
+            .iter()
+            .enumerate()
+            .rev()
+            .map(|(index, entry)| {
+                let outcome_style = match entry.outcome {
+                    IoOutcome::Succeeded => succeeded_style,
+                    IoOutcome::Skipped => skipped_style,
+                    IoOutcome::Failed => failed_style,
+                };
+                let unread_marker = if index >= first_unread_index {
+                    "● "
+                } else {
+                    "  "
+                };
+                let mut line = Line::from(vec![
+                    Span::styled(unread_marker, outcome_style),
+                    Span::styled(
+                        format!("{} ", entry.completed_at.format("%H:%M:%S")),
+                        general_style,
+                    ),
+                    Span::styled(format!("{} {}", entry.kind, entry.outcome), outcome_style),
+                    Span::styled(format!(": {}", entry.details), general_style),
+                ]);
+                if index >= first_unread_index {
+                    line = line.patch_style(Modifier::BOLD);
+                }
+                ListItem::new(line)
+            })
+            .collect::<Vec<ListItem>>();
+        let unread_count = app.state.notifications.unread_count();
+
+        if is_active && check_if_mouse_is_in_area(&app.state.current_mouse_coordinates, &chunks[0])
+        {
+            calculate_mouse_list_select_index(
+                app.state.current_mouse_coordinates.1,
+                &notification_items,
+                chunks[0],
+                &mut app.state.app_list_states.notifications,
+            );
+        }
+        let list_border_style = get_mouse_focusable_field_style(
+            app,
+            Focus::NotificationsList,
+            &chunks[0],
+            is_active,
+            false,
+        );
+        let list_title = if notification_items.is_empty() {
+            "No background operations yet".to_string()
+        } else {
+            format!("{} unread", unread_count)
+        };
+        let notification_list = List::new(notification_items)
+            .block(
+                Block::default()
+                    .title(list_title)
+                    .style(general_style)
+                    .border_style(list_border_style)
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded),
+            )
+            .highlight_style(list_select_style)
+            .highlight_symbol(LIST_SELECTED_SYMBOL);
+
+        let mark_read_button_style =
+            get_mouse_focusable_field_style(app, Focus::SubmitButton, &chunks[1], is_active, false);
+        let mark_read_button = Paragraph::new("Mark all as read")
+            .style(mark_read_button_style)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(mark_read_button_style)
+                    .border_type(BorderType::Rounded),
+            )
+            .alignment(Alignment::Center);
+        let border_block = Block::default()
+            .title(tr(MessageId::TitleNotifications))
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(general_style);
+
+        render_blank_styled_canvas(rect, &app.current_theme, popup_area, is_active);
+        rect.render_stateful_widget(
+            notification_list,
+            chunks[0],
+            &mut app.state.app_list_states.notifications,
+        );
+        rect.render_widget(mark_read_button, chunks[1]);
+        rect.render_widget(border_block, popup_area);
+        if app.config.enable_mouse_support {
+            render_close_button(rect, app, is_active)
+        }
+    }
+}
@@ -0,0 +1,34 @@
+use crate::{
+    app::App,
+    i18n::{tr, MessageId},
+    ui::{
+        rendering::{common::render_yes_no_popup, popup::RecoverSaveFile},
+        Renderable,
+    },
+};
+use ratatui::Frame;
+
+impl Renderable for RecoverSaveFile {
+    fn render(rect: &mut Frame, app: &mut App, is_active: bool) {
+        let message = if let Some(candidate) = &app.state.save_recovery_candidate {
+            let recovery_file_name = candidate
+                .recovery_path
+                .file_name()
+                .map(|file_name| file_name.to_string_lossy().to_string())
+                .unwrap_or_default();
+            format!(
+                "{} could not be read. Load {} ({} boards) instead?",
+                candidate.corrupt_file_name, recovery_file_name, candidate.board_count
+            )
+        } else {
+            String::new()
+        };
+        render_yes_no_popup(
+            rect,
+            app,
+            is_active,
+            tr(MessageId::TitleRecoverSaveFile),
+            message,
+        );
+    }
+}
@@ -0,0 +1,155 @@
+use crate::{
+    app::{
+        state::{AppStatus, Focus, KeyBindingEnum},
+        App,
+    },
+    i18n::{tr, MessageId},
+    ui::{
+        rendering::{
+            common::{render_blank_styled_canvas, render_close_button},
+            popup::SearchCards,
+            utils::{
+                calculate_viewport_corrected_cursor_position, centered_rect_with_length,
+                check_if_active_and_get_style, get_mouse_focusable_field_style,
+            },
+        },
+        Renderable,
+    },
+};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Paragraph},
+    Frame,
+};
+
+impl Renderable for SearchCards {
+    fn render(rect: &mut Frame, app: &mut App, is_active: bool) {
+        let popup_area = centered_rect_with_length(60, 9, rect.area());
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(
+                [
+                    Constraint::Length(1),
+                    Constraint::Length(3),
+                    Constraint::Length(3),
+                ]
+                .as_ref(),
+            )
+            .margin(1)
+            .split(popup_area);
+
+        let general_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.general_style,
+        );
+        let help_key_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.help_key_style,
+        );
+        let help_text_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.help_text_style,
+        );
+        let error_text_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.error_text_style,
+        );
+        let text_input_style =
+            get_mouse_focusable_field_style(app, Focus::TextInput, &chunks[1], is_active, true);
+
+        let query = app.state.text_buffers.card_search.get_joined_lines();
+        let match_count = app
+            .filtered_boards
+            .get_boards()
+            .iter()
+            .map(|board| board.cards.len())
+            .sum::<usize>();
+        let status_text = if query.trim().is_empty() {
+            Paragraph::new("Type to filter cards by name or description").style(general_style)
+        } else if match_count == 0 {
+            Paragraph::new("No cards match").style(error_text_style)
+        } else {
+            Paragraph::new(format!(
+                "{} card{} across {} board{}",
+                match_count,
+                if match_count == 1 { "" } else { "s" },
+                app.filtered_boards.len(),
+                if app.filtered_boards.len() == 1 {
+                    ""
+                } else {
+                    "s"
+                }
+            ))
+            .style(general_style)
+        }
+        .alignment(Alignment::Center);
+
+        let user_input = if query.is_empty() {
+            Line::from(Span::styled(
+                "No input",
+                app.current_theme.inactive_text_style,
+            ))
+        } else {
+            Line::from(Span::styled(query, general_style))
+        };
+        let text_input = Paragraph::new(user_input).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(text_input_style)
+                .border_type(BorderType::Rounded),
+        );
+
+        let accept_key = app
+            .get_first_keybinding(KeyBindingEnum::Accept)
+            .unwrap_or("".to_string());
+        let cancel_key = app
+            .get_first_keybinding(KeyBindingEnum::GoToPreviousViewOrCancel)
+            .unwrap_or("".to_string());
+
+        let help_text = Paragraph::new(Line::from(vec![
+            Span::styled("Press ", help_text_style),
+            Span::styled(accept_key, help_key_style),
+            Span::styled(" to keep the filter or ", help_text_style),
+            Span::styled(cancel_key, help_key_style),
+            Span::styled(" to clear it.", help_text_style),
+        ]))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(general_style)
+                .border_type(BorderType::Rounded),
+        )
+        .alignment(Alignment::Center)
+        .wrap(ratatui::widgets::Wrap { trim: true });
+
+        let border_block = Block::default()
+            .title(tr(MessageId::TitleSearchCards))
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(general_style);
+
+        if app.state.app_status == AppStatus::UserInput {
+            let (x_pos, y_pos) = calculate_viewport_corrected_cursor_position(
+                &app.state.text_buffers.card_search,
+                &app.config.show_line_numbers,
+                &chunks[1],
+            );
+            rect.set_cursor_position((x_pos, y_pos));
+        }
+
+        render_blank_styled_canvas(rect, &app.current_theme, popup_area, is_active);
+        rect.render_widget(status_text, chunks[0]);
+        rect.render_widget(text_input, chunks[1]);
+        rect.render_widget(help_text, chunks[2]);
+        rect.render_widget(border_block, popup_area);
+        if app.config.enable_mouse_support {
+            render_close_button(rect, app, is_active);
+        }
+    }
+}
@@ -0,0 +1,89 @@
+use crate::{
+    app::{state::Focus, App},
+    constants::LIST_SELECTED_SYMBOL,
+    i18n::{tr, MessageId},
+    ui::{
+        rendering::{
+            common::{render_blank_styled_canvas, render_close_button},
+            popup::SelectCardTemplate,
+            utils::{
+                calculate_mouse_list_select_index, centered_rect_with_percentage,
+                check_if_active_and_get_style, check_if_mouse_is_in_area,
+            },
+        },
+        Renderable,
+    },
+};
+use ratatui::{
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, List, ListItem},
+    Frame,
+};
+
+impl Renderable for SelectCardTemplate {
+    fn render(rect: &mut Frame, app: &mut App, is_active: bool) {
+        let general_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.general_style,
+        );
+        let help_text_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.help_text_style,
+        );
+        let list_select_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.list_select_style,
+        );
+        let templates = app
+            .state
+            .card_templates
+            .iter()
+            .map(|template| {
+                let mut spans = vec![Span::styled(template.name.clone(), general_style)];
+                if !template.tags.is_empty() {
+                    spans.push(Span::styled(
+                        format!(" ({})", template.tags.join(", ")),
+                        help_text_style,
+                    ));
+                }
+                ListItem::new(vec![Line::from(spans)])
+            })
+            .collect::<Vec<ListItem>>();
+        let percent_height =
+            (((templates.len() + 3) as f32 / rect.area().height as f32) * 100.0) as u16;
+        let popup_area = centered_rect_with_percentage(50, percent_height, rect.area());
+        if check_if_mouse_is_in_area(&app.state.current_mouse_coordinates, &popup_area) {
+            app.state.mouse_focus = Some(Focus::SelectCardTemplatePopup);
+            app.state.set_focus(Focus::SelectCardTemplatePopup);
+            calculate_mouse_list_select_index(
+                app.state.current_mouse_coordinates.1,
+                &templates,
+                popup_area,
+                &mut app.state.app_list_states.card_templates,
+            );
+        }
+        let templates_list = List::new(templates)
+            .block(
+                Block::default()
+                    .title(tr(MessageId::TitleCardTemplates))
+                    .style(general_style)
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded),
+            )
+            .highlight_style(list_select_style)
+            .highlight_symbol(LIST_SELECTED_SYMBOL);
+
+        render_blank_styled_canvas(rect, &app.current_theme, popup_area, is_active);
+        rect.render_stateful_widget(
+            templates_list,
+            popup_area,
+            &mut app.state.app_list_states.card_templates,
+        );
+        if app.config.enable_mouse_support {
+            render_close_button(rect, app, is_active);
+        }
+    }
+}
@@ -4,6 +4,7 @@ use crate::{
         App,
     },
     constants::LIST_SELECTED_SYMBOL,
+    i18n::{tr, MessageId},
     ui::{
         rendering::{
             common::{render_blank_styled_canvas, render_close_button},
@@ -119,7 +120,7 @@ impl Renderable for SelectDefaultView {
             .alignment(Alignment::Left)
             .block(
                 Block::default()
-                    .title("Help")
+                    .title(tr(MessageId::TitleHelp))
                     .borders(Borders::ALL)
                     .style(general_style)
                     .border_type(BorderType::Rounded),
@@ -1,6 +1,6 @@
 use crate::{
     app::{
-        kanban::{CardPriority, CardStatus},
+        kanban::{format_estimate, parse_estimate, parse_minutes, CardPriority, CardStatus},
         state::{AppStatus, Focus},
         App, DateTimeFormat,
     },
@@ -14,10 +14,11 @@ use crate::{
                 check_if_active_and_get_style, check_if_mouse_is_in_area, get_button_style,
             },
         },
+        text_box::TextBox,
         widgets::SelfViewportCorrection,
         PopUp, Renderable,
     },
-    util::{date_format_converter, date_format_finder},
+    util::{date_format_converter, date_format_finder, format_id},
 };
 use chrono::{Local, NaiveDate, NaiveDateTime};
 use ratatui::{
@@ -27,6 +28,10 @@ use ratatui::{
     Frame,
 };
 
+const ESTIMATED_TIME_LABEL: &str = "Estimated time (min): ";
+const ACTUAL_TIME_LABEL: &str = "Actual time (min): ";
+const WAITING_ON_LABEL: &str = "Waiting on: ";
+
 impl Renderable for ViewCard {
     fn render(rect: &mut Frame, app: &mut App, is_active: bool) {
         let popup_area = centered_rect_with_percentage(90, 90, rect.area());
@@ -55,12 +60,26 @@ impl Renderable for ViewCard {
         let card_tags_style = get_button_style(app, Focus::CardTags, None, is_active, false);
         let card_comments_style =
             get_button_style(app, Focus::CardComments, None, is_active, false);
+        let card_checklist_style =
+            get_button_style(app, Focus::CardChecklist, None, is_active, false);
         let save_changes_style = get_button_style(app, Focus::SubmitButton, None, is_active, false);
+        let save_as_template_style =
+            get_button_style(app, Focus::SaveCardAsTemplate, None, is_active, false);
         let name_style = get_button_style(app, Focus::CardName, None, is_active, false);
         let description_style =
             get_button_style(app, Focus::CardDescription, None, is_active, false);
+        let card_start_default_style =
+            get_button_style(app, Focus::CardStartDate, None, is_active, false);
         let card_due_default_style =
             get_button_style(app, Focus::CardDueDate, None, is_active, false);
+        let card_estimate_default_style =
+            get_button_style(app, Focus::CardEstimate, None, is_active, false);
+        let card_estimated_time_default_style =
+            get_button_style(app, Focus::CardEstimatedTime, None, is_active, false);
+        let card_actual_time_default_style =
+            get_button_style(app, Focus::CardActualTime, None, is_active, false);
+        let card_waiting_on_default_style =
+            get_button_style(app, Focus::CardWaitingOn, None, is_active, false);
         if app.state.current_board_id.is_none() || app.state.current_card_id.is_none() {
             let no_board_or_card_selected = Paragraph::new("No board or card selected.")
                 .block(
@@ -118,14 +137,19 @@ impl Renderable for ViewCard {
         } else {
             card.unwrap().to_owned()
         };
+        let picker_date = if app.state.picking_card_start_date {
+            card.start_date.clone().unwrap_or_default()
+        } else {
+            card.due_date.clone()
+        };
         if app.widgets.date_time_picker.selected_date_time.is_none()
-            && !card.due_date.is_empty()
-            && card.due_date != FIELD_NOT_SET
+            && !picker_date.is_empty()
+            && picker_date != FIELD_NOT_SET
         {
-            if let Ok(current_format) = date_format_finder(card.due_date.trim()) {
+            if let Ok(current_format) = date_format_finder(picker_date.trim()) {
                 app.widgets.date_time_picker.selected_date_time =
                     match NaiveDateTime::parse_from_str(
-                        card.due_date.trim(),
+                        picker_date.trim(),
                         current_format.to_parser_string(),
                     ) {
                         Ok(date_time) => Some(date_time),
@@ -179,7 +203,12 @@ impl Renderable for ViewCard {
             .set_block(description_block);
 
         // Process Card Extra Info
-        let (card_extra_info_widget, card_extra_info_items_len, card_due_date_width) = {
+        let (
+            card_extra_info_widget,
+            card_extra_info_items_len,
+            card_start_date_width,
+            card_due_date_width,
+        ) = {
             let card_date_created = if date_format_finder(&card.date_created).is_ok() {
                 if let Ok(parsed_date) =
                     date_format_converter(&card.date_created, app.config.date_time_format)
@@ -256,7 +285,33 @@ impl Renderable for ViewCard {
                 }
             };
             let card_priority = format!("Priority: {}", card.priority);
-            let card_status = format!("Status: {}", card.card_status);
+            let card_status = format!("Status: {}", card.get_status_name());
+            let card_color = format!(
+                "Color: {}",
+                card.highlight_color
+                    .map_or("None".to_string(), |color| color.to_string())
+            );
+            let parsed_start_date = card
+                .start_date
+                .as_deref()
+                .filter(|start_date| date_format_finder(start_date).is_ok())
+                .and_then(|start_date| {
+                    date_format_converter(start_date, app.config.date_time_format).ok()
+                })
+                .unwrap_or_else(|| FIELD_NOT_SET.to_string());
+            let card_start_date_styled = if !is_active {
+                Span::styled(
+                    format!("Start: {}", parsed_start_date),
+                    app.current_theme.inactive_text_style,
+                )
+            } else if app.state.focus == Focus::CardStartDate {
+                Span::styled(format!("Start: {}", parsed_start_date), list_select_style)
+            } else {
+                Span::styled(
+                    format!("Start: {}", parsed_start_date),
+                    card_start_default_style,
+                )
+            };
             let parsed_due_date = if date_format_finder(&card.due_date).is_ok() {
                 date_format_converter(&card.due_date, app.config.date_time_format)
             } else {
@@ -347,18 +402,131 @@ impl Renderable for ViewCard {
             } else {
                 Span::styled(card_status, app.current_theme.general_style)
             };
-            let card_extra_info_items = vec![
+            let card_color_styled = if !is_active {
+                Span::styled(card_color, app.current_theme.inactive_text_style)
+            } else if app.state.focus == Focus::CardColor {
+                Span::styled(card_color, app.current_theme.list_select_style)
+            } else if let Some(highlight_color) = card.highlight_color {
+                Span::styled(
+                    card_color,
+                    app.current_theme.general_style.fg(highlight_color.into()),
+                )
+            } else {
+                Span::styled(card_color, app.current_theme.general_style)
+            };
+            // While editing the typed text is shown as is, with the reason it would be rejected
+            let (card_estimate, card_estimate_error) = if app.state.card_being_edited.is_some() {
+                let typed_estimate = app.state.text_buffers.card_estimate.get_joined_lines();
+                let estimate_error = parse_estimate(&typed_estimate).err();
+                (typed_estimate, estimate_error)
+            } else {
+                (
+                    card.estimate
+                        .map_or(FIELD_NOT_SET.to_string(), format_estimate),
+                    None,
+                )
+            };
+            let card_estimate_style = if !is_active {
+                app.current_theme.inactive_text_style
+            } else if app.state.focus == Focus::CardEstimate {
+                list_select_style
+            } else {
+                card_estimate_default_style
+            };
+            let mut card_estimate_spans = vec![Span::styled(
+                format!("Estimate: {}", card_estimate),
+                card_estimate_style,
+            )];
+            if let Some(card_estimate_error) = card_estimate_error {
+                card_estimate_spans.push(Span::styled(
+                    format!(" ({})", card_estimate_error),
+                    error_style,
+                ));
+            }
+            let minutes_spans = |label: &str,
+                                 text_buffer: &TextBox,
+                                 minutes: Option<u32>,
+                                 focus: Focus,
+                                 default_style| {
+                let (minutes, minutes_error) = if app.state.card_being_edited.is_some() {
+                    let typed_minutes = text_buffer.get_joined_lines();
+                    let minutes_error = parse_minutes(&typed_minutes).err();
+                    (typed_minutes, minutes_error)
+                } else {
+                    (
+                        minutes.map_or(FIELD_NOT_SET.to_string(), |minutes| minutes.to_string()),
+                        None,
+                    )
+                };
+                let minutes_style = if !is_active {
+                    app.current_theme.inactive_text_style
+                } else if app.state.focus == focus {
+                    list_select_style
+                } else {
+                    default_style
+                };
+                let mut spans = vec![Span::styled(format!("{}{}", label, minutes), minutes_style)];
+                if let Some(minutes_error) = minutes_error {
+                    spans.push(Span::styled(format!(" ({})", minutes_error), error_style));
+                }
+                spans
+            };
+            let card_estimated_time_spans = minutes_spans(
+                ESTIMATED_TIME_LABEL,
+                &app.state.text_buffers.card_estimated_time,
+                card.estimated_minutes,
+                Focus::CardEstimatedTime,
+                card_estimated_time_default_style,
+            );
+            let card_actual_time_spans = minutes_spans(
+                ACTUAL_TIME_LABEL,
+                &app.state.text_buffers.card_actual_time,
+                card.actual_minutes,
+                Focus::CardActualTime,
+                card_actual_time_default_style,
+            );
+            let card_waiting_on = if app.state.card_being_edited.is_some() {
+                app.state.text_buffers.card_waiting_on.get_joined_lines()
+            } else {
+                card.waiting_on
+                    .clone()
+                    .unwrap_or_else(|| FIELD_NOT_SET.to_string())
+            };
+            let card_waiting_on_style = if !is_active {
+                app.current_theme.inactive_text_style
+            } else if app.state.focus == Focus::CardWaitingOn {
+                list_select_style
+            } else {
+                card_waiting_on_default_style
+            };
+            let card_waiting_on_styled = Span::styled(
+                format!("{}{}", WAITING_ON_LABEL, card_waiting_on),
+                card_waiting_on_style,
+            );
+            let mut card_extra_info_items = vec![
                 ListItem::new(vec![Line::from(card_date_created)]),
                 ListItem::new(vec![Line::from(card_date_modified)]),
+                ListItem::new(vec![Line::from(card_start_date_styled.clone())]),
                 ListItem::new(vec![Line::from(card_due_date_styled.clone())]),
                 ListItem::new(vec![Line::from(card_date_completed)]),
                 ListItem::new(vec![Line::from(card_priority_styled)]),
                 ListItem::new(vec![Line::from(card_status_styled)]),
+                ListItem::new(vec![Line::from(card_color_styled)]),
+                ListItem::new(vec![Line::from(card_estimate_spans)]),
+                ListItem::new(vec![Line::from(card_estimated_time_spans)]),
+                ListItem::new(vec![Line::from(card_actual_time_spans)]),
+                ListItem::new(vec![Line::from(card_waiting_on_styled)]),
             ];
+            if !card.links.is_empty() {
+                card_extra_info_items.push(ListItem::new(vec![Line::from(Span::styled(
+                    format!("Links: {}", card.links.join(", ")),
+                    general_style,
+                ))]));
+            }
             let card_extra_info_items_len = card_extra_info_items.len();
             let card_extra_info = List::new(card_extra_info_items).block(
                 Block::default()
-                    .title("Card Info")
+                    .title(format!("Card Info (id {})", format_id(card.id)))
                     .borders(Borders::ALL)
                     .border_type(BorderType::Rounded)
                     .border_style(general_style),
@@ -366,6 +534,7 @@ impl Renderable for ViewCard {
             (
                 card_extra_info,
                 card_extra_info_items_len,
+                card_start_date_styled.width(),
                 card_due_date_styled.width(),
             )
         };
@@ -427,7 +596,9 @@ impl Renderable for ViewCard {
             let mut collector_end = 0;
             for (i, tag) in card.tags.iter().enumerate() {
                 let tag_string = format!("{}) {} ", i + 1, tag);
-                if (collector.len() + tag_string.len()) < (popup_area.width - 2) as usize {
+                if (collector.len() + tag_string.len())
+                    < popup_area.width.saturating_sub(2) as usize
+                {
                     collector.push_str(&tag_string);
                     collector_end = i + 1;
                 } else {
@@ -504,7 +675,9 @@ impl Renderable for ViewCard {
             let mut collector_end = 0;
             for (i, comment) in card.comments.iter().enumerate() {
                 let comment_string = format!("{}) {} ", i + 1, comment);
-                if (collector.len() + comment_string.len()) < (popup_area.width - 2) as usize {
+                if (collector.len() + comment_string.len())
+                    < popup_area.width.saturating_sub(2) as usize
+                {
                     collector.push_str(&comment_string);
                     collector_end = i + 1;
                 } else {
@@ -525,36 +698,65 @@ impl Renderable for ViewCard {
             card_comment_lines
         };
 
+        // Process Card Checklist
+        let card_checklist_lines = {
+            let selected_item = if app.state.focus == Focus::CardChecklist {
+                app.state.app_list_states.card_view_checklist.selected()
+            } else {
+                None
+            };
+            card.checklist
+                .iter()
+                .enumerate()
+                .map(|(index, checklist_item)| {
+                    let item_style = if Some(index) == selected_item {
+                        keyboard_focus_style
+                    } else {
+                        general_style
+                    };
+                    Line::from(Span::styled(
+                        format!(
+                            "[{}] {}",
+                            if checklist_item.completed { "x" } else { " " },
+                            checklist_item.text
+                        ),
+                        item_style,
+                    ))
+                })
+                .collect::<Vec<Line>>()
+        };
+
         // Determine chunk sizes
         let card_chunks = {
             let min_box_height: u16 = 2;
             let border_height: u16 = 2;
-            let max_height: u16 = popup_area.height - border_height;
+            let max_height: u16 = popup_area.height.saturating_sub(border_height);
             let submit_button_height: u16 = 3;
             let card_name_box_height: u16 = 3;
-            let card_extra_info_height: u16 = 8;
-            let mut available_height: u16 = if app.state.card_being_edited.is_some() {
-                max_height - card_name_box_height - card_extra_info_height - submit_button_height
-            } else {
-                max_height - card_name_box_height - card_extra_info_height
-            };
+            let card_extra_info_height: u16 = card_extra_info_items_len as u16 + border_height;
+            let mut available_height: u16 = max_height.saturating_sub(
+                card_name_box_height + card_extra_info_height + submit_button_height,
+            );
 
             let raw_card_description_height =
                 app.state.text_buffers.card_description.get_num_lines() as u16;
 
             let raw_tags_height = card_tag_lines.len() as u16;
             let raw_comments_height = card_comment_lines.len() as u16;
+            let raw_checklist_height = card_checklist_lines.len() as u16;
 
             let mut card_description_height = if app.state.focus == Focus::CardDescription {
                 if available_height
                     .saturating_sub(raw_tags_height + border_height)
                     .saturating_sub(raw_comments_height + border_height)
+                    .saturating_sub(raw_checklist_height + border_height)
                     > 0
                 {
                     let calc = available_height
                         - raw_tags_height
                         - raw_comments_height
-                        - (border_height * 2);
+                        - raw_checklist_height
+                        - (border_height * 3);
                     if calc < (raw_card_description_height + border_height) {
                         let diff = (raw_card_description_height + border_height) - calc;
                         if diff < min_box_height {
@@ -573,6 +775,7 @@ impl Renderable for ViewCard {
             } else if ((raw_card_description_height + border_height) <= available_height)
                 && app.state.focus != Focus::CardTags
                 && app.state.focus != Focus::CardComments
+                && app.state.focus != Focus::CardChecklist
             {
                 raw_card_description_height.saturating_sub(border_height)
             } else {
@@ -605,60 +808,73 @@ impl Renderable for ViewCard {
 
             available_height = available_height.saturating_sub(card_comments_height);
 
+            let card_checklist_height = if available_height > 0 {
+                if app.state.focus == Focus::CardChecklist {
+                    raw_checklist_height + border_height
+                } else {
+                    min_box_height
+                }
+            } else {
+                min_box_height
+            };
+
+            available_height = available_height.saturating_sub(card_checklist_height);
+
             if available_height > 0 {
                 card_description_height += available_height;
             }
 
-            if app.state.card_being_edited.is_some() {
-                Layout::default()
-                    .direction(Direction::Vertical)
-                    .constraints([
-                        Constraint::Length(card_name_box_height),
-                        Constraint::Length(card_description_height),
-                        Constraint::Length(card_extra_info_height),
-                        Constraint::Length(card_tags_height),
-                        Constraint::Length(card_comments_height),
-                        Constraint::Length(submit_button_height),
-                    ])
-                    .margin(1)
-                    .split(popup_area)
-            } else {
-                Layout::default()
-                    .direction(Direction::Vertical)
-                    .constraints([
-                        Constraint::Length(card_name_box_height),
-                        Constraint::Length(card_description_height),
-                        Constraint::Length(card_extra_info_height),
-                        Constraint::Length(card_tags_height),
-                        Constraint::Length(card_comments_height),
-                    ])
-                    .margin(1)
-                    .split(popup_area)
-            }
+            Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([
+                    Constraint::Length(card_name_box_height),
+                    Constraint::Length(card_description_height),
+                    Constraint::Length(card_checklist_height),
+                    Constraint::Length(card_extra_info_height),
+                    Constraint::Length(card_tags_height),
+                    Constraint::Length(card_comments_height),
+                    Constraint::Length(submit_button_height),
+                ])
+                .margin(1)
+                .split(popup_area)
         };
 
         if app.state.z_stack.last() == Some(&PopUp::DateTimePicker) {
             if app.widgets.date_time_picker.get_anchor().is_none() {
+                let (date_width, date_row_offset) = if app.state.picking_card_start_date {
+                    (card_start_date_width, 3)
+                } else {
+                    (card_due_date_width, 4)
+                };
                 app.widgets.date_time_picker.set_anchor(Some((
-                    card_chunks[2].x + card_due_date_width as u16 + 2,
-                    card_chunks[2].y + 3,
+                    card_chunks[3].x + date_width as u16 + 2,
+                    card_chunks[3].y + date_row_offset,
                 ))); // offsets to make sure date is visible
             }
             app.widgets.date_time_picker.current_viewport = Some(rect.area());
         }
 
         if is_active
-            && check_if_mouse_is_in_area(&app.state.current_mouse_coordinates, &card_chunks[2])
+            && check_if_mouse_is_in_area(&app.state.current_mouse_coordinates, &card_chunks[3])
         {
-            let top_of_list = card_chunks[2].y + 1;
-            let mut bottom_of_list = card_chunks[2].y + card_extra_info_items_len as u16;
-            if bottom_of_list > card_chunks[2].bottom() {
-                bottom_of_list = card_chunks[2].bottom();
+            let top_of_list = card_chunks[3].y + 1;
+            let mut bottom_of_list = card_chunks[3].y + card_extra_info_items_len as u16;
+            if bottom_of_list > card_chunks[3].bottom() {
+                bottom_of_list = card_chunks[3].bottom();
             }
             let mouse_y = app.state.current_mouse_coordinates.1;
             if mouse_y >= top_of_list && mouse_y <= bottom_of_list {
                 match mouse_y - top_of_list {
                     2 => {
+                        app.state.set_focus(Focus::CardStartDate);
+                        app.state.mouse_focus = Some(Focus::CardStartDate);
+                        app.state
+                            .app_list_states
+                            .card_view_comment_list
+                            .select(None);
+                        app.state.app_list_states.card_view_tag_list.select(None);
+                    }
+                    3 => {
                         app.state.set_focus(Focus::CardDueDate);
                         app.state.mouse_focus = Some(Focus::CardDueDate);
                         app.state
@@ -667,7 +883,7 @@ impl Renderable for ViewCard {
                             .select(None);
                         app.state.app_list_states.card_view_tag_list.select(None);
                     }
-                    4 => {
+                    5 => {
                         app.state.set_focus(Focus::CardPriority);
                         app.state.mouse_focus = Some(Focus::CardPriority);
                         app.state
@@ -676,7 +892,7 @@ impl Renderable for ViewCard {
                             .select(None);
                         app.state.app_list_states.card_view_tag_list.select(None);
                     }
-                    5 => {
+                    6 => {
                         app.state.set_focus(Focus::CardStatus);
                         app.state.mouse_focus = Some(Focus::CardStatus);
                         app.state
@@ -685,6 +901,51 @@ impl Renderable for ViewCard {
                             .select(None);
                         app.state.app_list_states.card_view_tag_list.select(None);
                     }
+                    7 => {
+                        app.state.set_focus(Focus::CardColor);
+                        app.state.mouse_focus = Some(Focus::CardColor);
+                        app.state
+                            .app_list_states
+                            .card_view_comment_list
+                            .select(None);
+                        app.state.app_list_states.card_view_tag_list.select(None);
+                    }
+                    8 => {
+                        app.state.set_focus(Focus::CardEstimate);
+                        app.state.mouse_focus = Some(Focus::CardEstimate);
+                        app.state
+                            .app_list_states
+                            .card_view_comment_list
+                            .select(None);
+                        app.state.app_list_states.card_view_tag_list.select(None);
+                    }
+                    9 => {
+                        app.state.set_focus(Focus::CardEstimatedTime);
+                        app.state.mouse_focus = Some(Focus::CardEstimatedTime);
+                        app.state
+                            .app_list_states
+                            .card_view_comment_list
+                            .select(None);
+                        app.state.app_list_states.card_view_tag_list.select(None);
+                    }
+                    10 => {
+                        app.state.set_focus(Focus::CardActualTime);
+                        app.state.mouse_focus = Some(Focus::CardActualTime);
+                        app.state
+                            .app_list_states
+                            .card_view_comment_list
+                            .select(None);
+                        app.state.app_list_states.card_view_tag_list.select(None);
+                    }
+                    11 => {
+                        app.state.set_focus(Focus::CardWaitingOn);
+                        app.state.mouse_focus = Some(Focus::CardWaitingOn);
+                        app.state
+                            .app_list_states
+                            .card_view_comment_list
+                            .select(None);
+                        app.state.app_list_states.card_view_tag_list.select(None);
+                    }
                     _ => {
                         app.state.set_focus(Focus::NoFocus);
                         app.state.mouse_focus = None;
@@ -741,8 +1002,23 @@ impl Renderable for ViewCard {
             )
             .alignment(Alignment::Left);
 
+        let card_checklist_title = if let Some((completed, total)) = card.checklist_progress() {
+            format!("Checklist ({}/{})", completed, total)
+        } else {
+            "Checklist (0)".to_string()
+        };
+        let card_checklist_widget = Paragraph::new(card_checklist_lines)
+            .block(
+                Block::default()
+                    .title(card_checklist_title)
+                    .border_type(BorderType::Rounded)
+                    .borders(Borders::ALL)
+                    .border_style(card_checklist_style),
+            )
+            .alignment(Alignment::Left);
+
         if is_active
-            && check_if_mouse_is_in_area(&app.state.current_mouse_coordinates, &card_chunks[3])
+            && check_if_mouse_is_in_area(&app.state.current_mouse_coordinates, &card_chunks[4])
         {
             app.state.set_focus(Focus::CardTags);
             app.state.mouse_focus = Some(Focus::CardTags);
@@ -753,13 +1029,25 @@ impl Renderable for ViewCard {
         }
 
         if is_active
-            && check_if_mouse_is_in_area(&app.state.current_mouse_coordinates, &card_chunks[4])
+            && check_if_mouse_is_in_area(&app.state.current_mouse_coordinates, &card_chunks[5])
         {
             app.state.set_focus(Focus::CardComments);
             app.state.mouse_focus = Some(Focus::CardComments);
             app.state.app_list_states.card_view_tag_list.select(None);
         }
 
+        if is_active
+            && check_if_mouse_is_in_area(&app.state.current_mouse_coordinates, &card_chunks[2])
+        {
+            app.state.set_focus(Focus::CardChecklist);
+            app.state.mouse_focus = Some(Focus::CardChecklist);
+            app.state
+                .app_list_states
+                .card_view_comment_list
+                .select(None);
+            app.state.app_list_states.card_view_tag_list.select(None);
+        }
+
         if app.state.app_status == AppStatus::UserInput {
             match app.state.focus {
                 Focus::CardName => {
@@ -778,6 +1066,49 @@ impl Renderable for ViewCard {
                     );
                     rect.set_cursor_position((x_pos, y_pos));
                 }
+                Focus::CardEstimate => {
+                    // Estimate is the eighth row of the card info list
+                    let estimate_row_offset = 8;
+                    let x_pos = card_chunks[3].left()
+                        + 1
+                        + "Estimate: ".len() as u16
+                        + app.state.text_buffers.card_estimate.cursor().1 as u16;
+                    let y_pos = card_chunks[3].top() + estimate_row_offset;
+                    rect.set_cursor_position((x_pos, y_pos));
+                }
+                Focus::CardEstimatedTime | Focus::CardActualTime => {
+                    // The time rows follow the estimate row
+                    let (label, text_buffer, row_offset) =
+                        if app.state.focus == Focus::CardEstimatedTime {
+                            (
+                                ESTIMATED_TIME_LABEL,
+                                &app.state.text_buffers.card_estimated_time,
+                                9,
+                            )
+                        } else {
+                            (
+                                ACTUAL_TIME_LABEL,
+                                &app.state.text_buffers.card_actual_time,
+                                10,
+                            )
+                        };
+                    let x_pos = card_chunks[3].left()
+                        + 1
+                        + label.len() as u16
+                        + text_buffer.cursor().1 as u16;
+                    let y_pos = card_chunks[3].top() + row_offset;
+                    rect.set_cursor_position((x_pos, y_pos));
+                }
+                Focus::CardWaitingOn => {
+                    // Waiting on comes right after the actual time row
+                    let waiting_on_row_offset = 11;
+                    let x_pos = card_chunks[3].left()
+                        + 1
+                        + WAITING_ON_LABEL.len() as u16
+                        + app.state.text_buffers.card_waiting_on.cursor().1 as u16;
+                    let y_pos = card_chunks[3].top() + waiting_on_row_offset;
+                    rect.set_cursor_position((x_pos, y_pos));
+                }
                 Focus::CardTags => {
                     if app
                         .state
@@ -821,12 +1152,12 @@ impl Renderable for ViewCard {
                         if let Some(text_box) = app.state.text_buffers.card_tags.get(selected_index)
                         {
                             let text_box_cursor = text_box.cursor();
-                            let x_pos = card_chunks[3].left()
+                            let x_pos = card_chunks[4].left()
                                 + length_before_selected_tag as u16
                                 + text_box_cursor.1 as u16
                                 + tag_offset
                                 + digits_in_counter as u16;
-                            let y_pos = card_chunks[3].top() + y_index as u16 + 1;
+                            let y_pos = card_chunks[4].top() + y_index as u16 + 1;
 
                             if app.state.focus == Focus::CardTags {
                                 app.widgets.tag_picker.set_anchor(Some((
@@ -885,16 +1216,40 @@ impl Renderable for ViewCard {
                             app.state.text_buffers.card_comments.get(selected_index)
                         {
                             let text_box_cursor = text_box.cursor();
-                            let x_pos = card_chunks[4].left()
+                            let x_pos = card_chunks[5].left()
                                 + length_before_selected_comment as u16
                                 + text_box_cursor.1 as u16
                                 + comment_offset
                                 + digits_in_counter as u16;
-                            let y_pos = card_chunks[4].top() + y_index as u16 + 1;
+                            let y_pos = card_chunks[5].top() + y_index as u16 + 1;
                             rect.set_cursor_position((x_pos, y_pos));
                         }
                     }
                 }
+                Focus::CardChecklist => {
+                    if let Some((selected_index, text_box)) = app
+                        .state
+                        .app_list_states
+                        .card_view_checklist
+                        .selected()
+                        .and_then(|index| {
+                            app.state
+                                .text_buffers
+                                .card_checklist
+                                .get(index)
+                                .map(|text_box| (index, text_box))
+                        })
+                    {
+                        // One item per line, the typed text starts after the "[ ] " box
+                        let checkbox_offset = "[ ] ".len() as u16;
+                        let x_pos = card_chunks[2].left()
+                            + 1
+                            + checkbox_offset
+                            + text_box.cursor().1 as u16;
+                        let y_pos = card_chunks[2].top() + 1 + selected_index as u16;
+                        rect.set_cursor_position((x_pos, y_pos));
+                    }
+                }
                 _ => {}
             }
         }
@@ -906,23 +1261,53 @@ impl Renderable for ViewCard {
             app.state.text_buffers.card_description.widget(),
             card_chunks[1],
         );
-        rect.render_widget(card_extra_info_widget, card_chunks[2]);
-        rect.render_widget(card_tags_widget, card_chunks[3]);
-        rect.render_widget(card_comments_widget, card_chunks[4]);
-
-        // Render Submit button if card is being edited
-        if app.state.card_being_edited.is_some() {
-            if is_active
-                && check_if_mouse_is_in_area(&app.state.current_mouse_coordinates, &card_chunks[5])
-            {
-                app.state.set_focus(Focus::SubmitButton);
-                app.state.mouse_focus = Some(Focus::SubmitButton);
-                app.state
-                    .app_list_states
-                    .card_view_comment_list
-                    .select(None);
-                app.state.app_list_states.card_view_tag_list.select(None);
+        rect.render_widget(card_extra_info_widget, card_chunks[3]);
+        rect.render_widget(card_tags_widget, card_chunks[4]);
+        rect.render_widget(card_comments_widget, card_chunks[5]);
+        rect.render_widget(card_checklist_widget, card_chunks[2]);
+
+        // Save Changes only shows up while the card is being edited, Save as Template always does
+        let (save_changes_chunk, save_as_template_chunk) = if app.state.card_being_edited.is_some()
+        {
+            let button_chunks = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+                .split(card_chunks[6]);
+            (Some(button_chunks[0]), button_chunks[1])
+        } else {
+            (None, card_chunks[6])
+        };
+        for (button_focus, button_chunk) in [
+            (Focus::SubmitButton, save_changes_chunk),
+            (Focus::SaveCardAsTemplate, Some(save_as_template_chunk)),
+        ] {
+            if let Some(button_chunk) = button_chunk {
+                if is_active
+                    && check_if_mouse_is_in_area(
+                        &app.state.current_mouse_coordinates,
+                        &button_chunk,
+                    )
+                {
+                    app.state.set_focus(button_focus);
+                    app.state.mouse_focus = Some(button_focus);
+                    app.state
+                        .app_list_states
+                        .card_view_comment_list
+                        .select(None);
+                    app.state.app_list_states.card_view_tag_list.select(None);
+                }
             }
+        }
+        let save_as_template_button = Paragraph::new("Save as Template")
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+                    .border_style(save_as_template_style),
+            )
+            .alignment(Alignment::Center);
+        rect.render_widget(save_as_template_button, save_as_template_chunk);
+        if let Some(save_changes_chunk) = save_changes_chunk {
             let save_changes_button = Paragraph::new("Save Changes")
                 .block(
                     Block::default()
@@ -932,7 +1317,7 @@ impl Renderable for ViewCard {
                         .border_style(save_changes_style),
                 )
                 .alignment(Alignment::Center);
-            rect.render_widget(save_changes_button, card_chunks[5]);
+            rect.render_widget(save_changes_button, save_changes_chunk);
         }
 
         if app.config.enable_mouse_support {
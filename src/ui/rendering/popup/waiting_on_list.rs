@@ -0,0 +1,98 @@
+use crate::{
+    app::{
+        state::Focus,
+        waiting_on::{WaitingOnGroups, WaitingOnRow},
+        App,
+    },
+    constants::LIST_SELECTED_SYMBOL,
+    i18n::{tr, MessageId},
+    ui::{
+        rendering::{
+            common::{render_blank_styled_canvas, render_close_button},
+            popup::WaitingOnList,
+            utils::{
+                calculate_mouse_list_select_index, centered_rect_with_percentage,
+                check_if_active_and_get_style, check_if_mouse_is_in_area,
+            },
+        },
+        Renderable,
+    },
+};
+use ratatui::{
+    style::Modifier,
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, List, ListItem},
+    Frame,
+};
+
+impl Renderable for WaitingOnList {
+    fn render(rect: &mut Frame, app: &mut App, is_active: bool) {
+        let general_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.general_style,
+        );
+        let help_text_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.help_text_style,
+        );
+        let list_select_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.list_select_style,
+        );
+        let rows = WaitingOnGroups::new(&app.boards)
+            .rows
+            .into_iter()
+            .map(|row| match row {
+                WaitingOnRow::Group {
+                    waiting_on,
+                    card_count,
+                } => ListItem::new(vec![Line::from(vec![
+                    Span::styled(
+                        format!("⏳ {}", waiting_on),
+                        general_style.add_modifier(Modifier::BOLD),
+                    ),
+                    Span::styled(format!(" ({})", card_count), help_text_style),
+                ])]),
+                WaitingOnRow::Card(waiting_card) => ListItem::new(vec![Line::from(vec![
+                    Span::styled(format!("  {}", waiting_card.card_name), general_style),
+                    Span::styled(format!(" ({})", waiting_card.board_name), help_text_style),
+                ])]),
+            })
+            .collect::<Vec<ListItem>>();
+        let percent_height = (((rows.len() + 3) as f32 / rect.area().height as f32) * 100.0) as u16;
+        let popup_area = centered_rect_with_percentage(50, percent_height, rect.area());
+        if check_if_mouse_is_in_area(&app.state.current_mouse_coordinates, &popup_area) {
+            app.state.mouse_focus = Some(Focus::WaitingOnListPopup);
+            app.state.set_focus(Focus::WaitingOnListPopup);
+            calculate_mouse_list_select_index(
+                app.state.current_mouse_coordinates.1,
+                &rows,
+                popup_area,
+                &mut app.state.app_list_states.waiting_on_list,
+            );
+        }
+        let waiting_on_list = List::new(rows)
+            .block(
+                Block::default()
+                    .title(tr(MessageId::TitleWaitingOn))
+                    .style(general_style)
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded),
+            )
+            .highlight_style(list_select_style)
+            .highlight_symbol(LIST_SELECTED_SYMBOL);
+
+        render_blank_styled_canvas(rect, &app.current_theme, popup_area, is_active);
+        rect.render_stateful_widget(
+            waiting_on_list,
+            popup_area,
+            &mut app.state.app_list_states.waiting_on_list,
+        );
+        if app.config.enable_mouse_support {
+            render_close_button(rect, app, is_active);
+        }
+    }
+}
@@ -0,0 +1,116 @@
+use crate::{
+    app::App,
+    constants::{SCROLLBAR_BEGIN_SYMBOL, SCROLLBAR_END_SYMBOL, SCROLLBAR_TRACK_SYMBOL},
+    i18n::{tr, MessageId},
+    ui::{
+        rendering::{
+            common::{render_blank_styled_canvas, render_close_button},
+            popup::WhatsNew,
+            utils::{centered_rect_with_length, check_if_active_and_get_style},
+        },
+        Renderable,
+    },
+};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Margin},
+    style::Modifier,
+    text::{Line, Span},
+    widgets::{
+        Block, BorderType, Borders, Paragraph, Scrollbar, ScrollbarOrientation, ScrollbarState,
+        Wrap,
+    },
+    Frame,
+};
+
+impl Renderable for WhatsNew {
+    fn render(rect: &mut Frame, app: &mut App, is_active: bool) {
+        let popup_area = centered_rect_with_length(80, 24, rect.area());
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Fill(1), Constraint::Length(1)].as_ref())
+            .margin(1)
+            .split(popup_area);
+
+        let general_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.general_style,
+        );
+        let version_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.help_key_style,
+        );
+        let scrollbar_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.progress_bar_style,
+        );
+
+        let mut changelog_lines = vec![];
+        for entry in app.state.whats_new_entries.iter() {
+            if !changelog_lines.is_empty() {
+                changelog_lines.push(Line::from(""));
+            }
+            changelog_lines.push(Line::from(Span::styled(
+                format!("Version {}", entry.version),
+                version_style.add_modifier(Modifier::BOLD),
+            )));
+            for change in entry.changes.iter() {
+                changelog_lines.push(Line::from(Span::styled(
+                    format!("  • {}", change),
+                    general_style,
+                )));
+            }
+        }
+        if changelog_lines.is_empty() {
+            changelog_lines.push(Line::from(Span::styled(
+                "Nothing new in this version",
+                general_style,
+            )));
+        }
+
+        // Lines are short enough that wrapping rarely kicks in, the line count is a close
+        // enough bound for the scroll range
+        let line_count = changelog_lines.len() as u16;
+        let max_scroll = line_count.saturating_sub(chunks[0].height.saturating_sub(2));
+        app.state.whats_new_scroll = app.state.whats_new_scroll.min(max_scroll);
+
+        let changelog_paragraph = Paragraph::new(changelog_lines)
+            .block(
+                Block::default()
+                    .title(tr(MessageId::TitleWhatsNew))
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+                    .border_style(general_style),
+            )
+            .wrap(Wrap { trim: false })
+            .scroll((app.state.whats_new_scroll, 0));
+        let help_paragraph = Paragraph::new("Up/Down to scroll, Esc to close")
+            .alignment(Alignment::Center)
+            .style(general_style);
+
+        render_blank_styled_canvas(rect, &app.current_theme, popup_area, is_active);
+        rect.render_widget(changelog_paragraph, chunks[0]);
+        if max_scroll > 0 {
+            let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight)
+                .begin_symbol(SCROLLBAR_BEGIN_SYMBOL)
+                .style(scrollbar_style)
+                .end_symbol(SCROLLBAR_END_SYMBOL)
+                .track_symbol(SCROLLBAR_TRACK_SYMBOL)
+                .track_style(app.current_theme.inactive_text_style);
+            let mut scrollbar_state = ScrollbarState::new(max_scroll as usize + 1)
+                .position(app.state.whats_new_scroll as usize);
+            let scrollbar_area = chunks[0].inner(Margin {
+                vertical: 1,
+                horizontal: 0,
+            });
+            rect.render_stateful_widget(scrollbar, scrollbar_area, &mut scrollbar_state);
+        }
+        rect.render_widget(help_paragraph, chunks[1]);
+        if app.config.enable_mouse_support {
+            render_close_button(rect, app, is_active)
+        }
+    }
+}
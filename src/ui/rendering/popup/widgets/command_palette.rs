@@ -4,17 +4,22 @@ use crate::{
         App,
     },
     constants::{
-        LIST_SELECTED_SYMBOL, SCROLLBAR_BEGIN_SYMBOL, SCROLLBAR_END_SYMBOL, SCROLLBAR_TRACK_SYMBOL,
+        LIST_SELECTED_SYMBOL, MIN_SEARCH_SNIPPET_WIDTH, SCROLLBAR_BEGIN_SYMBOL,
+        SCROLLBAR_END_SYMBOL, SCROLLBAR_TRACK_SYMBOL,
     },
+    i18n::{tr, MessageId},
+    inputs::key::Key,
     ui::{
         rendering::{
             common::{render_blank_styled_canvas, render_close_button},
             popup::widgets::CommandPalette,
             utils::{
                 calculate_viewport_corrected_cursor_position, check_if_active_and_get_style,
-                check_if_mouse_is_in_area, get_scrollable_widget_row_bounds,
+                check_if_mouse_is_in_area, get_match_snippet, get_scrollable_widget_row_bounds,
+                highlight_matched_chars,
             },
         },
+        widgets::command_palette::{CardSearchField, CardSearchResult, CommandPaletteWidget},
         Renderable,
     },
 };
@@ -28,6 +33,7 @@ use ratatui::{
     },
     Frame,
 };
+use unicode_width::UnicodeWidthStr;
 
 impl Renderable for CommandPalette {
     fn render(rect: &mut Frame, app: &mut App, is_active: bool) {
@@ -152,6 +158,11 @@ impl Renderable for CommandPalette {
             app.current_theme.inactive_text_style,
             app.current_theme.help_text_style,
         );
+        let error_text_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.error_text_style,
+        );
         let progress_bar_style = check_if_active_and_get_style(
             is_active,
             app.current_theme.inactive_text_style,
@@ -163,58 +174,68 @@ impl Renderable for CommandPalette {
             general_style
         };
 
-        let command_search_results =
-            if let Some(raw_search_results) = &app.widgets.command_palette.command_search_results {
-                let mut list_items = vec![];
-                for item in raw_search_results {
-                    let mut spans = vec![];
-                    for c in item.to_string().chars() {
-                        if current_search_text_input
-                            .to_lowercase()
-                            .contains(c.to_string().to_lowercase().as_str())
-                        {
-                            spans.push(Span::styled(c.to_string(), keyboard_focus_style));
-                        } else {
-                            spans.push(Span::styled(c.to_string(), command_search_text_style));
-                        }
-                    }
-                    list_items.push(ListItem::new(Line::from(spans)));
-                }
-                list_items
-            } else {
-                app.widgets
-                    .command_palette
-                    .available_commands
-                    .iter()
-                    .map(|c| ListItem::new(Line::from(format!("Command - {}", c))))
-                    .collect::<Vec<ListItem>>()
-            };
-
-        let card_search_results = if app.widgets.command_palette.card_search_results.is_some()
-            && !current_search_text_input.is_empty()
-            && current_search_text_input.len() > 1
+        // Outer margin, list borders and the highlight symbol
+        let command_row_width = (horizontal_chunks[1].width as usize)
+            .saturating_sub(4 + LIST_SELECTED_SYMBOL.chars().count());
+        let command_search_results = if let Some(quick_entry_error) =
+            &app.widgets.command_palette.quick_entry_error
+        {
+            vec![ListItem::new(Line::from(Span::styled(
+                quick_entry_error.clone(),
+                error_text_style,
+            )))]
+        } else if let Some(raw_search_results) = &app.widgets.command_palette.command_search_results
         {
-            let raw_search_results = app
-                .widgets
-                .command_palette
-                .card_search_results
-                .as_ref()
-                .unwrap();
             let mut list_items = vec![];
-            for (item, _) in raw_search_results {
-                let item = if item.len() > (horizontal_chunks[1].width - 2) as usize {
-                    format!("{}...", &item[0..(horizontal_chunks[1].width - 5) as usize])
-                } else {
-                    item.to_string()
-                };
-                list_items.push(ListItem::new(Line::from(Span::styled(
-                    item.to_string(),
-                    card_search_text_style,
-                ))));
+            for item in raw_search_results {
+                let item_text = item.to_string();
+                let bound_keys = item
+                    .keybinding_enum()
+                    .and_then(|keybinding_enum| {
+                        app.config.keybindings.get_keybindings(keybinding_enum)
+                    })
+                    .map(|keys| {
+                        keys.iter()
+                            .map(|key| key.to_string())
+                            .collect::<Vec<String>>()
+                            .join(", ")
+                    })
+                    .unwrap_or_default();
+                let matched_indices =
+                    CommandPaletteWidget::fuzzy_match(&current_search_text_input, &item_text)
+                        .map(|fuzzy_match| fuzzy_match.matched_indices)
+                        .unwrap_or_default();
+                let mut spans = highlight_matched_chars(
+                    &item_text,
+                    &matched_indices,
+                    command_row_width,
+                    command_search_text_style,
+                    keyboard_focus_style,
+                );
+                if !bound_keys.is_empty() {
+                    let padding = command_row_width
+                        .saturating_sub(item_text.chars().count() + bound_keys.chars().count());
+                    spans.push(Span::raw(" ".repeat(padding.max(1))));
+                    spans.push(Span::styled(bound_keys, help_key_style));
+                }
+                list_items.push(ListItem::new(Line::from(spans)));
             }
             list_items
         } else {
-            vec![]
+            app.widgets
+                .command_palette
+                .available_commands
+                .iter()
+                .map(|c| ListItem::new(Line::from(format!("Command - {}", c))))
+                .collect::<Vec<ListItem>>()
+        };
+
+        // The rows are built once the list height is known, see below
+        let show_card_search_results =
+            !current_search_text_input.is_empty() && current_search_text_input.len() > 1;
+        let card_search_results_count = match &app.widgets.command_palette.card_search_results {
+            Some(raw_search_results) if show_card_search_results => raw_search_results.len(),
+            _ => 0,
         };
 
         let board_search_results = if app.widgets.command_palette.board_search_results.is_some()
@@ -228,15 +249,13 @@ impl Renderable for CommandPalette {
                 .as_ref()
                 .unwrap();
             let mut list_items = vec![];
-            for (item, _) in raw_search_results {
-                let item = if item.len() > (horizontal_chunks[1].width - 2) as usize {
-                    format!("{}...", &item[0..(horizontal_chunks[1].width - 5) as usize])
-                } else {
-                    item.to_string()
-                };
-                list_items.push(ListItem::new(Line::from(Span::styled(
-                    item.to_string(),
+            for search_result in raw_search_results {
+                list_items.push(ListItem::new(Line::from(highlight_matched_chars(
+                    &search_result.search_helper,
+                    &search_result.matched_indices,
+                    command_row_width,
                     board_search_text_style,
+                    keyboard_focus_style,
                 ))));
             }
             list_items
@@ -245,19 +264,19 @@ impl Renderable for CommandPalette {
         };
 
         let max_height = if app.state.user_login_data.auth_token.is_some() {
-            (rect.area().height - 14) as usize
+            rect.area().height.saturating_sub(14) as usize
         } else {
-            (rect.area().height - 12) as usize
+            rect.area().height.saturating_sub(12) as usize
         };
         let min_height = 2;
         let command_search_results_length = command_search_results.len() + 2;
-        let card_search_results_length = card_search_results.len() + 2;
+        let card_search_results_length = card_search_results_count + 2;
         let board_search_results_length = board_search_results.len() + 2;
         let command_search_results_length = if command_search_results_length >= min_height {
             if (command_search_results_length + (2 * min_height)) < max_height {
                 command_search_results_length
             } else {
-                let calc = max_height - (2 * min_height);
+                let calc = max_height.saturating_sub(2 * min_height);
                 if calc < min_height {
                     min_height
                 } else {
@@ -273,7 +292,7 @@ impl Renderable for CommandPalette {
             {
                 card_search_results_length
             } else {
-                let calc = max_height - (command_search_results_length + min_height);
+                let calc = max_height.saturating_sub(command_search_results_length + min_height);
                 if calc < min_height {
                     min_height
                 } else {
@@ -291,8 +310,9 @@ impl Renderable for CommandPalette {
             {
                 board_search_results_length
             } else {
-                let calc = max_height
-                    - (command_search_results_length + card_search_results_length + min_height);
+                let calc = max_height.saturating_sub(
+                    command_search_results_length + card_search_results_length + min_height,
+                );
                 if calc < min_height {
                     min_height
                 } else {
@@ -435,7 +455,65 @@ impl Renderable for CommandPalette {
             .highlight_style(command_search_highlight_style)
             .highlight_symbol(LIST_SELECTED_SYMBOL);
 
-        let card_search_results_list = List::new(card_search_results.clone())
+        // Context snippets are only looked up for the rows that fit in the list
+        let (first_visible_card_row, last_visible_card_row) = get_scrollable_widget_row_bounds(
+            card_search_results_count,
+            app.state
+                .app_list_states
+                .command_palette_card_search
+                .selected()
+                .unwrap_or(0),
+            app.state
+                .app_list_states
+                .command_palette_card_search
+                .offset(),
+            search_results_chunks[1].height.saturating_sub(2) as usize,
+        );
+        let mut card_search_results = vec![];
+        if let Some(raw_search_results) = app
+            .widgets
+            .command_palette
+            .card_search_results
+            .as_ref()
+            .filter(|_| show_card_search_results)
+        {
+            for (index, search_result) in raw_search_results.iter().enumerate() {
+                let snippet_line =
+                    if (first_visible_card_row..=last_visible_card_row).contains(&index) {
+                        get_card_search_snippet_line(app, search_result)
+                    } else {
+                        None
+                    };
+                let helper_width = search_result.search_helper.width();
+                let snippet_width = command_row_width.saturating_sub(helper_width + 2);
+                let snippet = snippet_line
+                    .filter(|_| snippet_width >= MIN_SEARCH_SNIPPET_WIDTH)
+                    .and_then(|line| {
+                        get_match_snippet(&line, &current_search_text_input, snippet_width)
+                    });
+                let mut spans = vec![];
+                if let Some((before, matched, after)) = snippet {
+                    spans.push(Span::styled(
+                        format!("{}: ", search_result.search_helper),
+                        card_search_text_style,
+                    ));
+                    spans.push(Span::styled(before, card_search_text_style));
+                    spans.push(Span::styled(matched, keyboard_focus_style));
+                    spans.push(Span::styled(after, card_search_text_style));
+                } else {
+                    spans.extend(highlight_matched_chars(
+                        &search_result.search_helper,
+                        &search_result.matched_indices,
+                        command_row_width,
+                        card_search_text_style,
+                        keyboard_focus_style,
+                    ));
+                }
+                card_search_results.push(ListItem::new(Line::from(spans)));
+            }
+        }
+
+        let card_search_results_list = List::new(card_search_results)
             .block(
                 Block::default()
                     .title("Cards")
@@ -449,7 +527,7 @@ impl Renderable for CommandPalette {
         let board_search_results_list = List::new(board_search_results.clone())
             .block(
                 Block::default()
-                    .title("Boards")
+                    .title(tr(MessageId::TitleBoards))
                     .border_style(board_search_border_style)
                     .borders(Borders::ALL)
                     .border_type(BorderType::Rounded),
@@ -487,13 +565,15 @@ impl Renderable for CommandPalette {
             Span::styled(next_focus_key, help_key_style),
             Span::styled(" or ", help_text_style),
             Span::styled(prv_focus_key, help_key_style),
-            Span::styled(" to change focus", help_text_style),
+            Span::styled(" to change focus. Press ", help_text_style),
+            Span::styled(Key::Ctrl('b').to_string(), help_key_style),
+            Span::styled(" to edit the keybinding of a Command", help_text_style),
         ]);
 
         let help_paragraph = Paragraph::new(help_spans)
             .block(
                 Block::default()
-                    .title("Help")
+                    .title(tr(MessageId::TitleHelp))
                     .borders(Borders::ALL)
                     .border_type(BorderType::Rounded)
                     .style(general_style),
@@ -579,7 +659,7 @@ impl Renderable for CommandPalette {
                     .app_list_states
                     .command_palette_command_search
                     .offset(),
-                (search_results_chunks[0].height - 2) as usize,
+                search_results_chunks[0].height.saturating_sub(2) as usize,
             );
             let current_mouse_y_position = app.state.current_mouse_coordinates.1;
             let hovered_index = if current_mouse_y_position > search_results_chunks[0].y
@@ -629,7 +709,7 @@ impl Renderable for CommandPalette {
                     .app_list_states
                     .command_palette_card_search
                     .offset(),
-                (search_results_chunks[1].height - 2) as usize,
+                search_results_chunks[1].height.saturating_sub(2) as usize,
             );
             let current_mouse_y_position = app.state.current_mouse_coordinates.1;
             let hovered_index = if current_mouse_y_position > search_results_chunks[1].y
@@ -659,7 +739,7 @@ impl Renderable for CommandPalette {
                 .track_style(app.current_theme.inactive_text_style);
 
             let mut scrollbar_state =
-                ScrollbarState::new(card_search_results.len()).position(current_index);
+                ScrollbarState::new(card_search_results_count).position(current_index);
             let scrollbar_area = search_results_chunks[1].inner(Margin {
                 horizontal: 0,
                 vertical: 1,
@@ -679,7 +759,7 @@ impl Renderable for CommandPalette {
                     .app_list_states
                     .command_palette_board_search
                     .offset(),
-                (search_results_chunks[2].height - 2) as usize,
+                search_results_chunks[2].height.saturating_sub(2) as usize,
             );
             let current_mouse_y_position = app.state.current_mouse_coordinates.1;
             let hovered_index = if current_mouse_y_position > search_results_chunks[2].y
@@ -724,3 +804,18 @@ impl Renderable for CommandPalette {
         }
     }
 }
+
+/// The line of the description or comment the search matched on, None for name and tag matches
+/// as the row already shows those
+fn get_card_search_snippet_line(app: &App, search_result: &CardSearchResult) -> Option<String> {
+    let card = app
+        .boards
+        .find_board_with_card_id(search_result.card_id)
+        .and_then(|(_, board)| board.cards.get_card_with_id(search_result.card_id))?;
+    let (text, line) = match search_result.field {
+        CardSearchField::Description { line } => (&card.description, line),
+        CardSearchField::Comment { comment, line } => (card.comments.get(comment)?, line),
+        CardSearchField::Name | CardSearchField::Tags | CardSearchField::WaitingOn => return None,
+    };
+    text.split('\n').nth(line).map(str::to_string)
+}
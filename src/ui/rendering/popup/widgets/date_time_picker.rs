@@ -33,7 +33,8 @@ impl Renderable for DateTimePicker {
             y: anchor.1,
             width: app.widgets.date_time_picker.widget_width,
             height: app.widgets.date_time_picker.widget_height,
-        };
+        }
+        .intersection(rect.area());
 
         app.widgets
             .date_time_picker
@@ -41,12 +42,11 @@ impl Renderable for DateTimePicker {
 
         // 3 is for the " - ", additional 4 is to compensate for the borders that show when focus is on month or year
         let title_length = (current_month.len() + 3 + current_year.len() + 4) as u16;
-        let padding = (render_area
+        let padding = render_area
             .width
             .min(app.widgets.date_time_picker.date_target_width)
-            - 3
-            - 2)
-        .saturating_sub(title_length); // 3 is for the Time section expand button, 2 is for margin
+            .saturating_sub(3 + 2)
+            .saturating_sub(title_length); // 3 is for the Time section expand button, 2 is for margin
         let month_length = current_month.len() as u16 + (padding / 2) + 2;
         let year_length = current_year.len() as u16 + (padding / 2) + 2;
 
@@ -4,4 +4,4 @@ pub mod tag_picker;
 
 pub struct CommandPalette;
 pub struct DateTimePicker;
-pub struct TagPicker;
\ No newline at end of file
+pub struct TagPicker;
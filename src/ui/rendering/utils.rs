@@ -1,6 +1,7 @@
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
     style::Style,
+    text::Span,
     widgets::ListState,
 };
 
@@ -9,9 +10,13 @@ use crate::{
         state::{AppStatus, Focus},
         App,
     },
+    constants::{MIN_BODY_HEIGHT, MIN_SECONDARY_PANE_HEIGHT},
     ui::text_box::TextBox,
     util::num_digits,
 };
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+const TRUNCATION_ELLIPSIS: &str = "...";
 
 /// Checks for popup to return inactive style if not returns the style passed
 pub fn check_if_active_and_get_style(
@@ -47,7 +52,8 @@ pub fn check_if_mouse_is_in_area(mouse_coordinates: &(u16, u16), rect_to_check:
         rect_to_check.x + rect_to_check.width,
         rect_to_check.y + rect_to_check.height,
     );
-    if x >= &x1 && x <= &x2 && y >= &y1 && y <= &y2 {
+    // The far edges belong to the next area, one row cards would otherwise overlap
+    if x >= &x1 && x < &x2 && y >= &y1 && y < &y2 {
         return true;
     }
     false
@@ -80,6 +86,7 @@ pub fn centered_rect_with_percentage(percent_width: u16, percent_height: u16, r:
 }
 
 pub fn centered_rect_with_length(width: u16, height: u16, r: Rect) -> Rect {
+    let (width, height) = (width.min(r.width), height.min(r.height));
     let popup_layout = Layout::default()
         .direction(Direction::Vertical)
         .constraints(
@@ -106,6 +113,7 @@ pub fn centered_rect_with_length(width: u16, height: u16, r: Rect) -> Rect {
 }
 
 pub fn top_left_rect(width: u16, height: u16, r: Rect) -> Rect {
+    let (width, height) = (width.min(r.width), height.min(r.height));
     let popup_layout = Layout::default()
         .direction(Direction::Vertical)
         .constraints(
@@ -190,6 +198,25 @@ pub fn get_button_style(
     }
 }
 
+/// Height of each of the `pane_count` help and log panes stacked under the body, they give way
+/// before the body drops under its minimum height
+pub fn get_secondary_pane_height(
+    app: &mut App,
+    render_area: Rect,
+    title_height: u16,
+    pane_count: u16,
+) -> u16 {
+    let max_height = (render_area
+        .height
+        .saturating_sub(title_height + MIN_BODY_HEIGHT)
+        / pane_count.max(1))
+    .max(MIN_SECONDARY_PANE_HEIGHT);
+    app.state.max_secondary_pane_height = Some(max_height);
+    app.state
+        .secondary_pane_height
+        .clamp(MIN_SECONDARY_PANE_HEIGHT, max_height)
+}
+
 pub fn get_scrollable_widget_row_bounds(
     all_rows_len: usize,
     selected_index: usize,
@@ -309,3 +336,219 @@ pub fn calculate_mouse_list_select_index<T>(
         list_state.select(Some((mouse_y - top_of_list) as usize));
     }
 }
+
+/// Shortens the text to fit in max_width columns, ending it with an ellipsis if anything was cut
+pub fn truncate_to_width(text: &str, max_width: usize) -> String {
+    if text.width() <= max_width {
+        text.to_string()
+    } else {
+        end_with_ellipsis(text, max_width)
+    }
+}
+
+/// Cuts the text to fit like truncate_to_width and styles the chars at matched_indices with
+/// highlight_style, the ellipsis is never highlighted
+pub fn highlight_matched_chars(
+    text: &str,
+    matched_indices: &[usize],
+    max_width: usize,
+    style: Style,
+    highlight_style: Style,
+) -> Vec<Span<'static>> {
+    let shown_text = truncate_to_width(text, max_width);
+    let kept_chars = if shown_text == text {
+        shown_text.chars().count()
+    } else {
+        shown_text
+            .chars()
+            .count()
+            .saturating_sub(TRUNCATION_ELLIPSIS.chars().count())
+    };
+    let mut spans = vec![];
+    let mut run = String::new();
+    let mut run_highlighted = false;
+    for (index, c) in shown_text.chars().enumerate() {
+        let highlighted = index < kept_chars && matched_indices.contains(&index);
+        if highlighted != run_highlighted && !run.is_empty() {
+            let run_style = if run_highlighted {
+                highlight_style
+            } else {
+                style
+            };
+            spans.push(Span::styled(std::mem::take(&mut run), run_style));
+        }
+        run_highlighted = highlighted;
+        run.push(c);
+    }
+    if !run.is_empty() {
+        let run_style = if run_highlighted {
+            highlight_style
+        } else {
+            style
+        };
+        spans.push(Span::styled(run, run_style));
+    }
+    spans
+}
+
+fn end_with_ellipsis(text: &str, max_width: usize) -> String {
+    let ellipsis_width = TRUNCATION_ELLIPSIS.width();
+    if max_width <= ellipsis_width {
+        return ".".repeat(max_width);
+    }
+    let mut truncated = String::new();
+    let mut truncated_width = 0;
+    for c in text.chars() {
+        let char_width = c.width().unwrap_or(0);
+        if truncated_width + char_width + ellipsis_width > max_width {
+            break;
+        }
+        truncated.push(c);
+        truncated_width += char_width;
+    }
+    truncated.push_str(TRUNCATION_ELLIPSIS);
+    truncated
+}
+
+/// Keeps the end of the text closest to what follows it, the mirror of end_with_ellipsis
+fn start_with_ellipsis(text: &str, max_width: usize) -> String {
+    let ellipsis_width = TRUNCATION_ELLIPSIS.width();
+    if max_width <= ellipsis_width {
+        return ".".repeat(max_width);
+    }
+    let mut kept = vec![];
+    let mut kept_width = 0;
+    for c in text.chars().rev() {
+        let char_width = c.width().unwrap_or(0);
+        if kept_width + char_width + ellipsis_width > max_width {
+            break;
+        }
+        kept.push(c);
+        kept_width += char_width;
+    }
+    format!(
+        "{}{}",
+        TRUNCATION_ELLIPSIS,
+        kept.into_iter().rev().collect::<String>()
+    )
+}
+
+/// Finds the first case insensitive match of the query in the line and cuts the line down to
+/// max_width columns around it, keeping about as much before the match as after. Returns the
+/// text before the match, the match and the text after, None when the line does not contain
+/// the query
+pub fn get_match_snippet(
+    line: &str,
+    query: &str,
+    max_width: usize,
+) -> Option<(String, String, String)> {
+    let query_chars = query
+        .chars()
+        .flat_map(char::to_lowercase)
+        .collect::<Vec<char>>();
+    if query_chars.is_empty() {
+        return None;
+    }
+    let line_chars = line
+        .chars()
+        .map(|c| if c == '\t' { ' ' } else { c })
+        .collect::<Vec<char>>();
+    // Lowercasing can turn one char into several, each keeps the index of the char it came from
+    let lowercase_chars = line_chars
+        .iter()
+        .enumerate()
+        .flat_map(|(index, c)| c.to_lowercase().map(move |lowercase| (lowercase, index)))
+        .collect::<Vec<(char, usize)>>();
+    let match_start = lowercase_chars
+        .windows(query_chars.len())
+        .position(|window| window.iter().map(|(c, _)| c).eq(query_chars.iter()))?;
+    let start = lowercase_chars[match_start].1;
+    let end = lowercase_chars[match_start + query_chars.len() - 1].1 + 1;
+    let before = line_chars[..start]
+        .iter()
+        .skip_while(|c| c.is_whitespace())
+        .collect::<String>();
+    let matched = line_chars[start..end].iter().collect::<String>();
+    let after = line_chars[end..].iter().collect::<String>();
+
+    let (before_width, matched_width, after_width) =
+        (before.width(), matched.width(), after.width());
+    if before_width + matched_width + after_width <= max_width {
+        return Some((before, matched, after));
+    }
+    if matched_width + TRUNCATION_ELLIPSIS.width() >= max_width {
+        return Some((
+            String::new(),
+            truncate_to_width(&matched, max_width),
+            String::new(),
+        ));
+    }
+    // Space one side does not need goes to the other
+    let space_left = max_width - matched_width;
+    let mut before_space = space_left / 2;
+    let mut after_space = space_left - before_space;
+    if after_width < after_space {
+        before_space += after_space - after_width;
+        after_space = after_width;
+    } else if before_width < before_space {
+        after_space += before_space - before_width;
+        before_space = before_width;
+    }
+    let before = if before_width <= before_space {
+        before
+    } else {
+        start_with_ellipsis(&before, before_space)
+    };
+    Some((before, matched, truncate_to_width(&after, after_space)))
+}
+
+fn wrap_to_width(line: &str, max_width: usize) -> impl Iterator<Item = String> + '_ {
+    let mut chars = line.chars().peekable();
+    let mut is_first_segment = true;
+    std::iter::from_fn(move || {
+        if chars.peek().is_none() {
+            // Blank lines still take up a row
+            return if std::mem::take(&mut is_first_segment) {
+                Some(String::new())
+            } else {
+                None
+            };
+        }
+        is_first_segment = false;
+        let mut segment = String::new();
+        let mut segment_width = 0;
+        while let Some(&c) = chars.peek() {
+            let char_width = c.width().unwrap_or(0);
+            if segment_width + char_width > max_width && !segment.is_empty() {
+                break;
+            }
+            segment.push(c);
+            segment_width += char_width;
+            chars.next();
+        }
+        Some(segment)
+    })
+}
+
+/// Wraps the text into at most max_lines rows of max_width columns, the text past the last row
+/// is never laid out and the last row gets an ellipsis when something was left out
+pub fn get_preview_lines(text: &str, max_width: usize, max_lines: usize) -> Vec<String> {
+    if max_width == 0 || max_lines == 0 {
+        return vec![];
+    }
+    let mut wrapped_lines = text.lines().flat_map(|line| wrap_to_width(line, max_width));
+    let mut preview_lines = wrapped_lines
+        .by_ref()
+        .take(max_lines)
+        .collect::<Vec<String>>();
+    if wrapped_lines.next().is_some() {
+        if let Some(last_line) = preview_lines.last_mut() {
+            *last_line = if last_line.width() + TRUNCATION_ELLIPSIS.width() <= max_width {
+                format!("{}{}", last_line, TRUNCATION_ELLIPSIS)
+            } else {
+                end_with_ellipsis(last_line, max_width)
+            };
+        }
+    }
+    preview_lines
+}
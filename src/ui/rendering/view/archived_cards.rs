@@ -0,0 +1,176 @@
+use crate::{
+    app::{
+        kanban::CardStatus,
+        state::{Focus, KeyBindingEnum},
+        App,
+    },
+    constants::LIST_SELECTED_SYMBOL,
+    i18n::{tr, MessageId},
+    ui::{
+        rendering::{
+            common::render_close_button, utils::check_if_active_and_get_style, view::ArchivedCards,
+        },
+        Renderable,
+    },
+};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+
+impl Renderable for ArchivedCards {
+    fn render(rect: &mut Frame, app: &mut App, is_active: bool) {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(
+                [
+                    Constraint::Length(3),
+                    Constraint::Fill(1),
+                    Constraint::Length(3),
+                ]
+                .as_ref(),
+            )
+            .split(rect.area());
+
+        let general_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.general_style,
+        );
+        let list_select_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.list_select_style,
+        );
+        let help_key_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.help_key_style,
+        );
+        let help_text_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.help_text_style,
+        );
+        let list_border_style = if is_active && app.state.focus == Focus::Body {
+            app.current_theme.keyboard_focus_style
+        } else {
+            general_style
+        };
+
+        let archived_cards = app.boards.get_archived_cards();
+        let title_paragraph = Paragraph::new(format!(
+            "{} archived card{}",
+            archived_cards.len(),
+            if archived_cards.len() == 1 { "" } else { "s" }
+        ))
+        .alignment(Alignment::Center)
+        .block(
+            Block::default()
+                .title(tr(MessageId::TitleArchivedCards))
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .style(general_style),
+        );
+
+        // Every board gets a header row, so the selected card is looked up by its row
+        let selected_card_index = app.state.app_list_states.archived_cards.selected();
+        let mut selected_row = None;
+        let mut list_items = Vec::new();
+        let mut previous_board_id = None;
+        for (card_index, (board_id, card)) in archived_cards.iter().enumerate() {
+            if previous_board_id != Some(*board_id) {
+                let board_name = app
+                    .boards
+                    .get_board_with_id(*board_id)
+                    .map(|board| board.name.clone())
+                    .unwrap_or_default();
+                list_items.push(ListItem::new(Line::from(Span::styled(
+                    board_name,
+                    help_key_style,
+                ))));
+                previous_board_id = Some(*board_id);
+            }
+            if selected_card_index == Some(card_index) {
+                selected_row = Some(list_items.len());
+            }
+            let status_before_archive = card
+                .status_before_archive
+                .clone()
+                .unwrap_or(CardStatus::Active);
+            list_items.push(ListItem::new(Line::from(vec![
+                Span::styled(format!("  {}", card.name), general_style),
+                Span::styled(
+                    format!(
+                        " · was {} · modified {}",
+                        status_before_archive, card.date_modified
+                    ),
+                    help_text_style,
+                ),
+            ])));
+        }
+        let archived_cards_list = if list_items.is_empty() {
+            List::new(vec![ListItem::new(Line::from(Span::styled(
+                "No archived cards",
+                general_style,
+            )))])
+        } else {
+            List::new(list_items)
+        }
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(list_border_style),
+        )
+        .highlight_style(list_select_style)
+        .highlight_symbol(LIST_SELECTED_SYMBOL);
+        let mut archived_cards_list_state = ListState::default().with_selected(selected_row);
+
+        let up_key = app
+            .get_first_keybinding(KeyBindingEnum::Up)
+            .unwrap_or("".to_string());
+        let down_key = app
+            .get_first_keybinding(KeyBindingEnum::Down)
+            .unwrap_or("".to_string());
+        let accept_key = app
+            .get_first_keybinding(KeyBindingEnum::Accept)
+            .unwrap_or("".to_string());
+        let cancel_key = app
+            .get_first_keybinding(KeyBindingEnum::GoToPreviousViewOrCancel)
+            .unwrap_or("".to_string());
+        let help_paragraph = Paragraph::new(Line::from(vec![
+            Span::styled("Use ", help_text_style),
+            Span::styled(up_key, help_key_style),
+            Span::styled(" and ", help_text_style),
+            Span::styled(down_key, help_key_style),
+            Span::styled(" to pick a card. Press ", help_text_style),
+            Span::styled(accept_key, help_key_style),
+            Span::styled(" to restore it or ", help_text_style),
+            Span::styled(cancel_key, help_key_style),
+            Span::styled(" to go back", help_text_style),
+        ]))
+        .alignment(Alignment::Center)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(general_style),
+        )
+        .wrap(ratatui::widgets::Wrap { trim: true });
+
+        rect.render_widget(title_paragraph, chunks[0]);
+        rect.render_stateful_widget(
+            archived_cards_list,
+            chunks[1],
+            &mut archived_cards_list_state,
+        );
+        rect.render_widget(help_paragraph, chunks[2]);
+
+        if app.config.enable_mouse_support {
+            render_close_button(rect, app, is_active);
+        }
+    }
+}
@@ -3,7 +3,7 @@ use crate::{
     ui::{
         rendering::{
             common::{draw_help, render_body, render_card_being_dragged, render_close_button},
-            utils::check_if_active_and_get_style,
+            utils::{check_if_active_and_get_style, get_secondary_pane_height},
             view::BodyHelp,
         },
         Renderable,
@@ -17,9 +17,16 @@ use ratatui::{
 
 impl Renderable for BodyHelp {
     fn render(rect: &mut Frame, app: &mut App, is_active: bool) {
+        let secondary_pane_height = get_secondary_pane_height(app, rect.area(), 0, 1);
         let chunks = Layout::default()
             .direction(Direction::Vertical)
-            .constraints([Constraint::Fill(1), Constraint::Length(5)].as_ref())
+            .constraints(
+                [
+                    Constraint::Fill(1),
+                    Constraint::Length(secondary_pane_height),
+                ]
+                .as_ref(),
+            )
             .split(rect.area());
 
         let help_chunks = Layout::default()
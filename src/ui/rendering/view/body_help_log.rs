@@ -5,7 +5,7 @@ use crate::{
             common::{
                 draw_help, render_body, render_card_being_dragged, render_close_button, render_logs,
             },
-            utils::check_if_active_and_get_style,
+            utils::{check_if_active_and_get_style, get_secondary_pane_height},
             view::BodyHelpLog,
         },
         Renderable,
@@ -19,13 +19,14 @@ use ratatui::{
 
 impl Renderable for BodyHelpLog {
     fn render(rect: &mut Frame, app: &mut App, is_active: bool) {
+        let secondary_pane_height = get_secondary_pane_height(app, rect.area(), 0, 2);
         let chunks = Layout::default()
             .direction(Direction::Vertical)
             .constraints(
                 [
                     Constraint::Fill(1),
-                    Constraint::Length(5),
-                    Constraint::Length(5),
+                    Constraint::Length(secondary_pane_height),
+                    Constraint::Length(secondary_pane_height),
                 ]
                 .as_ref(),
             )
@@ -3,6 +3,7 @@ use crate::{
     ui::{
         rendering::{
             common::{render_body, render_card_being_dragged, render_close_button, render_logs},
+            utils::get_secondary_pane_height,
             view::BodyLog,
         },
         Renderable,
@@ -15,9 +16,16 @@ use ratatui::{
 
 impl Renderable for BodyLog {
     fn render(rect: &mut Frame, app: &mut App, is_active: bool) {
+        let secondary_pane_height = get_secondary_pane_height(app, rect.area(), 0, 1);
         let chunks = Layout::default()
             .direction(Direction::Vertical)
-            .constraints([Constraint::Fill(1), Constraint::Length(5)].as_ref())
+            .constraints(
+                [
+                    Constraint::Fill(1),
+                    Constraint::Length(secondary_pane_height),
+                ]
+                .as_ref(),
+            )
             .split(rect.area());
 
         render_body(rect, chunks[0], app, false, is_active);
@@ -0,0 +1,214 @@
+use crate::{
+    app::{
+        calendar::{cards_due_per_day, month_grid},
+        state::KeyBindingEnum,
+        App,
+    },
+    i18n::{tr, MessageId},
+    ui::{
+        rendering::{
+            common::render_close_button,
+            utils::{check_if_active_and_get_style, truncate_to_width},
+            view::Calendar,
+        },
+        widgets::date_time_picker::CalenderType,
+        Renderable,
+    },
+};
+use chrono::Datelike;
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout},
+    style::Modifier,
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Paragraph},
+    Frame,
+};
+
+impl Renderable for Calendar {
+    fn render(rect: &mut Frame, app: &mut App, is_active: bool) {
+        let selected_date = app.state.calendar_date;
+        let weeks = month_grid(selected_date, &app.config.date_picker_calender_format);
+        let due_per_day = cards_due_per_day(&app.boards, selected_date);
+        let today = chrono::Local::now().date_naive();
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(
+                [
+                    Constraint::Length(3),
+                    Constraint::Length(1),
+                    Constraint::Fill(1),
+                    Constraint::Length(3),
+                ]
+                .as_ref(),
+            )
+            .split(rect.area());
+        let column_constraints = [Constraint::Ratio(1, 7); 7];
+        let header_chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(column_constraints.as_ref())
+            .split(chunks[1]);
+        let week_chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(
+                weeks
+                    .iter()
+                    .map(|_| Constraint::Ratio(1, weeks.len() as u32))
+                    .collect::<Vec<Constraint>>(),
+            )
+            .split(chunks[2]);
+
+        let general_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.general_style,
+        );
+        let selected_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.keyboard_focus_style,
+        );
+        let help_key_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.help_key_style,
+        );
+        let help_text_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.help_text_style,
+        );
+
+        let month_name = chrono::Month::try_from(selected_date.month() as u8)
+            .map(|month| month.name())
+            .unwrap_or_default();
+        let title_paragraph =
+            Paragraph::new(format!("< {} {} >", month_name, selected_date.year()))
+                .alignment(Alignment::Center)
+                .block(
+                    Block::default()
+                        .title(tr(MessageId::TitleCalendar))
+                        .borders(Borders::ALL)
+                        .border_type(BorderType::Rounded)
+                        .style(general_style),
+                );
+
+        let day_names = match app.config.date_picker_calender_format {
+            CalenderType::MondayFirst => ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"],
+            CalenderType::SundayFirst => ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"],
+        };
+
+        let left_key = app
+            .get_first_keybinding(KeyBindingEnum::Left)
+            .unwrap_or("".to_string());
+        let right_key = app
+            .get_first_keybinding(KeyBindingEnum::Right)
+            .unwrap_or("".to_string());
+        let up_key = app
+            .get_first_keybinding(KeyBindingEnum::Up)
+            .unwrap_or("".to_string());
+        let down_key = app
+            .get_first_keybinding(KeyBindingEnum::Down)
+            .unwrap_or("".to_string());
+        let accept_key = app
+            .get_first_keybinding(KeyBindingEnum::Accept)
+            .unwrap_or("".to_string());
+        let cancel_key = app
+            .get_first_keybinding(KeyBindingEnum::GoToPreviousViewOrCancel)
+            .unwrap_or("".to_string());
+        let help_paragraph = Paragraph::new(Line::from(vec![
+            Span::styled("Use ", help_text_style),
+            Span::styled(left_key, help_key_style),
+            Span::styled(" and ", help_text_style),
+            Span::styled(right_key, help_key_style),
+            Span::styled(" to change the month, ", help_text_style),
+            Span::styled(up_key, help_key_style),
+            Span::styled(" and ", help_text_style),
+            Span::styled(down_key, help_key_style),
+            Span::styled(" to change the day. Press ", help_text_style),
+            Span::styled(accept_key, help_key_style),
+            Span::styled(" to see the cards due that day or ", help_text_style),
+            Span::styled(cancel_key, help_key_style),
+            Span::styled(" to go back", help_text_style),
+        ]))
+        .alignment(Alignment::Center)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(general_style),
+        )
+        .wrap(ratatui::widgets::Wrap { trim: true });
+
+        rect.render_widget(title_paragraph, chunks[0]);
+        for (day_name, header_area) in day_names.iter().zip(header_chunks.iter()) {
+            rect.render_widget(
+                Paragraph::new(*day_name)
+                    .alignment(Alignment::Center)
+                    .style(help_text_style),
+                *header_area,
+            );
+        }
+        for (week, week_area) in weeks.iter().zip(week_chunks.iter()) {
+            let day_chunks = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints(column_constraints.as_ref())
+                .split(*week_area);
+            for (day, day_area) in week.iter().zip(day_chunks.iter()) {
+                let Some(day) = day else {
+                    continue;
+                };
+                let border_style = if *day == selected_date {
+                    selected_style
+                } else {
+                    general_style
+                };
+                let day_title_style = if *day == today {
+                    general_style.add_modifier(Modifier::BOLD | Modifier::UNDERLINED)
+                } else {
+                    general_style
+                };
+                let day_block = Block::default()
+                    .title(Span::styled(day.day().to_string(), day_title_style))
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+                    .border_style(border_style);
+                let inner_area = day_block.inner(*day_area);
+                let cell_width = inner_area.width as usize;
+                let cell_height = inner_area.height as usize;
+                let due_cards = due_per_day.get(day).map(Vec::as_slice).unwrap_or_default();
+                // The last line says how many did not fit when there are more cards than lines
+                let shown_cards = if due_cards.len() > cell_height {
+                    cell_height.saturating_sub(1)
+                } else {
+                    due_cards.len()
+                };
+                let mut card_lines = due_cards
+                    .iter()
+                    .take(shown_cards)
+                    .map(|due_card| {
+                        Line::from(Span::styled(
+                            truncate_to_width(&due_card.card_name, cell_width),
+                            general_style,
+                        ))
+                    })
+                    .collect::<Vec<Line>>();
+                if shown_cards < due_cards.len() && cell_height > 0 {
+                    card_lines.push(Line::from(Span::styled(
+                        truncate_to_width(
+                            &format!("+{} more", due_cards.len() - shown_cards),
+                            cell_width,
+                        ),
+                        help_text_style,
+                    )));
+                }
+                rect.render_widget(Paragraph::new(card_lines).block(day_block), *day_area);
+            }
+        }
+        rect.render_widget(help_paragraph, chunks[3]);
+
+        if app.config.enable_mouse_support {
+            render_close_button(rect, app, is_active);
+        }
+    }
+}
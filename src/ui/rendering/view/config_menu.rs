@@ -1,9 +1,10 @@
 use crate::{
     app::{
         state::{Focus, KeyBindingEnum},
-        App,
+        App, ConfigEnum,
     },
     constants::{SCROLLBAR_BEGIN_SYMBOL, SCROLLBAR_END_SYMBOL, SCROLLBAR_TRACK_SYMBOL},
+    i18n::{tr, MessageId},
     ui::{
         rendering::{
             common::{draw_title, render_close_button, render_logs},
@@ -26,6 +27,7 @@ use ratatui::{
     },
     Frame,
 };
+use std::str::FromStr;
 
 impl Renderable for ConfigMenu {
     fn render(rect: &mut Frame, app: &mut App, is_active: bool) {
@@ -181,7 +183,13 @@ fn draw_config_table_selector(
             .max()
             .unwrap_or(0)
             + 1;
-        let cells = item.iter().map(|c| Cell::from(c.to_string()));
+        let cells = item.iter().enumerate().map(|(index, c)| {
+            // Only the name column is translated, the rows themselves keep the stable names
+            match ConfigEnum::from_str(c) {
+                Ok(config_enum) if index == 0 => Cell::from(tr(config_enum.message_id())),
+                _ => Cell::from(c.to_string()),
+            }
+        });
         Row::new(cells).height(height as u16)
     });
 
@@ -193,11 +201,15 @@ fn draw_config_table_selector(
 
     Table::new(
         rows,
-        [Constraint::Percentage(40), Constraint::Percentage(60)],
+        [
+            Constraint::Percentage(35),
+            Constraint::Percentage(40),
+            Constraint::Percentage(25),
+        ],
     )
     .block(
         Block::default()
-            .title("Config Editor")
+            .title(tr(MessageId::TitleConfigEditor))
             .borders(Borders::ALL)
             .style(config_text_style)
             .border_style(default_style)
@@ -249,6 +261,10 @@ fn draw_config_help<'a>(app: &mut App, is_active: bool) -> Paragraph<'a> {
         Span::styled(accept_key.clone(), help_key_style),
         Span::styled(" or ", help_text_style),
         Span::styled("<Mouse Left Click>", help_key_style),
+        Span::styled(", numbers can also be stepped with ", help_text_style),
+        Span::styled("<+>", help_key_style),
+        Span::styled(" and ", help_text_style),
+        Span::styled("<->", help_key_style),
         Span::styled(". Press ", help_text_style),
         Span::styled(cancel_key, help_key_style),
         Span::styled(
@@ -270,7 +286,7 @@ fn draw_config_help<'a>(app: &mut App, is_active: bool) -> Paragraph<'a> {
         .alignment(Alignment::Left)
         .block(
             Block::default()
-                .title("Help")
+                .title(tr(MessageId::TitleHelp))
                 .borders(Borders::ALL)
                 .style(help_box_style)
                 .border_type(BorderType::Rounded),
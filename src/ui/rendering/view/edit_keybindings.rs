@@ -4,6 +4,7 @@ use crate::{
         App,
     },
     constants::{SCROLLBAR_BEGIN_SYMBOL, SCROLLBAR_END_SYMBOL, SCROLLBAR_TRACK_SYMBOL},
+    i18n::{tr, MessageId},
     ui::{
         rendering::{
             common::{draw_title, render_close_button},
@@ -180,7 +181,7 @@ impl Renderable for EditKeybindings {
         let edit_keybinding_help = Paragraph::new(edit_keybinding_help_spans)
             .block(
                 Block::default()
-                    .title("Help")
+                    .title(tr(MessageId::TitleHelp))
                     .borders(Borders::ALL)
                     .border_type(BorderType::Rounded),
             )
@@ -1,6 +1,7 @@
 use crate::{
     app::App,
     constants::LIST_SELECTED_SYMBOL,
+    i18n::{tr, MessageId},
     ui::{
         rendering::{
             common::{draw_help, draw_title, render_close_button, render_logs},
@@ -161,7 +162,7 @@ fn draw_main_menu(app: &mut App, render_area: Rect, rect: &mut Frame, is_active:
     let main_menu = List::new(list_items)
         .block(
             Block::default()
-                .title("Main menu")
+                .title(tr(MessageId::TitleMainMenu))
                 .style(default_style)
                 .borders(Borders::ALL)
                 .border_style(menu_style)
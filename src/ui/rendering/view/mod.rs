@@ -1,10 +1,13 @@
 // TODO: Unify the style of all the views, with comments (styles, chunks, etc etc) in the same order with comments
 
+pub mod archived_cards;
 pub mod body_help;
 pub mod body_help_log;
 pub mod body_log;
+pub mod calendar;
 pub mod config_menu;
 pub mod create_theme;
+pub mod edit_board_notes;
 pub mod edit_keybindings;
 pub mod help_menu;
 pub mod load_a_save;
@@ -16,6 +19,8 @@ pub mod new_board_form;
 pub mod new_card_form;
 pub mod reset_password;
 pub mod signup;
+pub mod stats;
+pub mod swimlane;
 pub mod title_body;
 pub mod title_body_help;
 pub mod title_body_help_log;
@@ -37,6 +42,7 @@ pub struct MainMenuView;
 pub struct HelpMenu;
 pub struct LogView;
 pub struct NewBoardForm;
+pub struct EditBoardNotes;
 pub struct NewCardForm;
 pub struct LoadASave;
 pub struct CreateTheme;
@@ -44,3 +50,7 @@ pub struct Login;
 pub struct Signup;
 pub struct ResetPassword;
 pub struct LoadCloudSave;
+pub struct ArchivedCards;
+pub struct Stats;
+pub struct Calendar;
+pub struct Swimlane;
@@ -12,7 +12,7 @@ use crate::{
             },
             view::NewBoardForm,
         },
-        Renderable,
+        Renderable, View,
     },
 };
 use ratatui::{
@@ -65,14 +65,19 @@ impl Renderable for NewBoardForm {
         let submit_style =
             get_mouse_focusable_field_style(app, Focus::SubmitButton, &chunks[4], is_active, false);
 
-        let title_paragraph = Paragraph::new("Create a new Board")
-            .alignment(Alignment::Center)
-            .block(
-                Block::default()
-                    .borders(Borders::ALL)
-                    .border_type(BorderType::Rounded)
-                    .style(general_style),
-            );
+        let title = if app.state.current_view == View::EditBoard {
+            "Edit Board"
+        } else if app.state.form_drafts.is_restored(View::NewBoard) {
+            "Create a new Board (draft)"
+        } else {
+            "Create a new Board"
+        };
+        let title_paragraph = Paragraph::new(title).alignment(Alignment::Center).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .style(general_style),
+        );
         rect.render_widget(title_paragraph, chunks[0]);
 
         let board_name_block = Block::default()
@@ -1,5 +1,6 @@
 use crate::{
     app::{
+        kanban::take_estimate_token,
         state::{AppStatus, Focus, KeyBindingEnum},
         App,
     },
@@ -12,7 +13,7 @@ use crate::{
             },
             view::NewCardForm,
         },
-        PopUp, Renderable,
+        PopUp, Renderable, View,
     },
 };
 use ratatui::{
@@ -38,6 +39,10 @@ impl Renderable for NewCardForm {
                 .as_ref(),
             )
             .split(rect.area());
+        let button_chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)].as_ref())
+            .split(chunks[5]);
 
         let card_due_date = app
             .widgets
@@ -80,24 +85,58 @@ impl Renderable for NewCardForm {
             app.current_theme.inactive_text_style,
             app.current_theme.help_text_style,
         );
-        let submit_style =
-            get_mouse_focusable_field_style(app, Focus::SubmitButton, &chunks[5], is_active, false);
+        let use_template_style = get_mouse_focusable_field_style(
+            app,
+            Focus::UseTemplate,
+            &button_chunks[0],
+            is_active,
+            false,
+        );
+        let submit_style = get_mouse_focusable_field_style(
+            app,
+            Focus::SubmitButton,
+            &button_chunks[1],
+            is_active,
+            false,
+        );
 
-        let title_paragraph = Paragraph::new("Create a new Card")
-            .alignment(Alignment::Center)
-            .block(
-                Block::default()
-                    .borders(Borders::ALL)
-                    .border_type(BorderType::Rounded)
-                    .style(general_style),
-            );
+        let mut title = if app.state.form_drafts.is_restored(View::NewCard) {
+            "Create a new Card (draft)".to_string()
+        } else {
+            "Create a new Card".to_string()
+        };
+        if let Some(template) = &app.state.new_card_template {
+            title.push_str(&format!(" from template '{}'", template.name));
+        }
+        let title_paragraph = Paragraph::new(title).alignment(Alignment::Center).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .style(general_style),
+        );
         rect.render_widget(title_paragraph, chunks[0]);
 
+        // A quick-add estimate token that would be rejected is pointed out while typing
+        let card_name_title =
+            match take_estimate_token(&app.state.text_buffers.card_name.get_joined_lines()) {
+                Err(e) => Line::from(vec![
+                    Span::raw("Card Name (required) "),
+                    Span::styled(
+                        format!("- {}", e),
+                        check_if_active_and_get_style(
+                            is_active,
+                            app.current_theme.inactive_text_style,
+                            app.current_theme.error_text_style,
+                        ),
+                    ),
+                ]),
+                Ok(_) => Line::from("Card Name (required)"),
+            };
         let card_name_block = Block::default()
             .borders(Borders::ALL)
             .style(name_style)
             .border_type(BorderType::Rounded)
-            .title("Card Name (required)");
+            .title(card_name_title);
         app.state.text_buffers.card_name.set_block(card_name_block);
         rect.render_widget(app.state.text_buffers.card_name.widget(), chunks[1]);
         let description_length = app.state.text_buffers.card_description.get_num_lines();
@@ -182,13 +221,23 @@ impl Renderable for NewCardForm {
             .wrap(ratatui::widgets::Wrap { trim: true });
         rect.render_widget(help_paragraph, chunks[4]);
 
+        let use_template_button = Paragraph::new("Use Template")
+            .alignment(Alignment::Center)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .style(use_template_style)
+                    .border_type(BorderType::Rounded),
+            );
+        rect.render_widget(use_template_button, button_chunks[0]);
+
         let submit_button = Paragraph::new("Submit").alignment(Alignment::Center).block(
             Block::default()
                 .borders(Borders::ALL)
                 .style(submit_style)
                 .border_type(BorderType::Rounded),
         );
-        rect.render_widget(submit_button, chunks[5]);
+        rect.render_widget(submit_button, button_chunks[1]);
 
         if app.state.app_status == AppStatus::UserInput {
             match app.state.focus {
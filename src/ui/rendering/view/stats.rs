@@ -0,0 +1,353 @@
+use crate::{
+    app::{
+        analytics::{
+            completed_this_week, estimate_per_status, most_overdue_cards, priority_counts,
+            status_counts_per_board,
+        },
+        kanban::{format_estimate, CardPriority},
+        state::KeyBindingEnum,
+        App,
+    },
+    constants::STATS_MOST_OVERDUE_CARDS,
+    i18n::{tr, MessageId},
+    model::stats_history::render_sparkline,
+    ui::{
+        rendering::{
+            common::render_close_button, utils::check_if_active_and_get_style, view::Stats,
+        },
+        Renderable,
+    },
+};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Cell, Gauge, Paragraph, Row, Table},
+    Frame,
+};
+
+impl Renderable for Stats {
+    fn render(rect: &mut Frame, app: &mut App, is_active: bool) {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(
+                [
+                    Constraint::Length(3),
+                    Constraint::Length(6),
+                    Constraint::Fill(1),
+                    Constraint::Length(3),
+                ]
+                .as_ref(),
+            )
+            .split(rect.area());
+
+        let general_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.general_style,
+        );
+        let sparkline_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.progress_bar_style,
+        );
+        let help_key_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.help_key_style,
+        );
+        let help_text_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.help_text_style,
+        );
+        let error_text_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.error_text_style,
+        );
+
+        let metric = app.state.stats_metric;
+        let title_paragraph = Paragraph::new(format!("< {} >", metric))
+            .alignment(Alignment::Center)
+            .block(
+                Block::default()
+                    .title(tr(MessageId::TitleStats))
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+                    .style(general_style),
+            );
+
+        let history = &app.state.stats_history;
+        let stats_lines = if history.is_empty() {
+            vec![Line::from(Span::styled(
+                "No stats recorded yet, one is taken every time changes are saved",
+                general_style,
+            ))]
+        } else {
+            let series = history
+                .iter()
+                .map(|record| metric.value(record))
+                .collect::<Vec<usize>>();
+            let sparkline_width = chunks[1].width.saturating_sub(2) as usize;
+            let shown_points = series.len().min(sparkline_width);
+            let shown_series = &series[series.len() - shown_points..];
+            let min = shown_series.iter().min().copied().unwrap_or_default();
+            let max = shown_series.iter().max().copied().unwrap_or_default();
+            let current = shown_series.last().copied().unwrap_or_default();
+            let first_date = &history[history.len() - shown_points].date;
+            let last_date = &history[history.len() - 1].date;
+            vec![
+                Line::from(Span::styled(
+                    render_sparkline(&series, sparkline_width),
+                    sparkline_style,
+                )),
+                Line::default(),
+                Line::from(Span::styled(
+                    format!("Min: {}  Max: {}  Current: {}", min, max, current),
+                    general_style,
+                )),
+                Line::from(Span::styled(
+                    format!(
+                        "{} save{} from {} to {}",
+                        shown_points,
+                        if shown_points == 1 { "" } else { "s" },
+                        first_date,
+                        last_date
+                    ),
+                    help_text_style,
+                )),
+            ]
+        };
+        let stats_paragraph = Paragraph::new(stats_lines).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(general_style),
+        );
+
+        let left_key = app
+            .get_first_keybinding(KeyBindingEnum::Left)
+            .unwrap_or("".to_string());
+        let right_key = app
+            .get_first_keybinding(KeyBindingEnum::Right)
+            .unwrap_or("".to_string());
+        let cancel_key = app
+            .get_first_keybinding(KeyBindingEnum::GoToPreviousViewOrCancel)
+            .unwrap_or("".to_string());
+        let help_paragraph = Paragraph::new(Line::from(vec![
+            Span::styled("Use ", help_text_style),
+            Span::styled(left_key, help_key_style),
+            Span::styled(" and ", help_text_style),
+            Span::styled(right_key, help_key_style),
+            Span::styled(" to switch what is shown. Press ", help_text_style),
+            Span::styled(cancel_key, help_key_style),
+            Span::styled(" to go back", help_text_style),
+        ]))
+        .alignment(Alignment::Center)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(general_style),
+        )
+        .wrap(ratatui::widgets::Wrap { trim: true });
+
+        let dashboard_chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(
+                [
+                    Constraint::Percentage(40),
+                    Constraint::Percentage(25),
+                    Constraint::Percentage(35),
+                ]
+                .as_ref(),
+            )
+            .split(chunks[2]);
+        let now = chrono::Local::now().naive_local();
+
+        let header_row = Row::new(["Board", "Active", "Done", "Stale", "Archived"])
+            .style(help_text_style)
+            .bottom_margin(1);
+        let status_rows = status_counts_per_board(&app.boards)
+            .into_iter()
+            .map(|counts| {
+                let status_cell = |count: usize, status_style| {
+                    Cell::from(count.to_string()).style(check_if_active_and_get_style(
+                        is_active,
+                        app.current_theme.inactive_text_style,
+                        status_style,
+                    ))
+                };
+                Row::new([
+                    Cell::from(counts.board_name.clone()),
+                    status_cell(counts.active, app.current_theme.card_status_active_style),
+                    status_cell(
+                        counts.complete,
+                        app.current_theme.card_status_completed_style,
+                    ),
+                    status_cell(counts.stale, app.current_theme.card_status_stale_style),
+                    Cell::from(counts.archived.to_string()),
+                ])
+            })
+            .collect::<Vec<Row>>();
+        let status_table = Table::new(
+            status_rows,
+            [
+                Constraint::Fill(1),
+                Constraint::Length(6),
+                Constraint::Length(6),
+                Constraint::Length(6),
+                Constraint::Length(8),
+            ],
+        )
+        .header(header_row)
+        .style(general_style)
+        .block(
+            Block::default()
+                .title(tr(MessageId::TitleCardsByStatus))
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(general_style),
+        );
+
+        let status_estimates = estimate_per_status(&app.boards);
+        let status_chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(
+                [
+                    Constraint::Fill(1),
+                    Constraint::Length(if status_estimates.is_empty() { 0 } else { 3 }),
+                ]
+                .as_ref(),
+            )
+            .split(dashboard_chunks[0]);
+        let estimate_spans = status_estimates
+            .iter()
+            .enumerate()
+            .flat_map(|(index, (status_name, estimate))| {
+                [
+                    Span::styled(
+                        format!("{}{}: ", if index == 0 { "" } else { "  " }, status_name),
+                        help_text_style,
+                    ),
+                    Span::styled(format_estimate(*estimate), general_style),
+                ]
+            })
+            .collect::<Vec<Span>>();
+        let estimate_paragraph = Paragraph::new(Line::from(estimate_spans)).block(
+            Block::default()
+                .title(tr(MessageId::TitleEstimateByStatus))
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(general_style),
+        );
+
+        let priority_counts = priority_counts(&app.boards);
+        let cards_on_boards = priority_counts
+            .iter()
+            .map(|(_, count)| count)
+            .sum::<usize>();
+        let priority_block = Block::default()
+            .title(tr(MessageId::TitleCardsByPriority))
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(general_style);
+        let priority_area = priority_block.inner(dashboard_chunks[1]);
+        let priority_chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(
+                priority_counts
+                    .iter()
+                    .map(|_| Constraint::Length(1))
+                    .chain([Constraint::Length(1), Constraint::Length(1)])
+                    .collect::<Vec<Constraint>>(),
+            )
+            .split(priority_area);
+        let priority_gauges = priority_counts
+            .iter()
+            .map(|(priority, count)| {
+                let priority_style = match priority {
+                    CardPriority::High => app.current_theme.card_priority_high_style,
+                    CardPriority::Medium => app.current_theme.card_priority_medium_style,
+                    CardPriority::Low => app.current_theme.card_priority_low_style,
+                };
+                let ratio = if cards_on_boards == 0 {
+                    0.0
+                } else {
+                    *count as f64 / cards_on_boards as f64
+                };
+                Gauge::default()
+                    .gauge_style(check_if_active_and_get_style(
+                        is_active,
+                        app.current_theme.inactive_text_style,
+                        priority_style,
+                    ))
+                    .ratio(ratio)
+                    .label(format!("{} {}", priority, count))
+            })
+            .collect::<Vec<Gauge>>();
+        let completed_paragraph = Paragraph::new(format!(
+            "{} completed this week",
+            completed_this_week(&app.boards, now)
+        ))
+        .style(general_style);
+
+        let overdue_cards = most_overdue_cards(&app.boards, now, STATS_MOST_OVERDUE_CARDS);
+        let overdue_lines = if overdue_cards.is_empty() {
+            vec![Line::from(Span::styled(
+                "Nothing is overdue",
+                general_style,
+            ))]
+        } else {
+            overdue_cards
+                .iter()
+                .map(|overdue_card| {
+                    Line::from(vec![
+                        Span::styled(
+                            format!(
+                                "{} day{} ",
+                                overdue_card.days_overdue,
+                                if overdue_card.days_overdue == 1 {
+                                    ""
+                                } else {
+                                    "s"
+                                }
+                            ),
+                            error_text_style,
+                        ),
+                        Span::styled(overdue_card.card_name.clone(), general_style),
+                        Span::styled(format!(" ({})", overdue_card.board_name), help_text_style),
+                    ])
+                })
+                .collect()
+        };
+        let overdue_paragraph = Paragraph::new(overdue_lines).block(
+            Block::default()
+                .title(tr(MessageId::TitleMostOverdue))
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(general_style),
+        );
+
+        rect.render_widget(title_paragraph, chunks[0]);
+        rect.render_widget(stats_paragraph, chunks[1]);
+        rect.render_widget(status_table, status_chunks[0]);
+        if !status_estimates.is_empty() {
+            rect.render_widget(estimate_paragraph, status_chunks[1]);
+        }
+        rect.render_widget(priority_block, dashboard_chunks[1]);
+        for (gauge, gauge_area) in priority_gauges.into_iter().zip(priority_chunks.iter()) {
+            rect.render_widget(gauge, *gauge_area);
+        }
+        rect.render_widget(
+            completed_paragraph,
+            priority_chunks[priority_chunks.len() - 1],
+        );
+        rect.render_widget(overdue_paragraph, dashboard_chunks[2]);
+        rect.render_widget(help_paragraph, chunks[3]);
+
+        if app.config.enable_mouse_support {
+            render_close_button(rect, app, is_active);
+        }
+    }
+}
@@ -0,0 +1,270 @@
+use crate::{
+    app::{
+        state::{Focus, KeyBindingEnum},
+        App,
+    },
+    ui::{
+        rendering::{
+            common::render_close_button,
+            utils::{check_if_active_and_get_style, truncate_to_width},
+            view::Swimlane,
+        },
+        Renderable,
+    },
+};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout},
+    style::Modifier,
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Paragraph},
+    Frame,
+};
+
+const LANE_LABEL_WIDTH: u16 = 10;
+
+impl Renderable for Swimlane {
+    fn render(rect: &mut Frame, app: &mut App, is_active: bool) {
+        let grid = app.get_swimlane_grid();
+        let current_status = app.get_current_swimlane_status(&grid);
+        let boards = if app.filtered_boards.is_empty() {
+            &app.boards
+        } else {
+            &app.filtered_boards
+        };
+        let is_body_focused = app.state.focus == Focus::Body;
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(
+                [
+                    Constraint::Length(1),
+                    Constraint::Fill(1),
+                    Constraint::Length(3),
+                ]
+                .as_ref(),
+            )
+            .split(rect.area());
+        let mut column_constraints = vec![Constraint::Length(LANE_LABEL_WIDTH)];
+        column_constraints.extend(
+            grid.board_ids
+                .iter()
+                .map(|_| Constraint::Ratio(1, grid.board_ids.len() as u32)),
+        );
+        let header_chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(column_constraints.as_slice())
+            .split(chunks[0]);
+        let lane_chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(
+                grid.lanes
+                    .iter()
+                    .map(|_| Constraint::Ratio(1, grid.lanes.len() as u32))
+                    .collect::<Vec<Constraint>>(),
+            )
+            .split(chunks[1]);
+
+        let general_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.general_style,
+        );
+        let selected_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.keyboard_focus_style,
+        );
+        let help_key_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.help_key_style,
+        );
+        let help_text_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.help_text_style,
+        );
+        let accent_style = |board_id: (u64, u64)| {
+            boards
+                .get_board_with_id(board_id)
+                .and_then(|board| board.accent_color)
+                .filter(|_| is_active)
+                .map_or(general_style, |accent_color| {
+                    general_style.fg(accent_color.into())
+                })
+        };
+
+        if grid.board_ids.is_empty() {
+            rect.render_widget(
+                Paragraph::new("No boards to show")
+                    .alignment(Alignment::Center)
+                    .style(help_text_style)
+                    .block(
+                        Block::default()
+                            .borders(Borders::ALL)
+                            .border_type(BorderType::Rounded)
+                            .border_style(general_style),
+                    ),
+                chunks[1],
+            );
+        }
+        for (board_id, header_area) in grid.board_ids.iter().zip(header_chunks.iter().skip(1)) {
+            let board_name = boards
+                .get_board_with_id(*board_id)
+                .map(|board| board.name.clone())
+                .unwrap_or_default();
+            let header_style = if app.state.current_board_id == Some(*board_id) {
+                accent_style(*board_id).add_modifier(Modifier::BOLD | Modifier::UNDERLINED)
+            } else {
+                accent_style(*board_id).add_modifier(Modifier::BOLD)
+            };
+            rect.render_widget(
+                Paragraph::new(truncate_to_width(&board_name, header_area.width as usize))
+                    .alignment(Alignment::Center)
+                    .style(header_style),
+                *header_area,
+            );
+        }
+        for (lane, lane_area) in grid.lanes.iter().zip(lane_chunks.iter()) {
+            let cell_chunks = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints(column_constraints.as_slice())
+                .split(*lane_area);
+            let label_style = if lane.status == current_status {
+                selected_style
+            } else {
+                general_style
+            };
+            rect.render_widget(
+                Paragraph::new(truncate_to_width(
+                    &lane.status.to_string(),
+                    LANE_LABEL_WIDTH as usize,
+                ))
+                .style(label_style),
+                cell_chunks[0],
+            );
+            for ((board_id, card_ids), cell_area) in grid
+                .board_ids
+                .iter()
+                .zip(lane.cells.iter())
+                .zip(cell_chunks.iter().skip(1))
+            {
+                let is_current_cell = is_body_focused
+                    && lane.status == current_status
+                    && app.state.current_board_id == Some(*board_id);
+                let cell_block = Block::default()
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+                    .border_style(if is_current_cell {
+                        selected_style
+                    } else {
+                        accent_style(*board_id)
+                    });
+                let inner_area = cell_block.inner(*cell_area);
+                let cell_width = inner_area.width as usize;
+                let cell_height = inner_area.height as usize;
+                if card_ids.is_empty() {
+                    rect.render_widget(
+                        Paragraph::new(Line::from(Span::styled("— none —", help_text_style)))
+                            .alignment(Alignment::Center)
+                            .block(cell_block),
+                        *cell_area,
+                    );
+                    continue;
+                }
+                // The selected card is kept in view, the last line says how many did not fit
+                let shown_cards = if card_ids.len() > cell_height {
+                    cell_height.saturating_sub(1)
+                } else {
+                    card_ids.len()
+                };
+                let selected_index = app
+                    .state
+                    .current_card_id
+                    .filter(|_| is_current_cell)
+                    .and_then(|card_id| card_ids.iter().position(|id| *id == card_id))
+                    .unwrap_or(0);
+                let first_shown = (selected_index + 1).saturating_sub(shown_cards);
+                let mut card_lines = card_ids
+                    .iter()
+                    .skip(first_shown)
+                    .take(shown_cards)
+                    .filter_map(|card_id| {
+                        let card = boards
+                            .get_board_with_id(*board_id)
+                            .and_then(|board| board.cards.get_card_with_id(*card_id))?;
+                        let card_style =
+                            if is_current_cell && app.state.current_card_id == Some(*card_id) {
+                                selected_style
+                            } else if let Some(highlight_color) =
+                                card.highlight_color.filter(|_| is_active)
+                            {
+                                general_style.fg(highlight_color.into())
+                            } else {
+                                general_style
+                            };
+                        Some(Line::from(Span::styled(
+                            truncate_to_width(&card.name, cell_width),
+                            card_style,
+                        )))
+                    })
+                    .collect::<Vec<Line>>();
+                if shown_cards < card_ids.len() && cell_height > 0 {
+                    card_lines.push(Line::from(Span::styled(
+                        truncate_to_width(
+                            &format!("+{} more", card_ids.len() - shown_cards),
+                            cell_width,
+                        ),
+                        help_text_style,
+                    )));
+                }
+                rect.render_widget(Paragraph::new(card_lines).block(cell_block), *cell_area);
+            }
+        }
+
+        let left_key = app
+            .get_first_keybinding(KeyBindingEnum::Left)
+            .unwrap_or("".to_string());
+        let right_key = app
+            .get_first_keybinding(KeyBindingEnum::Right)
+            .unwrap_or("".to_string());
+        let up_key = app
+            .get_first_keybinding(KeyBindingEnum::Up)
+            .unwrap_or("".to_string());
+        let down_key = app
+            .get_first_keybinding(KeyBindingEnum::Down)
+            .unwrap_or("".to_string());
+        let accept_key = app
+            .get_first_keybinding(KeyBindingEnum::Accept)
+            .unwrap_or("".to_string());
+        let help_paragraph = Paragraph::new(Line::from(vec![
+            Span::styled("Use ", help_text_style),
+            Span::styled(left_key, help_key_style),
+            Span::styled(" and ", help_text_style),
+            Span::styled(right_key, help_key_style),
+            Span::styled(" to change the board, ", help_text_style),
+            Span::styled(up_key, help_key_style),
+            Span::styled(" and ", help_text_style),
+            Span::styled(down_key, help_key_style),
+            Span::styled(
+                " to move through the cards and statuses. Press ",
+                help_text_style,
+            ),
+            Span::styled(accept_key, help_key_style),
+            Span::styled(" to open the selected card", help_text_style),
+        ]))
+        .alignment(Alignment::Center)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(general_style),
+        )
+        .wrap(ratatui::widgets::Wrap { trim: true });
+        rect.render_widget(help_paragraph, chunks[2]);
+
+        if app.config.enable_mouse_support {
+            render_close_button(rect, app, is_active);
+        }
+    }
+}
@@ -5,7 +5,7 @@ use crate::{
             common::{
                 draw_help, draw_title, render_body, render_card_being_dragged, render_close_button,
             },
-            utils::check_if_active_and_get_style,
+            utils::{check_if_active_and_get_style, get_secondary_pane_height},
             view::TitleBodyHelp,
         },
         Renderable,
@@ -19,13 +19,14 @@ use ratatui::{
 
 impl Renderable for TitleBodyHelp {
     fn render(rect: &mut Frame, app: &mut App, is_active: bool) {
+        let secondary_pane_height = get_secondary_pane_height(app, rect.area(), 3, 1);
         let chunks = Layout::default()
             .direction(Direction::Vertical)
             .constraints(
                 [
                     Constraint::Length(3),
                     Constraint::Fill(1),
-                    Constraint::Length(5),
+                    Constraint::Length(secondary_pane_height),
                 ]
                 .as_ref(),
             )
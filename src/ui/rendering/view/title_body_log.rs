@@ -6,6 +6,7 @@ use crate::{
                 draw_title, render_body, render_card_being_dragged, render_close_button,
                 render_logs,
             },
+            utils::get_secondary_pane_height,
             view::TitleBodyLog,
         },
         Renderable,
@@ -18,13 +19,14 @@ use ratatui::{
 
 impl Renderable for TitleBodyLog {
     fn render(rect: &mut Frame, app: &mut App, is_active: bool) {
+        let secondary_pane_height = get_secondary_pane_height(app, rect.area(), 3, 1);
         let chunks = Layout::default()
             .direction(Direction::Vertical)
             .constraints(
                 [
                     Constraint::Length(3),
                     Constraint::Fill(1),
-                    Constraint::Length(5),
+                    Constraint::Length(secondary_pane_height),
                 ]
                 .as_ref(),
             )
@@ -18,6 +18,8 @@ pub enum TextBoxEditKind {
     DeleteStr(String),
     InsertChunk(Vec<String>),
     DeleteChunk(Vec<String>),
+    /// Every line before and after a find and replace
+    ReplaceAll(Vec<String>, Vec<String>),
 }
 
 impl TextBoxEditKind {
@@ -78,6 +80,9 @@ impl TextBoxEditKind {
                 first_line.truncate(after.offset);
                 first_line.push_str(&last_line);
             }
+            TextBoxEditKind::ReplaceAll(_, new_lines) => {
+                *lines = new_lines.clone();
+            }
         }
     }
 
@@ -92,6 +97,7 @@ impl TextBoxEditKind {
             DeleteStr(s) => InsertStr(s),
             InsertChunk(c) => DeleteChunk(c),
             DeleteChunk(c) => InsertChunk(c),
+            ReplaceAll(old_lines, new_lines) => ReplaceAll(new_lines, old_lines),
         }
     }
 }
@@ -3,11 +3,12 @@
 // having to worry about the original codebase, and use the latest possible ratatui version
 // without waiting for the original author as the original codebase is not actively maintained.
 
-use crate::{inputs::key::Key, util::spaces};
+use crate::{inputs::key::Key, io::clipboard::read_from_clipboard, util::spaces};
 use helper_enums::{CursorMove, TextBoxEditKind, TextBoxScroll, YankText};
 use helper_structs::{
     CursorPos, TextBoxEdit, TextBoxHistory, TextBoxRenderer, TextBoxViewport, TextLineFormatter,
 };
+use portable_atomic::{AtomicBool, Ordering as AtomicOrdering};
 use ratatui::{
     layout::Alignment,
     style::{Modifier, Style},
@@ -16,12 +17,19 @@ use ratatui::{
 };
 use std::cmp::Ordering;
 use unicode_width::UnicodeWidthChar;
-use utils::{find_word_end_forward, find_word_start_backward};
+use utils::{find_word_end_forward, find_word_start_backward, sanitize_text};
 
 pub mod helper_enums;
 pub mod helper_structs;
 pub mod utils;
 
+static NORMALIZE_INSERTED_TEXT: AtomicBool = AtomicBool::new(false);
+
+/// NFC normalizes text inserted with [`TextBox::insert_str`] from now on, in every text box
+pub fn set_unicode_normalization(enabled: bool) {
+    NORMALIZE_INSERTED_TEXT.store(enabled, AtomicOrdering::Relaxed);
+}
+
 #[derive(Clone, Debug)]
 pub struct TextBox<'a> {
     lines: Vec<String>,
@@ -285,7 +293,7 @@ impl<'a> TextBox<'a> {
                 false
             }
             Key::Ctrl('x') => self.cut(),
-            Key::Ctrl('v') => self.paste(),
+            Key::Ctrl('v') => self.paste_from_clipboard(),
             Key::PageDown => {
                 if self.single_line_mode {
                     return false;
@@ -377,6 +385,54 @@ impl<'a> TextBox<'a> {
         }
     }
 
+    /// Pastes the system clipboard, the internal yank buffer is pasted instead when there is no
+    /// clipboard to read, as on a headless machine reached over SSH
+    pub fn paste_from_clipboard(&mut self) -> bool {
+        match read_from_clipboard() {
+            Ok(text) => self.paste_external(&text),
+            Err(err) => {
+                log::debug!("Falling back to the internal yank buffer: {}", err);
+                self.paste()
+            }
+        }
+    }
+
+    /// Inserts text that came from outside the app in place of the selection. Line endings are
+    /// normalized, line breaks become spaces in single line mode and one undo removes the whole
+    /// paste
+    ///
+    /// ```
+    /// use rust_kanban::ui::text_box::TextBox;
+    ///
+    /// let mut text_box = TextBox::from_list_of_str(vec!["ab"], true);
+    /// assert!(text_box.paste_external("one\r\ntwo\nthree"));
+    /// assert_eq!(text_box.get_joined_lines(), "one two threeab");
+    ///
+    /// let mut text_box = TextBox::from_list_of_str(vec!["keep drop"], false);
+    /// text_box.select_all();
+    /// assert!(text_box.paste_external("first\r\nsecond"));
+    /// assert_eq!(text_box.get_joined_lines(), "first\nsecond");
+    /// // The replaced selection comes back with a single undo
+    /// assert!(text_box.undo());
+    /// assert_eq!(text_box.get_joined_lines(), "keep drop");
+    /// ```
+    pub fn paste_external(&mut self, text: &str) -> bool {
+        if self.selection_range().is_none() {
+            return self.insert_str(text);
+        }
+        // Replacing a selection takes a delete and an insert, both are folded into one entry
+        let lines_before = self.lines.clone();
+        let history_before = self.history.clone();
+        let (row, col) = self.cursor;
+        let before = CursorPos::new(row, col, self.line_offset(row, col));
+        self.insert_str(text);
+        self.history = history_before;
+        let (row, col) = self.cursor;
+        let edit = TextBoxEditKind::ReplaceAll(lines_before, self.lines.clone());
+        self.push_history(edit, before, self.line_offset(row, col));
+        true
+    }
+
     fn selection_range(&self) -> Option<(CursorPos, CursorPos)> {
         let (sr, sc) = self.selection_start?;
         let (er, ec) = self.cursor;
@@ -455,6 +511,14 @@ impl<'a> TextBox<'a> {
             self.insert_newline();
             return;
         }
+        // Control characters typed or pasted one key at a time never make it into the text
+        let mut buf = [0; 4];
+        let Some(c) = sanitize_text(c.encode_utf8(&mut buf), false, false)
+            .chars()
+            .next()
+        else {
+            return;
+        };
 
         self.delete_selection(false);
         let (row, col) = self.cursor;
@@ -475,11 +539,12 @@ impl<'a> TextBox<'a> {
 
     pub fn insert_str<S: AsRef<str>>(&mut self, s: S) -> bool {
         let modified = self.delete_selection(false);
-        let mut lines: Vec<_> = s
-            .as_ref()
-            .split('\n')
-            .map(|s| s.strip_suffix('\r').unwrap_or(s).to_string())
-            .collect();
+        let s = sanitize_text(
+            s.as_ref(),
+            !self.single_line_mode,
+            NORMALIZE_INSERTED_TEXT.load(AtomicOrdering::Relaxed),
+        );
+        let mut lines: Vec<_> = s.split('\n').map(|s| s.to_string()).collect();
         match lines.len() {
             0 => modified,
             1 => self.insert_piece(lines.remove(0)),
@@ -819,6 +884,58 @@ impl<'a> TextBox<'a> {
         }
     }
 
+    /// The (row, col) of every match of `query`, col counts chars like the cursor does. Matches
+    /// are found left to right without overlapping, the same ones [`TextBox::replace_all`]
+    /// replaces, and an empty query matches nothing
+    pub fn find_matches(&self, query: &str) -> Vec<(usize, usize)> {
+        if query.is_empty() {
+            return vec![];
+        }
+        self.lines
+            .iter()
+            .enumerate()
+            .flat_map(|(row, line)| {
+                line.match_indices(query)
+                    .map(move |(offset, _)| (row, line[..offset].chars().count()))
+            })
+            .collect()
+    }
+
+    /// Replaces every match of `query` and returns how many there were. All of them go into a
+    /// single history entry so one undo brings the text back, an empty query is rejected and
+    /// replaces nothing
+    pub fn replace_all(&mut self, query: &str, replacement: &str) -> usize {
+        let match_count = self.find_matches(query).len();
+        if match_count == 0 {
+            return 0;
+        }
+        let replacement = sanitize_text(
+            replacement,
+            !self.single_line_mode,
+            NORMALIZE_INSERTED_TEXT.load(AtomicOrdering::Relaxed),
+        );
+        let new_lines: Vec<String> = self
+            .lines
+            .join("\n")
+            .replace(query, &replacement)
+            .split('\n')
+            .map(|line| line.to_string())
+            .collect();
+
+        self.cancel_selection();
+        let (row, col) = self.cursor;
+        let before = CursorPos::new(row, col, self.line_offset(row, col));
+        let edit = TextBoxEditKind::ReplaceAll(self.lines.clone(), new_lines);
+        edit.apply(&mut self.lines, &before, &before);
+
+        // The cursor stays where it was as far as the new text allows
+        let row = row.min(self.lines.len() - 1);
+        let col = col.min(self.lines[row].chars().count());
+        self.cursor = (row, col);
+        self.push_history(edit, before, self.line_offset(row, col));
+        match_count
+    }
+
     pub fn select_all(&mut self) {
         self.move_cursor(CursorMove::Jump(u16::MAX, u16::MAX));
         self.selection_start = Some((0, 0));
@@ -969,3 +1086,39 @@ impl<'a> TextBox<'a> {
         raw_length
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn find_matches_counts_overlapping_matches_once_in_char_columns() {
+        let text_box = TextBox::from_list_of_str(vec!["aaaa", "café au café"], false);
+        assert_eq!(text_box.find_matches("aa"), vec![(0, 0), (0, 2)]);
+        // Columns are in chars, not bytes
+        assert_eq!(text_box.find_matches("café"), vec![(1, 0), (1, 8)]);
+        assert!(text_box.find_matches("").is_empty());
+    }
+
+    #[test]
+    fn replace_all_rejects_an_empty_query() {
+        let mut text_box = TextBox::from_list_of_str(vec!["teh cat"], false);
+        assert_eq!(text_box.replace_all("", "x"), 0);
+        assert_eq!(text_box.get_joined_lines(), "teh cat");
+        assert!(!text_box.undo());
+    }
+
+    #[test]
+    fn each_replace_all_is_undone_in_one_step() {
+        let mut text_box = TextBox::from_list_of_str(vec!["teh cat", "tehteh ünï"], false);
+        assert_eq!(text_box.replace_all("teh", "the"), 3);
+        assert_eq!(text_box.replace_all("ünï", "uni"), 1);
+        assert_eq!(text_box.get_joined_lines(), "the cat\nthethe uni");
+        assert!(text_box.undo());
+        assert_eq!(text_box.get_joined_lines(), "the cat\nthethe ünï");
+        assert!(text_box.undo());
+        assert_eq!(text_box.get_joined_lines(), "teh cat\ntehteh ünï");
+        assert!(text_box.redo());
+        assert_eq!(text_box.get_joined_lines(), "the cat\nthethe ünï");
+    }
+}
@@ -1,4 +1,13 @@
 use super::helper_enums::CharKind;
+use std::{iter::Peekable, str::Chars};
+use unicode_normalization::UnicodeNormalization;
+
+const BYTE_ORDER_MARK: char = '\u{feff}';
+const ESCAPE: char = '\u{1b}';
+const BELL: char = '\u{7}';
+/// C1 forms of "ESC [" and "ESC ]"
+const C1_CSI: char = '\u{9b}';
+const C1_OSC: char = '\u{9d}';
 
 pub fn find_word_start_forward(line: &str, start_col: usize) -> Option<usize> {
     let mut it = line.chars().enumerate().skip(start_col);
@@ -43,3 +52,92 @@ pub fn find_word_start_backward(line: &str, start_col: usize) -> Option<usize> {
     }
     (cur != CharKind::Space).then_some(0)
 }
+
+/// Cleans text before it goes into a text box. Escape sequences, C0 and C1 control characters,
+/// byte order marks and the replacement characters that decoding leaves for lone surrogates are
+/// dropped as they break the layout when drawn. Line breaks and tabs are kept when allowed and
+/// turned into spaces otherwise, everything else including emoji, right to left text and
+/// combining marks is kept as is. With normalize the result is NFC normalized
+///
+/// ```
+/// use rust_kanban::ui::text_box::utils::sanitize_text;
+///
+/// // A raw escape sequence, only its text survives
+/// assert_eq!(sanitize_text("\u{1b}[31mred\u{1b}[0m", false, false), "red");
+/// // A title set through OSC and a lone escape
+/// assert_eq!(sanitize_text("a\u{1b}]0;title\u{7}b\u{1b}", false, false), "ab");
+/// // A BOM prefixed paste with Windows line endings
+/// assert_eq!(sanitize_text("\u{feff}one\r\ntwo", true, false), "one\ntwo");
+/// assert_eq!(sanitize_text("\u{feff}one\r\ntwo", false, false), "one two");
+/// // Lone surrogates from OSC 52 clipboard data end up as replacement characters once decoded
+/// let decoded = String::from_utf8_lossy(b"ok\xed\xa0\x80!");
+/// assert_eq!(sanitize_text(&decoded, false, false), "ok!");
+/// // Other control characters, C1 ones included
+/// assert_eq!(sanitize_text("a\u{0}b\u{7f}c\u{85}d\u{9b}2Je", false, false), "abcde");
+/// // Legitimate unicode is left alone
+/// let family = "👨\u{200d}👩\u{200d}👧 שלום e\u{301}";
+/// assert_eq!(sanitize_text(family, false, false), family);
+/// assert_eq!(sanitize_text("e\u{301}", false, true), "é");
+/// ```
+pub fn sanitize_text(text: &str, allow_line_breaks: bool, normalize: bool) -> String {
+    let mut sanitized = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            ESCAPE => skip_escape_sequence(&mut chars),
+            C1_CSI => skip_control_sequence(&mut chars),
+            C1_OSC => skip_string_sequence(&mut chars),
+            '\r' => {
+                chars.next_if_eq(&'\n');
+                sanitized.push(if allow_line_breaks { '\n' } else { ' ' });
+            }
+            '\n' | '\t' => sanitized.push(if allow_line_breaks { c } else { ' ' }),
+            BYTE_ORDER_MARK | char::REPLACEMENT_CHARACTER => {}
+            c if c.is_control() => {}
+            c => sanitized.push(c),
+        }
+    }
+    if normalize {
+        sanitized.nfc().collect()
+    } else {
+        sanitized
+    }
+}
+
+/// Skips what follows an ESC, the sequence ends where the terminal would end it
+fn skip_escape_sequence(chars: &mut Peekable<Chars>) {
+    match chars.peek() {
+        Some('[') => {
+            chars.next();
+            skip_control_sequence(chars);
+        }
+        Some(']' | 'P' | 'X' | '^' | '_') => {
+            chars.next();
+            skip_string_sequence(chars);
+        }
+        Some(' '..='~') => {
+            chars.next();
+        }
+        _ => {}
+    }
+}
+
+/// Parameter and intermediate bytes up to and including the final byte of a CSI sequence
+fn skip_control_sequence(chars: &mut Peekable<Chars>) {
+    while chars.next_if(|c| matches!(c, ' '..='?')).is_some() {}
+    chars.next_if(|c| matches!(c, '@'..='~'));
+}
+
+/// OSC and the other string sequences run until BEL or the ESC \\ terminator
+fn skip_string_sequence(chars: &mut Peekable<Chars>) {
+    while let Some(c) = chars.next() {
+        match c {
+            BELL => return,
+            ESCAPE => {
+                chars.next_if_eq(&'\\');
+                return;
+            }
+            _ => {}
+        }
+    }
+}
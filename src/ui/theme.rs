@@ -2,8 +2,8 @@ use crate::{
     app::App,
     constants::SAMPLE_TEXT,
     ui::inbuilt_themes::{
-        cyberpunk_theme, default_theme, dracula_theme, light_theme, matrix_theme, metro_theme,
-        midnight_blue_theme, slate_theme,
+        cyberpunk_theme, default_theme, dracula_theme, high_contrast_theme, light_theme,
+        matrix_theme, metro_theme, midnight_blue_theme, slate_theme,
     },
 };
 use ratatui::{
@@ -54,6 +54,7 @@ impl Theme {
             cyberpunk_theme(),
             default_theme(),
             dracula_theme(),
+            high_contrast_theme(),
             light_theme(),
             matrix_theme(),
             metro_theme(),
@@ -1,14 +1,16 @@
 use crate::{
-    app::App,
+    app::{state::KeyBindingEnum, App},
     constants::{
-        MAX_TOASTS_TO_DISPLAY, MIN_TERM_HEIGHT, MIN_TERM_WIDTH, SCREEN_TO_TOAST_WIDTH_RATIO,
+        MAX_TOASTS_TO_DISPLAY, MAX_TOAST_LINES, MIN_TERM_HEIGHT, MIN_TERM_WIDTH,
+        SCREEN_TO_TOAST_WIDTH_RATIO, TUTORIAL_BOX_HEIGHT, TUTORIAL_BOX_WIDTH,
     },
     ui::{
+        color_depth::ColorDepth,
         rendering::{
             common::{draw_title, render_blank_styled_canvas, render_logs},
             utils::top_left_rect,
         },
-        widgets::toast::Toast,
+        widgets::{toast::Toast, tutorial::TutorialStep, SelfViewportCorrection},
     },
 };
 use log::debug;
@@ -18,6 +20,7 @@ use ratatui::{
     widgets::{Block, BorderType, Borders, Clear, Paragraph, Wrap},
     Frame,
 };
+use unicode_width::UnicodeWidthStr;
 
 pub fn draw_size_error(rect: &mut Frame, size: &Rect, msg: String, app: &mut App) {
     let chunks = Layout::default()
@@ -102,8 +105,11 @@ pub fn render_toast(rect: &mut Frame, app: &mut App) {
     if toasts.is_empty() {
         return;
     }
+    let toast_width = rect.area().width / SCREEN_TO_TOAST_WIDTH_RATIO;
+    let x_offset = rect.area().width - toast_width;
+    let mut hidden_toasts = all_toasts.len() - toasts.len();
     let mut total_height_rendered = 1;
-    for toast in toasts.iter() {
+    for (index, toast) in toasts.iter().enumerate() {
         let toast_style = app
             .current_theme
             .general_style
@@ -113,14 +119,10 @@ pub fn render_toast(rect: &mut Frame, app: &mut App) {
                 toast.toast_color.2,
             ));
         let toast_title = toast.title.to_owned();
-        let x_offset = rect.area().width - (rect.area().width / SCREEN_TO_TOAST_WIDTH_RATIO);
-        let lines = textwrap::wrap(
-            &toast.message,
-            ((rect.area().width / SCREEN_TO_TOAST_WIDTH_RATIO).saturating_sub(2)) as usize,
-        )
-        .iter()
-        .map(|x| Line::from(x.to_string()))
-        .collect::<Vec<Line>>();
+        let lines = wrap_toast_message(&toast.message, toast_width.saturating_sub(2) as usize)
+            .into_iter()
+            .map(Line::from)
+            .collect::<Vec<Line>>();
         let toast_height = lines.len() as u16 + 2;
         let toast_block = Block::default()
             .title(toast_title)
@@ -130,37 +132,39 @@ pub fn render_toast(rect: &mut Frame, app: &mut App) {
         let toast_paragraph = Paragraph::new(lines)
             .block(toast_block)
             .alignment(Alignment::Left)
-            .wrap(ratatui::widgets::Wrap { trim: true })
             .style(toast_style);
         if toast_height + total_height_rendered > rect.area().height {
             debug!("Toast height is greater than the height of the screen");
+            hidden_toasts += toasts.len() - index;
             break;
         }
-        rect.render_widget(
-            Clear,
-            Rect::new(
-                x_offset,
-                total_height_rendered,
-                rect.area().width / SCREEN_TO_TOAST_WIDTH_RATIO,
-                toast_height,
-            ),
-        );
-        rect.render_widget(
-            toast_paragraph,
-            Rect::new(
-                x_offset,
-                total_height_rendered,
-                rect.area().width / SCREEN_TO_TOAST_WIDTH_RATIO,
-                toast_height,
-            ),
-        );
+        let toast_area = Rect::new(x_offset, total_height_rendered, toast_width, toast_height);
+        rect.render_widget(Clear, toast_area);
+        rect.render_widget(toast_paragraph, toast_area);
         total_height_rendered += toast_height;
         if total_height_rendered >= rect.area().height {
             debug!("Toast height is greater than the height of the screen");
+            hidden_toasts += toasts.len() - index - 1;
             break;
         }
     }
 
+    // The toasts that did not fit are still in the notification history
+    if hidden_toasts > 0 && total_height_rendered < rect.area().height {
+        let more_text = match app.get_first_keybinding(KeyBindingEnum::ToggleNotifications) {
+            Some(key) if !key.is_empty() => {
+                format!("(+{} more, {} to see all) ", hidden_toasts, key)
+            }
+            _ => format!("(+{} more) ", hidden_toasts),
+        };
+        let more_paragraph = Paragraph::new(more_text)
+            .alignment(Alignment::Right)
+            .style(app.current_theme.inactive_text_style);
+        let more_area = Rect::new(x_offset, total_height_rendered, toast_width, 1);
+        rect.render_widget(Clear, more_area);
+        rect.render_widget(more_paragraph, more_area);
+    }
+
     let text_offset = 15;
     let toast_count = app.widgets.toast_widget.toasts.len();
     let toast_count_text = format!(" {} Message(s)", toast_count);
@@ -178,6 +182,84 @@ pub fn render_toast(rect: &mut Frame, app: &mut App) {
     rect.render_widget(toast_count_paragraph, message_area);
 }
 
+/// Wraps a toast message to the inside of the toast, breaking words that are too long for a line.
+/// Messages longer than MAX_TOAST_LINES are cut off with an ellipsis
+fn wrap_toast_message(message: &str, width: usize) -> Vec<String> {
+    let width = width.max(1);
+    let mut lines = textwrap::wrap(message, width)
+        .into_iter()
+        .map(|line| line.into_owned())
+        .collect::<Vec<String>>();
+    if lines.len() > MAX_TOAST_LINES {
+        lines.truncate(MAX_TOAST_LINES);
+        if let Some(last_line) = lines.last_mut() {
+            while !last_line.is_empty() && last_line.width() + 1 > width {
+                last_line.pop();
+            }
+            last_line.push('…');
+        }
+    }
+    lines
+}
+
+/// Floats the current tutorial step above the view and popups without taking focus
+pub fn render_tutorial(rect: &mut Frame, app: &mut App) {
+    let Some(step) = app.widgets.tutorial.step else {
+        return;
+    };
+    app.widgets.tutorial.set_anchor(Some((
+        rect.area().width.saturating_sub(TUTORIAL_BOX_WIDTH) / 2,
+        rect.area().height.saturating_sub(TUTORIAL_BOX_HEIGHT + 3),
+    )));
+    app.widgets.tutorial.set_current_viewport(Some(rect.area()));
+    let anchor = app
+        .widgets
+        .tutorial
+        .viewport_corrected_anchor
+        .unwrap_or_default();
+    let render_area = Rect::new(anchor.0, anchor.1, TUTORIAL_BOX_WIDTH, TUTORIAL_BOX_HEIGHT)
+        .intersection(rect.area());
+
+    let skip_keys = app
+        .config
+        .keybindings
+        .skip_tutorial_step
+        .iter()
+        .map(|key| key.to_string())
+        .collect::<Vec<String>>()
+        .join(" or ");
+    let tutorial_lines = vec![
+        Line::from(Span::styled(
+            step.instruction(app),
+            app.current_theme.general_style,
+        )),
+        Line::from(""),
+        Line::from(Span::styled(
+            format!(
+                "{} skips this step, run the tutorial command again to stop",
+                skip_keys
+            ),
+            app.current_theme.help_text_style,
+        )),
+    ];
+    let tutorial_paragraph = Paragraph::new(tutorial_lines)
+        .block(
+            Block::default()
+                .title(format!(
+                    "Tutorial ({}/{})",
+                    step.number(),
+                    TutorialStep::COUNT
+                ))
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(app.current_theme.help_key_style),
+        )
+        .wrap(Wrap { trim: true });
+
+    render_blank_styled_canvas(rect, &app.current_theme, render_area, true);
+    rect.render_widget(tutorial_paragraph, render_area);
+}
+
 pub fn render_debug_panel(rect: &mut Frame, app: &mut App) {
     let current_view = &app.state.current_view.to_string();
     let popup = if app.state.z_stack.is_empty() {
@@ -202,10 +284,22 @@ pub fn render_debug_panel(rect: &mut Frame, app: &mut App) {
     } else {
         "None".to_string()
     };
+    let widget_lock_time = if let Some(lock_time) = app.state.widget_lock_time_per_second {
+        format!("{}μs/s", lock_time.as_micros())
+    } else {
+        "None".to_string()
+    };
     let current_board_id = app.state.current_board_id;
     let current_card_id = app.state.current_card_id;
 
-    let debug_panel_area = top_left_rect(38, 10, rect.area());
+    let color_depth = app.color_depth();
+    let quantization = if matches!(color_depth, ColorDepth::Ansi256 | ColorDepth::Ansi16) {
+        "quantized"
+    } else {
+        "as is"
+    };
+
+    let debug_panel_area = top_left_rect(38, 12, rect.area());
     let strings = [
         format!("App status: {:?}", app.state.app_status),
         format!("View: {}", current_view),
@@ -213,8 +307,10 @@ pub fn render_debug_panel(rect: &mut Frame, app: &mut App) {
         format!("CMousePos: {:?}", app.state.current_mouse_coordinates),
         format!("Popup: {}", popup),
         format!("Avg Render Time: {}", ui_render_time),
+        format!("Widget Lock: {}", widget_lock_time),
         format!("CB-ID: {:?}", current_board_id),
         format!("CC-ID: {:?}", current_card_id),
+        format!("Colors: {} ({})", color_depth, quantization),
     ];
     let strings = strings
         .iter()
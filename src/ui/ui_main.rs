@@ -1,27 +1,38 @@
 use crate::{
     app::{state::AppStatus, App},
-    ui::{rendering::common, ui_helper},
+    ui::{color_depth::degrade_buffer_colors, rendering::common, ui_helper},
+    util::SpanTimer,
 };
+use log::info;
 use ratatui::Frame;
 
 /// Main UI Drawing handler
 pub fn draw(rect: &mut Frame, app: &mut App) {
     let is_active = app.state.z_stack.is_empty();
+    let mut frame_timer = SpanTimer::new(std::mem::take(&mut app.state.profile_next_frame));
+    if rect.area() != app.state.last_frame_area {
+        app.state.last_frame_area = rect.area();
+        app.state.layout_generation += 1;
+    }
 
     // Background
     common::render_blank_styled_canvas(rect, &app.current_theme, rect.area(), is_active);
+    frame_timer.end_span("Background");
 
     // Check if the terminal size is too small or the app is still initializing
     if let Err(msg) = ui_helper::check_size(&rect.area()) {
         ui_helper::draw_size_error(rect, &rect.area(), msg, app);
+        degrade_buffer_colors(rect.buffer_mut(), app.color_depth());
         return;
     } else if *app.status() == AppStatus::Init {
         ui_helper::draw_loading_screen(rect, &rect.area(), app);
+        degrade_buffer_colors(rect.buffer_mut(), app.color_depth());
         return;
     }
 
     // Render the current View
     app.state.current_view.render(rect, app, is_active);
+    frame_timer.end_span("View");
 
     // Render Popups
     let z_stack_len = app.state.z_stack.len();
@@ -50,11 +61,416 @@ pub fn draw(rect: &mut Frame, app: &mut App) {
         }
     }
 
+    frame_timer.end_span("Popups");
+
+    ui_helper::render_tutorial(rect, app);
+    frame_timer.end_span("Tutorial");
+
     // Render Toasts
     ui_helper::render_toast(rect, app);
+    frame_timer.end_span("Toasts");
 
     // Render the debug menu if toggled
     if app.state.debug_menu_toggled {
         ui_helper::render_debug_panel(rect, app);
     }
+    frame_timer.end_span("Debug panel");
+
+    // Last, so every RGB color drawn above is brought down to what the terminal can show
+    degrade_buffer_colors(rect.buffer_mut(), app.color_depth());
+    frame_timer.end_span("Color depth");
+
+    if frame_timer.is_enabled() {
+        let sections = frame_timer
+            .spans()
+            .iter()
+            .map(|(section, duration)| format!("{}: {:.3?}", section, duration))
+            .collect::<Vec<String>>();
+        info!(
+            "Frame profile ({:.3?} total) {}",
+            frame_timer.total(),
+            sections.join(", ")
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        app::{
+            app_helper::{handle_mouse_action, refresh_visible_cards_for_all_boards},
+            CardDensity, CardFaceToken,
+        },
+        constants::{MIN_TERM_HEIGHT, MIN_TERM_WIDTH},
+        inputs::mouse::Mouse,
+        io::io_handler::refresh_visible_boards_and_cards,
+        model::kanban::{Board, Boards, Card, CardPriority},
+        ui::{PopUp, View},
+    };
+    use ratatui::{backend::TestBackend, buffer::Buffer, Terminal};
+    use strum::IntoEnumIterator;
+
+    /// The smallest size the app draws at, a slightly bigger one and one below the minimum that
+    /// only shows the size warning
+    const TERMINAL_SIZES: [(u16, u16); 3] = [
+        (MIN_TERM_WIDTH, MIN_TERM_HEIGHT),
+        (MIN_TERM_WIDTH + 10, MIN_TERM_HEIGHT + 5),
+        (60, 20),
+    ];
+
+    fn app_with_selected_card<'a>() -> App<'a> {
+        let (mut app, _io_rx) = App::for_tests();
+        let mut board = Board::new("Todo", "Things to do");
+        let card = Card::default();
+        let card_id = card.id;
+        board.cards.add_card(card);
+        let board_id = board.id;
+        app.boards.set_boards(Boards::from(vec![board]));
+        refresh_visible_boards_and_cards(&mut app);
+        app.state.current_board_id = Some(board_id);
+        app.state.current_card_id = Some(card_id);
+        app
+    }
+
+    fn render(app: &mut App, (width, height): (u16, u16)) -> Buffer {
+        let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+        terminal.draw(|rect| draw(rect, app)).unwrap();
+        terminal.backend().buffer().clone()
+    }
+
+    fn has_text(buffer: &Buffer) -> bool {
+        buffer
+            .content()
+            .iter()
+            .any(|cell| !cell.symbol().trim().is_empty())
+    }
+
+    #[test]
+    fn every_popup_renders_at_small_sizes() {
+        for popup in PopUp::iter() {
+            for with_card in [false, true] {
+                for size in TERMINAL_SIZES {
+                    let mut app = if with_card {
+                        app_with_selected_card()
+                    } else {
+                        App::for_tests().0
+                    };
+                    app.state.z_stack.push(popup);
+                    let buffer = render(&mut app, size);
+                    assert!(
+                        has_text(&buffer),
+                        "{:?} drew nothing at {:?} (with card: {})",
+                        popup,
+                        size,
+                        with_card
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn every_popup_draws_over_the_view() {
+        for popup in PopUp::iter() {
+            let mut app = app_with_selected_card();
+            let without_popup = render(&mut app, TERMINAL_SIZES[0]);
+            app.state.z_stack.push(popup);
+            let with_popup = render(&mut app, TERMINAL_SIZES[0]);
+            assert_ne!(without_popup, with_popup, "{:?} drew nothing", popup);
+        }
+    }
+
+    fn app_with_many_cards<'a>(density: CardDensity) -> (App<'a>, (u64, u64)) {
+        let (mut app, _io_rx) = App::for_tests();
+        let mut board = Board::new("Todo", "");
+        for card_number in 1..=30 {
+            board.cards.add_card(Card {
+                name: format!("Card {:02}", card_number),
+                ..Card::default()
+            });
+        }
+        let board_id = board.id;
+        app.boards.set_boards(Boards::from(vec![board]));
+        app.config.card_density = density;
+        refresh_visible_boards_and_cards(&mut app);
+        app.initialized();
+        app.set_view(View::Zen);
+        (app, board_id)
+    }
+
+    fn shown_card_count(app: &App, board_id: (u64, u64)) -> usize {
+        app.visible_boards_and_cards.get(&board_id).unwrap().len()
+    }
+
+    fn screen_rows(buffer: &Buffer) -> Vec<String> {
+        (0..buffer.area.height)
+            .map(|y| {
+                (0..buffer.area.width)
+                    .map(|x| buffer[(x, y)].symbol())
+                    .collect::<String>()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn single_line_densities_fit_more_cards_at_the_smallest_size() {
+        let (mut comfortable_app, board_id) = app_with_many_cards(CardDensity::Comfortable);
+        render(&mut comfortable_app, TERMINAL_SIZES[0]);
+        let comfortable_count = shown_card_count(&comfortable_app, board_id);
+        assert_eq!(
+            comfortable_count,
+            comfortable_app.config.no_of_cards_to_show as usize
+        );
+        for density in [CardDensity::Compact, CardDensity::Minimal] {
+            let (mut app, board_id) = app_with_many_cards(density);
+            let buffer = render(&mut app, TERMINAL_SIZES[0]);
+            let shown_count = shown_card_count(&app, board_id);
+            assert!(shown_count > comfortable_count, "{}", density);
+            assert_eq!(shown_count, app.get_no_of_cards_to_show() as usize);
+            // Every shown card got a row of its own
+            let rows = screen_rows(&buffer);
+            for card_number in 1..=shown_count {
+                let name = format!("Card {:02}", card_number);
+                assert_eq!(
+                    rows.iter().filter(|row| row.contains(&name)).count(),
+                    1,
+                    "{} {}",
+                    density,
+                    name
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn switching_density_rewindows_the_boards_straight_away() {
+        let (mut app, board_id) = app_with_many_cards(CardDensity::Comfortable);
+        render(&mut app, TERMINAL_SIZES[0]);
+        let comfortable_count = shown_card_count(&app, board_id);
+        app.config.card_density = CardDensity::Compact;
+        refresh_visible_cards_for_all_boards(&mut app);
+        assert!(shown_card_count(&app, board_id) > comfortable_count);
+    }
+
+    #[test]
+    fn the_mouse_selects_the_single_line_card_under_it() {
+        let (mut app, board_id) = app_with_many_cards(CardDensity::Compact);
+        let buffer = render(&mut app, TERMINAL_SIZES[0]);
+        let rows = screen_rows(&buffer);
+        let target_row = rows.iter().position(|row| row.contains("Card 05")).unwrap();
+        let target_column = rows[target_row].find("Card 05").unwrap();
+        let target_column = rows[target_row][..target_column].chars().count();
+        app.state.current_mouse_coordinates = (target_column as u16, target_row as u16);
+        render(&mut app, TERMINAL_SIZES[0]);
+        let expected_card_id = app
+            .boards
+            .get_board_with_id(board_id)
+            .unwrap()
+            .cards
+            .get_card_with_index(4)
+            .unwrap()
+            .id;
+        assert_eq!(app.state.current_card_id, Some(expected_card_id));
+    }
+
+    /// Where the card name starts on screen
+    fn card_position(buffer: &Buffer, card_name: &str) -> (u16, u16) {
+        let rows = screen_rows(buffer);
+        let row = rows.iter().position(|row| row.contains(card_name)).unwrap();
+        let column = rows[row][..rows[row].find(card_name).unwrap()]
+            .chars()
+            .count();
+        (column as u16, row as u16)
+    }
+
+    fn card_name_of(app: &App, board_id: (u64, u64), card_id: Option<(u64, u64)>) -> String {
+        app.boards
+            .get_board_with_id(board_id)
+            .unwrap()
+            .cards
+            .get_card_with_id(card_id.unwrap())
+            .unwrap()
+            .name
+            .clone()
+    }
+
+    #[test]
+    fn a_click_after_a_resize_waits_for_the_pointer_to_move() {
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        let (mut app, board_id) = app_with_many_cards(CardDensity::Compact);
+        let small_size = TERMINAL_SIZES[0];
+        let large_size = (small_size.0 + 20, small_size.1 + 10);
+        let buffer = render(&mut app, small_size);
+        let (x, y) = card_position(&buffer, "Card 05");
+
+        runtime.block_on(handle_mouse_action(&mut app, Mouse::Move(x, y)));
+        render(&mut app, small_size);
+        runtime.block_on(handle_mouse_action(&mut app, Mouse::LeftPress));
+        assert_eq!(app.state.z_stack.last(), Some(&PopUp::ViewCard));
+        assert_eq!(
+            card_name_of(&app, board_id, app.state.current_card_id),
+            "Card 05"
+        );
+        app.close_popup();
+
+        // The layout the pointer was last reported against is gone, the click is dropped
+        let generation_before_resize = app.state.layout_generation;
+        let buffer = render(&mut app, large_size);
+        assert_eq!(app.state.layout_generation, generation_before_resize + 1);
+        runtime.block_on(handle_mouse_action(&mut app, Mouse::LeftPress));
+        assert!(app.state.z_stack.is_empty());
+
+        // Once the pointer moves the click acts on the card under it in the new layout
+        let (x, y) = card_position(&buffer, "Card 07");
+        runtime.block_on(handle_mouse_action(&mut app, Mouse::Move(x, y)));
+        render(&mut app, large_size);
+        runtime.block_on(handle_mouse_action(&mut app, Mouse::LeftPress));
+        assert_eq!(app.state.z_stack.last(), Some(&PopUp::ViewCard));
+        assert_eq!(
+            card_name_of(&app, board_id, app.state.current_card_id),
+            "Card 07"
+        );
+    }
+
+    #[test]
+    fn rendering_at_the_same_size_keeps_the_layout_generation() {
+        let (mut app, _board_id) = app_with_many_cards(CardDensity::Compact);
+        render(&mut app, TERMINAL_SIZES[0]);
+        let generation = app.state.layout_generation;
+        render(&mut app, TERMINAL_SIZES[0]);
+        assert_eq!(app.state.layout_generation, generation);
+    }
+
+    #[test]
+    fn mouse_coordinates_are_clamped_to_the_last_frame() {
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        let (mut app, _board_id) = app_with_many_cards(CardDensity::Compact);
+        let (width, height) = TERMINAL_SIZES[0];
+        render(&mut app, (width + 20, height + 10));
+        render(&mut app, (width, height));
+        runtime.block_on(handle_mouse_action(
+            &mut app,
+            Mouse::Move(width + 15, height + 5),
+        ));
+        assert_eq!(app.state.current_mouse_coordinates, (width - 1, height - 1));
+    }
+
+    #[test]
+    fn a_click_on_a_card_that_scrolled_away_does_not_open_it() {
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        let (mut app, board_id) = app_with_many_cards(CardDensity::Compact);
+        let buffer = render(&mut app, TERMINAL_SIZES[0]);
+        let (x, y) = card_position(&buffer, "Card 03");
+        runtime.block_on(handle_mouse_action(&mut app, Mouse::Move(x, y)));
+        render(&mut app, TERMINAL_SIZES[0]);
+        // The window moves on before the click is handled
+        app.visible_boards_and_cards
+            .get_mut(&board_id)
+            .unwrap()
+            .retain(|card_id| Some(*card_id) != app.state.current_card_id);
+        runtime.block_on(handle_mouse_action(&mut app, Mouse::LeftPress));
+        assert!(app.state.z_stack.is_empty());
+        assert!(app
+            .widgets
+            .toast_widget
+            .toasts
+            .iter()
+            .any(|toast| toast.message == "No card selected"));
+    }
+
+    /// The screen of a single board holding one sample card drawn with the given layout
+    fn render_sample_card(density: CardDensity, card_face_layout: Vec<CardFaceToken>) -> String {
+        let (mut app, _io_rx) = App::for_tests();
+        let mut board = Board::new("Todo", "");
+        board.cards.add_card(Card {
+            name: "Sample card".to_string(),
+            description: "A description".to_string(),
+            priority: CardPriority::High,
+            tags: vec!["docs".to_string()],
+            ..Card::default()
+        });
+        app.boards.set_boards(Boards::from(vec![board]));
+        app.config.card_density = density;
+        app.config.card_face_layout = card_face_layout;
+        refresh_visible_boards_and_cards(&mut app);
+        app.initialized();
+        app.set_view(View::Zen);
+        screen_rows(&render(&mut app, TERMINAL_SIZES[1])).join("\n")
+    }
+
+    #[test]
+    fn the_default_card_face_layout_draws_the_usual_fields() {
+        let screen = render_sample_card(CardDensity::Comfortable, CardFaceToken::default_layout());
+        for expected in [
+            "Sample card",
+            "A description",
+            "Priority: High",
+            "Status: Active",
+        ] {
+            assert!(screen.contains(expected), "{} is missing", expected);
+        }
+        assert!(!screen.contains("Tags: docs"));
+        assert!(!screen.contains("Id: #"));
+    }
+
+    #[test]
+    fn a_custom_card_face_layout_draws_only_its_tokens() {
+        let screen = render_sample_card(
+            CardDensity::Comfortable,
+            vec![
+                CardFaceToken::Priority,
+                CardFaceToken::Name,
+                CardFaceToken::Id,
+                CardFaceToken::Tags,
+            ],
+        );
+        for expected in ["Sample card", "Priority: High | Id: #", "Tags: docs"] {
+            assert!(screen.contains(expected), "{} is missing", expected);
+        }
+        assert!(!screen.contains("A description"));
+        assert!(!screen.contains("Status:"));
+    }
+
+    #[test]
+    fn tokens_the_card_has_no_value_for_are_skipped() {
+        let screen = render_sample_card(
+            CardDensity::Comfortable,
+            vec![
+                CardFaceToken::Name,
+                CardFaceToken::Estimate,
+                CardFaceToken::DueRelative,
+                CardFaceToken::Priority,
+            ],
+        );
+        assert!(screen.contains("Priority: High"));
+        assert!(!screen.contains("Estimate"));
+        assert!(!screen.contains("Due in"));
+        assert!(!screen.contains(" | "));
+    }
+
+    #[test]
+    fn single_line_densities_cap_the_card_face_tokens() {
+        let layout = vec![
+            CardFaceToken::Name,
+            CardFaceToken::Priority,
+            CardFaceToken::Id,
+            CardFaceToken::Tags,
+        ];
+        let compact_screen = render_sample_card(CardDensity::Compact, layout.clone());
+        let compact_row = compact_screen
+            .lines()
+            .find(|row| row.contains("Sample card"))
+            .unwrap();
+        assert!(compact_row.contains("[H] Sample card · #"));
+        assert!(!compact_row.contains("docs"));
+
+        let minimal_screen = render_sample_card(CardDensity::Minimal, layout);
+        let minimal_row = minimal_screen
+            .lines()
+            .find(|row| row.contains("Sample card"))
+            .unwrap();
+        assert!(!minimal_row.contains("[H]"));
+        assert!(!minimal_row.contains(" · "));
+    }
 }
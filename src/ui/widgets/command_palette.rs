@@ -1,30 +1,88 @@
 use crate::{
     app::{
-        app_helper::reset_preview_boards,
+        app_helper::{
+            cancel_focus_timer, find_duplicate_boards, handle_archive_card,
+            handle_copy_board_to_clipboard, handle_copy_card_to_clipboard, handle_duplicate_board,
+            handle_duplicate_card, handle_move_board, open_archived_cards,
+            open_board_accent_color_selector, open_calendar, open_card_highlight_color_selector,
+            open_card_search, open_edit_board_form, open_edit_board_notes_form,
+            open_filter_by_date_range, open_filter_by_priority, open_filter_by_status,
+            open_move_card_to_board, open_set_log_level, open_sort_cards_by, open_stats,
+            open_waiting_on_list, open_whats_new, reset_preview_boards, sync_from_mirror,
+            toggle_auto_sort_by_priority, toggle_focus_timer,
+        },
         handle_exit,
-        state::{AppState, AppStatus, Focus},
-        App, AppReturn,
+        state::{AppState, AppStatus, Focus, KeyBindingEnum},
+        App, AppConfig, AppReturn, ConfigEnum,
     },
-    constants::RANDOM_SEARCH_TERM,
-    io::{io_handler::refresh_visible_boards_and_cards, IoEvent},
+    changelog::CHANGELOG,
+    constants::{
+        BOARD_EXPORT_FILE_PREFIX, CSV_EXPORT_FILE_NAME, FOCUS_TIMER_MINUTES,
+        MARKDOWN_EXPORT_FILE_NAME, RANDOM_SEARCH_TERM,
+    },
+    i18n::{tr, MessageId},
+    io::{
+        data_handler::get_theme_backup_summary, io_handler::refresh_visible_boards_and_cards,
+        IoEvent,
+    },
+    model::save_file::make_file_system_safe_name,
     ui::{widgets::Widget, PopUp, View},
 };
 use log::{debug, error, info};
 use std::{
+    cmp::Reverse,
     fmt::{self, Display},
     vec,
 };
 use strum::{EnumIter, EnumString, IntoEnumIterator};
 
+/// Where in a card the search found the query, lines are the rows of the text split on newlines
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CardSearchField {
+    Name,
+    Description { line: usize },
+    Tags,
+    WaitingOn,
+    Comment { comment: usize, line: usize },
+}
+
+/// A fuzzy match and the chars of the target it matched, counted in chars rather than bytes
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FuzzyMatch {
+    pub score: usize,
+    pub matched_indices: Vec<usize>,
+}
+
+#[derive(Debug, Clone)]
+pub struct CardSearchResult {
+    pub card_id: (u64, u64),
+    pub field: CardSearchField,
+    /// Card name and where it matched, the context snippet is only built for the rows on screen
+    pub search_helper: String,
+    /// Chars of the search helper to highlight, only set when the name matched
+    pub matched_indices: Vec<usize>,
+}
+
+#[derive(Debug, Clone)]
+pub struct BoardSearchResult {
+    pub board_id: (u64, u64),
+    /// Board name and where it matched
+    pub search_helper: String,
+    /// Chars of the search helper to highlight, only set when the name matched
+    pub matched_indices: Vec<usize>,
+}
+
 #[derive(Debug)]
 pub struct CommandPaletteWidget {
     pub already_in_user_input_mode: bool,
     pub available_commands: Vec<CommandPaletteActions>,
-    pub board_search_results: Option<Vec<(String, (u64, u64))>>,
-    pub card_search_results: Option<Vec<(String, (u64, u64))>>,
+    pub board_search_results: Option<Vec<BoardSearchResult>>,
+    pub card_search_results: Option<Vec<CardSearchResult>>,
     pub command_search_results: Option<Vec<CommandPaletteActions>>,
     pub last_focus: Option<Focus>,
     pub last_search_string: String,
+    /// Validation error for a rejected "<setting> <value>" entry, shown instead of the commands
+    pub quick_entry_error: Option<String>,
 }
 
 impl CommandPaletteWidget {
@@ -38,6 +96,7 @@ impl CommandPaletteWidget {
             command_search_results: None,
             last_focus: None,
             last_search_string: RANDOM_SEARCH_TERM.to_string(),
+            quick_entry_error: None,
         }
     }
 
@@ -46,6 +105,7 @@ impl CommandPaletteWidget {
         self.card_search_results = None;
         self.command_search_results = None;
         self.last_search_string = RANDOM_SEARCH_TERM.to_string();
+        self.quick_entry_error = None;
         app_state.text_buffers.command_palette.reset();
         Self::reset_list_states(app_state);
     }
@@ -65,8 +125,150 @@ impl CommandPaletteWidget {
             .select(None);
     }
 
+    /// Scores how well the query matches the target, None unless every character of the query
+    /// appears in it in order
+    pub fn fuzzy_match_score(query: &str, target: &str) -> Option<usize> {
+        Self::fuzzy_match(query, target).map(|fuzzy_match| fuzzy_match.score)
+    }
+
+    /// Lines the query up with the target the way that scores best, None unless every character
+    /// of the query appears in it in order. Each matched character scores 1, runs of consecutive
+    /// characters and matches at the start of a word score higher and plain substrings get a
+    /// bonus, so "ncard" finds "New Card" and "card" ranks "Card" above "Discard". Ties go to the
+    /// earliest characters, so the same input always gets the same score and highlights
+    ///
+    /// ```
+    /// use rust_kanban::ui::widgets::command_palette::CommandPaletteWidget;
+    ///
+    /// let new_card = CommandPaletteWidget::fuzzy_match("ncard", "New Card").unwrap();
+    /// assert_eq!(new_card.matched_indices, [0, 4, 5, 6, 7]);
+    /// assert!(CommandPaletteWidget::fuzzy_match("dracn", "New Card").is_none());
+    ///
+    /// // The word start wins over the earlier "card" inside "Discard"
+    /// let discard_card = CommandPaletteWidget::fuzzy_match("card", "Discard Card").unwrap();
+    /// assert_eq!(discard_card.matched_indices, [8, 9, 10, 11]);
+    ///
+    /// let score = |target: &str| CommandPaletteWidget::fuzzy_match_score("card", target);
+    /// assert!(score("Card") > score("Change Current Card Priority"));
+    /// assert!(score("Change Current Card Priority") > score("Discard"));
+    /// assert!(score("Discard") > score("Clear Archived Data"));
+    /// assert_eq!(score("Discard"), score("Discard"));
+    /// ```
+    pub fn fuzzy_match(query: &str, target: &str) -> Option<FuzzyMatch> {
+        let query = query.to_lowercase().chars().collect::<Vec<char>>();
+        if query.is_empty() {
+            return Some(FuzzyMatch::default());
+        }
+        // One char per target char, so the matched indices line up with the target
+        let target_chars = target
+            .chars()
+            .map(|c| c.to_lowercase().next().unwrap_or(c))
+            .collect::<Vec<char>>();
+        // Most targets do not match at all, a single pass rules them out before the table
+        let mut unmatched_query = query.iter().peekable();
+        for target_char in &target_chars {
+            if unmatched_query.peek() == Some(&target_char) {
+                unmatched_query.next();
+            }
+        }
+        if unmatched_query.peek().is_some() {
+            return None;
+        }
+        let char_score = |target_index: usize| {
+            if target_index == 0 || !target_chars[target_index - 1].is_alphanumeric() {
+                4
+            } else {
+                1
+            }
+        };
+        // best_scores[i][j] is the top score with query[i] matched at target[j], with the
+        // index query[i - 1] was matched at
+        let mut best_scores: Vec<Vec<Option<(usize, usize)>>> =
+            vec![vec![None; target_chars.len()]; query.len()];
+        for (query_index, query_char) in query.iter().enumerate() {
+            // Top score for the previous query character matched before target[j - 1]
+            let mut best_gapped: Option<(usize, usize)> = None;
+            for target_index in 0..target_chars.len() {
+                if query_index > 0 && target_index >= 2 {
+                    if let Some((score, _)) = best_scores[query_index - 1][target_index - 2] {
+                        if best_gapped.is_none_or(|(best_score, _)| score > best_score) {
+                            best_gapped = Some((score, target_index - 2));
+                        }
+                    }
+                }
+                if target_chars[target_index] != *query_char {
+                    continue;
+                }
+                if query_index == 0 {
+                    best_scores[0][target_index] = Some((char_score(target_index), 0));
+                    continue;
+                }
+                let consecutive = target_index.checked_sub(1).and_then(|previous_index| {
+                    best_scores[query_index - 1][previous_index]
+                        .map(|(score, _)| (score + 4, previous_index))
+                });
+                let previous = match (consecutive, best_gapped) {
+                    (Some(consecutive), Some(gapped)) if gapped.0 > consecutive.0 => Some(gapped),
+                    (consecutive, gapped) => consecutive.or(gapped),
+                };
+                best_scores[query_index][target_index] = previous.map(|(score, previous_index)| {
+                    (score + char_score(target_index), previous_index)
+                });
+            }
+        }
+        let mut best_end: Option<(usize, usize)> = None;
+        for (target_index, entry) in best_scores[query.len() - 1].iter().enumerate() {
+            if let Some((score, _)) = entry {
+                if best_end.is_none_or(|(best_score, _)| *score > best_score) {
+                    best_end = Some((*score, target_index));
+                }
+            }
+        }
+        let (mut score, mut target_index) = best_end?;
+        let mut matched_indices = vec![0; query.len()];
+        for query_index in (0..query.len()).rev() {
+            matched_indices[query_index] = target_index;
+            if let Some((_, previous_index)) = best_scores[query_index][target_index] {
+                target_index = previous_index;
+            }
+        }
+        let query_string = query.iter().collect::<String>();
+        let target_lowercase = target_chars.iter().collect::<String>();
+        if target_lowercase.starts_with(&query_string) {
+            score += 2 * query.len();
+        }
+        if target_lowercase.contains(&query_string) {
+            score += 2 * query.len();
+        }
+        Some(FuzzyMatch {
+            score,
+            matched_indices,
+        })
+    }
+
+    /// Splits "tickrate 100" style input into the setting and its value, None when the first
+    /// word is not a quick entry alias
+    fn parse_quick_entry(input: &str) -> Option<(ConfigEnum, String)> {
+        let mut words = input.split_whitespace();
+        let config_enum = ConfigEnum::from_quick_entry_alias(&words.next()?.to_lowercase())?;
+        let value = words.next()?;
+        if words.next().is_some() {
+            return None;
+        }
+        Some((config_enum, value.to_string()))
+    }
+
     pub async fn handle_command(app: &mut App<'_>) -> AppReturn {
-        if let Some(command_index) = app
+        let search_text = app.state.text_buffers.command_palette.get_joined_lines();
+        if let Some((config_enum, value)) = Self::parse_quick_entry(&search_text) {
+            if let Err(error_message) = config_enum.validate_value(&value) {
+                app.widgets.command_palette.quick_entry_error = Some(error_message);
+                return AppReturn::Continue;
+            }
+            app.close_popup();
+            AppConfig::edit_config(app, config_enum, &value);
+            app.widgets.command_palette.reset(&mut app.state);
+        } else if let Some(command_index) = app
             .state
             .app_list_states
             .command_palette_command_search
@@ -103,6 +305,40 @@ impl CommandPaletteWidget {
                         app.close_popup();
                         app.dispatch(IoEvent::SaveLocalData).await;
                     }
+                    CommandPaletteActions::ExportToMarkdown => {
+                        app.close_popup();
+                        let export_path = app.config.save_directory.join(MARKDOWN_EXPORT_FILE_NAME);
+                        app.dispatch(IoEvent::ExportToMarkdown(export_path)).await;
+                    }
+                    CommandPaletteActions::ExportToCsv => {
+                        app.close_popup();
+                        let export_path = app.config.save_directory.join(CSV_EXPORT_FILE_NAME);
+                        app.dispatch(IoEvent::ExportToCsv(export_path)).await;
+                    }
+                    CommandPaletteActions::ExportCurrentBoardToJson => {
+                        app.close_popup();
+                        let current_board = app.state.current_board_id.and_then(|board_id| {
+                            app.boards
+                                .get_board_with_id(board_id)
+                                .map(|board| (board_id, make_file_system_safe_name(&board.name)))
+                        });
+                        if let Some((board_id, safe_name)) = current_board {
+                            let export_path = app
+                                .config
+                                .save_directory
+                                .join(format!("{}{}.json", BOARD_EXPORT_FILE_PREFIX, safe_name));
+                            app.dispatch(IoEvent::ExportBoardToJson(board_id, export_path))
+                                .await;
+                        } else {
+                            app.send_error_toast("No board Selected / Available", None);
+                        }
+                    }
+                    CommandPaletteActions::ExportVisibleToMarkdown => {
+                        app.close_popup();
+                        let export_path = app.config.save_directory.join(MARKDOWN_EXPORT_FILE_NAME);
+                        app.dispatch(IoEvent::ExportVisibleToMarkdown(export_path))
+                            .await;
+                    }
                     CommandPaletteActions::NewBoard => {
                         if View::views_with_kanban_board().contains(&app.state.current_view) {
                             app.close_popup();
@@ -112,6 +348,157 @@ impl CommandPaletteWidget {
                             app.send_error_toast("Cannot create a new board in this view", None);
                         }
                     }
+                    CommandPaletteActions::EditBoard => {
+                        app.close_popup();
+                        if !View::views_with_kanban_board().contains(&app.state.current_view) {
+                            app.send_error_toast(
+                                &tr(MessageId::ToastCannotEditABoardInThisView),
+                                None,
+                            );
+                        } else if !open_edit_board_form(app) {
+                            app.send_error_toast("No board Selected / Available", None);
+                        }
+                    }
+                    CommandPaletteActions::ImportFromTrello => {
+                        app.close_popup();
+                        app.set_popup(PopUp::ImportTrelloPrompt);
+                    }
+                    CommandPaletteActions::ImportFromJson => {
+                        app.close_popup();
+                        app.set_popup(PopUp::ImportJsonPrompt);
+                    }
+                    CommandPaletteActions::ImportBoardFromJson => {
+                        app.close_popup();
+                        app.set_popup(PopUp::ImportBoardJsonPrompt);
+                    }
+                    CommandPaletteActions::ImportFromGithubIssues => {
+                        app.close_popup();
+                        app.set_popup(PopUp::ImportGithubPrompt);
+                    }
+                    CommandPaletteActions::FindDuplicateBoards => {
+                        app.close_popup();
+                        find_duplicate_boards(app);
+                    }
+                    CommandPaletteActions::SearchCards => {
+                        app.close_popup();
+                        if View::views_with_kanban_board().contains(&app.state.current_view) {
+                            open_card_search(app);
+                        } else {
+                            app.send_error_toast(
+                                &tr(MessageId::ToastCannotSearchCardsInThisView),
+                                None,
+                            );
+                        }
+                    }
+                    CommandPaletteActions::ArchiveCurrentCard => {
+                        app.close_popup();
+                        if View::views_with_kanban_board().contains(&app.state.current_view) {
+                            handle_archive_card(app);
+                        } else {
+                            app.send_error_toast(
+                                &tr(MessageId::ToastCannotArchiveACardInThisView),
+                                None,
+                            );
+                        }
+                    }
+                    CommandPaletteActions::DuplicateCurrentBoard => {
+                        app.close_popup();
+                        if View::views_with_kanban_board().contains(&app.state.current_view) {
+                            handle_duplicate_board(app);
+                        } else {
+                            app.send_error_toast(
+                                &tr(MessageId::ToastCannotDuplicateABoardInThisView),
+                                None,
+                            );
+                        }
+                    }
+                    CommandPaletteActions::DuplicateCurrentCard => {
+                        app.close_popup();
+                        if View::views_with_kanban_board().contains(&app.state.current_view) {
+                            handle_duplicate_card(app);
+                        } else {
+                            app.send_error_toast(
+                                &tr(MessageId::ToastCannotDuplicateACardInThisView),
+                                None,
+                            );
+                        }
+                    }
+                    CommandPaletteActions::CopyCardToClipboard => {
+                        app.close_popup();
+                        if View::views_with_kanban_board().contains(&app.state.current_view) {
+                            handle_copy_card_to_clipboard(app);
+                        } else {
+                            app.send_error_toast(
+                                &tr(MessageId::ToastCannotCopyACardInThisView),
+                                None,
+                            );
+                        }
+                    }
+                    CommandPaletteActions::CopyBoardToClipboard => {
+                        app.close_popup();
+                        if View::views_with_kanban_board().contains(&app.state.current_view) {
+                            handle_copy_board_to_clipboard(app, false);
+                        } else {
+                            app.send_error_toast(
+                                &tr(MessageId::ToastCannotCopyABoardInThisView),
+                                None,
+                            );
+                        }
+                    }
+                    CommandPaletteActions::CopyVisibleBoardToClipboard => {
+                        app.close_popup();
+                        if View::views_with_kanban_board().contains(&app.state.current_view) {
+                            handle_copy_board_to_clipboard(app, true);
+                        } else {
+                            app.send_error_toast(
+                                &tr(MessageId::ToastCannotCopyABoardInThisView),
+                                None,
+                            );
+                        }
+                    }
+                    CommandPaletteActions::OpenArchivedCards => {
+                        app.close_popup();
+                        open_archived_cards(app);
+                    }
+                    CommandPaletteActions::OpenStats => {
+                        app.close_popup();
+                        open_stats(app);
+                    }
+                    CommandPaletteActions::OpenCalendar => {
+                        app.close_popup();
+                        open_calendar(app);
+                    }
+                    CommandPaletteActions::ShowWaitingOnList => {
+                        app.close_popup();
+                        open_waiting_on_list(app);
+                    }
+                    CommandPaletteActions::UndoAllSinceLastSave => {
+                        app.close_popup();
+                        if View::views_with_kanban_board().contains(&app.state.current_view) {
+                            app.undo_to_save_point();
+                        } else {
+                            app.send_error_toast(&tr(MessageId::ToastCannotUndoInThisView), None);
+                        }
+                    }
+                    CommandPaletteActions::RedoAll => {
+                        app.close_popup();
+                        if View::views_with_kanban_board().contains(&app.state.current_view) {
+                            app.redo_all();
+                        } else {
+                            app.send_error_toast(&tr(MessageId::ToastCannotRedoInThisView), None);
+                        }
+                    }
+                    CommandPaletteActions::EditBoardNotes => {
+                        app.close_popup();
+                        if !View::views_with_kanban_board().contains(&app.state.current_view) {
+                            app.send_error_toast(
+                                &tr(MessageId::ToastCannotEditBoardNotesInThisView),
+                                None,
+                            );
+                        } else if !open_edit_board_notes_form(app) {
+                            app.send_error_toast("No board Selected / Available", None);
+                        }
+                    }
                     CommandPaletteActions::NewCard => {
                         if View::views_with_kanban_board().contains(&app.state.current_view) {
                             if app.state.current_board_id.is_none() {
@@ -194,6 +581,148 @@ impl CommandPaletteWidget {
                         }
                         app.send_error_toast("Could not find current card", None);
                     }
+                    CommandPaletteActions::ProfileNextFrame => {
+                        app.close_popup();
+                        app.state.profile_next_frame = true;
+                        app.send_info_toast(
+                            "Render timings for the next frame will be written to the log",
+                            None,
+                        );
+                    }
+                    CommandPaletteActions::SetLogLevel => {
+                        app.close_popup();
+                        open_set_log_level(app);
+                    }
+                    CommandPaletteActions::RestoreThemesFromBackup => {
+                        app.close_popup();
+                        match get_theme_backup_summary() {
+                            Ok(summary) if summary.backup_themes > 0 => {
+                                app.state.theme_backup_summary = Some(summary);
+                                app.set_popup(PopUp::ConfirmRestoreThemesFromBackup);
+                                app.state.app_status = AppStatus::Initialized;
+                            }
+                            Ok(_) => {
+                                app.send_warning_toast(
+                                    &tr(MessageId::ToastNoThemeBackupsFound),
+                                    None,
+                                );
+                            }
+                            Err(e) => {
+                                error!("Could not read theme backups: {}", e);
+                                app.send_error_toast(
+                                    &tr(MessageId::ToastCouldNotReadThemeBackups),
+                                    None,
+                                );
+                            }
+                        }
+                    }
+                    CommandPaletteActions::SortCurrentBoardCards => {
+                        if !View::views_with_kanban_board().contains(&app.state.current_view) {
+                            app.send_error_toast(
+                                &tr(MessageId::ToastCannotSortCardsInThisView),
+                                None,
+                            );
+                            return AppReturn::Continue;
+                        }
+                        app.close_popup();
+                        if !open_sort_cards_by(app) {
+                            app.send_error_toast("No board selected", None);
+                        }
+                    }
+                    CommandPaletteActions::ToggleAutoSortByPriority => {
+                        app.close_popup();
+                        if !View::views_with_kanban_board().contains(&app.state.current_view) {
+                            app.send_error_toast(
+                                &tr(MessageId::ToastCannotSortCardsInThisView),
+                                None,
+                            );
+                        } else {
+                            toggle_auto_sort_by_priority(app);
+                        }
+                    }
+                    CommandPaletteActions::MoveCurrentCardToBoard => {
+                        if !View::views_with_kanban_board().contains(&app.state.current_view) {
+                            app.send_error_toast(
+                                &tr(MessageId::ToastCannotMoveCardsInThisView),
+                                None,
+                            );
+                            return AppReturn::Continue;
+                        }
+                        app.close_popup();
+                        open_move_card_to_board(app);
+                    }
+                    CommandPaletteActions::SetCurrentBoardAccentColor => {
+                        if !View::views_with_kanban_board().contains(&app.state.current_view) {
+                            app.send_error_toast(
+                                &tr(MessageId::ToastCannotChangeBoardAccentInThisView),
+                                None,
+                            );
+                            return AppReturn::Continue;
+                        }
+                        app.close_popup();
+                        if !open_board_accent_color_selector(app) {
+                            app.send_error_toast(
+                                &tr(MessageId::ToastCouldNotFindCurrentBoard),
+                                None,
+                            );
+                        }
+                    }
+                    CommandPaletteActions::SetCurrentCardHighlight => {
+                        if !View::views_with_kanban_board().contains(&app.state.current_view) {
+                            app.send_error_toast(
+                                &tr(MessageId::ToastCannotChangeCardHighlightInThisView),
+                                None,
+                            );
+                            return AppReturn::Continue;
+                        }
+                        app.close_popup();
+                        if !open_card_highlight_color_selector(app) {
+                            app.send_error_toast("Could not find current card", None);
+                        }
+                    }
+                    CommandPaletteActions::SyncFromMirror => {
+                        app.close_popup();
+                        sync_from_mirror(app, false);
+                        app.state.app_status = AppStatus::Initialized;
+                    }
+                    CommandPaletteActions::ToggleHighContrastMode => {
+                        app.close_popup();
+                        let toggled_value = app
+                            .config
+                            .get_toggled_value_as_string(ConfigEnum::HighContrastMode);
+                        AppConfig::edit_config(app, ConfigEnum::HighContrastMode, &toggled_value);
+                    }
+                    CommandPaletteActions::ToggleFocusTimer => {
+                        app.close_popup();
+                        toggle_focus_timer(app);
+                    }
+                    CommandPaletteActions::CancelFocusTimer => {
+                        app.close_popup();
+                        cancel_focus_timer(app);
+                    }
+                    CommandPaletteActions::InteractiveTutorial => {
+                        app.close_popup();
+                        if app.widgets.tutorial.step.is_some() {
+                            app.widgets.tutorial.stop();
+                            app.send_info_toast(
+                                "Tutorial stopped, everything you made is kept",
+                                None,
+                            );
+                        } else {
+                            app.widgets.tutorial.start(&app.boards);
+                        }
+                    }
+                    CommandPaletteActions::WhatsNew => {
+                        app.close_popup();
+                        open_whats_new(app, CHANGELOG.iter().collect());
+                    }
+                    CommandPaletteActions::CycleCardDensity => {
+                        app.close_popup();
+                        let next_density = app
+                            .config
+                            .get_toggled_value_as_string(ConfigEnum::CardDensity);
+                        AppConfig::edit_config(app, ConfigEnum::CardDensity, &next_density);
+                    }
                     CommandPaletteActions::LoadASaveLocal => {
                         app.close_popup();
                         reset_preview_boards(app);
@@ -221,8 +750,45 @@ impl CommandPaletteWidget {
                             app.state.all_available_tags = Some(tags);
                         }
                     }
+                    CommandPaletteActions::FilterByPriority => {
+                        if !View::views_with_kanban_board().contains(&app.state.current_view) {
+                            app.send_error_toast(
+                                &tr(MessageId::ToastCannotFilterCardsInThisView),
+                                None,
+                            );
+                            return AppReturn::Continue;
+                        }
+                        app.close_popup();
+                        open_filter_by_priority(app);
+                    }
+                    CommandPaletteActions::FilterByStatus => {
+                        if !View::views_with_kanban_board().contains(&app.state.current_view) {
+                            app.send_error_toast(
+                                &tr(MessageId::ToastCannotFilterCardsInThisView),
+                                None,
+                            );
+                            return AppReturn::Continue;
+                        }
+                        app.close_popup();
+                        open_filter_by_status(app);
+                    }
+                    CommandPaletteActions::FilterByDateRange => {
+                        if !View::views_with_kanban_board().contains(&app.state.current_view) {
+                            app.send_error_toast(
+                                &tr(MessageId::ToastCannotFilterCardsInThisView),
+                                None,
+                            );
+                            return AppReturn::Continue;
+                        }
+                        app.close_popup();
+                        open_filter_by_date_range(app);
+                    }
                     CommandPaletteActions::ClearFilter => {
-                        if app.filtered_boards.is_empty() {
+                        if app.filtered_boards.is_empty()
+                            && app.state.active_status_filter.is_none()
+                            && app.state.active_priority_filter.is_none()
+                            && app.state.active_date_filter.is_none()
+                        {
                             app.send_warning_toast("No filters to clear", None);
                             return AppReturn::Continue;
                         } else {
@@ -230,7 +796,11 @@ impl CommandPaletteWidget {
                         }
                         app.state.filter_tags = None;
                         app.state.all_available_tags = None;
+                        app.state.active_status_filter = None;
+                        app.state.active_priority_filter = None;
+                        app.state.active_date_filter = None;
                         app.state.app_list_states.filter_by_tag_list.select(None);
+                        app.state.text_buffers.card_search.reset();
                         app.close_popup();
                         app.filtered_boards.reset();
                         refresh_visible_boards_and_cards(app);
@@ -270,6 +840,10 @@ impl CommandPaletteWidget {
                         app.dispatch(IoEvent::SyncLocalData).await;
                         app.close_popup();
                     }
+                    CommandPaletteActions::ForceFullSync => {
+                        app.dispatch(IoEvent::ForceSyncLocalData).await;
+                        app.close_popup();
+                    }
                     CommandPaletteActions::LoadASaveCloud => {
                         if app.state.user_login_data.auth_token.is_some() {
                             app.set_view(View::LoadCloudSave);
@@ -285,89 +859,12 @@ impl CommandPaletteWidget {
                         }
                     }
                     CommandPaletteActions::MoveBoardLeft => {
-                        if let Some(current_board_id) = app.state.current_board_id {
-                            let current_board_index = app.boards.get_board_index(current_board_id);
-                            if current_board_index.is_none() {
-                                app.send_error_toast("No board selected", None);
-                                return AppReturn::Continue;
-                            }
-                            let current_board_index = current_board_index.unwrap();
-                            let board_name = app
-                                .boards
-                                .get_board_with_id(current_board_id)
-                                .unwrap()
-                                .name
-                                .clone();
-                            if current_board_index == 0 {
-                                app.send_error_toast(
-                                    format!("'{}' is already the first board", board_name).as_str(),
-                                    None,
-                                );
-                                return AppReturn::Continue;
-                            }
-                            let swap_result = app
-                                .boards
-                                .swap(current_board_index, current_board_index - 1);
-
-                            if swap_result.is_err() {
-                                app.send_error_toast(
-                                    format!("Could not move '{}' to the left", board_name).as_str(),
-                                    None,
-                                );
-                            }
-
-                            app.close_popup();
-                            app.send_info_toast(
-                                format!("'{}' moved to the left", board_name).as_str(),
-                                None,
-                            );
-                            refresh_visible_boards_and_cards(app);
-                        } else {
-                            app.send_error_toast("No board selected", None);
-                        }
+                        app.close_popup();
+                        handle_move_board(app, false);
                     }
                     CommandPaletteActions::MoveBoardRight => {
-                        if let Some(current_board_id) = app.state.current_board_id {
-                            let current_board_index = app.boards.get_board_index(current_board_id);
-                            if current_board_index.is_none() {
-                                app.send_error_toast("No board selected", None);
-                                return AppReturn::Continue;
-                            }
-                            let current_board_index = current_board_index.unwrap();
-                            let board_name = app
-                                .boards
-                                .get_board_with_id(current_board_id)
-                                .unwrap()
-                                .name
-                                .clone();
-                            if current_board_index == app.boards.get_boards().len() - 1 {
-                                app.send_error_toast(
-                                    format!("'{}' is already the last board", board_name).as_str(),
-                                    None,
-                                );
-                                return AppReturn::Continue;
-                            }
-                            let swap_result = app
-                                .boards
-                                .swap(current_board_index, current_board_index + 1);
-
-                            if swap_result.is_err() {
-                                app.send_error_toast(
-                                    format!("Could not move '{}' to the right", board_name)
-                                        .as_str(),
-                                    None,
-                                );
-                            }
-
-                            app.close_popup();
-                            app.send_info_toast(
-                                format!("'{}' moved to the right", board_name).as_str(),
-                                None,
-                            );
-                            refresh_visible_boards_and_cards(app);
-                        } else {
-                            app.send_error_toast("No board selected", None);
-                        }
+                        app.close_popup();
+                        handle_move_board(app, true);
                     }
                 }
                 app.widgets.command_palette.reset(&mut app.state);
@@ -381,13 +878,72 @@ impl CommandPaletteWidget {
             app.widgets.command_palette.already_in_user_input_mode = false;
             app.widgets.command_palette.last_focus = None;
         }
-        if app.state.z_stack.last() != Some(&PopUp::CustomHexColorPromptFG)
-            || app.state.z_stack.last() != Some(&PopUp::CustomHexColorPromptBG)
-        {
+        // Prompts opened by the command start out taking input
+        if !matches!(
+            app.state.z_stack.last(),
+            Some(
+                PopUp::CustomHexColorPromptFG
+                    | PopUp::CustomHexColorPromptBG
+                    | PopUp::ImportTrelloPrompt
+                    | PopUp::ImportJsonPrompt
+                    | PopUp::ImportBoardJsonPrompt
+                    | PopUp::ImportGithubPrompt
+                    | PopUp::SearchCards
+            )
+        ) {
             app.state.app_status = AppStatus::Initialized;
         }
         AppReturn::Continue
     }
+
+    /// Opens the keybinding editor for the highlighted command, the palette is reopened once
+    /// the edit is done
+    pub fn edit_keybinding_for_selected_command(app: &mut App) -> AppReturn {
+        let selected_command = app
+            .state
+            .app_list_states
+            .command_palette_command_search
+            .selected()
+            .and_then(|command_index| {
+                app.widgets
+                    .command_palette
+                    .command_search_results
+                    .as_ref()
+                    .and_then(|search_results| search_results.get(command_index))
+            })
+            .cloned();
+        let Some(command) = selected_command else {
+            debug!("No command selected in the command palette");
+            return AppReturn::Continue;
+        };
+        let Some(keybinding_enum) = command.keybinding_enum() else {
+            app.send_warning_toast(&format!("'{}' has no keybinding to edit", command), None);
+            return AppReturn::Continue;
+        };
+        // Same ordering edit_keybinding uses to resolve the selected index
+        let mut keybinding_enums = app
+            .config
+            .keybindings
+            .iter()
+            .map(|(keybinding_enum, _)| keybinding_enum)
+            .collect::<Vec<KeyBindingEnum>>();
+        keybinding_enums.sort_by_key(|keybinding_enum| keybinding_enum.to_string());
+        let keybinding_index = keybinding_enums
+            .iter()
+            .position(|candidate| *candidate == keybinding_enum);
+
+        app.close_popup();
+        app.widgets.command_palette.reset(&mut app.state);
+        app.state
+            .app_table_states
+            .edit_keybindings
+            .select(keybinding_index);
+        app.state.edited_keybinding = None;
+        app.state.keybinding_edit_from_command_palette = true;
+        app.set_popup(PopUp::EditSpecificKeyBinding);
+        app.state.app_status = AppStatus::KeyBindMode;
+        AppReturn::Continue
+    }
 }
 
 impl Widget for CommandPaletteWidget {
@@ -403,124 +959,171 @@ impl Widget for CommandPaletteWidget {
             {
                 return;
             }
+            app.widgets.command_palette.quick_entry_error = None;
             let current_search_string = app.state.text_buffers.command_palette.get_joined_lines();
             let current_search_string = current_search_string.to_lowercase();
-            let search_results = app
+            let mut scored_commands = app
                 .widgets
                 .command_palette
                 .available_commands
                 .iter()
-                .filter(|action| {
-                    action
-                        .to_string()
-                        .to_lowercase()
-                        .contains(&current_search_string)
+                .filter_map(|action| {
+                    CommandPaletteWidget::fuzzy_match_score(
+                        &current_search_string,
+                        &action.to_string(),
+                    )
+                    .map(|score| (score, action.clone()))
                 })
-                .cloned()
+                .collect::<Vec<(usize, CommandPaletteActions)>>();
+            // Stable, so equal scores keep the alphabetical order of the commands
+            scored_commands.sort_by_key(|(score, ..)| Reverse(*score));
+            let mut command_search_results = scored_commands
+                .into_iter()
+                .map(|(_, action)| action)
                 .collect::<Vec<CommandPaletteActions>>();
-
-            // Making sure the results which start with the search string are shown first
-            let mut command_search_results = if search_results.is_empty() {
-                if current_search_string.is_empty() {
-                    CommandPaletteActions::all(app.debug_mode)
-                } else {
-                    let all_actions = CommandPaletteActions::all(app.debug_mode);
-                    let mut results = vec![];
-                    for action in all_actions {
-                        if action
-                            .to_string()
-                            .to_lowercase()
-                            .starts_with(&current_search_string)
-                        {
-                            results.push(action);
-                        }
-                    }
-                    results
-                }
-            } else {
-                let mut ordered_command_search_results = vec![];
-                let mut extra_command_results = vec![];
-                for result in search_results {
-                    if result
-                        .to_string()
-                        .to_lowercase()
-                        .starts_with(&current_search_string)
-                    {
-                        ordered_command_search_results.push(result);
-                    } else {
-                        extra_command_results.push(result);
-                    }
-                }
-                ordered_command_search_results.extend(extra_command_results);
-                ordered_command_search_results
-            };
             if command_search_results.is_empty() {
                 command_search_results = vec![CommandPaletteActions::NoCommandsFound]
             }
 
-            let mut card_search_results: Vec<(String, (u64, u64))> = vec![];
+            // Names are matched fuzzily, the longer fields only by substring as scattered
+            // characters would be found in almost any description
+            let substring_score = |field: &str| {
+                if field.to_lowercase().contains(&current_search_string) {
+                    CommandPaletteWidget::fuzzy_match_score(&current_search_string, field)
+                } else {
+                    None
+                }
+            };
+            let matched_line = |field: &str| {
+                field
+                    .split('\n')
+                    .position(|line| line.to_lowercase().contains(&current_search_string))
+                    .unwrap_or(0)
+            };
+            let mut scored_cards: Vec<(usize, CardSearchResult)> = vec![];
             if !current_search_string.is_empty() {
                 for board in app.boards.get_boards() {
                     for card in board.cards.get_all_cards() {
-                        let search_helper =
-                            if card.name.to_lowercase().contains(&current_search_string) {
-                                format!("{} - Matched in Name", card.name)
-                            } else if card
-                                .description
-                                .to_lowercase()
-                                .contains(&current_search_string)
-                            {
-                                format!("{} - Matched in Description", card.name)
-                            } else if card
-                                .tags
+                        let search_match = if let Some(name_match) =
+                            CommandPaletteWidget::fuzzy_match(&current_search_string, &card.name)
+                        {
+                            Some((
+                                name_match.score,
+                                CardSearchField::Name,
+                                "Name",
+                                name_match.matched_indices,
+                            ))
+                        } else if let Some(score) = substring_score(&card.description) {
+                            Some((
+                                score,
+                                CardSearchField::Description {
+                                    line: matched_line(&card.description),
+                                },
+                                "Description",
+                                vec![],
+                            ))
+                        } else if let Some(score) = card
+                            .tags
+                            .iter()
+                            .filter_map(|tag| substring_score(tag))
+                            .max()
+                        {
+                            Some((score, CardSearchField::Tags, "Tags", vec![]))
+                        } else if let Some(score) =
+                            card.waiting_on.as_deref().and_then(substring_score)
+                        {
+                            Some((score, CardSearchField::WaitingOn, "Waiting on", vec![]))
+                        } else {
+                            card.comments
                                 .iter()
-                                .any(|tag| tag.to_lowercase().contains(&current_search_string))
-                            {
-                                format!("{} - Matched in Tags", card.name)
-                            } else if card.comments.iter().any(|comment| {
-                                comment.to_lowercase().contains(&current_search_string)
-                            }) {
-                                format!("{} - Matched in Comments", card.name)
-                            } else {
-                                String::new()
-                            };
-                        if !search_helper.is_empty() {
-                            card_search_results.push((search_helper, card.id));
+                                .enumerate()
+                                .filter_map(|(comment_index, comment)| {
+                                    substring_score(comment).map(|score| (score, comment_index))
+                                })
+                                .max_by_key(|(score, comment_index)| {
+                                    (*score, Reverse(*comment_index))
+                                })
+                                .map(|(score, comment_index)| {
+                                    (
+                                        score,
+                                        CardSearchField::Comment {
+                                            comment: comment_index,
+                                            line: matched_line(&card.comments[comment_index]),
+                                        },
+                                        "Comments",
+                                        vec![],
+                                    )
+                                })
+                        };
+                        if let Some((score, field, field_name, matched_indices)) = search_match {
+                            scored_cards.push((
+                                score,
+                                CardSearchResult {
+                                    card_id: card.id,
+                                    field,
+                                    search_helper: format!(
+                                        "{} - Matched in {}",
+                                        card.name, field_name
+                                    ),
+                                    matched_indices,
+                                },
+                            ));
                         }
                     }
                 }
             }
+            scored_cards.sort_by_key(|(score, ..)| Reverse(*score));
+            let card_search_results = scored_cards
+                .into_iter()
+                .map(|(_, card_search_result)| card_search_result)
+                .collect::<Vec<CardSearchResult>>();
             if card_search_results.is_empty() {
                 app.widgets.command_palette.card_search_results = None;
             } else {
-                app.widgets.command_palette.card_search_results = Some(card_search_results.clone());
+                app.widgets.command_palette.card_search_results = Some(card_search_results);
             }
 
-            let mut board_search_results: Vec<(String, (u64, u64))> = vec![];
+            let mut scored_boards: Vec<(usize, BoardSearchResult)> = vec![];
             if !current_search_string.is_empty() {
                 for board in app.boards.get_boards() {
-                    let search_helper =
-                        if board.name.to_lowercase().contains(&current_search_string) {
-                            format!("{} - Matched in Name", board.name)
-                        } else if board
-                            .description
-                            .to_lowercase()
-                            .contains(&current_search_string)
-                        {
-                            format!("{} - Matched in Description", board.name)
-                        } else {
-                            String::new()
-                        };
-                    if !search_helper.is_empty() {
-                        board_search_results.push((search_helper, board.id));
+                    let search_match = if let Some(name_match) =
+                        CommandPaletteWidget::fuzzy_match(&current_search_string, &board.name)
+                    {
+                        Some((
+                            name_match.score,
+                            format!("{} - Matched in Name", board.name),
+                            name_match.matched_indices,
+                        ))
+                    } else {
+                        substring_score(&board.description).map(|score| {
+                            (
+                                score,
+                                format!("{} - Matched in Description", board.name),
+                                vec![],
+                            )
+                        })
+                    };
+                    if let Some((score, search_helper, matched_indices)) = search_match {
+                        scored_boards.push((
+                            score,
+                            BoardSearchResult {
+                                board_id: board.id,
+                                search_helper,
+                                matched_indices,
+                            },
+                        ));
                     }
                 }
             }
+            scored_boards.sort_by_key(|(score, ..)| Reverse(*score));
+            let board_search_results = scored_boards
+                .into_iter()
+                .map(|(_, board_search_result)| board_search_result)
+                .collect::<Vec<BoardSearchResult>>();
             if board_search_results.is_empty() {
                 app.widgets.command_palette.board_search_results = None;
             } else {
-                app.widgets.command_palette.board_search_results =
-                    Some(board_search_results.clone());
+                app.widgets.command_palette.board_search_results = Some(board_search_results);
             }
 
             app.widgets.command_palette.command_search_results = Some(command_search_results);
@@ -539,6 +1142,8 @@ impl Widget for CommandPaletteWidget {
 
 #[derive(Clone, Debug, PartialEq, EnumIter, EnumString)]
 pub enum CommandPaletteActions {
+    ArchiveCurrentCard,
+    CancelFocusTimer,
     ChangeCurrentCardStatus,
     ChangeCurrentCardPriority,
     ChangeDateFormat,
@@ -546,10 +1151,31 @@ pub enum CommandPaletteActions {
     ChangeView,
     ClearFilter,
     ConfigMenu,
+    CopyBoardToClipboard,
+    CopyCardToClipboard,
+    CopyVisibleBoardToClipboard,
     CreateATheme,
     DebugMenu,
+    DuplicateCurrentBoard,
+    DuplicateCurrentCard,
+    EditBoard,
+    EditBoardNotes,
+    ExportCurrentBoardToJson,
+    ExportToCsv,
+    ExportToMarkdown,
+    ExportVisibleToMarkdown,
+    FilterByDateRange,
+    FilterByPriority,
+    FilterByStatus,
     FilterByTag,
+    FindDuplicateBoards,
+    ForceFullSync,
     HelpMenu,
+    ImportFromGithubIssues,
+    ImportBoardFromJson,
+    ImportFromJson,
+    ImportFromTrello,
+    InteractiveTutorial,
     LoadASaveCloud,
     LoadASaveLocal,
     Login,
@@ -558,28 +1184,73 @@ pub enum CommandPaletteActions {
     NewBoard,
     NewCard,
     NoCommandsFound,
+    OpenArchivedCards,
+    OpenStats,
+    OpenCalendar,
+    ProfileNextFrame,
     Quit,
+    RedoAll,
     ResetPassword,
     ResetUI,
+    RestoreThemesFromBackup,
     SaveKanbanState,
+    SearchCards,
     SignUp,
+    SyncFromMirror,
     SyncLocalData,
     MoveBoardLeft,
     MoveBoardRight,
+    MoveCurrentCardToBoard,
+    SetCurrentBoardAccentColor,
+    SetCurrentCardHighlight,
+    SetLogLevel,
+    ShowWaitingOnList,
+    SortCurrentBoardCards,
+    ToggleAutoSortByPriority,
+    ToggleHighContrastMode,
+    CycleCardDensity,
+    ToggleFocusTimer,
+    UndoAllSinceLastSave,
+    WhatsNew,
 }
 
 impl Display for CommandPaletteActions {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
+            Self::ArchiveCurrentCard => write!(f, "Archive Current Card"),
+            Self::DuplicateCurrentBoard => write!(f, "Duplicate Current Board"),
+            Self::DuplicateCurrentCard => write!(f, "Duplicate Current Card"),
+            Self::CancelFocusTimer => write!(f, "Cancel Focus Timer"),
             Self::ChangeCurrentCardStatus => write!(f, "Change Current Card Status"),
             Self::ChangeCurrentCardPriority => write!(f, "Change Current Card Priority"),
             Self::ChangeDateFormat => write!(f, "Change Date Format"),
             Self::ChangeTheme => write!(f, "Change Theme"),
             Self::ChangeView => write!(f, "Change View"),
             Self::ClearFilter => write!(f, "Clear Filter"),
+            Self::CopyBoardToClipboard => write!(f, "Copy Board to Clipboard"),
+            Self::CopyCardToClipboard => write!(f, "Copy Card to Clipboard"),
+            Self::CopyVisibleBoardToClipboard => {
+                write!(f, "Copy Board to Clipboard (Visible Cards Only)")
+            }
             Self::CreateATheme => write!(f, "Create a Theme"),
             Self::DebugMenu => write!(f, "Toggle Debug Panel"),
+            Self::EditBoard => write!(f, "Edit Current Board"),
+            Self::EditBoardNotes => write!(f, "Edit Board Notes"),
+            Self::ExportCurrentBoardToJson => write!(f, "Export Current Board to JSON"),
+            Self::ExportToCsv => write!(f, "Export to CSV"),
+            Self::ExportToMarkdown => write!(f, "Export to Markdown"),
+            Self::ExportVisibleToMarkdown => write!(f, "Export to Markdown (Visible Cards Only)"),
+            Self::FilterByPriority => write!(f, "Filter by Priority"),
+            Self::FilterByStatus => write!(f, "Filter by Status"),
+            Self::FilterByDateRange => write!(f, "Filter by Due Date"),
             Self::FilterByTag => write!(f, "Filter by Tag"),
+            Self::FindDuplicateBoards => write!(f, "Find Duplicate Boards"),
+            Self::ForceFullSync => write!(f, "Force Full Sync"),
+            Self::ImportFromGithubIssues => write!(f, "Import from GitHub Issues"),
+            Self::ImportBoardFromJson => write!(f, "Import Board from JSON"),
+            Self::ImportFromJson => write!(f, "Import from JSON"),
+            Self::ImportFromTrello => write!(f, "Import from Trello"),
+            Self::InteractiveTutorial => write!(f, "Interactive Tutorial"),
             Self::LoadASaveCloud => write!(f, "Load a Save (Cloud)"),
             Self::LoadASaveLocal => write!(f, "Load a Save (Local)"),
             Self::Login => write!(f, "Login"),
@@ -587,17 +1258,43 @@ impl Display for CommandPaletteActions {
             Self::NewBoard => write!(f, "New Board"),
             Self::NewCard => write!(f, "New Card"),
             Self::NoCommandsFound => write!(f, "No Commands Found"),
+            Self::OpenArchivedCards => write!(f, "Open Archived Cards"),
+            Self::OpenStats => write!(f, "Open Stats"),
+            Self::OpenCalendar => write!(f, "Open Calendar"),
+            Self::ProfileNextFrame => write!(f, "Profile Next Frame"),
             Self::ConfigMenu => write!(f, "Configure"),
             Self::HelpMenu => write!(f, "Open Help Menu"),
             Self::MainMenu => write!(f, "Open Main Menu"),
             Self::Quit => write!(f, "Quit"),
             Self::ResetPassword => write!(f, "Reset Password"),
             Self::ResetUI => write!(f, "Reset UI"),
+            Self::RestoreThemesFromBackup => write!(f, "Restore Themes from Backup"),
             Self::SaveKanbanState => write!(f, "Save Kanban State"),
+            Self::SearchCards => write!(f, "Search Cards"),
             Self::SignUp => write!(f, "Sign Up"),
+            Self::SyncFromMirror => write!(f, "Sync from Markdown Mirror"),
             Self::SyncLocalData => write!(f, "Sync Local Data"),
             Self::MoveBoardLeft => write!(f, "Move Current Board Left"),
             Self::MoveBoardRight => write!(f, "Move Current Board Right"),
+            Self::MoveCurrentCardToBoard => write!(f, "Move Current Card to Board"),
+            Self::SetCurrentBoardAccentColor => write!(f, "Set Current Board Accent Color"),
+            Self::SetCurrentCardHighlight => write!(f, "Set Current Card Highlight"),
+            Self::SetLogLevel => write!(f, "Set Log Level"),
+            Self::ShowWaitingOnList => write!(f, "Show Waiting-On List"),
+            Self::SortCurrentBoardCards => write!(f, "Sort Cards in Current Board"),
+            Self::ToggleAutoSortByPriority => {
+                write!(f, "Toggle Auto Sort by Priority for Current Board")
+            }
+            Self::ToggleHighContrastMode => write!(f, "Toggle High Contrast Mode"),
+            Self::CycleCardDensity => write!(f, "Cycle Card Density"),
+            Self::ToggleFocusTimer => write!(
+                f,
+                "Start or Pause {} Minute Focus on Current Card",
+                FOCUS_TIMER_MINUTES
+            ),
+            Self::RedoAll => write!(f, "Redo All"),
+            Self::UndoAllSinceLastSave => write!(f, "Undo All Since Last Save"),
+            Self::WhatsNew => write!(f, "What's New"),
         }
     }
 }
@@ -618,4 +1315,193 @@ impl CommandPaletteActions {
                 .collect()
         }
     }
+
+    /// The keybinding that does the same thing as the command, kept exhaustive so new
+    /// commands have to decide whether they have a shortcut
+    pub fn keybinding_enum(&self) -> Option<KeyBindingEnum> {
+        match self {
+            Self::ArchiveCurrentCard => Some(KeyBindingEnum::ArchiveCard),
+            Self::DuplicateCurrentBoard => Some(KeyBindingEnum::DuplicateBoard),
+            Self::DuplicateCurrentCard => Some(KeyBindingEnum::DuplicateCard),
+            Self::MoveBoardLeft => Some(KeyBindingEnum::MoveBoardLeft),
+            Self::MoveBoardRight => Some(KeyBindingEnum::MoveBoardRight),
+            Self::ConfigMenu => Some(KeyBindingEnum::OpenConfigMenu),
+            Self::EditBoard => Some(KeyBindingEnum::EditBoard),
+            Self::ExportToMarkdown => Some(KeyBindingEnum::ExportToMarkdown),
+            Self::FilterByPriority => Some(KeyBindingEnum::FilterByPriority),
+            Self::FilterByStatus => Some(KeyBindingEnum::FilterByStatus),
+            Self::FilterByDateRange => Some(KeyBindingEnum::FilterByDateRange),
+            Self::MainMenu => Some(KeyBindingEnum::GoToMainMenu),
+            Self::NewBoard => Some(KeyBindingEnum::NewBoard),
+            Self::NewCard => Some(KeyBindingEnum::NewCard),
+            Self::Quit => Some(KeyBindingEnum::Quit),
+            Self::ResetUI => Some(KeyBindingEnum::ResetUI),
+            Self::SaveKanbanState => Some(KeyBindingEnum::SaveState),
+            Self::MoveCurrentCardToBoard => Some(KeyBindingEnum::MoveCardToBoard),
+            Self::SetCurrentCardHighlight => Some(KeyBindingEnum::SetCardHighlightColor),
+            Self::SortCurrentBoardCards => Some(KeyBindingEnum::SortCards),
+            Self::ToggleFocusTimer => Some(KeyBindingEnum::ToggleFocusTimer),
+            Self::CancelFocusTimer
+            | Self::EditBoardNotes
+            | Self::ToggleAutoSortByPriority
+            | Self::ExportCurrentBoardToJson
+            | Self::ExportToCsv
+            | Self::ExportVisibleToMarkdown
+            | Self::ImportFromGithubIssues
+            | Self::ImportBoardFromJson
+            | Self::ImportFromJson
+            | Self::ImportFromTrello
+            | Self::SearchCards
+            | Self::ChangeCurrentCardStatus
+            | Self::ChangeCurrentCardPriority
+            | Self::ChangeDateFormat
+            | Self::ChangeTheme
+            | Self::ChangeView
+            | Self::ClearFilter
+            | Self::CopyBoardToClipboard
+            | Self::CopyCardToClipboard
+            | Self::CopyVisibleBoardToClipboard
+            | Self::CreateATheme
+            | Self::DebugMenu
+            | Self::FilterByTag
+            | Self::FindDuplicateBoards
+            | Self::ForceFullSync
+            | Self::HelpMenu
+            | Self::InteractiveTutorial
+            | Self::LoadASaveCloud
+            | Self::LoadASaveLocal
+            | Self::Login
+            | Self::Logout
+            | Self::NoCommandsFound
+            | Self::OpenArchivedCards
+            | Self::OpenStats
+            | Self::OpenCalendar
+            | Self::ProfileNextFrame
+            | Self::RedoAll
+            | Self::ResetPassword
+            | Self::RestoreThemesFromBackup
+            | Self::SetCurrentBoardAccentColor
+            | Self::SetLogLevel
+            | Self::ShowWaitingOnList
+            | Self::SignUp
+            | Self::SyncFromMirror
+            | Self::SyncLocalData
+            | Self::ToggleHighContrastMode
+            | Self::CycleCardDensity
+            | Self::UndoAllSinceLastSave
+            | Self::WhatsNew => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Every command with a shortcut, a command left out here must not have one
+    const COMMANDS_WITH_KEYBINDINGS: [(CommandPaletteActions, KeyBindingEnum); 21] = [
+        (
+            CommandPaletteActions::ArchiveCurrentCard,
+            KeyBindingEnum::ArchiveCard,
+        ),
+        (
+            CommandPaletteActions::DuplicateCurrentBoard,
+            KeyBindingEnum::DuplicateBoard,
+        ),
+        (
+            CommandPaletteActions::DuplicateCurrentCard,
+            KeyBindingEnum::DuplicateCard,
+        ),
+        (
+            CommandPaletteActions::MoveBoardLeft,
+            KeyBindingEnum::MoveBoardLeft,
+        ),
+        (
+            CommandPaletteActions::MoveBoardRight,
+            KeyBindingEnum::MoveBoardRight,
+        ),
+        (
+            CommandPaletteActions::ConfigMenu,
+            KeyBindingEnum::OpenConfigMenu,
+        ),
+        (CommandPaletteActions::EditBoard, KeyBindingEnum::EditBoard),
+        (
+            CommandPaletteActions::ExportToMarkdown,
+            KeyBindingEnum::ExportToMarkdown,
+        ),
+        (
+            CommandPaletteActions::FilterByPriority,
+            KeyBindingEnum::FilterByPriority,
+        ),
+        (
+            CommandPaletteActions::FilterByStatus,
+            KeyBindingEnum::FilterByStatus,
+        ),
+        (
+            CommandPaletteActions::FilterByDateRange,
+            KeyBindingEnum::FilterByDateRange,
+        ),
+        (
+            CommandPaletteActions::MainMenu,
+            KeyBindingEnum::GoToMainMenu,
+        ),
+        (CommandPaletteActions::NewBoard, KeyBindingEnum::NewBoard),
+        (CommandPaletteActions::NewCard, KeyBindingEnum::NewCard),
+        (CommandPaletteActions::Quit, KeyBindingEnum::Quit),
+        (CommandPaletteActions::ResetUI, KeyBindingEnum::ResetUI),
+        (
+            CommandPaletteActions::SaveKanbanState,
+            KeyBindingEnum::SaveState,
+        ),
+        (
+            CommandPaletteActions::MoveCurrentCardToBoard,
+            KeyBindingEnum::MoveCardToBoard,
+        ),
+        (
+            CommandPaletteActions::SetCurrentCardHighlight,
+            KeyBindingEnum::SetCardHighlightColor,
+        ),
+        (
+            CommandPaletteActions::SortCurrentBoardCards,
+            KeyBindingEnum::SortCards,
+        ),
+        (
+            CommandPaletteActions::ToggleFocusTimer,
+            KeyBindingEnum::ToggleFocusTimer,
+        ),
+    ];
+
+    #[test]
+    fn every_command_maps_to_its_keybinding() {
+        for command in CommandPaletteActions::iter() {
+            let expected_keybinding = COMMANDS_WITH_KEYBINDINGS
+                .iter()
+                .find(|(expected_command, _)| *expected_command == command)
+                .map(|(_, keybinding)| *keybinding);
+            assert_eq!(
+                command.keybinding_enum(),
+                expected_keybinding,
+                "{} maps to the wrong keybinding",
+                command
+            );
+        }
+    }
+
+    #[test]
+    fn every_mapped_keybinding_resolves_in_the_config() {
+        let config = AppConfig::default();
+        for command in CommandPaletteActions::iter() {
+            if let Some(keybinding_enum) = command.keybinding_enum() {
+                assert!(
+                    config
+                        .keybindings
+                        .get_keybindings(keybinding_enum)
+                        .is_some(),
+                    "{} maps to {:?} which the config does not know",
+                    command,
+                    keybinding_enum
+                );
+            }
+        }
+    }
 }
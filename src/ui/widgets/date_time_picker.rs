@@ -1,7 +1,8 @@
 use crate::{
     app::{state::Focus, App, DateTimeFormat},
     constants::{
-        DATE_TIME_PICKER_ANIM_DURATION, FIELD_NOT_SET, MIN_DATE_PICKER_HEIGHT, MIN_DATE_PICKER_WIDTH, TIME_PICKER_WIDTH
+        DATE_TIME_PICKER_ANIM_DURATION, FIELD_NOT_SET, MIN_DATE_PICKER_HEIGHT,
+        MIN_DATE_PICKER_WIDTH, TIME_PICKER_WIDTH,
     },
     ui::{
         theme::Theme,
@@ -659,37 +660,30 @@ impl<'a> DateTimePickerWidget<'a> {
                 .with_day(date_to_select as u32);
         }
     }
-}
 
-impl<'a> Widget for DateTimePickerWidget<'a> {
-    fn update(app: &mut App) {
-        if app.state.z_stack.last() != Some(&PopUp::DateTimePicker) {
-            return;
-        }
-        let disable_animations = app.config.disable_animations;
-        let date_time_picker = &mut app.widgets.date_time_picker;
-        match date_time_picker.date_picker_anim_state {
+    /// Steps the open and close animations and keeps the layout in sync. Only borrows the picker
+    /// so the widget loop touches as little of the app as it can, returns true once the date
+    /// picker has finished closing
+    fn update_picker(&mut self, disable_animations: bool) -> bool {
+        let mut date_picker_closed = false;
+        match self.date_picker_anim_state {
             WidgetAnimState::Opening | WidgetAnimState::Closing => {
                 if disable_animations {
-                    date_time_picker.date_picker_anim_state = date_time_picker
-                        .date_picker_anim_state
-                        .complete_current_stage();
-                    return;
+                    self.date_picker_anim_state =
+                        self.date_picker_anim_state.complete_current_stage();
+                    return date_picker_closed;
                 }
-                let current_percentage = date_time_picker.calculate_animation_percentage();
-                let opening = matches!(
-                    date_time_picker.date_picker_anim_state,
-                    WidgetAnimState::Opening
-                );
+                let current_percentage = self.calculate_animation_percentage();
+                let opening = matches!(self.date_picker_anim_state, WidgetAnimState::Opening);
                 if current_percentage < 1.0 {
-                    date_time_picker.date_picker_anim_state = if opening {
+                    self.date_picker_anim_state = if opening {
                         WidgetAnimState::Opening
                     } else {
                         WidgetAnimState::Closing
                     };
-                    date_time_picker.update_date_picker_height(current_percentage, opening);
+                    self.update_date_picker_height(current_percentage, opening);
                 } else {
-                    date_time_picker.date_picker_anim_state = if opening {
+                    self.date_picker_anim_state = if opening {
                         WidgetAnimState::Open
                     } else {
                         WidgetAnimState::Closed
@@ -697,40 +691,33 @@ impl<'a> Widget for DateTimePickerWidget<'a> {
                 }
             }
             WidgetAnimState::Open => {
-                if date_time_picker.date_target_height != date_time_picker.widget_height {
-                    date_time_picker.widget_height = date_time_picker.date_target_height;
+                if self.date_target_height != self.widget_height {
+                    self.widget_height = self.date_target_height;
                 }
             }
             WidgetAnimState::Closed => {
-                app.state.z_stack.pop();
-                if app.state.current_view != View::NewCard {
-                    date_time_picker.reset();
-                }
+                date_picker_closed = true;
             }
         }
 
-        match date_time_picker.time_picker_anim_state {
+        match self.time_picker_anim_state {
             WidgetAnimState::Opening | WidgetAnimState::Closing => {
                 if disable_animations {
-                    date_time_picker.time_picker_anim_state = date_time_picker
-                        .time_picker_anim_state
-                        .complete_current_stage();
-                    return;
+                    self.time_picker_anim_state =
+                        self.time_picker_anim_state.complete_current_stage();
+                    return date_picker_closed;
                 }
-                let current_percentage = date_time_picker.calculate_animation_percentage();
-                let opening = matches!(
-                    date_time_picker.time_picker_anim_state,
-                    WidgetAnimState::Opening
-                );
+                let current_percentage = self.calculate_animation_percentage();
+                let opening = matches!(self.time_picker_anim_state, WidgetAnimState::Opening);
                 if current_percentage < 1.0 {
-                    date_time_picker.time_picker_anim_state = if opening {
+                    self.time_picker_anim_state = if opening {
                         WidgetAnimState::Opening
                     } else {
                         WidgetAnimState::Closing
                     };
-                    date_time_picker.update_time_picker_width(current_percentage, opening);
+                    self.update_time_picker_width(current_percentage, opening);
                 } else {
-                    date_time_picker.time_picker_anim_state = if opening {
+                    self.time_picker_anim_state = if opening {
                         WidgetAnimState::Open
                     } else {
                         WidgetAnimState::Closed
@@ -738,28 +725,22 @@ impl<'a> Widget for DateTimePickerWidget<'a> {
                 }
             }
             WidgetAnimState::Open => {
-                if (date_time_picker.date_target_width + date_time_picker.time_target_width)
-                    != date_time_picker.widget_width
-                {
-                    date_time_picker.widget_width =
-                        date_time_picker.date_target_width + date_time_picker.time_target_width;
+                if (self.date_target_width + self.time_target_width) != self.widget_width {
+                    self.widget_width = self.date_target_width + self.time_target_width;
                 }
             }
             WidgetAnimState::Closed => {
-                if date_time_picker.widget_width != date_time_picker.date_target_width {
-                    date_time_picker.widget_width = date_time_picker.date_target_width;
+                if self.widget_width != self.date_target_width {
+                    self.widget_width = self.date_target_width;
                 }
             }
         }
 
-        date_time_picker.self_correct(
-            date_time_picker.date_target_height,
-            date_time_picker.date_target_width,
-        );
+        self.self_correct(self.date_target_height, self.date_target_width);
 
         let mut re_calculate = false;
-        if let Some((_, calc_date, calc_render_area)) = &date_time_picker.calculated_mouse_coords {
-            if let Some(selected_date) = date_time_picker.selected_date_time {
+        if let Some((_, calc_date, calc_render_area)) = &self.calculated_mouse_coords {
+            if let Some(selected_date) = self.selected_date_time {
                 // check if same month
                 if selected_date.month() != calc_date.month() {
                     re_calculate = true;
@@ -767,16 +748,36 @@ impl<'a> Widget for DateTimePickerWidget<'a> {
             } else {
                 re_calculate = true;
             }
-            if let Some(render_area) = date_time_picker.current_render_area {
+            if let Some(render_area) = self.current_render_area {
                 if render_area != *calc_render_area {
                     re_calculate = true;
                 }
             }
-        } else if date_time_picker.current_render_area.is_some() {
+        } else if self.current_render_area.is_some() {
             re_calculate = true;
         }
         if re_calculate {
-            date_time_picker.calculate_mouse_coords_for_dates();
+            self.calculate_mouse_coords_for_dates();
+        }
+        date_picker_closed
+    }
+}
+
+impl<'a> Widget for DateTimePickerWidget<'a> {
+    fn update(app: &mut App) {
+        if app.state.z_stack.last() != Some(&PopUp::DateTimePicker) {
+            return;
+        }
+        let disable_animations = app.config.disable_animations;
+        if app
+            .widgets
+            .date_time_picker
+            .update_picker(disable_animations)
+        {
+            app.state.z_stack.pop();
+            if app.state.current_view != View::NewCard {
+                app.widgets.date_time_picker.reset();
+            }
         }
     }
 }
@@ -799,7 +800,7 @@ impl<'a> SelfViewportCorrection for DateTimePickerWidget<'a> {
     }
     fn set_anchor(&mut self, anchor: Option<(u16, u16)>) {
         self.set_last_anchor(self.anchor);
-        self.anchor = anchor;        
+        self.anchor = anchor;
     }
     fn set_last_anchor(&mut self, anchor: Option<(u16, u16)>) {
         self.last_anchor = anchor;
@@ -814,3 +815,105 @@ impl<'a> SelfViewportCorrection for DateTimePickerWidget<'a> {
         self.last_corrected_viewport = viewport;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    const TARGET_HEIGHT: u16 = 20;
+
+    fn opening_picker<'a>() -> DateTimePickerWidget<'a> {
+        let mut picker = DateTimePickerWidget::new(CalenderType::default());
+        picker.date_target_height = TARGET_HEIGHT;
+        picker.open_date_picker();
+        picker
+    }
+
+    fn started_ago(picker: &mut DateTimePickerWidget, elapsed: Duration) {
+        picker.last_anim_tick = Instant::now() - elapsed;
+    }
+
+    #[test]
+    fn animation_progress_comes_from_the_time_passed() {
+        let mut picker = opening_picker();
+        started_ago(
+            &mut picker,
+            Duration::from_millis(DATE_TIME_PICKER_ANIM_DURATION / 2),
+        );
+        let percentage = picker.calculate_animation_percentage();
+        assert!((0.5..1.0).contains(&percentage), "{percentage}");
+
+        picker.update_picker(false);
+        assert!(matches!(
+            picker.date_picker_anim_state,
+            WidgetAnimState::Opening
+        ));
+        assert!(
+            picker.widget_height > MIN_DATE_PICKER_HEIGHT && picker.widget_height < TARGET_HEIGHT,
+            "{}",
+            picker.widget_height
+        );
+    }
+
+    #[test]
+    fn a_single_late_update_finishes_the_animation() {
+        let mut picker = opening_picker();
+        started_ago(
+            &mut picker,
+            Duration::from_millis(DATE_TIME_PICKER_ANIM_DURATION),
+        );
+        assert!(!picker.update_picker(false));
+        assert!(matches!(
+            picker.date_picker_anim_state,
+            WidgetAnimState::Open
+        ));
+        picker.update_picker(false);
+        assert_eq!(picker.widget_height, TARGET_HEIGHT);
+
+        picker.close_date_picker();
+        started_ago(
+            &mut picker,
+            Duration::from_millis(DATE_TIME_PICKER_ANIM_DURATION * 3),
+        );
+        picker.update_picker(false);
+        assert!(matches!(
+            picker.date_picker_anim_state,
+            WidgetAnimState::Closed
+        ));
+        assert!(picker.update_picker(false));
+    }
+
+    #[test]
+    fn disabled_animations_complete_the_stage_at_once() {
+        let mut picker = opening_picker();
+        picker.update_picker(true);
+        assert!(matches!(
+            picker.date_picker_anim_state,
+            WidgetAnimState::Open
+        ));
+        picker.close_date_picker();
+        picker.update_picker(true);
+        assert!(matches!(
+            picker.date_picker_anim_state,
+            WidgetAnimState::Closed
+        ));
+    }
+
+    #[test]
+    fn a_slow_tickrate_does_not_slow_the_animation() {
+        let (mut app, _rx) = App::for_tests();
+        app.config.tickrate = 1000;
+        app.state.z_stack.push(PopUp::DateTimePicker);
+        app.widgets.date_time_picker = opening_picker();
+        started_ago(
+            &mut app.widgets.date_time_picker,
+            Duration::from_millis(DATE_TIME_PICKER_ANIM_DURATION),
+        );
+        DateTimePickerWidget::update(&mut app);
+        assert!(matches!(
+            app.widgets.date_time_picker.date_picker_anim_state,
+            WidgetAnimState::Open
+        ));
+    }
+}
@@ -3,15 +3,20 @@ use close_button::CloseButtonWidget;
 use command_palette::CommandPaletteWidget;
 use date_time_picker::{CalenderType, DateTimePickerWidget};
 use ratatui::layout::Rect;
-use std::sync::Arc;
+use std::{
+    sync::Arc,
+    time::{Duration, Instant},
+};
 use tag_picker::TagPickerWidget;
 use toast::ToastWidget;
+use tutorial::TutorialWidget;
 
 pub mod close_button;
 pub mod command_palette;
 pub mod date_time_picker;
 pub mod tag_picker;
 pub mod toast;
+pub mod tutorial;
 
 trait Widget {
     fn update(app: &mut App);
@@ -19,20 +24,41 @@ trait Widget {
 
 pub struct WidgetManager<'a> {
     pub app: Arc<tokio::sync::Mutex<App<'a>>>,
+    lock_held_for: Duration,
+    measuring_since: Instant,
 }
 
 impl WidgetManager<'_> {
     pub fn new(app: Arc<tokio::sync::Mutex<App>>) -> WidgetManager {
-        WidgetManager { app }
+        WidgetManager {
+            app,
+            lock_held_for: Duration::ZERO,
+            measuring_since: Instant::now(),
+        }
     }
 
-    pub async fn update(&mut self) {
+    /// Runs one round of widget updates and returns how long to wait before the next one, going
+    /// by the tickrate so the app is not locked any more often than the UI redraws. Animations
+    /// work out their progress from the time passed, fewer rounds do not slow them down
+    pub async fn update(&mut self) -> Duration {
         let mut app = self.app.lock().await;
+        let lock_acquired_at = Instant::now();
         ToastWidget::update(&mut app);
         CommandPaletteWidget::update(&mut app);
         CloseButtonWidget::update(&mut app);
         DateTimePickerWidget::update(&mut app);
         TagPickerWidget::update(&mut app);
+        TutorialWidget::update(&mut app);
+        self.lock_held_for += lock_acquired_at.elapsed();
+
+        let measured_for = self.measuring_since.elapsed();
+        if measured_for >= Duration::from_secs(1) {
+            app.state.widget_lock_time_per_second =
+                Some(self.lock_held_for.div_f64(measured_for.as_secs_f64()));
+            self.lock_held_for = Duration::ZERO;
+            self.measuring_since = Instant::now();
+        }
+        Duration::from_millis(app.config.tickrate as u64)
     }
 }
 
@@ -42,6 +68,7 @@ pub struct Widgets<'a> {
     pub toast_widget: ToastWidget,
     pub date_time_picker: DateTimePickerWidget<'a>,
     pub tag_picker: TagPickerWidget,
+    pub tutorial: TutorialWidget,
 }
 
 impl<'a> Widgets<'a> {
@@ -52,6 +79,7 @@ impl<'a> Widgets<'a> {
             toast_widget: ToastWidget::default(),
             date_time_picker: DateTimePickerWidget::new(calender_type),
             tag_picker: TagPickerWidget::default(),
+            tutorial: TutorialWidget::default(),
         }
     }
 }
@@ -111,3 +139,48 @@ pub trait SelfViewportCorrection {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn widget_manager<'a>(tickrate: u16) -> WidgetManager<'a> {
+        let (mut app, _rx) = App::for_tests();
+        app.config.tickrate = tickrate;
+        WidgetManager::new(Arc::new(tokio::sync::Mutex::new(app)))
+    }
+
+    #[test]
+    fn the_widget_loop_waits_for_the_configured_tickrate() {
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        for tickrate in [16, 50, 250] {
+            let mut widget_manager = widget_manager(tickrate);
+            let wait = runtime.block_on(widget_manager.update());
+            assert_eq!(wait, Duration::from_millis(tickrate as u64));
+        }
+    }
+
+    #[test]
+    fn lock_time_is_reported_once_a_second_has_been_measured() {
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        let mut widget_manager = widget_manager(50);
+        runtime.block_on(widget_manager.update());
+        let app = widget_manager.app.clone();
+        assert!(runtime
+            .block_on(app.lock())
+            .state
+            .widget_lock_time_per_second
+            .is_none());
+
+        widget_manager.measuring_since = Instant::now() - Duration::from_secs(1);
+        runtime.block_on(widget_manager.update());
+        let lock_time = runtime
+            .block_on(app.lock())
+            .state
+            .widget_lock_time_per_second
+            .expect("a full second was measured");
+        assert!(lock_time < Duration::from_millis(500), "{lock_time:?}");
+        assert_eq!(widget_manager.lock_held_for, Duration::ZERO);
+        assert!(widget_manager.measuring_since.elapsed() < Duration::from_secs(1));
+    }
+}
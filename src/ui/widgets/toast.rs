@@ -19,6 +19,8 @@ pub struct Toast {
     pub title: String,
     pub toast_color: (u8, u8, u8),
     pub toast_type: ToastType,
+    /// Set when the toast announces an action that can be undone straight from the toast
+    pub undo_action_id: Option<u64>,
 }
 
 impl Toast {
@@ -30,6 +32,7 @@ impl Toast {
             title: toast_type.as_string(),
             toast_color: toast_type.as_color(theme),
             toast_type: toast_type.clone(),
+            undo_action_id: None,
         }
     }
 
@@ -47,6 +50,7 @@ impl Toast {
             title,
             toast_color: toast_type.as_color(theme),
             toast_type: toast_type.clone(),
+            undo_action_id: None,
         }
     }
 }
@@ -0,0 +1,206 @@
+use crate::{
+    app::{kanban::Boards, App},
+    constants::{FIELD_NOT_SET, TUTORIAL_BOX_HEIGHT, TUTORIAL_BOX_WIDTH},
+    i18n::{tr, MessageId},
+    inputs::key::Key,
+    ui::{
+        widgets::{SelfViewportCorrection, Widget},
+        PopUp,
+    },
+};
+use log::info;
+use ratatui::layout::Rect;
+use std::collections::{HashMap, HashSet};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TutorialStep {
+    CreateBoard,
+    CreateCard,
+    OpenCard,
+    SetDueDate,
+}
+
+impl TutorialStep {
+    pub const COUNT: usize = 4;
+
+    pub fn number(&self) -> usize {
+        match self {
+            Self::CreateBoard => 1,
+            Self::CreateCard => 2,
+            Self::OpenCard => 3,
+            Self::SetDueDate => 4,
+        }
+    }
+
+    fn next(&self) -> Option<Self> {
+        match self {
+            Self::CreateBoard => Some(Self::CreateCard),
+            Self::CreateCard => Some(Self::OpenCard),
+            Self::OpenCard => Some(Self::SetDueDate),
+            Self::SetDueDate => None,
+        }
+    }
+
+    pub fn instruction(&self, app: &App) -> String {
+        let keybindings = &app.config.keybindings;
+        match self {
+            Self::CreateBoard => format!(
+                "Press {} to create a new board, give it a name and submit it",
+                keys_to_string(&keybindings.new_board)
+            ),
+            Self::CreateCard => format!(
+                "Select a board and press {} to add a card to it",
+                keys_to_string(&keybindings.new_card)
+            ),
+            Self::OpenCard => format!(
+                "Select a card and press {} to open it",
+                keys_to_string(&keybindings.accept)
+            ),
+            Self::SetDueDate => format!(
+                "In the card view move to Due, press {} to pick a date and save the card",
+                keys_to_string(&keybindings.accept)
+            ),
+        }
+    }
+}
+
+fn keys_to_string(keys: &[Key]) -> String {
+    keys.iter()
+        .map(|key| key.to_string())
+        .collect::<Vec<String>>()
+        .join(" or ")
+}
+
+/// Walks through creating a board and a card in the real data. A step only looks at the boards
+/// and popups, it never opens or closes anything itself so it cannot get in the way
+#[derive(Debug, Default)]
+pub struct TutorialWidget {
+    pub step: Option<TutorialStep>,
+    known_board_ids: HashSet<(u64, u64)>,
+    known_card_ids: HashSet<(u64, u64)>,
+    known_due_dates: HashMap<(u64, u64), String>,
+    anchor: Option<(u16, u16)>,
+    last_anchor: Option<(u16, u16)>,
+    pub viewport_corrected_anchor: Option<(u16, u16)>,
+    current_viewport: Option<Rect>,
+    last_corrected_viewport: Option<Rect>,
+}
+
+impl TutorialWidget {
+    pub fn start(&mut self, boards: &Boards) {
+        info!("Starting the interactive tutorial");
+        self.step = Some(TutorialStep::CreateBoard);
+        self.remember_boards(boards);
+    }
+
+    /// Leaves everything made during the tutorial in place
+    pub fn stop(&mut self) {
+        info!("Stopping the interactive tutorial");
+        self.step = None;
+        self.known_board_ids.clear();
+        self.known_card_ids.clear();
+        self.known_due_dates.clear();
+    }
+
+    /// Moves on to the next step, returns false once there are no steps left
+    pub fn advance(&mut self, boards: &Boards) -> bool {
+        match self.step.and_then(|step| step.next()) {
+            Some(next_step) => {
+                info!("Tutorial moved on to step {}", next_step.number());
+                self.step = Some(next_step);
+                self.remember_boards(boards);
+                true
+            }
+            None => {
+                self.stop();
+                false
+            }
+        }
+    }
+
+    /// Steps are done by changes made after they started, so existing data never counts
+    fn remember_boards(&mut self, boards: &Boards) {
+        self.known_board_ids.clear();
+        self.known_card_ids.clear();
+        self.known_due_dates.clear();
+        for board in boards.get_boards() {
+            self.known_board_ids.insert(board.id);
+            for card in board.cards.get_all_cards() {
+                self.known_card_ids.insert(card.id);
+                self.known_due_dates.insert(card.id, card.due_date.clone());
+            }
+        }
+    }
+
+    fn is_step_done(&self, app: &App) -> bool {
+        let boards = app.boards.get_boards();
+        let mut cards = boards
+            .iter()
+            .flat_map(|board| board.cards.get_all_cards().iter());
+        match self.step {
+            Some(TutorialStep::CreateBoard) => boards
+                .iter()
+                .any(|board| !self.known_board_ids.contains(&board.id)),
+            Some(TutorialStep::CreateCard) => {
+                cards.any(|card| !self.known_card_ids.contains(&card.id))
+            }
+            Some(TutorialStep::OpenCard) => app.state.z_stack.contains(&PopUp::ViewCard),
+            Some(TutorialStep::SetDueDate) => cards.any(|card| {
+                card.due_date != FIELD_NOT_SET
+                    && self.known_due_dates.get(&card.id) != Some(&card.due_date)
+            }),
+            None => false,
+        }
+    }
+}
+
+impl Widget for TutorialWidget {
+    fn update(app: &mut App) {
+        if app.widgets.tutorial.step.is_none() {
+            return;
+        }
+        app.widgets
+            .tutorial
+            .self_correct(TUTORIAL_BOX_HEIGHT, TUTORIAL_BOX_WIDTH);
+        if !app.widgets.tutorial.is_step_done(app) {
+            return;
+        }
+        if !app.widgets.tutorial.advance(&app.boards) {
+            app.send_info_toast(&tr(MessageId::ToastTutorialCompleteYouAreAllSet), None);
+        }
+    }
+}
+
+impl SelfViewportCorrection for TutorialWidget {
+    fn get_anchor(&self) -> Option<(u16, u16)> {
+        self.anchor
+    }
+    fn get_last_anchor(&self) -> Option<(u16, u16)> {
+        self.last_anchor
+    }
+    fn get_viewport_corrected_anchor(&self) -> Option<(u16, u16)> {
+        self.viewport_corrected_anchor
+    }
+    fn get_current_viewport(&self) -> Option<Rect> {
+        self.current_viewport
+    }
+    fn get_last_corrected_viewport(&self) -> Option<Rect> {
+        self.last_corrected_viewport
+    }
+    fn set_anchor(&mut self, anchor: Option<(u16, u16)>) {
+        self.set_last_anchor(self.anchor);
+        self.anchor = anchor;
+    }
+    fn set_last_anchor(&mut self, anchor: Option<(u16, u16)>) {
+        self.last_anchor = anchor;
+    }
+    fn set_viewport_corrected_anchor(&mut self, anchor: Option<(u16, u16)>) {
+        self.viewport_corrected_anchor = anchor;
+    }
+    fn set_current_viewport(&mut self, viewport: Option<Rect>) {
+        self.current_viewport = viewport;
+    }
+    fn set_last_corrected_viewport(&mut self, viewport: Option<Rect>) {
+        self.last_corrected_viewport = viewport;
+    }
+}
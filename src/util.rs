@@ -1,27 +1,47 @@
 use crate::{
-    app::{App, AppReturn, DateTimeFormat},
-    constants::{ENCRYPTION_KEY_FILE_NAME, FIELD_NOT_SET},
+    app::{card_filter::CardFilter, kanban::Boards, App, AppConfig, AppReturn},
+    constants::{
+        APP_TITLE, ENCRYPTION_KEY_FILE_NAME, FIELD_NOT_SET, PANIC_REPORT_FILE_NAME,
+        SAFE_MODE_HINT_WINDOW,
+    },
     inputs::{events::Events, InputEvent},
     io::{
-        data_handler::reset_config,
+        data_handler::{
+            get_available_local_save_files, get_config, get_local_kanban_state, get_saved_themes,
+            reset_config, save_kanban_state_locally,
+        },
+        interop::taskwarrior::{export_tasks, import_tasks, parse_task_export},
         io_handler::{
-            delete_a_save_from_database, generate_new_encryption_key,
-            get_all_save_ids_and_creation_dates_for_user, get_config_dir, login_for_user,
-            save_user_encryption_key,
+            delete_a_save_from_database, fetch_github_issues, generate_new_encryption_key,
+            get_all_save_ids_and_creation_dates_for_user, get_config_dir, get_latest_save_file,
+            login_for_user, refresh_visible_boards_and_cards, save_user_encryption_key,
         },
         IoEvent,
     },
+    model::github_issues::import_github_issues,
     ui::ui_main,
 };
-use chrono::{Datelike, NaiveDate, NaiveDateTime, NaiveTime};
-use crossterm::{event::EnableMouseCapture, execute};
+
+pub use crate::model::{
+    date_time_format::{date_format_converter, date_format_finder, parse_date_time},
+    ids::{format_id, format_short_id, generate_id, stable_hash},
+};
+use crossterm::{
+    event::{DisableBracketedPaste, EnableBracketedPaste, EnableMouseCapture},
+    execute,
+};
 use eyre::Result;
-use ratatui::{backend::CrosstermBackend, layout::Rect, Terminal};
-use std::{borrow::Cow, io::stdout, sync::Arc, time::Duration};
+use ratatui::{
+    backend::{CrosstermBackend, TestBackend},
+    layout::Rect,
+    Terminal,
+};
+use std::{borrow::Cow, io::stdout, path::PathBuf, sync::Arc, time::Duration};
 use tokio::time::Instant;
 
 pub async fn start_ui(app: &Arc<tokio::sync::Mutex<App<'_>>>) -> Result<()> {
     crossterm::terminal::enable_raw_mode()?;
+    execute!(stdout(), EnableBracketedPaste)?;
     {
         let app = app.lock().await;
         if app.config.enable_mouse_support {
@@ -62,10 +82,17 @@ pub async fn start_ui(app: &Arc<tokio::sync::Mutex<App<'_>>>) -> Result<()> {
         let result = match events.next().await {
             InputEvent::KeyBoardInput(key) => app.do_action(key).await,
             InputEvent::MouseAction(mouse_action) => app.handle_mouse(mouse_action).await,
+            InputEvent::Paste(text) => app.handle_paste(&text).await,
             InputEvent::Tick => {
                 if app.state.previous_mouse_coordinates != app.state.current_mouse_coordinates {
                     app.state.previous_mouse_coordinates = app.state.current_mouse_coordinates;
                 }
+                app.check_focus_timer();
+                app.check_due_date_reminders();
+                app.auto_archive_completed_cards();
+                if app.is_auto_save_due() {
+                    app.dispatch(IoEvent::AutoSave).await;
+                }
                 AppReturn::Continue
             }
         };
@@ -76,6 +103,7 @@ pub async fn start_ui(app: &Arc<tokio::sync::Mutex<App<'_>>>) -> Result<()> {
     }
 
     execute!(stdout(), crossterm::event::DisableMouseCapture)?;
+    execute!(stdout(), DisableBracketedPaste)?;
     terminal.clear()?;
     terminal.set_cursor_position((0, 0))?;
     terminal.show_cursor()?;
@@ -126,22 +154,21 @@ pub fn lerp_between(
     (r, g, b)
 }
 
-// parses a string to rgb values from a hex string
+/// Parses a `#RRGGBB` hex color, the `#RGB` shorthand is expanded by doubling each digit
 pub fn parse_hex_to_rgb(hex_string: &str) -> Option<(u8, u8, u8)> {
-    if hex_string.len() != 7 {
+    let digits = hex_string.strip_prefix('#')?;
+    if !digits.chars().all(|c| c.is_ascii_hexdigit()) {
         return None;
     }
-    if !hex_string.starts_with('#') {
-        return None;
-    }
-    let hex_string = hex_string.trim_start_matches('#');
-    let r = u8::from_str_radix(&hex_string[0..2], 16);
-    let g = u8::from_str_radix(&hex_string[2..4], 16);
-    let b = u8::from_str_radix(&hex_string[4..6], 16);
-    match (r, g, b) {
-        (Ok(r), Ok(g), Ok(b)) => Some((r, g, b)),
-        _ => None,
-    }
+    let digits = match digits.len() {
+        3 => digits.chars().flat_map(|c| [c, c]).collect::<String>(),
+        6 => digits.to_string(),
+        _ => return None,
+    };
+    let r = u8::from_str_radix(&digits[0..2], 16).ok()?;
+    let g = u8::from_str_radix(&digits[2..4], 16).ok()?;
+    let b = u8::from_str_radix(&digits[4..6], 16).ok()?;
+    Some((r, g, b))
 }
 
 // TODO: Find a way to get the terminal background color
@@ -149,109 +176,6 @@ pub fn get_term_bg_color() -> (u8, u8, u8) {
     (0, 0, 0)
 }
 
-pub fn date_format_finder(date_string: &str) -> Result<DateTimeFormat, String> {
-    let all_formats_with_time = DateTimeFormat::all_formats_with_time();
-    for date_format in DateTimeFormat::get_all_date_formats() {
-        if all_formats_with_time.contains(&date_format) {
-            match NaiveDateTime::parse_from_str(date_string, date_format.to_parser_string()) {
-                Ok(_) => return Ok(date_format),
-                Err(_) => {
-                    continue;
-                }
-            }
-        } else {
-            match NaiveDate::parse_from_str(date_string, date_format.to_parser_string()) {
-                Ok(_) => return Ok(date_format),
-                Err(_) => {
-                    continue;
-                }
-            }
-        }
-    }
-    Err("Invalid date format".to_string())
-}
-
-pub fn date_format_converter(
-    date_string: &str,
-    date_format: DateTimeFormat,
-) -> Result<String, String> {
-    if date_string == FIELD_NOT_SET || date_string.is_empty() {
-        return Ok(date_string.to_string());
-    }
-    let given_date_format = date_format_finder(date_string)?;
-    if given_date_format == date_format {
-        return Ok(date_string.to_string());
-    }
-    let all_formats_with_time = DateTimeFormat::all_formats_with_time();
-    let all_formats_without_time = DateTimeFormat::all_formats_without_time();
-    if all_formats_with_time.contains(&given_date_format)
-        && all_formats_without_time.contains(&date_format)
-    {
-        let naive_date_time =
-            NaiveDateTime::parse_from_str(date_string, given_date_format.to_parser_string());
-        if let Ok(naive_date_time) = naive_date_time {
-            let naive_date = NaiveDate::from_ymd_opt(
-                naive_date_time.year(),
-                naive_date_time.month(),
-                naive_date_time.day(),
-            );
-            if let Some(naive_date) = naive_date {
-                return Ok(naive_date
-                    .format(date_format.to_parser_string())
-                    .to_string());
-            } else {
-                Err("Invalid date format".to_string())
-            }
-        } else {
-            Err("Invalid date format".to_string())
-        }
-    } else if all_formats_without_time.contains(&given_date_format)
-        && all_formats_with_time.contains(&date_format)
-    {
-        let naive_date =
-            NaiveDate::parse_from_str(date_string, given_date_format.to_parser_string());
-        if let Ok(naive_date) = naive_date {
-            let default_time = NaiveTime::from_hms_opt(0, 0, 0);
-            if let Some(default_time) = default_time {
-                let naive_date_time = NaiveDateTime::new(naive_date, default_time);
-                return Ok(naive_date_time
-                    .format(date_format.to_parser_string())
-                    .to_string());
-            } else {
-                Err("Invalid date format".to_string())
-            }
-        } else {
-            Err("Invalid date format".to_string())
-        }
-    } else if all_formats_with_time.contains(&given_date_format)
-        && all_formats_with_time.contains(&date_format)
-    {
-        let naive_date_time =
-            NaiveDateTime::parse_from_str(date_string, given_date_format.to_parser_string());
-        if let Ok(naive_date_time) = naive_date_time {
-            return Ok(naive_date_time
-                .format(date_format.to_parser_string())
-                .to_string());
-        } else {
-            Err("Invalid date format".to_string())
-        }
-    } else if all_formats_without_time.contains(&given_date_format)
-        && all_formats_without_time.contains(&date_format)
-    {
-        let naive_date =
-            NaiveDate::parse_from_str(date_string, given_date_format.to_parser_string());
-        if let Ok(naive_date) = naive_date {
-            return Ok(naive_date
-                .format(date_format.to_parser_string())
-                .to_string());
-        } else {
-            Err("Invalid date format".to_string())
-        }
-    } else {
-        Err("Invalid date format".to_string())
-    }
-}
-
 /// only to be used as a cli argument function
 pub async fn gen_new_key_main(email_id: String, password: String) -> Result<()> {
     let mut previous_key_lost = false;
@@ -378,12 +302,358 @@ pub async fn gen_new_key_main(email_id: String, password: String) -> Result<()>
     Ok(())
 }
 
+/// only to be used from the panic hook, errors are ignored as there is nothing left to report them to
+pub fn write_panic_report(report: &str) {
+    if let Ok(mut panic_report_path) = get_config_dir() {
+        panic_report_path.push(PANIC_REPORT_FILE_NAME);
+        _ = std::fs::write(panic_report_path, report);
+    }
+}
+
+/// only to be used as a cli argument function, called before the terminal is initialised
+pub fn print_safe_mode_hint_after_crash() {
+    let Ok(mut panic_report_path) = get_config_dir() else {
+        return;
+    };
+    panic_report_path.push(PANIC_REPORT_FILE_NAME);
+    let crashed_recently = std::fs::metadata(&panic_report_path)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+        .and_then(|modified| modified.elapsed().ok())
+        .is_some_and(|elapsed| elapsed < Duration::from_secs(SAFE_MODE_HINT_WINDOW));
+    if crashed_recently {
+        bunt::eprintln!(
+            "{$yellow}[WARN]{/$}  - {} crashed less than a minute ago (see {}), if it keeps crashing on startup try --safe-mode",
+            APP_TITLE,
+            panic_report_path.display()
+        );
+    }
+}
+
 pub fn reset_app_main() {
     print_info("🚀 Resetting config");
     reset_config();
     print_info("👍 Config reset");
 }
 
+/// Records how long consecutive spans take, does nothing unless enabled so it can stay in hot paths
+pub struct SpanTimer {
+    enabled: bool,
+    span_start: Instant,
+    spans: Vec<(&'static str, Duration)>,
+}
+
+impl SpanTimer {
+    pub fn new(enabled: bool) -> Self {
+        Self {
+            enabled,
+            span_start: Instant::now(),
+            spans: Vec::new(),
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Ends the current span under the given name and starts the next one
+    pub fn end_span(&mut self, name: &'static str) {
+        if self.enabled {
+            self.spans.push((name, self.span_start.elapsed()));
+            self.span_start = Instant::now();
+        }
+    }
+
+    pub fn spans(&self) -> &[(&'static str, Duration)] {
+        &self.spans
+    }
+
+    pub fn total(&self) -> Duration {
+        self.spans.iter().map(|(_, duration)| *duration).sum()
+    }
+}
+
+/// Runs the normal startup phases with timings and prints them along with environment info,
+/// meant to be attached to bug reports about slowness
+pub fn profile_startup_main() {
+    print_info("🚀 Profiling startup");
+    let mut timer = SpanTimer::new(true);
+
+    let config = match get_config(false) {
+        Ok(config) => config,
+        Err(e) => {
+            print_warn(&format!("Could not load config, using defaults: {}", e));
+            AppConfig::default()
+        }
+    };
+    timer.end_span("Config load");
+
+    let saved_themes = get_saved_themes()
+        .map(|loaded_themes| loaded_themes.themes)
+        .unwrap_or_default();
+    timer.end_span("Theme load");
+
+    let save_files = get_available_local_save_files(&config).unwrap_or_default();
+    timer.end_span("Save listing");
+
+    let latest_save_file = get_latest_save_file(&config).ok();
+    let boards = latest_save_file
+        .clone()
+        .and_then(|save_file| get_local_kanban_state(save_file, false, &config).ok())
+        .unwrap_or_default();
+    timer.end_span("Last save load");
+
+    let (io_tx, _io_rx) = tokio::sync::mpsc::channel::<IoEvent>(1);
+    let mut app = App::with_config(io_tx, false, config.clone());
+    app.all_themes.extend(saved_themes.clone());
+    app.boards.set_boards(boards.clone());
+    refresh_visible_boards_and_cards(&mut app);
+    app.initialized();
+    let default_view = app.config.default_view;
+    app.set_view(default_view);
+    timer.end_span("App setup");
+
+    let terminal_size = crossterm::terminal::size().ok();
+    let (width, height) = terminal_size.unwrap_or((120, 40));
+    if let Err(e) = render_first_frame(&mut app, width, height) {
+        print_error(&e);
+    }
+    timer.end_span("First frame render");
+
+    println!();
+    println!("{:<22}{:>12}", "Phase", "Duration");
+    for (phase, duration) in timer.spans() {
+        println!("{:<22}{:>12}", phase, format!("{:.3?}", duration));
+    }
+    println!("{:<22}{:>12}", "Total", format!("{:.3?}", timer.total()));
+    println!();
+    println!("{:<22}{}", "Version", env!("CARGO_PKG_VERSION"));
+    println!(
+        "{:<22}{} {}",
+        "Platform",
+        std::env::consts::OS,
+        std::env::consts::ARCH
+    );
+    match terminal_size {
+        Some((width, height)) => println!("{:<22}{}x{}", "Terminal size", width, height),
+        None => println!(
+            "{:<22}unknown (rendered at {}x{})",
+            "Terminal size", width, height
+        ),
+    }
+    println!("{:<22}{}", "Saved themes", saved_themes.len());
+    println!("{:<22}{}", "Local save files", save_files.len());
+    println!(
+        "{:<22}{}",
+        "Last save",
+        latest_save_file.unwrap_or_else(|| FIELD_NOT_SET.to_string())
+    );
+    println!(
+        "{:<22}{} boards, {} cards",
+        "Save size",
+        boards.len(),
+        count_cards(&boards)
+    );
+}
+
+/// Imports the open issues of a GitHub repository into the latest local save without starting
+/// the UI, the result is written as a new save version
+pub async fn import_github_main(repo: String, token_env: String, board_name: String) -> Result<()> {
+    print_info(&format!("🚀 Importing GitHub issues from {}", repo));
+    let config = match get_config(false) {
+        Ok(config) => config,
+        Err(e) => {
+            print_warn(&format!("Could not load config, using defaults: {}", e));
+            AppConfig::default()
+        }
+    };
+    let mut boards = match get_latest_save_file(&config) {
+        Ok(save_file) => match get_local_kanban_state(save_file, false, &config) {
+            Ok(boards) => boards,
+            Err(e) => {
+                print_error(&format!("Could not load the latest save: {}", e));
+                print_error("Aborting...");
+                return Ok(());
+            }
+        },
+        Err(_) => {
+            print_warn("No local save found, starting from no boards");
+            Boards::default()
+        }
+    };
+
+    let token = std::env::var(&token_env)
+        .ok()
+        .filter(|token| !token.is_empty());
+    if token.is_none() {
+        print_warn(&format!(
+            "{} is not set, only public repositories can be read",
+            token_env
+        ));
+    }
+    let (issues, failures) = match fetch_github_issues(&repo, token.as_deref()).await {
+        Ok(fetched_issues) => fetched_issues,
+        Err(e) => {
+            print_error(&format!("GitHub import failed: {}", e));
+            return Ok(());
+        }
+    };
+    let import = import_github_issues(&mut boards, &issues, &board_name, config.date_time_format);
+    for failure in &failures {
+        print_warn(&format!(
+            "Not imported {}: {}",
+            failure.issue, failure.reason
+        ));
+    }
+
+    if import.added_cards.is_empty() && import.updated_cards.is_empty() {
+        print_info("Nothing changed, no new save was written");
+    } else {
+        match save_kanban_state_locally(boards.get_boards().clone(), &config, false) {
+            Ok(save_path) => print_info(&format!("Saved to {}", save_path)),
+            Err(e) => {
+                print_error(&format!("Could not save the imported cards: {}", e));
+                return Ok(());
+            }
+        }
+    }
+    print_info(&format!("👍 {}", import.summary()));
+    if !failures.is_empty() {
+        print_warn(&format!(
+            "{} issue{} could not be imported",
+            failures.len(),
+            if failures.len() == 1 { "" } else { "s" }
+        ));
+    }
+    Ok(())
+}
+
+/// Writes the cards of the latest save that pass `card_filter` as taskwarrior tasks. Only the
+/// tasks go to stdout so it can be piped straight into `task import`
+pub fn export_taskwarrior_main(output: Option<PathBuf>, card_filter: CardFilter) {
+    let config = get_config(false).unwrap_or_default();
+    let boards = match get_latest_save_file(&config)
+        .and_then(|save_file| get_local_kanban_state(save_file, false, &config))
+    {
+        Ok(boards) => boards,
+        Err(e) => {
+            print_error(&format!("Could not load the latest save: {}", e));
+            return;
+        }
+    };
+    let boards = Boards::from(card_filter.apply(
+        boards.get_boards(),
+        config.date_time_format,
+        chrono::Local::now().naive_local(),
+    ));
+    let tasks = match export_tasks(&boards) {
+        Ok(tasks) => tasks,
+        Err(e) => {
+            print_error(&e);
+            return;
+        }
+    };
+    match output {
+        Some(path) => match std::fs::write(&path, tasks) {
+            Ok(()) => {
+                print_info(&format!(
+                    "👍 Exported {} cards to {}",
+                    count_cards(&boards),
+                    path.display()
+                ));
+                // The tasks are plain JSON with no room for a header, so the filter is only
+                // mentioned here
+                if let Some(export_note) = card_filter.export_note(config.date_time_format) {
+                    print_info(&export_note);
+                }
+            }
+            Err(e) => print_error(&format!("Could not write {}: {}", path.display(), e)),
+        },
+        None => println!("{}", tasks),
+    }
+}
+
+/// Reads the output of `task export` from a file or stdin into the latest save, updating the
+/// cards the tasks were exported from and adding the rest
+pub fn import_taskwarrior_main(input: Option<PathBuf>) {
+    print_info("🚀 Importing taskwarrior tasks");
+    let config = match get_config(false) {
+        Ok(config) => config,
+        Err(e) => {
+            print_warn(&format!("Could not load config, using defaults: {}", e));
+            AppConfig::default()
+        }
+    };
+    let json = match &input {
+        Some(path) => std::fs::read_to_string(path),
+        None => std::io::read_to_string(std::io::stdin()),
+    };
+    let tasks = match json
+        .map_err(|e| e.to_string())
+        .and_then(|json| parse_task_export(&json))
+    {
+        Ok(tasks) => tasks,
+        Err(e) => {
+            print_error(&format!("Could not read the tasks: {}", e));
+            return;
+        }
+    };
+    let mut boards = match get_latest_save_file(&config) {
+        Ok(save_file) => match get_local_kanban_state(save_file, false, &config) {
+            Ok(boards) => boards,
+            Err(e) => {
+                print_error(&format!("Could not load the latest save: {}", e));
+                print_error("Aborting...");
+                return;
+            }
+        },
+        Err(_) => {
+            print_warn("No local save found, starting from no boards");
+            Boards::default()
+        }
+    };
+
+    let import = import_tasks(&mut boards, &tasks, config.date_time_format);
+    for board_name in &import.created_boards {
+        print_info(&format!("Made board '{}'", board_name));
+    }
+    if import.added_cards == 0 && import.updated_cards == 0 {
+        print_info("Nothing changed, no new save was written");
+    } else {
+        match save_kanban_state_locally(boards.get_boards().clone(), &config, false) {
+            Ok(save_path) => print_info(&format!("Saved to {}", save_path)),
+            Err(e) => {
+                print_error(&format!("Could not save the imported cards: {}", e));
+                return;
+            }
+        }
+    }
+    print_info(&format!("👍 {}", import.summary()));
+}
+
+/// Draws one frame into a TestBackend the size of the terminal, the way the first frame of a
+/// normal start is drawn, without taking over the terminal
+fn render_first_frame(
+    app: &mut App,
+    width: u16,
+    height: u16,
+) -> Result<Terminal<TestBackend>, String> {
+    let mut terminal = Terminal::new(TestBackend::new(width, height))
+        .map_err(|e| format!("Could not create test terminal: {}", e))?;
+    terminal
+        .draw(|rect| ui_main::draw(rect, app))
+        .map_err(|e| format!("Could not render first frame: {}", e))?;
+    Ok(terminal)
+}
+
+fn count_cards(boards: &Boards) -> usize {
+    boards
+        .get_boards()
+        .iter()
+        .map(|board| board.cards.len())
+        .sum()
+}
+
 pub fn print_error(error: &str) {
     bunt::println!("{$red}[ERROR]{/$} - {}", error);
 }
@@ -433,3 +703,77 @@ pub fn replace_tabs(s: &str, tab_len: u8) -> Cow<'_, str> {
         Cow::Owned(buf)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::kanban::{Board, Card};
+
+    #[test]
+    fn a_disabled_span_timer_records_nothing() {
+        let mut timer = SpanTimer::new(false);
+        timer.end_span("Config load");
+        timer.end_span("Theme load");
+        assert!(!timer.is_enabled());
+        assert!(timer.spans().is_empty());
+        assert_eq!(timer.total(), Duration::ZERO);
+    }
+
+    #[test]
+    fn an_enabled_span_timer_records_each_span_in_order() {
+        let mut timer = SpanTimer::new(true);
+        timer.end_span("Config load");
+        std::thread::sleep(Duration::from_millis(5));
+        timer.end_span("Theme load");
+        let names = timer
+            .spans()
+            .iter()
+            .map(|(name, _)| *name)
+            .collect::<Vec<&str>>();
+        assert_eq!(names, vec!["Config load", "Theme load"]);
+        assert!(timer.spans()[1].1 >= Duration::from_millis(5));
+        assert_eq!(timer.total(), timer.spans()[0].1 + timer.spans()[1].1);
+    }
+
+    #[test]
+    fn the_profiled_first_frame_shows_the_loaded_boards() {
+        let (mut app, _io_rx) = App::for_tests();
+        let mut board = Board::new("Profiled board", "");
+        board.cards.add_card(Card::default());
+        app.boards.set_boards(Boards::from(vec![board]));
+        refresh_visible_boards_and_cards(&mut app);
+        app.initialized();
+        let default_view = app.config.default_view;
+        app.set_view(default_view);
+        app.state.profile_next_frame = true;
+
+        let terminal = render_first_frame(&mut app, 120, 40).unwrap();
+        let screen = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect::<String>();
+        assert!(screen.contains("Profiled board"));
+        // Profiling covers a single frame
+        assert!(!app.state.profile_next_frame);
+        assert_eq!(count_cards(&app.boards), 1);
+    }
+
+    #[test]
+    fn hex_colors_parse_in_full_and_shorthand_form() {
+        assert_eq!(parse_hex_to_rgb("#ff8000"), Some((255, 128, 0)));
+        assert_eq!(parse_hex_to_rgb("#AbCdEf"), Some((171, 205, 239)));
+        assert_eq!(parse_hex_to_rgb("#f80"), Some((255, 136, 0)));
+    }
+
+    #[test]
+    fn malformed_hex_colors_are_rejected() {
+        for invalid in ["#ff80", "#ff80001", "#", "", "ff8000", "#gg8000", "#+f8000"] {
+            assert_eq!(parse_hex_to_rgb(invalid), None, "{}", invalid);
+        }
+        // Multi byte characters are rejected before the digits are sliced
+        assert_eq!(parse_hex_to_rgb("#éé80"), None);
+    }
+}